@@ -151,13 +151,20 @@ public sealed class DraconisClient : IDisposable
         EnsureNotDisposed();
         var code = NativeMethods.DracGetPrimaryOutput(_mgr, out var native);
         ThrowIfError(code);
-        return new DisplayInfo(
-            native.Id,
-            native.Width,
-            native.Height,
-            native.RefreshRate,
-            native.IsPrimary
-        );
+        try
+        {
+            return new DisplayInfo(
+                native.Id,
+                native.Width,
+                native.Height,
+                native.RefreshRate,
+                native.IsPrimary
+            );
+        }
+        finally
+        {
+            NativeMethods.DracFreeDisplayInfo(ref native);
+        }
     }
 
     public IReadOnlyList<NetworkInterfaceInfo> GetNetworkInterfaces()
@@ -394,6 +401,7 @@ public sealed class Plugin : IDisposable
             DracPluginFieldValueType.String => TakeString(value.Value.StringValue, free: false),
             DracPluginFieldValueType.Array => PluginFieldArrayToObjectArray(value.Value.ArrayValue),
             DracPluginFieldValueType.Object => PluginFieldObjectToDictionary(value.Value.ObjectValue),
+            DracPluginFieldValueType.Timestamp => DateTimeOffset.FromUnixTimeMilliseconds(value.Value.TimestampValue),
             _ => null,
         };
     }
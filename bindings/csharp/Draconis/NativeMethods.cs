@@ -28,6 +28,13 @@ public enum DracErrorCode : int
     Success = 255,
 }
 
+internal enum DracDuplex : int
+{
+    Unknown = 0,
+    Half = 1,
+    Full = 2,
+}
+
 internal enum DracBatteryStatus : int
 {
     Unknown = 0,
@@ -68,6 +75,12 @@ internal struct DracDiskInfo
     public IntPtr DriveType;
     public ulong TotalBytes;
     public ulong UsedBytes;
+    public long TotalInodes;
+    public long UsedInodes;
+    public IntPtr MountOptions;
+    public nuint MountOptionCount;
+    [MarshalAs(UnmanagedType.I1)]
+    public bool IsReadOnly;
     [MarshalAs(UnmanagedType.I1)]
     public bool IsSystemDrive;
 }
@@ -88,6 +101,20 @@ internal struct DracDisplayInfo
     public double RefreshRate;
     [MarshalAs(UnmanagedType.I1)]
     public bool IsPrimary;
+    public int PositionX;
+    public int PositionY;
+    public double ScaleFactor;
+    public double Dpi;
+    public byte ColorDepth;
+    public ushort Rotation;
+    public int Connector;
+    public IntPtr Manufacturer;
+    public IntPtr Model;
+    public IntPtr Serial;
+    public int Gamut;
+    [MarshalAs(UnmanagedType.I1)]
+    public bool HdrSupported;
+    public int HdrEnabled;
 }
 
 [StructLayout(LayoutKind.Sequential)]
@@ -108,6 +135,9 @@ internal struct DracNetworkInterface
     public bool IsUp;
     [MarshalAs(UnmanagedType.I1)]
     public bool IsLoopback;
+    public long LinkSpeedMbps;
+    public int Mtu;
+    public DracDuplex Duplex;
 }
 
 [StructLayout(LayoutKind.Sequential)]
@@ -125,6 +155,12 @@ internal struct DracBattery
     public long TimeRemainingSecs;
 }
 
+internal enum DracPluginType : int
+{
+    InfoProvider = 0,
+    OutputFormat = 1,
+}
+
 [StructLayout(LayoutKind.Sequential)]
 internal struct DracPluginInfo
 {
@@ -132,6 +168,20 @@ internal struct DracPluginInfo
     public IntPtr Version;
     public IntPtr Author;
     public IntPtr Description;
+    public DracPluginType Type;
+    public IntPtr Capabilities;
+    public nuint CapabilityCount;
+    [MarshalAs(UnmanagedType.I1)]
+    public bool RequiresNetwork;
+    [MarshalAs(UnmanagedType.I1)]
+    public bool RequiresFilesystem;
+    [MarshalAs(UnmanagedType.I1)]
+    public bool RequiresAdmin;
+    [MarshalAs(UnmanagedType.I1)]
+    public bool RequiresCaching;
+    public IntPtr ConfigSchema;
+    [MarshalAs(UnmanagedType.I1)]
+    public bool IsStatic;
 }
 
 [StructLayout(LayoutKind.Sequential)]
@@ -163,6 +213,8 @@ internal enum DracPluginFieldValueType
     String,
     Array,
     Object,
+    Timestamp,
+    Null,
 }
 
 [StructLayout(LayoutKind.Explicit)]
@@ -175,6 +227,7 @@ internal struct DracPluginFieldValueUnion
     [FieldOffset(0)] public IntPtr StringValue;
     [FieldOffset(0)] public DracPluginFieldValueArray ArrayValue;
     [FieldOffset(0)] public DracPluginFieldValueObject ObjectValue;
+    [FieldOffset(0)] public long TimestampValue;
 }
 
 [StructLayout(LayoutKind.Sequential)]
@@ -251,6 +304,9 @@ internal static class NativeMethods
     [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
     internal static extern void DracFreeDiskInfoList(ref DracDiskInfoList list);
 
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern void DracFreeDisplayInfo(ref DracDisplayInfo info);
+
     [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
     internal static extern void DracFreeDisplayInfoList(ref DracDisplayInfoList list);
 
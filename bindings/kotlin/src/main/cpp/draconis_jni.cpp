@@ -66,6 +66,13 @@ namespace {
         }
         return map;
       }
+      case DRAC_PLUGIN_FIELD_TIMESTAMP: {
+        jclass    cls  = env->FindClass("java/lang/Long");
+        jmethodID ctor = env->GetMethodID(cls, "<init>", "(J)V");
+        return env->NewObject(cls, ctor, static_cast<jlong>(value.timestampValue));
+      }
+      case DRAC_PLUGIN_FIELD_NULL:
+        return nullptr;
     }
 
     return nullptr;
@@ -302,9 +309,11 @@ extern "C" {
 
     jclass    displayClass = env->FindClass("draconis/DisplayInfo");
     jmethodID ctor         = env->GetMethodID(displayClass, "<init>", "(JJJDZ)V");
-    return env->NewObject(
+    jobject   obj          = env->NewObject(
       displayClass, ctor, (jlong)display.id, (jlong)display.width, (jlong)display.height, (jdouble)display.refreshRate, (jboolean)display.isPrimary
     );
+    DracFreeDisplayInfo(&display);
+    return obj;
   }
 
   JNIEXPORT auto JNICALL Java_draconis_CacheManager_nativeGetNetworkInterfaces(JNIEnv* env, jobject /*obj*/, jlong handle) -> jobjectArray {
@@ -27,6 +27,7 @@ namespace {
       case DRAC_ERROR_RESOURCE_EXHAUSTED:  return "Resource exhausted";
       case DRAC_ERROR_TIMEOUT:             return "Timeout";
       case DRAC_ERROR_UNAVAILABLE_FEATURE: return "Unavailable feature";
+      case DRAC_ERROR_CANCELLED:           return "Cancelled";
       case DRAC_SUCCESS:                   return "Success";
       default:                             return "Unknown error";
     }
@@ -56,6 +57,10 @@ namespace {
           result[value.objectValue.items[i].key ? value.objectValue.items[i].key : ""] = pluginFieldValueToLua(lua, value.objectValue.items[i].value);
         return result;
       }
+      case DRAC_PLUGIN_FIELD_TIMESTAMP:
+        return sol::make_object(lua, value.timestampValue);
+      case DRAC_PLUGIN_FIELD_NULL:
+        return sol::nil;
     }
 
     return sol::nil;
@@ -253,7 +258,9 @@ namespace {
       sol::state_view lua(state);
       DracDisplayInfo info {};
       check(DracGetPrimaryOutput(mgr, &info));
-      return display_info_to_table(lua, info);
+      sol::table table = display_info_to_table(lua, info);
+      DracFreeDisplayInfo(&info);
+      return table;
     }
 
     [[nodiscard]] auto getNetworkInterfaces(sol::this_state state) const -> sol::table {
@@ -407,6 +414,7 @@ DRAC_LUA_API auto luaopen_draconis(lua_State* State) -> int {
       {  "ResourceExhausted",  DRAC_ERROR_RESOURCE_EXHAUSTED },
       {            "Timeout",             DRAC_ERROR_TIMEOUT },
       { "UnavailableFeature", DRAC_ERROR_UNAVAILABLE_FEATURE },
+      {          "Cancelled",           DRAC_ERROR_CANCELLED },
       {            "Success",                   DRAC_SUCCESS },
   }
   );
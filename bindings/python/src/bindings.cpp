@@ -47,6 +47,10 @@ namespace {
           result[nb::str(value.objectValue.items[i].key ? value.objectValue.items[i].key : "")] = plugin_field_value_to_python(value.objectValue.items[i].value);
         return std::move(result);
       }
+      case DRAC_PLUGIN_FIELD_TIMESTAMP:
+        return nb::int_(value.timestampValue);
+      case DRAC_PLUGIN_FIELD_NULL:
+        return nb::none();
     }
 
     return nb::none();
@@ -75,6 +79,7 @@ NB_MODULE(draconis, module) {
     .value("ResourceExhausted", DRAC_ERROR_RESOURCE_EXHAUSTED)
     .value("Timeout", DRAC_ERROR_TIMEOUT)
     .value("UnavailableFeature", DRAC_ERROR_UNAVAILABLE_FEATURE)
+    .value("Cancelled", DRAC_ERROR_CANCELLED)
     .value("Success", DRAC_SUCCESS);
 
   nb::enum_<DracBatteryStatus>(module, "BatteryStatus")
@@ -22,10 +22,13 @@ fn main() {
   println!("cargo:rerun-if-env-changed=DRAC_PLUGIN_DIRS");
   println!("cargo:rerun-if-env-changed=DRAC_STATIC_PLUGINS");
   println!("cargo:rerun-if-env-changed=DRAC_PACKAGECOUNT");
+  println!("cargo:rerun-if-env-changed=DRAC_PUBLICIP");
+  println!("cargo:rerun-if-env-changed=DRAC_CLOUDINFO");
   println!("cargo:rerun-if-env-changed=DRAC_CACHING");
   println!("cargo:rerun-if-env-changed=DRAC_BUILD_TYPE");
 
   run_meson_build(&monorepo_root, &build_dir);
+  emit_plugin_cfg(&build_dir);
 
   generate_bindings(&monorepo_root, &out_dir);
 
@@ -40,6 +43,8 @@ fn run_meson_build(monorepo_root: &Path, build_dir: &Path) {
   let plugin_dirs = env::var("DRAC_PLUGIN_DIRS").ok();
   let static_plugins = env::var("DRAC_STATIC_PLUGINS").ok();
   let packagecount = env::var("DRAC_PACKAGECOUNT").ok();
+  let publicip = env::var("DRAC_PUBLICIP").ok();
+  let cloudinfo = env::var("DRAC_CLOUDINFO").ok();
   let caching = env::var("DRAC_CACHING").ok();
   let build_type = env::var("DRAC_BUILD_TYPE").ok();
 
@@ -48,6 +53,8 @@ fn run_meson_build(monorepo_root: &Path, build_dir: &Path) {
     || plugin_dirs.is_some()
     || static_plugins.is_some()
     || packagecount.is_some()
+    || publicip.is_some()
+    || cloudinfo.is_some()
     || caching.is_some()
     || build_type.is_some();
 
@@ -82,6 +89,14 @@ fn run_meson_build(monorepo_root: &Path, build_dir: &Path) {
       args.push(format!("-Dpackagecount={}", val));
     }
 
+    if let Some(val) = &publicip {
+      args.push(format!("-Dpublicip={}", val));
+    }
+
+    if let Some(val) = &cloudinfo {
+      args.push(format!("-Dcloudinfo={}", val));
+    }
+
     if let Some(val) = &plugin_dirs {
       args.push(format!("-Dplugin_dirs={}", val));
     }
@@ -121,6 +136,14 @@ fn run_meson_build(monorepo_root: &Path, build_dir: &Path) {
       args.push(format!("-Dpackagecount={}", val));
     }
 
+    if let Some(val) = &publicip {
+      args.push(format!("-Dpublicip={}", val));
+    }
+
+    if let Some(val) = &cloudinfo {
+      args.push(format!("-Dcloudinfo={}", val));
+    }
+
     if let Some(val) = &caching {
       args.push(format!("-Dcaching={}", val));
     }
@@ -149,6 +172,19 @@ fn run_meson_build(monorepo_root: &Path, build_dir: &Path) {
   }
 }
 
+/// Sets `cfg(drac_plugins)` when the library was built with the plugin system,
+/// so tests that register plugins can be left out of builds without it.
+fn emit_plugin_cfg(build_dir: &Path) {
+  println!("cargo:rustc-check-cfg=cfg(drac_plugins)");
+
+  let compile_commands = std::fs::read_to_string(build_dir.join("compile_commands.json"))
+    .expect("Failed to read compile_commands.json");
+
+  if compile_commands.contains("-DDRAC_ENABLE_PLUGINS=1") {
+    println!("cargo:rustc-cfg=drac_plugins");
+  }
+}
+
 fn generate_bindings(monorepo_root: &Path, out_dir: &str) {
   let header_path = monorepo_root.join("c-api/include/draconis_c.h");
 
@@ -197,16 +233,25 @@ fn link_system_libs(target_os: &str) {
     "windows" => {
       for lib in &[
         "dwmapi", "setupapi", "dxgi", "dxguid", "ole32", "propsys", "iphlpapi", "ws2_32",
-        "advapi32", "user32", "shell32", "psapi", "bcrypt",
+        "advapi32", "user32", "shell32", "psapi", "bcrypt", "ntdll", "powrprof", "wbemuuid",
+        "oleaut32", "gdi32", "wtsapi32", "bthprops", "wlanapi", "mpr", "tbs", "dxva2", "shcore",
+        "winspool",
       ] {
         println!("cargo:rustc-link-lib=dylib={}", lib);
       }
     }
     "macos" => {
+      println!("cargo:rustc-link-lib=framework=AppKit");
+      println!("cargo:rustc-link-lib=framework=AVFoundation");
+      println!("cargo:rustc-link-lib=framework=CoreAudio");
       println!("cargo:rustc-link-lib=framework=CoreGraphics");
+      println!("cargo:rustc-link-lib=framework=CoreWLAN");
       println!("cargo:rustc-link-lib=framework=Foundation");
+      println!("cargo:rustc-link-lib=framework=IOBluetooth");
       println!("cargo:rustc-link-lib=framework=IOKit");
+      println!("cargo:rustc-link-lib=framework=ServiceManagement");
       println!("cargo:rustc-link-lib=framework=SystemConfiguration");
+      println!("cargo:rustc-link-lib=dylib=IOReport");
     }
     "linux" | "freebsd" | "netbsd" | "openbsd" => {
       println!("cargo:rustc-link-lib=dylib=dl");
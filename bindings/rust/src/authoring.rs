@@ -0,0 +1,566 @@
+//! Writing info provider plugins in Rust.
+//!
+//! Implement [`DracPlugin`] and register the type with [`drac_plugin!`]. The
+//! plugin can then be loaded by name through [`Plugin::new`] and the plugin
+//! manager, the same way as a statically linked C++ plugin.
+//!
+//! ```ignore
+//! #[derive(Default)]
+//! struct Uptime {
+//!   seconds: u64,
+//! }
+//!
+//! impl DracPlugin for Uptime {
+//!   const NAME: &'static CStr = c"uptime_rs";
+//!
+//!   fn initialize(&mut self) -> Result<()> {
+//!     Ok(())
+//!   }
+//!
+//!   fn collect(&mut self) -> Result<()> {
+//!     self.seconds = get_uptime()?.as_secs();
+//!     Ok(())
+//!   }
+//!
+//!   fn fields(&self) -> HashMap<String, PluginFieldValue> {
+//!     HashMap::from([("seconds".to_owned(), PluginFieldValue::U64(self.seconds))])
+//!   }
+//! }
+//!
+//! draconis::drac_plugin!(Uptime)?;
+//! let manager = PluginManager::new();
+//! let plugin = Plugin::new(&manager, "uptime_rs")?;
+//! ```
+//!
+//! [`Plugin::new`]: crate::Plugin::new
+
+use std::{
+  collections::HashMap,
+  ffi::{CStr, CString, c_char, c_void},
+  panic::{self, AssertUnwindSafe},
+  ptr,
+  sync::{Arc, Mutex, PoisonError},
+  time::UNIX_EPOCH,
+};
+
+use crate::{
+  sys,
+  types::{
+    DRAC_ERROR_API_UNAVAILABLE, DRAC_ERROR_CANCELLED, DRAC_ERROR_CONFIGURATION_ERROR,
+    DRAC_ERROR_CORRUPTED_DATA, DRAC_ERROR_INTERNAL_ERROR, DRAC_ERROR_INVALID_ARGUMENT,
+    DRAC_ERROR_IO_ERROR, DRAC_ERROR_NETWORK_ERROR, DRAC_ERROR_NOT_FOUND, DRAC_ERROR_NOT_SUPPORTED,
+    DRAC_ERROR_OTHER, DRAC_ERROR_OUT_OF_MEMORY, DRAC_ERROR_PARSE_ERROR,
+    DRAC_ERROR_PERMISSION_DENIED, DRAC_ERROR_PERMISSION_REQUIRED, DRAC_ERROR_PLATFORM_SPECIFIC,
+    DRAC_ERROR_RESOURCE_EXHAUSTED, DRAC_ERROR_TIMEOUT, DRAC_ERROR_UNAVAILABLE_FEATURE,
+    DRAC_PLUGIN_FIELD_ARRAY, DRAC_PLUGIN_FIELD_BOOL, DRAC_PLUGIN_FIELD_F64, DRAC_PLUGIN_FIELD_I64,
+    DRAC_PLUGIN_FIELD_NULL, DRAC_PLUGIN_FIELD_OBJECT, DRAC_PLUGIN_FIELD_STRING,
+    DRAC_PLUGIN_FIELD_TIMESTAMP, DRAC_PLUGIN_FIELD_U64, DRAC_SUCCESS, DracError, DracErrorCode,
+    ErrorCode, PluginFieldSchema, PluginFieldValue, PluginPermissions, Result,
+  },
+};
+
+/// An info provider plugin implemented in Rust.
+///
+/// Every call on a plugin instance comes from the thread using the
+/// [`Plugin`](crate::Plugin) that loaded it, so implementations don't need to be
+/// `Sync`. A panic in any method is reported to the host as
+/// [`ErrorCode::InternalError`] rather than unwinding into C++.
+pub trait DracPlugin: Default + 'static {
+  /// Registry name, passed to [`Plugin::new`](crate::Plugin::new).
+  const NAME: &'static CStr;
+  const VERSION: &'static CStr = c"0.1.0";
+  const AUTHOR: &'static CStr = c"";
+  const DESCRIPTION: &'static CStr = c"";
+  /// Key the plugin's fields are nested under. Defaults to [`NAME`](Self::NAME).
+  const PROVIDER_ID: &'static CStr = Self::NAME;
+  const DISPLAY_LABEL: &'static CStr = Self::NAME;
+  /// Nerd Font icon shown before the label, including its trailing space.
+  const DISPLAY_ICON: &'static CStr = c"";
+  /// Describes the TOML keys [`set_config`](Self::set_config) accepts.
+  const CONFIG_SCHEMA: Option<&'static CStr> = None;
+  /// What the plugin needs from the host, reported by
+  /// [`PluginManager::discover`](crate::PluginManager::discover).
+  const PERMISSIONS: PluginPermissions = PluginPermissions {
+    network:    false,
+    filesystem: false,
+    admin:      false,
+    caching:    false,
+  };
+
+  /// Receives the TOML passed to [`Plugin::set_config`](crate::Plugin::set_config).
+  fn set_config(&mut self, toml_config: &str) -> Result<()> {
+    let _ = toml_config;
+    Ok(())
+  }
+
+  fn initialize(&mut self) -> Result<()>;
+
+  /// Refreshes the data returned by [`fields`](Self::fields).
+  fn collect(&mut self) -> Result<()>;
+
+  fn fields(&self) -> HashMap<String, PluginFieldValue>;
+
+  /// Describes the fields [`fields`](Self::fields) returns, so hosts can lay
+  /// out the plugin's data before the first collection.
+  fn field_schema(&self) -> Vec<PluginFieldSchema> {
+    Vec::new()
+  }
+
+  /// Single-line text shown in the UI, e.g. `21°C, Clear sky`.
+  fn display_value(&self) -> Option<String> {
+    None
+  }
+
+  fn is_enabled(&self) -> bool {
+    true
+  }
+
+  /// Called when the host starts listening for updates, with a handle the
+  /// plugin can push its fields through as soon as they change, rather than
+  /// waiting to be polled. The default drops it, for plugins that are only
+  /// polled.
+  fn set_updater(&mut self, updater: PluginUpdater) {
+    let _ = updater;
+  }
+}
+
+/// Pushes a plugin's fields to the callbacks registered with
+/// [`Plugin::subscribe`](crate::Plugin::subscribe). Can be cloned and used
+/// from any thread; pushes are dropped while the host isn't listening.
+#[derive(Clone)]
+pub struct PluginUpdater {
+  sink: Arc<Mutex<Option<UpdateSink>>>,
+}
+
+/// Where the host currently wants pushed fields delivered.
+struct UpdateSink {
+  push:    unsafe extern "C" fn(*mut c_void, *const sys::DracPluginFieldList),
+  context: *mut c_void,
+}
+
+// The host accepts pushes from any thread.
+unsafe impl Send for UpdateSink {}
+
+impl PluginUpdater {
+  pub fn push(&self, fields: &HashMap<String, PluginFieldValue>) {
+    // Held through the call, so the host can't stop listening while it runs.
+    let sink = self.sink.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(sink) = sink.as_ref() {
+      let mut arena = FieldArena::default();
+      let (items, count) = arena.object(fields);
+      let list = sys::DracPluginFieldList {
+        items,
+        count,
+      };
+
+      unsafe { (sink.push)(sink.context, &list) };
+    }
+  }
+}
+
+/// The C vtable for a [`DracPlugin`] type, built by [`drac_plugin!`].
+pub struct PluginVTable(sys::DracPluginVTable);
+
+// Only holds pointers to 'static C strings and to functions.
+unsafe impl Sync for PluginVTable {}
+
+impl PluginVTable {
+  pub const fn new<P: DracPlugin>() -> Self {
+    Self(sys::DracPluginVTable {
+      name:               P::NAME.as_ptr(),
+      version:            P::VERSION.as_ptr(),
+      author:             P::AUTHOR.as_ptr(),
+      description:        P::DESCRIPTION.as_ptr(),
+      providerId:         P::PROVIDER_ID.as_ptr(),
+      displayLabel:       P::DISPLAY_LABEL.as_ptr(),
+      displayIcon:        P::DISPLAY_ICON.as_ptr(),
+      configSchema:       match P::CONFIG_SCHEMA {
+        Some(schema) => schema.as_ptr(),
+        None => ptr::null(),
+      },
+      requiresNetwork:    P::PERMISSIONS.network,
+      requiresFilesystem: P::PERMISSIONS.filesystem,
+      requiresAdmin:      P::PERMISSIONS.admin,
+      requiresCaching:    P::PERMISSIONS.caching,
+      create:             Some(create::<P>),
+      destroy:            Some(destroy::<P>),
+      setConfig:          Some(set_config::<P>),
+      initialize:         Some(initialize::<P>),
+      collect:            Some(collect::<P>),
+      fields:             Some(fields::<P>),
+      fieldSchema:        Some(field_schema::<P>),
+      displayValue:       Some(display_value::<P>),
+      lastError:          Some(last_error::<P>),
+      isEnabled:          Some(is_enabled::<P>),
+      setUpdateCallback:  Some(set_update_callback::<P>),
+    })
+  }
+
+  /// Registers the plugin with the plugin manager under its
+  /// [`NAME`](DracPlugin::NAME).
+  ///
+  /// Fails with [`ErrorCode::InvalidArgument`] if a plugin with that name is
+  /// already registered.
+  pub fn register(&'static self) -> Result<()> {
+    let result = unsafe { sys::DracRegisterPlugin(&self.0) };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "PluginVTable::register"))
+    }
+  }
+}
+
+/// Builds the C vtable for a [`DracPlugin`] type and registers it with the
+/// plugin manager, returning the result of [`PluginVTable::register`].
+///
+/// ```ignore
+/// draconis::drac_plugin!(MyPlugin)?;
+/// let manager = draconis::PluginManager::new();
+/// let plugin = draconis::Plugin::new(&manager, "my_plugin")?;
+/// ```
+#[macro_export]
+macro_rules! drac_plugin {
+  ($plugin:ty) => {{
+    static VTABLE: $crate::PluginVTable = $crate::PluginVTable::new::<$plugin>();
+    VTABLE.register()
+  }};
+}
+
+/// A plugin instance and the C copies of what it last returned, which the
+/// host reads until the next call.
+struct Hosted<P> {
+  plugin:        P,
+  fields:        FieldArena,
+  field_schema:  FieldArena,
+  display_value: Option<CString>,
+  last_error:    Option<CString>,
+  update_sink:   Arc<Mutex<Option<UpdateSink>>>,
+}
+
+impl<P: DracPlugin> Hosted<P> {
+  /// Runs `call` on the plugin, converting its result (or a panic) to a C
+  /// error code and keeping the error message for `lastError`.
+  fn run(&mut self, call: impl FnOnce(&mut P) -> Result<()>) -> DracErrorCode {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| call(&mut self.plugin)))
+      .unwrap_or_else(|_| Err(DracError::new(ErrorCode::InternalError, "DracPlugin")));
+
+    match result {
+      Ok(()) => {
+        self.last_error = None;
+        DRAC_SUCCESS
+      }
+      Err(error) => {
+        let message = if error.message.is_empty() {
+          error.code.to_string()
+        } else {
+          error.message
+        };
+
+        self.last_error = Some(c_string(&message));
+        error_code_to_c(error.code)
+      }
+    }
+  }
+}
+
+unsafe fn hosted<'a, P>(state: *mut c_void) -> &'a mut Hosted<P> {
+  unsafe { &mut *state.cast::<Hosted<P>>() }
+}
+
+extern "C" fn create<P: DracPlugin>() -> *mut c_void {
+  panic::catch_unwind(|| {
+    Box::into_raw(Box::new(Hosted {
+      plugin:        P::default(),
+      fields:        FieldArena::default(),
+      field_schema:  FieldArena::default(),
+      display_value: None,
+      last_error:    None,
+      update_sink:   Arc::default(),
+    }))
+    .cast()
+  })
+  .unwrap_or(ptr::null_mut())
+}
+
+unsafe extern "C" fn destroy<P: DracPlugin>(state: *mut c_void) {
+  let hosted = unsafe { Box::from_raw(state.cast::<Hosted<P>>()) };
+  let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(hosted)));
+}
+
+unsafe extern "C" fn set_config<P: DracPlugin>(
+  state: *mut c_void,
+  toml_config: *const c_char,
+) -> DracErrorCode {
+  let toml_config = unsafe { CStr::from_ptr(toml_config) }.to_string_lossy();
+  unsafe { hosted::<P>(state) }.run(|plugin| plugin.set_config(&toml_config))
+}
+
+unsafe extern "C" fn initialize<P: DracPlugin>(state: *mut c_void) -> DracErrorCode {
+  unsafe { hosted::<P>(state) }.run(P::initialize)
+}
+
+unsafe extern "C" fn collect<P: DracPlugin>(state: *mut c_void) -> DracErrorCode {
+  unsafe { hosted::<P>(state) }.run(P::collect)
+}
+
+unsafe extern "C" fn fields<P: DracPlugin>(state: *mut c_void) -> sys::DracPluginFieldList {
+  let hosted = unsafe { hosted::<P>(state) };
+  let fields = panic::catch_unwind(AssertUnwindSafe(|| hosted.plugin.fields())).unwrap_or_default();
+
+  hosted.fields = FieldArena::default();
+  let (items, count) = hosted.fields.object(&fields);
+
+  sys::DracPluginFieldList {
+    items,
+    count,
+  }
+}
+
+unsafe extern "C" fn field_schema<P: DracPlugin>(
+  state: *mut c_void,
+) -> sys::DracPluginFieldSchemaList {
+  let hosted = unsafe { hosted::<P>(state) };
+  let schema =
+    panic::catch_unwind(AssertUnwindSafe(|| hosted.plugin.field_schema())).unwrap_or_default();
+
+  hosted.field_schema = FieldArena::default();
+  let (items, count) = hosted.field_schema.schema(&schema);
+
+  sys::DracPluginFieldSchemaList {
+    items,
+    count,
+  }
+}
+
+unsafe extern "C" fn display_value<P: DracPlugin>(state: *mut c_void) -> *const c_char {
+  let hosted = unsafe { hosted::<P>(state) };
+
+  hosted.display_value = panic::catch_unwind(AssertUnwindSafe(|| hosted.plugin.display_value()))
+    .ok()
+    .flatten()
+    .map(|value| c_string(&value));
+
+  hosted
+    .display_value
+    .as_ref()
+    .map_or(ptr::null(), |value| value.as_ptr())
+}
+
+unsafe extern "C" fn last_error<P: DracPlugin>(state: *mut c_void) -> *const c_char {
+  unsafe { hosted::<P>(state) }
+    .last_error
+    .as_ref()
+    .map_or(ptr::null(), |error| error.as_ptr())
+}
+
+unsafe extern "C" fn is_enabled<P: DracPlugin>(state: *mut c_void) -> bool {
+  let hosted = unsafe { hosted::<P>(state) };
+  panic::catch_unwind(AssertUnwindSafe(|| hosted.plugin.is_enabled())).unwrap_or(false)
+}
+
+unsafe extern "C" fn set_update_callback<P: DracPlugin>(
+  state: *mut c_void,
+  push: sys::DracPluginPushFields,
+  context: *mut c_void,
+) {
+  let hosted = unsafe { hosted::<P>(state) };
+  let sink = push.map(|push| UpdateSink {
+    push,
+    context,
+  });
+  let listening = sink.is_some();
+
+  // Waits for a push in progress, which may still be using the old sink.
+  *hosted
+    .update_sink
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner) = sink;
+
+  if listening {
+    let updater = PluginUpdater {
+      sink: Arc::clone(&hosted.update_sink),
+    };
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| hosted.plugin.set_updater(updater)));
+  }
+}
+
+/// Converts a string for C, dropping interior NULs rather than failing.
+fn c_string(value: &str) -> CString {
+  CString::new(value.replace('\0', "")).unwrap_or_default()
+}
+
+fn error_code_to_c(code: ErrorCode) -> DracErrorCode {
+  match code {
+    ErrorCode::ApiUnavailable => DRAC_ERROR_API_UNAVAILABLE,
+    ErrorCode::ConfigurationError => DRAC_ERROR_CONFIGURATION_ERROR,
+    ErrorCode::CorruptedData => DRAC_ERROR_CORRUPTED_DATA,
+    ErrorCode::InternalError => DRAC_ERROR_INTERNAL_ERROR,
+    ErrorCode::InvalidArgument => DRAC_ERROR_INVALID_ARGUMENT,
+    ErrorCode::IoError => DRAC_ERROR_IO_ERROR,
+    ErrorCode::NetworkError => DRAC_ERROR_NETWORK_ERROR,
+    ErrorCode::NotFound => DRAC_ERROR_NOT_FOUND,
+    ErrorCode::NotSupported => DRAC_ERROR_NOT_SUPPORTED,
+    ErrorCode::Other => DRAC_ERROR_OTHER,
+    ErrorCode::OutOfMemory => DRAC_ERROR_OUT_OF_MEMORY,
+    ErrorCode::ParseError => DRAC_ERROR_PARSE_ERROR,
+    ErrorCode::PermissionDenied => DRAC_ERROR_PERMISSION_DENIED,
+    ErrorCode::PermissionRequired => DRAC_ERROR_PERMISSION_REQUIRED,
+    ErrorCode::PlatformSpecific => DRAC_ERROR_PLATFORM_SPECIFIC,
+    ErrorCode::ResourceExhausted => DRAC_ERROR_RESOURCE_EXHAUSTED,
+    ErrorCode::Timeout => DRAC_ERROR_TIMEOUT,
+    ErrorCode::UnavailableFeature => DRAC_ERROR_UNAVAILABLE_FEATURE,
+    ErrorCode::Cancelled => DRAC_ERROR_CANCELLED,
+    // A failed call can't report success.
+    ErrorCode::Success => DRAC_ERROR_OTHER,
+  }
+}
+
+/// Owns the C representation of a field map or field schema.
+///
+/// Nested arrays and objects live in their own `Vec`s, whose heap buffers
+/// don't move when the outer `Vec`s grow, so the pointers handed out stay
+/// valid until the arena is dropped.
+#[derive(Default)]
+struct FieldArena {
+  strings: Vec<CString>,
+  values:  Vec<Vec<sys::DracPluginFieldValue>>,
+  objects: Vec<Vec<sys::DracPluginField>>,
+  schemas: Vec<Vec<sys::DracPluginFieldSchema>>,
+}
+
+impl FieldArena {
+  fn string(&mut self, value: &str) -> *mut c_char {
+    let string = c_string(value);
+    let ptr = string.as_ptr().cast_mut();
+    self.strings.push(string);
+    ptr
+  }
+
+  fn schema(&mut self, schema: &[PluginFieldSchema]) -> (*mut sys::DracPluginFieldSchema, usize) {
+    let items: Vec<_> = schema
+      .iter()
+      .map(|field| sys::DracPluginFieldSchema {
+        name:        self.string(&field.name),
+        type_:       field.field_type.to_c() as _,
+        unit:        field
+          .unit
+          .as_deref()
+          .map_or(ptr::null_mut(), |unit| self.string(unit)),
+        description: self.string(&field.description),
+      })
+      .collect();
+
+    self.schemas.push(items);
+    let items = self.schemas.last_mut().expect("just pushed");
+    (items.as_mut_ptr(), items.len())
+  }
+
+  fn object(
+    &mut self,
+    fields: &HashMap<String, PluginFieldValue>,
+  ) -> (*mut sys::DracPluginField, usize) {
+    let items: Vec<_> = fields
+      .iter()
+      .map(|(key, value)| sys::DracPluginField {
+        key:   self.string(key),
+        value: self.value(value),
+      })
+      .collect();
+
+    self.objects.push(items);
+    let items = self.objects.last_mut().expect("just pushed");
+    (items.as_mut_ptr(), items.len())
+  }
+
+  fn value(&mut self, value: &PluginFieldValue) -> sys::DracPluginFieldValue {
+    use sys::DracPluginFieldValue__bindgen_ty_1 as Union;
+
+    let (kind, union) = match value {
+      PluginFieldValue::Bool(value) => (
+        DRAC_PLUGIN_FIELD_BOOL,
+        Union {
+          boolValue: *value,
+        },
+      ),
+      PluginFieldValue::I64(value) => (
+        DRAC_PLUGIN_FIELD_I64,
+        Union {
+          i64Value: *value,
+        },
+      ),
+      PluginFieldValue::U64(value) => (
+        DRAC_PLUGIN_FIELD_U64,
+        Union {
+          u64Value: *value,
+        },
+      ),
+      PluginFieldValue::F64(value) => (
+        DRAC_PLUGIN_FIELD_F64,
+        Union {
+          f64Value: *value,
+        },
+      ),
+      PluginFieldValue::String(value) => (
+        DRAC_PLUGIN_FIELD_STRING,
+        Union {
+          stringValue: self.string(value),
+        },
+      ),
+      PluginFieldValue::Array(items) => {
+        let items: Vec<_> = items.iter().map(|item| self.value(item)).collect();
+        self.values.push(items);
+        let items = self.values.last_mut().expect("just pushed");
+
+        (
+          DRAC_PLUGIN_FIELD_ARRAY,
+          Union {
+            arrayValue: sys::DracPluginFieldValueArray {
+              items: items.as_mut_ptr(),
+              count: items.len(),
+            },
+          },
+        )
+      }
+      PluginFieldValue::Object(fields) => {
+        let (items, count) = self.object(fields);
+
+        (
+          DRAC_PLUGIN_FIELD_OBJECT,
+          Union {
+            objectValue: sys::DracPluginFieldValueObject {
+              items,
+              count,
+            },
+          },
+        )
+      }
+      PluginFieldValue::Timestamp(time) => {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+          Ok(since) => since.as_millis() as i64,
+          Err(before) => -(before.duration().as_millis() as i64),
+        };
+
+        (
+          DRAC_PLUGIN_FIELD_TIMESTAMP,
+          Union {
+            timestampValue: millis,
+          },
+        )
+      }
+      PluginFieldValue::Null => (
+        DRAC_PLUGIN_FIELD_NULL,
+        Union {
+          i64Value: 0,
+        },
+      ),
+    };
+
+    sys::DracPluginFieldValue {
+      type_:            kind as _,
+      __bindgen_anon_1: union,
+    }
+  }
+}
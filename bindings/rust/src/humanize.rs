@@ -0,0 +1,176 @@
+//! Human-readable formatting of byte counts and durations, and the `Display`
+//! impls built on them.
+
+use std::{fmt, time::Duration};
+
+use crate::types::{
+  Battery, BatteryDevice, BatteryStatus, CPUCores, DiskInfo, DisplayInfo, NetworkInterface, OSInfo,
+  ResourceUsage, UptimeBreakdown,
+};
+
+const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Formats a byte count with binary units, e.g. `15.6 GiB` or `512 B`.
+pub fn bytes(bytes: u64) -> String {
+  let mut value = bytes as f64;
+  let mut unit = 0;
+
+  while value >= 1024.0 && unit < UNITS.len() - 1 {
+    value /= 1024.0;
+    unit += 1;
+  }
+
+  if unit == 0 {
+    format!("{bytes} {}", UNITS[0])
+  } else {
+    format!("{value:.1} {}", UNITS[unit])
+  }
+}
+
+/// Formats a duration as its non-zero days, hours, minutes and seconds, e.g.
+/// `3d 4h` or `12m 5s`. A zero duration is `0s`.
+pub fn duration(duration: Duration) -> String {
+  UptimeBreakdown::from(duration).to_string()
+}
+
+/// Formats `part` as a whole percentage of `total`, or `0%` if `total` is 0.
+pub fn percent(part: u64, total: u64) -> String {
+  if total == 0 {
+    "0%".to_string()
+  } else {
+    format!("{:.0}%", part as f64 / total as f64 * 100.0)
+  }
+}
+
+impl fmt::Display for UptimeBreakdown {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let parts = [
+      (self.days, "d"),
+      (self.hours, "h"),
+      (self.minutes, "m"),
+      (self.seconds, "s"),
+    ];
+
+    let mut written = false;
+
+    for (value, suffix) in parts {
+      if value > 0 {
+        if written {
+          f.write_str(" ")?;
+        }
+
+        write!(f, "{value}{suffix}")?;
+        written = true;
+      }
+    }
+
+    if !written {
+      f.write_str("0s")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl fmt::Display for ResourceUsage {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} / {} ({})",
+      bytes(self.used_bytes),
+      bytes(self.total_bytes),
+      percent(self.used_bytes, self.total_bytes)
+    )
+  }
+}
+
+impl fmt::Display for DiskInfo {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} ({}): {} / {} ({})",
+      self.mount_point,
+      self.filesystem,
+      bytes(self.used_bytes),
+      bytes(self.total_bytes),
+      percent(self.used_bytes, self.total_bytes)
+    )
+  }
+}
+
+impl fmt::Display for BatteryStatus {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      BatteryStatus::Unknown => "Unknown",
+      BatteryStatus::Charging => "Charging",
+      BatteryStatus::Discharging => "Discharging",
+      BatteryStatus::Full => "Full",
+      BatteryStatus::NotPresent => "Not present",
+    })
+  }
+}
+
+impl fmt::Display for Battery {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.percentage {
+      Some(percentage) => write!(f, "{percentage}% ({})", self.status)?,
+      None => write!(f, "{}", self.status)?,
+    }
+
+    if let Some(secs) = self.time_remaining_secs.filter(|&secs| secs > 0) {
+      write!(
+        f,
+        ", {} remaining",
+        duration(Duration::from_secs(secs as u64))
+      )?;
+    }
+
+    Ok(())
+  }
+}
+
+impl fmt::Display for BatteryDevice {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.name {
+      Some(name) => write!(f, "{name}: {}", self.battery),
+      None => write!(f, "{}", self.battery),
+    }
+  }
+}
+
+impl fmt::Display for OSInfo {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.version.is_empty() {
+      f.write_str(&self.name)
+    } else {
+      write!(f, "{} {}", self.name, self.version)
+    }
+  }
+}
+
+impl fmt::Display for CPUCores {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} cores, {} threads", self.physical, self.logical)
+  }
+}
+
+impl fmt::Display for DisplayInfo {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}x{}", self.width, self.height)?;
+
+    if self.refresh_rate > 0.0 {
+      write!(f, " @ {:.0}Hz", self.refresh_rate)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl fmt::Display for NetworkInterface {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.ipv4_address.as_ref().or(self.ipv6_address.as_ref()) {
+      Some(address) => write!(f, "{} ({address})", self.name),
+      None => f.write_str(&self.name),
+    }
+  }
+}
@@ -9,12 +9,22 @@
 //!
 //! ```ignore
 //! draconis::init_static_plugins();
-//! let plugin = draconis::Plugin::new("NowPlayingPlugin").expect("Failed to load");
+//! let manager = draconis::PluginManager::new();
+//! let plugin = draconis::Plugin::new(&manager, "NowPlayingPlugin").expect("Failed to load");
 //! ```
 
+mod authoring;
+pub mod humanize;
+mod scheduler;
+#[cfg(feature = "async")]
+mod stream;
 mod sys;
 mod types;
 
+pub use authoring::*;
+pub use scheduler::*;
+#[cfg(feature = "async")]
+pub use stream::*;
 pub use types::*;
 
 /// Initialize static plugins.
@@ -30,6 +40,14 @@ pub fn init_static_plugins() -> usize {
 
 #[cfg(test)]
 mod tests {
+  // Plugins that register themselves through `drac_plugin!`, which needs a
+  // library built with the plugin system
+  #[cfg(drac_plugins)]
+  mod fixtures;
+
+  #[cfg(drac_plugins)]
+  use fixtures::*;
+
   use super::*;
 
   #[test]
@@ -38,23 +56,768 @@ mod tests {
     drop(cache);
   }
 
+  #[test]
+  fn test_cache_manager_shared_across_threads() {
+    let cache = CacheManager::new();
+
+    std::thread::scope(|scope| {
+      for _ in 0..4 {
+        scope.spawn(|| get_mem_info(&cache).expect("Failed to get memory info"));
+      }
+    });
+  }
+
+  #[test]
+  fn test_global_cache() {
+    assert!(std::ptr::eq(global(), global()));
+
+    let cpu_model = global().cpu_model().expect("Failed to get CPU model");
+    assert_eq!(cpu_model, get_cpu_model(global()).unwrap());
+  }
+
+  #[test]
+  fn test_cache_ttl() {
+    let cache = CacheManager::new();
+
+    cache
+      .set_ttl(CacheDomain::Cpu, CacheTtl::NeverExpire)
+      .expect("Failed to set TTL");
+    let cached = cache.cpu_model().expect("Failed to get CPU model");
+
+    cache
+      .set_ttl(CacheDomain::Cpu, std::time::Duration::ZERO)
+      .expect("Failed to set TTL");
+    assert_eq!(cache.cpu_model().expect("Failed to get CPU model"), cached);
+
+    cache
+      .reset_ttl(CacheDomain::Cpu)
+      .expect("Failed to reset TTL");
+  }
+
+  #[test]
+  fn test_cache_stats() {
+    let stats = CacheManager::new()
+      .stats()
+      .expect("Failed to get cache stats");
+    assert_eq!((stats.hits, stats.misses, stats.entries), (0, 0, 0));
+    assert!(stats.last_refresh_per_domain.is_empty());
+  }
+
+  #[test]
+  fn test_cache_prefetch() {
+    let cache = CacheManager::new();
+    assert!(
+      !cache
+        .is_ready(CacheDomain::Cpu)
+        .expect("Failed to check readiness")
+    );
+
+    cache
+      .prefetch(&[CacheDomain::Cpu])
+      .expect("Failed to start prefetch");
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+
+    while !cache
+      .is_ready(CacheDomain::Cpu)
+      .expect("Failed to check readiness")
+    {
+      if std::time::Instant::now() >= deadline {
+        panic!("Prefetch didn't finish in time");
+      }
+
+      std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+  }
+
+  #[test]
+  fn test_string_ref() {
+    let cache = CacheManager::new();
+
+    if let (Ok(owned), Ok(first), Ok(second)) = (
+      cache.kernel_version(),
+      cache.kernel_version_ref(),
+      cache.kernel_version_ref(),
+    ) {
+      assert_eq!(owned, first);
+      assert_eq!(first.as_ptr(), second.as_ptr());
+    }
+  }
+
+  #[test]
+  fn test_collect_fields() {
+    let cache = CacheManager::new();
+    let fields = cache
+      .collect_fields(FieldMask::MEMORY | FieldMask::CPU_CORES)
+      .expect("Failed to collect fields");
+
+    assert!(fields.memory.is_some());
+    assert!(fields.cpu_cores.is_some());
+    assert!(fields.cpu_model.is_none());
+
+    if let (Some(Ok(batched)), Ok(single)) = (fields.cpu_cores, cache.cpu_cores()) {
+      assert_eq!(
+        (batched.physical, batched.logical),
+        (single.physical, single.logical)
+      );
+    }
+  }
+
+  #[test]
+  fn test_cache_invalidate() {
+    let cache = CacheManager::new();
+    let cached = cache.cpu_model().expect("Failed to get CPU model");
+
+    cache
+      .invalidate(CacheDomain::Cpu)
+      .expect("Failed to invalidate domain");
+    assert_eq!(cache.cpu_model().expect("Failed to get CPU model"), cached);
+
+    cache.invalidate_all().expect("Failed to invalidate caches");
+    assert_eq!(cache.cpu_model().expect("Failed to get CPU model"), cached);
+  }
+
   #[test]
   fn test_uptime() {
     let uptime = get_uptime();
     assert!(uptime > 0);
   }
 
+  #[test]
+  fn test_uptime_breakdown() {
+    let up = UptimeBreakdown::from(std::time::Duration::from_secs(90_061));
+    assert_eq!(
+      up,
+      UptimeBreakdown {
+        days:    1,
+        hours:   1,
+        minutes: 1,
+        seconds: 1,
+      }
+    );
+  }
+
+  #[test]
+  fn test_boot_time_is_stable() {
+    let first = get_boot_time().expect("Failed to get boot time");
+    let second = get_boot_time().expect("Failed to get boot time");
+    assert_eq!(first, second);
+    assert!(first < std::time::SystemTime::now());
+  }
+
   #[test]
   fn test_memory_info() {
-    let mut cache = CacheManager::new();
-    let usage = get_mem_info(&mut cache).expect("Failed to get memory info");
+    let cache = CacheManager::new();
+    let usage = get_mem_info(&cache).expect("Failed to get memory info");
     assert!(usage.total_bytes > 0);
   }
 
+  #[test]
+  fn test_memory_details() {
+    let cache = CacheManager::new();
+    let details = get_memory_details(&cache).expect("Failed to get memory details");
+    assert!(details.total_bytes > 0);
+    assert!(details.available_bytes <= details.total_bytes);
+  }
+
   #[test]
   fn test_cpu_cores() {
-    let mut cache = CacheManager::new();
-    let cores = get_cpu_cores(&mut cache).expect("Failed to get CPU cores");
+    let cache = CacheManager::new();
+    let cores = get_cpu_cores(&cache).expect("Failed to get CPU cores");
     assert!(cores.logical > 0);
   }
+
+  #[test]
+  fn test_processes_include_self() {
+    let cache = CacheManager::new();
+    let processes = get_processes(&cache).expect("Failed to get processes");
+    assert!(processes.iter().any(|p| p.pid == std::process::id()));
+  }
+
+  #[test]
+  fn test_process_lookup_self() {
+    let process = get_process(std::process::id()).expect("Failed to look up own process");
+    assert_eq!(process.pid, std::process::id());
+  }
+
+  #[test]
+  fn test_current_user() {
+    let user = get_current_user().expect("Failed to get current user");
+    assert!(!user.username.is_empty());
+  }
+
+  #[test]
+  fn test_hostname() {
+    let hostname = get_hostname().expect("Failed to get hostname");
+    assert!(!hostname.is_empty());
+  }
+
+  #[test]
+  fn test_os_string_getters() {
+    let hostname = get_hostname_os().expect("Failed to get hostname");
+    assert_eq!(hostname.to_string_lossy(), get_hostname().unwrap());
+
+    if let Ok(disk) = get_system_disk(&CacheManager::new()) {
+      assert_eq!(disk.mount_path().to_string_lossy(), disk.mount_point);
+    }
+  }
+
+  #[test]
+  fn test_error_code_is_error() {
+    let error: Box<dyn std::error::Error> = Box::new(ErrorCode::PermissionRequired);
+    assert_eq!(error.to_string(), "operation requires elevated privileges");
+  }
+
+  #[test]
+  fn test_error_carries_message() {
+    let error = get_disk_health("not-a-disk").unwrap_err();
+    assert_eq!(error.source_fn, "get_disk_health");
+    assert!(!error.message.is_empty());
+  }
+
+  #[test]
+  fn test_humanize() {
+    assert_eq!(humanize::bytes(512), "512 B");
+    assert_eq!(humanize::bytes(16_750_372_454), "15.6 GiB");
+    assert_eq!(
+      humanize::duration(std::time::Duration::from_secs(273_600)),
+      "3d 4h"
+    );
+    assert_eq!(humanize::duration(std::time::Duration::ZERO), "0s");
+
+    let usage = ResourceUsage {
+      used_bytes:  1 << 30,
+      total_bytes: 4 << 30,
+    };
+    assert_eq!(usage.to_string(), "1.0 GiB / 4.0 GiB (25%)");
+  }
+
+  #[test]
+  fn test_sampler_tick() {
+    let cache = CacheManager::new();
+    let mut sampler = Sampler::new(&cache);
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let rates = sampler.tick(&cache);
+    assert!(rates.elapsed >= std::time::Duration::from_millis(100));
+    let usage = rates.cpu_usage.expect("Failed to get CPU usage");
+    assert!(usage.iter().all(|u| (0.0..=100.0).contains(u)));
+  }
+
+  #[test]
+  fn test_system_info_collect() {
+    let cache = CacheManager::new();
+    let info = SystemInfo::collect(&cache);
+    let os = info
+      .os
+      .expect("Operating system wasn't selected")
+      .expect("Failed to get operating system");
+    assert!(!os.name.is_empty());
+    assert!(
+      info
+        .memory
+        .expect("Memory wasn't selected")
+        .expect("Failed to get memory info")
+        .total_bytes
+        > 0
+    );
+  }
+
+  #[test]
+  fn test_system_info_collect_with() {
+    let cache = CacheManager::new();
+    let info = SystemInfo::collect_with(&cache, Selection::MEMORY);
+    assert!(
+      info
+        .memory
+        .expect("Memory wasn't selected")
+        .expect("Failed to get memory info")
+        .total_bytes
+        > 0
+    );
+    assert!(info.disks.is_none());
+
+    let all_but_disks = Selection::ALL - Selection::DISKS;
+    assert_eq!(all_but_disks, !Selection::DISKS);
+    assert_eq!(all_but_disks & Selection::DISKS, Selection::empty());
+    assert!(all_but_disks.contains(Selection::MEMORY | Selection::BATTERY));
+    assert_eq!(Selection::ALL.bits().count_ones(), 8);
+    assert_eq!(
+      FieldMask::ALL.bits().count_ones() as usize,
+      FieldMask::COUNT
+    );
+  }
+
+  #[test]
+  fn test_system_info_diff_unchanged() {
+    let cache = CacheManager::new();
+    let info = SystemInfo::collect(&cache);
+    let diff = info.diff(&info);
+    assert!(diff.changed_fields.is_empty());
+    assert!(diff.added_disks.is_empty() && diff.removed_disks.is_empty());
+    assert!(diff.address_changes.is_empty());
+    assert_eq!(diff.memory_growth.unwrap_or(0), 0);
+  }
+
+  #[cfg(feature = "async")]
+  #[test]
+  fn test_stream_memory() {
+    use futures_util::StreamExt;
+
+    let memory: Vec<_> = futures_executor::block_on(
+      stream_memory(std::time::Duration::from_millis(10))
+        .take(2)
+        .collect(),
+    );
+
+    assert_eq!(memory.len(), 2);
+    for usage in memory {
+      assert!(usage.expect("Failed to get memory info").total_bytes > 0);
+    }
+  }
+
+  #[cfg(feature = "json")]
+  #[test]
+  fn test_system_info_json_round_trip() {
+    let info = SystemInfo {
+      os:        Some(Ok(OSInfo {
+        name:    "Arch Linux".to_string(),
+        version: "rolling".to_string(),
+        id:      "arch".to_string(),
+      })),
+      kernel:    Some(Ok("6.9.1".to_string())),
+      cpu_model: Some(Err(ErrorCode::NotFound.into())),
+      cpu_cores: None,
+      memory:    Some(Ok(ResourceUsage {
+        used_bytes:  1024,
+        total_bytes: 4096,
+      })),
+      disks:     Some(Ok(Vec::new())),
+      displays:  Some(Err(ErrorCode::NotSupported.into())),
+      network:   Some(Ok(Vec::new())),
+      battery:   Some(Err(ErrorCode::NotFound.into())),
+    };
+
+    let json = info.to_json().expect("Failed to serialize snapshot");
+    let loaded = SystemInfo::from_json(&json).expect("Failed to load snapshot");
+    assert_eq!(
+      loaded.os.expect("Missing OS").expect("Failed OS").id,
+      "arch"
+    );
+    assert_eq!(
+      loaded
+        .memory
+        .expect("Missing memory")
+        .expect("Failed memory")
+        .total_bytes,
+      4096
+    );
+    assert!(loaded.cpu_cores.is_none());
+    assert_eq!(
+      loaded.battery.expect("Missing battery").unwrap_err(),
+      ErrorCode::NotFound
+    );
+
+    let future = json.replacen("\"schema_version\": 2", "\"schema_version\": 99", 1);
+    assert_eq!(
+      SystemInfo::from_json(&future).unwrap_err(),
+      ErrorCode::NotSupported
+    );
+
+    let malformed = SystemInfo::from_json("{").unwrap_err();
+    assert_eq!(malformed, ErrorCode::ParseError);
+    assert!(!malformed.message.is_empty());
+  }
+
+  #[test]
+  fn test_cpu_usage_per_core() {
+    let usage = get_cpu_usage_per_core().expect("Failed to get per-core CPU usage");
+    assert!(!usage.is_empty());
+    assert!(usage.iter().all(|u| (0.0..=100.0).contains(u)));
+  }
+
+  #[test]
+  fn test_now_playing_from_fields() {
+    let fields = std::collections::HashMap::from([
+      (
+        "title".to_owned(),
+        PluginFieldValue::String("Song".to_owned()),
+      ),
+      (
+        "artist".to_owned(),
+        PluginFieldValue::Array(vec![
+          PluginFieldValue::String("A".to_owned()),
+          PluginFieldValue::String("B".to_owned()),
+        ]),
+      ),
+      ("position".to_owned(), PluginFieldValue::F64(61.5)),
+      ("duration".to_owned(), PluginFieldValue::U64(180)),
+      (
+        "state".to_owned(),
+        PluginFieldValue::String("Paused".to_owned()),
+      ),
+    ]);
+
+    let now = NowPlaying::from_fields(&fields);
+    assert_eq!(now.title.as_deref(), Some("Song"));
+    assert_eq!(now.artist.as_deref(), Some("A, B"));
+    assert_eq!(now.album, None);
+    assert_eq!(now.position, Some(std::time::Duration::from_millis(61_500)));
+    assert_eq!(now.duration, Some(std::time::Duration::from_secs(180)));
+    assert_eq!(now.state, PlaybackState::Paused);
+  }
+
+  #[test]
+  fn test_weather_from_fields() {
+    let fields = std::collections::HashMap::from([
+      ("temperature".to_owned(), PluginFieldValue::F64(21.5)),
+      (
+        "description".to_owned(),
+        PluginFieldValue::String("Light rain".to_owned()),
+      ),
+      ("humidity".to_owned(), PluginFieldValue::I64(80)),
+      (
+        "fetched_at".to_owned(),
+        PluginFieldValue::U64(1_700_000_000),
+      ),
+    ]);
+
+    let weather = Weather::from_fields(&fields);
+    assert_eq!(weather.temperature, Some(21.5));
+    assert_eq!(weather.condition, WeatherCondition::Rain);
+    assert_eq!(weather.humidity, Some(80.0));
+    assert_eq!(weather.location, None);
+    assert_eq!(
+      weather.fetched_at,
+      Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000))
+    );
+  }
+
+  #[test]
+  fn test_plugin_field_value_accessors() {
+    let fetched_at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+
+    assert_eq!(PluginFieldValue::U64(42).as_i64(), Some(42));
+    assert_eq!(PluginFieldValue::U64(u64::MAX).as_i64(), None);
+    assert_eq!(PluginFieldValue::Bool(true).as_bool(), Some(true));
+    assert_eq!(
+      PluginFieldValue::Timestamp(fetched_at).as_timestamp(),
+      Some(fetched_at)
+    );
+    assert!(PluginFieldValue::Null.is_null());
+    assert!(!PluginFieldValue::String(String::new()).is_null());
+
+    let fields = std::collections::HashMap::from([(
+      "fetched_at".to_owned(),
+      PluginFieldValue::Timestamp(fetched_at),
+    )]);
+    assert_eq!(Weather::from_fields(&fields).fetched_at, Some(fetched_at));
+  }
+
+  #[cfg(drac_plugins)]
+  #[test]
+  fn test_rust_plugin() {
+    drac_plugin!(CountingPlugin).expect("Failed to register plugin");
+
+    let cache = CacheManager::new();
+    let manager = PluginManager::new();
+    let mut plugin = Plugin::new(&manager, "rust_counting_plugin").expect("Failed to load plugin");
+
+    let schema = plugin.field_schema();
+    assert_eq!(schema.len(), 1);
+    assert_eq!(schema[0].name, "collections");
+    assert_eq!(schema[0].field_type, PluginFieldType::U64);
+    assert_eq!(schema[0].unit, None);
+
+    plugin
+      .initialize(&cache)
+      .expect("Failed to initialize plugin");
+    plugin
+      .collect_data(&cache)
+      .expect("Failed to collect plugin data");
+
+    let fields = plugin.get_fields().expect("Failed to get plugin fields");
+    assert_eq!(fields.get("collections"), Some(&PluginFieldValue::U64(1)));
+    assert_eq!(plugin.config_schema().as_deref(), Some("enabled = bool"));
+
+    // A reload starts over with a fresh instance
+    plugin.reload(&cache).expect("Failed to reload plugin");
+    plugin
+      .initialize(&cache)
+      .expect("Failed to initialize plugin");
+    plugin
+      .collect_data(&cache)
+      .expect("Failed to collect plugin data");
+    let fields = plugin.get_fields().expect("Failed to get plugin fields");
+    assert_eq!(fields.get("collections"), Some(&PluginFieldValue::U64(1)));
+  }
+
+  #[test]
+  fn test_plugin_manager_owners() {
+    let cache = CacheManager::new();
+    let first = PluginManager::new();
+    let second = PluginManager::new();
+    drop(first);
+
+    // Still initialized through the second owner
+    let mut watcher = second.watch_paths(std::time::Duration::from_secs(60));
+    match watcher.poll(&cache) {
+      Err(error) if error == ErrorCode::NotSupported => {}
+      result => assert!(result.expect("Failed to reload plugins").is_empty()),
+    }
+    assert!(
+      watcher
+        .poll(&cache)
+        .expect("Throttled poll failed")
+        .is_empty()
+    );
+  }
+
+  #[cfg(drac_plugins)]
+  #[test]
+  fn test_discover_plugins() {
+    drac_plugin!(DescribedPlugin).expect("Failed to register plugin");
+
+    let manager = PluginManager::new();
+    let plugins = manager.discover().expect("Failed to discover plugins");
+    let info = plugins
+      .iter()
+      .find(|info| info.name == "rust_described_plugin")
+      .expect("Registered plugin wasn't discovered");
+
+    assert_eq!(info.plugin_type, PluginType::InfoProvider);
+    assert_eq!(info.capabilities, ["described"]);
+    assert!(info.permissions.network && info.permissions.caching);
+    assert!(!info.permissions.admin);
+    assert_eq!(info.config_schema.as_deref(), Some("units = string"));
+    assert!(info.is_static);
+  }
+
+  #[cfg(drac_plugins)]
+  #[test]
+  fn test_plugin_collect_timeout() {
+    drac_plugin!(SlowPlugin).expect("Failed to register plugin");
+
+    let cache = CacheManager::new();
+    let manager = PluginManager::new();
+    let mut plugin = Plugin::new(&manager, "rust_slow_plugin").expect("Failed to load plugin");
+    plugin
+      .initialize(&cache)
+      .expect("Failed to initialize plugin");
+
+    let error = plugin
+      .collect_data_with_timeout(&cache, std::time::Duration::from_millis(10))
+      .expect_err("Collection should time out");
+    assert_eq!(error, ErrorCode::Timeout);
+
+    // Fails rather than waiting for the abandoned collection
+    let error = plugin
+      .collect_data(&cache)
+      .expect_err("Collection should still be running");
+    assert_eq!(error, ErrorCode::Timeout);
+
+    // Picks up the collection that timed out
+    plugin
+      .collect_data_with_timeout(&cache, std::time::Duration::from_secs(5))
+      .expect("Failed to collect plugin data");
+
+    let token = CancellationToken::new();
+    token.clone().cancel();
+    assert!(token.is_cancelled());
+    let error = plugin
+      .collect_data_cancellable(&cache, None, &token)
+      .expect_err("Collection should be cancelled");
+    assert_eq!(error, ErrorCode::Cancelled);
+  }
+
+  #[cfg(drac_plugins)]
+  #[test]
+  fn test_plugin_subscribe() {
+    drac_plugin!(PushingPlugin).expect("Failed to register plugin");
+
+    let cache = CacheManager::new();
+    let manager = PluginManager::new();
+    let mut plugin = Plugin::new(&manager, "rust_pushing_plugin").expect("Failed to load plugin");
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let subscription = plugin
+      .subscribe(move |fields| {
+        let _ = sender.send(fields.get("title").cloned());
+      })
+      .expect("Failed to subscribe to plugin");
+
+    plugin
+      .initialize(&cache)
+      .expect("Failed to initialize plugin");
+    plugin
+      .collect_data(&cache)
+      .expect("Failed to collect plugin data");
+
+    let title = receiver
+      .recv_timeout(std::time::Duration::from_secs(5))
+      .expect("Plugin update wasn't delivered");
+    assert_eq!(title, Some(PluginFieldValue::String("Song".to_owned())));
+
+    drop(subscription);
+
+    // Dropping the subscription from its own callback mustn't deadlock
+    let slot = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let subscription = plugin
+      .subscribe({
+        let slot = std::sync::Arc::clone(&slot);
+        move |_| {
+          drop(slot.lock().unwrap().take());
+          let _ = sender.send(());
+        }
+      })
+      .expect("Failed to subscribe to plugin");
+    *slot.lock().unwrap() = Some(subscription);
+
+    plugin
+      .collect_data(&cache)
+      .expect("Failed to collect plugin data");
+    receiver
+      .recv_timeout(std::time::Duration::from_secs(5))
+      .expect("Plugin update wasn't delivered");
+    assert!(slot.lock().unwrap().is_none());
+  }
+
+  #[cfg(drac_plugins)]
+  #[test]
+  fn test_plugin_scheduler() {
+    drac_plugin!(TickingPlugin).expect("Failed to register plugin");
+
+    let plugin = ScheduledPlugin::new("rust_ticking_plugin", std::time::Duration::from_millis(10));
+
+    let error = PluginScheduler::start(vec![plugin.clone(), plugin.clone()])
+      .err()
+      .expect("Duplicate plugins should be rejected");
+    assert_eq!(error, ErrorCode::InvalidArgument);
+
+    let scheduler = PluginScheduler::start(vec![plugin]).expect("Failed to start scheduler");
+    let results = scheduler.results();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+
+    let snapshot = loop {
+      let snapshot = results
+        .get("rust_ticking_plugin")
+        .expect("Scheduled plugin has no results");
+
+      if matches!(snapshot.fields.get("ticks"), Some(PluginFieldValue::U64(ticks)) if *ticks >= 2) {
+        break snapshot;
+      }
+
+      assert!(
+        std::time::Instant::now() < deadline,
+        "Plugin wasn't collected again"
+      );
+      std::thread::sleep(std::time::Duration::from_millis(5));
+    };
+
+    assert!(snapshot.collected_at.is_some());
+    assert_eq!(snapshot.error, None);
+
+    drop(scheduler);
+    assert!(results.get("rust_ticking_plugin").is_some());
+  }
+
+  #[cfg(drac_plugins)]
+  #[test]
+  fn test_plugin_scheduler_timeout() {
+    drac_plugin!(StalledPlugin).expect("Failed to register plugin");
+
+    let mut plugin = ScheduledPlugin::new("rust_stalled_plugin", std::time::Duration::from_secs(1));
+    plugin.timeout = std::time::Duration::from_millis(20);
+
+    let scheduler = PluginScheduler::start(vec![plugin]).expect("Failed to start scheduler");
+    let results = scheduler.results();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+
+    while results
+      .get("rust_stalled_plugin")
+      .expect("Scheduled plugin has no results")
+      .error
+      .is_none()
+    {
+      assert!(
+        std::time::Instant::now() < deadline,
+        "Collection didn't time out"
+      );
+      std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+
+    let snapshot = results.get("rust_stalled_plugin").unwrap();
+    assert_eq!(snapshot.error.unwrap(), ErrorCode::Timeout);
+    assert_eq!(snapshot.collected_at, None);
+
+    // Doesn't wait out the stalled collection
+    let dropped_at = std::time::Instant::now();
+    drop(scheduler);
+    assert!(dropped_at.elapsed() < std::time::Duration::from_secs(5));
+  }
+
+  // The sandbox's helper process loads the plugin from its library, which a
+  // plugin registered from Rust doesn't have
+  #[cfg(drac_plugins)]
+  #[test]
+  fn test_plugin_sandbox_requires_library() {
+    drac_plugin!(SandboxedPlugin).expect("Failed to register plugin");
+
+    let manager = PluginManager::new();
+    let mut plugin = Plugin::new(&manager, "rust_sandboxed_plugin").expect("Failed to load plugin");
+
+    let error = plugin
+      .set_sandbox(Some(PluginSandbox::default()))
+      .expect_err("A registered plugin has no library to sandbox");
+    assert_eq!(error, ErrorCode::NotSupported);
+
+    plugin
+      .set_sandbox(None)
+      .expect("Failed to collect in-process");
+  }
+
+  #[cfg(feature = "config")]
+  #[test]
+  fn test_weather_config_toml() {
+    let config = WeatherConfig {
+      units: WeatherUnits::Imperial,
+      coords: Some(Coordinates {
+        lat: 40.7128,
+        lon: -74.006,
+      }),
+      ..WeatherConfig::default()
+    };
+
+    let toml_config = toml::to_string(&config).expect("Failed to serialize config");
+    assert!(toml_config.contains("provider = \"openmeteo\""));
+    assert!(toml_config.contains("units = \"imperial\""));
+    assert!(toml_config.contains("[coords]"));
+    assert!(!toml_config.contains("api_key"));
+
+    assert_eq!(toml::from_str::<WeatherConfig>(&toml_config), Ok(config));
+  }
+
+  #[test]
+  #[cfg(target_os = "linux")]
+  fn test_kernel_cmdline() {
+    let cache = CacheManager::new();
+    let cmdline = get_kernel_cmdline(&cache).expect("Failed to get kernel command line");
+    assert!(!cmdline.is_empty());
+  }
+
+  #[test]
+  #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+  fn test_watcher() {
+    let mut watcher = Watcher::new().expect("Failed to create system watcher");
+    watcher
+      .wait(std::time::Duration::from_millis(10))
+      .expect("Failed to wait for system events");
+  }
+
+  #[test]
+  #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+  fn test_on_system_event() {
+    let subscription = on_network_change(|_| {}).expect("Failed to subscribe to system events");
+    drop(subscription);
+  }
 }
@@ -0,0 +1,313 @@
+//! Collecting plugins on a schedule from a background thread.
+//!
+//! ```ignore
+//! let scheduler = PluginScheduler::start(vec![
+//!   ScheduledPlugin::new("weather", Duration::from_secs(600)),
+//!   ScheduledPlugin::new("now_playing", Duration::from_secs(2)),
+//! ])?;
+//!
+//! let results = scheduler.results();
+//! std::thread::spawn(move || {
+//!   if let Some(snapshot) = results.get("now_playing") {
+//!     println!("{:?}", NowPlaying::from_fields(&snapshot.fields));
+//!   }
+//! });
+//! ```
+
+use std::{
+  collections::{HashMap, HashSet},
+  sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError, mpsc},
+  thread::JoinHandle,
+  time::{Duration, Instant, SystemTime},
+};
+
+use crate::types::{
+  CancellationToken, DracError, ErrorCode, Plugin, PluginFieldValue, PluginManager, Result, global,
+};
+
+/// A plugin for [`PluginScheduler`] to load and collect.
+#[derive(Debug, Clone)]
+pub struct ScheduledPlugin {
+  /// Name passed to [`Plugin::new`], which also keys its results.
+  pub name:     String,
+  /// Time between the starts of consecutive collections.
+  pub interval: Duration,
+  /// How long to wait for a collection before reporting it as
+  /// [`ErrorCode::Timeout`]. The collection keeps running, and the next one
+  /// that falls due waits for it instead of starting another. Defaults to the
+  /// interval.
+  pub timeout:  Duration,
+  /// TOML passed to [`Plugin::set_config`] before the plugin is initialized.
+  pub config:   Option<String>,
+}
+
+impl ScheduledPlugin {
+  pub fn new(name: impl Into<String>, interval: Duration) -> Self {
+    Self {
+      name: name.into(),
+      interval,
+      timeout: interval,
+      config: None,
+    }
+  }
+}
+
+/// The outcome of a scheduled plugin's collections so far.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginSnapshot {
+  /// Fields from the last successful collection; empty until the first one.
+  pub fields:       HashMap<String, PluginFieldValue>,
+  /// When the last successful collection finished.
+  pub collected_at: Option<SystemTime>,
+  /// Why the latest collection failed, or `None` if it succeeded.
+  pub error:        Option<DracError>,
+}
+
+/// The latest [`PluginSnapshot`] of each plugin a [`PluginScheduler`]
+/// collects. Cheap to clone, and can be read from any thread.
+#[derive(Debug, Clone, Default)]
+pub struct PluginResults {
+  snapshots: Arc<Mutex<HashMap<String, PluginSnapshot>>>,
+}
+
+impl PluginResults {
+  /// The named plugin's snapshot, or `None` if it isn't scheduled.
+  pub fn get(&self, name: &str) -> Option<PluginSnapshot> {
+    self.lock().get(name).cloned()
+  }
+
+  /// Every scheduled plugin's snapshot, keyed by name.
+  pub fn all(&self) -> HashMap<String, PluginSnapshot> {
+    self.lock().clone()
+  }
+
+  fn lock(&self) -> MutexGuard<'_, HashMap<String, PluginSnapshot>> {
+    self
+      .snapshots
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+#[derive(Default)]
+struct StopSignal {
+  stopped: Mutex<bool>,
+  condvar: Condvar,
+  /// Cancelled on stop, so a collection in progress is abandoned.
+  token:   CancellationToken,
+}
+
+impl StopSignal {
+  fn stop(&self) {
+    *self.stopped.lock().unwrap_or_else(PoisonError::into_inner) = true;
+    self.condvar.notify_all();
+    self.token.cancel();
+  }
+
+  /// Sleeps until `deadline`, returning early with `true` once stopped.
+  fn wait_until(&self, deadline: Instant) -> bool {
+    let mut stopped = self.stopped.lock().unwrap_or_else(PoisonError::into_inner);
+
+    while !*stopped {
+      let now = Instant::now();
+
+      if now >= deadline {
+        return false;
+      }
+
+      stopped = self
+        .condvar
+        .wait_timeout(stopped, deadline - now)
+        .unwrap_or_else(PoisonError::into_inner)
+        .0;
+    }
+
+    true
+  }
+}
+
+/// Collects a set of plugins on their own intervals from a background thread,
+/// publishing the results through [`PluginResults`].
+///
+/// Each plugin is collected as soon as it's loaded and then once every
+/// interval. Intervals that were missed are skipped rather than collected in
+/// a burst. Plugins are collected one at a time, so a slow collection delays
+/// any others that fall due meanwhile, up to its
+/// [timeout](ScheduledPlugin::timeout).
+///
+/// A plugin loaded from a search path is a single instance per name, shared
+/// with every other [`Plugin`] loaded under that name. Don't configure or
+/// collect a scheduled plugin through another handle while the scheduler
+/// runs; read its results from [`PluginResults`] instead.
+///
+/// Dropping the scheduler stops the thread without waiting for a collection
+/// in progress, and unloads the plugins. A plugin whose collection was
+/// abandoned is unloaded once that collection returns.
+pub struct PluginScheduler {
+  results: PluginResults,
+  stop:    Arc<StopSignal>,
+  thread:  Option<JoinHandle<()>>,
+}
+
+impl PluginScheduler {
+  /// Starts a thread that loads, configures and initializes `plugins`, then
+  /// collects them with the [global](crate::global) cache.
+  ///
+  /// Fails with the first error from loading, configuring or initializing a
+  /// plugin, or with [`ErrorCode::InvalidArgument`] if an interval or timeout
+  /// is zero or a name is scheduled twice.
+  pub fn start(plugins: Vec<ScheduledPlugin>) -> Result<Self> {
+    let mut names = HashSet::new();
+
+    if plugins.iter().any(|plugin| {
+      plugin.interval.is_zero() || plugin.timeout.is_zero() || !names.insert(plugin.name.as_str())
+    }) {
+      return Err(DracError::new(
+        ErrorCode::InvalidArgument,
+        "PluginScheduler::start",
+      ));
+    }
+
+    let results = PluginResults::default();
+    let stop = Arc::new(StopSignal::default());
+    let (ready, started) = mpsc::channel();
+
+    let thread = std::thread::spawn({
+      let results = results.clone();
+      let stop = Arc::clone(&stop);
+      move || run(plugins, &results, &stop, &ready)
+    });
+
+    match started.recv() {
+      Ok(Ok(())) => Ok(Self {
+        results,
+        stop,
+        thread: Some(thread),
+      }),
+      Ok(Err(error)) => {
+        let _ = thread.join();
+        Err(error)
+      }
+      // The thread panicked before it finished loading
+      Err(_) => {
+        let _ = thread.join();
+        Err(DracError::new(
+          ErrorCode::InternalError,
+          "PluginScheduler::start",
+        ))
+      }
+    }
+  }
+
+  /// A handle to the latest results, which stays readable after the
+  /// scheduler is dropped.
+  pub fn results(&self) -> PluginResults {
+    self.results.clone()
+  }
+}
+
+impl Drop for PluginScheduler {
+  fn drop(&mut self) {
+    self.stop.stop();
+
+    if let Some(thread) = self.thread.take() {
+      let _ = thread.join();
+    }
+  }
+}
+
+/// A loaded plugin and when it's next due.
+struct Scheduled<'m> {
+  plugin:   Plugin<'m>,
+  name:     String,
+  interval: Duration,
+  timeout:  Duration,
+  next:     Instant,
+}
+
+impl<'m> Scheduled<'m> {
+  fn load(manager: &'m PluginManager, scheduled: ScheduledPlugin) -> Result<Self> {
+    let mut plugin = Plugin::new(manager, &scheduled.name)?;
+
+    if let Some(config) = &scheduled.config {
+      plugin.set_config(config)?;
+    }
+
+    plugin.initialize(global())?;
+
+    Ok(Self {
+      plugin,
+      name: scheduled.name,
+      interval: scheduled.interval,
+      timeout: scheduled.timeout,
+      next: Instant::now(),
+    })
+  }
+
+  fn collect(&mut self, results: &PluginResults, token: &CancellationToken) {
+    let outcome = self
+      .plugin
+      .collect_data_cancellable(global(), Some(self.timeout), token)
+      .and_then(|()| self.plugin.get_fields());
+
+    // Stopping isn't a failure of the plugin's
+    if matches!(&outcome, Err(error) if *error == ErrorCode::Cancelled) {
+      return;
+    }
+
+    let mut snapshots = results.lock();
+    let snapshot = snapshots.entry(self.name.clone()).or_default();
+
+    match outcome {
+      Ok(fields) => {
+        snapshot.fields = fields;
+        snapshot.collected_at = Some(SystemTime::now());
+        snapshot.error = None;
+      }
+      Err(error) => snapshot.error = Some(error),
+    }
+  }
+}
+
+fn run(
+  plugins: Vec<ScheduledPlugin>,
+  results: &PluginResults,
+  stop: &StopSignal,
+  ready: &mpsc::Sender<Result<()>>,
+) {
+  let manager = PluginManager::new();
+
+  let loaded: Result<Vec<_>> = plugins
+    .into_iter()
+    .map(|scheduled| Scheduled::load(&manager, scheduled))
+    .collect();
+
+  let mut scheduled = match loaded {
+    Ok(scheduled) => scheduled,
+    Err(error) => {
+      let _ = ready.send(Err(error));
+      return;
+    }
+  };
+
+  results.lock().extend(
+    scheduled
+      .iter()
+      .map(|plugin| (plugin.name.clone(), PluginSnapshot::default())),
+  );
+
+  let _ = ready.send(Ok(()));
+
+  while let Some(due) = scheduled.iter().map(|plugin| plugin.next).min() {
+    if stop.wait_until(due) {
+      return;
+    }
+
+    for plugin in &mut scheduled {
+      if plugin.next <= Instant::now() {
+        plugin.collect(results, &stop.token);
+        plugin.next = (plugin.next + plugin.interval).max(Instant::now());
+      }
+    }
+  }
+}
@@ -0,0 +1,120 @@
+//! Periodic metric streams, available with the `async` feature.
+//!
+//! Each stream polls on a fixed schedule using a runtime-agnostic timer, so it
+//! can be driven by any executor. The underlying reads are short, non-blocking
+//! file and syscall reads, so they run inline on the polling task.
+
+use std::time::{Duration, Instant};
+
+use futures_core::Stream;
+use futures_timer::Delay;
+use futures_util::stream;
+
+use crate::types::{
+  Battery, CpuSampler, Rates, ResourceUsage, Result, Sampler, get_battery_info, get_mem_info,
+  global,
+};
+
+/// Fires every `interval`, skipping ticks that were missed rather than
+/// bursting to catch up.
+struct Ticker {
+  interval: Duration,
+  next:     Instant,
+}
+
+impl Ticker {
+  fn new(interval: Duration, immediate: bool) -> Self {
+    let now = Instant::now();
+
+    Self {
+      interval,
+      next: if immediate { now } else { now + interval },
+    }
+  }
+
+  async fn tick(&mut self) {
+    let now = Instant::now();
+
+    if self.next > now {
+      Delay::new(self.next - now).await;
+    }
+
+    self.next = (self.next + self.interval).max(Instant::now());
+  }
+}
+
+/// Yields `poll()` immediately and then once every `interval`.
+fn polled<T>(interval: Duration, poll: impl FnMut() -> Result<T>) -> impl Stream<Item = Result<T>> {
+  stream::unfold(
+    (Ticker::new(interval, true), poll),
+    |(mut ticker, mut poll)| async move {
+      ticker.tick().await;
+      let item = poll();
+      Some((item, (ticker, poll)))
+    },
+  )
+}
+
+/// Takes a baseline with `init` and yields `poll(&mut state)` once every
+/// `interval` after it. If `init` fails, its error is the only item.
+fn sampled<S, T>(
+  interval: Duration,
+  init: impl FnOnce() -> Result<S>,
+  poll: impl FnMut(&mut S) -> Result<T>,
+) -> impl Stream<Item = Result<T>> {
+  let state = match init() {
+    Ok(state) => Ok((Ticker::new(interval, false), state, poll)),
+    Err(error) => Err(error),
+  };
+
+  stream::unfold(Some(state), |state| async move {
+    match state? {
+      Ok((mut ticker, mut state, mut poll)) => {
+        ticker.tick().await;
+        let item = poll(&mut state);
+        Some((item, Some(Ok((ticker, state, poll)))))
+      }
+      Err(error) => Some((Err(error), None)),
+    }
+  })
+}
+
+/// Streams per-core CPU usage, as returned by [`CpuSampler::poll`], once
+/// every `interval`.
+///
+/// The first item arrives after one interval, since usage is measured between
+/// two samples.
+///
+/// ```ignore
+/// let mut usage = std::pin::pin!(draconis::stream_cpu_usage(Duration::from_secs(1)));
+/// while let Some(per_core) = usage.next().await {
+///   println!("{:?}", per_core?);
+/// }
+/// ```
+pub fn stream_cpu_usage(interval: Duration) -> impl Stream<Item = Result<Vec<f64>>> {
+  sampled(interval, CpuSampler::new, CpuSampler::poll)
+}
+
+/// Streams RAM usage immediately and then once every `interval`.
+pub fn stream_memory(interval: Duration) -> impl Stream<Item = Result<ResourceUsage>> {
+  polled(interval, || get_mem_info(global()))
+}
+
+/// Streams the primary battery's state immediately and then once every
+/// `interval`.
+pub fn stream_battery(interval: Duration) -> impl Stream<Item = Result<Battery>> {
+  polled(interval, || get_battery_info(global()))
+}
+
+/// Streams CPU, network, and disk rates, as returned by [`Sampler::tick`],
+/// once every `interval`.
+///
+/// The first item arrives after one interval, covering the time since the
+/// stream was created.
+pub fn stream_rates(interval: Duration) -> impl Stream<Item = Result<Rates>> {
+  sampled(
+    interval,
+    || Ok(Sampler::new(global())),
+    |sampler| Ok(sampler.tick(global())),
+  )
+}
@@ -0,0 +1,180 @@
+//! Plugins registered by the plugin tests.
+
+use std::{collections::HashMap, ffi::CStr};
+
+use crate::*;
+
+#[derive(Default)]
+pub(super) struct CountingPlugin {
+  collections: u64,
+}
+
+impl DracPlugin for CountingPlugin {
+  const NAME: &'static CStr = c"rust_counting_plugin";
+  const CONFIG_SCHEMA: Option<&'static CStr> = Some(c"enabled = bool");
+
+  fn initialize(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn collect(&mut self) -> Result<()> {
+    self.collections += 1;
+    Ok(())
+  }
+
+  fn fields(&self) -> HashMap<String, PluginFieldValue> {
+    HashMap::from([(
+      "collections".to_owned(),
+      PluginFieldValue::U64(self.collections),
+    )])
+  }
+
+  fn field_schema(&self) -> Vec<PluginFieldSchema> {
+    vec![PluginFieldSchema {
+      name:        "collections".to_owned(),
+      field_type:  PluginFieldType::U64,
+      unit:        None,
+      description: "Times the plugin has collected".to_owned(),
+    }]
+  }
+}
+
+#[derive(Default)]
+pub(super) struct DescribedPlugin;
+
+impl DracPlugin for DescribedPlugin {
+  const NAME: &'static CStr = c"rust_described_plugin";
+  const PROVIDER_ID: &'static CStr = c"described";
+  const CONFIG_SCHEMA: Option<&'static CStr> = Some(c"units = string");
+  const PERMISSIONS: PluginPermissions = PluginPermissions {
+    network:    true,
+    filesystem: false,
+    admin:      false,
+    caching:    true,
+  };
+
+  fn initialize(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn collect(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn fields(&self) -> HashMap<String, PluginFieldValue> {
+    HashMap::new()
+  }
+}
+
+#[derive(Default)]
+pub(super) struct SlowPlugin;
+
+impl DracPlugin for SlowPlugin {
+  const NAME: &'static CStr = c"rust_slow_plugin";
+
+  fn initialize(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn collect(&mut self) -> Result<()> {
+    std::thread::sleep(std::time::Duration::from_millis(300));
+    Ok(())
+  }
+
+  fn fields(&self) -> HashMap<String, PluginFieldValue> {
+    HashMap::new()
+  }
+}
+
+#[derive(Default)]
+pub(super) struct StalledPlugin;
+
+impl DracPlugin for StalledPlugin {
+  const NAME: &'static CStr = c"rust_stalled_plugin";
+
+  fn initialize(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn collect(&mut self) -> Result<()> {
+    std::thread::sleep(std::time::Duration::from_secs(60));
+    Ok(())
+  }
+
+  fn fields(&self) -> HashMap<String, PluginFieldValue> {
+    HashMap::new()
+  }
+}
+
+#[derive(Default)]
+pub(super) struct PushingPlugin {
+  updater: Option<PluginUpdater>,
+}
+
+impl DracPlugin for PushingPlugin {
+  const NAME: &'static CStr = c"rust_pushing_plugin";
+
+  fn initialize(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn collect(&mut self) -> Result<()> {
+    if let Some(updater) = &self.updater {
+      updater.push(&self.fields());
+    }
+
+    Ok(())
+  }
+
+  fn fields(&self) -> HashMap<String, PluginFieldValue> {
+    HashMap::from([(
+      "title".to_owned(),
+      PluginFieldValue::String("Song".to_owned()),
+    )])
+  }
+
+  fn set_updater(&mut self, updater: PluginUpdater) {
+    self.updater = Some(updater);
+  }
+}
+
+#[derive(Default)]
+pub(super) struct TickingPlugin {
+  ticks: u64,
+}
+
+impl DracPlugin for TickingPlugin {
+  const NAME: &'static CStr = c"rust_ticking_plugin";
+
+  fn initialize(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn collect(&mut self) -> Result<()> {
+    self.ticks += 1;
+    Ok(())
+  }
+
+  fn fields(&self) -> HashMap<String, PluginFieldValue> {
+    HashMap::from([("ticks".to_owned(), PluginFieldValue::U64(self.ticks))])
+  }
+}
+
+#[derive(Default)]
+pub(super) struct SandboxedPlugin;
+
+impl DracPlugin for SandboxedPlugin {
+  const NAME: &'static CStr = c"rust_sandboxed_plugin";
+
+  fn initialize(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn collect(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn fields(&self) -> HashMap<String, PluginFieldValue> {
+    HashMap::new()
+  }
+}
@@ -1,11 +1,47 @@
 //! High-level Rust types wrapping the C API
 
-use std::ffi::CStr;
+use std::{
+  borrow::Cow,
+  ffi::{CStr, OsStr, OsString},
+  path::Path,
+};
 
 use crate::sys;
 
 pub type DracErrorCode = i32;
 pub type DracBatteryStatus = i32;
+pub type DracProcessState = i32;
+pub type DracInitSystemKind = i32;
+pub type DracServiceState = i32;
+pub type DracContainerRuntime = i32;
+pub type DracContainerState = i32;
+pub type DracSensorKind = i32;
+pub type DracPowerMode = i32;
+pub type DracCPUCacheType = i32;
+pub type DracCPUVulnerabilityState = i32;
+pub type DracSessionType = i32;
+pub type DracDisplayServer = i32;
+pub type DracDisplayConnector = i32;
+pub type DracDisplayGamut = i32;
+pub type DracAudioDirection = i32;
+pub type DracWifiBand = i32;
+pub type DracPrinterState = i32;
+pub type DracDuplex = i32;
+pub type DracDiskHealthStatus = i32;
+pub type DracNetworkMountProtocol = i32;
+pub type DracNetworkMountState = i32;
+pub type DracHypervisor = i32;
+pub type DracContainer = i32;
+pub type DracCloudProvider = i32;
+pub type DracSecureBootState = i32;
+pub type DracMacFramework = i32;
+pub type DracMacMode = i32;
+pub type DracFirewallBackend = i32;
+pub type DracDomainMembership = i32;
+pub type DracSystemEventKind = i32;
+pub type DracCacheDomain = i32;
+pub type DracStringField = i32;
+pub type DracField = i32;
 
 pub const DRAC_SUCCESS: DracErrorCode = 255;
 pub const DRAC_ERROR_API_UNAVAILABLE: DracErrorCode = 0;
@@ -26,21 +62,27 @@ pub const DRAC_ERROR_PLATFORM_SPECIFIC: DracErrorCode = 14;
 pub const DRAC_ERROR_RESOURCE_EXHAUSTED: DracErrorCode = 15;
 pub const DRAC_ERROR_TIMEOUT: DracErrorCode = 16;
 pub const DRAC_ERROR_UNAVAILABLE_FEATURE: DracErrorCode = 17;
+pub const DRAC_ERROR_CANCELLED: DracErrorCode = 18;
 
 pub const DRAC_BATTERY_UNKNOWN: DracBatteryStatus = 0;
 pub const DRAC_BATTERY_CHARGING: DracBatteryStatus = 1;
 pub const DRAC_BATTERY_DISCHARGING: DracBatteryStatus = 2;
 pub const DRAC_BATTERY_FULL: DracBatteryStatus = 3;
 
-const DRAC_PLUGIN_FIELD_BOOL: u32 = 0;
-const DRAC_PLUGIN_FIELD_I64: u32 = 1;
-const DRAC_PLUGIN_FIELD_U64: u32 = 2;
-const DRAC_PLUGIN_FIELD_F64: u32 = 3;
-const DRAC_PLUGIN_FIELD_STRING: u32 = 4;
-const DRAC_PLUGIN_FIELD_ARRAY: u32 = 5;
-const DRAC_PLUGIN_FIELD_OBJECT: u32 = 6;
+pub(crate) const DRAC_PLUGIN_FIELD_BOOL: u32 = 0;
+pub(crate) const DRAC_PLUGIN_FIELD_I64: u32 = 1;
+pub(crate) const DRAC_PLUGIN_FIELD_U64: u32 = 2;
+pub(crate) const DRAC_PLUGIN_FIELD_F64: u32 = 3;
+pub(crate) const DRAC_PLUGIN_FIELD_STRING: u32 = 4;
+pub(crate) const DRAC_PLUGIN_FIELD_ARRAY: u32 = 5;
+pub(crate) const DRAC_PLUGIN_FIELD_OBJECT: u32 = 6;
+pub(crate) const DRAC_PLUGIN_FIELD_TIMESTAMP: u32 = 7;
+pub(crate) const DRAC_PLUGIN_FIELD_NULL: u32 = 8;
+
+pub(crate) const DRAC_PLUGIN_TYPE_OUTPUT_FORMAT: u32 = 1;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PluginFieldValue {
   Bool(bool),
   I64(i64),
@@ -49,30 +91,365 @@ pub enum PluginFieldValue {
   String(String),
   Array(Vec<PluginFieldValue>),
   Object(std::collections::HashMap<String, PluginFieldValue>),
+  /// A point in time, carried with millisecond precision.
+  Timestamp(std::time::SystemTime),
+  Null,
 }
-pub const DRAC_BATTERY_NOT_PRESENT: DracBatteryStatus = 4;
 
+/// The kind of value a plugin field holds, matching the variants of
+/// [`PluginFieldValue`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PluginFieldType {
+  Bool,
+  I64,
+  U64,
+  F64,
+  String,
+  Array,
+  Object,
+  Timestamp,
+  Null,
+}
+
+impl PluginFieldType {
+  pub(crate) fn from_c(kind: u32) -> Self {
+    match kind {
+      DRAC_PLUGIN_FIELD_BOOL => PluginFieldType::Bool,
+      DRAC_PLUGIN_FIELD_I64 => PluginFieldType::I64,
+      DRAC_PLUGIN_FIELD_U64 => PluginFieldType::U64,
+      DRAC_PLUGIN_FIELD_F64 => PluginFieldType::F64,
+      DRAC_PLUGIN_FIELD_STRING => PluginFieldType::String,
+      DRAC_PLUGIN_FIELD_ARRAY => PluginFieldType::Array,
+      DRAC_PLUGIN_FIELD_OBJECT => PluginFieldType::Object,
+      DRAC_PLUGIN_FIELD_TIMESTAMP => PluginFieldType::Timestamp,
+      _ => PluginFieldType::Null,
+    }
+  }
+
+  pub(crate) fn to_c(self) -> u32 {
+    match self {
+      PluginFieldType::Bool => DRAC_PLUGIN_FIELD_BOOL,
+      PluginFieldType::I64 => DRAC_PLUGIN_FIELD_I64,
+      PluginFieldType::U64 => DRAC_PLUGIN_FIELD_U64,
+      PluginFieldType::F64 => DRAC_PLUGIN_FIELD_F64,
+      PluginFieldType::String => DRAC_PLUGIN_FIELD_STRING,
+      PluginFieldType::Array => DRAC_PLUGIN_FIELD_ARRAY,
+      PluginFieldType::Object => DRAC_PLUGIN_FIELD_OBJECT,
+      PluginFieldType::Timestamp => DRAC_PLUGIN_FIELD_TIMESTAMP,
+      PluginFieldType::Null => DRAC_PLUGIN_FIELD_NULL,
+    }
+  }
+}
+
+/// Describes a field a plugin emits, as returned by
+/// [`Plugin::field_schema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PluginFieldSchema {
+  pub name:        String,
+  pub field_type:  PluginFieldType,
+  /// e.g. `°C` or `bytes`; `None` if the value has no unit.
+  pub unit:        Option<String>,
+  pub description: String,
+}
+
+impl PluginFieldValue {
+  /// The value as a string slice, if it is a string.
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      PluginFieldValue::String(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  /// The value as a float, if it is any numeric type.
+  pub fn as_f64(&self) -> Option<f64> {
+    match *self {
+      PluginFieldValue::I64(value) => Some(value as f64),
+      PluginFieldValue::U64(value) => Some(value as f64),
+      PluginFieldValue::F64(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  /// The value as a signed integer, if it is an integer that fits.
+  pub fn as_i64(&self) -> Option<i64> {
+    match *self {
+      PluginFieldValue::I64(value) => Some(value),
+      PluginFieldValue::U64(value) => i64::try_from(value).ok(),
+      _ => None,
+    }
+  }
+
+  /// The value as a bool, if it is one.
+  pub fn as_bool(&self) -> Option<bool> {
+    match *self {
+      PluginFieldValue::Bool(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  /// The value as a point in time, if it is a timestamp.
+  pub fn as_timestamp(&self) -> Option<std::time::SystemTime> {
+    match *self {
+      PluginFieldValue::Timestamp(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  pub fn is_null(&self) -> bool {
+    matches!(self, PluginFieldValue::Null)
+  }
+}
+pub const DRAC_BATTERY_NOT_PRESENT: DracBatteryStatus = 4;
+
+pub const DRAC_PROCESS_UNKNOWN: DracProcessState = 0;
+pub const DRAC_PROCESS_RUNNING: DracProcessState = 1;
+pub const DRAC_PROCESS_SLEEPING: DracProcessState = 2;
+pub const DRAC_PROCESS_DISK_SLEEP: DracProcessState = 3;
+pub const DRAC_PROCESS_STOPPED: DracProcessState = 4;
+pub const DRAC_PROCESS_ZOMBIE: DracProcessState = 5;
+pub const DRAC_PROCESS_IDLE: DracProcessState = 6;
+
+pub const DRAC_INIT_SYSTEM_UNKNOWN: DracInitSystemKind = 0;
+pub const DRAC_INIT_SYSTEM_SYSTEMD: DracInitSystemKind = 1;
+pub const DRAC_INIT_SYSTEM_OPENRC: DracInitSystemKind = 2;
+pub const DRAC_INIT_SYSTEM_RUNIT: DracInitSystemKind = 3;
+pub const DRAC_INIT_SYSTEM_S6: DracInitSystemKind = 4;
+pub const DRAC_INIT_SYSTEM_DINIT: DracInitSystemKind = 5;
+pub const DRAC_INIT_SYSTEM_SYSVINIT: DracInitSystemKind = 6;
+pub const DRAC_INIT_SYSTEM_LAUNCHD: DracInitSystemKind = 7;
+pub const DRAC_INIT_SYSTEM_SERVICE_CONTROL_MANAGER: DracInitSystemKind = 8;
+
+pub const DRAC_SERVICE_STATE_UNKNOWN: DracServiceState = 0;
+pub const DRAC_SERVICE_STATE_RUNNING: DracServiceState = 1;
+pub const DRAC_SERVICE_STATE_STOPPED: DracServiceState = 2;
+pub const DRAC_SERVICE_STATE_STARTING: DracServiceState = 3;
+pub const DRAC_SERVICE_STATE_STOPPING: DracServiceState = 4;
+pub const DRAC_SERVICE_STATE_FAILED: DracServiceState = 5;
+
+pub const DRAC_CONTAINER_RUNTIME_DOCKER: DracContainerRuntime = 0;
+pub const DRAC_CONTAINER_RUNTIME_PODMAN: DracContainerRuntime = 1;
+pub const DRAC_CONTAINER_RUNTIME_LXC: DracContainerRuntime = 2;
+
+pub const DRAC_CONTAINER_STATE_UNKNOWN: DracContainerState = 0;
+pub const DRAC_CONTAINER_STATE_RUNNING: DracContainerState = 1;
+pub const DRAC_CONTAINER_STATE_PAUSED: DracContainerState = 2;
+pub const DRAC_CONTAINER_STATE_RESTARTING: DracContainerState = 3;
+
+pub const DRAC_SENSOR_TEMPERATURE: DracSensorKind = 0;
+pub const DRAC_SENSOR_VOLTAGE: DracSensorKind = 1;
+pub const DRAC_SENSOR_CURRENT: DracSensorKind = 2;
+pub const DRAC_SENSOR_POWER: DracSensorKind = 3;
+pub const DRAC_SENSOR_FAN: DracSensorKind = 4;
+
+pub const DRAC_POWER_MODE_UNKNOWN: DracPowerMode = 0;
+pub const DRAC_POWER_MODE_POWER_SAVER: DracPowerMode = 1;
+pub const DRAC_POWER_MODE_BALANCED: DracPowerMode = 2;
+pub const DRAC_POWER_MODE_PERFORMANCE: DracPowerMode = 3;
+
+pub const DRAC_CPU_CACHE_DATA: DracCPUCacheType = 0;
+pub const DRAC_CPU_CACHE_INSTRUCTION: DracCPUCacheType = 1;
+pub const DRAC_CPU_CACHE_UNIFIED: DracCPUCacheType = 2;
+
+pub const DRAC_CPU_VULNERABILITY_UNKNOWN: DracCPUVulnerabilityState = 0;
+pub const DRAC_CPU_VULNERABILITY_NOT_AFFECTED: DracCPUVulnerabilityState = 1;
+pub const DRAC_CPU_VULNERABILITY_MITIGATED: DracCPUVulnerabilityState = 2;
+pub const DRAC_CPU_VULNERABILITY_VULNERABLE: DracCPUVulnerabilityState = 3;
+
+pub const DRAC_SESSION_UNKNOWN: DracSessionType = 0;
+pub const DRAC_SESSION_GRAPHICAL: DracSessionType = 1;
+pub const DRAC_SESSION_TERMINAL: DracSessionType = 2;
+pub const DRAC_SESSION_REMOTE: DracSessionType = 3;
+
+pub const DRAC_DISPLAY_SERVER_UNKNOWN: DracDisplayServer = 0;
+pub const DRAC_DISPLAY_SERVER_WAYLAND: DracDisplayServer = 1;
+pub const DRAC_DISPLAY_SERVER_X11: DracDisplayServer = 2;
+pub const DRAC_DISPLAY_SERVER_WINDOWS: DracDisplayServer = 3;
+pub const DRAC_DISPLAY_SERVER_QUARTZ: DracDisplayServer = 4;
+pub const DRAC_DISPLAY_SERVER_TTY: DracDisplayServer = 5;
+
+pub const DRAC_DISPLAY_CONNECTOR_UNKNOWN: DracDisplayConnector = 0;
+pub const DRAC_DISPLAY_CONNECTOR_INTERNAL: DracDisplayConnector = 1;
+pub const DRAC_DISPLAY_CONNECTOR_EDP: DracDisplayConnector = 2;
+pub const DRAC_DISPLAY_CONNECTOR_LVDS: DracDisplayConnector = 3;
+pub const DRAC_DISPLAY_CONNECTOR_DSI: DracDisplayConnector = 4;
+pub const DRAC_DISPLAY_CONNECTOR_HDMI: DracDisplayConnector = 5;
+pub const DRAC_DISPLAY_CONNECTOR_DISPLAY_PORT: DracDisplayConnector = 6;
+pub const DRAC_DISPLAY_CONNECTOR_DVI: DracDisplayConnector = 7;
+pub const DRAC_DISPLAY_CONNECTOR_VGA: DracDisplayConnector = 8;
+pub const DRAC_DISPLAY_CONNECTOR_VIRTUAL: DracDisplayConnector = 9;
+
+pub const DRAC_DISPLAY_GAMUT_UNKNOWN: DracDisplayGamut = 0;
+pub const DRAC_DISPLAY_GAMUT_SRGB: DracDisplayGamut = 1;
+pub const DRAC_DISPLAY_GAMUT_P3: DracDisplayGamut = 2;
+pub const DRAC_DISPLAY_GAMUT_BT2020: DracDisplayGamut = 3;
+
+pub const DRAC_AUDIO_OUTPUT: DracAudioDirection = 0;
+pub const DRAC_AUDIO_INPUT: DracAudioDirection = 1;
+
+pub const DRAC_WIFI_BAND_UNKNOWN: DracWifiBand = 0;
+pub const DRAC_WIFI_BAND_2_4GHZ: DracWifiBand = 1;
+pub const DRAC_WIFI_BAND_5GHZ: DracWifiBand = 2;
+pub const DRAC_WIFI_BAND_6GHZ: DracWifiBand = 3;
+
+pub const DRAC_PRINTER_STATE_UNKNOWN: DracPrinterState = 0;
+pub const DRAC_PRINTER_STATE_IDLE: DracPrinterState = 1;
+pub const DRAC_PRINTER_STATE_PRINTING: DracPrinterState = 2;
+pub const DRAC_PRINTER_STATE_ERROR: DracPrinterState = 3;
+
+pub const DRAC_DUPLEX_UNKNOWN: DracDuplex = 0;
+pub const DRAC_DUPLEX_HALF: DracDuplex = 1;
+pub const DRAC_DUPLEX_FULL: DracDuplex = 2;
+
+pub const DRAC_DISK_HEALTH_UNKNOWN: DracDiskHealthStatus = 0;
+pub const DRAC_DISK_HEALTH_PASSED: DracDiskHealthStatus = 1;
+pub const DRAC_DISK_HEALTH_FAILING: DracDiskHealthStatus = 2;
+
+pub const DRAC_NETWORK_MOUNT_UNKNOWN: DracNetworkMountProtocol = 0;
+pub const DRAC_NETWORK_MOUNT_NFS: DracNetworkMountProtocol = 1;
+pub const DRAC_NETWORK_MOUNT_SMB: DracNetworkMountProtocol = 2;
+pub const DRAC_NETWORK_MOUNT_SSHFS: DracNetworkMountProtocol = 3;
+pub const DRAC_NETWORK_MOUNT_WEBDAV: DracNetworkMountProtocol = 4;
+
+pub const DRAC_NETWORK_MOUNT_STATE_UNKNOWN: DracNetworkMountState = 0;
+pub const DRAC_NETWORK_MOUNT_STATE_CONNECTED: DracNetworkMountState = 1;
+pub const DRAC_NETWORK_MOUNT_STATE_DISCONNECTED: DracNetworkMountState = 2;
+
+pub const DRAC_HYPERVISOR_NONE: DracHypervisor = 0;
+pub const DRAC_HYPERVISOR_KVM: DracHypervisor = 1;
+pub const DRAC_HYPERVISOR_QEMU: DracHypervisor = 2;
+pub const DRAC_HYPERVISOR_HYPERV: DracHypervisor = 3;
+pub const DRAC_HYPERVISOR_VMWARE: DracHypervisor = 4;
+pub const DRAC_HYPERVISOR_PARALLELS: DracHypervisor = 5;
+pub const DRAC_HYPERVISOR_VIRTUALBOX: DracHypervisor = 6;
+pub const DRAC_HYPERVISOR_XEN: DracHypervisor = 7;
+pub const DRAC_HYPERVISOR_APPLE: DracHypervisor = 8;
+pub const DRAC_HYPERVISOR_OTHER: DracHypervisor = 9;
+
+pub const DRAC_CONTAINER_NONE: DracContainer = 0;
+pub const DRAC_CONTAINER_DOCKER: DracContainer = 1;
+pub const DRAC_CONTAINER_PODMAN: DracContainer = 2;
+pub const DRAC_CONTAINER_LXC: DracContainer = 3;
+pub const DRAC_CONTAINER_WSL: DracContainer = 4;
+pub const DRAC_CONTAINER_OTHER: DracContainer = 5;
+
+pub const DRAC_SECURE_BOOT_UNKNOWN: DracSecureBootState = 0;
+pub const DRAC_SECURE_BOOT_UNSUPPORTED: DracSecureBootState = 1;
+pub const DRAC_SECURE_BOOT_DISABLED: DracSecureBootState = 2;
+pub const DRAC_SECURE_BOOT_ENABLED: DracSecureBootState = 3;
+
+pub const DRAC_MAC_FRAMEWORK_NONE: DracMacFramework = 0;
+pub const DRAC_MAC_FRAMEWORK_SELINUX: DracMacFramework = 1;
+pub const DRAC_MAC_FRAMEWORK_APPARMOR: DracMacFramework = 2;
+
+pub const DRAC_MAC_MODE_DISABLED: DracMacMode = 0;
+pub const DRAC_MAC_MODE_PERMISSIVE: DracMacMode = 1;
+pub const DRAC_MAC_MODE_ENFORCING: DracMacMode = 2;
+
+pub const DRAC_FIREWALL_BACKEND_FIREWALLD: DracFirewallBackend = 0;
+pub const DRAC_FIREWALL_BACKEND_UFW: DracFirewallBackend = 1;
+pub const DRAC_FIREWALL_BACKEND_NFTABLES: DracFirewallBackend = 2;
+pub const DRAC_FIREWALL_BACKEND_WINDOWS_FIREWALL: DracFirewallBackend = 3;
+pub const DRAC_FIREWALL_BACKEND_ALF: DracFirewallBackend = 4;
+
+pub const DRAC_DOMAIN_MEMBERSHIP_UNJOINED: DracDomainMembership = 0;
+pub const DRAC_DOMAIN_MEMBERSHIP_WORKGROUP: DracDomainMembership = 1;
+pub const DRAC_DOMAIN_MEMBERSHIP_DOMAIN: DracDomainMembership = 2;
+
+pub const DRAC_CLOUD_AWS: DracCloudProvider = 0;
+pub const DRAC_CLOUD_GCP: DracCloudProvider = 1;
+pub const DRAC_CLOUD_AZURE: DracCloudProvider = 2;
+pub const DRAC_CLOUD_HETZNER: DracCloudProvider = 3;
+pub const DRAC_CLOUD_DIGITAL_OCEAN: DracCloudProvider = 4;
+pub const DRAC_CLOUD_ORACLE: DracCloudProvider = 5;
+
+pub const DRAC_SYSTEM_EVENT_DEVICE_ADDED: DracSystemEventKind = 0;
+pub const DRAC_SYSTEM_EVENT_DEVICE_REMOVED: DracSystemEventKind = 1;
+pub const DRAC_SYSTEM_EVENT_BATTERY_CHANGED: DracSystemEventKind = 2;
+pub const DRAC_SYSTEM_EVENT_NETWORK_UP: DracSystemEventKind = 3;
+pub const DRAC_SYSTEM_EVENT_NETWORK_DOWN: DracSystemEventKind = 4;
+pub const DRAC_SYSTEM_EVENT_DISPLAY_CHANGED: DracSystemEventKind = 5;
+
+pub const DRAC_CACHE_DOMAIN_CPU: DracCacheDomain = 0;
+pub const DRAC_CACHE_DOMAIN_GPU: DracCacheDomain = 1;
+pub const DRAC_CACHE_DOMAIN_NETWORK: DracCacheDomain = 2;
+pub const DRAC_CACHE_DOMAIN_SYSTEM: DracCacheDomain = 3;
+pub const DRAC_CACHE_DOMAIN_HARDWARE: DracCacheDomain = 4;
+pub const DRAC_CACHE_DOMAIN_DESKTOP: DracCacheDomain = 5;
+pub const DRAC_CACHE_DOMAIN_PACKAGES: DracCacheDomain = 6;
+
+pub const DRAC_STRING_CPU_MODEL: DracStringField = 0;
+pub const DRAC_STRING_GPU_MODEL: DracStringField = 1;
+pub const DRAC_STRING_HOST: DracStringField = 2;
+pub const DRAC_STRING_KERNEL_VERSION: DracStringField = 3;
+pub const DRAC_STRING_KERNEL_CMDLINE: DracStringField = 4;
+pub const DRAC_STRING_DESKTOP_ENVIRONMENT: DracStringField = 5;
+pub const DRAC_STRING_WINDOW_MANAGER: DracStringField = 6;
+pub const DRAC_STRING_SHELL: DracStringField = 7;
+pub const DRAC_STRING_TERMINAL: DracStringField = 8;
+pub const DRAC_STRING_TERMINAL_FONT: DracStringField = 9;
+pub const DRAC_STRING_MACHINE_ID: DracStringField = 10;
+pub const DRAC_STRING_HARDWARE_SERIAL: DracStringField = 11;
+
+pub const DRAC_FIELD_CPU_MODEL: DracField = 1 << 0;
+pub const DRAC_FIELD_GPU_MODEL: DracField = 1 << 1;
+pub const DRAC_FIELD_HOST: DracField = 1 << 2;
+pub const DRAC_FIELD_KERNEL_VERSION: DracField = 1 << 3;
+pub const DRAC_FIELD_OPERATING_SYSTEM: DracField = 1 << 4;
+pub const DRAC_FIELD_DESKTOP_ENVIRONMENT: DracField = 1 << 5;
+pub const DRAC_FIELD_WINDOW_MANAGER: DracField = 1 << 6;
+pub const DRAC_FIELD_SHELL: DracField = 1 << 7;
+pub const DRAC_FIELD_CPU_CORES: DracField = 1 << 8;
+pub const DRAC_FIELD_MEMORY: DracField = 1 << 9;
+pub const DRAC_FIELD_SWAP: DracField = 1 << 10;
+pub const DRAC_FIELD_DISK_USAGE: DracField = 1 << 11;
+pub const DRAC_FIELD_BATTERY: DracField = 1 << 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum ErrorCode {
+  #[error("a required OS service or API is unavailable")]
   ApiUnavailable,
+  #[error("configuration or environment issue")]
   ConfigurationError,
+  #[error("data is present but corrupt or inconsistent")]
   CorruptedData,
+  #[error("internal error in the system information library")]
   InternalError,
+  #[error("invalid argument")]
   InvalidArgument,
+  #[error("I/O error")]
   IoError,
+  #[error("network error")]
   NetworkError,
+  #[error("resource not found")]
   NotFound,
+  #[error("operation not supported on this platform or configuration")]
   NotSupported,
+  #[error("unclassified error")]
   Other,
+  #[error("out of memory")]
   OutOfMemory,
+  #[error("failed to parse data obtained from the OS")]
   ParseError,
+  #[error("permission denied")]
   PermissionDenied,
+  #[error("operation requires elevated privileges")]
   PermissionRequired,
+  #[error("platform-specific error")]
   PlatformSpecific,
+  #[error("system resource limit reached")]
   ResourceExhausted,
+  #[error("operation timed out")]
   Timeout,
+  #[error("feature not present on this hardware or OS")]
   UnavailableFeature,
+  #[error("operation was cancelled")]
+  Cancelled,
+  #[error("success")]
   Success,
 }
 
@@ -97,15 +474,101 @@ impl From<DracErrorCode> for ErrorCode {
       DRAC_ERROR_RESOURCE_EXHAUSTED => ErrorCode::ResourceExhausted,
       DRAC_ERROR_TIMEOUT => ErrorCode::Timeout,
       DRAC_ERROR_UNAVAILABLE_FEATURE => ErrorCode::UnavailableFeature,
+      DRAC_ERROR_CANCELLED => ErrorCode::Cancelled,
       DRAC_SUCCESS => ErrorCode::Success,
       _ => ErrorCode::Other,
     }
   }
 }
 
-pub type Result<T> = std::result::Result<T, ErrorCode>;
+/// An error from the library: its category, the explanation the C++ side
+/// gave, and the binding function that returned it.
+///
+/// Compares equal to an [`ErrorCode`] of the same category, so callers that
+/// only care about the category can keep matching on the code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DracError {
+  pub code:      ErrorCode,
+  /// Empty if the failure carried no explanation (e.g. argument validation).
+  pub message:   String,
+  /// The binding function that failed, e.g. `get_disks`.
+  #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+  pub source_fn: &'static str,
+}
+
+impl DracError {
+  /// An error without an explanation.
+  pub fn new(code: ErrorCode, source_fn: &'static str) -> Self {
+    Self {
+      code,
+      message: String::new(),
+      source_fn,
+    }
+  }
+
+  /// Converts a failed C API result, taking the message the library recorded
+  /// for it on this thread.
+  pub(crate) fn last(result: DracErrorCode, source_fn: &'static str) -> Self {
+    let mut recorded = DRAC_SUCCESS;
+    let ptr = unsafe { sys::DracGetLastErrorMessage(&mut recorded) };
+    let mut message = String::new();
+
+    if !ptr.is_null() {
+      // A message recorded for another code is stale; validation errors don't record one.
+      if recorded == result {
+        message = unsafe { CStr::from_ptr(ptr) }
+          .to_string_lossy()
+          .into_owned();
+      }
+
+      unsafe { sys::DracFreeString(ptr) };
+    }
+
+    Self {
+      code: ErrorCode::from(result),
+      message,
+      source_fn,
+    }
+  }
+}
+
+impl From<ErrorCode> for DracError {
+  fn from(code: ErrorCode) -> Self {
+    Self::new(code, "")
+  }
+}
+
+impl PartialEq<ErrorCode> for DracError {
+  fn eq(&self, code: &ErrorCode) -> bool {
+    self.code == *code
+  }
+}
+
+impl std::fmt::Display for DracError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if !self.source_fn.is_empty() {
+      write!(f, "{}: ", self.source_fn)?;
+    }
+
+    if self.message.is_empty() {
+      write!(f, "{}", self.code)
+    } else {
+      write!(f, "{} ({})", self.message, self.code)
+    }
+  }
+}
+
+impl std::error::Error for DracError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    Some(&self.code)
+  }
+}
+
+pub type Result<T> = std::result::Result<T, DracError>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BatteryStatus {
   Unknown,
   Charging,
@@ -127,670 +590,5687 @@ impl From<DracBatteryStatus> for BatteryStatus {
   }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct ResourceUsage {
-  pub used_bytes:  u64,
-  pub total_bytes: u64,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerMode {
+  Unknown,
+  PowerSaver,
+  Balanced,
+  Performance,
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct CPUCores {
-  pub physical: usize,
-  pub logical:  usize,
+impl From<DracPowerMode> for PowerMode {
+  fn from(mode: DracPowerMode) -> Self {
+    match mode {
+      DRAC_POWER_MODE_POWER_SAVER => PowerMode::PowerSaver,
+      DRAC_POWER_MODE_BALANCED => PowerMode::Balanced,
+      DRAC_POWER_MODE_PERFORMANCE => PowerMode::Performance,
+      _ => PowerMode::Unknown,
+    }
+  }
 }
 
-#[derive(Debug, Clone)]
-pub struct OSInfo {
-  pub name:    String,
-  pub version: String,
-  pub id:      String,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CpuCacheType {
+  Unknown,
+  Data,
+  Instruction,
+  Unified,
 }
 
-#[derive(Debug, Clone)]
-pub struct DiskInfo {
-  pub name:            String,
-  pub mount_point:     String,
-  pub filesystem:      String,
-  pub drive_type:      String,
-  pub total_bytes:     u64,
-  pub used_bytes:      u64,
-  pub is_system_drive: bool,
+impl From<DracCPUCacheType> for CpuCacheType {
+  fn from(cache_type: DracCPUCacheType) -> Self {
+    match cache_type {
+      DRAC_CPU_CACHE_DATA => CpuCacheType::Data,
+      DRAC_CPU_CACHE_INSTRUCTION => CpuCacheType::Instruction,
+      DRAC_CPU_CACHE_UNIFIED => CpuCacheType::Unified,
+      _ => CpuCacheType::Unknown,
+    }
+  }
 }
 
-#[derive(Debug, Clone)]
-pub struct DisplayInfo {
-  pub id:           u64,
-  pub width:        u64,
-  pub height:       u64,
-  pub refresh_rate: f64,
-  pub is_primary:   bool,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CpuVulnerabilityState {
+  Unknown,
+  NotAffected,
+  Mitigated,
+  Vulnerable,
 }
 
-#[derive(Debug, Clone)]
-pub struct NetworkInterface {
-  pub name:         String,
-  pub ipv4_address: Option<String>,
-  pub ipv6_address: Option<String>,
-  pub mac_address:  Option<String>,
-  pub is_up:        bool,
-  pub is_loopback:  bool,
+impl From<DracCPUVulnerabilityState> for CpuVulnerabilityState {
+  fn from(state: DracCPUVulnerabilityState) -> Self {
+    match state {
+      DRAC_CPU_VULNERABILITY_NOT_AFFECTED => CpuVulnerabilityState::NotAffected,
+      DRAC_CPU_VULNERABILITY_MITIGATED => CpuVulnerabilityState::Mitigated,
+      DRAC_CPU_VULNERABILITY_VULNERABLE => CpuVulnerabilityState::Vulnerable,
+      _ => CpuVulnerabilityState::Unknown,
+    }
+  }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Battery {
-  pub status:              BatteryStatus,
-  pub percentage:          Option<u8>,
-  pub time_remaining_secs: Option<i64>,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProcessState {
+  Unknown,
+  Running,
+  Sleeping,
+  DiskSleep,
+  Stopped,
+  Zombie,
+  Idle,
 }
 
-pub struct CacheManager {
-  handle: *mut sys::DracCacheManager,
+impl From<DracProcessState> for ProcessState {
+  fn from(state: DracProcessState) -> Self {
+    match state {
+      DRAC_PROCESS_RUNNING => ProcessState::Running,
+      DRAC_PROCESS_SLEEPING => ProcessState::Sleeping,
+      DRAC_PROCESS_DISK_SLEEP => ProcessState::DiskSleep,
+      DRAC_PROCESS_STOPPED => ProcessState::Stopped,
+      DRAC_PROCESS_ZOMBIE => ProcessState::Zombie,
+      DRAC_PROCESS_IDLE => ProcessState::Idle,
+      _ => ProcessState::Unknown,
+    }
+  }
 }
 
-impl CacheManager {
-  pub fn new() -> Self {
-    let handle = unsafe { sys::DracCreateCacheManager() };
-    assert!(!handle.is_null(), "Failed to create cache manager");
-    Self { handle }
-  }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InitSystemKind {
+  Unknown,
+  Systemd,
+  OpenRC,
+  Runit,
+  S6,
+  Dinit,
+  SysVinit,
+  Launchd,
+  /// The Windows Service Control Manager.
+  ServiceControlManager,
 }
 
-impl Default for CacheManager {
-  fn default() -> Self {
-    Self::new()
+impl From<DracInitSystemKind> for InitSystemKind {
+  fn from(kind: DracInitSystemKind) -> Self {
+    match kind {
+      DRAC_INIT_SYSTEM_SYSTEMD => InitSystemKind::Systemd,
+      DRAC_INIT_SYSTEM_OPENRC => InitSystemKind::OpenRC,
+      DRAC_INIT_SYSTEM_RUNIT => InitSystemKind::Runit,
+      DRAC_INIT_SYSTEM_S6 => InitSystemKind::S6,
+      DRAC_INIT_SYSTEM_DINIT => InitSystemKind::Dinit,
+      DRAC_INIT_SYSTEM_SYSVINIT => InitSystemKind::SysVinit,
+      DRAC_INIT_SYSTEM_LAUNCHD => InitSystemKind::Launchd,
+      DRAC_INIT_SYSTEM_SERVICE_CONTROL_MANAGER => InitSystemKind::ServiceControlManager,
+      _ => InitSystemKind::Unknown,
+    }
   }
 }
 
-impl Drop for CacheManager {
-  fn drop(&mut self) {
-    unsafe {
-      sys::DracDestroyCacheManager(self.handle);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ServiceState {
+  Unknown,
+  Running,
+  Stopped,
+  Starting,
+  Stopping,
+  /// The service exited with an error or crashed.
+  Failed,
+}
+
+impl From<DracServiceState> for ServiceState {
+  fn from(state: DracServiceState) -> Self {
+    match state {
+      DRAC_SERVICE_STATE_RUNNING => ServiceState::Running,
+      DRAC_SERVICE_STATE_STOPPED => ServiceState::Stopped,
+      DRAC_SERVICE_STATE_STARTING => ServiceState::Starting,
+      DRAC_SERVICE_STATE_STOPPING => ServiceState::Stopping,
+      DRAC_SERVICE_STATE_FAILED => ServiceState::Failed,
+      _ => ServiceState::Unknown,
     }
   }
 }
 
-pub fn get_uptime() -> u64 {
-  unsafe { sys::DracGetUptime() }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContainerRuntime {
+  Unknown,
+  Docker,
+  Podman,
+  Lxc,
 }
 
-pub fn get_mem_info(cache: &mut CacheManager) -> Result<ResourceUsage> {
-  let mut usage = sys::DracResourceUsage {
-    usedBytes:  0,
-    totalBytes: 0,
-  };
+impl From<DracContainerRuntime> for ContainerRuntime {
+  fn from(runtime: DracContainerRuntime) -> Self {
+    match runtime {
+      DRAC_CONTAINER_RUNTIME_DOCKER => ContainerRuntime::Docker,
+      DRAC_CONTAINER_RUNTIME_PODMAN => ContainerRuntime::Podman,
+      DRAC_CONTAINER_RUNTIME_LXC => ContainerRuntime::Lxc,
+      _ => ContainerRuntime::Unknown,
+    }
+  }
+}
 
-  let result = unsafe { sys::DracGetMemInfo(cache.handle, &mut usage) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContainerState {
+  Unknown,
+  Running,
+  Paused,
+  Restarting,
+}
 
-  if result == DRAC_SUCCESS {
-    Ok(ResourceUsage {
-      used_bytes:  usage.usedBytes,
-      total_bytes: usage.totalBytes,
-    })
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracContainerState> for ContainerState {
+  fn from(state: DracContainerState) -> Self {
+    match state {
+      DRAC_CONTAINER_STATE_RUNNING => ContainerState::Running,
+      DRAC_CONTAINER_STATE_PAUSED => ContainerState::Paused,
+      DRAC_CONTAINER_STATE_RESTARTING => ContainerState::Restarting,
+      _ => ContainerState::Unknown,
+    }
   }
 }
 
-pub fn get_cpu_cores(cache: &mut CacheManager) -> Result<CPUCores> {
-  let mut cores = sys::DracCPUCores {
-    physical: 0,
-    logical:  0,
-  };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SensorKind {
+  Unknown,
+  Temperature,
+  Voltage,
+  Current,
+  Power,
+  Fan,
+}
 
-  let result = unsafe { sys::DracGetCpuCores(cache.handle, &mut cores) };
+impl SensorKind {
+  /// Unit the readings of this kind are reported in, or an empty string for
+  /// [`SensorKind::Unknown`].
+  pub fn unit(self) -> &'static str {
+    match self {
+      SensorKind::Unknown => "",
+      SensorKind::Temperature => "°C",
+      SensorKind::Voltage => "V",
+      SensorKind::Current => "A",
+      SensorKind::Power => "W",
+      SensorKind::Fan => "RPM",
+    }
+  }
+}
 
-  if result == DRAC_SUCCESS {
-    Ok(CPUCores {
-      physical: cores.physical,
-      logical:  cores.logical,
-    })
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracSensorKind> for SensorKind {
+  fn from(kind: DracSensorKind) -> Self {
+    match kind {
+      DRAC_SENSOR_TEMPERATURE => SensorKind::Temperature,
+      DRAC_SENSOR_VOLTAGE => SensorKind::Voltage,
+      DRAC_SENSOR_CURRENT => SensorKind::Current,
+      DRAC_SENSOR_POWER => SensorKind::Power,
+      DRAC_SENSOR_FAN => SensorKind::Fan,
+      _ => SensorKind::Unknown,
+    }
   }
 }
 
-pub fn get_operating_system(cache: &mut CacheManager) -> Result<OSInfo> {
-  let mut info = sys::DracOSInfo {
-    name:    std::ptr::null_mut(),
-    version: std::ptr::null_mut(),
-    id:      std::ptr::null_mut(),
-  };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SessionType {
+  Unknown,
+  Graphical,
+  Terminal,
+  Remote,
+}
 
-  let result = unsafe { sys::DracGetOperatingSystem(cache.handle, &mut info) };
+impl From<DracSessionType> for SessionType {
+  fn from(session_type: DracSessionType) -> Self {
+    match session_type {
+      DRAC_SESSION_GRAPHICAL => SessionType::Graphical,
+      DRAC_SESSION_TERMINAL => SessionType::Terminal,
+      DRAC_SESSION_REMOTE => SessionType::Remote,
+      _ => SessionType::Unknown,
+    }
+  }
+}
 
-  if result == DRAC_SUCCESS {
-    let name = if info.name.is_null() {
-      String::new()
-    } else {
-      unsafe { CStr::from_ptr(info.name) }
-        .to_string_lossy()
-        .into_owned()
-    };
-    let version = if info.version.is_null() {
-      String::new()
-    } else {
-      unsafe { CStr::from_ptr(info.version) }
-        .to_string_lossy()
-        .into_owned()
-    };
-    let id = if info.id.is_null() {
-      String::new()
-    } else {
-      unsafe { CStr::from_ptr(info.id) }
-        .to_string_lossy()
-        .into_owned()
-    };
+/// The display server a session runs on.
+///
+/// Named `DisplayServer` rather than `SessionType`, which already describes
+/// the kind of login session in [`SessionInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DisplayServer {
+  Unknown,
+  Wayland,
+  X11,
+  Windows,
+  Quartz,
+  Tty,
+}
 
-    unsafe { sys::DracFreeOSInfo(&mut info) };
+impl From<DracDisplayServer> for DisplayServer {
+  fn from(server: DracDisplayServer) -> Self {
+    match server {
+      DRAC_DISPLAY_SERVER_WAYLAND => DisplayServer::Wayland,
+      DRAC_DISPLAY_SERVER_X11 => DisplayServer::X11,
+      DRAC_DISPLAY_SERVER_WINDOWS => DisplayServer::Windows,
+      DRAC_DISPLAY_SERVER_QUARTZ => DisplayServer::Quartz,
+      DRAC_DISPLAY_SERVER_TTY => DisplayServer::Tty,
+      _ => DisplayServer::Unknown,
+    }
+  }
+}
 
-    Ok(OSInfo {
-      name,
-      version,
-      id,
-    })
-  } else {
-    Err(ErrorCode::from(result))
+/// Physical connector a display is attached through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DisplayConnector {
+  Unknown,
+  /// Built-in panel whose link type isn't exposed.
+  Internal,
+  /// Embedded DisplayPort (most laptop panels).
+  Edp,
+  Lvds,
+  /// MIPI DSI (phones, tablets, single-board computers).
+  Dsi,
+  Hdmi,
+  /// DisplayPort, including USB-C and Thunderbolt alternate mode.
+  DisplayPort,
+  Dvi,
+  Vga,
+  /// Virtual or software output (virtual machines, remote sessions).
+  Virtual,
+}
+
+impl From<DracDisplayConnector> for DisplayConnector {
+  fn from(connector: DracDisplayConnector) -> Self {
+    match connector {
+      DRAC_DISPLAY_CONNECTOR_INTERNAL => DisplayConnector::Internal,
+      DRAC_DISPLAY_CONNECTOR_EDP => DisplayConnector::Edp,
+      DRAC_DISPLAY_CONNECTOR_LVDS => DisplayConnector::Lvds,
+      DRAC_DISPLAY_CONNECTOR_DSI => DisplayConnector::Dsi,
+      DRAC_DISPLAY_CONNECTOR_HDMI => DisplayConnector::Hdmi,
+      DRAC_DISPLAY_CONNECTOR_DISPLAY_PORT => DisplayConnector::DisplayPort,
+      DRAC_DISPLAY_CONNECTOR_DVI => DisplayConnector::Dvi,
+      DRAC_DISPLAY_CONNECTOR_VGA => DisplayConnector::Vga,
+      DRAC_DISPLAY_CONNECTOR_VIRTUAL => DisplayConnector::Virtual,
+      _ => DisplayConnector::Unknown,
+    }
   }
 }
 
-pub fn get_battery_info(cache: &mut CacheManager) -> Result<Battery> {
-  let mut battery = sys::DracBattery {
-    status:            DRAC_BATTERY_UNKNOWN,
-    percentage:        255,
-    timeRemainingSecs: -1,
-  };
-
-  let result = unsafe { sys::DracGetBatteryInfo(cache.handle, &mut battery) };
+/// Widest color gamut a display advertises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DisplayGamut {
+  Unknown,
+  /// sRGB / BT.709.
+  Srgb,
+  /// DCI-P3 / Display P3.
+  P3,
+  Bt2020,
+}
 
-  if result == DRAC_SUCCESS {
-    Ok(Battery {
-      status:              BatteryStatus::from(battery.status),
-      percentage:          if battery.percentage == 255 {
-        None
-      } else {
-        Some(battery.percentage)
-      },
-      time_remaining_secs: if battery.timeRemainingSecs < 0 {
-        None
-      } else {
-        Some(battery.timeRemainingSecs)
-      },
-    })
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracDisplayGamut> for DisplayGamut {
+  fn from(gamut: DracDisplayGamut) -> Self {
+    match gamut {
+      DRAC_DISPLAY_GAMUT_SRGB => DisplayGamut::Srgb,
+      DRAC_DISPLAY_GAMUT_P3 => DisplayGamut::P3,
+      DRAC_DISPLAY_GAMUT_BT2020 => DisplayGamut::Bt2020,
+      _ => DisplayGamut::Unknown,
+    }
   }
 }
 
-pub fn get_cpu_model(cache: &mut CacheManager) -> Result<String> {
-  let mut ptr = std::ptr::null_mut();
-  let result = unsafe { sys::DracGetCPUModel(cache.handle, &mut ptr) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AudioDirection {
+  Unknown,
+  Output,
+  Input,
+}
 
-  if result == DRAC_SUCCESS && !ptr.is_null() {
-    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
-    unsafe { sys::DracFreeString(ptr) };
-    Ok(s)
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracAudioDirection> for AudioDirection {
+  fn from(direction: DracAudioDirection) -> Self {
+    match direction {
+      DRAC_AUDIO_OUTPUT => AudioDirection::Output,
+      DRAC_AUDIO_INPUT => AudioDirection::Input,
+      _ => AudioDirection::Unknown,
+    }
   }
 }
 
-pub fn get_gpu_model(cache: &mut CacheManager) -> Result<String> {
-  let mut ptr = std::ptr::null_mut();
-  let result = unsafe { sys::DracGetGPUModel(cache.handle, &mut ptr) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Duplex {
+  Unknown,
+  Half,
+  Full,
+}
 
-  if result == DRAC_SUCCESS && !ptr.is_null() {
-    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
-    unsafe { sys::DracFreeString(ptr) };
-    Ok(s)
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracDuplex> for Duplex {
+  fn from(duplex: DracDuplex) -> Self {
+    match duplex {
+      DRAC_DUPLEX_HALF => Duplex::Half,
+      DRAC_DUPLEX_FULL => Duplex::Full,
+      _ => Duplex::Unknown,
+    }
   }
 }
 
-pub fn get_desktop_environment(cache: &mut CacheManager) -> Result<String> {
-  let mut ptr = std::ptr::null_mut();
-  let result = unsafe { sys::DracGetDesktopEnvironment(cache.handle, &mut ptr) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiskHealthStatus {
+  Unknown,
+  Passed,
+  Failing,
+}
 
-  if result == DRAC_SUCCESS && !ptr.is_null() {
-    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
-    unsafe { sys::DracFreeString(ptr) };
-    Ok(s)
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracDiskHealthStatus> for DiskHealthStatus {
+  fn from(status: DracDiskHealthStatus) -> Self {
+    match status {
+      DRAC_DISK_HEALTH_PASSED => DiskHealthStatus::Passed,
+      DRAC_DISK_HEALTH_FAILING => DiskHealthStatus::Failing,
+      _ => DiskHealthStatus::Unknown,
+    }
   }
 }
 
-pub fn get_window_manager(cache: &mut CacheManager) -> Result<String> {
-  let mut ptr = std::ptr::null_mut();
-  let result = unsafe { sys::DracGetWindowManager(cache.handle, &mut ptr) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NetworkMountProtocol {
+  Unknown,
+  Nfs,
+  Smb,
+  Sshfs,
+  WebDav,
+}
 
-  if result == DRAC_SUCCESS && !ptr.is_null() {
-    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
-    unsafe { sys::DracFreeString(ptr) };
-    Ok(s)
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracNetworkMountProtocol> for NetworkMountProtocol {
+  fn from(protocol: DracNetworkMountProtocol) -> Self {
+    match protocol {
+      DRAC_NETWORK_MOUNT_NFS => NetworkMountProtocol::Nfs,
+      DRAC_NETWORK_MOUNT_SMB => NetworkMountProtocol::Smb,
+      DRAC_NETWORK_MOUNT_SSHFS => NetworkMountProtocol::Sshfs,
+      DRAC_NETWORK_MOUNT_WEBDAV => NetworkMountProtocol::WebDav,
+      _ => NetworkMountProtocol::Unknown,
+    }
   }
 }
 
-pub fn get_shell(cache: &mut CacheManager) -> Result<String> {
-  let mut ptr = std::ptr::null_mut();
-  let result = unsafe { sys::DracGetShell(cache.handle, &mut ptr) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NetworkMountState {
+  Unknown,
+  Connected,
+  Disconnected,
+}
 
-  if result == DRAC_SUCCESS && !ptr.is_null() {
-    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
-    unsafe { sys::DracFreeString(ptr) };
-    Ok(s)
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracNetworkMountState> for NetworkMountState {
+  fn from(state: DracNetworkMountState) -> Self {
+    match state {
+      DRAC_NETWORK_MOUNT_STATE_CONNECTED => NetworkMountState::Connected,
+      DRAC_NETWORK_MOUNT_STATE_DISCONNECTED => NetworkMountState::Disconnected,
+      _ => NetworkMountState::Unknown,
+    }
   }
 }
 
-pub fn get_host(cache: &mut CacheManager) -> Result<String> {
-  let mut ptr = std::ptr::null_mut();
-  let result = unsafe { sys::DracGetHost(cache.handle, &mut ptr) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Hypervisor {
+  None,
+  Kvm,
+  Qemu,
+  HyperV,
+  VMware,
+  Parallels,
+  VirtualBox,
+  Xen,
+  Apple,
+  Other,
+}
 
-  if result == DRAC_SUCCESS && !ptr.is_null() {
-    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
-    unsafe { sys::DracFreeString(ptr) };
-    Ok(s)
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracHypervisor> for Hypervisor {
+  fn from(hypervisor: DracHypervisor) -> Self {
+    match hypervisor {
+      DRAC_HYPERVISOR_NONE => Hypervisor::None,
+      DRAC_HYPERVISOR_KVM => Hypervisor::Kvm,
+      DRAC_HYPERVISOR_QEMU => Hypervisor::Qemu,
+      DRAC_HYPERVISOR_HYPERV => Hypervisor::HyperV,
+      DRAC_HYPERVISOR_VMWARE => Hypervisor::VMware,
+      DRAC_HYPERVISOR_PARALLELS => Hypervisor::Parallels,
+      DRAC_HYPERVISOR_VIRTUALBOX => Hypervisor::VirtualBox,
+      DRAC_HYPERVISOR_XEN => Hypervisor::Xen,
+      DRAC_HYPERVISOR_APPLE => Hypervisor::Apple,
+      _ => Hypervisor::Other,
+    }
   }
 }
 
-pub fn get_kernel_version(cache: &mut CacheManager) -> Result<String> {
-  let mut ptr = std::ptr::null_mut();
-  let result = unsafe { sys::DracGetKernelVersion(cache.handle, &mut ptr) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Container {
+  None,
+  Docker,
+  Podman,
+  Lxc,
+  Wsl,
+  Other,
+}
 
-  if result == DRAC_SUCCESS && !ptr.is_null() {
-    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
-    unsafe { sys::DracFreeString(ptr) };
-    Ok(s)
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracContainer> for Container {
+  fn from(container: DracContainer) -> Self {
+    match container {
+      DRAC_CONTAINER_NONE => Container::None,
+      DRAC_CONTAINER_DOCKER => Container::Docker,
+      DRAC_CONTAINER_PODMAN => Container::Podman,
+      DRAC_CONTAINER_LXC => Container::Lxc,
+      DRAC_CONTAINER_WSL => Container::Wsl,
+      _ => Container::Other,
+    }
   }
 }
 
-pub fn get_disk_usage(cache: &mut CacheManager) -> Result<ResourceUsage> {
-  let mut usage = sys::DracResourceUsage {
-    usedBytes:  0,
-    totalBytes: 0,
-  };
-
-  let result = unsafe { sys::DracGetDiskUsage(cache.handle, &mut usage) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SecureBootState {
+  Unknown,
+  /// Booted through legacy BIOS, where Secure Boot doesn't exist.
+  Unsupported,
+  Disabled,
+  Enabled,
+}
 
-  if result == DRAC_SUCCESS {
-    Ok(ResourceUsage {
-      used_bytes:  usage.usedBytes,
-      total_bytes: usage.totalBytes,
-    })
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracSecureBootState> for SecureBootState {
+  fn from(state: DracSecureBootState) -> Self {
+    match state {
+      DRAC_SECURE_BOOT_UNSUPPORTED => SecureBootState::Unsupported,
+      DRAC_SECURE_BOOT_DISABLED => SecureBootState::Disabled,
+      DRAC_SECURE_BOOT_ENABLED => SecureBootState::Enabled,
+      _ => SecureBootState::Unknown,
+    }
   }
 }
 
-pub fn get_disks(cache: &mut CacheManager) -> Result<Vec<DiskInfo>> {
-  let mut list = sys::DracDiskInfoList {
-    items: std::ptr::null_mut(),
-    count: 0,
-  };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MacFramework {
+  None,
+  SELinux,
+  AppArmor,
+}
 
-  let result = unsafe { sys::DracGetDisks(cache.handle, &mut list) };
+impl From<DracMacFramework> for MacFramework {
+  fn from(framework: DracMacFramework) -> Self {
+    match framework {
+      DRAC_MAC_FRAMEWORK_SELINUX => MacFramework::SELinux,
+      DRAC_MAC_FRAMEWORK_APPARMOR => MacFramework::AppArmor,
+      _ => MacFramework::None,
+    }
+  }
+}
 
-  if result == DRAC_SUCCESS {
-    let mut disks = Vec::with_capacity(list.count);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MacMode {
+  Disabled,
+  /// Denials are only logged (SELinux permissive, AppArmor complain mode).
+  Permissive,
+  Enforcing,
+}
 
-    for i in 0..list.count {
-      let disk = unsafe { &*list.items.add(i) };
-      disks.push(DiskInfo {
-        name:            if disk.name.is_null() {
-          String::new()
-        } else {
-          unsafe { CStr::from_ptr(disk.name) }
-            .to_string_lossy()
-            .into_owned()
-        },
-        mount_point:     if disk.mountPoint.is_null() {
-          String::new()
-        } else {
-          unsafe { CStr::from_ptr(disk.mountPoint) }
-            .to_string_lossy()
-            .into_owned()
-        },
-        filesystem:      if disk.filesystem.is_null() {
-          String::new()
-        } else {
-          unsafe { CStr::from_ptr(disk.filesystem) }
-            .to_string_lossy()
-            .into_owned()
-        },
-        drive_type:      if disk.driveType.is_null() {
-          String::new()
-        } else {
-          unsafe { CStr::from_ptr(disk.driveType) }
-            .to_string_lossy()
-            .into_owned()
-        },
-        total_bytes:     disk.totalBytes,
-        used_bytes:      disk.usedBytes,
-        is_system_drive: disk.isSystemDrive,
-      });
+impl From<DracMacMode> for MacMode {
+  fn from(mode: DracMacMode) -> Self {
+    match mode {
+      DRAC_MAC_MODE_PERMISSIVE => MacMode::Permissive,
+      DRAC_MAC_MODE_ENFORCING => MacMode::Enforcing,
+      _ => MacMode::Disabled,
     }
-
-    unsafe { sys::DracFreeDiskInfoList(&mut list) };
-    Ok(disks)
-  } else {
-    Err(ErrorCode::from(result))
   }
 }
 
-pub fn get_system_disk(cache: &mut CacheManager) -> Result<DiskInfo> {
-  let mut disk = sys::DracDiskInfo {
-    name:          std::ptr::null_mut(),
-    mountPoint:    std::ptr::null_mut(),
-    filesystem:    std::ptr::null_mut(),
-    driveType:     std::ptr::null_mut(),
-    totalBytes:    0,
-    usedBytes:     0,
-    isSystemDrive: false,
-  };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FirewallBackend {
+  Unknown,
+  Firewalld,
+  Ufw,
+  Nftables,
+  WindowsFirewall,
+  /// The macOS Application Firewall.
+  Alf,
+}
 
-  let result = unsafe { sys::DracGetSystemDisk(cache.handle, &mut disk) };
+impl From<DracFirewallBackend> for FirewallBackend {
+  fn from(backend: DracFirewallBackend) -> Self {
+    match backend {
+      DRAC_FIREWALL_BACKEND_FIREWALLD => FirewallBackend::Firewalld,
+      DRAC_FIREWALL_BACKEND_UFW => FirewallBackend::Ufw,
+      DRAC_FIREWALL_BACKEND_NFTABLES => FirewallBackend::Nftables,
+      DRAC_FIREWALL_BACKEND_WINDOWS_FIREWALL => FirewallBackend::WindowsFirewall,
+      DRAC_FIREWALL_BACKEND_ALF => FirewallBackend::Alf,
+      _ => FirewallBackend::Unknown,
+    }
+  }
+}
 
-  if result == DRAC_SUCCESS {
-    let info = DiskInfo {
-      name:            if disk.name.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(disk.name) }
-          .to_string_lossy()
-          .into_owned()
-      },
-      mount_point:     if disk.mountPoint.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(disk.mountPoint) }
-          .to_string_lossy()
-          .into_owned()
-      },
-      filesystem:      if disk.filesystem.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(disk.filesystem) }
-          .to_string_lossy()
-          .into_owned()
-      },
-      drive_type:      if disk.driveType.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(disk.driveType) }
-          .to_string_lossy()
-          .into_owned()
-      },
-      total_bytes:     disk.totalBytes,
-      used_bytes:      disk.usedBytes,
-      is_system_drive: disk.isSystemDrive,
-    };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DomainMembership {
+  Unjoined,
+  Workgroup,
+  /// Joined to an Active Directory domain.
+  Domain,
+}
 
-    unsafe { sys::DracFreeDiskInfo(&mut disk) };
-    Ok(info)
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracDomainMembership> for DomainMembership {
+  fn from(membership: DracDomainMembership) -> Self {
+    match membership {
+      DRAC_DOMAIN_MEMBERSHIP_WORKGROUP => DomainMembership::Workgroup,
+      DRAC_DOMAIN_MEMBERSHIP_DOMAIN => DomainMembership::Domain,
+      _ => DomainMembership::Unjoined,
+    }
   }
 }
 
-pub fn get_outputs(cache: &mut CacheManager) -> Result<Vec<DisplayInfo>> {
-  let mut list = sys::DracDisplayInfoList {
-    items: std::ptr::null_mut(),
-    count: 0,
-  };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CloudProvider {
+  Unknown,
+  Aws,
+  Gcp,
+  Azure,
+  Hetzner,
+  DigitalOcean,
+  Oracle,
+}
 
-  let result = unsafe { sys::DracGetOutputs(cache.handle, &mut list) };
+impl From<DracCloudProvider> for CloudProvider {
+  fn from(provider: DracCloudProvider) -> Self {
+    match provider {
+      DRAC_CLOUD_AWS => CloudProvider::Aws,
+      DRAC_CLOUD_GCP => CloudProvider::Gcp,
+      DRAC_CLOUD_AZURE => CloudProvider::Azure,
+      DRAC_CLOUD_HETZNER => CloudProvider::Hetzner,
+      DRAC_CLOUD_DIGITAL_OCEAN => CloudProvider::DigitalOcean,
+      DRAC_CLOUD_ORACLE => CloudProvider::Oracle,
+      _ => CloudProvider::Unknown,
+    }
+  }
+}
 
-  if result == DRAC_SUCCESS {
-    let mut displays = Vec::with_capacity(list.count);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WifiBand {
+  Unknown,
+  Ghz2_4,
+  Ghz5,
+  Ghz6,
+}
 
-    for i in 0..list.count {
-      let display = unsafe { &*list.items.add(i) };
-      displays.push(DisplayInfo {
-        id:           display.id,
-        width:        display.width,
-        height:       display.height,
-        refresh_rate: display.refreshRate,
-        is_primary:   display.isPrimary,
-      });
+impl From<DracWifiBand> for WifiBand {
+  fn from(band: DracWifiBand) -> Self {
+    match band {
+      DRAC_WIFI_BAND_2_4GHZ => WifiBand::Ghz2_4,
+      DRAC_WIFI_BAND_5GHZ => WifiBand::Ghz5,
+      DRAC_WIFI_BAND_6GHZ => WifiBand::Ghz6,
+      _ => WifiBand::Unknown,
     }
-
-    unsafe { sys::DracFreeDisplayInfoList(&mut list) };
-    Ok(displays)
-  } else {
-    Err(ErrorCode::from(result))
   }
 }
 
-pub fn get_primary_output(cache: &mut CacheManager) -> Result<DisplayInfo> {
-  let mut display = sys::DracDisplayInfo {
-    id:          0,
-    width:       0,
-    height:      0,
-    refreshRate: 0.0,
-    isPrimary:   false,
-  };
-
-  let result = unsafe { sys::DracGetPrimaryOutput(cache.handle, &mut display) };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrinterState {
+  Unknown,
+  Idle,
+  Printing,
+  /// Stopped, paused, offline, or reporting an error condition.
+  Error,
+}
 
-  if result == DRAC_SUCCESS {
-    Ok(DisplayInfo {
-      id:           display.id,
-      width:        display.width,
-      height:       display.height,
-      refresh_rate: display.refreshRate,
-      is_primary:   display.isPrimary,
-    })
-  } else {
-    Err(ErrorCode::from(result))
+impl From<DracPrinterState> for PrinterState {
+  fn from(state: DracPrinterState) -> Self {
+    match state {
+      DRAC_PRINTER_STATE_IDLE => PrinterState::Idle,
+      DRAC_PRINTER_STATE_PRINTING => PrinterState::Printing,
+      DRAC_PRINTER_STATE_ERROR => PrinterState::Error,
+      _ => PrinterState::Unknown,
+    }
   }
 }
 
-pub fn get_network_interfaces(cache: &mut CacheManager) -> Result<Vec<NetworkInterface>> {
-  let mut list = sys::DracNetworkInterfaceList {
-    items: std::ptr::null_mut(),
-    count: 0,
-  };
-
-  let result = unsafe { sys::DracGetNetworkInterfaces(cache.handle, &mut list) };
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceUsage {
+  pub used_bytes:  u64,
+  pub total_bytes: u64,
+}
 
-  if result == DRAC_SUCCESS {
-    let mut interfaces = Vec::with_capacity(list.count);
+/// Detailed breakdown of physical memory, matching the columns of `free`.
+///
+/// Fields the platform doesn't track are reported as 0.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryDetails {
+  pub total_bytes:     u64,
+  pub used_bytes:      u64,
+  pub free_bytes:      u64,
+  pub available_bytes: u64,
+  pub cached_bytes:    u64,
+  pub buffers_bytes:   u64,
+  pub shared_bytes:    u64,
+  pub kernel_bytes:    u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CPUCores {
+  pub physical: usize,
+  pub logical:  usize,
+}
+
+/// CPU clock speeds in MHz. Values the platform doesn't expose are `None`.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuFrequency {
+  pub current_mhz: Option<u64>,
+  pub base_mhz:    Option<u64>,
+  pub max_mhz:     Option<u64>,
+}
+
+/// CPU identity and instruction set extensions.
+///
+/// Feature flags use the names from Linux's `/proc/cpuinfo` on every
+/// platform, so NEON is reported as `asimd` on AArch64.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuDetails {
+  /// Instruction set architecture, e.g. `x86_64` or `aarch64`.
+  pub architecture: String,
+  /// CPUID vendor string or Arm implementer, e.g. `GenuineIntel`.
+  pub vendor:       Option<String>,
+  /// Family on x86, architecture version on Arm.
+  pub family:       Option<u32>,
+  /// Model on x86, part number on Arm.
+  pub model:        Option<u32>,
+  /// Stepping on x86, revision on Arm.
+  pub stepping:     Option<u32>,
+  /// Supported extensions, e.g. `avx2`, `avx512f`, `asimd`, or `sve`.
+  pub flags:        Vec<String>,
+}
+
+/// One level of the CPU cache hierarchy.
+///
+/// Instances of the same level and type are grouped, so a CPU with a private
+/// L2 per core reports a single L2 entry whose `instance_count` matches its
+/// core count.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuCacheInfo {
+  pub cache_type:       CpuCacheType,
+  pub level:            u8,
+  /// Size of a single instance.
+  pub size_bytes:       u64,
+  pub line_size_bytes:  Option<u32>,
+  /// Logical CPUs sharing one instance, e.g. the threads of a core or every
+  /// CPU of a complex.
+  pub shared_cpu_count: u32,
+  pub instance_count:   u32,
+}
+
+/// A NUMA node: a set of CPUs and the memory local to them.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumaNode {
+  pub id:                 u32,
+  /// Memory attached to the node. Not exposed on Windows.
+  pub total_memory_bytes: Option<u64>,
+  pub free_memory_bytes:  Option<u64>,
+  /// Logical CPU numbers belonging to the node, in ascending order.
+  pub cpus:               Vec<u32>,
+}
+
+/// A CPU vulnerability and the state of its mitigation.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuVulnerability {
+  /// Linux's name for the vulnerability, e.g. `meltdown` or `spectre_v2`.
+  pub name:    String,
+  pub state:   CpuVulnerabilityState,
+  /// The OS's description, e.g. `Mitigation: PTI`.
+  pub details: Option<String>,
+}
+
+/// Cumulative time counters for a single logical CPU.
+///
+/// The units are platform-specific, so only the difference between two
+/// samples is meaningful. Use [`CpuSampler`] to turn samples into usage.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CPUTimes {
+  pub idle:  u64,
+  pub total: u64,
+}
+
+/// An uptime split into whole days, hours, minutes and seconds.
+///
+/// ```ignore
+/// let up = draconis::UptimeBreakdown::from(draconis::get_uptime_duration());
+/// println!("{}d {}h {}m", up.days, up.hours, up.minutes);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UptimeBreakdown {
+  pub days:    u64,
+  pub hours:   u64,
+  pub minutes: u64,
+  pub seconds: u64,
+}
+
+impl From<std::time::Duration> for UptimeBreakdown {
+  fn from(duration: std::time::Duration) -> Self {
+    let total = duration.as_secs();
+
+    UptimeBreakdown {
+      days:    total / 86_400,
+      hours:   (total % 86_400) / 3_600,
+      minutes: (total % 3_600) / 60,
+      seconds: total % 60,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OSInfo {
+  pub name:    String,
+  pub version: String,
+  pub id:      String,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskInfo {
+  pub name:              String,
+  pub mount_point:       String,
+  /// `mount_point` as the OS reported it, which may not be valid UTF-8. Not
+  /// serialized. See [`DiskInfo::mount_path`].
+  #[cfg_attr(feature = "serde", serde(skip))]
+  pub mount_point_bytes: Vec<u8>,
+  pub filesystem:        String,
+  pub drive_type:        String,
+  pub total_bytes:       u64,
+  pub used_bytes:        u64,
+  pub total_inodes:      Option<u64>,
+  pub used_inodes:       Option<u64>,
+  pub mount_options:     Vec<String>,
+  pub is_read_only:      bool,
+  pub is_system_drive:   bool,
+}
+
+impl DiskInfo {
+  /// The mount point as a path that file operations can use, even when it
+  /// isn't valid UTF-8 and `mount_point` had to replace bytes.
+  ///
+  /// Falls back to `mount_point` for deserialized snapshots.
+  pub fn mount_path(&self) -> &Path {
+    if self.mount_point_bytes.is_empty() {
+      return Path::new(&self.mount_point);
+    }
+
+    Path::new(os_str_from_bytes(&self.mount_point_bytes))
+  }
+}
+
+/// A loaded kernel module (Linux), kernel extension (macOS), or driver
+/// (Windows).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KernelModule {
+  /// Module name, e.g. `nvidia`, `com.apple.iokit.IOUSBFamily`, or
+  /// `nvlddmkm.sys`.
+  pub name:       String,
+  /// Size in memory; not reported on Windows.
+  pub size_bytes: Option<u64>,
+}
+
+/// A filesystem mounted from a remote server.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkMount {
+  pub server:      String,
+  pub export_path: String,
+  pub mount_point: String,
+  pub protocol:    NetworkMountProtocol,
+  pub state:       NetworkMountState,
+}
+
+/// A single ATA SMART attribute.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmartAttribute {
+  pub id:        u8,
+  pub name:      String,
+  pub value:     u8,
+  pub worst:     u8,
+  pub threshold: Option<u8>,
+  pub raw:       u64,
+}
+
+/// SMART health data for a block device.
+///
+/// NVMe drives have no attribute table, so `attributes` is empty for them.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskHealth {
+  pub device:              String,
+  pub status:              DiskHealthStatus,
+  pub power_on_hours:      Option<u64>,
+  pub reallocated_sectors: Option<u64>,
+  pub temperature_celsius: Option<f64>,
+  pub attributes:          Vec<SmartAttribute>,
+}
+
+/// The current temperature of a physical drive.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskTemperature {
+  pub device:  String,
+  pub celsius: f64,
+}
+
+/// Cumulative I/O counters of a physical drive since boot.
+///
+/// Sample twice and divide the difference by the elapsed time to get IOPS or
+/// throughput, or use [`Sampler`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskIOStats {
+  /// Drive name, e.g. `sda`, `disk0`, or `PhysicalDrive0`.
+  pub device:      String,
+  pub read_ops:    u64,
+  pub write_ops:   u64,
+  pub read_bytes:  u64,
+  pub write_bytes: u64,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FanInfo {
+  /// Sensor label, e.g. `CPU Fan` or `thinkpad fan1`.
+  pub label:      String,
+  /// Current speed. Stopped fans report `Some(0)`; Windows only reports
+  /// the target speed.
+  pub rpm:        Option<u32>,
+  pub target_rpm: Option<u32>,
+}
+
+/// One channel of a hardware monitoring chip, as listed by `sensors`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensorReading {
+  pub kind:  SensorKind,
+  /// Chip or driver the sensor belongs to, e.g. `coretemp` or `AppleSMC`.
+  pub chip:  String,
+  pub label: String,
+  /// Current reading, in the unit given by [`SensorKind::unit`].
+  pub value: f64,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GpuInfo {
+  pub vendor:              String,
+  pub model:               String,
+  pub vram_bytes:          Option<u64>,
+  pub driver:              Option<String>,
+  pub is_integrated:       bool,
+  /// Current temperature in degrees Celsius, if the platform exposes it.
+  pub temperature_celsius: Option<f64>,
+  /// Current power draw in watts, if the platform exposes it.
+  pub power_watts:         Option<f64>,
+  /// User-facing driver version, e.g. `Mesa 24.1.3` or `552.44`.
+  pub driver_version:      Option<String>,
+  /// Highest Vulkan version the driver supports, e.g. `1.3.280`.
+  pub vulkan_version:      Option<String>,
+  /// Highest OpenGL version, preferring the core profile, e.g. `4.6`.
+  pub opengl_version:      Option<String>,
+  /// Highest Direct3D feature level, e.g. `12_2` (Windows only).
+  pub directx_version:     Option<String>,
+  /// Highest Metal feature set, e.g. `3` (macOS only).
+  pub metal_version:       Option<String>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayInfo {
+  pub id:            u64,
+  pub width:         u64,
+  pub height:        u64,
+  pub refresh_rate:  f64,
+  pub is_primary:    bool,
+  /// Horizontal offset of the top-left corner in the virtual desktop.
+  pub position_x:    i32,
+  /// Vertical offset of the top-left corner in the virtual desktop.
+  pub position_y:    i32,
+  /// Scaling factor applied by the OS or compositor (1.0 when unscaled).
+  pub scale_factor:  f64,
+  /// Physical pixel density, if the display reports its size.
+  pub dpi:           Option<f64>,
+  /// Color bits per pixel, excluding padding (24 for 8 bits per channel).
+  pub color_depth:   Option<u8>,
+  /// Clockwise rotation in degrees (0, 90, 180 or 270).
+  pub rotation:      u16,
+  pub connector:     DisplayConnector,
+  /// Vendor name decoded from the EDID, e.g. `Dell`.
+  pub manufacturer:  Option<String>,
+  /// Monitor model name, e.g. `DELL U2723QE`.
+  pub model:         Option<String>,
+  /// Serial number, if the monitor reports one.
+  pub serial:        Option<String>,
+  /// Widest gamut the display advertises (HDR monitors generally advertise
+  /// BT.2020 input).
+  pub gamut:         DisplayGamut,
+  /// Whether the display accepts HDR (PQ or HLG) signals.
+  pub hdr_supported: bool,
+  /// Whether HDR output is currently enabled, if the platform exposes it.
+  pub hdr_enabled:   Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BacklightInfo {
+  /// Backlight or display name, e.g. `intel_backlight` or `DP-1`.
+  pub device:  String,
+  /// Current brightness, from 0 to 100.
+  pub percent: f64,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkInterface {
+  pub name:            String,
+  pub ipv4_address:    Option<String>,
+  pub ipv6_address:    Option<String>,
+  pub mac_address:     Option<String>,
+  pub is_up:           bool,
+  pub is_loopback:     bool,
+  pub link_speed_mbps: Option<u64>,
+  pub mtu:             Option<u32>,
+  pub duplex:          Duplex,
+}
+
+/// Cumulative traffic counters of a network interface.
+///
+/// Counters are totals since the interface came up; sample twice and divide
+/// the difference by the elapsed time to get a transfer rate.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InterfaceStats {
+  pub name:       String,
+  pub rx_bytes:   u64,
+  pub tx_bytes:   u64,
+  pub rx_packets: u64,
+  pub tx_packets: u64,
+  pub rx_errors:  u64,
+  pub tx_errors:  u64,
+  pub rx_dropped: u64,
+  pub tx_dropped: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Battery {
+  pub status:              BatteryStatus,
+  pub percentage:          Option<u8>,
+  pub time_remaining_secs: Option<i64>,
+}
+
+/// One of the batteries returned by [`get_batteries`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatteryDevice {
+  /// Platform identifier such as `BAT0` or `InternalBattery-0`.
+  pub name:          Option<String>,
+  /// Whether this battery belongs to a peripheral (mouse, headset, ...).
+  pub is_peripheral: bool,
+  pub battery:       Battery,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerSource {
+  pub ac_connected:  bool,
+  /// Rated or negotiated wattage of the connected charger.
+  pub charger_watts: Option<f64>,
+  /// Power negotiated over USB Power Delivery, if the charger is a USB-PD
+  /// source.
+  pub usb_pd_watts:  Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerProfile {
+  pub mode:     PowerMode,
+  /// The platform's name for the profile, e.g. `power-saver` or a Windows
+  /// power plan name.
+  pub name:     Option<String>,
+  /// The cpufreq scaling governor, e.g. `schedutil`. Linux only.
+  pub governor: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessInfo {
+  pub pid:         u32,
+  pub ppid:        u32,
+  pub name:        String,
+  pub cmdline:     String,
+  /// Average CPU usage over the process's lifetime, where 100.0 is one full core.
+  pub cpu_percent: f64,
+  pub rss_bytes:   u64,
+  pub state:       ProcessState,
+  pub user:        Option<String>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitSystem {
+  pub kind: InitSystemKind,
+  /// Name of the init process, e.g. `systemd` or `launchd`.
+  pub name: String,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceInfo {
+  /// Service name as the init system knows it, e.g. `sshd.service`.
+  pub name:        String,
+  pub state:       ServiceState,
+  /// Human-readable description or display name.
+  pub description: Option<String>,
+  /// Whether the service is started at boot, if the init system reports it.
+  pub enabled:     Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContainerInfo {
+  pub runtime:      ContainerRuntime,
+  pub state:        ContainerState,
+  /// Runtime-assigned container ID. LXC containers use their name.
+  pub id:           String,
+  pub name:         String,
+  /// Image the container was created from. Always `None` for LXC.
+  pub image:        Option<String>,
+  /// Average CPU usage over the container's lifetime, where 100.0 is one
+  /// full core. Linux only.
+  pub cpu_percent:  Option<f64>,
+  /// Memory charged to the container's cgroup. Linux only.
+  pub memory_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionInfo {
+  pub username:     String,
+  /// TTY, seat, or window station name (e.g. `tty1`, `pts/0`, `Console`).
+  pub terminal:     String,
+  /// Host the session is connected from, for remote sessions.
+  pub remote_host:  Option<String>,
+  pub login_time:   Option<std::time::SystemTime>,
+  pub session_type: SessionType,
+}
+
+/// The display server of the current session.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplaySession {
+  pub server:     DisplayServer,
+  /// Wayland compositor name (e.g. `KWin`, `Hyprland`), if on Wayland and detectable.
+  pub compositor: Option<String>,
+}
+
+/// Desktop appearance settings of the current user.
+///
+/// Each field is `None` if the platform or desktop doesn't expose it.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThemeInfo {
+  /// GTK theme, Qt widget style, or light/dark mode (e.g. `Breeze`, `Dark`).
+  pub widget_theme: Option<String>,
+  pub icon_theme:   Option<String>,
+  pub cursor_theme: Option<String>,
+  /// System UI font as "Family Size".
+  pub font:         Option<String>,
+}
+
+/// The user's preferred applications for common roles.
+///
+/// Each field is `None` if no preference could be determined.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefaultApplications {
+  /// Text editor, usually a command from `$VISUAL` or `$EDITOR`.
+  pub editor:   Option<String>,
+  pub browser:  Option<String>,
+  pub terminal: Option<String>,
+}
+
+/// An audio output (sink) or input (source) device.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AudioDevice {
+  pub direction:   AudioDirection,
+  /// Stable backend identifier (PulseAudio name, CoreAudio UID, or WASAPI endpoint ID).
+  pub id:          String,
+  pub name:        String,
+  pub is_default:  bool,
+  pub channels:    Option<u32>,
+  pub sample_rate: Option<u32>,
+}
+
+/// Volume of the default audio output device.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VolumeInfo {
+  /// Volume as a percentage; may exceed 100 where the backend allows amplification.
+  pub percent: f64,
+  pub muted:   bool,
+}
+
+/// A local Bluetooth controller.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BluetoothAdapter {
+  pub name:    String,
+  /// Hardware address, e.g. `AA:BB:CC:DD:EE:FF`.
+  pub address: String,
+  pub powered: bool,
+}
+
+/// A paired or connected remote Bluetooth device.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BluetoothDevice {
+  pub name:            String,
+  /// Hardware address, e.g. `AA:BB:CC:DD:EE:FF`.
+  pub address:         String,
+  pub paired:          bool,
+  pub connected:       bool,
+  /// Battery level reported by the device (Linux/BlueZ only).
+  pub battery_percent: Option<u8>,
+}
+
+/// Bluetooth adapters and the devices they know about.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BluetoothInfo {
+  pub adapters: Vec<BluetoothAdapter>,
+  pub devices:  Vec<BluetoothDevice>,
+}
+
+/// A configured printer or print queue.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrinterInfo {
+  pub state:      PrinterState,
+  /// Queue name as used by `lp -d` or the Windows printer name.
+  pub name:       String,
+  /// Make and model (CUPS) or driver name (Windows).
+  pub driver:     Option<String>,
+  /// Device URI (CUPS), e.g. `ipp://printer.local/ipp/print`, or port name (Windows).
+  pub connection: Option<String>,
+  pub is_default: bool,
+}
+
+/// A video capture device, such as a webcam.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraInfo {
+  pub name:   String,
+  /// Device node on Linux (e.g. `/dev/video0`), AVFoundation unique ID on
+  /// macOS, or device interface path on Windows.
+  pub id:     String,
+  /// Whether another process is currently capturing from the device.
+  pub in_use: bool,
+}
+
+/// Connection details of the active Wi-Fi interface.
+///
+/// Fields the platform doesn't report are `None`; on macOS the SSID and BSSID
+/// require Location Services permission.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WifiInfo {
+  /// Name of the wireless interface; matches [`NetworkInterface::name`].
+  pub interface_name: String,
+  pub ssid:           Option<String>,
+  /// Access point hardware address, e.g. `AA:BB:CC:DD:EE:FF`.
+  pub bssid:          Option<String>,
+  pub signal_dbm:     Option<i32>,
+  pub signal_percent: Option<u8>,
+  pub frequency_mhz:  Option<u32>,
+  pub band:           WifiBand,
+  pub link_rate_mbps: Option<f64>,
+}
+
+/// The virtualization and containerization the process runs under.
+///
+/// The two are independent: a Docker container inside a KVM guest reports
+/// both.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VirtEnv {
+  pub hypervisor:        Hypervisor,
+  pub container:         Container,
+  /// Raw vendor identification, e.g. the CPUID signature `KVMKVMKVM`.
+  pub hypervisor_vendor: Option<String>,
+}
+
+impl VirtEnv {
+  /// Whether the process runs directly on physical hardware.
+  pub fn is_bare_metal(&self) -> bool {
+    self.hypervisor == Hypervisor::None && self.container == Container::None
+  }
+}
+
+/// The boot-integrity features of the platform: Secure Boot and the TPM.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecurityPlatformInfo {
+  pub secure_boot:   SecureBootState,
+  pub tpm_present:   bool,
+  /// TPM specification version, `2.0` or `1.2`.
+  pub tpm_version:   Option<String>,
+  /// Whether the firmware recorded a TPM event log of the boot. On Linux
+  /// the log is usually only visible to root.
+  pub measured_boot: bool,
+}
+
+/// The mandatory access control framework the kernel enforces.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MacStatus {
+  pub framework: MacFramework,
+  pub mode:      MacMode,
+  /// Loaded SELinux policy type, e.g. `targeted`. Always `None` for
+  /// AppArmor, which has no single policy name.
+  pub policy:    Option<String>,
+}
+
+/// A set of firewall rules that can be switched on or off as a unit.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirewallProfile {
+  /// Profile name, e.g. `Public` on Windows or the firewalld zone.
+  pub name:    String,
+  pub enabled: bool,
+}
+
+/// The host firewall and its profiles.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirewallStatus {
+  pub backend:  FirewallBackend,
+  pub profiles: Vec<FirewallProfile>,
+}
+
+impl FirewallStatus {
+  /// Whether any profile of the firewall is enabled.
+  pub fn is_enabled(&self) -> bool {
+    self.profiles.iter().any(|profile| profile.enabled)
+  }
+}
+
+/// Active Directory domain or workgroup membership, and Azure AD join state.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DomainInfo {
+  pub membership:      DomainMembership,
+  /// NetBIOS name of the domain or workgroup, e.g. `CORP` or `WORKGROUP`.
+  pub name:            Option<String>,
+  /// DNS name of the Active Directory domain, e.g. `corp.example.com`.
+  pub dns_domain:      Option<String>,
+  /// Whether the machine is joined to Azure AD, alone or hybrid with a
+  /// domain. Devices only registered through a work account don't count.
+  pub azure_ad_joined: bool,
+  /// Display name of the Azure AD tenant.
+  pub azure_tenant:    Option<String>,
+}
+
+/// The motherboard and the system firmware (BIOS/UEFI).
+///
+/// Fields the firmware leaves as an OEM placeholder are `None`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirmwareInfo {
+  pub board_vendor:  Option<String>,
+  pub board_model:   Option<String>,
+  pub board_version: Option<String>,
+  pub vendor:        Option<String>,
+  pub version:       Option<String>,
+  /// Release date as the firmware reports it, e.g. `04/27/2022`.
+  pub release_date:  Option<String>,
+  pub is_uefi:       bool,
+}
+
+/// The next hop of the system's default route.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gateway {
+  pub address:        String,
+  pub interface_name: String,
+}
+
+/// Public addresses this machine is seen as on the internet.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PublicIp {
+  pub v4: Option<String>,
+  pub v6: Option<String>,
+}
+
+/// The cloud provider this machine runs on, and the instance it runs as.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloudInfo {
+  pub provider:      CloudProvider,
+  /// Instance type, e.g. `t3.micro`; not every provider exposes it.
+  pub instance_type: Option<String>,
+  pub region:        Option<String>,
+}
+
+/// The user account the current process runs as.
+///
+/// POSIX systems set `uid`/`gid`, while Windows sets `sid` instead.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserInfo {
+  pub username:       String,
+  pub uid:            Option<u32>,
+  pub gid:            Option<u32>,
+  pub sid:            Option<String>,
+  pub home_directory: String,
+  pub shell:          Option<String>,
+}
+
+/// The system's local timezone.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timezone {
+  /// IANA timezone name, e.g. `Europe/Berlin`.
+  pub name:               String,
+  /// Offset from UTC currently in effect, including DST.
+  pub utc_offset_seconds: i32,
+}
+
+/// A configured keyboard layout: an XKB layout, a Windows input locale, or a
+/// macOS input source.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyboardLayout {
+  /// Layout identifier, e.g. `us` (XKB), `en-US` (Windows), or `U.S.` (macOS).
+  pub name:        String,
+  /// Layout variant, e.g. `dvorak`.
+  pub variant:     Option<String>,
+  /// Human-readable name, if the platform provides one.
+  pub description: Option<String>,
+  /// Whether this is the layout currently in use.
+  pub active:      bool,
+}
+
+/// Handle to the library's cache.
+///
+/// The cache synchronizes internally, so one manager can be shared by
+/// reference across threads; concurrent requests for the same key are
+/// fetched once.
+///
+/// Slow, rarely changing values such as the GPU and host model are also
+/// persisted to the user's cache directory and reused by later processes.
+/// Persisted values are tagged with a fingerprint of the hardware and
+/// discarded if it has changed since.
+pub struct CacheManager {
+  handle: *mut sys::DracCacheManager,
+}
+
+// The C++ cache guards its state with a mutex and never hands out references
+// into it, so the handle may be moved to and used from any thread.
+unsafe impl Send for CacheManager {}
+unsafe impl Sync for CacheManager {}
+
+impl CacheManager {
+  pub fn new() -> Self {
+    let handle = unsafe { sys::DracCreateCacheManager() };
+    assert!(!handle.is_null(), "Failed to create cache manager");
+    Self { handle }
+  }
+}
+
+impl CacheManager {
+  /// Overrides how long cached values in `domain` stay fresh, regardless of
+  /// each getter's own policy.
+  ///
+  /// The TTL is measured from when each value was stored, so it applies to
+  /// values already cached too. A zero duration makes every call fetch anew.
+  ///
+  /// ```ignore
+  /// cache.set_ttl(CacheDomain::Network, Duration::from_secs(5))?;
+  /// cache.set_ttl(CacheDomain::Cpu, CacheTtl::NeverExpire)?;
+  /// ```
+  pub fn set_ttl(&self, domain: CacheDomain, ttl: impl Into<CacheTtl>) -> Result<()> {
+    let result = match ttl.into() {
+      CacheTtl::After(ttl) => {
+        let secs = u32::try_from(ttl.as_secs()).unwrap_or(u32::MAX);
+        unsafe { sys::DracSetCacheTtl(self.handle, domain.to_c() as _, secs) }
+      }
+      CacheTtl::NeverExpire => unsafe {
+        sys::DracSetCacheNeverExpire(self.handle, domain.to_c() as _)
+      },
+    };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "CacheManager::set_ttl"))
+    }
+  }
+
+  /// Removes an override set with [`CacheManager::set_ttl`], so the domain's
+  /// getters use their own policies again.
+  pub fn reset_ttl(&self, domain: CacheDomain) -> Result<()> {
+    let result = unsafe { sys::DracResetCacheTtl(self.handle, domain.to_c() as _) };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "CacheManager::reset_ttl"))
+    }
+  }
+
+  /// Removes every cached value in `domain`, in memory and on disk, so the
+  /// next call fetches anew. Useful after the application learns something
+  /// changed, e.g. from a [`Watcher`].
+  pub fn invalidate(&self, domain: CacheDomain) -> Result<()> {
+    let result = unsafe { sys::DracInvalidateCache(self.handle, domain.to_c() as _) };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "CacheManager::invalidate"))
+    }
+  }
+
+  /// Removes every cached value, in memory and on disk.
+  ///
+  /// On-disk entries are shared, so this also affects other cache managers
+  /// and processes.
+  pub fn invalidate_all(&self) -> Result<()> {
+    let result = unsafe { sys::DracInvalidateAllCaches(self.handle) };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "CacheManager::invalidate_all"))
+    }
+  }
+
+  /// Starts fetching the cached values in `domains` on a background thread,
+  /// so the first paint doesn't block on slow system queries.
+  ///
+  /// Domains are fetched one at a time, in order. Dropping the cache waits for
+  /// the domain in progress to finish.
+  ///
+  /// ```ignore
+  /// let cache = CacheManager::new();
+  /// cache.prefetch(&[CacheDomain::Gpu, CacheDomain::Hardware])?;
+  /// // ...set up the UI...
+  /// if cache.is_ready(CacheDomain::Gpu)? {
+  ///   println!("{}", cache.gpu_model()?);
+  /// }
+  /// ```
+  pub fn prefetch(&self, domains: &[CacheDomain]) -> Result<()> {
+    let domains: Vec<_> = domains.iter().map(|domain| domain.to_c() as _).collect();
+    let result = unsafe { sys::DracPrefetchCache(self.handle, domains.as_ptr(), domains.len()) };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "CacheManager::prefetch"))
+    }
+  }
+
+  /// Whether a [`CacheManager::prefetch`] of `domain` has finished, whether
+  /// or not its fetches succeeded. Domains never prefetched are not ready.
+  pub fn is_ready(&self, domain: CacheDomain) -> Result<bool> {
+    let mut ready = false;
+    let result = unsafe { sys::DracIsCacheReady(self.handle, domain.to_c() as _, &mut ready) };
+
+    if result == DRAC_SUCCESS {
+      Ok(ready)
+    } else {
+      Err(DracError::last(result, "CacheManager::is_ready"))
+    }
+  }
+
+  /// Returns lookup counts since the cache was created, and when each domain
+  /// last fetched from the system.
+  pub fn stats(&self) -> Result<CacheStats> {
+    let mut stats = sys::DracCacheStats {
+      hits:         0,
+      misses:       0,
+      entries:      0,
+      refreshes:    std::ptr::null_mut(),
+      refreshCount: 0,
+    };
+
+    let result = unsafe { sys::DracGetCacheStats(self.handle, &mut stats) };
+
+    if result != DRAC_SUCCESS {
+      return Err(DracError::last(result, "CacheManager::stats"));
+    }
+
+    let last_refresh_per_domain = (0..stats.refreshCount)
+      .filter_map(|i| {
+        let refresh = unsafe { &*stats.refreshes.add(i) };
+        let secs = u64::try_from(refresh.refreshedAt).ok()?;
+
+        Some((
+          CacheDomain::from_c(refresh.domain)?,
+          std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs),
+        ))
+      })
+      .collect();
+
+    unsafe { sys::DracFreeCacheStats(&mut stats) };
+
+    Ok(CacheStats {
+      hits: stats.hits,
+      misses: stats.misses,
+      entries: stats.entries,
+      last_refresh_per_domain,
+    })
+  }
+}
+
+/// Groups of cached values whose TTL can be set together with
+/// [`CacheManager::set_ttl`].
+///
+/// Memory, disk, battery, process, and other live metrics are never cached,
+/// so they have no domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CacheDomain {
+  /// CPU model, cores, details, caches, and vulnerabilities.
+  Cpu,
+  /// GPU model and graphics API devices.
+  Gpu,
+  /// Primary network interface and public IP.
+  Network,
+  /// OS version, kernel, kernel command line, and init system.
+  System,
+  /// Host model, firmware, serial, machine ID, virtualization, security
+  /// platform, and cloud provider.
+  Hardware,
+  /// Desktop environment, window manager, display session, displays, theme,
+  /// terminal, and shell.
+  Desktop,
+  /// Package counts and pending updates.
+  Packages,
+}
+
+impl CacheDomain {
+  fn from_c(domain: DracCacheDomain) -> Option<Self> {
+    match domain {
+      DRAC_CACHE_DOMAIN_CPU => Some(CacheDomain::Cpu),
+      DRAC_CACHE_DOMAIN_GPU => Some(CacheDomain::Gpu),
+      DRAC_CACHE_DOMAIN_NETWORK => Some(CacheDomain::Network),
+      DRAC_CACHE_DOMAIN_SYSTEM => Some(CacheDomain::System),
+      DRAC_CACHE_DOMAIN_HARDWARE => Some(CacheDomain::Hardware),
+      DRAC_CACHE_DOMAIN_DESKTOP => Some(CacheDomain::Desktop),
+      DRAC_CACHE_DOMAIN_PACKAGES => Some(CacheDomain::Packages),
+      _ => None,
+    }
+  }
+
+  fn to_c(self) -> DracCacheDomain {
+    match self {
+      CacheDomain::Cpu => DRAC_CACHE_DOMAIN_CPU,
+      CacheDomain::Gpu => DRAC_CACHE_DOMAIN_GPU,
+      CacheDomain::Network => DRAC_CACHE_DOMAIN_NETWORK,
+      CacheDomain::System => DRAC_CACHE_DOMAIN_SYSTEM,
+      CacheDomain::Hardware => DRAC_CACHE_DOMAIN_HARDWARE,
+      CacheDomain::Desktop => DRAC_CACHE_DOMAIN_DESKTOP,
+      CacheDomain::Packages => DRAC_CACHE_DOMAIN_PACKAGES,
+    }
+  }
+}
+
+/// A snapshot of a [`CacheManager`]'s effectiveness, from
+/// [`CacheManager::stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheStats {
+  /// Lookups answered from memory, disk, or another caller's concurrent
+  /// fetch.
+  pub hits:                    u64,
+  /// Lookups that fetched from the system.
+  pub misses:                  u64,
+  /// Values currently held in memory.
+  pub entries:                 usize,
+  /// When each domain last fetched from the system, to the second. Domains
+  /// that haven't fetched yet are absent.
+  pub last_refresh_per_domain: std::collections::HashMap<CacheDomain, std::time::SystemTime>,
+}
+
+/// How long cached values in a [`CacheDomain`] stay fresh.
+///
+/// Durations are whole seconds; anything shorter than a second behaves like
+/// zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheTtl {
+  After(std::time::Duration),
+  NeverExpire,
+}
+
+impl From<std::time::Duration> for CacheTtl {
+  fn from(ttl: std::time::Duration) -> Self {
+    CacheTtl::After(ttl)
+  }
+}
+
+impl Default for CacheManager {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Drop for CacheManager {
+  fn drop(&mut self) {
+    unsafe {
+      sys::DracDestroyCacheManager(self.handle);
+    }
+  }
+}
+
+/// Forwards each listed getter as a method on [`CacheManager`], so callers
+/// holding a cache (or [`global`]) can write `cache.cpu_model()`.
+macro_rules! cache_methods {
+  ($($method:ident => $getter:ident: $ty:ty;)*) => {
+    impl CacheManager {
+      $(
+        #[doc = concat!("See [`", stringify!($getter), "`].")]
+        pub fn $method(&self) -> Result<$ty> {
+          $getter(self)
+        }
+      )*
+    }
+  };
+}
+
+cache_methods! {
+  processes => get_processes: Vec<ProcessInfo>;
+  init_system => get_init_system: InitSystem;
+  containers => get_containers: Vec<ContainerInfo>;
+  mem_info => get_mem_info: ResourceUsage;
+  swap_info => get_swap_info: ResourceUsage;
+  memory_details => get_memory_details: MemoryDetails;
+  cpu_cores => get_cpu_cores: CPUCores;
+  cpu_frequency => get_cpu_frequency: CpuFrequency;
+  cpu_details => get_cpu_details: CpuDetails;
+  cpu_caches => get_cpu_caches: Vec<CpuCacheInfo>;
+  numa_topology => get_numa_topology: Vec<NumaNode>;
+  cpu_vulnerabilities => get_cpu_vulnerabilities: Vec<CpuVulnerability>;
+  cpu_temperature => get_cpu_temperature: f64;
+  fans => get_fans: Vec<FanInfo>;
+  sensors => get_sensors: Vec<SensorReading>;
+  cpu_power_watts => get_cpu_power_watts: f64;
+  operating_system => get_operating_system: OSInfo;
+  battery_info => get_battery_info: Battery;
+  batteries => get_batteries: Vec<BatteryDevice>;
+  power_source => get_power_source: PowerSource;
+  power_profile => get_power_profile: PowerProfile;
+  pending_updates => get_pending_updates: u64;
+  cpu_model => get_cpu_model: String;
+  gpu_model => get_gpu_model: String;
+  gpus => get_gpus: Vec<GpuInfo>;
+  desktop_environment => get_desktop_environment: String;
+  window_manager => get_window_manager: String;
+  session_type => get_session_type: DisplaySession;
+  theme_info => get_theme_info: ThemeInfo;
+  audio_devices => get_audio_devices: Vec<AudioDevice>;
+  volume => get_volume: VolumeInfo;
+  shell => get_shell: String;
+  shell_os => get_shell_os: OsString;
+  terminal => get_terminal: String;
+  terminal_os => get_terminal_os: OsString;
+  terminal_font => get_terminal_font: String;
+  host => get_host: String;
+  domain_info => get_domain_info: DomainInfo;
+  firmware_info => get_firmware_info: FirmwareInfo;
+  machine_id => get_machine_id: String;
+  hardware_serial => get_hardware_serial: String;
+  virtualization => get_virtualization: VirtEnv;
+  security_platform_info => get_security_platform_info: SecurityPlatformInfo;
+  mac_status => get_mac_status: MacStatus;
+  firewall_status => get_firewall_status: FirewallStatus;
+  kernel_version => get_kernel_version: String;
+  kernel_cmdline => get_kernel_cmdline: String;
+  kernel_modules => get_kernel_modules: Vec<KernelModule>;
+  disk_usage => get_disk_usage: ResourceUsage;
+  disks => get_disks: Vec<DiskInfo>;
+  system_disk => get_system_disk: DiskInfo;
+  disk_temperatures => get_disk_temperatures: Vec<DiskTemperature>;
+  disk_io_stats => get_disk_io_stats: Vec<DiskIOStats>;
+  network_mounts => get_network_mounts: Vec<NetworkMount>;
+  outputs => get_outputs: Vec<DisplayInfo>;
+  primary_output => get_primary_output: DisplayInfo;
+  brightness => get_brightness: Vec<BacklightInfo>;
+  network_interfaces => get_network_interfaces: Vec<NetworkInterface>;
+  network_stats => get_network_stats: Vec<InterfaceStats>;
+  primary_network_interface => get_primary_network_interface: NetworkInterface;
+  bluetooth_devices => get_bluetooth_devices: BluetoothInfo;
+  printers => get_printers: Vec<PrinterInfo>;
+  cameras => get_cameras: Vec<CameraInfo>;
+  wifi_info => get_wifi_info: WifiInfo;
+  default_gateway => get_default_gateway: Gateway;
+  dns_servers => get_dns_servers: Vec<String>;
+  public_ip => get_public_ip: PublicIp;
+  cloud_info => get_cloud_info: CloudInfo;
+}
+
+impl CacheManager {
+  /// See [`collect_fields`].
+  pub fn collect_fields(&self, mask: FieldMask) -> Result<FieldSet> {
+    collect_fields(self, mask)
+  }
+}
+
+impl CacheManager {
+  /// Borrows a string owned by the cache, which keeps every value it has
+  /// returned until it is dropped. Only values that aren't valid UTF-8 are
+  /// copied.
+  fn string_ref(&self, field: DracStringField, source_fn: &'static str) -> Result<Cow<'_, str>> {
+    let mut ptr = std::ptr::null();
+    let result = unsafe { sys::DracGetStringRef(self.handle, field as _, &mut ptr) };
+
+    if result == DRAC_SUCCESS && !ptr.is_null() {
+      Ok(unsafe { CStr::from_ptr(ptr) }.to_string_lossy())
+    } else {
+      Err(DracError::last(result, source_fn))
+    }
+  }
+}
+
+/// Adds a borrowing variant of each listed string method to [`CacheManager`],
+/// for widgets that poll at several hertz and shouldn't allocate every frame.
+///
+/// The cache keeps each distinct value for its whole lifetime, so these suit
+/// values that rarely change.
+macro_rules! cache_string_refs {
+  ($($method:ident => $owned:ident: $field:ident;)*) => {
+    impl CacheManager {
+      $(
+        #[doc = concat!(
+          "Like [`CacheManager::", stringify!($owned), "`], but borrows the ",
+          "string from the cache instead of allocating a copy."
+        )]
+        pub fn $method(&self) -> Result<Cow<'_, str>> {
+          self.string_ref($field, stringify!($method))
+        }
+      )*
+    }
+  };
+}
+
+cache_string_refs! {
+  cpu_model_ref => cpu_model: DRAC_STRING_CPU_MODEL;
+  gpu_model_ref => gpu_model: DRAC_STRING_GPU_MODEL;
+  host_ref => host: DRAC_STRING_HOST;
+  kernel_version_ref => kernel_version: DRAC_STRING_KERNEL_VERSION;
+  kernel_cmdline_ref => kernel_cmdline: DRAC_STRING_KERNEL_CMDLINE;
+  desktop_environment_ref => desktop_environment: DRAC_STRING_DESKTOP_ENVIRONMENT;
+  window_manager_ref => window_manager: DRAC_STRING_WINDOW_MANAGER;
+  shell_ref => shell: DRAC_STRING_SHELL;
+  terminal_ref => terminal: DRAC_STRING_TERMINAL;
+  terminal_font_ref => terminal_font: DRAC_STRING_TERMINAL_FONT;
+  machine_id_ref => machine_id: DRAC_STRING_MACHINE_ID;
+  hardware_serial_ref => hardware_serial: DRAC_STRING_HARDWARE_SERIAL;
+}
+
+/// Returns a process-wide cache, created on first use.
+///
+/// Meant for CLI tools and scripts that have no natural owner for a
+/// [`CacheManager`]; libraries should still take one by reference so callers
+/// control its lifetime.
+///
+/// ```ignore
+/// let cpu = draconis::global().cpu_model()?;
+/// ```
+pub fn global() -> &'static CacheManager {
+  static GLOBAL: std::sync::OnceLock<CacheManager> = std::sync::OnceLock::new();
+  GLOBAL.get_or_init(CacheManager::new)
+}
+
+/// Returns the system uptime in seconds, or 0 on error.
+///
+/// Prefer [`get_uptime_duration`], which makes the unit explicit.
+pub fn get_uptime() -> u64 {
+  unsafe { sys::DracGetUptime() }
+}
+
+/// Returns the system uptime, or a zero duration on error.
+pub fn get_uptime_duration() -> std::time::Duration {
+  std::time::Duration::from_secs(get_uptime())
+}
+
+/// Returns the time the system booted.
+///
+/// This is read from a value the kernel records at boot, so unlike
+/// `now() - get_uptime()` it returns the same instant on every call.
+pub fn get_boot_time() -> Result<std::time::SystemTime> {
+  let mut boot_time: i64 = 0;
+
+  let result = unsafe { sys::DracGetBootTime(&mut boot_time) };
+
+  if result == DRAC_SUCCESS {
+    let secs = u64::try_from(boot_time)
+      .map_err(|_| DracError::new(ErrorCode::CorruptedData, "get_boot_time"))?;
+    Ok(std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+  } else {
+    Err(DracError::last(result, "get_boot_time"))
+  }
+}
+
+/// Returns the 1, 5, and 15 minute load averages.
+///
+/// Windows doesn't track load averages, so this always fails there.
+pub fn get_load_average() -> Result<(f64, f64, f64)> {
+  let mut load = sys::DracLoadAverage {
+    oneMinute:      0.0,
+    fiveMinutes:    0.0,
+    fifteenMinutes: 0.0,
+  };
+
+  let result = unsafe { sys::DracGetLoadAverage(&mut load) };
+
+  if result == DRAC_SUCCESS {
+    Ok((load.oneMinute, load.fiveMinutes, load.fifteenMinutes))
+  } else {
+    Err(DracError::last(result, "get_load_average"))
+  }
+}
+
+fn process_info_from_c(process: &sys::DracProcessInfo) -> ProcessInfo {
+  ProcessInfo {
+    pid:         process.pid,
+    ppid:        process.ppid,
+    name:        unsafe { string_or_empty(process.name) },
+    cmdline:     unsafe { string_or_empty(process.cmdline) },
+    cpu_percent: process.cpuPercent,
+    rss_bytes:   process.rssBytes,
+    state:       ProcessState::from(process.state),
+    user:        unsafe { opt_string(process.user) },
+  }
+}
+
+/// Returns every running process.
+///
+/// CPU usage is averaged over each process's lifetime, so no sampling delay
+/// is needed. Processes that exit mid-scan are skipped.
+pub fn get_processes(cache: &CacheManager) -> Result<Vec<ProcessInfo>> {
+  let mut list = sys::DracProcessInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetProcesses(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let processes = (0..list.count)
+      .map(|i| process_info_from_c(unsafe { &*list.items.add(i) }))
+      .collect();
+
+    unsafe { sys::DracFreeProcessInfoList(&mut list) };
+    Ok(processes)
+  } else {
+    Err(DracError::last(result, "get_processes"))
+  }
+}
+
+/// Looks up a single process by PID without scanning the whole process table.
+pub fn get_process(pid: u32) -> Result<ProcessInfo> {
+  let mut process = sys::DracProcessInfo {
+    pid:        0,
+    ppid:       0,
+    name:       std::ptr::null_mut(),
+    cmdline:    std::ptr::null_mut(),
+    cpuPercent: 0.0,
+    rssBytes:   0,
+    state:      DRAC_PROCESS_UNKNOWN,
+    user:       std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetProcess(pid, &mut process) };
+
+  if result == DRAC_SUCCESS {
+    let info = process_info_from_c(&process);
+
+    unsafe { sys::DracFreeProcessInfo(&mut process) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_process"))
+  }
+}
+
+/// Finds every process with the given executable name.
+///
+/// On Linux the name is matched against the kernel's 15-character `comm`.
+/// On Windows the match is case-insensitive and `.exe` may be omitted.
+pub fn find_processes(name: &str) -> Result<Vec<ProcessInfo>> {
+  let c_name = std::ffi::CString::new(name)
+    .map_err(|_| DracError::new(ErrorCode::InvalidArgument, "find_processes"))?;
+
+  let mut list = sys::DracProcessInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracFindProcesses(c_name.as_ptr(), &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let processes = (0..list.count)
+      .map(|i| process_info_from_c(unsafe { &*list.items.add(i) }))
+      .collect();
+
+    unsafe { sys::DracFreeProcessInfoList(&mut list) };
+    Ok(processes)
+  } else {
+    Err(DracError::last(result, "find_processes"))
+  }
+}
+
+fn service_info_from_c(service: &sys::DracServiceInfo) -> ServiceInfo {
+  ServiceInfo {
+    name:        unsafe { string_or_empty(service.name) },
+    state:       ServiceState::from(service.state),
+    description: unsafe { opt_string(service.description) },
+    enabled:     match service.enabled {
+      -1 => None,
+      enabled => Some(enabled != 0),
+    },
+  }
+}
+
+/// Returns the init system or service manager running as PID 1.
+pub fn get_init_system(cache: &CacheManager) -> Result<InitSystem> {
+  let mut init = sys::DracInitSystem {
+    kind: DRAC_INIT_SYSTEM_UNKNOWN,
+    name: std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetInitSystem(cache.handle, &mut init) };
+
+  if result == DRAC_SUCCESS {
+    let init_system = InitSystem {
+      kind: InitSystemKind::from(init.kind),
+      name: unsafe { string_or_empty(init.name) },
+    };
+
+    unsafe { sys::DracFreeInitSystem(&mut init) };
+    Ok(init_system)
+  } else {
+    Err(DracError::last(result, "get_init_system"))
+  }
+}
+
+/// Looks up the state of a single service.
+///
+/// For systemd a missing `.service` suffix is added. Fails with
+/// [`ErrorCode::NotFound`] if the service does not exist.
+pub fn get_service_status(name: &str) -> Result<ServiceInfo> {
+  let c_name = std::ffi::CString::new(name)
+    .map_err(|_| DracError::new(ErrorCode::InvalidArgument, "get_service_status"))?;
+
+  let mut service = sys::DracServiceInfo {
+    state:       DRAC_SERVICE_STATE_UNKNOWN,
+    name:        std::ptr::null_mut(),
+    description: std::ptr::null_mut(),
+    enabled:     -1,
+  };
+
+  let result = unsafe { sys::DracGetServiceStatus(c_name.as_ptr(), &mut service) };
+
+  if result == DRAC_SUCCESS {
+    let info = service_info_from_c(&service);
+
+    unsafe { sys::DracFreeServiceInfo(&mut service) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_service_status"))
+  }
+}
+
+/// Returns every service known to the init system.
+///
+/// Fails with [`ErrorCode::NotSupported`] for init systems that cannot be
+/// queried, such as runit or s6.
+pub fn list_services() -> Result<Vec<ServiceInfo>> {
+  let mut list = sys::DracServiceInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracListServices(&mut list) };
+
+  if result == DRAC_SUCCESS {
+    let services = (0..list.count)
+      .map(|i| service_info_from_c(unsafe { &*list.items.add(i) }))
+      .collect();
+
+    unsafe { sys::DracFreeServiceInfoList(&mut list) };
+    Ok(services)
+  } else {
+    Err(DracError::last(result, "list_services"))
+  }
+}
+
+/// Returns the running Docker, Podman, and LXC containers.
+///
+/// The daemons are queried over their API sockets, so this fails with
+/// [`ErrorCode::PermissionDenied`] when the user can't access the Docker
+/// socket, and with [`ErrorCode::NotFound`] when no runtime is running.
+pub fn get_containers(cache: &CacheManager) -> Result<Vec<ContainerInfo>> {
+  let mut list = sys::DracContainerInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetContainers(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let containers = (0..list.count)
+      .map(|i| {
+        let container = unsafe { &*list.items.add(i) };
+
+        ContainerInfo {
+          runtime:      ContainerRuntime::from(container.runtime),
+          state:        ContainerState::from(container.state),
+          id:           unsafe { string_or_empty(container.id) },
+          name:         unsafe { string_or_empty(container.name) },
+          image:        unsafe { opt_string(container.image) },
+          cpu_percent:  (!container.cpuPercent.is_nan()).then_some(container.cpuPercent),
+          memory_bytes: u64::try_from(container.memoryBytes).ok(),
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeContainerInfoList(&mut list) };
+    Ok(containers)
+  } else {
+    Err(DracError::last(result, "get_containers"))
+  }
+}
+
+/// Returns the logged-in user sessions.
+///
+/// On Unix this reads the utmpx database, so a terminal emulator inside a
+/// desktop session shows up as its own terminal session.
+pub fn get_sessions() -> Result<Vec<SessionInfo>> {
+  let mut list = sys::DracSessionInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetSessions(&mut list) };
+
+  if result == DRAC_SUCCESS {
+    let sessions = (0..list.count)
+      .map(|i| {
+        let session = unsafe { &*list.items.add(i) };
+
+        SessionInfo {
+          username:     unsafe { string_or_empty(session.username) },
+          terminal:     unsafe { string_or_empty(session.terminal) },
+          remote_host:  unsafe { opt_string(session.remoteHost) },
+          login_time:   u64::try_from(session.loginTime)
+            .ok()
+            .map(|secs| std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs)),
+          session_type: SessionType::from(session.type_),
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeSessionInfoList(&mut list) };
+    Ok(sessions)
+  } else {
+    Err(DracError::last(result, "get_sessions"))
+  }
+}
+
+pub fn get_current_user() -> Result<UserInfo> {
+  let mut user = sys::DracUserInfo {
+    username:      std::ptr::null_mut(),
+    uid:           -1,
+    gid:           -1,
+    sid:           std::ptr::null_mut(),
+    homeDirectory: std::ptr::null_mut(),
+    shell:         std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetCurrentUser(&mut user) };
+
+  if result == DRAC_SUCCESS {
+    let info = UserInfo {
+      username:       unsafe { string_or_empty(user.username) },
+      uid:            u32::try_from(user.uid).ok(),
+      gid:            u32::try_from(user.gid).ok(),
+      sid:            unsafe { opt_string(user.sid) },
+      home_directory: unsafe { string_or_empty(user.homeDirectory) },
+      shell:          unsafe { opt_string(user.shell) },
+    };
+
+    unsafe { sys::DracFreeUserInfo(&mut user) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_current_user"))
+  }
+}
+
+/// Returns the user's locale in the platform's native format, e.g.
+/// `en_US.UTF-8` on Linux or `en-US` on Windows.
+pub fn get_locale() -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetLocale(&mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_locale"))
+  }
+}
+
+pub fn get_timezone() -> Result<Timezone> {
+  let mut tz = sys::DracTimezoneInfo {
+    name:             std::ptr::null_mut(),
+    utcOffsetSeconds: 0,
+  };
+
+  let result = unsafe { sys::DracGetTimezone(&mut tz) };
+
+  if result == DRAC_SUCCESS {
+    let info = Timezone {
+      name:               unsafe { string_or_empty(tz.name) },
+      utc_offset_seconds: tz.utcOffsetSeconds,
+    };
+
+    unsafe { sys::DracFreeTimezoneInfo(&mut tz) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_timezone"))
+  }
+}
+
+/// Returns the configured keyboard layouts, in their configured order, and
+/// which one is active.
+///
+/// This is never cached, since the active layout can change at any time.
+/// Layouts read from configuration files are never marked active.
+pub fn get_keyboard_layouts() -> Result<Vec<KeyboardLayout>> {
+  let mut list = sys::DracKeyboardLayoutList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetKeyboardLayouts(&mut list) };
+
+  if result == DRAC_SUCCESS {
+    let layouts = (0..list.count)
+      .map(|i| {
+        let layout = unsafe { &*list.items.add(i) };
+
+        KeyboardLayout {
+          name:        unsafe { string_or_empty(layout.name) },
+          variant:     unsafe { opt_string(layout.variant) },
+          description: unsafe { opt_string(layout.description) },
+          active:      layout.active,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeKeyboardLayoutList(&mut list) };
+    Ok(layouts)
+  } else {
+    Err(DracError::last(result, "get_keyboard_layouts"))
+  }
+}
+
+pub fn get_mem_info(cache: &CacheManager) -> Result<ResourceUsage> {
+  let mut usage = sys::DracResourceUsage {
+    usedBytes:  0,
+    totalBytes: 0,
+  };
+
+  let result = unsafe { sys::DracGetMemInfo(cache.handle, &mut usage) };
+
+  if result == DRAC_SUCCESS {
+    Ok(ResourceUsage {
+      used_bytes:  usage.usedBytes,
+      total_bytes: usage.totalBytes,
+    })
+  } else {
+    Err(DracError::last(result, "get_mem_info"))
+  }
+}
+
+pub fn get_swap_info(cache: &CacheManager) -> Result<ResourceUsage> {
+  let mut usage = sys::DracResourceUsage {
+    usedBytes:  0,
+    totalBytes: 0,
+  };
+
+  let result = unsafe { sys::DracGetSwapInfo(cache.handle, &mut usage) };
+
+  if result == DRAC_SUCCESS {
+    Ok(ResourceUsage {
+      used_bytes:  usage.usedBytes,
+      total_bytes: usage.totalBytes,
+    })
+  } else {
+    Err(DracError::last(result, "get_swap_info"))
+  }
+}
+
+pub fn get_memory_details(cache: &CacheManager) -> Result<MemoryDetails> {
+  let mut details = sys::DracMemoryDetails {
+    totalBytes:     0,
+    usedBytes:      0,
+    freeBytes:      0,
+    availableBytes: 0,
+    cachedBytes:    0,
+    buffersBytes:   0,
+    sharedBytes:    0,
+    kernelBytes:    0,
+  };
+
+  let result = unsafe { sys::DracGetMemoryDetails(cache.handle, &mut details) };
+
+  if result == DRAC_SUCCESS {
+    Ok(MemoryDetails {
+      total_bytes:     details.totalBytes,
+      used_bytes:      details.usedBytes,
+      free_bytes:      details.freeBytes,
+      available_bytes: details.availableBytes,
+      cached_bytes:    details.cachedBytes,
+      buffers_bytes:   details.buffersBytes,
+      shared_bytes:    details.sharedBytes,
+      kernel_bytes:    details.kernelBytes,
+    })
+  } else {
+    Err(DracError::last(result, "get_memory_details"))
+  }
+}
+
+pub fn get_cpu_cores(cache: &CacheManager) -> Result<CPUCores> {
+  let mut cores = sys::DracCPUCores {
+    physical: 0,
+    logical:  0,
+  };
+
+  let result = unsafe { sys::DracGetCpuCores(cache.handle, &mut cores) };
+
+  if result == DRAC_SUCCESS {
+    Ok(CPUCores {
+      physical: cores.physical,
+      logical:  cores.logical,
+    })
+  } else {
+    Err(DracError::last(result, "get_cpu_cores"))
+  }
+}
+
+pub fn get_cpu_frequency(cache: &CacheManager) -> Result<CpuFrequency> {
+  let mut freq = sys::DracCPUFrequency {
+    currentMhz: 0,
+    baseMhz:    0,
+    maxMhz:     0,
+  };
+
+  let result = unsafe { sys::DracGetCPUFrequency(cache.handle, &mut freq) };
+
+  if result == DRAC_SUCCESS {
+    let non_zero = |mhz: u64| if mhz == 0 { None } else { Some(mhz) };
+
+    Ok(CpuFrequency {
+      current_mhz: non_zero(freq.currentMhz),
+      base_mhz:    non_zero(freq.baseMhz),
+      max_mhz:     non_zero(freq.maxMhz),
+    })
+  } else {
+    Err(DracError::last(result, "get_cpu_frequency"))
+  }
+}
+
+/// Returns the CPU's architecture, vendor, family/model/stepping, and feature
+/// flags.
+pub fn get_cpu_details(cache: &CacheManager) -> Result<CpuDetails> {
+  let mut details = sys::DracCPUDetails {
+    architecture: std::ptr::null_mut(),
+    vendor:       std::ptr::null_mut(),
+    family:       -1,
+    model:        -1,
+    stepping:     -1,
+    flags:        std::ptr::null_mut(),
+    flagCount:    0,
+  };
+
+  let result = unsafe { sys::DracGetCPUDetails(cache.handle, &mut details) };
+
+  if result == DRAC_SUCCESS {
+    let cpu_details = CpuDetails {
+      architecture: unsafe { string_or_empty(details.architecture) },
+      vendor:       unsafe { opt_string(details.vendor) },
+      family:       u32::try_from(details.family).ok(),
+      model:        u32::try_from(details.model).ok(),
+      stepping:     u32::try_from(details.stepping).ok(),
+      flags:        (0..details.flagCount)
+        .filter_map(|i| unsafe { opt_string(*details.flags.add(i)) })
+        .collect(),
+    };
+
+    unsafe { sys::DracFreeCPUDetails(&mut details) };
+    Ok(cpu_details)
+  } else {
+    Err(DracError::last(result, "get_cpu_details"))
+  }
+}
+
+/// Returns the CPU cache hierarchy, one entry per cache level and type in
+/// L1d, L1i, L2, L3 order.
+pub fn get_cpu_caches(cache: &CacheManager) -> Result<Vec<CpuCacheInfo>> {
+  let mut list = sys::DracCPUCacheInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetCPUCaches(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let caches = (0..list.count)
+      .map(|i| {
+        let info = unsafe { &*list.items.add(i) };
+
+        CpuCacheInfo {
+          cache_type:       CpuCacheType::from(info.type_),
+          level:            info.level,
+          size_bytes:       info.sizeBytes,
+          line_size_bytes:  (info.lineSizeBytes != 0).then_some(info.lineSizeBytes),
+          shared_cpu_count: info.sharedCpuCount,
+          instance_count:   info.instanceCount,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeCPUCacheInfoList(&mut list) };
+    Ok(caches)
+  } else {
+    Err(DracError::last(result, "get_cpu_caches"))
+  }
+}
+
+/// Returns the NUMA nodes, their memory, and which CPUs belong to each.
+///
+/// Systems without NUMA report a single node. This is never cached, since
+/// free memory changes constantly.
+pub fn get_numa_topology(cache: &CacheManager) -> Result<Vec<NumaNode>> {
+  let mut list = sys::DracNumaNodeList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetNumaTopology(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let nodes = (0..list.count)
+      .map(|i| {
+        let node = unsafe { &*list.items.add(i) };
+
+        NumaNode {
+          id:                 node.id,
+          total_memory_bytes: u64::try_from(node.totalMemoryBytes).ok(),
+          free_memory_bytes:  u64::try_from(node.freeMemoryBytes).ok(),
+          cpus:               if node.cpus.is_null() {
+            Vec::new()
+          } else {
+            unsafe { std::slice::from_raw_parts(node.cpus, node.cpuCount) }.to_vec()
+          },
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeNumaNodeList(&mut list) };
+    Ok(nodes)
+  } else {
+    Err(DracError::last(result, "get_numa_topology"))
+  }
+}
+
+/// Returns the known CPU vulnerabilities and whether each is mitigated,
+/// ordered by name.
+///
+/// Linux reports every vulnerability the kernel knows about; Windows only
+/// covers Meltdown, L1TF, Spectre v2 and Speculative Store Bypass, and macOS
+/// returns [`ErrorCode::NotSupported`].
+pub fn get_cpu_vulnerabilities(cache: &CacheManager) -> Result<Vec<CpuVulnerability>> {
+  let mut list = sys::DracCPUVulnerabilityList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetCPUVulnerabilities(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let vulnerabilities = (0..list.count)
+      .map(|i| {
+        let vulnerability = unsafe { &*list.items.add(i) };
+
+        CpuVulnerability {
+          name:    unsafe { string_or_empty(vulnerability.name) },
+          state:   CpuVulnerabilityState::from(vulnerability.state),
+          details: unsafe { opt_string(vulnerability.details) },
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeCPUVulnerabilityList(&mut list) };
+    Ok(vulnerabilities)
+  } else {
+    Err(DracError::last(result, "get_cpu_vulnerabilities"))
+  }
+}
+
+/// Returns the CPU package temperature in degrees Celsius.
+pub fn get_cpu_temperature(cache: &CacheManager) -> Result<f64> {
+  let mut celsius = 0.0;
+  let result = unsafe { sys::DracGetCPUTemperature(cache.handle, &mut celsius) };
+
+  if result == DRAC_SUCCESS {
+    Ok(celsius)
+  } else {
+    Err(DracError::last(result, "get_cpu_temperature"))
+  }
+}
+
+/// Returns the fans reported by the hardware monitoring sensors.
+///
+/// Fails with [`ErrorCode::NotFound`] if no fan sensor is exposed, which is
+/// common on desktops whose Super I/O chip has no driver loaded.
+pub fn get_fans(cache: &CacheManager) -> Result<Vec<FanInfo>> {
+  let mut list = sys::DracFanInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetFans(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let fans = (0..list.count)
+      .map(|i| {
+        let fan = unsafe { &*list.items.add(i) };
+
+        FanInfo {
+          label:      unsafe { string_or_empty(fan.label) },
+          rpm:        u32::try_from(fan.rpm).ok(),
+          target_rpm: u32::try_from(fan.targetRpm).ok(),
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeFanInfoList(&mut list) };
+    Ok(fans)
+  } else {
+    Err(DracError::last(result, "get_fans"))
+  }
+}
+
+/// Returns every temperature, voltage, current, power and fan sensor the
+/// platform exposes.
+///
+/// On Windows only the ACPI thermal zones are available unless
+/// LibreHardwareMonitor is running.
+pub fn get_sensors(cache: &CacheManager) -> Result<Vec<SensorReading>> {
+  let mut list = sys::DracSensorReadingList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetSensors(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let sensors = (0..list.count)
+      .map(|i| {
+        let sensor = unsafe { &*list.items.add(i) };
+
+        SensorReading {
+          kind:  SensorKind::from(sensor.kind),
+          chip:  unsafe { string_or_empty(sensor.chip) },
+          label: unsafe { string_or_empty(sensor.label) },
+          value: sensor.value,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeSensorReadingList(&mut list) };
+    Ok(sensors)
+  } else {
+    Err(DracError::last(result, "get_sensors"))
+  }
+}
+
+/// Returns the power drawn by the CPU package(s) in watts.
+///
+/// This blocks for about 100 ms while sampling energy counters. On Linux the
+/// RAPL counters are root-only by default, so expect
+/// [`ErrorCode::PermissionDenied`] when running unprivileged.
+pub fn get_cpu_power_watts(cache: &CacheManager) -> Result<f64> {
+  let mut watts = 0.0;
+  let result = unsafe { sys::DracGetCPUPowerWatts(cache.handle, &mut watts) };
+
+  if result == DRAC_SUCCESS {
+    Ok(watts)
+  } else {
+    Err(DracError::last(result, "get_cpu_power_watts"))
+  }
+}
+
+pub fn get_cpu_times_per_core() -> Result<Vec<CPUTimes>> {
+  let mut list = sys::DracCPUTimesList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetCPUTimesPerCore(&mut list) };
+
+  if result == DRAC_SUCCESS {
+    let mut times = Vec::with_capacity(list.count);
+
+    for i in 0..list.count {
+      let item = unsafe { &*list.items.add(i) };
+      times.push(CPUTimes {
+        idle:  item.idle,
+        total: item.total,
+      });
+    }
+
+    unsafe { sys::DracFreeCPUTimesList(&mut list) };
+    Ok(times)
+  } else {
+    Err(DracError::last(result, "get_cpu_times_per_core"))
+  }
+}
+
+/// Computes per-core CPU usage from successive samples.
+///
+/// Each call to [`CpuSampler::poll`] returns the utilization of every logical
+/// CPU since the previous poll (or since the sampler was created), as a
+/// percentage in `0.0..=100.0`.
+///
+/// # Example
+/// ```ignore
+/// let mut sampler = draconis::CpuSampler::new()?;
+/// loop {
+///   std::thread::sleep(std::time::Duration::from_secs(1));
+///   println!("{:?}", sampler.poll()?);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CpuSampler {
+  previous: Vec<CPUTimes>,
+}
+
+impl CpuSampler {
+  /// Creates a sampler, taking the initial baseline sample.
+  pub fn new() -> Result<Self> {
+    Ok(Self {
+      previous: get_cpu_times_per_core()?,
+    })
+  }
+
+  /// Takes a new sample and returns per-core usage since the last one.
+  ///
+  /// If the number of logical CPUs changed between samples (e.g. CPU
+  /// hotplug), only the cores present in both samples are reported.
+  pub fn poll(&mut self) -> Result<Vec<f64>> {
+    let current = get_cpu_times_per_core()?;
+    let usage = cpu_usage_between(&self.previous, &current);
+
+    self.previous = current;
+    Ok(usage)
+  }
+}
+
+/// Per-core utilization between two samples, for the cores present in both.
+fn cpu_usage_between(previous: &[CPUTimes], current: &[CPUTimes]) -> Vec<f64> {
+  previous
+    .iter()
+    .zip(current)
+    .map(|(prev, curr)| {
+      let total = curr.total.saturating_sub(prev.total);
+      let idle = curr.idle.saturating_sub(prev.idle);
+
+      if total == 0 {
+        0.0
+      } else {
+        (total.saturating_sub(idle) as f64 / total as f64 * 100.0).clamp(0.0, 100.0)
+      }
+    })
+    .collect()
+}
+
+/// Returns the utilization of every logical CPU as a percentage.
+///
+/// This blocks for a short sampling window (200ms). Use [`CpuSampler`]
+/// directly to control the window or to poll repeatedly.
+pub fn get_cpu_usage_per_core() -> Result<Vec<f64>> {
+  let mut sampler = CpuSampler::new()?;
+  std::thread::sleep(std::time::Duration::from_millis(200));
+  sampler.poll()
+}
+
+pub fn get_operating_system(cache: &CacheManager) -> Result<OSInfo> {
+  let mut info = sys::DracOSInfo {
+    name:    std::ptr::null_mut(),
+    version: std::ptr::null_mut(),
+    id:      std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetOperatingSystem(cache.handle, &mut info) };
+
+  if result == DRAC_SUCCESS {
+    let name = if info.name.is_null() {
+      String::new()
+    } else {
+      unsafe { CStr::from_ptr(info.name) }
+        .to_string_lossy()
+        .into_owned()
+    };
+    let version = if info.version.is_null() {
+      String::new()
+    } else {
+      unsafe { CStr::from_ptr(info.version) }
+        .to_string_lossy()
+        .into_owned()
+    };
+    let id = if info.id.is_null() {
+      String::new()
+    } else {
+      unsafe { CStr::from_ptr(info.id) }
+        .to_string_lossy()
+        .into_owned()
+    };
+
+    unsafe { sys::DracFreeOSInfo(&mut info) };
+
+    Ok(OSInfo {
+      name,
+      version,
+      id,
+    })
+  } else {
+    Err(DracError::last(result, "get_operating_system"))
+  }
+}
+
+pub fn get_battery_info(cache: &CacheManager) -> Result<Battery> {
+  let mut battery = sys::DracBattery {
+    status:            DRAC_BATTERY_UNKNOWN,
+    percentage:        255,
+    timeRemainingSecs: -1,
+  };
+
+  let result = unsafe { sys::DracGetBatteryInfo(cache.handle, &mut battery) };
+
+  if result == DRAC_SUCCESS {
+    Ok(Battery {
+      status:              BatteryStatus::from(battery.status),
+      percentage:          if battery.percentage == 255 {
+        None
+      } else {
+        Some(battery.percentage)
+      },
+      time_remaining_secs: if battery.timeRemainingSecs < 0 {
+        None
+      } else {
+        Some(battery.timeRemainingSecs)
+      },
+    })
+  } else {
+    Err(DracError::last(result, "get_battery_info"))
+  }
+}
+
+/// Returns every battery, including peripherals where the platform reports
+/// them.
+///
+/// This is never cached, so it can be polled to follow charge levels.
+pub fn get_batteries(cache: &CacheManager) -> Result<Vec<BatteryDevice>> {
+  let mut list = sys::DracBatteryDeviceList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetBatteries(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let batteries = (0..list.count)
+      .map(|i| {
+        let battery = unsafe { &*list.items.add(i) };
+
+        BatteryDevice {
+          name:          unsafe { opt_string(battery.name) },
+          is_peripheral: battery.isPeripheral,
+          battery:       Battery {
+            status:              BatteryStatus::from(battery.status),
+            percentage:          (battery.percentage != 255).then_some(battery.percentage),
+            time_remaining_secs: (battery.timeRemainingSecs >= 0)
+              .then_some(battery.timeRemainingSecs),
+          },
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeBatteryDeviceList(&mut list) };
+    Ok(batteries)
+  } else {
+    Err(DracError::last(result, "get_batteries"))
+  }
+}
+
+/// Returns whether external power is connected, and how much the charger can
+/// supply where the platform reports it.
+///
+/// This is never cached, so it can be polled to follow plug/unplug events.
+pub fn get_power_source(cache: &CacheManager) -> Result<PowerSource> {
+  let mut source = sys::DracPowerSource {
+    acConnected:  false,
+    chargerWatts: f64::NAN,
+    usbPdWatts:   f64::NAN,
+  };
+
+  let result = unsafe { sys::DracGetPowerSource(cache.handle, &mut source) };
+
+  if result == DRAC_SUCCESS {
+    Ok(PowerSource {
+      ac_connected:  source.acConnected,
+      charger_watts: (!source.chargerWatts.is_nan()).then_some(source.chargerWatts),
+      usb_pd_watts:  (!source.usbPdWatts.is_nan()).then_some(source.usbPdWatts),
+    })
+  } else {
+    Err(DracError::last(result, "get_power_source"))
+  }
+}
+
+/// Returns the active power profile and CPU frequency governor.
+///
+/// This is never cached, so it can be polled to follow profile switches.
+pub fn get_power_profile(cache: &CacheManager) -> Result<PowerProfile> {
+  let mut profile = sys::DracPowerProfile {
+    mode:     DRAC_POWER_MODE_UNKNOWN,
+    name:     std::ptr::null_mut(),
+    governor: std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetPowerProfile(cache.handle, &mut profile) };
+
+  if result == DRAC_SUCCESS {
+    let power_profile = PowerProfile {
+      mode:     PowerMode::from(profile.mode),
+      name:     unsafe { opt_string(profile.name) },
+      governor: unsafe { opt_string(profile.governor) },
+    };
+
+    unsafe { sys::DracFreePowerProfile(&mut profile) };
+    Ok(power_profile)
+  } else {
+    Err(DracError::last(result, "get_power_profile"))
+  }
+}
+
+/// Returns the number of pending updates reported by the system package
+/// manager (apt, dnf, pacman, softwareupdate, or winget).
+///
+/// Only local metadata is consulted, and results are cached for an hour.
+pub fn get_pending_updates(cache: &CacheManager) -> Result<u64> {
+  let mut count: u64 = 0;
+
+  let result = unsafe { sys::DracGetPendingUpdates(cache.handle, &mut count) };
+
+  if result == DRAC_SUCCESS {
+    Ok(count)
+  } else {
+    Err(DracError::last(result, "get_pending_updates"))
+  }
+}
+
+pub fn get_cpu_model(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetCPUModel(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_cpu_model"))
+  }
+}
+
+pub fn get_gpu_model(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetGPUModel(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_gpu_model"))
+  }
+}
+
+pub fn get_gpus(cache: &CacheManager) -> Result<Vec<GpuInfo>> {
+  let mut list = sys::DracGPUInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetGPUs(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let mut gpus = Vec::with_capacity(list.count);
+
+    for i in 0..list.count {
+      let gpu = unsafe { &*list.items.add(i) };
+      gpus.push(GpuInfo {
+        vendor:              unsafe { string_or_empty(gpu.vendor) },
+        model:               unsafe { string_or_empty(gpu.model) },
+        vram_bytes:          (gpu.vramBytes != 0).then_some(gpu.vramBytes),
+        driver:              unsafe { opt_string(gpu.driver) },
+        is_integrated:       gpu.isIntegrated,
+        temperature_celsius: (!gpu.temperatureCelsius.is_nan()).then_some(gpu.temperatureCelsius),
+        power_watts:         (!gpu.powerWatts.is_nan()).then_some(gpu.powerWatts),
+        driver_version:      unsafe { opt_string(gpu.driverVersion) },
+        vulkan_version:      unsafe { opt_string(gpu.vulkanVersion) },
+        opengl_version:      unsafe { opt_string(gpu.openGLVersion) },
+        directx_version:     unsafe { opt_string(gpu.directXVersion) },
+        metal_version:       unsafe { opt_string(gpu.metalVersion) },
+      });
+    }
+
+    unsafe { sys::DracFreeGPUInfoList(&mut list) };
+    Ok(gpus)
+  } else {
+    Err(DracError::last(result, "get_gpus"))
+  }
+}
+
+pub fn get_desktop_environment(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetDesktopEnvironment(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_desktop_environment"))
+  }
+}
+
+pub fn get_window_manager(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetWindowManager(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_window_manager"))
+  }
+}
+
+/// Get the display server of the current session.
+///
+/// Unlike [`get_window_manager`], this tells Wayland apart from X11 even when
+/// `DISPLAY` is set for Xwayland.
+pub fn get_session_type(cache: &CacheManager) -> Result<DisplaySession> {
+  let mut session = sys::DracDisplaySession {
+    server:     DRAC_DISPLAY_SERVER_UNKNOWN,
+    compositor: std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetDisplaySession(cache.handle, &mut session) };
+
+  if result == DRAC_SUCCESS {
+    let info = DisplaySession {
+      server:     DisplayServer::from(session.server),
+      compositor: unsafe { opt_string(session.compositor) },
+    };
+
+    unsafe { sys::DracFreeDisplaySession(&mut session) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_session_type"))
+  }
+}
+
+/// Get the widget, icon, and cursor theme and the system UI font.
+pub fn get_theme_info(cache: &CacheManager) -> Result<ThemeInfo> {
+  let mut theme = sys::DracThemeInfo {
+    widgetTheme: std::ptr::null_mut(),
+    iconTheme:   std::ptr::null_mut(),
+    cursorTheme: std::ptr::null_mut(),
+    font:        std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetThemeInfo(cache.handle, &mut theme) };
+
+  if result == DRAC_SUCCESS {
+    let info = ThemeInfo {
+      widget_theme: unsafe { opt_string(theme.widgetTheme) },
+      icon_theme:   unsafe { opt_string(theme.iconTheme) },
+      cursor_theme: unsafe { opt_string(theme.cursorTheme) },
+      font:         unsafe { opt_string(theme.font) },
+    };
+
+    unsafe { sys::DracFreeThemeInfo(&mut theme) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_theme_info"))
+  }
+}
+
+/// Get the user's default text editor, web browser, and terminal emulator.
+///
+/// This is never cached, since the user can change these at any time.
+pub fn get_default_applications() -> Result<DefaultApplications> {
+  let mut apps = sys::DracDefaultApplications {
+    editor:   std::ptr::null_mut(),
+    browser:  std::ptr::null_mut(),
+    terminal: std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetDefaultApplications(&mut apps) };
+
+  if result == DRAC_SUCCESS {
+    let info = DefaultApplications {
+      editor:   unsafe { opt_string(apps.editor) },
+      browser:  unsafe { opt_string(apps.browser) },
+      terminal: unsafe { opt_string(apps.terminal) },
+    };
+
+    unsafe { sys::DracFreeDefaultApplications(&mut apps) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_default_applications"))
+  }
+}
+
+/// Get audio output and input devices, outputs first.
+///
+/// On Linux this requires the library to be built with PulseAudio support
+/// (which also covers PipeWire); otherwise `ErrorCode::NotSupported` is returned.
+pub fn get_audio_devices(cache: &CacheManager) -> Result<Vec<AudioDevice>> {
+  let mut list = sys::DracAudioDeviceList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetAudioDevices(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let devices = (0..list.count)
+      .map(|i| {
+        let device = unsafe { &*list.items.add(i) };
+
+        AudioDevice {
+          direction:   AudioDirection::from(device.direction),
+          id:          unsafe { string_or_empty(device.id) },
+          name:        unsafe { string_or_empty(device.name) },
+          is_default:  device.isDefault,
+          channels:    u32::try_from(device.channels).ok(),
+          sample_rate: u32::try_from(device.sampleRate).ok(),
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeAudioDeviceList(&mut list) };
+    Ok(devices)
+  } else {
+    Err(DracError::last(result, "get_audio_devices"))
+  }
+}
+
+/// Get the volume and mute state of the default audio output device.
+pub fn get_volume(cache: &CacheManager) -> Result<VolumeInfo> {
+  let mut volume = sys::DracVolumeInfo {
+    percent: 0.0,
+    muted:   false,
+  };
+
+  let result = unsafe { sys::DracGetVolume(cache.handle, &mut volume) };
+
+  if result == DRAC_SUCCESS {
+    Ok(VolumeInfo {
+      percent: volume.percent,
+      muted:   volume.muted,
+    })
+  } else {
+    Err(DracError::last(result, "get_volume"))
+  }
+}
+
+/// Set the volume of the default audio output device, as a percentage.
+pub fn set_volume(percent: f64) -> Result<()> {
+  let result = unsafe { sys::DracSetVolume(percent) };
+
+  if result == DRAC_SUCCESS {
+    Ok(())
+  } else {
+    Err(DracError::last(result, "set_volume"))
+  }
+}
+
+/// Mute or unmute the default audio output device.
+pub fn set_muted(muted: bool) -> Result<()> {
+  let result = unsafe { sys::DracSetMuted(muted) };
+
+  if result == DRAC_SUCCESS {
+    Ok(())
+  } else {
+    Err(DracError::last(result, "set_muted"))
+  }
+}
+
+pub fn get_shell(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetShell(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_shell"))
+  }
+}
+
+/// Like [`get_shell`], but keeps bytes that aren't valid UTF-8, so the path
+/// can be executed or opened.
+pub fn get_shell_os(cache: &CacheManager) -> Result<OsString> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetShell(cache.handle, &mut ptr) };
+  os_string_result(result, ptr, "get_shell_os")
+}
+
+/// Get the terminal emulator the current process is running in (e.g. "kitty").
+pub fn get_terminal(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetTerminal(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_terminal"))
+  }
+}
+
+/// Like [`get_terminal`], but keeps bytes that aren't valid UTF-8.
+pub fn get_terminal_os(cache: &CacheManager) -> Result<OsString> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetTerminal(cache.handle, &mut ptr) };
+  os_string_result(result, ptr, "get_terminal_os")
+}
+
+/// Get the font configured in the current terminal emulator, as "Family Size".
+///
+/// Returns `ErrorCode::NotSupported` for terminals whose configuration can't be read.
+pub fn get_terminal_font(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetTerminalFont(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_terminal_font"))
+  }
+}
+
+pub fn get_host(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetHost(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_host"))
+  }
+}
+
+/// Get the machine's network hostname, e.g. `archlinux`.
+///
+/// Unlike [`get_host`], which reports the hardware model, this is the name the
+/// machine goes by on the network. It is never cached, since it can change at
+/// any time.
+pub fn get_hostname() -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetHostname(&mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_hostname"))
+  }
+}
+
+/// Like [`get_hostname`], but keeps bytes that aren't valid UTF-8.
+pub fn get_hostname_os() -> Result<OsString> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetHostname(&mut ptr) };
+  os_string_result(result, ptr, "get_hostname_os")
+}
+
+/// Get the machine's fully qualified domain name, e.g.
+/// `build01.corp.example.com`.
+///
+/// On Unix this resolves the hostname like `hostname -f`, so it may block on
+/// DNS. On Windows it is the bare hostname outside a domain.
+pub fn get_fqdn() -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetFQDN(&mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_fqdn"))
+  }
+}
+
+/// Get the Active Directory domain or workgroup membership and Azure AD join
+/// state.
+///
+/// Fails with [`ErrorCode::NotSupported`] outside Windows.
+pub fn get_domain_info(cache: &CacheManager) -> Result<DomainInfo> {
+  let mut info = sys::DracDomainInfo {
+    membership:    DRAC_DOMAIN_MEMBERSHIP_UNJOINED,
+    name:          std::ptr::null_mut(),
+    dnsDomain:     std::ptr::null_mut(),
+    azureAdJoined: false,
+    azureTenant:   std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetDomainInfo(cache.handle, &mut info) };
+
+  if result == DRAC_SUCCESS {
+    let domain_info = DomainInfo {
+      membership:      DomainMembership::from(info.membership),
+      name:            unsafe { opt_string(info.name) },
+      dns_domain:      unsafe { opt_string(info.dnsDomain) },
+      azure_ad_joined: info.azureAdJoined,
+      azure_tenant:    unsafe { opt_string(info.azureTenant) },
+    };
+
+    unsafe { sys::DracFreeDomainInfo(&mut info) };
+    Ok(domain_info)
+  } else {
+    Err(DracError::last(result, "get_domain_info"))
+  }
+}
+
+/// Get the motherboard and BIOS/UEFI firmware information.
+pub fn get_firmware_info(cache: &CacheManager) -> Result<FirmwareInfo> {
+  let mut info = sys::DracFirmwareInfo {
+    boardVendor:  std::ptr::null_mut(),
+    boardModel:   std::ptr::null_mut(),
+    boardVersion: std::ptr::null_mut(),
+    vendor:       std::ptr::null_mut(),
+    version:      std::ptr::null_mut(),
+    releaseDate:  std::ptr::null_mut(),
+    isUefi:       false,
+  };
+
+  let result = unsafe { sys::DracGetFirmwareInfo(cache.handle, &mut info) };
+
+  if result == DRAC_SUCCESS {
+    let firmware = FirmwareInfo {
+      board_vendor:  unsafe { opt_string(info.boardVendor) },
+      board_model:   unsafe { opt_string(info.boardModel) },
+      board_version: unsafe { opt_string(info.boardVersion) },
+      vendor:        unsafe { opt_string(info.vendor) },
+      version:       unsafe { opt_string(info.version) },
+      release_date:  unsafe { opt_string(info.releaseDate) },
+      is_uefi:       info.isUefi,
+    };
+
+    unsafe { sys::DracFreeFirmwareInfo(&mut info) };
+    Ok(firmware)
+  } else {
+    Err(DracError::last(result, "get_firmware_info"))
+  }
+}
+
+/// Get the operating system's unique machine identifier.
+///
+/// Stable across hostname changes, but regenerated by a reinstall.
+pub fn get_machine_id(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetMachineId(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_machine_id"))
+  }
+}
+
+/// Get the hardware serial number the vendor assigned to the machine.
+///
+/// Fails with [`ErrorCode::PermissionRequired`] when reading it needs
+/// elevated rights (root on Linux).
+pub fn get_hardware_serial(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetHardwareSerial(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_hardware_serial"))
+  }
+}
+
+/// Detect the hypervisor and container runtime the process runs under.
+pub fn get_virtualization(cache: &CacheManager) -> Result<VirtEnv> {
+  let mut env = sys::DracVirtEnv {
+    hypervisor:       DRAC_HYPERVISOR_NONE,
+    container:        DRAC_CONTAINER_NONE,
+    hypervisorVendor: std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetVirtualization(cache.handle, &mut env) };
+
+  if result == DRAC_SUCCESS {
+    let hypervisor_vendor = unsafe { opt_string(env.hypervisorVendor) };
+
+    let virt_env = VirtEnv {
+      hypervisor: Hypervisor::from(env.hypervisor),
+      container: Container::from(env.container),
+      hypervisor_vendor,
+    };
+
+    unsafe { sys::DracFreeVirtEnv(&mut env) };
+    Ok(virt_env)
+  } else {
+    Err(DracError::last(result, "get_virtualization"))
+  }
+}
+
+/// Get the Secure Boot state and the TPM presence, version, and measured-boot
+/// status.
+///
+/// Fails with [`ErrorCode::NotSupported`] on macOS.
+pub fn get_security_platform_info(cache: &CacheManager) -> Result<SecurityPlatformInfo> {
+  let mut info = sys::DracSecurityPlatformInfo {
+    secureBoot:   DRAC_SECURE_BOOT_UNKNOWN,
+    tpmPresent:   false,
+    tpmVersion:   std::ptr::null_mut(),
+    measuredBoot: false,
+  };
+
+  let result = unsafe { sys::DracGetSecurityPlatformInfo(cache.handle, &mut info) };
+
+  if result == DRAC_SUCCESS {
+    let tpm_version = unsafe { opt_string(info.tpmVersion) };
+
+    let platform_info = SecurityPlatformInfo {
+      secure_boot: SecureBootState::from(info.secureBoot),
+      tpm_present: info.tpmPresent,
+      tpm_version,
+      measured_boot: info.measuredBoot,
+    };
+
+    unsafe { sys::DracFreeSecurityPlatformInfo(&mut info) };
+    Ok(platform_info)
+  } else {
+    Err(DracError::last(result, "get_security_platform_info"))
+  }
+}
+
+/// Get the status of SELinux or AppArmor.
+///
+/// Fails with [`ErrorCode::NotSupported`] outside Linux.
+pub fn get_mac_status(cache: &CacheManager) -> Result<MacStatus> {
+  let mut status = sys::DracMacStatus {
+    framework: DRAC_MAC_FRAMEWORK_NONE,
+    mode:      DRAC_MAC_MODE_DISABLED,
+    policy:    std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetMacStatus(cache.handle, &mut status) };
+
+  if result == DRAC_SUCCESS {
+    let policy = unsafe { opt_string(status.policy) };
+
+    let mac_status = MacStatus {
+      framework: MacFramework::from(status.framework),
+      mode: MacMode::from(status.mode),
+      policy,
+    };
+
+    unsafe { sys::DracFreeMacStatus(&mut status) };
+    Ok(mac_status)
+  } else {
+    Err(DracError::last(result, "get_mac_status"))
+  }
+}
+
+/// Get the host firewall and whether each of its profiles is enabled.
+///
+/// Fails with [`ErrorCode::NotFound`] if no supported firewall is installed.
+pub fn get_firewall_status(cache: &CacheManager) -> Result<FirewallStatus> {
+  let mut status = sys::DracFirewallStatus {
+    backend:      DRAC_FIREWALL_BACKEND_FIREWALLD,
+    profiles:     std::ptr::null_mut(),
+    profileCount: 0,
+  };
+
+  let result = unsafe { sys::DracGetFirewallStatus(cache.handle, &mut status) };
+
+  if result == DRAC_SUCCESS {
+    let profiles = (0..status.profileCount)
+      .map(|i| {
+        let profile = unsafe { &*status.profiles.add(i) };
+
+        FirewallProfile {
+          name:    unsafe { string_or_empty(profile.name) },
+          enabled: profile.enabled,
+        }
+      })
+      .collect();
+
+    let firewall_status = FirewallStatus {
+      backend: FirewallBackend::from(status.backend),
+      profiles,
+    };
+
+    unsafe { sys::DracFreeFirewallStatus(&mut status) };
+    Ok(firewall_status)
+  } else {
+    Err(DracError::last(result, "get_firewall_status"))
+  }
+}
+
+pub fn get_kernel_version(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetKernelVersion(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_kernel_version"))
+  }
+}
+
+/// Get the command line the kernel was booted with.
+pub fn get_kernel_cmdline(cache: &CacheManager) -> Result<String> {
+  let mut ptr = std::ptr::null_mut();
+  let result = unsafe { sys::DracGetKernelCmdline(cache.handle, &mut ptr) };
+
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, "get_kernel_cmdline"))
+  }
+}
+
+/// Get the kernel modules currently loaded.
+///
+/// Modules built into the Linux kernel are not listed.
+pub fn get_kernel_modules(cache: &CacheManager) -> Result<Vec<KernelModule>> {
+  let mut list = sys::DracKernelModuleList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetKernelModules(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let modules = (0..list.count)
+      .map(|i| {
+        let module = unsafe { &*list.items.add(i) };
+
+        KernelModule {
+          name:       unsafe { string_or_empty(module.name) },
+          size_bytes: u64::try_from(module.sizeBytes).ok(),
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeKernelModuleList(&mut list) };
+    Ok(modules)
+  } else {
+    Err(DracError::last(result, "get_kernel_modules"))
+  }
+}
+
+pub fn get_disk_usage(cache: &CacheManager) -> Result<ResourceUsage> {
+  let mut usage = sys::DracResourceUsage {
+    usedBytes:  0,
+    totalBytes: 0,
+  };
+
+  let result = unsafe { sys::DracGetDiskUsage(cache.handle, &mut usage) };
+
+  if result == DRAC_SUCCESS {
+    Ok(ResourceUsage {
+      used_bytes:  usage.usedBytes,
+      total_bytes: usage.totalBytes,
+    })
+  } else {
+    Err(DracError::last(result, "get_disk_usage"))
+  }
+}
+
+pub fn get_disks(cache: &CacheManager) -> Result<Vec<DiskInfo>> {
+  let mut list = sys::DracDiskInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetDisks(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let mut disks = Vec::with_capacity(list.count);
+
+    for i in 0..list.count {
+      let disk = unsafe { &*list.items.add(i) };
+      disks.push(DiskInfo {
+        name:              if disk.name.is_null() {
+          String::new()
+        } else {
+          unsafe { CStr::from_ptr(disk.name) }
+            .to_string_lossy()
+            .into_owned()
+        },
+        mount_point:       if disk.mountPoint.is_null() {
+          String::new()
+        } else {
+          unsafe { CStr::from_ptr(disk.mountPoint) }
+            .to_string_lossy()
+            .into_owned()
+        },
+        mount_point_bytes: c_bytes(disk.mountPoint),
+        filesystem:        if disk.filesystem.is_null() {
+          String::new()
+        } else {
+          unsafe { CStr::from_ptr(disk.filesystem) }
+            .to_string_lossy()
+            .into_owned()
+        },
+        drive_type:        if disk.driveType.is_null() {
+          String::new()
+        } else {
+          unsafe { CStr::from_ptr(disk.driveType) }
+            .to_string_lossy()
+            .into_owned()
+        },
+        total_bytes:       disk.totalBytes,
+        used_bytes:        disk.usedBytes,
+        total_inodes:      u64::try_from(disk.totalInodes).ok(),
+        used_inodes:       u64::try_from(disk.usedInodes).ok(),
+        mount_options:     mount_options_from_c(disk),
+        is_read_only:      disk.isReadOnly,
+        is_system_drive:   disk.isSystemDrive,
+      });
+    }
+
+    unsafe { sys::DracFreeDiskInfoList(&mut list) };
+    Ok(disks)
+  } else {
+    Err(DracError::last(result, "get_disks"))
+  }
+}
+
+fn mount_options_from_c(disk: &sys::DracDiskInfo) -> Vec<String> {
+  (0..disk.mountOptionCount)
+    .map(|i| unsafe { *disk.mountOptions.add(i) })
+    .filter_map(|option| unsafe { opt_string(option) })
+    .collect()
+}
+
+/// Copies a C string from the library, or `None` for a null pointer.
+unsafe fn opt_string(ptr: *const std::ffi::c_char) -> Option<String> {
+  (!ptr.is_null()).then(|| {
+    unsafe { CStr::from_ptr(ptr) }
+      .to_string_lossy()
+      .into_owned()
+  })
+}
+
+/// Copies a C string from the library, or an empty string for a null pointer.
+unsafe fn string_or_empty(ptr: *const std::ffi::c_char) -> String {
+  unsafe { opt_string(ptr) }.unwrap_or_default()
+}
+
+/// Copies a C string's bytes as-is, or nothing for a null pointer.
+fn c_bytes(ptr: *const std::ffi::c_char) -> Vec<u8> {
+  if ptr.is_null() {
+    Vec::new()
+  } else {
+    unsafe { CStr::from_ptr(ptr) }.to_bytes().to_vec()
+  }
+}
+
+/// Reinterprets bytes from the library as an OS string.
+///
+/// On Unix the library passes OS bytes through unchanged, so this is lossless.
+/// Elsewhere it converts to UTF-8 itself, so the bytes are already valid.
+fn os_str_from_bytes(bytes: &[u8]) -> &OsStr {
+  #[cfg(unix)]
+  {
+    use std::os::unix::ffi::OsStrExt;
+    OsStr::from_bytes(bytes)
+  }
+
+  #[cfg(not(unix))]
+  {
+    OsStr::new(std::str::from_utf8(bytes).unwrap_or_default())
+  }
+}
+
+/// Takes ownership of a string returned by the library without replacing
+/// bytes that aren't valid UTF-8.
+fn os_string_result(
+  result: DracErrorCode,
+  ptr: *mut std::ffi::c_char,
+  source_fn: &'static str,
+) -> Result<OsString> {
+  if result == DRAC_SUCCESS && !ptr.is_null() {
+    let s = os_str_from_bytes(unsafe { CStr::from_ptr(ptr) }.to_bytes()).to_os_string();
+    unsafe { sys::DracFreeString(ptr) };
+    Ok(s)
+  } else {
+    Err(DracError::last(result, source_fn))
+  }
+}
+
+pub fn get_system_disk(cache: &CacheManager) -> Result<DiskInfo> {
+  let mut disk = sys::DracDiskInfo {
+    name:             std::ptr::null_mut(),
+    mountPoint:       std::ptr::null_mut(),
+    filesystem:       std::ptr::null_mut(),
+    driveType:        std::ptr::null_mut(),
+    totalBytes:       0,
+    usedBytes:        0,
+    totalInodes:      -1,
+    usedInodes:       -1,
+    mountOptions:     std::ptr::null_mut(),
+    mountOptionCount: 0,
+    isReadOnly:       false,
+    isSystemDrive:    false,
+  };
+
+  let result = unsafe { sys::DracGetSystemDisk(cache.handle, &mut disk) };
+
+  if result == DRAC_SUCCESS {
+    let info = DiskInfo {
+      name:              if disk.name.is_null() {
+        String::new()
+      } else {
+        unsafe { CStr::from_ptr(disk.name) }
+          .to_string_lossy()
+          .into_owned()
+      },
+      mount_point:       if disk.mountPoint.is_null() {
+        String::new()
+      } else {
+        unsafe { CStr::from_ptr(disk.mountPoint) }
+          .to_string_lossy()
+          .into_owned()
+      },
+      mount_point_bytes: c_bytes(disk.mountPoint),
+      filesystem:        if disk.filesystem.is_null() {
+        String::new()
+      } else {
+        unsafe { CStr::from_ptr(disk.filesystem) }
+          .to_string_lossy()
+          .into_owned()
+      },
+      drive_type:        if disk.driveType.is_null() {
+        String::new()
+      } else {
+        unsafe { CStr::from_ptr(disk.driveType) }
+          .to_string_lossy()
+          .into_owned()
+      },
+      total_bytes:       disk.totalBytes,
+      used_bytes:        disk.usedBytes,
+      total_inodes:      u64::try_from(disk.totalInodes).ok(),
+      used_inodes:       u64::try_from(disk.usedInodes).ok(),
+      mount_options:     mount_options_from_c(&disk),
+      is_read_only:      disk.isReadOnly,
+      is_system_drive:   disk.isSystemDrive,
+    };
+
+    unsafe { sys::DracFreeDiskInfo(&mut disk) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_system_disk"))
+  }
+}
+
+/// Reads SMART health data for a block device, e.g. `"sda"`, `"/dev/nvme0n1"`
+/// or `"PhysicalDrive0"`.
+///
+/// This needs raw device access, so it fails with
+/// [`ErrorCode::PermissionRequired`] when not running as root/administrator.
+/// Not supported on macOS.
+pub fn get_disk_health(device: &str) -> Result<DiskHealth> {
+  let c_device = std::ffi::CString::new(device)
+    .map_err(|_| DracError::new(ErrorCode::InvalidArgument, "get_disk_health"))?;
+
+  let mut health = sys::DracDiskHealth {
+    device:             std::ptr::null_mut(),
+    status:             DRAC_DISK_HEALTH_UNKNOWN,
+    powerOnHours:       -1,
+    reallocatedSectors: -1,
+    temperatureCelsius: f64::NAN,
+    attributes:         std::ptr::null_mut(),
+    attributeCount:     0,
+  };
+
+  let result = unsafe { sys::DracGetDiskHealth(c_device.as_ptr(), &mut health) };
+
+  if result == DRAC_SUCCESS {
+    let attributes = (0..health.attributeCount)
+      .map(|i| {
+        let attribute = unsafe { &*health.attributes.add(i) };
+
+        SmartAttribute {
+          id:        attribute.id,
+          name:      unsafe { string_or_empty(attribute.name) },
+          value:     attribute.value,
+          worst:     attribute.worst,
+          threshold: u8::try_from(attribute.threshold).ok(),
+          raw:       attribute.raw,
+        }
+      })
+      .collect();
+
+    let info = DiskHealth {
+      device: unsafe { string_or_empty(health.device) },
+      status: DiskHealthStatus::from(health.status),
+      power_on_hours: u64::try_from(health.powerOnHours).ok(),
+      reallocated_sectors: u64::try_from(health.reallocatedSectors).ok(),
+      temperature_celsius: (!health.temperatureCelsius.is_nan())
+        .then_some(health.temperatureCelsius),
+      attributes,
+    };
+
+    unsafe { sys::DracFreeDiskHealth(&mut health) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_disk_health"))
+  }
+}
+
+/// Reads the temperature of every drive that exposes a sensor.
+///
+/// Unlike [`get_disk_health`] this doesn't need elevated privileges. On Linux,
+/// SATA drives only show up when the `drivetemp` module is loaded.
+pub fn get_disk_temperatures(cache: &CacheManager) -> Result<Vec<DiskTemperature>> {
+  let mut list = sys::DracDiskTemperatureList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetDiskTemperatures(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let temperatures = (0..list.count)
+      .map(|i| {
+        let temperature = unsafe { &*list.items.add(i) };
+
+        DiskTemperature {
+          device:  unsafe { string_or_empty(temperature.device) },
+          celsius: temperature.celsius,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeDiskTemperatureList(&mut list) };
+    Ok(temperatures)
+  } else {
+    Err(DracError::last(result, "get_disk_temperatures"))
+  }
+}
+
+/// Reads the cumulative I/O counters of every physical drive.
+///
+/// Counters are read live on every call. On Linux, partitions, loop devices
+/// and RAM disks are skipped.
+pub fn get_disk_io_stats(cache: &CacheManager) -> Result<Vec<DiskIOStats>> {
+  let mut list = sys::DracDiskIOStatsList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetDiskIOStats(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let stats = (0..list.count)
+      .map(|i| {
+        let disk = unsafe { &*list.items.add(i) };
+
+        DiskIOStats {
+          device:      unsafe { string_or_empty(disk.device) },
+          read_ops:    disk.readOps,
+          write_ops:   disk.writeOps,
+          read_bytes:  disk.readBytes,
+          write_bytes: disk.writeBytes,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeDiskIOStatsList(&mut list) };
+    Ok(stats)
+  } else {
+    Err(DracError::last(result, "get_disk_io_stats"))
+  }
+}
+
+/// Lists the filesystems mounted from remote servers: NFS, SMB, SSHFS and
+/// WebDAV mounts, and mapped drives on Windows.
+///
+/// The connection state is only known for FUSE mounts on Linux and mapped
+/// drives on Windows; probing a kernel NFS/SMB mount with a dead server could
+/// block indefinitely.
+pub fn get_network_mounts(cache: &CacheManager) -> Result<Vec<NetworkMount>> {
+  let mut list = sys::DracNetworkMountList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetNetworkMounts(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let mounts = (0..list.count)
+      .map(|i| {
+        let mount = unsafe { &*list.items.add(i) };
+
+        NetworkMount {
+          server:      unsafe { string_or_empty(mount.server) },
+          export_path: unsafe { string_or_empty(mount.exportPath) },
+          mount_point: unsafe { string_or_empty(mount.mountPoint) },
+          protocol:    NetworkMountProtocol::from(mount.protocol),
+          state:       NetworkMountState::from(mount.state),
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeNetworkMountList(&mut list) };
+    Ok(mounts)
+  } else {
+    Err(DracError::last(result, "get_network_mounts"))
+  }
+}
+
+pub fn get_outputs(cache: &CacheManager) -> Result<Vec<DisplayInfo>> {
+  let mut list = sys::DracDisplayInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetOutputs(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let mut displays = Vec::with_capacity(list.count);
+
+    for i in 0..list.count {
+      displays.push(display_info_from_c(unsafe { &*list.items.add(i) }));
+    }
+
+    unsafe { sys::DracFreeDisplayInfoList(&mut list) };
+    Ok(displays)
+  } else {
+    Err(DracError::last(result, "get_outputs"))
+  }
+}
+
+pub fn get_primary_output(cache: &CacheManager) -> Result<DisplayInfo> {
+  let mut display = sys::DracDisplayInfo {
+    id:           0,
+    width:        0,
+    height:       0,
+    refreshRate:  0.0,
+    isPrimary:    false,
+    positionX:    0,
+    positionY:    0,
+    scaleFactor:  1.0,
+    dpi:          f64::NAN,
+    colorDepth:   255,
+    rotation:     0,
+    connector:    DRAC_DISPLAY_CONNECTOR_UNKNOWN,
+    manufacturer: std::ptr::null_mut(),
+    model:        std::ptr::null_mut(),
+    serial:       std::ptr::null_mut(),
+    gamut:        DRAC_DISPLAY_GAMUT_UNKNOWN,
+    hdrSupported: false,
+    hdrEnabled:   -1,
+  };
+
+  let result = unsafe { sys::DracGetPrimaryOutput(cache.handle, &mut display) };
+
+  if result == DRAC_SUCCESS {
+    let info = display_info_from_c(&display);
+
+    unsafe { sys::DracFreeDisplayInfo(&mut display) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_primary_output"))
+  }
+}
+
+fn display_info_from_c(display: &sys::DracDisplayInfo) -> DisplayInfo {
+  DisplayInfo {
+    id:            display.id,
+    width:         display.width,
+    height:        display.height,
+    refresh_rate:  display.refreshRate,
+    is_primary:    display.isPrimary,
+    position_x:    display.positionX,
+    position_y:    display.positionY,
+    scale_factor:  display.scaleFactor,
+    dpi:           (!display.dpi.is_nan()).then_some(display.dpi),
+    color_depth:   (display.colorDepth != 255).then_some(display.colorDepth),
+    rotation:      display.rotation,
+    connector:     DisplayConnector::from(display.connector),
+    manufacturer:  unsafe { opt_string(display.manufacturer) },
+    model:         unsafe { opt_string(display.model) },
+    serial:        unsafe { opt_string(display.serial) },
+    gamut:         DisplayGamut::from(display.gamut),
+    hdr_supported: display.hdrSupported,
+    hdr_enabled:   match display.hdrEnabled {
+      -1 => None,
+      enabled => Some(enabled != 0),
+    },
+  }
+}
+
+/// Returns the brightness of every display whose backlight can be read.
+///
+/// Built-in panels are always covered; external monitors only when they
+/// answer DDC/CI (on Linux this needs the `i2c-dev` module and access to
+/// `/dev/i2c-*`). This is never cached.
+pub fn get_brightness(cache: &CacheManager) -> Result<Vec<BacklightInfo>> {
+  let mut list = sys::DracBacklightInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetBrightness(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let backlights = (0..list.count)
+      .map(|i| {
+        let backlight = unsafe { &*list.items.add(i) };
+
+        BacklightInfo {
+          device:  unsafe { string_or_empty(backlight.device) },
+          percent: backlight.percent,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeBacklightInfoList(&mut list) };
+    Ok(backlights)
+  } else {
+    Err(DracError::last(result, "get_brightness"))
+  }
+}
+
+pub fn get_network_interfaces(cache: &CacheManager) -> Result<Vec<NetworkInterface>> {
+  let mut list = sys::DracNetworkInterfaceList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetNetworkInterfaces(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let mut interfaces = Vec::with_capacity(list.count);
+
+    for i in 0..list.count {
+      let iface = unsafe { &*list.items.add(i) };
+      interfaces.push(NetworkInterface {
+        name:            if iface.name.is_null() {
+          String::new()
+        } else {
+          unsafe { CStr::from_ptr(iface.name) }
+            .to_string_lossy()
+            .into_owned()
+        },
+        ipv4_address:    if iface.ipv4Address.is_null() {
+          None
+        } else {
+          Some(
+            unsafe { CStr::from_ptr(iface.ipv4Address) }
+              .to_string_lossy()
+              .into_owned(),
+          )
+        },
+        ipv6_address:    if iface.ipv6Address.is_null() {
+          None
+        } else {
+          Some(
+            unsafe { CStr::from_ptr(iface.ipv6Address) }
+              .to_string_lossy()
+              .into_owned(),
+          )
+        },
+        mac_address:     if iface.macAddress.is_null() {
+          None
+        } else {
+          Some(
+            unsafe { CStr::from_ptr(iface.macAddress) }
+              .to_string_lossy()
+              .into_owned(),
+          )
+        },
+        is_up:           iface.isUp,
+        is_loopback:     iface.isLoopback,
+        link_speed_mbps: u64::try_from(iface.linkSpeedMbps).ok(),
+        mtu:             u32::try_from(iface.mtu).ok(),
+        duplex:          Duplex::from(iface.duplex),
+      });
+    }
+
+    unsafe { sys::DracFreeNetworkInterfaceList(&mut list) };
+    Ok(interfaces)
+  } else {
+    Err(DracError::last(result, "get_network_interfaces"))
+  }
+}
+
+/// Get the cumulative traffic counters of every network interface.
+///
+/// The counters are never cached, so every call reads fresh values.
+pub fn get_network_stats(cache: &CacheManager) -> Result<Vec<InterfaceStats>> {
+  let mut list = sys::DracInterfaceStatsList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetNetworkStats(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let stats = (0..list.count)
+      .map(|i| {
+        let item = unsafe { &*list.items.add(i) };
+
+        InterfaceStats {
+          name:       unsafe { string_or_empty(item.name) },
+          rx_bytes:   item.rxBytes,
+          tx_bytes:   item.txBytes,
+          rx_packets: item.rxPackets,
+          tx_packets: item.txPackets,
+          rx_errors:  item.rxErrors,
+          tx_errors:  item.txErrors,
+          rx_dropped: item.rxDropped,
+          tx_dropped: item.txDropped,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeInterfaceStatsList(&mut list) };
+    Ok(stats)
+  } else {
+    Err(DracError::last(result, "get_network_stats"))
+  }
+}
+
+pub fn get_primary_network_interface(cache: &CacheManager) -> Result<NetworkInterface> {
+  let mut iface = sys::DracNetworkInterface {
+    name:          std::ptr::null_mut(),
+    ipv4Address:   std::ptr::null_mut(),
+    ipv6Address:   std::ptr::null_mut(),
+    macAddress:    std::ptr::null_mut(),
+    isUp:          false,
+    isLoopback:    false,
+    linkSpeedMbps: -1,
+    mtu:           -1,
+    duplex:        DRAC_DUPLEX_UNKNOWN,
+  };
+
+  let result = unsafe { sys::DracGetPrimaryNetworkInterface(cache.handle, &mut iface) };
+
+  if result == DRAC_SUCCESS {
+    let info = NetworkInterface {
+      name:            if iface.name.is_null() {
+        String::new()
+      } else {
+        unsafe { CStr::from_ptr(iface.name) }
+          .to_string_lossy()
+          .into_owned()
+      },
+      ipv4_address:    if iface.ipv4Address.is_null() {
+        None
+      } else {
+        Some(
+          unsafe { CStr::from_ptr(iface.ipv4Address) }
+            .to_string_lossy()
+            .into_owned(),
+        )
+      },
+      ipv6_address:    if iface.ipv6Address.is_null() {
+        None
+      } else {
+        Some(
+          unsafe { CStr::from_ptr(iface.ipv6Address) }
+            .to_string_lossy()
+            .into_owned(),
+        )
+      },
+      mac_address:     if iface.macAddress.is_null() {
+        None
+      } else {
+        Some(
+          unsafe { CStr::from_ptr(iface.macAddress) }
+            .to_string_lossy()
+            .into_owned(),
+        )
+      },
+      is_up:           iface.isUp,
+      is_loopback:     iface.isLoopback,
+      link_speed_mbps: u64::try_from(iface.linkSpeedMbps).ok(),
+      mtu:             u32::try_from(iface.mtu).ok(),
+      duplex:          Duplex::from(iface.duplex),
+    };
+
+    unsafe { sys::DracFreeNetworkInterface(&mut iface) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_primary_network_interface"))
+  }
+}
+
+/// Get Bluetooth adapters and their paired or connected devices.
+///
+/// On Linux this requires the library to be built with D-Bus support and
+/// BlueZ to be running; otherwise an error is returned.
+pub fn get_bluetooth_devices(cache: &CacheManager) -> Result<BluetoothInfo> {
+  let mut info = sys::DracBluetoothInfo {
+    adapters:     std::ptr::null_mut(),
+    adapterCount: 0,
+    devices:      std::ptr::null_mut(),
+    deviceCount:  0,
+  };
+
+  let result = unsafe { sys::DracGetBluetoothDevices(cache.handle, &mut info) };
+
+  if result == DRAC_SUCCESS {
+    let adapters = (0..info.adapterCount)
+      .map(|i| {
+        let adapter = unsafe { &*info.adapters.add(i) };
+
+        BluetoothAdapter {
+          name:    unsafe { string_or_empty(adapter.name) },
+          address: unsafe { string_or_empty(adapter.address) },
+          powered: adapter.powered,
+        }
+      })
+      .collect();
+
+    let devices = (0..info.deviceCount)
+      .map(|i| {
+        let device = unsafe { &*info.devices.add(i) };
+
+        BluetoothDevice {
+          name:            unsafe { string_or_empty(device.name) },
+          address:         unsafe { string_or_empty(device.address) },
+          paired:          device.paired,
+          connected:       device.connected,
+          battery_percent: u8::try_from(device.batteryPercent).ok(),
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeBluetoothInfo(&mut info) };
+    Ok(BluetoothInfo {
+      adapters,
+      devices,
+    })
+  } else {
+    Err(DracError::last(result, "get_bluetooth_devices"))
+  }
+}
 
-    for i in 0..list.count {
-      let iface = unsafe { &*list.items.add(i) };
-      interfaces.push(NetworkInterface {
-        name:         if iface.name.is_null() {
-          String::new()
-        } else {
-          unsafe { CStr::from_ptr(iface.name) }
-            .to_string_lossy()
-            .into_owned()
-        },
-        ipv4_address: if iface.ipv4Address.is_null() {
-          None
-        } else {
-          Some(
-            unsafe { CStr::from_ptr(iface.ipv4Address) }
-              .to_string_lossy()
-              .into_owned(),
-          )
-        },
-        ipv6_address: if iface.ipv6Address.is_null() {
-          None
-        } else {
-          Some(
-            unsafe { CStr::from_ptr(iface.ipv6Address) }
-              .to_string_lossy()
-              .into_owned(),
-          )
-        },
-        mac_address:  if iface.macAddress.is_null() {
-          None
-        } else {
-          Some(
-            unsafe { CStr::from_ptr(iface.macAddress) }
-              .to_string_lossy()
-              .into_owned(),
-          )
-        },
-        is_up:        iface.isUp,
-        is_loopback:  iface.isLoopback,
-      });
+/// Get the configured printers and their current state.
+///
+/// On Linux and macOS this queries the local CUPS scheduler over its domain
+/// socket; an error is returned if CUPS isn't running. A system with no
+/// printers yields an empty list.
+pub fn get_printers(cache: &CacheManager) -> Result<Vec<PrinterInfo>> {
+  let mut list = sys::DracPrinterInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetPrinters(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let printers = (0..list.count)
+      .map(|i| {
+        let printer = unsafe { &*list.items.add(i) };
+
+        PrinterInfo {
+          state:      PrinterState::from(printer.state),
+          name:       unsafe { string_or_empty(printer.name) },
+          driver:     unsafe { opt_string(printer.driver) },
+          connection: unsafe { opt_string(printer.connection) },
+          is_default: printer.isDefault,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreePrinterInfoList(&mut list) };
+    Ok(printers)
+  } else {
+    Err(DracError::last(result, "get_printers"))
+  }
+}
+
+/// Get the video capture devices and whether each is in use.
+///
+/// Listing cameras never starts a capture. The in-use flag is best-effort: on
+/// Linux, other users' processes are only visible to root, and on Windows
+/// usage is tracked per app, so every camera reports in use while any is.
+pub fn get_cameras(cache: &CacheManager) -> Result<Vec<CameraInfo>> {
+  let mut list = sys::DracCameraInfoList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetCameras(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let cameras = (0..list.count)
+      .map(|i| {
+        let camera = unsafe { &*list.items.add(i) };
+
+        CameraInfo {
+          name:   unsafe { string_or_empty(camera.name) },
+          id:     unsafe { string_or_empty(camera.id) },
+          in_use: camera.inUse,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeCameraInfoList(&mut list) };
+    Ok(cameras)
+  } else {
+    Err(DracError::last(result, "get_cameras"))
+  }
+}
+
+/// Get the connection details of the active Wi-Fi interface.
+pub fn get_wifi_info(cache: &CacheManager) -> Result<WifiInfo> {
+  let mut wifi = sys::DracWifiInfo {
+    interfaceName: std::ptr::null_mut(),
+    ssid:          std::ptr::null_mut(),
+    bssid:         std::ptr::null_mut(),
+    signalDbm:     i32::MIN,
+    signalPercent: -1,
+    frequencyMhz:  -1,
+    band:          DRAC_WIFI_BAND_UNKNOWN,
+    linkRateMbps:  f64::NAN,
+  };
+
+  let result = unsafe { sys::DracGetWifiInfo(cache.handle, &mut wifi) };
+
+  if result == DRAC_SUCCESS {
+    let info = WifiInfo {
+      interface_name: unsafe { string_or_empty(wifi.interfaceName) },
+      ssid:           unsafe { opt_string(wifi.ssid) },
+      bssid:          unsafe { opt_string(wifi.bssid) },
+      signal_dbm:     (wifi.signalDbm != i32::MIN).then_some(wifi.signalDbm),
+      signal_percent: u8::try_from(wifi.signalPercent).ok(),
+      frequency_mhz:  u32::try_from(wifi.frequencyMhz).ok(),
+      band:           WifiBand::from(wifi.band),
+      link_rate_mbps: (!wifi.linkRateMbps.is_nan()).then_some(wifi.linkRateMbps),
+    };
+
+    unsafe { sys::DracFreeWifiInfo(&mut wifi) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_wifi_info"))
+  }
+}
+
+/// Get the default gateway and the interface it is reached through.
+///
+/// The IPv4 default route is preferred over the IPv6 one.
+pub fn get_default_gateway(cache: &CacheManager) -> Result<Gateway> {
+  let mut gateway = sys::DracGateway {
+    address:       std::ptr::null_mut(),
+    interfaceName: std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetDefaultGateway(cache.handle, &mut gateway) };
+
+  if result == DRAC_SUCCESS {
+    let info = Gateway {
+      address:        unsafe { string_or_empty(gateway.address) },
+      interface_name: unsafe { string_or_empty(gateway.interfaceName) },
+    };
+
+    unsafe { sys::DracFreeGateway(&mut gateway) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_default_gateway"))
+  }
+}
+
+/// Get the DNS servers the system resolver is configured to use, in resolver
+/// order.
+pub fn get_dns_servers(cache: &CacheManager) -> Result<Vec<String>> {
+  let mut list = sys::DracStringList {
+    items: std::ptr::null_mut(),
+    count: 0,
+  };
+
+  let result = unsafe { sys::DracGetDnsServers(cache.handle, &mut list) };
+
+  if result == DRAC_SUCCESS {
+    let servers = (0..list.count)
+      .map(|i| {
+        let item = unsafe { *list.items.add(i) };
+
+        unsafe { string_or_empty(item) }
+      })
+      .collect();
+
+    unsafe { sys::DracFreeStringList(&mut list) };
+    Ok(servers)
+  } else {
+    Err(DracError::last(result, "get_dns_servers"))
+  }
+}
+
+/// Look up the public IPv4 and IPv6 addresses of this machine.
+///
+/// This makes outbound HTTP requests with a short timeout, and results are
+/// cached for 30 minutes. The library must be built with the `publicip`
+/// option; otherwise `ErrorCode::NotSupported` is returned.
+pub fn get_public_ip(cache: &CacheManager) -> Result<PublicIp> {
+  let mut ip = sys::DracPublicIp {
+    v4: std::ptr::null_mut(),
+    v6: std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetPublicIp(cache.handle, &mut ip) };
+
+  if result == DRAC_SUCCESS {
+    let info = PublicIp {
+      v4: unsafe { opt_string(ip.v4) },
+      v6: unsafe { opt_string(ip.v6) },
+    };
+
+    unsafe { sys::DracFreePublicIp(&mut ip) };
+    Ok(info)
+  } else {
+    Err(DracError::last(result, "get_public_ip"))
+  }
+}
+
+/// Identify the cloud provider, instance type, and region of this machine.
+///
+/// The provider is recognized from SMBIOS strings, so non-cloud machines
+/// return `ErrorCode::NotFound` without touching the network. On a cloud
+/// instance, the provider's metadata service is queried, and results are
+/// cached for a day. The library must be built with the `cloudinfo` option;
+/// otherwise `ErrorCode::NotSupported` is returned.
+pub fn get_cloud_info(cache: &CacheManager) -> Result<CloudInfo> {
+  let mut info = sys::DracCloudInfo {
+    provider:     DRAC_CLOUD_AWS,
+    instanceType: std::ptr::null_mut(),
+    region:       std::ptr::null_mut(),
+  };
+
+  let result = unsafe { sys::DracGetCloudInfo(cache.handle, &mut info) };
+
+  if result == DRAC_SUCCESS {
+    let cloud = CloudInfo {
+      provider:      CloudProvider::from(info.provider),
+      instance_type: unsafe { opt_string(info.instanceType) },
+      region:        unsafe { opt_string(info.region) },
+    };
+
+    unsafe { sys::DracFreeCloudInfo(&mut info) };
+    Ok(cloud)
+  } else {
+    Err(DracError::last(result, "get_cloud_info"))
+  }
+}
+
+// ============================== //
+//  Rate Sampling                 //
+// ============================== //
+
+/// Transfer rates of one network interface between two samples.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InterfaceRate {
+  pub name:               String,
+  pub rx_bytes_per_sec:   f64,
+  pub tx_bytes_per_sec:   f64,
+  pub rx_packets_per_sec: f64,
+  pub tx_packets_per_sec: f64,
+}
+
+/// I/O rates of one physical drive between two samples.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskRate {
+  pub device:              String,
+  pub read_iops:           f64,
+  pub write_iops:          f64,
+  pub read_bytes_per_sec:  f64,
+  pub write_bytes_per_sec: f64,
+}
+
+/// Rates derived by [`Sampler::tick`] over the time since the previous tick.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rates {
+  /// Time covered by these rates.
+  pub elapsed:    std::time::Duration,
+  /// Per-core CPU utilization as a percentage in `0.0..=100.0`.
+  pub cpu_usage:  Result<Vec<f64>>,
+  pub interfaces: Result<Vec<InterfaceRate>>,
+  pub disks:      Result<Vec<DiskRate>>,
+}
+
+/// Keeps the previous CPU, network, and disk counters and turns them into
+/// rates on every [`Sampler::tick`].
+///
+/// Interfaces and drives are matched by name, so ones that appear between
+/// ticks are only reported from the next tick on. A domain whose previous
+/// sample failed reports that error once more, since it has no baseline.
+///
+/// # Example
+/// ```ignore
+/// let cache = draconis::CacheManager::new();
+/// let mut sampler = draconis::Sampler::new(&cache);
+/// loop {
+///   std::thread::sleep(std::time::Duration::from_secs(1));
+///   println!("{:?}", sampler.tick(&cache).disks);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sampler {
+  taken_at:   std::time::Instant,
+  cpu:        Result<Vec<CPUTimes>>,
+  interfaces: Result<Vec<InterfaceStats>>,
+  disks:      Result<Vec<DiskIOStats>>,
+}
+
+impl Sampler {
+  /// Creates a sampler, taking the initial baseline sample.
+  pub fn new(cache: &CacheManager) -> Self {
+    Self {
+      taken_at:   std::time::Instant::now(),
+      cpu:        get_cpu_times_per_core(),
+      interfaces: get_network_stats(cache),
+      disks:      get_disk_io_stats(cache),
+    }
+  }
+
+  /// Takes a new sample and returns the rates since the previous one.
+  pub fn tick(&mut self, cache: &CacheManager) -> Rates {
+    let now = std::time::Instant::now();
+    let elapsed = now.duration_since(self.taken_at);
+    let secs = elapsed.as_secs_f64();
+    let per_sec = |previous: u64, current: u64| {
+      if secs > 0.0 {
+        current.saturating_sub(previous) as f64 / secs
+      } else {
+        0.0
+      }
+    };
+
+    let cpu = get_cpu_times_per_core();
+    let interfaces = get_network_stats(cache);
+    let disks = get_disk_io_stats(cache);
+
+    let rates = Rates {
+      elapsed,
+      cpu_usage: between(&self.cpu, &cpu, cpu_usage_between),
+      interfaces: between(&self.interfaces, &interfaces, |previous, current| {
+        current
+          .iter()
+          .filter_map(|curr| {
+            let prev = previous.iter().find(|prev| prev.name == curr.name)?;
+
+            Some(InterfaceRate {
+              name:               curr.name.clone(),
+              rx_bytes_per_sec:   per_sec(prev.rx_bytes, curr.rx_bytes),
+              tx_bytes_per_sec:   per_sec(prev.tx_bytes, curr.tx_bytes),
+              rx_packets_per_sec: per_sec(prev.rx_packets, curr.rx_packets),
+              tx_packets_per_sec: per_sec(prev.tx_packets, curr.tx_packets),
+            })
+          })
+          .collect()
+      }),
+      disks: between(&self.disks, &disks, |previous, current| {
+        current
+          .iter()
+          .filter_map(|curr| {
+            let prev = previous.iter().find(|prev| prev.device == curr.device)?;
+
+            Some(DiskRate {
+              device:              curr.device.clone(),
+              read_iops:           per_sec(prev.read_ops, curr.read_ops),
+              write_iops:          per_sec(prev.write_ops, curr.write_ops),
+              read_bytes_per_sec:  per_sec(prev.read_bytes, curr.read_bytes),
+              write_bytes_per_sec: per_sec(prev.write_bytes, curr.write_bytes),
+            })
+          })
+          .collect()
+      }),
+    };
+
+    self.taken_at = now;
+    self.cpu = cpu;
+    self.interfaces = interfaces;
+    self.disks = disks;
+
+    rates
+  }
+}
+
+/// Applies `rate` to two samples, or returns the error of whichever failed.
+fn between<T, R>(
+  previous: &Result<Vec<T>>,
+  current: &Result<Vec<T>>,
+  rate: impl FnOnce(&[T], &[T]) -> Vec<R>,
+) -> Result<Vec<R>> {
+  match (previous, current) {
+    (Ok(previous), Ok(current)) => Ok(rate(previous, current)),
+    (Err(error), _) | (_, Err(error)) => Err(error.clone()),
+  }
+}
+
+// ============================== //
+//  System Snapshot               //
+// ============================== //
+
+/// A snapshot of the most commonly queried system information.
+///
+/// Each field holds the result of its individual getter, so one failing
+/// query (e.g. no battery on a desktop) doesn't hide the others. Fields left
+/// out of a [`SystemInfo::collect_with`] selection are `None`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemInfo {
+  pub os:        Option<Result<OSInfo>>,
+  pub kernel:    Option<Result<String>>,
+  pub cpu_model: Option<Result<String>>,
+  pub cpu_cores: Option<Result<CPUCores>>,
+  pub memory:    Option<Result<ResourceUsage>>,
+  pub disks:     Option<Result<Vec<DiskInfo>>>,
+  pub displays:  Option<Result<Vec<DisplayInfo>>>,
+  pub network:   Option<Result<Vec<NetworkInterface>>>,
+  pub battery:   Option<Result<Battery>>,
+}
+
+impl SystemInfo {
+  /// Query every field of the snapshot using the given cache.
+  ///
+  /// The fields are independent, so the slow ones (disks, displays, network
+  /// interfaces) are queried on their own scoped threads; providers like WMI
+  /// and DXGI then overlap instead of adding up.
+  pub fn collect(cache: &CacheManager) -> Self {
+    Self::collect_with(cache, Selection::ALL)
+  }
+
+  /// Query only the fields in `selection`, e.g. for a periodic collector that
+  /// shouldn't pay for disk scans and display enumeration.
+  ///
+  /// Fields outside the selection are `None`.
+  ///
+  /// ```ignore
+  /// let info = SystemInfo::collect_with(&cache, Selection::CPU | Selection::MEMORY);
+  /// ```
+  pub fn collect_with(cache: &CacheManager, selection: Selection) -> Self {
+    // A thread only pays off when there's another slow query to overlap with
+    let slow = Selection::DISKS | Selection::DISPLAYS | Selection::NETWORK;
+    let threaded = (selection & slow).bits().count_ones() > 1;
+
+    std::thread::scope(|scope| {
+      let disks = selection
+        .contains(Selection::DISKS)
+        .then(|| Pending::start(scope, threaded, || get_disks(cache)));
+      let displays = selection
+        .contains(Selection::DISPLAYS)
+        .then(|| Pending::start(scope, threaded, || get_outputs(cache)));
+      let network = selection
+        .contains(Selection::NETWORK)
+        .then(|| Pending::start(scope, threaded, || get_network_interfaces(cache)));
+
+      Self {
+        os:        selection
+          .contains(Selection::OS)
+          .then(|| get_operating_system(cache)),
+        kernel:    selection
+          .contains(Selection::KERNEL)
+          .then(|| get_kernel_version(cache)),
+        cpu_model: selection
+          .contains(Selection::CPU)
+          .then(|| get_cpu_model(cache)),
+        cpu_cores: selection
+          .contains(Selection::CPU)
+          .then(|| get_cpu_cores(cache)),
+        memory:    selection
+          .contains(Selection::MEMORY)
+          .then(|| get_mem_info(cache)),
+        disks:     disks.map(Pending::finish),
+        displays:  displays.map(Pending::finish),
+        network:   network.map(Pending::finish),
+        battery:   selection
+          .contains(Selection::BATTERY)
+          .then(|| get_battery_info(cache)),
+      }
+    })
+  }
+}
+
+/// A query started by [`SystemInfo::collect_with`], either running on its own
+/// scoped thread or already finished on the caller's.
+enum Pending<'scope, T> {
+  Running(std::thread::ScopedJoinHandle<'scope, T>),
+  Done(T),
+}
+
+impl<'scope, T: Send + 'scope> Pending<'scope, T> {
+  fn start<'env>(
+    scope: &'scope std::thread::Scope<'scope, 'env>,
+    threaded: bool,
+    query: impl FnOnce() -> T + Send + 'scope,
+  ) -> Self {
+    if threaded {
+      Pending::Running(scope.spawn(query))
+    } else {
+      Pending::Done(query())
+    }
+  }
+
+  fn finish(self) -> T {
+    match self {
+      Pending::Running(handle) => join(handle),
+      Pending::Done(value) => value,
+    }
+  }
+}
+
+/// Defines a set of flags with the bitwise operators, for selecting which
+/// fields a batched query fills. `ALL` is every listed flag.
+macro_rules! flag_set {
+  (
+    $(#[$meta:meta])*
+    pub struct $name:ident($bits:ty) {
+      $($(#[$flag_meta:meta])* const $flag:ident = $value:expr;)*
+    }
+  ) => {
+    $(#[$meta])*
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct $name($bits);
+
+    impl $name {
+      $($(#[$flag_meta])* pub const $flag: Self = Self($value);)*
+
+      /// Every field.
+      pub const ALL: Self = Self(0 $(| $value)*);
+
+      /// No fields.
+      pub const fn empty() -> Self {
+        Self(0)
+      }
+
+      pub const fn bits(self) -> $bits {
+        self.0
+      }
+
+      pub const fn is_empty(self) -> bool {
+        self.0 == 0
+      }
+
+      /// Whether every field in `other` is also in `self`.
+      pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+      }
+    }
+
+    impl std::ops::BitOr for $name {
+      type Output = Self;
+
+      fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+      }
+    }
+
+    impl std::ops::BitOrAssign for $name {
+      fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+      }
+    }
+
+    impl std::ops::BitAnd for $name {
+      type Output = Self;
+
+      fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+      }
+    }
+
+    impl std::ops::BitAndAssign for $name {
+      fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+      }
+    }
+
+    impl std::ops::Not for $name {
+      type Output = Self;
+
+      /// Every field not in `self`.
+      fn not(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+      }
+    }
+
+    impl std::ops::Sub for $name {
+      type Output = Self;
+
+      /// The fields of `self` that aren't in `rhs`.
+      fn sub(self, rhs: Self) -> Self {
+        Self(self.0 & !rhs.0)
+      }
+    }
+
+    impl std::ops::SubAssign for $name {
+      fn sub_assign(&mut self, rhs: Self) {
+        self.0 &= !rhs.0;
+      }
+    }
+  };
+}
+
+flag_set! {
+  /// Which [`SystemInfo`] fields [`SystemInfo::collect_with`] queries, combined
+  /// with `|`.
+  pub struct Selection(u32) {
+    const OS = 1 << 0;
+    const KERNEL = 1 << 1;
+    /// CPU model and core counts.
+    const CPU = 1 << 2;
+    const MEMORY = 1 << 3;
+    const DISKS = 1 << 4;
+    const DISPLAYS = 1 << 5;
+    const NETWORK = 1 << 6;
+    const BATTERY = 1 << 7;
+  }
+}
+
+#[cfg(feature = "json")]
+impl SystemInfo {
+  /// Version of the JSON snapshot schema written by [`SystemInfo::to_json`].
+  ///
+  /// Bumped whenever a field is added, removed, or changes meaning.
+  pub const SCHEMA_VERSION: u32 = 2;
+
+  /// Serialize the snapshot as JSON, tagged with [`SystemInfo::SCHEMA_VERSION`].
+  pub fn to_json(&self) -> Result<String> {
+    let snapshot = JsonSnapshot {
+      schema_version: Self::SCHEMA_VERSION,
+      info:           std::borrow::Cow::Borrowed(self),
+    };
+
+    serde_json::to_string_pretty(&snapshot).map_err(|err| DracError {
+      code:      ErrorCode::InternalError,
+      message:   err.to_string(),
+      source_fn: "SystemInfo::to_json",
+    })
+  }
+
+  /// Load a snapshot previously written by [`SystemInfo::to_json`].
+  ///
+  /// Returns `ErrorCode::ParseError` for malformed JSON and
+  /// `ErrorCode::NotSupported` for snapshots of another schema version.
+  pub fn from_json(json: &str) -> Result<Self> {
+    #[derive(serde::Deserialize)]
+    struct Header {
+      schema_version: u32,
+    }
+
+    let parse_error = |err: serde_json::Error| DracError {
+      code:      ErrorCode::ParseError,
+      message:   err.to_string(),
+      source_fn: "SystemInfo::from_json",
+    };
+
+    let header: Header = serde_json::from_str(json).map_err(parse_error)?;
+
+    if header.schema_version != Self::SCHEMA_VERSION {
+      return Err(DracError::new(
+        ErrorCode::NotSupported,
+        "SystemInfo::from_json",
+      ));
+    }
+
+    let snapshot: JsonSnapshot = serde_json::from_str(json).map_err(parse_error)?;
+
+    Ok(snapshot.info.into_owned())
+  }
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonSnapshot<'a> {
+  schema_version: u32,
+  #[serde(flatten)]
+  info:           std::borrow::Cow<'a, SystemInfo>,
+}
+
+/// What changed between two [`SystemInfo`] snapshots.
+///
+/// Lists are only compared when both snapshots hold them, so a failed or
+/// unselected query doesn't report every disk or interface as added or
+/// removed.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotDiff {
+  /// Names of scalar fields whose value changed or became (un)available:
+  /// `os`, `kernel`, `cpu_model`, or `cpu_cores`.
+  pub changed_fields:   Vec<String>,
+  /// Disks present only in the newer snapshot, matched by mount point.
+  pub added_disks:      Vec<DiskInfo>,
+  pub removed_disks:    Vec<DiskInfo>,
+  /// Displays present only in the newer snapshot, matched by ID.
+  pub added_displays:   Vec<DisplayInfo>,
+  pub removed_displays: Vec<DisplayInfo>,
+  /// Interfaces whose IPv4 or IPv6 address changed, appeared, or vanished.
+  pub address_changes:  Vec<AddressChange>,
+  /// Growth of used memory in bytes (negative if it shrank), if both
+  /// snapshots report memory.
+  pub memory_growth:    Option<i64>,
+}
+
+/// A change in the addresses of one network interface.
+///
+/// An address is `None` if the interface had none, or didn't exist, in that
+/// snapshot.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddressChange {
+  pub interface: String,
+  pub old_ipv4:  Option<String>,
+  pub new_ipv4:  Option<String>,
+  pub old_ipv6:  Option<String>,
+  pub new_ipv6:  Option<String>,
+}
+
+impl SystemInfo {
+  /// Compare this snapshot against a newer one.
+  pub fn diff(&self, newer: &SystemInfo) -> SnapshotDiff {
+    fn ok<T>(value: &Option<Result<T>>) -> Option<&T> {
+      value.as_ref().and_then(|result| result.as_ref().ok())
+    }
+
+    fn key<T, K>(value: &Option<Result<T>>, key: impl Fn(&T) -> K) -> Option<K> {
+      ok(value).map(key)
+    }
+
+    fn only_in<T: Clone, K: PartialEq>(
+      items: &Option<Result<Vec<T>>>,
+      others: &Option<Result<Vec<T>>>,
+      key: impl Fn(&T) -> K,
+    ) -> Vec<T> {
+      match (ok(items), ok(others)) {
+        (Some(items), Some(others)) => items
+          .iter()
+          .filter(|item| !others.iter().any(|other| key(other) == key(item)))
+          .cloned()
+          .collect(),
+        _ => Vec::new(),
+      }
+    }
+
+    let mut changed_fields = Vec::new();
+
+    let os_key = |os: &OSInfo| (os.name.clone(), os.version.clone(), os.id.clone());
+    let cores_key = |cores: &CPUCores| (cores.physical, cores.logical);
+
+    if key(&self.os, os_key) != key(&newer.os, os_key) {
+      changed_fields.push("os".to_string());
+    }
+    if key(&self.kernel, String::clone) != key(&newer.kernel, String::clone) {
+      changed_fields.push("kernel".to_string());
+    }
+    if key(&self.cpu_model, String::clone) != key(&newer.cpu_model, String::clone) {
+      changed_fields.push("cpu_model".to_string());
+    }
+    if key(&self.cpu_cores, cores_key) != key(&newer.cpu_cores, cores_key) {
+      changed_fields.push("cpu_cores".to_string());
     }
 
-    unsafe { sys::DracFreeNetworkInterfaceList(&mut list) };
-    Ok(interfaces)
-  } else {
-    Err(ErrorCode::from(result))
+    let mut address_changes = Vec::new();
+
+    if let (Some(old), Some(new)) = (ok(&self.network), ok(&newer.network)) {
+      let mut names: Vec<&String> = old.iter().chain(new).map(|iface| &iface.name).collect();
+      names.sort();
+      names.dedup();
+
+      for name in names {
+        let old = old.iter().find(|iface| &iface.name == name);
+        let new = new.iter().find(|iface| &iface.name == name);
+
+        let change = AddressChange {
+          interface: name.clone(),
+          old_ipv4:  old.and_then(|iface| iface.ipv4_address.clone()),
+          new_ipv4:  new.and_then(|iface| iface.ipv4_address.clone()),
+          old_ipv6:  old.and_then(|iface| iface.ipv6_address.clone()),
+          new_ipv6:  new.and_then(|iface| iface.ipv6_address.clone()),
+        };
+
+        if change.old_ipv4 != change.new_ipv4 || change.old_ipv6 != change.new_ipv6 {
+          address_changes.push(change);
+        }
+      }
+    }
+
+    let mount_point = |disk: &DiskInfo| disk.mount_point.clone();
+    let display_id = |display: &DisplayInfo| display.id;
+
+    SnapshotDiff {
+      changed_fields,
+      added_disks: only_in(&newer.disks, &self.disks, mount_point),
+      removed_disks: only_in(&self.disks, &newer.disks, mount_point),
+      added_displays: only_in(&newer.displays, &self.displays, display_id),
+      removed_displays: only_in(&self.displays, &newer.displays, display_id),
+      address_changes,
+      memory_growth: match (ok(&self.memory), ok(&newer.memory)) {
+        (Some(old), Some(new)) => Some(new.used_bytes as i64 - old.used_bytes as i64),
+        _ => None,
+      },
+    }
   }
 }
 
-pub fn get_primary_network_interface(cache: &mut CacheManager) -> Result<NetworkInterface> {
-  let mut iface = sys::DracNetworkInterface {
-    name:        std::ptr::null_mut(),
-    ipv4Address: std::ptr::null_mut(),
-    ipv6Address: std::ptr::null_mut(),
-    macAddress:  std::ptr::null_mut(),
-    isUp:        false,
-    isLoopback:  false,
+fn join<T>(handle: std::thread::ScopedJoinHandle<'_, T>) -> T {
+  handle
+    .join()
+    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+}
+
+// ============================== //
+//  Batched Queries               //
+// ============================== //
+
+flag_set! {
+  /// A set of fields for [`collect_fields`], combined with `|`.
+  ///
+  /// ```ignore
+  /// let fields = cache.collect_fields(FieldMask::CPU_MODEL | FieldMask::MEMORY)?;
+  /// ```
+  pub struct FieldMask(u64) {
+    const CPU_MODEL = DRAC_FIELD_CPU_MODEL as u64;
+    const GPU_MODEL = DRAC_FIELD_GPU_MODEL as u64;
+    const HOST = DRAC_FIELD_HOST as u64;
+    const KERNEL_VERSION = DRAC_FIELD_KERNEL_VERSION as u64;
+    const OPERATING_SYSTEM = DRAC_FIELD_OPERATING_SYSTEM as u64;
+    const DESKTOP_ENVIRONMENT = DRAC_FIELD_DESKTOP_ENVIRONMENT as u64;
+    const WINDOW_MANAGER = DRAC_FIELD_WINDOW_MANAGER as u64;
+    const SHELL = DRAC_FIELD_SHELL as u64;
+    const CPU_CORES = DRAC_FIELD_CPU_CORES as u64;
+    const MEMORY = DRAC_FIELD_MEMORY as u64;
+    const SWAP = DRAC_FIELD_SWAP as u64;
+    const DISK_USAGE = DRAC_FIELD_DISK_USAGE as u64;
+    const BATTERY = DRAC_FIELD_BATTERY as u64;
+  }
+}
+
+impl FieldMask {
+  /// How many fields there are, one bit each.
+  pub const COUNT: usize = (DRAC_FIELD_BATTERY as u64).trailing_zeros() as usize + 1;
+}
+
+/// Fields filled by [`collect_fields`].
+///
+/// Fields outside the mask are `None`. The others hold what their individual
+/// getter would have returned.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldSet {
+  pub cpu_model:           Option<Result<String>>,
+  pub gpu_model:           Option<Result<String>>,
+  pub host:                Option<Result<String>>,
+  pub kernel_version:      Option<Result<String>>,
+  pub operating_system:    Option<Result<OSInfo>>,
+  pub desktop_environment: Option<Result<String>>,
+  pub window_manager:      Option<Result<String>>,
+  pub shell:               Option<Result<String>>,
+  pub cpu_cores:           Option<Result<CPUCores>>,
+  pub memory:              Option<Result<ResourceUsage>>,
+  pub swap:                Option<Result<ResourceUsage>>,
+  pub disk_usage:          Option<Result<ResourceUsage>>,
+  pub battery:             Option<Result<Battery>>,
+}
+
+/// Queries every field in `mask` with a single call into the library, for
+/// callers that refresh many fields at once.
+pub fn collect_fields(cache: &CacheManager, mask: FieldMask) -> Result<FieldSet> {
+  let usage = sys::DracResourceUsage {
+    usedBytes:  0,
+    totalBytes: 0,
   };
 
-  let result = unsafe { sys::DracGetPrimaryNetworkInterface(cache.handle, &mut iface) };
+  let mut set = sys::DracFieldSet {
+    requested:          0,
+    errors:             [DRAC_SUCCESS as _; FieldMask::COUNT],
+    messages:           [std::ptr::null_mut(); FieldMask::COUNT],
+    cpuModel:           std::ptr::null_mut(),
+    gpuModel:           std::ptr::null_mut(),
+    host:               std::ptr::null_mut(),
+    kernelVersion:      std::ptr::null_mut(),
+    operatingSystem:    sys::DracOSInfo {
+      name:    std::ptr::null_mut(),
+      version: std::ptr::null_mut(),
+      id:      std::ptr::null_mut(),
+    },
+    desktopEnvironment: std::ptr::null_mut(),
+    windowManager:      std::ptr::null_mut(),
+    shell:              std::ptr::null_mut(),
+    cpuCores:           sys::DracCPUCores {
+      physical: 0,
+      logical:  0,
+    },
+    memory:             usage,
+    swap:               usage,
+    diskUsage:          usage,
+    battery:            sys::DracBattery {
+      status:            DRAC_BATTERY_UNKNOWN,
+      percentage:        255,
+      timeRemainingSecs: -1,
+    },
+  };
 
-  if result == DRAC_SUCCESS {
-    let info = NetworkInterface {
-      name:         if iface.name.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(iface.name) }
-          .to_string_lossy()
-          .into_owned()
-      },
-      ipv4_address: if iface.ipv4Address.is_null() {
-        None
-      } else {
-        Some(
-          unsafe { CStr::from_ptr(iface.ipv4Address) }
-            .to_string_lossy()
-            .into_owned(),
-        )
-      },
-      ipv6_address: if iface.ipv6Address.is_null() {
+  let result = unsafe { sys::DracCollect(cache.handle, mask.0, &mut set) };
+
+  if result != DRAC_SUCCESS {
+    return Err(DracError::last(result, "collect_fields"));
+  }
+
+  let string = |ptr: *mut std::ffi::c_char| unsafe { string_or_empty(ptr) };
+
+  let usage = |usage: sys::DracResourceUsage| ResourceUsage {
+    used_bytes:  usage.usedBytes,
+    total_bytes: usage.totalBytes,
+  };
+
+  let fields = FieldSet {
+    cpu_model:           field(&set, FieldMask::CPU_MODEL, || string(set.cpuModel)),
+    gpu_model:           field(&set, FieldMask::GPU_MODEL, || string(set.gpuModel)),
+    host:                field(&set, FieldMask::HOST, || string(set.host)),
+    kernel_version:      field(&set, FieldMask::KERNEL_VERSION, || {
+      string(set.kernelVersion)
+    }),
+    operating_system:    field(&set, FieldMask::OPERATING_SYSTEM, || OSInfo {
+      name:    string(set.operatingSystem.name),
+      version: string(set.operatingSystem.version),
+      id:      string(set.operatingSystem.id),
+    }),
+    desktop_environment: field(&set, FieldMask::DESKTOP_ENVIRONMENT, || {
+      string(set.desktopEnvironment)
+    }),
+    window_manager:      field(&set, FieldMask::WINDOW_MANAGER, || {
+      string(set.windowManager)
+    }),
+    shell:               field(&set, FieldMask::SHELL, || string(set.shell)),
+    cpu_cores:           field(&set, FieldMask::CPU_CORES, || CPUCores {
+      physical: set.cpuCores.physical,
+      logical:  set.cpuCores.logical,
+    }),
+    memory:              field(&set, FieldMask::MEMORY, || usage(set.memory)),
+    swap:                field(&set, FieldMask::SWAP, || usage(set.swap)),
+    disk_usage:          field(&set, FieldMask::DISK_USAGE, || usage(set.diskUsage)),
+    battery:             field(&set, FieldMask::BATTERY, || Battery {
+      status:              BatteryStatus::from(set.battery.status),
+      percentage:          if set.battery.percentage == 255 {
         None
       } else {
-        Some(
-          unsafe { CStr::from_ptr(iface.ipv6Address) }
-            .to_string_lossy()
-            .into_owned(),
-        )
+        Some(set.battery.percentage)
       },
-      mac_address:  if iface.macAddress.is_null() {
+      time_remaining_secs: if set.battery.timeRemainingSecs < 0 {
         None
       } else {
-        Some(
-          unsafe { CStr::from_ptr(iface.macAddress) }
-            .to_string_lossy()
-            .into_owned(),
-        )
+        Some(set.battery.timeRemainingSecs)
       },
-      is_up:        iface.isUp,
-      is_loopback:  iface.isLoopback,
+    }),
+  };
+
+  unsafe { sys::DracFreeFieldSet(&mut set) };
+
+  Ok(fields)
+}
+
+/// Converts one field of a collected set, if it was requested.
+fn field<T>(
+  set: &sys::DracFieldSet,
+  flag: FieldMask,
+  convert: impl FnOnce() -> T,
+) -> Option<Result<T>> {
+  if set.requested & flag.0 == 0 {
+    return None;
+  }
+
+  let index = flag.0.trailing_zeros() as usize;
+  let code = set.errors[index];
+
+  Some(if code == DRAC_SUCCESS {
+    Ok(convert())
+  } else {
+    Err(DracError {
+      code:      ErrorCode::from(code),
+      message:   unsafe { string_or_empty(set.messages[index]) },
+      source_fn: "collect_fields",
+    })
+  })
+}
+
+// ============================== //
+//  System Events                 //
+// ============================== //
+
+/// What changed in a [`SystemEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SystemEventKind {
+  Unknown,
+  DeviceAdded,
+  DeviceRemoved,
+  BatteryChanged,
+  NetworkUp,
+  NetworkDown,
+  DisplayChanged,
+}
+
+impl From<DracSystemEventKind> for SystemEventKind {
+  fn from(kind: DracSystemEventKind) -> Self {
+    match kind {
+      DRAC_SYSTEM_EVENT_DEVICE_ADDED => SystemEventKind::DeviceAdded,
+      DRAC_SYSTEM_EVENT_DEVICE_REMOVED => SystemEventKind::DeviceRemoved,
+      DRAC_SYSTEM_EVENT_BATTERY_CHANGED => SystemEventKind::BatteryChanged,
+      DRAC_SYSTEM_EVENT_NETWORK_UP => SystemEventKind::NetworkUp,
+      DRAC_SYSTEM_EVENT_NETWORK_DOWN => SystemEventKind::NetworkDown,
+      DRAC_SYSTEM_EVENT_DISPLAY_CHANGED => SystemEventKind::DisplayChanged,
+      _ => SystemEventKind::Unknown,
+    }
+  }
+}
+
+/// A change reported by a [`Watcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemEvent {
+  pub kind:   SystemEventKind,
+  /// The device, power supply, or interface the event concerns, e.g.
+  /// `sdb`, `BAT0`, or `wlan0`, when the platform names it.
+  pub source: Option<String>,
+}
+
+fn system_event_from_c(event: &sys::DracSystemEvent) -> SystemEvent {
+  SystemEvent {
+    kind:   SystemEventKind::from(event.kind),
+    source: unsafe { opt_string(event.source) },
+  }
+}
+
+/// Receives OS change notifications (device hotplug, battery, network link,
+/// and display changes) as [`SystemEvent`]s.
+///
+/// Events are delivered by the OS rather than polled, so an idle watcher
+/// costs nothing. It can be drained with [`Watcher::wait`], iterated (each
+/// `next` blocks until an event arrives), or moved onto a background thread
+/// with [`Watcher::into_receiver`].
+///
+/// # Example
+/// ```ignore
+/// for event in draconis::Watcher::new()? {
+///   println!("{:?}", event?);
+/// }
+/// ```
+pub struct Watcher {
+  handle:  *mut sys::DracSystemWatcher,
+  pending: std::collections::VecDeque<SystemEvent>,
+}
+
+// The native watcher owns its sockets or notification thread and is only
+// touched through `&mut self`, so it may be moved to another thread.
+unsafe impl Send for Watcher {}
+
+impl Watcher {
+  pub fn new() -> Result<Self> {
+    let mut handle = std::ptr::null_mut();
+    let result = unsafe { sys::DracCreateSystemWatcher(&mut handle) };
+
+    if result == DRAC_SUCCESS {
+      Ok(Self {
+        handle,
+        pending: std::collections::VecDeque::new(),
+      })
+    } else {
+      Err(DracError::last(result, "Watcher::new"))
+    }
+  }
+
+  /// Waits up to `timeout` for events and returns them, or an empty list if
+  /// none arrived in time.
+  pub fn wait(&mut self, timeout: std::time::Duration) -> Result<Vec<SystemEvent>> {
+    if !self.pending.is_empty() {
+      return Ok(self.pending.drain(..).collect());
+    }
+
+    let timeout_ms = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+
+    let mut list = sys::DracSystemEventList {
+      items: std::ptr::null_mut(),
+      count: 0,
     };
 
-    unsafe { sys::DracFreeNetworkInterface(&mut iface) };
-    Ok(info)
+    let result = unsafe { sys::DracWaitSystemEvents(self.handle, timeout_ms, &mut list) };
+
+    if result == DRAC_SUCCESS {
+      let events = (0..list.count)
+        .map(|i| system_event_from_c(unsafe { &*list.items.add(i) }))
+        .collect();
+
+      unsafe { sys::DracFreeSystemEventList(&mut list) };
+      Ok(events)
+    } else {
+      Err(DracError::last(result, "Watcher::wait"))
+    }
+  }
+
+  /// Moves the watcher onto a background thread that forwards each event,
+  /// or the error that stopped it, to the returned channel.
+  ///
+  /// The thread exits after the first event that arrives once the receiver
+  /// has been dropped.
+  pub fn into_receiver(mut self) -> std::sync::mpsc::Receiver<Result<SystemEvent>> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+      for event in &mut self {
+        let stop = event.is_err();
+
+        if sender.send(event).is_err() || stop {
+          break;
+        }
+      }
+    });
+
+    receiver
+  }
+}
+
+impl Iterator for Watcher {
+  type Item = Result<SystemEvent>;
+
+  /// Blocks until the next event arrives. Never returns `None`.
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if let Some(event) = self.pending.pop_front() {
+        return Some(Ok(event));
+      }
+
+      match self.wait(std::time::Duration::from_millis(u64::from(u32::MAX))) {
+        Ok(events) => self.pending.extend(events),
+        Err(error) => return Some(Err(error)),
+      }
+    }
+  }
+}
+
+impl Drop for Watcher {
+  fn drop(&mut self) {
+    unsafe {
+      sys::DracDestroySystemWatcher(self.handle);
+    }
+  }
+}
+
+thread_local! {
+  /// The `user_data` of the callback running on this thread, if any.
+  static DISPATCHING: std::cell::Cell<*mut std::ffi::c_void> =
+    const { std::cell::Cell::new(std::ptr::null_mut()) };
+}
+
+/// Runs a subscription's callback for the library, marking it as running so a
+/// drop from inside it can be detected.
+///
+/// Unwinding out of an `extern "C"` function aborts the process, so a panic is
+/// caught here instead, and later calls are still made.
+fn dispatch(user_data: *mut std::ffi::c_void, call: impl FnOnce()) {
+  let outer = DISPATCHING.replace(user_data);
+  let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(call));
+  DISPATCHING.set(outer);
+}
+
+/// Whether `callback` is running on this thread, where unsubscribing would
+/// wait on itself.
+fn is_dispatching<T>(callback: *mut T) -> bool {
+  DISPATCHING.get() == callback.cast()
+}
+
+type SystemEventCallback = Box<dyn FnMut(&SystemEvent) + Send>;
+
+/// A callback registered with [`on_system_event`] or one of its filtered
+/// variants. Dropping it unregisters the callback, waiting for a call in
+/// progress to return.
+///
+/// Dropped from inside its own callback, it unregisters from another thread
+/// once the callback returns instead.
+pub struct Subscription {
+  handle:   *mut sys::DracSystemEventSubscription,
+  callback: *mut SystemEventCallback,
+}
+
+// Dropping only signals and joins the library's event thread, which is the
+// only place the callback runs.
+unsafe impl Send for Subscription {}
+
+impl Drop for Subscription {
+  fn drop(&mut self) {
+    if is_dispatching(self.callback) {
+      let deferred = Self {
+        handle:   self.handle,
+        callback: self.callback,
+      };
+      std::thread::spawn(move || drop(deferred));
+      return;
+    }
+
+    unsafe {
+      sys::DracUnsubscribeSystemEvents(self.handle);
+      drop(Box::from_raw(self.callback));
+    }
+  }
+}
+
+unsafe extern "C" fn dispatch_system_event(
+  event: *const sys::DracSystemEvent,
+  user_data: *mut std::ffi::c_void,
+) {
+  let callback = unsafe { &mut *user_data.cast::<SystemEventCallback>() };
+  let event = system_event_from_c(unsafe { &*event });
+
+  dispatch(user_data, || callback(&event));
+}
+
+/// Invokes `callback` for every [`SystemEvent`], from a thread owned by the
+/// library, until the returned [`Subscription`] is dropped.
+///
+/// This suits toolkits that run their own event loop: the callback can post
+/// the event to it rather than a thread having to block on a [`Watcher`].
+/// Calls are never concurrent. A panic in the callback is caught at the FFI
+/// boundary rather than aborting the process.
+///
+/// # Example
+/// ```ignore
+/// let _subscription = draconis::on_system_event(|event| println!("{event:?}"))?;
+/// ```
+pub fn on_system_event(
+  callback: impl FnMut(&SystemEvent) + Send + 'static,
+) -> Result<Subscription> {
+  let callback: *mut SystemEventCallback = Box::into_raw(Box::new(Box::new(callback)));
+  let mut handle = std::ptr::null_mut();
+
+  let result = unsafe {
+    sys::DracSubscribeSystemEvents(Some(dispatch_system_event), callback.cast(), &mut handle)
+  };
+
+  if result == DRAC_SUCCESS {
+    Ok(Subscription {
+      handle,
+      callback,
+    })
   } else {
-    Err(ErrorCode::from(result))
+    drop(unsafe { Box::from_raw(callback) });
+    Err(DracError::last(result, "on_system_event"))
   }
 }
 
+/// Like [`on_system_event`], but only for
+/// [`SystemEventKind::BatteryChanged`].
+pub fn on_battery_change(
+  mut callback: impl FnMut(&SystemEvent) + Send + 'static,
+) -> Result<Subscription> {
+  on_system_event(move |event| {
+    if event.kind == SystemEventKind::BatteryChanged {
+      callback(event);
+    }
+  })
+}
+
+/// Like [`on_system_event`], but only for [`SystemEventKind::NetworkUp`] and
+/// [`SystemEventKind::NetworkDown`].
+pub fn on_network_change(
+  mut callback: impl FnMut(&SystemEvent) + Send + 'static,
+) -> Result<Subscription> {
+  on_system_event(move |event| {
+    if matches!(
+      event.kind,
+      SystemEventKind::NetworkUp | SystemEventKind::NetworkDown
+    ) {
+      callback(event);
+    }
+  })
+}
+
 // ============================== //
 //  Plugin System                 //
 // ============================== //
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PluginType {
+  /// Provides fields, like the weather and now playing plugins.
+  InfoProvider,
+  /// Renders system info in additional output formats.
+  OutputFormat,
+}
+
+/// What a plugin declares it needs from the host.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PluginPermissions {
+  pub network:    bool,
+  pub filesystem: bool,
+  pub admin:      bool,
+  pub caching:    bool,
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PluginInfo {
-  pub name:        String,
-  pub version:     String,
-  pub author:      String,
-  pub description: String,
+  pub name:          String,
+  pub version:       String,
+  pub author:        String,
+  pub description:   String,
+  pub plugin_type:   PluginType,
+  /// The provider ID of an info provider, or the formats an output plugin
+  /// writes.
+  pub capabilities:  Vec<String>,
+  pub permissions:   PluginPermissions,
+  /// Description of the TOML keys the plugin accepts, as returned by
+  /// [`Plugin::config_schema`].
+  pub config_schema: Option<String>,
+  /// Linked into the library, rather than loaded from a search path.
+  pub is_static:     bool,
+}
+
+/// Limits for a plugin collected in a helper process, set with
+/// [`Plugin::set_sandbox`]. `None` leaves that resource unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PluginSandbox {
+  /// Memory the helper may map (Unix) or commit (Windows), in bytes.
+  pub max_memory_bytes: Option<u64>,
+  /// CPU time each collection may use, rounded up to whole seconds.
+  pub max_cpu_time:     Option<std::time::Duration>,
+  /// Wall-clock time before a collection is abandoned and the helper killed.
+  pub timeout:          Option<std::time::Duration>,
+}
+
+/// A loaded plugin, borrowing the [`PluginManager`] it was loaded through.
+pub struct Plugin<'m> {
+  handle:  *mut sys::DracPlugin,
+  manager: std::marker::PhantomData<&'m PluginManager>,
+}
+
+struct CancelTokenHandle(*mut sys::DracCancelToken);
+
+impl Drop for CancelTokenHandle {
+  fn drop(&mut self) {
+    unsafe { sys::DracFreeCancelToken(self.0) };
+  }
+}
+
+unsafe impl Send for CancelTokenHandle {}
+unsafe impl Sync for CancelTokenHandle {}
+
+/// Stops a [`Plugin::collect_data_cancellable`] call from waiting any longer.
+///
+/// Clones share the same state, so one can be handed to another thread and
+/// cancelled from there while the original is used to collect.
+#[derive(Clone)]
+pub struct CancellationToken {
+  inner: std::sync::Arc<CancelTokenHandle>,
+}
+
+impl CancellationToken {
+  pub fn new() -> Self {
+    Self {
+      inner: std::sync::Arc::new(CancelTokenHandle(unsafe { sys::DracCreateCancelToken() })),
+    }
+  }
+
+  /// Cancel the token. Collections waiting on it return promptly with an
+  /// [`ErrorCode::Cancelled`] error, as do later ones given the same token.
+  pub fn cancel(&self) {
+    unsafe { sys::DracCancelTokenCancel(self.inner.0) };
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    unsafe { sys::DracCancelTokenIsCancelled(self.inner.0) }
+  }
+}
+
+impl Default for CancellationToken {
+  fn default() -> Self {
+    Self::new()
+  }
 }
 
-pub struct Plugin {
-  handle: *mut sys::DracPlugin,
+impl std::fmt::Debug for CancellationToken {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CancellationToken")
+      .field("cancelled", &self.is_cancelled())
+      .finish()
+  }
 }
 
-impl Plugin {
-  pub fn new(plugin_name: &str) -> Result<Self> {
+impl<'m> Plugin<'m> {
+  pub fn new(_manager: &'m PluginManager, plugin_name: &str) -> Result<Self> {
     let c_name = match std::ffi::CString::new(plugin_name) {
       Ok(s) => s,
-      Err(_) => return Err(ErrorCode::InvalidArgument),
+      Err(_) => return Err(DracError::new(ErrorCode::InvalidArgument, "Plugin::new")),
     };
     let handle = unsafe { sys::DracLoadPlugin(c_name.as_ptr()) };
 
     if handle.is_null() {
-      Err(ErrorCode::NotFound)
+      Err(DracError::new(ErrorCode::NotFound, "Plugin::new"))
     } else {
-      Ok(Self { handle })
+      Ok(Self {
+        handle,
+        manager: std::marker::PhantomData,
+      })
     }
   }
 
-  pub fn from_path(path: &str) -> Result<Self> {
+  pub fn from_path(_manager: &'m PluginManager, path: &str) -> Result<Self> {
     let c_path = match std::ffi::CString::new(path) {
       Ok(s) => s,
-      Err(_) => return Err(ErrorCode::InvalidArgument),
+      Err(_) => {
+        return Err(DracError::new(
+          ErrorCode::InvalidArgument,
+          "Plugin::from_path",
+        ));
+      }
     };
     let handle = unsafe { sys::DracLoadPluginFromPath(c_path.as_ptr()) };
 
-    if handle.is_null() {
-      Err(ErrorCode::NotFound)
+    if handle.is_null() {
+      Err(DracError::new(ErrorCode::NotFound, "Plugin::from_path"))
+    } else {
+      Ok(Self {
+        handle,
+        manager: std::marker::PhantomData,
+      })
+    }
+  }
+
+  pub fn initialize(&mut self, cache: &CacheManager) -> Result<()> {
+    let result = unsafe { sys::DracPluginInitialize(self.handle, cache.handle) };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "Plugin::initialize"))
+    }
+  }
+
+  /// Set plugin configuration from a TOML string.
+  /// Must be called before `initialize()` for the config to take effect.
+  ///
+  /// # Example
+  /// ```ignore
+  /// let toml_config = r#"
+  /// enabled = true
+  /// provider = "openmeteo"
+  /// units = "metric"
+  /// [coords]
+  /// lat = 40.7128
+  /// lon = -74.0060
+  /// "#;
+  /// plugin.set_config(toml_config)?;
+  /// plugin.initialize(&cache)?;
+  /// ```
+  pub fn set_config(&mut self, toml_config: &str) -> Result<()> {
+    let c_config = std::ffi::CString::new(toml_config)
+      .map_err(|_| DracError::new(ErrorCode::InvalidArgument, "Plugin::set_config"))?;
+    let result = unsafe { sys::DracPluginSetConfig(self.handle, c_config.as_ptr()) };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "Plugin::set_config"))
+    }
+  }
+
+  /// Set plugin configuration from a typed value, such as [`WeatherConfig`],
+  /// serialized to TOML. Must be called before `initialize()` for the config
+  /// to take effect.
+  ///
+  /// # Example
+  /// ```ignore
+  /// plugin.set_config_value(&WeatherConfig {
+  ///   coords: Some(Coordinates { lat: 40.7128, lon: -74.0060 }),
+  ///   ..WeatherConfig::default()
+  /// })?;
+  /// plugin.initialize(&cache)?;
+  /// ```
+  #[cfg(feature = "config")]
+  pub fn set_config_value<T: serde::Serialize>(&mut self, config: &T) -> Result<()> {
+    let toml_config = toml::to_string(config)
+      .map_err(|_| DracError::new(ErrorCode::ConfigurationError, "Plugin::set_config_value"))?;
+
+    self.set_config(&toml_config)
+  }
+
+  /// A description of the TOML keys the plugin accepts, if it provides one.
+  pub fn config_schema(&self) -> Option<String> {
+    let ptr = unsafe { sys::DracPluginGetConfigSchema(self.handle) };
+
+    if ptr.is_null() {
+      None
     } else {
-      Ok(Self { handle })
+      let s = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+      unsafe { sys::DracFreeString(ptr) };
+      Some(s)
     }
   }
 
-  pub fn initialize(&mut self, cache: &mut CacheManager) -> Result<()> {
-    let result = unsafe { sys::DracPluginInitialize(self.handle, cache.handle) };
+  /// The names, types, units and descriptions of the fields the plugin emits,
+  /// available before the first collection.
+  ///
+  /// Empty if the plugin doesn't describe its fields.
+  pub fn field_schema(&self) -> Vec<PluginFieldSchema> {
+    let mut list = unsafe { sys::DracPluginGetFieldSchema(self.handle) };
 
-    if result == DRAC_SUCCESS {
-      Ok(())
-    } else {
-      Err(ErrorCode::from(result))
+    if list.items.is_null() || list.count == 0 {
+      return Vec::new();
     }
+
+    let schema = (0..list.count)
+      .map(|i| {
+        let item = unsafe { &*list.items.add(i) };
+
+        PluginFieldSchema {
+          name:        unsafe { string_or_empty(item.name) },
+          field_type:  PluginFieldType::from_c(item.type_ as u32),
+          unit:        unsafe { opt_string(item.unit) },
+          description: unsafe { string_or_empty(item.description) },
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreePluginFieldSchemaList(&mut list) };
+
+    schema
   }
 
-  /// Set plugin configuration from a TOML string.
-  /// Must be called before `initialize()` for the config to take effect.
+  /// Replace the plugin's instance with a fresh one, re-reading its library
+  /// from disk if it was loaded dynamically.
   ///
-  /// # Example
-  /// ```ignore
-  /// let toml_config = r#"
-  /// enabled = true
-  /// provider = "openmeteo"
-  /// units = "metric"
-  /// [coords]
-  /// lat = 40.7128
-  /// lon = -74.0060
-  /// "#;
-  /// plugin.set_config(toml_config)?;
-  /// plugin.initialize(&mut cache)?;
-  /// ```
-  pub fn set_config(&mut self, toml_config: &str) -> Result<()> {
-    let c_config = std::ffi::CString::new(toml_config).map_err(|_| ErrorCode::InvalidArgument)?;
-    let result = unsafe { sys::DracPluginSetConfig(self.handle, c_config.as_ptr()) };
+  /// Configuration from [`set_config`](Self::set_config) is not carried over;
+  /// set it again and call [`initialize`](Self::initialize) before collecting.
+  pub fn reload(&mut self, cache: &CacheManager) -> Result<()> {
+    let result = unsafe { sys::DracPluginReload(self.handle, cache.handle) };
 
     if result == DRAC_SUCCESS {
       Ok(())
     } else {
-      Err(ErrorCode::from(result))
+      Err(DracError::last(result, "Plugin::reload"))
     }
   }
 
@@ -802,13 +6282,110 @@ impl Plugin {
     unsafe { sys::DracPluginIsReady(self.handle) }
   }
 
-  pub fn collect_data(&mut self, cache: &mut CacheManager) -> Result<()> {
+  pub fn collect_data(&mut self, cache: &CacheManager) -> Result<()> {
     let result = unsafe { sys::DracPluginCollectData(self.handle, cache.handle) };
 
     if result == DRAC_SUCCESS {
       Ok(())
     } else {
-      Err(ErrorCode::from(result))
+      Err(DracError::last(result, "Plugin::collect_data"))
+    }
+  }
+
+  /// Like [`collect_data`](Self::collect_data), but gives up waiting after
+  /// `timeout` with an [`ErrorCode::Timeout`] error.
+  ///
+  /// Unless the plugin is [sandboxed](Self::set_sandbox), the collection
+  /// itself can't be interrupted, so it keeps running in the background. Until it finishes the plugin reports no fields and isn't
+  /// ready, and the next call waits for it rather than starting another.
+  /// Configuring, reloading or otherwise collecting from the plugin fails with
+  /// [`ErrorCode::Timeout`] in the meantime, and dropping it doesn't wait: the
+  /// plugin is unloaded once the collection returns.
+  pub fn collect_data_with_timeout(
+    &mut self,
+    cache: &CacheManager,
+    timeout: std::time::Duration,
+  ) -> Result<()> {
+    self.collect_data_until(cache, Some(timeout), None)
+  }
+
+  /// Like [`collect_data_with_timeout`](Self::collect_data_with_timeout), but
+  /// also stops waiting with an [`ErrorCode::Cancelled`] error once `token` is
+  /// cancelled. With no `timeout`, waits until the collection finishes or
+  /// `token` is cancelled.
+  pub fn collect_data_cancellable(
+    &mut self,
+    cache: &CacheManager,
+    timeout: Option<std::time::Duration>,
+    token: &CancellationToken,
+  ) -> Result<()> {
+    self.collect_data_until(cache, timeout, Some(token))
+  }
+
+  fn collect_data_until(
+    &mut self,
+    cache: &CacheManager,
+    timeout: Option<std::time::Duration>,
+    token: Option<&CancellationToken>,
+  ) -> Result<()> {
+    // 0 waits indefinitely, so even a zero timeout waits for at least 1ms
+    let timeout_ms = timeout.map_or(0, |timeout| {
+      timeout.as_millis().clamp(1, u32::MAX as u128) as u32
+    });
+    let token = token.map_or(std::ptr::null_mut(), |token| token.inner.0);
+
+    let result = unsafe {
+      sys::DracPluginCollectDataWithTimeout(self.handle, cache.handle, timeout_ms, token)
+    };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "Plugin::collect_data_with_timeout"))
+    }
+  }
+
+  /// Collects the plugin in a helper process from now on, so a crash, hang
+  /// or runaway allocation surfaces as an error instead of taking the
+  /// application down. `None` collects in-process again.
+  ///
+  /// The helper (`draconis-plugin-host`, or the executable the
+  /// `DRAC_PLUGIN_HOST` environment variable names) loads the plugin's
+  /// library and keeps its own instance between collections, restarting
+  /// after a crash. [`set_config`](Self::set_config) configures it too.
+  /// A crash fails the collection with [`ErrorCode::InternalError`], and
+  /// exceeding a limit with [`ErrorCode::Timeout`],
+  /// [`ErrorCode::ResourceExhausted`] or [`ErrorCode::OutOfMemory`].
+  /// [`get_fields`](Self::get_fields) and
+  /// [`get_last_error`](Self::get_last_error) report the helper's results.
+  /// [`collect_data_with_timeout`](Self::collect_data_with_timeout) kills
+  /// the helper once the timeout elapses or the token is cancelled.
+  ///
+  /// Fails with [`ErrorCode::NotSupported`] for plugins that weren't loaded
+  /// from a library, such as those registered with
+  /// [`drac_plugin!`](crate::drac_plugin).
+  pub fn set_sandbox(&mut self, sandbox: Option<PluginSandbox>) -> Result<()> {
+    let limits = sandbox.map(|sandbox| sys::DracPluginSandbox {
+      maxMemoryBytes: sandbox.max_memory_bytes.unwrap_or(0),
+      maxCpuSeconds:  sandbox.max_cpu_time.map_or(0, |time| {
+        (time.as_secs() + u64::from(time.subsec_nanos() > 0)).clamp(1, u64::from(u32::MAX)) as u32
+      }),
+      timeoutMs:      sandbox.timeout.map_or(0, |timeout| {
+        timeout.as_millis().clamp(1, u32::MAX as u128) as u32
+      }),
+    });
+
+    let result = unsafe {
+      sys::DracPluginSetSandbox(
+        self.handle,
+        limits.as_ref().map_or(std::ptr::null(), std::ptr::from_ref),
+      )
+    };
+
+    if result == DRAC_SUCCESS {
+      Ok(())
+    } else {
+      Err(DracError::last(result, "Plugin::set_sandbox"))
     }
   }
 
@@ -867,17 +6444,27 @@ impl Plugin {
         }
         PluginFieldValue::Object(items)
       }
-      _ => PluginFieldValue::String(String::new()),
+      DRAC_PLUGIN_FIELD_TIMESTAMP => {
+        let millis = unsafe { value.__bindgen_anon_1.timestampValue };
+        let offset = std::time::Duration::from_millis(millis.unsigned_abs());
+        PluginFieldValue::Timestamp(if millis >= 0 {
+          std::time::UNIX_EPOCH + offset
+        } else {
+          std::time::UNIX_EPOCH - offset
+        })
+      }
+      DRAC_PLUGIN_FIELD_NULL => PluginFieldValue::Null,
+      _ => PluginFieldValue::Null,
     }
   }
 
-  pub fn get_fields(&self) -> Result<std::collections::HashMap<String, PluginFieldValue>> {
-    let mut fields = unsafe { sys::DracPluginGetFields(self.handle) };
-
+  fn fields_from_c(
+    fields: &sys::DracPluginFieldList,
+  ) -> std::collections::HashMap<String, PluginFieldValue> {
     let mut result = std::collections::HashMap::new();
 
     if fields.items.is_null() || fields.count == 0 {
-      return Ok(result);
+      return result;
     }
 
     for i in 0..fields.count {
@@ -893,11 +6480,63 @@ impl Plugin {
       result.insert(key, value);
     }
 
+    result
+  }
+
+  pub fn get_fields(&self) -> Result<std::collections::HashMap<String, PluginFieldValue>> {
+    let mut fields = unsafe { sys::DracPluginGetFields(self.handle) };
+    let result = Self::fields_from_c(&fields);
+
     unsafe { sys::DracFreePluginFieldList(&mut fields) };
 
     Ok(result)
   }
 
+  /// Invokes `callback` with the plugin's fields each time the plugin pushes
+  /// an update, from a thread owned by the library, until the returned
+  /// [`PluginSubscription`] is dropped.
+  ///
+  /// Event-driven plugins, like now playing, push as soon as their data
+  /// changes, so it doesn't have to be polled with
+  /// [`collect_data`](Self::collect_data). Plugins that are only polled never
+  /// call back. Updates that arrive while the callback is running are
+  /// coalesced into the latest one, and the subscription carries over
+  /// [`reload`](Self::reload)s. A panic in the callback is caught at the FFI
+  /// boundary rather than aborting the process.
+  ///
+  /// # Example
+  /// ```ignore
+  /// let _subscription = plugin.subscribe(|fields| {
+  ///   println!("{:?}", NowPlaying::from_fields(fields));
+  /// })?;
+  /// ```
+  pub fn subscribe(
+    &self,
+    callback: impl FnMut(&std::collections::HashMap<String, PluginFieldValue>) + Send + 'static,
+  ) -> Result<PluginSubscription> {
+    let callback: *mut PluginUpdateCallback = Box::into_raw(Box::new(Box::new(callback)));
+    let mut handle = std::ptr::null_mut();
+
+    let result = unsafe {
+      sys::DracPluginSubscribe(
+        self.handle,
+        Some(dispatch_plugin_update),
+        callback.cast(),
+        &mut handle,
+      )
+    };
+
+    if result == DRAC_SUCCESS {
+      Ok(PluginSubscription {
+        handle,
+        callback,
+      })
+    } else {
+      drop(unsafe { Box::from_raw(callback) });
+      Err(DracError::last(result, "Plugin::subscribe"))
+    }
+  }
+
   pub fn get_last_error(&self) -> Option<String> {
     let ptr = unsafe { sys::DracPluginGetLastError(self.handle) };
 
@@ -911,7 +6550,7 @@ impl Plugin {
   }
 }
 
-impl Drop for Plugin {
+impl Drop for Plugin<'_> {
   fn drop(&mut self) {
     unsafe {
       sys::DracUnloadPlugin(self.handle);
@@ -919,64 +6558,564 @@ impl Drop for Plugin {
   }
 }
 
-pub fn initialize_plugin_manager() {
-  unsafe { sys::DracInitPluginManager() };
+type PluginUpdateCallback =
+  Box<dyn FnMut(&std::collections::HashMap<String, PluginFieldValue>) + Send>;
+
+/// A callback registered with [`Plugin::subscribe`]. Dropping it unregisters
+/// the callback, waiting for a call in progress to return.
+///
+/// It may outlive the [`Plugin`]. Dropped from inside its own callback, it
+/// unregisters from another thread once the callback returns instead.
+pub struct PluginSubscription {
+  handle:   *mut sys::DracPluginSubscription,
+  callback: *mut PluginUpdateCallback,
+}
+
+// Dropping only signals and joins the library's delivery thread, which is the
+// only place the callback runs.
+unsafe impl Send for PluginSubscription {}
+
+impl Drop for PluginSubscription {
+  fn drop(&mut self) {
+    if is_dispatching(self.callback) {
+      let deferred = Self {
+        handle:   self.handle,
+        callback: self.callback,
+      };
+      std::thread::spawn(move || drop(deferred));
+      return;
+    }
+
+    unsafe {
+      sys::DracPluginUnsubscribe(self.handle);
+      drop(Box::from_raw(self.callback));
+    }
+  }
+}
+
+unsafe extern "C" fn dispatch_plugin_update(
+  fields: *const sys::DracPluginFieldList,
+  user_data: *mut std::ffi::c_void,
+) {
+  let callback = unsafe { &mut *user_data.cast::<PluginUpdateCallback>() };
+  let fields = Plugin::fields_from_c(unsafe { &*fields });
+
+  dispatch(user_data, || callback(&fields));
 }
 
-pub fn shutdown_plugin_manager() {
-  unsafe { sys::DracShutdownPluginManager() };
+/// Playback state reported by the now playing plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlaybackState {
+  Playing,
+  Paused,
+  Stopped,
+  /// The player didn't report a state, or reported one not listed here.
+  Unknown,
 }
 
-pub fn add_plugin_search_path(path: &str) {
-  if let Ok(c_path) = std::ffi::CString::new(path) {
-    unsafe { sys::DracAddPluginSearchPath(c_path.as_ptr()) };
+impl From<&str> for PlaybackState {
+  fn from(state: &str) -> Self {
+    match state.to_ascii_lowercase().as_str() {
+      "playing" => PlaybackState::Playing,
+      "paused" => PlaybackState::Paused,
+      "stopped" => PlaybackState::Stopped,
+      _ => PlaybackState::Unknown,
+    }
   }
 }
 
-pub fn discover_plugins() -> Result<Vec<PluginInfo>> {
-  let mut list = unsafe { sys::DracDiscoverPlugins() };
+/// The media currently playing, read from the now playing plugin's fields.
+///
+/// Every field is optional, since players only report what they know.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NowPlaying {
+  pub title:    Option<String>,
+  /// Track artist. Multiple artists are joined with `, `.
+  pub artist:   Option<String>,
+  pub album:    Option<String>,
+  /// Elapsed playback time.
+  pub position: Option<std::time::Duration>,
+  /// Length of the track.
+  pub duration: Option<std::time::Duration>,
+  pub state:    PlaybackState,
+  /// Name of the player, e.g. `Spotify` or `mpv`.
+  pub player:   Option<String>,
+}
+
+impl NowPlaying {
+  /// Builds the typed view from the plugin's `title`, `artist`, `album`,
+  /// `position` and `duration` (in seconds), `state`, and `player` fields.
+  ///
+  /// Missing fields and fields of an unexpected type are left empty.
+  pub fn from_fields(fields: &std::collections::HashMap<String, PluginFieldValue>) -> Self {
+    let string = |key: &str| {
+      fields
+        .get(key)
+        .and_then(PluginFieldValue::as_str)
+        .filter(|value| !value.is_empty())
+        .map(str::to_owned)
+    };
+
+    let seconds = |key: &str| {
+      fields
+        .get(key)
+        .and_then(PluginFieldValue::as_f64)
+        .and_then(|value| std::time::Duration::try_from_secs_f64(value).ok())
+    };
+
+    let artist = match fields.get("artist") {
+      Some(PluginFieldValue::Array(artists)) => {
+        let names: Vec<&str> = artists
+          .iter()
+          .filter_map(PluginFieldValue::as_str)
+          .collect();
+        (!names.is_empty()).then(|| names.join(", "))
+      }
+      _ => string("artist"),
+    };
 
-  if list.items.is_null() || list.count == 0 {
-    return Ok(Vec::new());
+    Self {
+      title: string("title"),
+      artist,
+      album: string("album"),
+      position: seconds("position"),
+      duration: seconds("duration"),
+      state: string("state").map_or(PlaybackState::Unknown, |state| {
+        PlaybackState::from(state.as_str())
+      }),
+      player: string("player"),
+    }
   }
+}
 
-  let mut result = Vec::with_capacity(list.count);
+/// Get the media currently playing from a loaded now playing plugin.
+///
+/// Call [`Plugin::collect_data`] first to refresh it. Fails with
+/// [`ErrorCode::NotFound`] if the plugin reports no media.
+pub fn now_playing(plugin: &Plugin) -> Result<NowPlaying> {
+  let fields = plugin.get_fields()?;
 
-  for i in 0..list.count {
-    let info = unsafe { &*list.items.add(i) };
-    result.push(PluginInfo {
-      name:        if info.name.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(info.name) }
-          .to_string_lossy()
-          .into_owned()
-      },
-      version:     if info.version.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(info.version) }
-          .to_string_lossy()
-          .into_owned()
-      },
-      author:      if info.author.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(info.author) }
-          .to_string_lossy()
-          .into_owned()
-      },
-      description: if info.description.is_null() {
-        String::new()
-      } else {
-        unsafe { CStr::from_ptr(info.description) }
-          .to_string_lossy()
-          .into_owned()
-      },
-    });
+  if fields.is_empty() {
+    return Err(DracError::new(ErrorCode::NotFound, "now_playing"));
+  }
+
+  Ok(NowPlaying::from_fields(&fields))
+}
+
+/// Weather condition, classified from the weather plugin's condition text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeatherCondition {
+  Clear,
+  PartlyCloudy,
+  Cloudy,
+  Fog,
+  Drizzle,
+  Rain,
+  Snow,
+  Thunderstorm,
+  /// The provider's text didn't match any known condition.
+  Unknown,
+}
+
+impl From<&str> for WeatherCondition {
+  fn from(text: &str) -> Self {
+    let text = text.to_ascii_lowercase();
+
+    // Checked from most to least severe, since providers describe mixed
+    // weather like "light rain and snow" or "thunderstorm with rain".
+    if text.contains("thunder") || text.contains("storm") {
+      WeatherCondition::Thunderstorm
+    } else if text.contains("snow") || text.contains("sleet") || text.contains("hail") {
+      WeatherCondition::Snow
+    } else if text.contains("rain") || text.contains("shower") {
+      WeatherCondition::Rain
+    } else if text.contains("drizzle") {
+      WeatherCondition::Drizzle
+    } else if text.contains("fog") || text.contains("mist") || text.contains("haze") {
+      WeatherCondition::Fog
+    } else if text.contains("partly") || text.contains("few clouds") || text.contains("scattered") {
+      WeatherCondition::PartlyCloudy
+    } else if text.contains("cloud") || text.contains("overcast") {
+      WeatherCondition::Cloudy
+    } else if text.contains("clear") || text.contains("sunny") || text.contains("fair") {
+      WeatherCondition::Clear
+    } else {
+      WeatherCondition::Unknown
+    }
+  }
+}
+
+/// The current weather, read from the weather plugin's fields.
+///
+/// Measurements are in the units the plugin is configured with (`metric` or
+/// `imperial`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Weather {
+  /// Temperature in °C or °F.
+  pub temperature:    Option<f64>,
+  pub condition:      WeatherCondition,
+  /// The provider's own description, e.g. `light rain`.
+  pub description:    Option<String>,
+  /// Relative humidity in percent.
+  pub humidity:       Option<f64>,
+  /// Wind speed in m/s or mph.
+  pub wind_speed:     Option<f64>,
+  /// Direction the wind blows from, in degrees clockwise from north.
+  pub wind_direction: Option<f64>,
+  /// Name of the forecast location, e.g. `New York`.
+  pub location:       Option<String>,
+  /// Weather provider, e.g. `openmeteo`.
+  pub provider:       Option<String>,
+  /// When the provider was last queried; the plugin caches its results.
+  pub fetched_at:     Option<std::time::SystemTime>,
+}
+
+impl Weather {
+  /// Builds the typed view from the plugin's `temperature`, `condition`,
+  /// `description`, `humidity`, `wind_speed`, `wind_direction`, `location`,
+  /// `provider`, and `fetched_at` (a timestamp or Unix seconds) fields.
+  ///
+  /// The condition is classified from `condition`, falling back to
+  /// `description`. Missing fields and fields of an unexpected type are left
+  /// empty.
+  pub fn from_fields(fields: &std::collections::HashMap<String, PluginFieldValue>) -> Self {
+    let string = |key: &str| {
+      fields
+        .get(key)
+        .and_then(PluginFieldValue::as_str)
+        .filter(|value| !value.is_empty())
+        .map(str::to_owned)
+    };
+
+    let number = |key: &str| {
+      fields
+        .get(key)
+        .and_then(PluginFieldValue::as_f64)
+        .filter(|value| value.is_finite())
+    };
+
+    let description = string("description");
+
+    let condition = string("condition")
+      .or_else(|| description.clone())
+      .map_or(WeatherCondition::Unknown, |text| {
+        WeatherCondition::from(text.as_str())
+      });
+
+    let fetched_at = fields
+      .get("fetched_at")
+      .and_then(PluginFieldValue::as_timestamp)
+      .or_else(|| {
+        number("fetched_at")
+          .and_then(|seconds| std::time::Duration::try_from_secs_f64(seconds).ok())
+          .map(|since_epoch| std::time::UNIX_EPOCH + since_epoch)
+      });
+
+    Self {
+      temperature: number("temperature"),
+      condition,
+      description,
+      humidity: number("humidity"),
+      wind_speed: number("wind_speed"),
+      wind_direction: number("wind_direction"),
+      location: string("location"),
+      provider: string("provider"),
+      fetched_at,
+    }
+  }
+}
+
+/// Get the current weather from a loaded weather plugin.
+///
+/// Call [`Plugin::collect_data`] first to refresh it. Fails with
+/// [`ErrorCode::NotFound`] if the plugin has no data.
+pub fn weather(plugin: &Plugin) -> Result<Weather> {
+  let fields = plugin.get_fields()?;
+
+  if fields.is_empty() {
+    return Err(DracError::new(ErrorCode::NotFound, "weather"));
+  }
+
+  Ok(Weather::from_fields(&fields))
+}
+
+/// Weather data source used by the weather plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum WeatherProvider {
+  #[default]
+  OpenMeteo,
+  MetNo,
+  /// Requires [`WeatherConfig::api_key`].
+  OpenWeatherMap,
+}
+
+/// Unit system the weather plugin reports temperature and wind speed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum WeatherUnits {
+  #[default]
+  Metric,
+  Imperial,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinates {
+  pub lat: f64,
+  pub lon: f64,
+}
+
+/// Configuration for the weather plugin, passed to
+/// [`Plugin::set_config_value`].
+///
+/// Set either `coords` or, with OpenWeatherMap, `city`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct WeatherConfig {
+  pub enabled:  bool,
+  pub provider: WeatherProvider,
+  pub units:    WeatherUnits,
+  #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+  pub coords:   Option<Coordinates>,
+  /// City name, e.g. `New York, US`. Only supported by OpenWeatherMap.
+  #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+  pub city:     Option<String>,
+  #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+  pub api_key:  Option<String>,
+}
+
+impl Default for WeatherConfig {
+  fn default() -> Self {
+    Self {
+      enabled:  true,
+      provider: WeatherProvider::default(),
+      units:    WeatherUnits::default(),
+      coords:   None,
+      city:     None,
+      api_key:  None,
+    }
+  }
+}
+
+/// Configuration for the now playing plugin, passed to
+/// [`Plugin::set_config_value`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct NowPlayingConfig {
+  pub enabled: bool,
+}
+
+impl Default for NowPlayingConfig {
+  fn default() -> Self {
+    Self {
+      enabled: true,
+    }
+  }
+}
+
+/// Number of live [`PluginManager`]s.
+static PLUGIN_MANAGER_OWNERS: std::sync::Mutex<usize> = std::sync::Mutex::new(0);
+
+/// Keeps the plugin manager initialized for as long as it lives.
+///
+/// Plugins are loaded through it and borrow it, so none can outlive the
+/// shutdown. Any number can exist at once: the manager is initialized when the
+/// first is created and shut down when the last is dropped.
+///
+/// ```ignore
+/// let manager = draconis::PluginManager::new();
+/// let mut weather = draconis::Plugin::new(&manager, "weather")?;
+/// ```
+#[derive(Debug)]
+pub struct PluginManager {
+  _private: (),
+}
+
+impl PluginManager {
+  pub fn new() -> Self {
+    let mut owners = PLUGIN_MANAGER_OWNERS
+      .lock()
+      .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    if *owners == 0 {
+      unsafe { sys::DracInitPluginManager() };
+    }
+
+    *owners += 1;
+
+    Self {
+      _private: (),
+    }
+  }
+
+  pub fn add_search_path(&self, path: &str) {
+    if let Ok(c_path) = std::ffi::CString::new(path) {
+      unsafe { sys::DracAddPluginSearchPath(c_path.as_ptr()) };
+    }
+  }
+
+  /// Adds the directories plugins are installed to: those in
+  /// `DRAC_PLUGIN_PATH`, the XDG data directories and the usual library
+  /// directories on Unix, or `%LOCALAPPDATA%` and `%APPDATA%` on Windows.
+  ///
+  /// They're already added when the first manager is created; calling this
+  /// again picks up changes to the environment since then.
+  pub fn add_default_search_paths(&self) {
+    unsafe { sys::DracAddDefaultPluginSearchPaths() };
+  }
+
+  /// Reload every dynamically loaded plugin whose library changed on disk
+  /// since it was loaded, returning the names of the plugins that were
+  /// reloaded.
+  ///
+  /// Existing [`Plugin`] handles to those plugins keep working and refer to
+  /// the new instances. Call this from the thread that uses the plugins.
+  pub fn reload_changed(&self, cache: &CacheManager) -> Result<Vec<String>> {
+    let mut list = sys::DracStringList {
+      items: std::ptr::null_mut(),
+      count: 0,
+    };
+
+    let result = unsafe { sys::DracReloadChangedPlugins(cache.handle, &mut list) };
+
+    if result == DRAC_SUCCESS {
+      let names = (0..list.count)
+        .map(|i| {
+          let item = unsafe { *list.items.add(i) };
+
+          unsafe { string_or_empty(item) }
+        })
+        .collect();
+
+      unsafe { sys::DracFreeStringList(&mut list) };
+      Ok(names)
+    } else {
+      Err(DracError::last(result, "PluginManager::reload_changed"))
+    }
+  }
+
+  /// Watch the loaded plugins' libraries, checking for changes at most once
+  /// per `interval`.
+  ///
+  /// ```ignore
+  /// let mut watcher = manager.watch_paths(Duration::from_secs(1));
+  /// loop {
+  ///   for name in watcher.poll(&cache)? {
+  ///     eprintln!("reloaded {name}");
+  ///   }
+  ///   render(&plugins)?;
+  /// }
+  /// ```
+  pub fn watch_paths(&self, interval: std::time::Duration) -> PluginWatcher<'_> {
+    PluginWatcher {
+      manager: self,
+      interval,
+      last_check: None,
+    }
+  }
+
+  /// Describes every static plugin and every plugin library in the search
+  /// paths, sorted by name.
+  ///
+  /// Plugins that aren't loaded yet are instantiated briefly to read their
+  /// metadata, but not initialized.
+  pub fn discover(&self) -> Result<Vec<PluginInfo>> {
+    let mut list = unsafe { sys::DracDiscoverPlugins() };
+
+    if list.items.is_null() || list.count == 0 {
+      return Ok(Vec::new());
+    }
+
+    let result = (0..list.count)
+      .map(|i| {
+        let info = unsafe { &*list.items.add(i) };
+
+        PluginInfo {
+          name:          unsafe { string_or_empty(info.name) },
+          version:       unsafe { string_or_empty(info.version) },
+          author:        unsafe { string_or_empty(info.author) },
+          description:   unsafe { string_or_empty(info.description) },
+          plugin_type:   match info.type_ as u32 {
+            DRAC_PLUGIN_TYPE_OUTPUT_FORMAT => PluginType::OutputFormat,
+            _ => PluginType::InfoProvider,
+          },
+          capabilities:  (0..info.capabilities.count)
+            .map(|j| unsafe { string_or_empty(*info.capabilities.items.add(j)) })
+            .collect(),
+          permissions:   PluginPermissions {
+            network:    info.requiresNetwork,
+            filesystem: info.requiresFilesystem,
+            admin:      info.requiresAdmin,
+            caching:    info.requiresCaching,
+          },
+          config_schema: unsafe { opt_string(info.configSchema) },
+          is_static:     info.isStatic,
+        }
+      })
+      .collect();
+
+    unsafe { sys::DracFreePluginInfoList(&mut list) };
+
+    Ok(result)
+  }
+}
+
+impl Default for PluginManager {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Drop for PluginManager {
+  fn drop(&mut self) {
+    let mut owners = PLUGIN_MANAGER_OWNERS
+      .lock()
+      .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    *owners -= 1;
+
+    if *owners == 0 {
+      unsafe { sys::DracShutdownPluginManager() };
+    }
   }
+}
+
+/// Reloads plugin libraries as they change on disk, for iterating on a plugin
+/// while a long-running host keeps going. Created by
+/// [`PluginManager::watch_paths`].
+#[derive(Debug)]
+pub struct PluginWatcher<'m> {
+  manager:    &'m PluginManager,
+  interval:   std::time::Duration,
+  last_check: Option<std::time::Instant>,
+}
 
-  unsafe { sys::DracFreePluginInfoList(&mut list) };
+impl PluginWatcher<'_> {
+  /// Reload changed plugins if at least the watch interval has passed since
+  /// the last check, returning the names of the plugins that were reloaded.
+  ///
+  /// Meant to be called from the host's own refresh loop, on the thread that
+  /// uses the plugins.
+  pub fn poll(&mut self, cache: &CacheManager) -> Result<Vec<String>> {
+    let now = std::time::Instant::now();
+
+    if self
+      .last_check
+      .is_some_and(|last| now.duration_since(last) < self.interval)
+    {
+      return Ok(Vec::new());
+    }
 
-  Ok(result)
+    self.last_check = Some(now);
+    self.manager.reload_changed(cache)
+  }
 }
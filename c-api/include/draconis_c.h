@@ -29,6 +29,46 @@ extern "C" {
   // Opaque handle for CacheManager
   typedef struct DracCacheManager DracCacheManager;
 
+  // Cache key groups matching draconis::utils::cache::CacheDomain
+  typedef enum DracCacheDomain {
+    DRAC_CACHE_DOMAIN_CPU      = 0,
+    DRAC_CACHE_DOMAIN_GPU      = 1,
+    DRAC_CACHE_DOMAIN_NETWORK  = 2,
+    DRAC_CACHE_DOMAIN_SYSTEM   = 3,
+    DRAC_CACHE_DOMAIN_HARDWARE = 4,
+    DRAC_CACHE_DOMAIN_DESKTOP  = 5,
+    DRAC_CACHE_DOMAIN_PACKAGES = 6,
+  } DracCacheDomain;
+
+  typedef struct DracCacheRefresh {
+    DracCacheDomain domain;
+    int64_t         refreshedAt; // Seconds since the Unix epoch
+  } DracCacheRefresh;
+
+  typedef struct DracCacheStats {
+    uint64_t          hits;      // Lookups answered from memory, disk, or another caller's concurrent fetch
+    uint64_t          misses;    // Lookups that fetched from the system
+    size_t            entries;   // Values currently held in memory
+    DracCacheRefresh* refreshes; // Only domains that have fetched at least once
+    size_t            refreshCount;
+  } DracCacheStats;
+
+  // String getters available through DracGetStringRef
+  typedef enum DracStringField {
+    DRAC_STRING_CPU_MODEL           = 0,
+    DRAC_STRING_GPU_MODEL           = 1,
+    DRAC_STRING_HOST                = 2,
+    DRAC_STRING_KERNEL_VERSION      = 3,
+    DRAC_STRING_KERNEL_CMDLINE      = 4,
+    DRAC_STRING_DESKTOP_ENVIRONMENT = 5,
+    DRAC_STRING_WINDOW_MANAGER      = 6,
+    DRAC_STRING_SHELL               = 7,
+    DRAC_STRING_TERMINAL            = 8,
+    DRAC_STRING_TERMINAL_FONT       = 9,
+    DRAC_STRING_MACHINE_ID          = 10,
+    DRAC_STRING_HARDWARE_SERIAL     = 11,
+  } DracStringField;
+
   // Error codes matching draconis::utils::error::DracErrorCode
   typedef enum DracErrorCode {
     DRAC_ERROR_API_UNAVAILABLE     = 0,
@@ -49,6 +89,7 @@ extern "C" {
     DRAC_ERROR_RESOURCE_EXHAUSTED  = 15,
     DRAC_ERROR_TIMEOUT             = 16,
     DRAC_ERROR_UNAVAILABLE_FEATURE = 17,
+    DRAC_ERROR_CANCELLED           = 18,
     DRAC_SUCCESS                   = 255 // Not an error - operation succeeded
   } DracErrorCode;
 
@@ -57,11 +98,105 @@ extern "C" {
     uint64_t totalBytes;
   } DracResourceUsage;
 
+  typedef struct DracMemoryDetails {
+    uint64_t totalBytes;
+    uint64_t usedBytes;
+    uint64_t freeBytes;
+    uint64_t availableBytes;
+    uint64_t cachedBytes;  // 0 if not tracked by the platform
+    uint64_t buffersBytes; // 0 if not tracked by the platform
+    uint64_t sharedBytes;  // 0 if not tracked by the platform
+    uint64_t kernelBytes;  // 0 if not tracked by the platform
+  } DracMemoryDetails;
+
   typedef struct DracCPUCores {
     size_t physical;
     size_t logical;
   } DracCPUCores;
 
+  typedef struct DracCPUFrequency {
+    uint64_t currentMhz; // 0 if not available
+    uint64_t baseMhz;    // 0 if not available
+    uint64_t maxMhz;     // 0 if not available
+  } DracCPUFrequency;
+
+  typedef struct DracCPUDetails {
+    char*   architecture;
+    char*   vendor;   // NULL if not available
+    int64_t family;   // -1 if not available
+    int64_t model;    // -1 if not available
+    int64_t stepping; // -1 if not available
+    char**  flags;
+    size_t  flagCount;
+  } DracCPUDetails;
+
+  typedef enum DracCPUCacheType {
+    DRAC_CPU_CACHE_DATA        = 0,
+    DRAC_CPU_CACHE_INSTRUCTION = 1,
+    DRAC_CPU_CACHE_UNIFIED     = 2,
+  } DracCPUCacheType;
+
+  typedef struct DracCPUCacheInfo {
+    DracCPUCacheType type;
+    uint8_t          level;
+    uint64_t         sizeBytes;
+    uint32_t         lineSizeBytes; // 0 if not available
+    uint32_t         sharedCpuCount;
+    uint32_t         instanceCount;
+  } DracCPUCacheInfo;
+
+  typedef struct DracCPUCacheInfoList {
+    DracCPUCacheInfo* items;
+    size_t            count;
+  } DracCPUCacheInfoList;
+
+  typedef struct DracNumaNode {
+    uint32_t  id;
+    int64_t   totalMemoryBytes; // -1 if not available
+    int64_t   freeMemoryBytes;  // -1 if not available
+    uint32_t* cpus;
+    size_t    cpuCount;
+  } DracNumaNode;
+
+  typedef struct DracNumaNodeList {
+    DracNumaNode* items;
+    size_t        count;
+  } DracNumaNodeList;
+
+  typedef enum DracCPUVulnerabilityState {
+    DRAC_CPU_VULNERABILITY_UNKNOWN      = 0,
+    DRAC_CPU_VULNERABILITY_NOT_AFFECTED = 1,
+    DRAC_CPU_VULNERABILITY_MITIGATED    = 2,
+    DRAC_CPU_VULNERABILITY_VULNERABLE   = 3,
+  } DracCPUVulnerabilityState;
+
+  typedef struct DracCPUVulnerability {
+    char*                     name;
+    DracCPUVulnerabilityState state;
+    char*                     details; // NULL if not available
+  } DracCPUVulnerability;
+
+  typedef struct DracCPUVulnerabilityList {
+    DracCPUVulnerability* items;
+    size_t                count;
+  } DracCPUVulnerabilityList;
+
+  typedef struct DracCPUTimes {
+    uint64_t idle;
+    uint64_t total;
+  } DracCPUTimes;
+
+  typedef struct DracCPUTimesList {
+    DracCPUTimes* items;
+    size_t        count;
+  } DracCPUTimesList;
+
+  typedef struct DracLoadAverage {
+    double oneMinute;
+    double fiveMinutes;
+    double fifteenMinutes;
+  } DracLoadAverage;
+
   typedef struct DracOSInfo {
     char* name;
     char* version;
@@ -75,6 +210,11 @@ extern "C" {
     char*    driveType;
     uint64_t totalBytes;
     uint64_t usedBytes;
+    int64_t  totalInodes; // -1 if not available
+    int64_t  usedInodes;  // -1 if not available
+    char**   mountOptions;
+    size_t   mountOptionCount;
+    bool     isReadOnly;
     bool     isSystemDrive;
   } DracDiskInfo;
 
@@ -83,12 +223,151 @@ extern "C" {
     size_t        count;
   } DracDiskInfoList;
 
+  typedef enum DracNetworkMountProtocol {
+    DRAC_NETWORK_MOUNT_UNKNOWN = 0,
+    DRAC_NETWORK_MOUNT_NFS     = 1,
+    DRAC_NETWORK_MOUNT_SMB     = 2,
+    DRAC_NETWORK_MOUNT_SSHFS   = 3,
+    DRAC_NETWORK_MOUNT_WEBDAV  = 4,
+  } DracNetworkMountProtocol;
+
+  typedef enum DracNetworkMountState {
+    DRAC_NETWORK_MOUNT_STATE_UNKNOWN      = 0,
+    DRAC_NETWORK_MOUNT_STATE_CONNECTED    = 1,
+    DRAC_NETWORK_MOUNT_STATE_DISCONNECTED = 2,
+  } DracNetworkMountState;
+
+  typedef struct DracNetworkMount {
+    char*                    server;
+    char*                    exportPath;
+    char*                    mountPoint;
+    DracNetworkMountProtocol protocol;
+    DracNetworkMountState    state;
+  } DracNetworkMount;
+
+  typedef struct DracNetworkMountList {
+    DracNetworkMount* items;
+    size_t            count;
+  } DracNetworkMountList;
+
+  typedef enum DracDiskHealthStatus {
+    DRAC_DISK_HEALTH_UNKNOWN = 0,
+    DRAC_DISK_HEALTH_PASSED  = 1,
+    DRAC_DISK_HEALTH_FAILING = 2,
+  } DracDiskHealthStatus;
+
+  typedef struct DracSmartAttribute {
+    uint8_t  id;
+    char*    name;
+    uint8_t  value;
+    uint8_t  worst;
+    int32_t  threshold; // -1 if not available
+    uint64_t raw;
+  } DracSmartAttribute;
+
+  typedef struct DracDiskHealth {
+    char*                device;
+    DracDiskHealthStatus status;
+    int64_t              powerOnHours;       // -1 if not available
+    int64_t              reallocatedSectors; // -1 if not available
+    double               temperatureCelsius; // NaN if not available
+    DracSmartAttribute*  attributes;
+    size_t               attributeCount;
+  } DracDiskHealth;
+
+  typedef struct DracDiskTemperature {
+    char*  device;
+    double celsius;
+  } DracDiskTemperature;
+
+  typedef struct DracDiskTemperatureList {
+    DracDiskTemperature* items;
+    size_t               count;
+  } DracDiskTemperatureList;
+
+  typedef struct DracDiskIOStats {
+    char*    device;
+    uint64_t readOps;
+    uint64_t writeOps;
+    uint64_t readBytes;
+    uint64_t writeBytes;
+  } DracDiskIOStats;
+
+  typedef struct DracDiskIOStatsList {
+    DracDiskIOStats* items;
+    size_t           count;
+  } DracDiskIOStatsList;
+
+  typedef struct DracFanInfo {
+    char*   label;
+    int32_t rpm;       // -1 if not available (Windows)
+    int32_t targetRpm; // -1 if not available
+  } DracFanInfo;
+
+  typedef struct DracFanInfoList {
+    DracFanInfo* items;
+    size_t       count;
+  } DracFanInfoList;
+
+  typedef enum DracSensorKind {
+    DRAC_SENSOR_TEMPERATURE = 0, // Degrees Celsius
+    DRAC_SENSOR_VOLTAGE     = 1, // Volts
+    DRAC_SENSOR_CURRENT     = 2, // Amperes
+    DRAC_SENSOR_POWER       = 3, // Watts
+    DRAC_SENSOR_FAN         = 4, // RPM
+  } DracSensorKind;
+
+  typedef struct DracSensorReading {
+    DracSensorKind kind;
+    char*          chip;
+    char*          label;
+    double         value;
+  } DracSensorReading;
+
+  typedef struct DracSensorReadingList {
+    DracSensorReading* items;
+    size_t             count;
+  } DracSensorReadingList;
+
+  typedef enum DracDisplayConnector {
+    DRAC_DISPLAY_CONNECTOR_UNKNOWN      = 0,
+    DRAC_DISPLAY_CONNECTOR_INTERNAL     = 1,
+    DRAC_DISPLAY_CONNECTOR_EDP          = 2,
+    DRAC_DISPLAY_CONNECTOR_LVDS         = 3,
+    DRAC_DISPLAY_CONNECTOR_DSI          = 4,
+    DRAC_DISPLAY_CONNECTOR_HDMI         = 5,
+    DRAC_DISPLAY_CONNECTOR_DISPLAY_PORT = 6,
+    DRAC_DISPLAY_CONNECTOR_DVI          = 7,
+    DRAC_DISPLAY_CONNECTOR_VGA          = 8,
+    DRAC_DISPLAY_CONNECTOR_VIRTUAL      = 9,
+  } DracDisplayConnector;
+
+  typedef enum DracDisplayGamut {
+    DRAC_DISPLAY_GAMUT_UNKNOWN = 0,
+    DRAC_DISPLAY_GAMUT_SRGB    = 1,
+    DRAC_DISPLAY_GAMUT_P3      = 2,
+    DRAC_DISPLAY_GAMUT_BT2020  = 3,
+  } DracDisplayGamut;
+
   typedef struct DracDisplayInfo {
-    uint64_t id;
-    uint64_t width;
-    uint64_t height;
-    double   refreshRate;
-    bool     isPrimary;
+    uint64_t             id;
+    uint64_t             width;
+    uint64_t             height;
+    double               refreshRate;
+    bool                 isPrimary;
+    int32_t              positionX;   // Top-left corner in the virtual desktop
+    int32_t              positionY;
+    double               scaleFactor; // 1.0 when the platform applies no scaling
+    double               dpi;         // NaN if not available
+    uint8_t              colorDepth;  // Color bits per pixel, UINT8_MAX (255) if not available
+    uint16_t             rotation;    // Clockwise degrees (0, 90, 180 or 270)
+    DracDisplayConnector connector;
+    char*                manufacturer; // NULL if not available
+    char*                model;        // NULL if not available
+    char*                serial;       // NULL if not available
+    DracDisplayGamut     gamut;        // Widest gamut the display advertises
+    bool                 hdrSupported;
+    int32_t              hdrEnabled;   // 1 if enabled, 0 if not, -1 if unknown
   } DracDisplayInfo;
 
   typedef struct DracDisplayInfoList {
@@ -96,13 +375,52 @@ extern "C" {
     size_t           count;
   } DracDisplayInfoList;
 
+  typedef struct DracBacklightInfo {
+    char*  device;
+    double percent;
+  } DracBacklightInfo;
+
+  typedef struct DracBacklightInfoList {
+    DracBacklightInfo* items;
+    size_t             count;
+  } DracBacklightInfoList;
+
+  typedef struct DracGPUInfo {
+    char*    vendor;
+    char*    model;
+    uint64_t vramBytes; // 0 if not available
+    char*    driver;    // NULL if not available
+    bool     isIntegrated;
+    double   temperatureCelsius; // NaN if not available
+    double   powerWatts;         // NaN if not available
+    char*    driverVersion;      // NULL if not available
+    char*    vulkanVersion;      // NULL if not available
+    char*    openGLVersion;      // NULL if not available
+    char*    directXVersion;     // Direct3D feature level, e.g. "12_2"; NULL if not available
+    char*    metalVersion;       // NULL if not available
+  } DracGPUInfo;
+
+  typedef struct DracGPUInfoList {
+    DracGPUInfo* items;
+    size_t       count;
+  } DracGPUInfoList;
+
+  typedef enum DracDuplex {
+    DRAC_DUPLEX_UNKNOWN = 0,
+    DRAC_DUPLEX_HALF    = 1,
+    DRAC_DUPLEX_FULL    = 2,
+  } DracDuplex;
+
   typedef struct DracNetworkInterface {
-    char* name;
-    char* ipv4Address; // NULL if not available
-    char* ipv6Address; // NULL if not available
-    char* macAddress;  // NULL if not available
-    bool  isUp;
-    bool  isLoopback;
+    char*      name;
+    char*      ipv4Address; // NULL if not available
+    char*      ipv6Address; // NULL if not available
+    char*      macAddress;  // NULL if not available
+    bool       isUp;
+    bool       isLoopback;
+    int64_t    linkSpeedMbps; // -1 if not available
+    int32_t    mtu;           // -1 if not available
+    DracDuplex duplex;
   } DracNetworkInterface;
 
   typedef struct DracNetworkInterfaceList {
@@ -110,6 +428,23 @@ extern "C" {
     size_t                count;
   } DracNetworkInterfaceList;
 
+  typedef struct DracInterfaceStats {
+    char*    name;
+    uint64_t rxBytes;
+    uint64_t txBytes;
+    uint64_t rxPackets;
+    uint64_t txPackets;
+    uint64_t rxErrors;
+    uint64_t txErrors;
+    uint64_t rxDropped;
+    uint64_t txDropped;
+  } DracInterfaceStats;
+
+  typedef struct DracInterfaceStatsList {
+    DracInterfaceStats* items;
+    size_t              count;
+  } DracInterfaceStatsList;
+
   typedef enum DracBatteryStatus {
     DRAC_BATTERY_UNKNOWN     = 0,
     DRAC_BATTERY_CHARGING    = 1,
@@ -124,113 +459,1303 @@ extern "C" {
     int64_t           timeRemainingSecs; // -1 if not available
   } DracBattery;
 
+  // Fields DracCollect can fill, combined into a uint64_t mask
+  typedef enum DracField {
+    DRAC_FIELD_CPU_MODEL           = 1 << 0,
+    DRAC_FIELD_GPU_MODEL           = 1 << 1,
+    DRAC_FIELD_HOST                = 1 << 2,
+    DRAC_FIELD_KERNEL_VERSION      = 1 << 3,
+    DRAC_FIELD_OPERATING_SYSTEM    = 1 << 4,
+    DRAC_FIELD_DESKTOP_ENVIRONMENT = 1 << 5,
+    DRAC_FIELD_WINDOW_MANAGER      = 1 << 6,
+    DRAC_FIELD_SHELL               = 1 << 7,
+    DRAC_FIELD_CPU_CORES           = 1 << 8,
+    DRAC_FIELD_MEMORY              = 1 << 9,
+    DRAC_FIELD_SWAP                = 1 << 10,
+    DRAC_FIELD_DISK_USAGE          = 1 << 11,
+    DRAC_FIELD_BATTERY             = 1 << 12,
+  } DracField;
+
+  typedef struct DracFieldSet {
+    uint64_t          requested;          // The mask passed to DracCollect
+    DracErrorCode     errors[13];         // Indexed by bit position; DRAC_SUCCESS if the field was filled
+    char*             messages[13];       // Indexed like errors; the failed field's error message, or NULL
+    char*             cpuModel;           // NULL unless filled, likewise for the other strings
+    char*             gpuModel;
+    char*             host;
+    char*             kernelVersion;
+    DracOSInfo        operatingSystem;
+    char*             desktopEnvironment;
+    char*             windowManager;
+    char*             shell;
+    DracCPUCores      cpuCores;
+    DracResourceUsage memory;
+    DracResourceUsage swap;
+    DracResourceUsage diskUsage;
+    DracBattery       battery;
+  } DracFieldSet;
+
+  typedef struct DracBatteryDevice {
+    char*             name;
+    bool              isPeripheral;
+    DracBatteryStatus status;
+    uint8_t           percentage;        // UINT8_MAX (255) if not available
+    int64_t           timeRemainingSecs; // -1 if not available
+  } DracBatteryDevice;
+
+  typedef struct DracBatteryDeviceList {
+    DracBatteryDevice* items;
+    size_t             count;
+  } DracBatteryDeviceList;
+
+  typedef enum DracPowerMode {
+    DRAC_POWER_MODE_UNKNOWN     = 0,
+    DRAC_POWER_MODE_POWER_SAVER = 1,
+    DRAC_POWER_MODE_BALANCED    = 2,
+    DRAC_POWER_MODE_PERFORMANCE = 3,
+  } DracPowerMode;
+
+  typedef struct DracPowerProfile {
+    DracPowerMode mode;
+    char*         name;     // NULL if not available
+    char*         governor; // NULL if not available (always NULL outside Linux)
+  } DracPowerProfile;
+
+  typedef struct DracPowerSource {
+    bool   acConnected;
+    double chargerWatts; // NaN if not available
+    double usbPdWatts;   // NaN if not available or not a USB-PD source
+  } DracPowerSource;
+
+  typedef enum DracProcessState {
+    DRAC_PROCESS_UNKNOWN    = 0,
+    DRAC_PROCESS_RUNNING    = 1,
+    DRAC_PROCESS_SLEEPING   = 2,
+    DRAC_PROCESS_DISK_SLEEP = 3,
+    DRAC_PROCESS_STOPPED    = 4,
+    DRAC_PROCESS_ZOMBIE     = 5,
+    DRAC_PROCESS_IDLE       = 6,
+  } DracProcessState;
+
+  typedef struct DracProcessInfo {
+    uint32_t         pid;
+    uint32_t         ppid;
+    char*            name;
+    char*            cmdline;
+    double           cpuPercent;
+    uint64_t         rssBytes;
+    DracProcessState state;
+    char*            user; // NULL if not available
+  } DracProcessInfo;
+
+  typedef struct DracProcessInfoList {
+    DracProcessInfo* items;
+    size_t           count;
+  } DracProcessInfoList;
+
+  typedef enum DracInitSystemKind {
+    DRAC_INIT_SYSTEM_UNKNOWN                 = 0,
+    DRAC_INIT_SYSTEM_SYSTEMD                 = 1,
+    DRAC_INIT_SYSTEM_OPENRC                  = 2,
+    DRAC_INIT_SYSTEM_RUNIT                   = 3,
+    DRAC_INIT_SYSTEM_S6                      = 4,
+    DRAC_INIT_SYSTEM_DINIT                   = 5,
+    DRAC_INIT_SYSTEM_SYSVINIT                = 6,
+    DRAC_INIT_SYSTEM_LAUNCHD                 = 7,
+    DRAC_INIT_SYSTEM_SERVICE_CONTROL_MANAGER = 8,
+  } DracInitSystemKind;
+
+  typedef struct DracInitSystem {
+    DracInitSystemKind kind;
+    char*              name;
+  } DracInitSystem;
+
+  typedef enum DracServiceState {
+    DRAC_SERVICE_STATE_UNKNOWN  = 0,
+    DRAC_SERVICE_STATE_RUNNING  = 1,
+    DRAC_SERVICE_STATE_STOPPED  = 2,
+    DRAC_SERVICE_STATE_STARTING = 3,
+    DRAC_SERVICE_STATE_STOPPING = 4,
+    DRAC_SERVICE_STATE_FAILED   = 5,
+  } DracServiceState;
+
+  typedef struct DracServiceInfo {
+    DracServiceState state;
+    char*            name;
+    char*            description; // NULL if not available
+    int32_t          enabled;     // 1 if started at boot, 0 if not, -1 if unknown
+  } DracServiceInfo;
+
+  typedef struct DracServiceInfoList {
+    DracServiceInfo* items;
+    size_t           count;
+  } DracServiceInfoList;
+
+  typedef enum DracContainerRuntime {
+    DRAC_CONTAINER_RUNTIME_DOCKER = 0,
+    DRAC_CONTAINER_RUNTIME_PODMAN = 1,
+    DRAC_CONTAINER_RUNTIME_LXC    = 2,
+  } DracContainerRuntime;
+
+  typedef enum DracContainerState {
+    DRAC_CONTAINER_STATE_UNKNOWN    = 0,
+    DRAC_CONTAINER_STATE_RUNNING    = 1,
+    DRAC_CONTAINER_STATE_PAUSED     = 2,
+    DRAC_CONTAINER_STATE_RESTARTING = 3,
+  } DracContainerState;
+
+  typedef struct DracContainerInfo {
+    DracContainerRuntime runtime;
+    DracContainerState   state;
+    char*                id;
+    char*                name;
+    char*                image;       // NULL if not available (LXC)
+    double               cpuPercent;  // NaN if not available (Linux only)
+    int64_t              memoryBytes; // -1 if not available (Linux only)
+  } DracContainerInfo;
+
+  typedef struct DracContainerInfoList {
+    DracContainerInfo* items;
+    size_t             count;
+  } DracContainerInfoList;
+
+  typedef enum DracSessionType {
+    DRAC_SESSION_UNKNOWN   = 0,
+    DRAC_SESSION_GRAPHICAL = 1,
+    DRAC_SESSION_TERMINAL  = 2,
+    DRAC_SESSION_REMOTE    = 3,
+  } DracSessionType;
+
+  typedef struct DracSessionInfo {
+    DracSessionType type;
+    char*           username;
+    char*           terminal;
+    char*           remoteHost; // NULL if not a remote session
+    int64_t         loginTime;  // Seconds since the Unix epoch, -1 if not available
+  } DracSessionInfo;
+
+  typedef struct DracSessionInfoList {
+    DracSessionInfo* items;
+    size_t           count;
+  } DracSessionInfoList;
+
+  typedef enum DracDisplayServer {
+    DRAC_DISPLAY_SERVER_UNKNOWN = 0,
+    DRAC_DISPLAY_SERVER_WAYLAND = 1,
+    DRAC_DISPLAY_SERVER_X11     = 2,
+    DRAC_DISPLAY_SERVER_WINDOWS = 3,
+    DRAC_DISPLAY_SERVER_QUARTZ  = 4,
+    DRAC_DISPLAY_SERVER_TTY     = 5,
+  } DracDisplayServer;
+
+  typedef struct DracDisplaySession {
+    DracDisplayServer server;
+    char*             compositor; // NULL if not on Wayland or not detectable
+  } DracDisplaySession;
+
+  typedef struct DracThemeInfo {
+    char* widgetTheme; // NULL if not available
+    char* iconTheme;   // NULL if not available
+    char* cursorTheme; // NULL if not available
+    char* font;        // NULL if not available
+  } DracThemeInfo;
+
+  typedef struct DracDefaultApplications {
+    char* editor;   // NULL if not available
+    char* browser;  // NULL if not available
+    char* terminal; // NULL if not available
+  } DracDefaultApplications;
+
+  typedef enum DracAudioDirection {
+    DRAC_AUDIO_OUTPUT = 0,
+    DRAC_AUDIO_INPUT  = 1,
+  } DracAudioDirection;
+
+  typedef struct DracAudioDevice {
+    DracAudioDirection direction;
+    char*              id;
+    char*              name;
+    bool               isDefault;
+    int32_t            channels;   // -1 if not available
+    int32_t            sampleRate; // Hz, -1 if not available
+  } DracAudioDevice;
+
+  typedef struct DracAudioDeviceList {
+    DracAudioDevice* items;
+    size_t           count;
+  } DracAudioDeviceList;
+
+  typedef struct DracVolumeInfo {
+    double percent; // May exceed 100 where the backend allows amplification
+    bool   muted;
+  } DracVolumeInfo;
+
+  typedef struct DracBluetoothAdapter {
+    char* name;
+    char* address;
+    bool  powered;
+  } DracBluetoothAdapter;
+
+  typedef struct DracBluetoothDevice {
+    char*   name;
+    char*   address;
+    bool    paired;
+    bool    connected;
+    int32_t batteryPercent; // -1 if not available
+  } DracBluetoothDevice;
+
+  typedef struct DracBluetoothInfo {
+    DracBluetoothAdapter* adapters;
+    size_t                adapterCount;
+    DracBluetoothDevice*  devices;
+    size_t                deviceCount;
+  } DracBluetoothInfo;
+
+  typedef enum DracPrinterState {
+    DRAC_PRINTER_STATE_UNKNOWN  = 0,
+    DRAC_PRINTER_STATE_IDLE     = 1,
+    DRAC_PRINTER_STATE_PRINTING = 2,
+    DRAC_PRINTER_STATE_ERROR    = 3,
+  } DracPrinterState;
+
+  typedef struct DracPrinterInfo {
+    DracPrinterState state;
+    char*            name;
+    char*            driver;     // NULL if not available
+    char*            connection; // NULL if not available
+    bool             isDefault;
+  } DracPrinterInfo;
+
+  typedef struct DracPrinterInfoList {
+    DracPrinterInfo* items;
+    size_t           count;
+  } DracPrinterInfoList;
+
+  typedef struct DracCameraInfo {
+    char* name;
+    char* id;
+    bool  inUse;
+  } DracCameraInfo;
+
+  typedef struct DracCameraInfoList {
+    DracCameraInfo* items;
+    size_t          count;
+  } DracCameraInfoList;
+
+  typedef enum DracWifiBand {
+    DRAC_WIFI_BAND_UNKNOWN = 0,
+    DRAC_WIFI_BAND_2_4GHZ  = 1,
+    DRAC_WIFI_BAND_5GHZ    = 2,
+    DRAC_WIFI_BAND_6GHZ    = 3,
+  } DracWifiBand;
+
+  typedef struct DracWifiInfo {
+    char*        interfaceName;
+    char*        ssid;          // NULL if not available
+    char*        bssid;         // NULL if not available
+    int32_t      signalDbm;     // INT32_MIN if not available
+    int32_t      signalPercent; // -1 if not available
+    int32_t      frequencyMhz;  // -1 if not available
+    DracWifiBand band;
+    double       linkRateMbps; // NaN if not available
+  } DracWifiInfo;
+
+  typedef struct DracGateway {
+    char* address;
+    char* interfaceName;
+  } DracGateway;
+
+  typedef struct DracStringList {
+    char** items;
+    size_t count;
+  } DracStringList;
+
+  typedef struct DracPublicIp {
+    char* v4; // NULL if not available
+    char* v6; // NULL if not available
+  } DracPublicIp;
+
+  typedef enum DracCloudProvider {
+    DRAC_CLOUD_AWS           = 0,
+    DRAC_CLOUD_GCP           = 1,
+    DRAC_CLOUD_AZURE         = 2,
+    DRAC_CLOUD_HETZNER       = 3,
+    DRAC_CLOUD_DIGITAL_OCEAN = 4,
+    DRAC_CLOUD_ORACLE        = 5,
+  } DracCloudProvider;
+
+  typedef struct DracCloudInfo {
+    DracCloudProvider provider;
+    char*             instanceType; // NULL if not available
+    char*             region;       // NULL if not available
+  } DracCloudInfo;
+
+  typedef struct DracFirmwareInfo {
+    char* boardVendor;  // NULL if not available
+    char* boardModel;   // NULL if not available
+    char* boardVersion; // NULL if not available
+    char* vendor;       // NULL if not available
+    char* version;      // NULL if not available
+    char* releaseDate;  // NULL if not available
+    bool  isUefi;
+  } DracFirmwareInfo;
+
+  typedef enum DracHypervisor {
+    DRAC_HYPERVISOR_NONE       = 0,
+    DRAC_HYPERVISOR_KVM        = 1,
+    DRAC_HYPERVISOR_QEMU       = 2,
+    DRAC_HYPERVISOR_HYPERV     = 3,
+    DRAC_HYPERVISOR_VMWARE     = 4,
+    DRAC_HYPERVISOR_PARALLELS  = 5,
+    DRAC_HYPERVISOR_VIRTUALBOX = 6,
+    DRAC_HYPERVISOR_XEN        = 7,
+    DRAC_HYPERVISOR_APPLE      = 8,
+    DRAC_HYPERVISOR_OTHER      = 9,
+  } DracHypervisor;
+
+  typedef enum DracContainer {
+    DRAC_CONTAINER_NONE   = 0,
+    DRAC_CONTAINER_DOCKER = 1,
+    DRAC_CONTAINER_PODMAN = 2,
+    DRAC_CONTAINER_LXC    = 3,
+    DRAC_CONTAINER_WSL    = 4,
+    DRAC_CONTAINER_OTHER  = 5,
+  } DracContainer;
+
+  typedef struct DracVirtEnv {
+    DracHypervisor hypervisor;       // DRAC_HYPERVISOR_NONE on bare metal
+    DracContainer  container;        // DRAC_CONTAINER_NONE outside a container
+    char*          hypervisorVendor; // NULL if not available
+  } DracVirtEnv;
+
+  typedef enum DracSecureBootState {
+    DRAC_SECURE_BOOT_UNKNOWN     = 0,
+    DRAC_SECURE_BOOT_UNSUPPORTED = 1,
+    DRAC_SECURE_BOOT_DISABLED    = 2,
+    DRAC_SECURE_BOOT_ENABLED     = 3,
+  } DracSecureBootState;
+
+  typedef struct DracSecurityPlatformInfo {
+    DracSecureBootState secureBoot;   // DRAC_SECURE_BOOT_UNSUPPORTED on legacy BIOS boots
+    bool                tpmPresent;
+    char*               tpmVersion;   // "2.0" or "1.2"; NULL if no TPM or not reported
+    bool                measuredBoot;
+  } DracSecurityPlatformInfo;
+
+  typedef enum DracMacFramework {
+    DRAC_MAC_FRAMEWORK_NONE     = 0,
+    DRAC_MAC_FRAMEWORK_SELINUX  = 1,
+    DRAC_MAC_FRAMEWORK_APPARMOR = 2,
+  } DracMacFramework;
+
+  typedef enum DracMacMode {
+    DRAC_MAC_MODE_DISABLED   = 0,
+    DRAC_MAC_MODE_PERMISSIVE = 1,
+    DRAC_MAC_MODE_ENFORCING  = 2,
+  } DracMacMode;
+
+  typedef struct DracMacStatus {
+    DracMacFramework framework;
+    DracMacMode      mode;
+    char*            policy; // SELinux policy type, e.g. "targeted"; NULL for AppArmor or if not available
+  } DracMacStatus;
+
+  typedef enum DracFirewallBackend {
+    DRAC_FIREWALL_BACKEND_FIREWALLD        = 0,
+    DRAC_FIREWALL_BACKEND_UFW              = 1,
+    DRAC_FIREWALL_BACKEND_NFTABLES         = 2,
+    DRAC_FIREWALL_BACKEND_WINDOWS_FIREWALL = 3,
+    DRAC_FIREWALL_BACKEND_ALF              = 4,
+  } DracFirewallBackend;
+
+  typedef struct DracFirewallProfile {
+    char* name;
+    bool  enabled;
+  } DracFirewallProfile;
+
+  typedef struct DracFirewallStatus {
+    DracFirewallBackend  backend;
+    DracFirewallProfile* profiles;
+    size_t               profileCount;
+  } DracFirewallStatus;
+
+  typedef enum DracDomainMembership {
+    DRAC_DOMAIN_MEMBERSHIP_UNJOINED  = 0,
+    DRAC_DOMAIN_MEMBERSHIP_WORKGROUP = 1,
+    DRAC_DOMAIN_MEMBERSHIP_DOMAIN    = 2,
+  } DracDomainMembership;
+
+  typedef struct DracDomainInfo {
+    DracDomainMembership membership;
+    char*                name;          // Domain or workgroup NetBIOS name; NULL if not available
+    char*                dnsDomain;     // NULL unless joined to an Active Directory domain
+    bool                 azureAdJoined;
+    char*                azureTenant;   // NULL unless Azure AD joined
+  } DracDomainInfo;
+
+  typedef struct DracKernelModule {
+    char*   name;
+    int64_t sizeBytes; // -1 if not available (Windows)
+  } DracKernelModule;
+
+  typedef struct DracKernelModuleList {
+    DracKernelModule* items;
+    size_t            count;
+  } DracKernelModuleList;
+
+  typedef struct DracUserInfo {
+    char*   username;
+    int64_t uid;           // -1 if not available (Windows)
+    int64_t gid;           // -1 if not available (Windows)
+    char*   sid;           // NULL if not available (non-Windows)
+    char*   homeDirectory;
+    char*   shell;         // NULL if not available
+  } DracUserInfo;
+
+  typedef struct DracTimezoneInfo {
+    char*   name;
+    int32_t utcOffsetSeconds;
+  } DracTimezoneInfo;
+
+  typedef struct DracKeyboardLayout {
+    char* name;
+    char* variant;     // NULL if not available
+    char* description; // NULL if not available
+    bool  active;
+  } DracKeyboardLayout;
+
+  typedef struct DracKeyboardLayoutList {
+    DracKeyboardLayout* items;
+    size_t              count;
+  } DracKeyboardLayoutList;
+
+  // Opaque handle for SystemWatcher
+  typedef struct DracSystemWatcher DracSystemWatcher;
+
+  typedef enum DracSystemEventKind {
+    DRAC_SYSTEM_EVENT_DEVICE_ADDED    = 0,
+    DRAC_SYSTEM_EVENT_DEVICE_REMOVED  = 1,
+    DRAC_SYSTEM_EVENT_BATTERY_CHANGED = 2,
+    DRAC_SYSTEM_EVENT_NETWORK_UP      = 3,
+    DRAC_SYSTEM_EVENT_NETWORK_DOWN    = 4,
+    DRAC_SYSTEM_EVENT_DISPLAY_CHANGED = 5,
+  } DracSystemEventKind;
+
+  typedef struct DracSystemEvent {
+    DracSystemEventKind kind;
+    char*               source; // NULL if the platform doesn't say which device or interface changed
+  } DracSystemEvent;
+
+  typedef struct DracSystemEventList {
+    DracSystemEvent* items;
+    size_t           count;
+  } DracSystemEventList;
+
+  typedef struct DracSystemEventSubscription DracSystemEventSubscription;
+
+  /**
+   * Invoked on a subscription's event thread for each event.
+   * `event` and its strings are only valid for the duration of the call.
+   */
+  typedef void (*DracSystemEventCallback)(const DracSystemEvent* event, void* user_data);
+
+  /**
+   * Creates a new CacheManager instance.
+   * Must be destroyed with DracDestroyCacheManager.
+   * The instance is internally synchronized and may be used from several threads at once.
+   */
+  DRAC_C_API DracCacheManager* DracCreateCacheManager(void);
+
+  /**
+   * Destroys a CacheManager instance.
+   */
+  DRAC_C_API void DracDestroyCacheManager(DracCacheManager* mgr);
+
+  /**
+   * Overrides how long cached values in a domain stay fresh, measured from when each was stored.
+   * Applies to values already cached, and keeps each getter's cache location.
+   * @param mgr The cache manager instance.
+   * @param domain The domain to override.
+   * @param ttl_seconds How long values stay fresh. 0 makes every call fetch anew.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracSetCacheTtl(DracCacheManager* mgr, DracCacheDomain domain, uint32_t ttl_seconds);
+
+  /**
+   * Makes cached values in a domain never expire.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracSetCacheNeverExpire(DracCacheManager* mgr, DracCacheDomain domain);
+
+  /**
+   * Removes a domain's override, so its getters use their own cache policies again.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracResetCacheTtl(DracCacheManager* mgr, DracCacheDomain domain);
+
+  /**
+   * Removes every cached value in a domain, in memory and on disk, so the next call fetches anew.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracInvalidateCache(DracCacheManager* mgr, DracCacheDomain domain);
+
+  /**
+   * Removes every cached value, in memory and on disk.
+   * On-disk entries are shared, so this also affects other cache managers and processes.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracInvalidateAllCaches(DracCacheManager* mgr);
+
+  /**
+   * Starts fetching the cached values in each domain on a background thread, so later calls don't block on the system.
+   * Prefetches run one domain at a time, in the order requested. Destroying the manager waits for the one in progress.
+   * @param mgr The cache manager instance.
+   * @param domains The domains to prefetch.
+   * @param count Number of entries in `domains`.
+   * @return DRAC_SUCCESS once the prefetches are queued, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracPrefetchCache(DracCacheManager* mgr, const DracCacheDomain* domains, size_t count);
+
+  /**
+   * Checks whether a domain's prefetch has finished, successfully or not.
+   * Domains that were never prefetched are not ready.
+   * @param mgr The cache manager instance.
+   * @param domain The domain to check.
+   * @param out_ready Pointer to receive whether the domain is ready.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracIsCacheReady(DracCacheManager* mgr, DracCacheDomain domain, bool* out_ready);
+
+  /**
+   * Gets lookup counts since the manager was created, and when each domain last fetched from the system.
+   * @param mgr The cache manager instance.
+   * @param out_stats Pointer to struct to receive the stats. Caller must free with DracFreeCacheStats.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCacheStats(DracCacheManager* mgr, DracCacheStats* out_stats);
+
+  /**
+   * Frees a CacheStats struct's refresh list.
+   */
+  DRAC_C_API void DracFreeCacheStats(DracCacheStats* stats);
+
+  /**
+   * Frees a string allocated by the library.
+   */
+  DRAC_C_API void DracFreeString(const char* str);
+
+  /**
+   * Takes the message of the most recent error returned on the calling thread, clearing it.
+   * Errors raised by argument validation in the C API itself carry no message.
+   * @param out_code Receives the code the message belongs to, or DRAC_SUCCESS if there is none. May be NULL.
+   * @return The message, or NULL if none was recorded since the last call. Caller must free with DracFreeString.
+   */
+  DRAC_C_API char* DracGetLastErrorMessage(DracErrorCode* out_code);
+
+  /**
+   * Frees an OSInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeOSInfo(DracOSInfo* info);
+
+  /**
+   * Frees a CPUDetails struct's string members and flags.
+   */
+  DRAC_C_API void DracFreeCPUDetails(DracCPUDetails* details);
+
+  /**
+   * Frees a DiskInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeDiskInfo(DracDiskInfo* info);
+
+  /**
+   * Frees a DiskInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeDiskInfoList(DracDiskInfoList* list);
+
+  /**
+   * Frees a DiskHealth struct's string members and attribute array.
+   */
+  DRAC_C_API void DracFreeDiskHealth(DracDiskHealth* health);
+
+  /**
+   * Frees a DiskTemperatureList and all its contents.
+   */
+  DRAC_C_API void DracFreeDiskTemperatureList(DracDiskTemperatureList* list);
+
+  /**
+   * Frees a DiskIOStatsList and all its contents.
+   */
+  DRAC_C_API void DracFreeDiskIOStatsList(DracDiskIOStatsList* list);
+
+  /**
+   * Frees a SystemEventList and all its contents.
+   */
+  DRAC_C_API void DracFreeSystemEventList(DracSystemEventList* list);
+
+  /**
+   * Frees a FanInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeFanInfoList(DracFanInfoList* list);
+
+  /**
+   * Frees a SensorReadingList and all its contents.
+   */
+  DRAC_C_API void DracFreeSensorReadingList(DracSensorReadingList* list);
+
+  /**
+   * Frees a NetworkMountList and all its contents.
+   */
+  DRAC_C_API void DracFreeNetworkMountList(DracNetworkMountList* list);
+
+  /**
+   * Frees a KernelModuleList and all its contents.
+   */
+  DRAC_C_API void DracFreeKernelModuleList(DracKernelModuleList* list);
+
+  /**
+   * Frees a DisplayInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeDisplayInfo(DracDisplayInfo* info);
+
+  /**
+   * Frees a DisplayInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeDisplayInfoList(DracDisplayInfoList* list);
+
+  /**
+   * Frees a BacklightInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeBacklightInfoList(DracBacklightInfoList* list);
+
+  /**
+   * Frees a GPUInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeGPUInfoList(DracGPUInfoList* list);
+
+  /**
+   * Frees a ProcessInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeProcessInfo(DracProcessInfo* info);
+
+  /**
+   * Frees a ProcessInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeProcessInfoList(DracProcessInfoList* list);
+
+  /**
+   * Frees an InitSystem struct's string members.
+   */
+  DRAC_C_API void DracFreeInitSystem(DracInitSystem* init);
+
+  /**
+   * Frees a ServiceInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeServiceInfo(DracServiceInfo* info);
+
+  /**
+   * Frees a ServiceInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeServiceInfoList(DracServiceInfoList* list);
+
+  /**
+   * Frees a ContainerInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeContainerInfoList(DracContainerInfoList* list);
+
+  /**
+   * Frees a SessionInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeSessionInfoList(DracSessionInfoList* list);
+
+  /**
+   * Frees a DisplaySession struct's string members.
+   */
+  DRAC_C_API void DracFreeDisplaySession(DracDisplaySession* session);
+
+  /**
+   * Frees a ThemeInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeThemeInfo(DracThemeInfo* info);
+
+  /**
+   * Frees a DefaultApplications struct's string members.
+   */
+  DRAC_C_API void DracFreeDefaultApplications(DracDefaultApplications* apps);
+
+  /**
+   * Frees an AudioDeviceList and all its contents.
+   */
+  DRAC_C_API void DracFreeAudioDeviceList(DracAudioDeviceList* list);
+
+  /**
+   * Frees a UserInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeUserInfo(DracUserInfo* info);
+
+  /**
+   * Frees a TimezoneInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeTimezoneInfo(DracTimezoneInfo* info);
+
+  /**
+   * Frees a KeyboardLayoutList and all its contents.
+   */
+  DRAC_C_API void DracFreeKeyboardLayoutList(DracKeyboardLayoutList* list);
+
+  /**
+   * Frees a NetworkInterface struct's string members.
+   */
+  DRAC_C_API void DracFreeNetworkInterface(DracNetworkInterface* iface);
+
+  /**
+   * Frees a NetworkInterfaceList and all its contents.
+   */
+  DRAC_C_API void DracFreeNetworkInterfaceList(DracNetworkInterfaceList* list);
+
+  /**
+   * Frees an InterfaceStatsList and all its contents.
+   */
+  DRAC_C_API void DracFreeInterfaceStatsList(DracInterfaceStatsList* list);
+
+  /**
+   * Frees a BluetoothInfo struct's adapter and device arrays.
+   */
+  DRAC_C_API void DracFreeBluetoothInfo(DracBluetoothInfo* info);
+
+  /**
+   * Frees a PrinterInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreePrinterInfoList(DracPrinterInfoList* list);
+
+  /**
+   * Frees a CameraInfoList and all its contents.
+   */
+  DRAC_C_API void DracFreeCameraInfoList(DracCameraInfoList* list);
+
+  /**
+   * Frees a WifiInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeWifiInfo(DracWifiInfo* info);
+
+  /**
+   * Frees a Gateway struct's string members.
+   */
+  DRAC_C_API void DracFreeGateway(DracGateway* gateway);
+
+  /**
+   * Frees a StringList and all its contents.
+   */
+  DRAC_C_API void DracFreeStringList(DracStringList* list);
+
+  /**
+   * Frees a PublicIp struct's string members.
+   */
+  DRAC_C_API void DracFreePublicIp(DracPublicIp* ip);
+
+  /**
+   * Frees a CloudInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeCloudInfo(DracCloudInfo* info);
+
+  /**
+   * Frees a PowerProfile struct's string members.
+   */
+  DRAC_C_API void DracFreePowerProfile(DracPowerProfile* profile);
+
+  /**
+   * Frees a BatteryDeviceList and all its contents.
+   */
+  DRAC_C_API void DracFreeBatteryDeviceList(DracBatteryDeviceList* list);
+
+  /**
+   * Frees a FirmwareInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeFirmwareInfo(DracFirmwareInfo* info);
+
+  /**
+   * Frees a VirtEnv struct's string members.
+   */
+  DRAC_C_API void DracFreeVirtEnv(DracVirtEnv* env);
+
+  /**
+   * Frees a SecurityPlatformInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeSecurityPlatformInfo(DracSecurityPlatformInfo* info);
+
+  /**
+   * Frees a MacStatus struct's string members.
+   */
+  DRAC_C_API void DracFreeMacStatus(DracMacStatus* status);
+
+  /**
+   * Frees a FirewallStatus struct's profiles.
+   */
+  DRAC_C_API void DracFreeFirewallStatus(DracFirewallStatus* status);
+
+  /**
+   * Frees a DomainInfo struct's string members.
+   */
+  DRAC_C_API void DracFreeDomainInfo(DracDomainInfo* info);
+
+  /**
+   * Frees a CPUTimesList.
+   */
+  DRAC_C_API void DracFreeCPUTimesList(DracCPUTimesList* list);
+
+  /**
+   * Frees a CPUCacheInfoList.
+   */
+  DRAC_C_API void DracFreeCPUCacheInfoList(DracCPUCacheInfoList* list);
+
+  /**
+   * Frees a NumaNodeList and all its contents.
+   */
+  DRAC_C_API void DracFreeNumaNodeList(DracNumaNodeList* list);
+
+  /**
+   * Frees a CPUVulnerabilityList and all its contents.
+   */
+  DRAC_C_API void DracFreeCPUVulnerabilityList(DracCPUVulnerabilityList* list);
+
+  /**
+   * Gets the system uptime in seconds.
+   * @return Uptime in seconds, 0 on error.
+   */
+  DRAC_C_API uint64_t DracGetUptime(void);
+
+  /**
+   * Gets the time the system booted, read from a value the kernel records at boot.
+   * @param out_boot_time Pointer to receive the boot time in seconds since the Unix epoch.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetBootTime(int64_t* out_boot_time);
+
+  /**
+   * Gets the 1, 5, and 15 minute system load averages.
+   * Not supported on Windows.
+   * @param out_load Pointer to struct to receive data.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetLoadAverage(DracLoadAverage* out_load);
+
+  /**
+   * Gets information about every running process.
+   * CPU usage is averaged over each process's lifetime.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeProcessInfoList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetProcesses(DracCacheManager* mgr, DracProcessInfoList* out_list);
+
+  /**
+   * Gets information about a single process without scanning the whole process table.
+   * @param pid The process ID to look up.
+   * @param out_process Pointer to struct to receive data. Caller must free with DracFreeProcessInfo.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no such process exists, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetProcess(uint32_t pid, DracProcessInfo* out_process);
+
+  /**
+   * Gets information about every process with the given executable name.
+   * Only matching processes are fully queried. On Windows the match is
+   * case-insensitive and the ".exe" suffix may be omitted.
+   * @param name The executable name to match.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeProcessInfoList.
+   * @return DRAC_SUCCESS on success (even if nothing matched), error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracFindProcesses(const char* name, DracProcessInfoList* out_list);
+
+  /**
+   * Gets the init system or service manager running as the system's first process.
+   * @param mgr The cache manager instance.
+   * @param out_init Pointer to struct to receive data. Caller must free with DracFreeInitSystem.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetInitSystem(DracCacheManager* mgr, DracInitSystem* out_init);
+
+  /**
+   * Gets the state of a single service. On Linux a missing ".service" suffix is added for systemd.
+   * @param name The service name, e.g. "sshd".
+   * @param out_service Pointer to struct to receive data. Caller must free with DracFreeServiceInfo.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no such service exists, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetServiceStatus(const char* name, DracServiceInfo* out_service);
+
+  /**
+   * Gets every service known to the init system.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeServiceInfoList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_SUPPORTED if the init system cannot be queried, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracListServices(DracServiceInfoList* out_list);
+
+  /**
+   * Gets the running Docker, Podman, and LXC containers.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeContainerInfoList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no container runtime is running, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetContainers(DracCacheManager* mgr, DracContainerInfoList* out_list);
+
+  /**
+   * Gets the logged-in user sessions.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeSessionInfoList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetSessions(DracSessionInfoList* out_list);
+
+  /**
+   * Gets the user account the current process runs as.
+   * @param out_user Pointer to struct to receive data. Caller must free with DracFreeUserInfo.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCurrentUser(DracUserInfo* out_user);
+
+  /**
+   * Gets the user's locale (e.g., "en_US.UTF-8" on POSIX, "en-US" on Windows).
+   * @param out_str Pointer to receive the string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetLocale(char** out_str);
+
+  /**
+   * Gets the local timezone's IANA name and the UTC offset currently in effect.
+   * @param out_tz Pointer to struct to receive data. Caller must free with DracFreeTimezoneInfo.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetTimezone(DracTimezoneInfo* out_tz);
+
+  /**
+   * Gets the configured keyboard layouts and which one is active. Never cached.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeKeyboardLayoutList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetKeyboardLayouts(DracKeyboardLayoutList* out_list);
+
+  /**
+   * Gets memory usage information.
+   * @param mgr The cache manager instance.
+   * @param out_usage Pointer to struct to receive data.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetMemInfo(DracCacheManager* mgr, DracResourceUsage* out_usage);
+
+  /**
+   * Gets a detailed breakdown of physical memory, matching what `free` reports.
+   * @param mgr The cache manager instance.
+   * @param out_details Pointer to struct to receive data.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetMemoryDetails(DracCacheManager* mgr, DracMemoryDetails* out_details);
+
+  /**
+   * Gets swap usage. A system without swap reports a total of 0.
+   * @param mgr The cache manager instance.
+   * @param out_usage Pointer to struct to receive data.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetSwapInfo(DracCacheManager* mgr, DracResourceUsage* out_usage);
+
+  /**
+   * Gets CPU cores information.
+   * @param mgr The cache manager instance.
+   * @param out_cores Pointer to struct to receive data.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCpuCores(DracCacheManager* mgr, DracCPUCores* out_cores);
+
+  /**
+   * Gets the current, base, and maximum CPU clock speeds.
+   * @param mgr The cache manager instance.
+   * @param out_freq Pointer to struct to receive data.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCPUFrequency(DracCacheManager* mgr, DracCPUFrequency* out_freq);
+
+  /**
+   * Gets the CPU's architecture, vendor, family/model/stepping, and feature flags.
+   * @param mgr The cache manager instance.
+   * @param out_details Pointer to struct to receive data. Caller must free with DracFreeCPUDetails.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCPUDetails(DracCacheManager* mgr, DracCPUDetails* out_details);
+
+  /**
+   * Gets the CPU cache hierarchy, one entry per cache level and type.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeCPUCacheInfoList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no cache information is exposed, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCPUCaches(DracCacheManager* mgr, DracCPUCacheInfoList* out_list);
+
+  /**
+   * Gets the NUMA nodes, their memory, and which CPUs belong to each. Never cached.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeNumaNodeList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if NUMA information is unavailable, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetNumaTopology(DracCacheManager* mgr, DracNumaNodeList* out_list);
+
+  /**
+   * Gets the known CPU vulnerabilities and whether each is mitigated.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeCPUVulnerabilityList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_SUPPORTED if the platform doesn't report them, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCPUVulnerabilities(DracCacheManager* mgr, DracCPUVulnerabilityList* out_list);
+
+  /**
+   * Gets the CPU package temperature.
+   * @param mgr The cache manager instance.
+   * @param out_celsius Pointer to receive the temperature in degrees Celsius.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCPUTemperature(DracCacheManager* mgr, double* out_celsius);
+
+  /**
+   * Gets the fans reported by the hardware monitoring sensors.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeFanInfoList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no fans are exposed, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetFans(DracCacheManager* mgr, DracFanInfoList* out_list);
+
+  /**
+   * Gets every temperature, voltage, current, power and fan sensor the platform exposes.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeSensorReadingList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no sensors are exposed, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetSensors(DracCacheManager* mgr, DracSensorReadingList* out_list);
+
+  /**
+   * Gets the power drawn by the CPU package(s). Blocks for about 100 ms while sampling energy counters.
+   * @param mgr The cache manager instance.
+   * @param out_watts Pointer to receive the power draw in watts.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_PERMISSION_DENIED if the counters need root, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCPUPowerWatts(DracCacheManager* mgr, double* out_watts);
+
+  /**
+   * Gets the cumulative time counters for each logical CPU.
+   * Counters are only meaningful as deltas between two samples.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeCPUTimesList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCPUTimesPerCore(DracCPUTimesList* out_list);
+
+  /**
+   * Gets operating system information.
+   * @param mgr The cache manager instance.
+   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeOSInfo.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetOperatingSystem(DracCacheManager* mgr, DracOSInfo* out_info);
+
+  /**
+   * Gets the desktop environment name.
+   * @param mgr The cache manager instance.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetDesktopEnvironment(DracCacheManager* mgr, char** out_str);
+
+  /**
+   * Gets the window manager name.
+   * @param mgr The cache manager instance.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetWindowManager(DracCacheManager* mgr, char** out_str);
+
+  /**
+   * Gets the display server of the current session.
+   * @param mgr The cache manager instance.
+   * @param out_session Pointer to struct to receive data. Caller must free with DracFreeDisplaySession.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetDisplaySession(DracCacheManager* mgr, DracDisplaySession* out_session);
+
   /**
-   * Creates a new CacheManager instance.
-   * Must be destroyed with DracDestroyCacheManager.
+   * Gets the widget, icon, and cursor theme and the system UI font.
+   * @param mgr The cache manager instance.
+   * @param out_theme Pointer to struct to receive data. Caller must free with DracFreeThemeInfo.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API DracCacheManager* DracCreateCacheManager(void);
+  DRAC_C_API DracErrorCode DracGetThemeInfo(DracCacheManager* mgr, DracThemeInfo* out_theme);
 
   /**
-   * Destroys a CacheManager instance.
+   * Gets the user's default text editor, web browser, and terminal emulator. Never cached.
+   * @param out_apps Pointer to struct to receive data. Caller must free with DracFreeDefaultApplications.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API void DracDestroyCacheManager(DracCacheManager* mgr);
+  DRAC_C_API DracErrorCode DracGetDefaultApplications(DracDefaultApplications* out_apps);
 
   /**
-   * Frees a string allocated by the library.
+   * Gets audio output and input devices, outputs first.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to list to receive data. Caller must free with DracFreeAudioDeviceList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API void DracFreeString(const char* str);
+  DRAC_C_API DracErrorCode DracGetAudioDevices(DracCacheManager* mgr, DracAudioDeviceList* out_list);
 
   /**
-   * Frees an OSInfo struct's string members.
+   * Gets the volume and mute state of the default audio output device.
+   * @param mgr The cache manager instance.
+   * @param out_volume Pointer to struct to receive data.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API void DracFreeOSInfo(DracOSInfo* info);
+  DRAC_C_API DracErrorCode DracGetVolume(DracCacheManager* mgr, DracVolumeInfo* out_volume);
 
   /**
-   * Frees a DiskInfo struct's string members.
+   * Sets the volume of the default audio output device.
+   * @param percent The new volume as a percentage, applied to all channels.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API void DracFreeDiskInfo(DracDiskInfo* info);
+  DRAC_C_API DracErrorCode DracSetVolume(double percent);
 
   /**
-   * Frees a DiskInfoList and all its contents.
+   * Mutes or unmutes the default audio output device.
+   * @param muted Whether the device should be muted.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API void DracFreeDiskInfoList(DracDiskInfoList* list);
+  DRAC_C_API DracErrorCode DracSetMuted(bool muted);
 
   /**
-   * Frees a DisplayInfoList.
+   * Gets the current shell name.
+   * @param mgr The cache manager instance.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API void DracFreeDisplayInfoList(DracDisplayInfoList* list);
+  DRAC_C_API DracErrorCode DracGetShell(DracCacheManager* mgr, char** out_str);
 
   /**
-   * Frees a NetworkInterface struct's string members.
+   * Gets the terminal emulator the current process is running in.
+   * @param mgr The cache manager instance.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API void DracFreeNetworkInterface(DracNetworkInterface* iface);
+  DRAC_C_API DracErrorCode DracGetTerminal(DracCacheManager* mgr, char** out_str);
 
   /**
-   * Frees a NetworkInterfaceList and all its contents.
+   * Gets the font configured in the current terminal emulator (e.g. "JetBrains Mono 11").
+   * @param mgr The cache manager instance.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_SUPPORTED if the terminal's config can't be read.
    */
-  DRAC_C_API void DracFreeNetworkInterfaceList(DracNetworkInterfaceList* list);
+  DRAC_C_API DracErrorCode DracGetTerminalFont(DracCacheManager* mgr, char** out_str);
 
   /**
-   * Gets the system uptime in seconds.
-   * @return Uptime in seconds, 0 on error.
+   * Gets the host/machine name.
+   * @param mgr The cache manager instance.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API uint64_t DracGetUptime(void);
+  DRAC_C_API DracErrorCode DracGetHost(DracCacheManager* mgr, char** out_str);
 
   /**
-   * Gets memory usage information.
-   * @param mgr The cache manager instance.
-   * @param out_usage Pointer to struct to receive data.
+   * Gets the machine's network hostname. Never cached.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
    * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API DracErrorCode DracGetMemInfo(DracCacheManager* mgr, DracResourceUsage* out_usage);
+  DRAC_C_API DracErrorCode DracGetHostname(char** out_str);
 
   /**
-   * Gets CPU cores information.
-   * @param mgr The cache manager instance.
-   * @param out_cores Pointer to struct to receive data.
+   * Gets the machine's fully qualified domain name. Never cached, and may block on DNS.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
    * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API DracErrorCode DracGetCpuCores(DracCacheManager* mgr, DracCPUCores* out_cores);
+  DRAC_C_API DracErrorCode DracGetFQDN(char** out_str);
 
   /**
-   * Gets operating system information.
+   * Gets the Active Directory domain or workgroup membership and Azure AD join state.
    * @param mgr The cache manager instance.
-   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeOSInfo.
-   * @return DRAC_SUCCESS on success, error code otherwise.
+   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeDomainInfo.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_SUPPORTED outside Windows, error code otherwise.
    */
-  DRAC_C_API DracErrorCode DracGetOperatingSystem(DracCacheManager* mgr, DracOSInfo* out_info);
+  DRAC_C_API DracErrorCode DracGetDomainInfo(DracCacheManager* mgr, DracDomainInfo* out_info);
 
   /**
-   * Gets the desktop environment name.
+   * Gets the motherboard and BIOS/UEFI firmware information.
    * @param mgr The cache manager instance.
-   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeFirmwareInfo.
    * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API DracErrorCode DracGetDesktopEnvironment(DracCacheManager* mgr, char** out_str);
+  DRAC_C_API DracErrorCode DracGetFirmwareInfo(DracCacheManager* mgr, DracFirmwareInfo* out_info);
 
   /**
-   * Gets the window manager name.
+   * Gets the operating system's unique machine identifier (e.g. /etc/machine-id, MachineGuid).
    * @param mgr The cache manager instance.
    * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
    * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API DracErrorCode DracGetWindowManager(DracCacheManager* mgr, char** out_str);
+  DRAC_C_API DracErrorCode DracGetMachineId(DracCacheManager* mgr, char** out_str);
 
   /**
-   * Gets the current shell name.
+   * Gets the hardware serial number the vendor assigned to the machine.
    * @param mgr The cache manager instance.
    * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_PERMISSION_REQUIRED without sufficient privileges (root on Linux),
+   *         error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetHardwareSerial(DracCacheManager* mgr, char** out_str);
+
+  /**
+   * Detects the hypervisor and container runtime the process runs under.
+   * @param mgr The cache manager instance.
+   * @param out_env Pointer to struct to receive data. Caller must free with DracFreeVirtEnv.
    * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API DracErrorCode DracGetShell(DracCacheManager* mgr, char** out_str);
+  DRAC_C_API DracErrorCode DracGetVirtualization(DracCacheManager* mgr, DracVirtEnv* out_env);
 
   /**
-   * Gets the host/machine name.
+   * Gets the Secure Boot state and the TPM presence, version, and measured-boot status.
    * @param mgr The cache manager instance.
-   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeSecurityPlatformInfo.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_SUPPORTED on macOS, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetSecurityPlatformInfo(DracCacheManager* mgr, DracSecurityPlatformInfo* out_info);
+
+  /**
+   * Gets the status of the mandatory access control framework (SELinux or AppArmor).
+   * @param mgr The cache manager instance.
+   * @param out_status Pointer to struct to receive data. Caller must free with DracFreeMacStatus.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_SUPPORTED outside Linux, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetMacStatus(DracCacheManager* mgr, DracMacStatus* out_status);
+
+  /**
+   * Gets the host firewall and whether each of its profiles is enabled.
+   * @param mgr The cache manager instance.
+   * @param out_status Pointer to struct to receive data. Caller must free with DracFreeFirewallStatus.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no supported firewall is installed, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetFirewallStatus(DracCacheManager* mgr, DracFirewallStatus* out_status);
+
+  /**
+   * Gets a string value without allocating a copy for the caller, e.g. for widgets that poll it many times a second.
+   * The string is owned by the cache manager and stays valid until it is destroyed, even after the value changes.
+   * Each distinct value is kept for the manager's lifetime, so this suits values that rarely change.
+   * @param mgr The cache manager instance.
+   * @param field The string getter to call.
+   * @param out_str Pointer to receive the string. Must not be freed.
    * @return DRAC_SUCCESS on success, error code otherwise.
    */
-  DRAC_C_API DracErrorCode DracGetHost(DracCacheManager* mgr, char** out_str);
+  DRAC_C_API DracErrorCode DracGetStringRef(DracCacheManager* mgr, DracStringField field, const char** out_str);
+
+  /**
+   * Fills every field in `mask` in one call, sparing callers that refresh many fields a crossing per field.
+   * Each field is queried on its own, so one failing (e.g. no battery on a desktop) only sets its own error.
+   * @param mgr The cache manager instance.
+   * @param mask DracField values combined with bitwise OR.
+   * @param out_set Pointer to struct to receive the fields. Caller must free with DracFreeFieldSet.
+   * @return DRAC_SUCCESS unless the arguments are invalid; check `errors` for each field's result.
+   */
+  DRAC_C_API DracErrorCode DracCollect(DracCacheManager* mgr, uint64_t mask, DracFieldSet* out_set);
+
+  /**
+   * Frees the strings and error messages in a FieldSet struct.
+   */
+  DRAC_C_API void DracFreeFieldSet(DracFieldSet* set);
 
   /**
    * Gets the CPU model name.
@@ -248,6 +1773,14 @@ extern "C" {
    */
   DRAC_C_API DracErrorCode DracGetGPUModel(DracCacheManager* mgr, char** out_str);
 
+  /**
+   * Gets information about every GPU in the system.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeGPUInfoList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetGPUs(DracCacheManager* mgr, DracGPUInfoList* out_list);
+
   /**
    * Gets the kernel version.
    * @param mgr The cache manager instance.
@@ -256,6 +1789,22 @@ extern "C" {
    */
   DRAC_C_API DracErrorCode DracGetKernelVersion(DracCacheManager* mgr, char** out_str);
 
+  /**
+   * Gets the command line the kernel was booted with.
+   * @param mgr The cache manager instance.
+   * @param out_str Pointer to receive allocated string. Caller must free with DracFreeString.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetKernelCmdline(DracCacheManager* mgr, char** out_str);
+
+  /**
+   * Gets the kernel modules (Linux), kernel extensions (macOS), or drivers (Windows) currently loaded.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeKernelModuleList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetKernelModules(DracCacheManager* mgr, DracKernelModuleList* out_list);
+
   /**
    * Gets total disk usage across all disks.
    * @param mgr The cache manager instance.
@@ -280,6 +1829,38 @@ extern "C" {
    */
   DRAC_C_API DracErrorCode DracGetSystemDisk(DracCacheManager* mgr, DracDiskInfo* out_info);
 
+  /**
+   * Reads SMART health data for a block device. Requires root/administrator privileges.
+   * @param device Device name or path, e.g. "sda", "/dev/nvme0n1" or "PhysicalDrive0".
+   * @param out_health Pointer to struct to receive data. Caller must free with DracFreeDiskHealth.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_PERMISSION_REQUIRED without sufficient privileges, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetDiskHealth(const char* device, DracDiskHealth* out_health);
+
+  /**
+   * Gets the temperature of every drive that exposes a sensor. Does not require elevated privileges.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeDiskTemperatureList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetDiskTemperatures(DracCacheManager* mgr, DracDiskTemperatureList* out_list);
+
+  /**
+   * Gets the cumulative I/O counters of every physical drive. Never cached; sample twice to compute IOPS.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeDiskIOStatsList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetDiskIOStats(DracCacheManager* mgr, DracDiskIOStatsList* out_list);
+
+  /**
+   * Gets the filesystems mounted from remote servers (NFS, SMB, SSHFS, WebDAV, mapped drives).
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeNetworkMountList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetNetworkMounts(DracCacheManager* mgr, DracNetworkMountList* out_list);
+
   /**
    * Gets information about all display outputs.
    * @param mgr The cache manager instance.
@@ -291,11 +1872,19 @@ extern "C" {
   /**
    * Gets information about the primary display output.
    * @param mgr The cache manager instance.
-   * @param out_info Pointer to struct to receive data.
+   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeDisplayInfo.
    * @return DRAC_SUCCESS on success, error code otherwise.
    */
   DRAC_C_API DracErrorCode DracGetPrimaryOutput(DracCacheManager* mgr, DracDisplayInfo* out_info);
 
+  /**
+   * Gets the brightness of every display whose backlight can be read. Never cached.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeBacklightInfoList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no display exposes its brightness, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetBrightness(DracCacheManager* mgr, DracBacklightInfoList* out_list);
+
   /**
    * Gets information about all network interfaces.
    * @param mgr The cache manager instance.
@@ -304,6 +1893,15 @@ extern "C" {
    */
   DRAC_C_API DracErrorCode DracGetNetworkInterfaces(DracCacheManager* mgr, DracNetworkInterfaceList* out_list);
 
+  /**
+   * Gets the cumulative traffic counters of every network interface.
+   * The counters are never cached; sample twice to compute transfer rates.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeInterfaceStatsList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetNetworkStats(DracCacheManager* mgr, DracInterfaceStatsList* out_list);
+
   /**
    * Gets information about the primary network interface.
    * @param mgr The cache manager instance.
@@ -312,6 +1910,76 @@ extern "C" {
    */
   DRAC_C_API DracErrorCode DracGetPrimaryNetworkInterface(DracCacheManager* mgr, DracNetworkInterface* out_iface);
 
+  /**
+   * Gets Bluetooth adapters and their paired or connected devices.
+   * @param mgr The cache manager instance.
+   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeBluetoothInfo.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetBluetoothDevices(DracCacheManager* mgr, DracBluetoothInfo* out_info);
+
+  /**
+   * Gets the configured printers and print queues.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreePrinterInfoList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetPrinters(DracCacheManager* mgr, DracPrinterInfoList* out_list);
+
+  /**
+   * Gets the video capture devices and whether each is in use by another process.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeCameraInfoList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCameras(DracCacheManager* mgr, DracCameraInfoList* out_list);
+
+  /**
+   * Gets the connection details of the active Wi-Fi interface.
+   * @param mgr The cache manager instance.
+   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeWifiInfo.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetWifiInfo(DracCacheManager* mgr, DracWifiInfo* out_info);
+
+  /**
+   * Gets the default gateway and the interface it is reached through.
+   * The IPv4 default route is preferred over the IPv6 one.
+   * @param mgr The cache manager instance.
+   * @param out_gateway Pointer to struct to receive data. Caller must free with DracFreeGateway.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetDefaultGateway(DracCacheManager* mgr, DracGateway* out_gateway);
+
+  /**
+   * Gets the DNS servers the system resolver is configured to use, in resolver order.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeStringList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetDnsServers(DracCacheManager* mgr, DracStringList* out_list);
+
+  /**
+   * Gets the public IPv4 and IPv6 addresses of this machine.
+   * Makes outbound HTTP requests; results are cached for 30 minutes.
+   * Returns DRAC_ERROR_NOT_SUPPORTED if built without the publicip option.
+   * @param mgr The cache manager instance.
+   * @param out_ip Pointer to struct to receive data. Caller must free with DracFreePublicIp.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetPublicIp(DracCacheManager* mgr, DracPublicIp* out_ip);
+
+  /**
+   * Identifies the cloud provider this machine runs on, and its instance type and region.
+   * Recognizes the provider from SMBIOS strings, then queries its instance metadata service;
+   * results are cached for a day.
+   * Returns DRAC_ERROR_NOT_SUPPORTED if built without the cloudinfo option.
+   * @param mgr The cache manager instance.
+   * @param out_info Pointer to struct to receive data. Caller must free with DracFreeCloudInfo.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if not on a recognized cloud, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetCloudInfo(DracCacheManager* mgr, DracCloudInfo* out_info);
+
   /**
    * Gets battery information.
    * @param mgr The cache manager instance.
@@ -320,17 +1988,110 @@ extern "C" {
    */
   DRAC_C_API DracErrorCode DracGetBatteryInfo(DracCacheManager* mgr, DracBattery* out_battery);
 
+  /**
+   * Gets every battery, including those of peripherals where the platform reports them. Never cached.
+   * @param mgr The cache manager instance.
+   * @param out_list Pointer to struct to receive data. Caller must free with DracFreeBatteryDeviceList.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no battery is present, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetBatteries(DracCacheManager* mgr, DracBatteryDeviceList* out_list);
+
+  /**
+   * Gets whether external power is connected, and the charger's wattage where available. Never cached.
+   * @param mgr The cache manager instance.
+   * @param out_source Pointer to struct to receive data.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if no power supply is exposed, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetPowerSource(DracCacheManager* mgr, DracPowerSource* out_source);
+
+  /**
+   * Gets the active power profile and CPU frequency governor. Never cached.
+   * @param mgr The cache manager instance.
+   * @param out_profile Pointer to struct to receive data. Caller must free with DracFreePowerProfile.
+   * @return DRAC_SUCCESS on success, DRAC_ERROR_NOT_FOUND if neither is available, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetPowerProfile(DracCacheManager* mgr, DracPowerProfile* out_profile);
+
+  /**
+   * Gets the number of pending updates reported by the system package manager.
+   * Only local metadata is consulted; results are cached for an hour.
+   * Returns DRAC_ERROR_NOT_SUPPORTED if built without package counting.
+   * @param mgr The cache manager instance.
+   * @param out_count Pointer to receive the number of pending updates.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracGetPendingUpdates(DracCacheManager* mgr, uint64_t* out_count);
+
+  // ============================== //
+  //  System Events                 //
+  // ============================== //
+
+  /**
+   * Subscribes to the OS's device hotplug, power, link state and display change notifications.
+   * Must be destroyed with DracDestroySystemWatcher.
+   * @param out_watcher Receives the watcher, or NULL on failure.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracCreateSystemWatcher(DracSystemWatcher** out_watcher);
+
+  /**
+   * Destroys a SystemWatcher, unsubscribing from its notifications.
+   */
+  DRAC_C_API void DracDestroySystemWatcher(DracSystemWatcher* watcher);
+
+  /**
+   * Blocks until at least one event arrives or the timeout elapses.
+   * Events that arrive between calls are queued, so none are lost.
+   * @param watcher The watcher instance. Must not be waited on from two threads at once.
+   * @param timeout_ms How long to wait, in milliseconds.
+   * @param out_list Pointer to struct to receive the events, oldest first; empty if the timeout elapsed.
+   *                 Caller must free with DracFreeSystemEventList.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracWaitSystemEvents(DracSystemWatcher* watcher, uint32_t timeout_ms, DracSystemEventList* out_list);
+
+  /**
+   * Starts a thread that invokes `callback` for each event, oldest first.
+   * Must be ended with DracUnsubscribeSystemEvents.
+   * @param callback Invoked on the subscription's thread, never concurrently with itself.
+   * @param user_data Passed through to `callback` unchanged.
+   * @param out_subscription Receives the subscription, or NULL on failure.
+   * @return DRAC_SUCCESS on success, error code otherwise.
+   */
+  DRAC_C_API DracErrorCode DracSubscribeSystemEvents(DracSystemEventCallback callback, void* user_data, DracSystemEventSubscription** out_subscription);
+
+  /**
+   * Stops a subscription's thread, waiting for a callback in progress to return.
+   * `callback` is not invoked again once this returns. Must not be called from the callback itself.
+   */
+  DRAC_C_API void DracUnsubscribeSystemEvents(DracSystemEventSubscription* subscription);
+
   // ============================== //
   //  Plugin System                 //
   // ============================== //
 
-  typedef struct DracPlugin DracPlugin;
+  typedef struct DracPlugin             DracPlugin;
+  typedef struct DracCancelToken        DracCancelToken;
+  typedef struct DracPluginSubscription DracPluginSubscription;
+
+  typedef enum DracPluginType {
+    DRAC_PLUGIN_TYPE_INFO_PROVIDER = 0,
+    DRAC_PLUGIN_TYPE_OUTPUT_FORMAT = 1,
+  } DracPluginType;
 
   typedef struct DracPluginInfo {
-    char* name;
-    char* version;
-    char* author;
-    char* description;
+    char*          name;
+    char*          version;
+    char*          author;
+    char*          description;
+    DracPluginType type;
+    DracStringList capabilities; // Provider ID of an info provider, or the formats an output plugin writes
+    bool           requiresNetwork;
+    bool           requiresFilesystem;
+    bool           requiresAdmin;
+    bool           requiresCaching;
+    char*          configSchema; // NULL if the plugin doesn't describe its TOML keys
+    bool           isStatic;     // Linked into the library rather than loaded from a search path
   } DracPluginInfo;
 
   typedef struct DracPluginInfoList {
@@ -355,6 +2116,8 @@ extern "C" {
     DRAC_PLUGIN_FIELD_STRING,
     DRAC_PLUGIN_FIELD_ARRAY,
     DRAC_PLUGIN_FIELD_OBJECT,
+    DRAC_PLUGIN_FIELD_TIMESTAMP,
+    DRAC_PLUGIN_FIELD_NULL,
   } DracPluginFieldValueType;
 
   typedef struct DracPluginFieldValue DracPluginFieldValue;
@@ -380,6 +2143,7 @@ extern "C" {
       char*                      stringValue;
       DracPluginFieldValueArray  arrayValue;
       DracPluginFieldValueObject objectValue;
+      int64_t                    timestampValue; // Milliseconds since the Unix epoch
     };
   };
 
@@ -393,25 +2157,143 @@ extern "C" {
     size_t           count;
   } DracPluginFieldList;
 
-  // Plugin manager lifecycle
+  typedef struct DracPluginFieldSchema {
+    char*                    name;
+    DracPluginFieldValueType type;
+    char*                    unit; // NULL if the value has no unit
+    char*                    description;
+  } DracPluginFieldSchema;
+
+  typedef struct DracPluginFieldSchemaList {
+    DracPluginFieldSchema* items;
+    size_t                 count;
+  } DracPluginFieldSchemaList;
+
+  // Limits for DracPluginSetSandbox; 0 leaves that resource unlimited
+  typedef struct DracPluginSandbox {
+    uint64_t maxMemoryBytes; // Memory the helper process may map (Unix) or commit (Windows)
+    uint32_t maxCpuSeconds;  // CPU time each collection may use
+    uint32_t timeoutMs;      // Wall-clock time a collection may take before the helper is killed
+  } DracPluginSandbox;
+
+  /**
+   * Invoked on a subscription's thread with the fields a plugin pushed.
+   * `fields` and its contents are only valid for the duration of the call.
+   */
+  typedef void (*DracPluginUpdateCallback)(const DracPluginFieldList* fields, void* user_data);
+
+  /**
+   * Passed to a vtable plugin's `setUpdateCallback`. The plugin calls it with
+   * `context` and its current fields whenever they change, from any thread;
+   * `fields` only needs to stay valid for the duration of the call.
+   */
+  typedef void (*DracPluginPushFields)(void* context, const DracPluginFieldList* fields);
+
+  /**
+   * Callbacks implementing an info provider plugin outside C++, e.g. in Rust.
+   * Registered with DracRegisterPlugin, after which DracLoadPlugin and the plugin
+   * manager load it by name like a static plugin.
+   *
+   * `state` is the pointer returned by `create`. Strings and field lists returned
+   * by callbacks stay owned by the plugin and must remain valid until the next
+   * call with the same `state`. Callbacks marked optional may be NULL.
+   */
+  typedef struct DracPluginVTable {
+    const char* name;         // Name passed to DracLoadPlugin
+    const char* version;
+    const char* author;
+    const char* description;
+    const char* providerId;   // Optional; defaults to `name`
+    const char* displayLabel; // Optional; defaults to `name`
+    const char* displayIcon;  // Optional
+    const char* configSchema; // Optional description of the TOML keys setConfig accepts
+    bool        requiresNetwork;
+    bool        requiresFilesystem;
+    bool        requiresAdmin;
+    bool        requiresCaching;
+
+    void* (*create)(void);
+    void (*destroy)(void* state);
+    DracErrorCode (*setConfig)(void* state, const char* tomlConfig); // Optional
+    DracErrorCode (*initialize)(void* state);
+    DracErrorCode (*collect)(void* state);
+    DracPluginFieldList (*fields)(void* state);
+    DracPluginFieldSchemaList (*fieldSchema)(void* state); // Optional
+    const char* (*displayValue)(void* state); // Optional; may return NULL
+    const char* (*lastError)(void* state);    // Optional; may return NULL
+    bool (*isEnabled)(void* state);           // Optional; enabled if NULL
+
+    // Optional; for plugins that push updates. Replaces the previous `push`, which
+    // must not be called once this returns. `push` is NULL when the host stops listening.
+    void (*setUpdateCallback)(void* state, DracPluginPushFields push, void* context);
+  } DracPluginVTable;
+
+  // Plugin manager lifecycle. Shutting down does nothing while a plugin is still running a
+  // collection DracPluginCollectDataWithTimeout gave up on.
   DRAC_C_API void DracInitPluginManager(void);
   DRAC_C_API void DracShutdownPluginManager(void);
 
   // Plugin discovery
-  DRAC_C_API void               DracAddPluginSearchPath(const char* path);
+  DRAC_C_API void DracAddPluginSearchPath(const char* path);
+
+  /**
+   * Add the directories plugins are installed to: those in DRAC_PLUGIN_PATH,
+   * the XDG data directories and the usual library directories on Unix, or
+   * %LOCALAPPDATA% and %APPDATA% on Windows. DracInitPluginManager already adds
+   * them; calling this again picks up changes to the environment.
+   */
+  DRAC_C_API void DracAddDefaultPluginSearchPaths(void);
+
+  /**
+   * Rescan the search paths and describe every static and discovered plugin,
+   * sorted by name. Plugins that aren't loaded are instantiated briefly to read
+   * their metadata, without being initialized.
+   * Caller must free with DracFreePluginInfoList.
+   */
   DRAC_C_API DracPluginInfoList DracDiscoverPlugins(void);
 
+  /**
+   * Register a plugin implemented through callbacks. `vtable` and its strings
+   * must stay valid for the rest of the process.
+   * @return DRAC_ERROR_INVALID_ARGUMENT if a required member is NULL or the name
+   *         is already registered.
+   */
+  DRAC_C_API DracErrorCode DracRegisterPlugin(const DracPluginVTable* vtable);
+
   // Plugin loading - by ID (searches paths) or by explicit path
   DRAC_C_API DracPlugin* DracLoadPlugin(const char* pluginId);
   DRAC_C_API DracPlugin* DracLoadPluginFromPath(const char* path);
   DRAC_C_API void        DracUnloadPlugin(DracPlugin* plugin);
 
+  /**
+   * Destroy the plugin's instance and create a new one, re-reading its library
+   * from disk if it was loaded dynamically. Configuration set with
+   * DracPluginSetConfig is not carried over, and the new instance must be
+   * initialized again.
+   * @return DRAC_ERROR_TIMEOUT while any handle to the plugin is still running a
+   *         collection DracPluginCollectDataWithTimeout gave up on.
+   */
+  DRAC_C_API DracErrorCode DracPluginReload(DracPlugin* plugin, DracCacheManager* cache);
+
+  /**
+   * Reload every dynamically loaded plugin whose library changed on disk since
+   * it was loaded. Existing handles to those plugins stay valid and refer to the
+   * new instances. Must not run concurrently with calls on those handles.
+   * @return DRAC_ERROR_TIMEOUT, without reloading anything, while a plugin is
+   *         still running a collection DracPluginCollectDataWithTimeout gave up on.
+   * @param out_list Receives the names of the reloaded plugins; free with DracFreeStringList.
+   */
+  DRAC_C_API DracErrorCode DracReloadChangedPlugins(DracCacheManager* cache, DracStringList* out_list);
+
   // Plugin initialization
   DRAC_C_API DracErrorCode DracPluginInitialize(DracPlugin* plugin, DracCacheManager* cache);
 
   // Plugin configuration - pass TOML config string to plugin
   DRAC_C_API DracErrorCode DracPluginSetConfig(DracPlugin* plugin, const char* tomlConfig);
 
+  // Description of the TOML keys the plugin accepts, or NULL if it doesn't provide one
+  DRAC_C_API char* DracPluginGetConfigSchema(DracPlugin* plugin);
+
   // Plugin state
   DRAC_C_API bool DracPluginIsEnabled(DracPlugin* plugin);
   DRAC_C_API bool DracPluginIsReady(DracPlugin* plugin);
@@ -421,9 +2303,72 @@ extern "C" {
   DRAC_C_API DracPluginFieldList DracPluginGetFields(DracPlugin* plugin);
   DRAC_C_API char*               DracPluginGetLastError(DracPlugin* plugin);
 
+  // Names, types, units and descriptions of the fields the plugin emits, available before the first collection.
+  // Empty if the plugin doesn't describe them. Caller must free with DracFreePluginFieldSchemaList.
+  DRAC_C_API DracPluginFieldSchemaList DracPluginGetFieldSchema(DracPlugin* plugin);
+
+  /**
+   * Like DracPluginCollectData, but stops waiting once `timeout_ms` elapses or
+   * `token` is cancelled. Unless the plugin is sandboxed, the collection itself
+   * can't be interrupted, so it keeps running in the background; until it finishes, the plugin reports no fields
+   * and isn't ready, and the next call to this function waits for it instead of
+   * starting another. Calls that configure, reload or collect from the plugin
+   * fail with DRAC_ERROR_TIMEOUT until it finishes, and DracUnloadPlugin returns
+   * at once, releasing the plugin when it does.
+   * @param timeout_ms How long to wait, in milliseconds, or 0 to wait until done or cancelled.
+   * @param token Optional; may be NULL.
+   * @return DRAC_ERROR_TIMEOUT if the timeout elapsed first, or DRAC_ERROR_CANCELLED if the token was cancelled.
+   */
+  DRAC_C_API DracErrorCode DracPluginCollectDataWithTimeout(DracPlugin* plugin, DracCacheManager* cache, uint32_t timeout_ms, DracCancelToken* token);
+
+  /**
+   * Runs the plugin's collections in a helper process (draconis-plugin-host, or
+   * the executable the DRAC_PLUGIN_HOST environment variable names) from now on,
+   * so a crash, hang or runaway allocation is reported as an error instead of
+   * taking the application down: DRAC_ERROR_INTERNAL_ERROR for a crash,
+   * DRAC_ERROR_TIMEOUT, DRAC_ERROR_RESOURCE_EXHAUSTED for the CPU limit and
+   * DRAC_ERROR_OUT_OF_MEMORY for the memory limit. The helper loads the plugin's
+   * library and keeps its own instance between collections; it is started when
+   * first needed and again after it dies or is killed.
+   * DracPluginSetConfig configures both instances, and the helper's gets the
+   * last accepted configuration again whenever it starts. DracPluginGetFields
+   * and DracPluginGetLastError report the helper's results, and updates it
+   * pushes aren't delivered. DracPluginCollectDataWithTimeout waits on the
+   * helper from the calling thread and kills it once the timeout elapses or the
+   * token is cancelled. Limits are enforced with rlimits on Unix and a job
+   * object on Windows. Calling this again replaces the helper.
+   * @param sandbox Limits for each collection, or NULL to collect in-process again.
+   * @return DRAC_ERROR_NOT_SUPPORTED for plugins that weren't loaded from a library.
+   */
+  DRAC_C_API DracErrorCode DracPluginSetSandbox(DracPlugin* plugin, const DracPluginSandbox* sandbox);
+
+  // Cancellation for DracPluginCollectDataWithTimeout; may be cancelled from any thread
+  DRAC_C_API DracCancelToken* DracCreateCancelToken(void);
+  DRAC_C_API void             DracCancelTokenCancel(DracCancelToken* token);
+  DRAC_C_API bool             DracCancelTokenIsCancelled(DracCancelToken* token);
+  DRAC_C_API void             DracFreeCancelToken(DracCancelToken* token);
+
+  /**
+   * Starts a thread that invokes `callback` with the plugin's fields each time
+   * the plugin pushes an update, e.g. when the playing track changes. Plugins
+   * that are only polled never push. Updates that arrive while `callback` is
+   * running are coalesced into the latest one. The subscription carries over
+   * reloads and may outlive `plugin`. Must be ended with DracPluginUnsubscribe.
+   * @param callback Invoked on the subscription's thread, never concurrently with itself.
+   * @param user_data Passed through to `callback` unchanged.
+   */
+  DRAC_C_API DracErrorCode DracPluginSubscribe(DracPlugin* plugin, DracPluginUpdateCallback callback, void* user_data, DracPluginSubscription** out_subscription);
+
+  /**
+   * Stops a subscription's thread, waiting for a callback in progress to return.
+   * `callback` is not invoked again once this returns. Must not be called from the callback itself.
+   */
+  DRAC_C_API void DracPluginUnsubscribe(DracPluginSubscription* subscription);
+
   // Memory cleanup
   DRAC_C_API void DracFreePluginInfoList(DracPluginInfoList* list);
   DRAC_C_API void DracFreePluginFieldList(DracPluginFieldList* list);
+  DRAC_C_API void DracFreePluginFieldSchemaList(DracPluginFieldSchemaList* list);
 
 #ifdef __cplusplus
 }
@@ -1,9 +1,33 @@
 #include "../include/draconis_c.h"
 
+#include <algorithm>
+#include <atomic>
+#include <bit>
+#include <chrono>
+#include <cmath>
+#include <condition_variable>
 #include <cstring>
+#include <deque>
+#include <future>
+#include <limits>
+#include <set>
+#include <thread>
+#include <utility>
 
 #include <Drac++/Core/System.hpp>
 
+#if DRAC_ENABLE_PACKAGECOUNT
+  #include <Drac++/Services/Packages.hpp>
+#endif
+
+#if DRAC_ENABLE_CLOUDINFO
+  #include <Drac++/Services/CloudInfo.hpp>
+#endif
+
+#if DRAC_ENABLE_PUBLICIP
+  #include <Drac++/Services/PublicIp.hpp>
+#endif
+
 #if DRAC_ENABLE_PLUGINS
   #include <Drac++/Core/Plugin.hpp>
   #include <Drac++/Core/PluginConfig.hpp>
@@ -24,10 +48,26 @@ using namespace draconis::utils::types;
 using namespace draconis::core::plugin;
 #endif
 
-// Convert C++ DracErrorCode to C DracErrorCode enum value
-#define TO_C_ERROR(err) static_cast<::DracErrorCode>(static_cast<u8>((err).code))
+// Convert C++ DracError to C DracErrorCode enum value, recording its message for DracGetLastErrorMessage
+#define TO_C_ERROR(err) RecordError(err)
 
 namespace {
+  struct LastError {
+    ::DracErrorCode code = DRAC_SUCCESS;
+    Option<String>  message;
+  };
+
+  // Per thread, so concurrent callers can't read each other's messages.
+  thread_local LastError LastErrorState;
+
+  auto RecordError(const draconis::utils::error::DracError& err) -> ::DracErrorCode {
+    const auto code = static_cast<::DracErrorCode>(static_cast<u8>(err.code));
+
+    LastErrorState = { .code = code, .message = err.message };
+
+    return code;
+  }
+
   auto DupString(const String& str) -> CStr* {
     CStr* result = new CStr[str.size() + 1];
     std::memcpy(result, str.c_str(), str.size() + 1);
@@ -41,6 +81,77 @@ namespace {
     return nullptr;
   }
 
+  auto CopyProcessInfo(const ProcessInfo& src, DracProcessInfo& dst) -> void {
+    dst.pid        = src.pid;
+    dst.ppid       = src.ppid;
+    dst.name       = DupString(src.name);
+    dst.cmdline    = DupString(src.cmdline);
+    dst.cpuPercent = src.cpuPercent;
+    dst.rssBytes   = src.rssBytes;
+    dst.state      = static_cast<DracProcessState>(src.state);
+    dst.user       = DupOptionalString(src.user);
+  }
+
+  auto CopyProcessInfoList(const Vec<ProcessInfo>& processes, DracProcessInfoList* out_list) -> void {
+    out_list->count = processes.size();
+    out_list->items = new DracProcessInfo[processes.size()];
+
+    Span<DracProcessInfo> outItems(out_list->items, out_list->count);
+    usize                 idx = 0;
+
+    for (DracProcessInfo& dst : outItems)
+      CopyProcessInfo(processes[idx++], dst);
+  }
+
+  auto CopyServiceInfo(const ServiceInfo& src, DracServiceInfo& dst) -> void {
+    dst.state       = static_cast<DracServiceState>(src.state);
+    dst.name        = DupString(src.name);
+    dst.description = DupOptionalString(src.description);
+    dst.enabled     = src.enabled.has_value() ? static_cast<int32_t>(*src.enabled) : -1;
+  }
+
+  auto CopyDisplayInfo(const DisplayInfo& src, DracDisplayInfo& dst) -> void {
+    dst.id           = src.id;
+    dst.width        = src.resolution.width;
+    dst.height       = src.resolution.height;
+    dst.refreshRate  = src.refreshRate;
+    dst.isPrimary    = src.isPrimary;
+    dst.positionX    = src.position.x;
+    dst.positionY    = src.position.y;
+    dst.scaleFactor  = src.scaleFactor;
+    dst.dpi          = src.dpi.value_or(std::numeric_limits<f64>::quiet_NaN());
+    dst.colorDepth   = src.colorDepth.value_or(UINT8_MAX);
+    dst.rotation     = src.rotation;
+    dst.connector    = static_cast<DracDisplayConnector>(src.connector);
+    dst.manufacturer = DupOptionalString(src.manufacturer);
+    dst.model        = DupOptionalString(src.model);
+    dst.serial       = DupOptionalString(src.serial);
+    dst.gamut        = static_cast<DracDisplayGamut>(src.gamut);
+    dst.hdrSupported = src.hdrSupported;
+    dst.hdrEnabled   = src.hdrEnabled.has_value() ? static_cast<int32_t>(*src.hdrEnabled) : -1;
+  }
+
+  auto CopyDiskInfo(const DiskInfo& src, DracDiskInfo& dst) -> void {
+    dst.name             = DupString(src.name);
+    dst.mountPoint       = DupString(src.mountPoint);
+    dst.filesystem       = DupString(src.filesystem);
+    dst.driveType        = DupString(src.driveType);
+    dst.totalBytes       = src.totalBytes;
+    dst.usedBytes        = src.usedBytes;
+    dst.totalInodes      = src.totalInodes ? static_cast<int64_t>(*src.totalInodes) : -1;
+    dst.usedInodes       = src.usedInodes ? static_cast<int64_t>(*src.usedInodes) : -1;
+    dst.mountOptionCount = src.mountOptions.size();
+    dst.mountOptions     = new char*[src.mountOptions.size()];
+    dst.isReadOnly       = src.isReadOnly;
+    dst.isSystemDrive    = src.isSystemDrive;
+
+    Span<char*> options(dst.mountOptions, dst.mountOptionCount);
+    usize       idx = 0;
+
+    for (char*& option : options)
+      option = DupString(src.mountOptions[idx++]);
+  }
+
 #if DRAC_ENABLE_PLUGINS
   auto ToCPluginFieldValue(const PluginFieldValue& value) -> DracPluginFieldValue {
     return std::visit(
@@ -72,6 +183,16 @@ namespace {
             .type        = DRAC_PLUGIN_FIELD_STRING,
             .stringValue = DupString(inner),
           };
+        } else if constexpr (std::same_as<T, PluginTimestamp>) {
+          return {
+            .type           = DRAC_PLUGIN_FIELD_TIMESTAMP,
+            .timestampValue = inner.time_since_epoch().count(),
+          };
+        } else if constexpr (std::same_as<T, std::nullptr_t>) {
+          return {
+            .type     = DRAC_PLUGIN_FIELD_NULL,
+            .i64Value = 0,
+          };
         } else if constexpr (std::same_as<T, PluginFieldArray>) {
           DracPluginFieldValueArray array {
             .items = new DracPluginFieldValue[inner.size()],
@@ -135,30 +256,495 @@ namespace {
       case DRAC_PLUGIN_FIELD_I64:
       case DRAC_PLUGIN_FIELD_U64:
       case DRAC_PLUGIN_FIELD_F64:
+      case DRAC_PLUGIN_FIELD_TIMESTAMP:
+      case DRAC_PLUGIN_FIELD_NULL:
+        break;
+    }
+  }
+
+  auto FromCPluginFieldValue(const DracPluginFieldValue& value) -> PluginFieldValue {
+    switch (value.type) {
+      case DRAC_PLUGIN_FIELD_BOOL:
+        return PluginFieldValue { value.boolValue };
+      case DRAC_PLUGIN_FIELD_I64:
+        return PluginFieldValue { i64 { value.i64Value } };
+      case DRAC_PLUGIN_FIELD_U64:
+        return PluginFieldValue { u64 { value.u64Value } };
+      case DRAC_PLUGIN_FIELD_F64:
+        return PluginFieldValue { f64 { value.f64Value } };
+      case DRAC_PLUGIN_FIELD_STRING:
+        return PluginFieldValue { String(value.stringValue ? value.stringValue : "") };
+      case DRAC_PLUGIN_FIELD_ARRAY: {
+        PluginFieldArray array;
+        array.reserve(value.arrayValue.count);
+
+        for (const DracPluginFieldValue& item : Span<const DracPluginFieldValue>(value.arrayValue.items, value.arrayValue.count))
+          array.emplace_back(FromCPluginFieldValue(item));
+
+        return PluginFieldValue { std::move(array) };
+      }
+      case DRAC_PLUGIN_FIELD_OBJECT: {
+        PluginFieldObject object;
+
+        for (const DracPluginField& item : Span<const DracPluginField>(value.objectValue.items, value.objectValue.count))
+          object.emplace(item.key ? item.key : "", FromCPluginFieldValue(item.value));
+
+        return PluginFieldValue { std::move(object) };
+      }
+      case DRAC_PLUGIN_FIELD_TIMESTAMP:
+        return PluginFieldValue { PluginTimestamp { std::chrono::milliseconds { value.timestampValue } } };
+      case DRAC_PLUGIN_FIELD_NULL:
         break;
     }
+
+    return PluginFieldValue { nullptr };
+  }
+
+  auto FromCPluginFieldList(const DracPluginFieldList& list) -> PluginFields {
+    PluginFields fields;
+
+    for (const DracPluginField& item : Span<const DracPluginField>(list.items, list.count))
+      fields.emplace(item.key ? item.key : "", FromCPluginFieldValue(item.value));
+
+    return fields;
+  }
+
+  auto ToCPluginFieldList(const PluginFields& fields) -> DracPluginFieldList {
+    DracPluginFieldList result = { .items = new DracPluginField[fields.size()], .count = fields.size() };
+
+    size_t idx = 0;
+    for (const auto& [key, value] : fields) {
+      result.items[idx].key   = DupString(key);
+      result.items[idx].value = ToCPluginFieldValue(value);
+      ++idx;
+    }
+
+    return result;
   }
+
+  // Adapts a plugin registered through DracRegisterPlugin to the C++ plugin interface,
+  // so the plugin manager and DracLoadPlugin can treat it like any static plugin.
+  class VTablePlugin final : public IInfoProviderPlugin {
+    const DracPluginVTable* m_vtable;
+    void*                   m_state;
+    PluginMetadata          m_metadata;
+    PluginUpdateCallback    m_updateCallback;
+    bool                    m_ready = false;
+
+    static auto OrEmpty(const char* str) -> String {
+      return str ? String(str) : String();
+    }
+
+    static auto PushFields(void* context, const DracPluginFieldList* fields) -> void {
+      const auto* plugin = static_cast<const VTablePlugin*>(context);
+      plugin->m_updateCallback(FromCPluginFieldList(*fields));
+    }
+
+    auto check(const ::DracErrorCode code) const -> Result<Unit> {
+      if (code == DRAC_SUCCESS)
+        return {};
+
+      return Err(draconis::utils::error::DracError(
+        static_cast<draconis::utils::error::DracErrorCode>(code),
+        getLastError().value_or(String())
+      ));
+    }
+
+   public:
+    VTablePlugin(const DracPluginVTable* vtable, void* state)
+      : m_vtable(vtable),
+        m_state(state),
+        m_metadata {
+          .name         = OrEmpty(vtable->name),
+          .version      = OrEmpty(vtable->version),
+          .author       = OrEmpty(vtable->author),
+          .description  = OrEmpty(vtable->description),
+          .type         = PluginType::InfoProvider,
+          .dependencies = {
+            .requiresNetwork    = vtable->requiresNetwork,
+            .requiresFilesystem = vtable->requiresFilesystem,
+            .requiresAdmin      = vtable->requiresAdmin,
+            .requiresCaching    = vtable->requiresCaching,
+          },
+        } {}
+
+    VTablePlugin(const VTablePlugin&)                    = delete;
+    VTablePlugin(VTablePlugin&&)                         = delete;
+    auto operator=(const VTablePlugin&) -> VTablePlugin& = delete;
+    auto operator=(VTablePlugin&&) -> VTablePlugin&      = delete;
+
+    ~VTablePlugin() override {
+      // Threads the plugin started may outlive its state, so they must stop pushing to this instance first.
+      if (m_vtable->setUpdateCallback)
+        m_vtable->setUpdateCallback(m_state, nullptr, nullptr);
+
+      m_vtable->destroy(m_state);
+    }
+
+    [[nodiscard]] auto getMetadata() const -> const PluginMetadata& override {
+      return m_metadata;
+    }
+
+    auto initialize(const PluginContext& /*ctx*/, ::PluginCache& /*cache*/) -> Result<Unit> override {
+      Result<Unit> result = check(m_vtable->initialize(m_state));
+      m_ready             = result.has_value();
+      return result;
+    }
+
+    auto setConfig(const StringView tomlConfig) -> Result<Unit> override {
+      if (!m_vtable->setConfig)
+        return {};
+
+      const String config(tomlConfig);
+      return check(m_vtable->setConfig(m_state, config.c_str()));
+    }
+
+    [[nodiscard]] auto getConfigSchema() const -> Option<String> override {
+      if (!m_vtable->configSchema)
+        return None;
+
+      return String(m_vtable->configSchema);
+    }
+
+    auto shutdown() -> Unit override {
+      m_ready = false;
+    }
+
+    [[nodiscard]] auto isReady() const -> bool override {
+      return m_ready;
+    }
+
+    [[nodiscard]] auto getProviderId() const -> String override {
+      return OrEmpty(m_vtable->providerId ? m_vtable->providerId : m_vtable->name);
+    }
+
+    auto collectData(::PluginCache& /*cache*/) -> Result<Unit> override {
+      return check(m_vtable->collect(m_state));
+    }
+
+    [[nodiscard]] auto getFields() const -> PluginFields override {
+      return FromCPluginFieldList(m_vtable->fields(m_state));
+    }
+
+    [[nodiscard]] auto getFieldSchema() const -> Vec<PluginFieldSchema> override {
+      if (!m_vtable->fieldSchema)
+        return {};
+
+      const DracPluginFieldSchemaList list = m_vtable->fieldSchema(m_state);
+
+      Vec<PluginFieldSchema> schema;
+      schema.reserve(list.count);
+
+      for (const DracPluginFieldSchema& item : Span<const DracPluginFieldSchema>(list.items, list.count))
+        schema.push_back({
+          .name        = OrEmpty(item.name),
+          .type        = static_cast<PluginFieldType>(item.type),
+          .unit        = item.unit ? Option<String>(item.unit) : None,
+          .description = OrEmpty(item.description),
+        });
+
+      return schema;
+    }
+
+    [[nodiscard]] auto getDisplayValue() const -> Result<String> override {
+      const char* value = m_vtable->displayValue ? m_vtable->displayValue(m_state) : nullptr;
+
+      if (!value)
+        ERR(draconis::utils::error::DracErrorCode::NotFound, "Plugin has no display value");
+
+      return String(value);
+    }
+
+    [[nodiscard]] auto getDisplayIcon() const -> String override {
+      return OrEmpty(m_vtable->displayIcon);
+    }
+
+    [[nodiscard]] auto getDisplayLabel() const -> String override {
+      return OrEmpty(m_vtable->displayLabel ? m_vtable->displayLabel : m_vtable->name);
+    }
+
+    [[nodiscard]] auto getLastError() const -> Option<String> override {
+      const char* error = m_vtable->lastError ? m_vtable->lastError(m_state) : nullptr;
+
+      if (!error)
+        return None;
+
+      return String(error);
+    }
+
+    [[nodiscard]] auto isEnabled() const -> bool override {
+      return !m_vtable->isEnabled || m_vtable->isEnabled(m_state);
+    }
+
+    auto setUpdateCallback(PluginUpdateCallback callback) -> Unit override {
+      if (!m_vtable->setUpdateCallback)
+        return;
+
+      // Detach first, so the plugin is done calling the old callback before it's replaced.
+      m_vtable->setUpdateCallback(m_state, nullptr, nullptr);
+      m_updateCallback = std::move(callback);
+
+      if (m_updateCallback)
+        m_vtable->setUpdateCallback(m_state, &PushFields, this);
+    }
+  };
+#endif
+
+  // Runs the cached getters in a domain, so later calls are answered from the cache.
+  // Results are discarded; failed fetches aren't cached, so those getters will simply fetch again.
+  auto PrefetchDomain(CacheManager& cache, const CacheDomain domain) -> Unit {
+    switch (domain) {
+      case CacheDomain::Cpu:
+        std::ignore = GetCPUModel(cache);
+        std::ignore = GetCPUCores(cache);
+        std::ignore = GetCPUDetails(cache);
+        break;
+      case CacheDomain::Gpu:
+        std::ignore = GetGPUModel(cache);
+        std::ignore = GetGPUs(cache);
+        break;
+      case CacheDomain::Network:
+        std::ignore = GetPrimaryNetworkInterface(cache);
+        break;
+      case CacheDomain::System:
+        std::ignore = GetOperatingSystem(cache);
+        std::ignore = GetKernelVersion(cache);
+        std::ignore = GetInitSystem(cache);
+        break;
+      case CacheDomain::Hardware:
+        std::ignore = GetHost(cache);
+        std::ignore = GetFirmwareInfo(cache);
+        std::ignore = GetVirtualization(cache);
+        break;
+      case CacheDomain::Desktop:
+        std::ignore = GetDesktopEnvironment(cache);
+        std::ignore = GetWindowManager(cache);
+        std::ignore = GetThemeInfo(cache);
+        std::ignore = GetShell(cache);
+        std::ignore = GetTerminal(cache);
+        break;
+      case CacheDomain::Packages:
+#if DRAC_ENABLE_PACKAGECOUNT
+        std::ignore = draconis::services::packages::GetPendingUpdateCount(cache);
 #endif
+        break;
+    }
+  }
 
 } // namespace
 
 struct DracCacheManager {
   CacheManager inner;
+
+  // Prefetches run in order on one worker thread, started by the first DracPrefetchCache call.
+  Mutex                   prefetchMutex;
+  std::condition_variable prefetchCondition;
+  std::deque<CacheDomain> prefetchQueue;
+  Array<usize, 7>         pendingPrefetches {}; // Queued or running, per domain
+  Array<bool, 7>          prefetched {};        // Whether a prefetch has finished, per domain
+  bool                    stopping = false;
+  std::thread             prefetchThread;
+
+  // Every value DracGetStringRef has returned, so the pointers it hands out outlive later changes.
+  Mutex                         internMutex;
+  std::set<String, std::less<>> internedStrings;
+
+  DracCacheManager() = default;
+
+  DracCacheManager(const DracCacheManager&)                    = delete;
+  DracCacheManager(DracCacheManager&&)                         = delete;
+  auto operator=(const DracCacheManager&) -> DracCacheManager& = delete;
+  auto operator=(DracCacheManager&&) -> DracCacheManager&      = delete;
+
+  ~DracCacheManager() {
+    {
+      LockGuard lock(prefetchMutex);
+      stopping = true;
+      prefetchQueue.clear();
+    }
+
+    prefetchCondition.notify_all();
+
+    if (prefetchThread.joinable())
+      prefetchThread.join();
+  }
+
+  auto runPrefetches() -> Unit {
+    std::unique_lock lock(prefetchMutex);
+
+    while (true) {
+      prefetchCondition.wait(lock, [this] { return stopping || !prefetchQueue.empty(); });
+
+      if (stopping)
+        return;
+
+      const CacheDomain domain = prefetchQueue.front();
+      prefetchQueue.pop_front();
+
+      lock.unlock();
+      PrefetchDomain(inner, domain);
+      lock.lock();
+
+      const auto idx = static_cast<usize>(domain);
+
+      if (--pendingPrefetches.at(idx) == 0)
+        prefetched.at(idx) = true;
+    }
+  }
 };
 
 extern "C" {
   auto DracCreateCacheManager(void) -> DracCacheManager* {
-    return new DracCacheManager();
+    auto* mgr = new DracCacheManager();
+
+    if (Result<String> fingerprint = GetHardwareFingerprint())
+      mgr->inner.setFingerprint(std::move(*fingerprint));
+
+    return mgr;
   }
 
   auto DracDestroyCacheManager(DracCacheManager* mgr) -> void {
     delete mgr;
   }
 
+  auto DracSetCacheTtl(DracCacheManager* mgr, const DracCacheDomain domain, const uint32_t ttl_seconds) -> DracErrorCode {
+    if (!mgr || domain < DRAC_CACHE_DOMAIN_CPU || domain > DRAC_CACHE_DOMAIN_PACKAGES)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    mgr->inner.setDomainTtl(static_cast<CacheDomain>(domain), std::chrono::seconds(ttl_seconds));
+    return DRAC_SUCCESS;
+  }
+
+  auto DracSetCacheNeverExpire(DracCacheManager* mgr, const DracCacheDomain domain) -> DracErrorCode {
+    if (!mgr || domain < DRAC_CACHE_DOMAIN_CPU || domain > DRAC_CACHE_DOMAIN_PACKAGES)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    mgr->inner.setDomainTtl(static_cast<CacheDomain>(domain), None);
+    return DRAC_SUCCESS;
+  }
+
+  auto DracResetCacheTtl(DracCacheManager* mgr, const DracCacheDomain domain) -> DracErrorCode {
+    if (!mgr || domain < DRAC_CACHE_DOMAIN_CPU || domain > DRAC_CACHE_DOMAIN_PACKAGES)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    mgr->inner.resetDomainTtl(static_cast<CacheDomain>(domain));
+    return DRAC_SUCCESS;
+  }
+
+  auto DracInvalidateCache(DracCacheManager* mgr, const DracCacheDomain domain) -> DracErrorCode {
+    if (!mgr || domain < DRAC_CACHE_DOMAIN_CPU || domain > DRAC_CACHE_DOMAIN_PACKAGES)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    mgr->inner.invalidateDomain(static_cast<CacheDomain>(domain));
+    return DRAC_SUCCESS;
+  }
+
+  auto DracInvalidateAllCaches(DracCacheManager* mgr) -> DracErrorCode {
+    if (!mgr)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    mgr->inner.invalidateAll();
+
+    return DRAC_SUCCESS;
+  }
+
+  auto DracPrefetchCache(DracCacheManager* mgr, const DracCacheDomain* domains, const size_t count) -> DracErrorCode {
+    if (!mgr || (!domains && count > 0))
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Span<const DracCacheDomain> requested(domains, count);
+
+    for (const DracCacheDomain domain : requested)
+      if (domain < DRAC_CACHE_DOMAIN_CPU || domain > DRAC_CACHE_DOMAIN_PACKAGES)
+        return DRAC_ERROR_INVALID_ARGUMENT;
+
+    {
+      LockGuard lock(mgr->prefetchMutex);
+
+      for (const DracCacheDomain domain : requested) {
+        const auto cacheDomain = static_cast<CacheDomain>(domain);
+
+        // A domain still waiting in the queue will pick up anything cached since, so it needn't run twice.
+        if (std::ranges::find(mgr->prefetchQueue, cacheDomain) != mgr->prefetchQueue.end())
+          continue;
+
+        mgr->prefetchQueue.push_back(cacheDomain);
+        ++mgr->pendingPrefetches.at(static_cast<usize>(domain));
+      }
+
+      if (!mgr->prefetchThread.joinable())
+        mgr->prefetchThread = std::thread([mgr]() -> void { mgr->runPrefetches(); });
+    }
+
+    mgr->prefetchCondition.notify_one();
+    return DRAC_SUCCESS;
+  }
+
+  auto DracIsCacheReady(DracCacheManager* mgr, const DracCacheDomain domain, bool* out_ready) -> DracErrorCode {
+    if (!mgr || !out_ready || domain < DRAC_CACHE_DOMAIN_CPU || domain > DRAC_CACHE_DOMAIN_PACKAGES)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    LockGuard lock(mgr->prefetchMutex);
+
+    const auto idx = static_cast<usize>(domain);
+    *out_ready     = mgr->prefetched.at(idx) && mgr->pendingPrefetches.at(idx) == 0;
+    return DRAC_SUCCESS;
+  }
+
+  auto DracGetCacheStats(DracCacheManager* mgr, DracCacheStats* out_stats) -> DracErrorCode {
+    if (!mgr || !out_stats)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    const CacheStats stats = mgr->inner.getStats();
+
+    *out_stats = {
+      .hits         = stats.hits,
+      .misses       = stats.misses,
+      .entries      = stats.entries,
+      .refreshes    = nullptr,
+      .refreshCount = 0,
+    };
+
+    if (stats.lastRefresh.empty())
+      return DRAC_SUCCESS;
+
+    out_stats->refreshCount = stats.lastRefresh.size();
+    out_stats->refreshes    = new DracCacheRefresh[stats.lastRefresh.size()];
+
+    Span<DracCacheRefresh> outItems(out_stats->refreshes, out_stats->refreshCount);
+    usize                  idx = 0;
+
+    for (const auto& [domain, refreshedAt] : stats.lastRefresh)
+      outItems[idx++] = {
+        .domain      = static_cast<DracCacheDomain>(domain),
+        .refreshedAt = std::chrono::duration_cast<std::chrono::seconds>(refreshedAt.time_since_epoch()).count(),
+      };
+
+    return DRAC_SUCCESS;
+  }
+
+  auto DracFreeCacheStats(DracCacheStats* stats) -> void {
+    if (!stats)
+      return;
+
+    delete[] stats->refreshes;
+    stats->refreshes    = nullptr;
+    stats->refreshCount = 0;
+  }
+
   auto DracFreeString(PCStr str) -> void {
     delete[] str;
   }
 
+  auto DracGetLastErrorMessage(DracErrorCode* out_code) -> char* {
+    LastError last = std::exchange(LastErrorState, {});
+
+    if (out_code)
+      *out_code = last.code;
+
+    return DupOptionalString(last.message);
+  }
+
   auto DracFreeOSInfo(DracOSInfo* info) -> void {
     if (!info)
       return;
@@ -171,6 +757,27 @@ extern "C" {
     info->id      = nullptr;
   }
 
+  auto DracFreeCPUDetails(DracCPUDetails* details) -> void {
+    if (!details)
+      return;
+
+    delete[] details->architecture;
+    delete[] details->vendor;
+    details->architecture = nullptr;
+    details->vendor       = nullptr;
+
+    if (details->flags) {
+      Span<char*> flags(details->flags, details->flagCount);
+      for (char* flag : flags)
+        delete[] flag;
+
+      delete[] details->flags;
+    }
+
+    details->flags     = nullptr;
+    details->flagCount = 0;
+  }
+
   auto DracFreeDiskInfo(DracDiskInfo* info) -> void {
     if (!info)
       return;
@@ -183,6 +790,17 @@ extern "C" {
     info->mountPoint = nullptr;
     info->filesystem = nullptr;
     info->driveType  = nullptr;
+
+    if (info->mountOptions) {
+      Span<char*> options(info->mountOptions, info->mountOptionCount);
+      for (char* option : options)
+        delete[] option;
+
+      delete[] info->mountOptions;
+    }
+
+    info->mountOptions     = nullptr;
+    info->mountOptionCount = 0;
   }
 
   auto DracFreeDiskInfoList(DracDiskInfoList* list) -> void {
@@ -198,56 +816,1967 @@ extern "C" {
     list->count = 0;
   }
 
-  auto DracFreeDisplayInfoList(DracDisplayInfoList* list) -> void {
+  auto DracFreeDiskHealth(DracDiskHealth* health) -> void {
+    if (!health)
+      return;
+
+    delete[] health->device;
+    health->device = nullptr;
+
+    if (health->attributes) {
+      Span<DracSmartAttribute> attributes(health->attributes, health->attributeCount);
+      for (DracSmartAttribute& attribute : attributes)
+        delete[] attribute.name;
+
+      delete[] health->attributes;
+    }
+
+    health->attributes     = nullptr;
+    health->attributeCount = 0;
+  }
+
+  auto DracFreeNetworkMountList(DracNetworkMountList* list) -> void {
     if (!list || !list->items)
       return;
 
+    Span<DracNetworkMount> items(list->items, list->count);
+    for (DracNetworkMount& item : items) {
+      delete[] item.server;
+      delete[] item.exportPath;
+      delete[] item.mountPoint;
+    }
+
     delete[] list->items;
     list->items = nullptr;
     list->count = 0;
   }
 
-  auto DracFreeNetworkInterface(DracNetworkInterface* iface) -> void {
-    if (!iface)
+  auto DracFreeKernelModuleList(DracKernelModuleList* list) -> void {
+    if (!list || !list->items)
       return;
 
-    delete[] iface->name;
-    delete[] iface->ipv4Address;
-    delete[] iface->ipv6Address;
-    delete[] iface->macAddress;
-    iface->name        = nullptr;
-    iface->ipv4Address = nullptr;
-    iface->ipv6Address = nullptr;
-    iface->macAddress  = nullptr;
+    Span<DracKernelModule> items(list->items, list->count);
+    for (DracKernelModule& item : items)
+      delete[] item.name;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
   }
 
-  auto DracFreeNetworkInterfaceList(DracNetworkInterfaceList* list) -> void {
+  auto DracFreeDiskTemperatureList(DracDiskTemperatureList* list) -> void {
     if (!list || !list->items)
       return;
 
-    Span<DracNetworkInterface> items(list->items, list->count);
-    for (DracNetworkInterface& item : items)
-      DracFreeNetworkInterface(&item);
+    Span<DracDiskTemperature> items(list->items, list->count);
+    for (DracDiskTemperature& item : items)
+      delete[] item.device;
 
     delete[] list->items;
     list->items = nullptr;
     list->count = 0;
   }
 
-  auto DracGetUptime(void) -> uint64_t {
-    Result<std::chrono::seconds> result = GetUptime();
+  auto DracFreeDiskIOStatsList(DracDiskIOStatsList* list) -> void {
+    if (!list || !list->items)
+      return;
 
-    if (result.has_value())
-      return static_cast<uint64_t>(result.value().count());
+    Span<DracDiskIOStats> items(list->items, list->count);
+    for (DracDiskIOStats& item : items)
+      delete[] item.device;
 
-    return 0;
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
   }
 
-  auto DracGetMemInfo(DracCacheManager* mgr, DracResourceUsage* out_usage) -> DracErrorCode {
-    if (!mgr || !out_usage)
+  auto DracFreeSystemEventList(DracSystemEventList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracSystemEvent> items(list->items, list->count);
+    for (DracSystemEvent& item : items)
+      delete[] item.source;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeFanInfoList(DracFanInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracFanInfo> items(list->items, list->count);
+    for (DracFanInfo& item : items)
+      delete[] item.label;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeSensorReadingList(DracSensorReadingList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracSensorReading> items(list->items, list->count);
+    for (DracSensorReading& item : items) {
+      delete[] item.chip;
+      delete[] item.label;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeDisplayInfo(DracDisplayInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->manufacturer;
+    delete[] info->model;
+    delete[] info->serial;
+    info->manufacturer = nullptr;
+    info->model        = nullptr;
+    info->serial       = nullptr;
+  }
+
+  auto DracFreeDisplayInfoList(DracDisplayInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracDisplayInfo> items(list->items, list->count);
+    for (DracDisplayInfo& item : items)
+      DracFreeDisplayInfo(&item);
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeBacklightInfoList(DracBacklightInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracBacklightInfo> items(list->items, list->count);
+    for (DracBacklightInfo& item : items)
+      delete[] item.device;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeGPUInfoList(DracGPUInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracGPUInfo> items(list->items, list->count);
+    for (DracGPUInfo& item : items) {
+      delete[] item.vendor;
+      delete[] item.model;
+      delete[] item.driver;
+      delete[] item.driverVersion;
+      delete[] item.vulkanVersion;
+      delete[] item.openGLVersion;
+      delete[] item.directXVersion;
+      delete[] item.metalVersion;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeProcessInfo(DracProcessInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->name;
+    delete[] info->cmdline;
+    delete[] info->user;
+    info->name    = nullptr;
+    info->cmdline = nullptr;
+    info->user    = nullptr;
+  }
+
+  auto DracFreeProcessInfoList(DracProcessInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracProcessInfo> items(list->items, list->count);
+    for (DracProcessInfo& item : items)
+      DracFreeProcessInfo(&item);
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeInitSystem(DracInitSystem* init) -> void {
+    if (!init)
+      return;
+
+    delete[] init->name;
+    init->name = nullptr;
+  }
+
+  auto DracFreeServiceInfo(DracServiceInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->name;
+    delete[] info->description;
+    info->name        = nullptr;
+    info->description = nullptr;
+  }
+
+  auto DracFreeServiceInfoList(DracServiceInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracServiceInfo> items(list->items, list->count);
+    for (DracServiceInfo& item : items)
+      DracFreeServiceInfo(&item);
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeContainerInfoList(DracContainerInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracContainerInfo> items(list->items, list->count);
+    for (DracContainerInfo& item : items) {
+      delete[] item.id;
+      delete[] item.name;
+      delete[] item.image;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeSessionInfoList(DracSessionInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracSessionInfo> items(list->items, list->count);
+    for (DracSessionInfo& item : items) {
+      delete[] item.username;
+      delete[] item.terminal;
+      delete[] item.remoteHost;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeDisplaySession(DracDisplaySession* session) -> void {
+    if (!session)
+      return;
+
+    delete[] session->compositor;
+    session->compositor = nullptr;
+  }
+
+  auto DracFreeThemeInfo(DracThemeInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->widgetTheme;
+    delete[] info->iconTheme;
+    delete[] info->cursorTheme;
+    delete[] info->font;
+    info->widgetTheme = nullptr;
+    info->iconTheme   = nullptr;
+    info->cursorTheme = nullptr;
+    info->font        = nullptr;
+  }
+
+  auto DracFreeDefaultApplications(DracDefaultApplications* apps) -> void {
+    if (!apps)
+      return;
+
+    delete[] apps->editor;
+    delete[] apps->browser;
+    delete[] apps->terminal;
+    apps->editor   = nullptr;
+    apps->browser  = nullptr;
+    apps->terminal = nullptr;
+  }
+
+  auto DracFreeAudioDeviceList(DracAudioDeviceList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracAudioDevice> items(list->items, list->count);
+    for (DracAudioDevice& item : items) {
+      delete[] item.id;
+      delete[] item.name;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeUserInfo(DracUserInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->username;
+    delete[] info->sid;
+    delete[] info->homeDirectory;
+    delete[] info->shell;
+    info->username      = nullptr;
+    info->sid           = nullptr;
+    info->homeDirectory = nullptr;
+    info->shell         = nullptr;
+  }
+
+  auto DracFreeTimezoneInfo(DracTimezoneInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->name;
+    info->name = nullptr;
+  }
+
+  auto DracFreeKeyboardLayoutList(DracKeyboardLayoutList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracKeyboardLayout> items(list->items, list->count);
+    for (DracKeyboardLayout& item : items) {
+      delete[] item.name;
+      delete[] item.variant;
+      delete[] item.description;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeNetworkInterface(DracNetworkInterface* iface) -> void {
+    if (!iface)
+      return;
+
+    delete[] iface->name;
+    delete[] iface->ipv4Address;
+    delete[] iface->ipv6Address;
+    delete[] iface->macAddress;
+    iface->name        = nullptr;
+    iface->ipv4Address = nullptr;
+    iface->ipv6Address = nullptr;
+    iface->macAddress  = nullptr;
+  }
+
+  auto DracFreeNetworkInterfaceList(DracNetworkInterfaceList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracNetworkInterface> items(list->items, list->count);
+    for (DracNetworkInterface& item : items)
+      DracFreeNetworkInterface(&item);
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeInterfaceStatsList(DracInterfaceStatsList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracInterfaceStats> items(list->items, list->count);
+    for (DracInterfaceStats& item : items)
+      delete[] item.name;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeBluetoothInfo(DracBluetoothInfo* info) -> void {
+    if (!info)
+      return;
+
+    if (info->adapters) {
+      Span<DracBluetoothAdapter> adapters(info->adapters, info->adapterCount);
+      for (DracBluetoothAdapter& adapter : adapters) {
+        delete[] adapter.name;
+        delete[] adapter.address;
+      }
+
+      delete[] info->adapters;
+    }
+
+    if (info->devices) {
+      Span<DracBluetoothDevice> devices(info->devices, info->deviceCount);
+      for (DracBluetoothDevice& device : devices) {
+        delete[] device.name;
+        delete[] device.address;
+      }
+
+      delete[] info->devices;
+    }
+
+    info->adapters     = nullptr;
+    info->adapterCount = 0;
+    info->devices      = nullptr;
+    info->deviceCount  = 0;
+  }
+
+  auto DracFreePrinterInfoList(DracPrinterInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracPrinterInfo> items(list->items, list->count);
+    for (DracPrinterInfo& item : items) {
+      delete[] item.name;
+      delete[] item.driver;
+      delete[] item.connection;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeCameraInfoList(DracCameraInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracCameraInfo> items(list->items, list->count);
+    for (DracCameraInfo& item : items) {
+      delete[] item.name;
+      delete[] item.id;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeWifiInfo(DracWifiInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->interfaceName;
+    delete[] info->ssid;
+    delete[] info->bssid;
+    info->interfaceName = nullptr;
+    info->ssid          = nullptr;
+    info->bssid         = nullptr;
+  }
+
+  auto DracFreeGateway(DracGateway* gateway) -> void {
+    if (!gateway)
+      return;
+
+    delete[] gateway->address;
+    delete[] gateway->interfaceName;
+    gateway->address       = nullptr;
+    gateway->interfaceName = nullptr;
+  }
+
+  auto DracFreeStringList(DracStringList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<char*> items(list->items, list->count);
+    for (char* item : items)
+      delete[] item;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreePublicIp(DracPublicIp* ip) -> void {
+    if (!ip)
+      return;
+
+    delete[] ip->v4;
+    delete[] ip->v6;
+    ip->v4 = nullptr;
+    ip->v6 = nullptr;
+  }
+
+  auto DracFreeCloudInfo(DracCloudInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->instanceType;
+    delete[] info->region;
+    info->instanceType = nullptr;
+    info->region       = nullptr;
+  }
+
+  auto DracFreePowerProfile(DracPowerProfile* profile) -> void {
+    if (!profile)
+      return;
+
+    delete[] profile->name;
+    delete[] profile->governor;
+    profile->name     = nullptr;
+    profile->governor = nullptr;
+  }
+
+  auto DracFreeBatteryDeviceList(DracBatteryDeviceList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracBatteryDevice> items(list->items, list->count);
+    for (DracBatteryDevice& item : items)
+      delete[] item.name;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeFirmwareInfo(DracFirmwareInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->boardVendor;
+    delete[] info->boardModel;
+    delete[] info->boardVersion;
+    delete[] info->vendor;
+    delete[] info->version;
+    delete[] info->releaseDate;
+    info->boardVendor  = nullptr;
+    info->boardModel   = nullptr;
+    info->boardVersion = nullptr;
+    info->vendor       = nullptr;
+    info->version      = nullptr;
+    info->releaseDate  = nullptr;
+  }
+
+  auto DracFreeVirtEnv(DracVirtEnv* env) -> void {
+    if (!env)
+      return;
+
+    delete[] env->hypervisorVendor;
+    env->hypervisorVendor = nullptr;
+  }
+
+  auto DracFreeSecurityPlatformInfo(DracSecurityPlatformInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->tpmVersion;
+    info->tpmVersion = nullptr;
+  }
+
+  auto DracFreeMacStatus(DracMacStatus* status) -> void {
+    if (!status)
+      return;
+
+    delete[] status->policy;
+    status->policy = nullptr;
+  }
+
+  auto DracFreeFirewallStatus(DracFirewallStatus* status) -> void {
+    if (!status)
+      return;
+
+    if (status->profiles) {
+      Span<DracFirewallProfile> profiles(status->profiles, status->profileCount);
+      for (DracFirewallProfile& profile : profiles)
+        delete[] profile.name;
+
+      delete[] status->profiles;
+    }
+
+    status->profiles     = nullptr;
+    status->profileCount = 0;
+  }
+
+  auto DracFreeDomainInfo(DracDomainInfo* info) -> void {
+    if (!info)
+      return;
+
+    delete[] info->name;
+    delete[] info->dnsDomain;
+    delete[] info->azureTenant;
+    info->name        = nullptr;
+    info->dnsDomain   = nullptr;
+    info->azureTenant = nullptr;
+  }
+
+  auto DracFreeCPUTimesList(DracCPUTimesList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeCPUCacheInfoList(DracCPUCacheInfoList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeCPUVulnerabilityList(DracCPUVulnerabilityList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracCPUVulnerability> items(list->items, list->count);
+    for (DracCPUVulnerability& item : items) {
+      delete[] item.name;
+      delete[] item.details;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracFreeNumaNodeList(DracNumaNodeList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    Span<DracNumaNode> items(list->items, list->count);
+    for (DracNumaNode& item : items)
+      delete[] item.cpus;
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
+  auto DracGetUptime(void) -> uint64_t {
+    Result<std::chrono::seconds> result = GetUptime();
+
+    if (result.has_value())
+      return static_cast<uint64_t>(result.value().count());
+
+    return 0;
+  }
+
+  auto DracGetBootTime(int64_t* out_boot_time) -> DracErrorCode {
+    if (!out_boot_time)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<std::chrono::system_clock::time_point> result = GetBootTime();
+
+    if (result.has_value()) {
+      *out_boot_time = static_cast<int64_t>(std::chrono::system_clock::to_time_t(result.value()));
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetLoadAverage(DracLoadAverage* out_load) -> DracErrorCode {
+    if (!out_load)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<LoadAverage> result = GetLoadAverage();
+
+    if (result.has_value()) {
+      LoadAverage& val         = result.value();
+      out_load->oneMinute      = val.oneMinute;
+      out_load->fiveMinutes    = val.fiveMinutes;
+      out_load->fifteenMinutes = val.fifteenMinutes;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetProcesses(DracCacheManager* mgr, DracProcessInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<ProcessInfo>> result = GetProcesses(mgr->inner);
+
+    if (result.has_value()) {
+      CopyProcessInfoList(result.value(), out_list);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetProcess(uint32_t pid, DracProcessInfo* out_process) -> DracErrorCode {
+    if (!out_process)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<ProcessInfo> result = GetProcess(pid);
+
+    if (result.has_value()) {
+      CopyProcessInfo(result.value(), *out_process);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracFindProcesses(const char* name, DracProcessInfoList* out_list) -> DracErrorCode {
+    if (!name || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<ProcessInfo>> result = FindProcesses(name);
+
+    if (result.has_value()) {
+      CopyProcessInfoList(result.value(), out_list);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetInitSystem(DracCacheManager* mgr, DracInitSystem* out_init) -> DracErrorCode {
+    if (!mgr || !out_init)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_init = { .kind = DRAC_INIT_SYSTEM_UNKNOWN, .name = nullptr };
+
+    Result<InitSystem> result = GetInitSystem(mgr->inner);
+
+    if (result.has_value()) {
+      const InitSystem& init = result.value();
+      out_init->kind         = static_cast<DracInitSystemKind>(init.kind);
+      out_init->name         = DupString(init.name);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetServiceStatus(const char* name, DracServiceInfo* out_service) -> DracErrorCode {
+    if (!name || !out_service)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_service = {
+      .state       = DRAC_SERVICE_STATE_UNKNOWN,
+      .name        = nullptr,
+      .description = nullptr,
+      .enabled     = -1,
+    };
+
+    Result<ServiceInfo> result = GetServiceStatus(name);
+
+    if (result.has_value()) {
+      CopyServiceInfo(result.value(), *out_service);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracListServices(DracServiceInfoList* out_list) -> DracErrorCode {
+    if (!out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<ServiceInfo>> result = ListServices();
+
+    if (result.has_value()) {
+      const Vec<ServiceInfo>& services = result.value();
+      out_list->count                  = services.size();
+      out_list->items                  = new DracServiceInfo[services.size()];
+
+      Span<DracServiceInfo> outItems(out_list->items, out_list->count);
+      usize                 idx = 0;
+
+      for (DracServiceInfo& dst : outItems)
+        CopyServiceInfo(services[idx++], dst);
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetContainers(DracCacheManager* mgr, DracContainerInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<ContainerInfo>> result = GetContainers(mgr->inner);
+
+    if (result.has_value()) {
+      const Vec<ContainerInfo>& containers = result.value();
+      out_list->count                      = containers.size();
+      out_list->items                      = new DracContainerInfo[containers.size()];
+
+      Span<DracContainerInfo> outItems(out_list->items, out_list->count);
+      usize                   idx = 0;
+
+      for (DracContainerInfo& dst : outItems) {
+        const ContainerInfo& src = containers[idx++];
+        dst.runtime              = static_cast<DracContainerRuntime>(src.runtime);
+        dst.state                = static_cast<DracContainerState>(src.state);
+        dst.id                   = DupString(src.id);
+        dst.name                 = DupString(src.name);
+        dst.image                = DupOptionalString(src.image);
+        dst.cpuPercent           = src.cpuPercent.value_or(std::numeric_limits<f64>::quiet_NaN());
+        dst.memoryBytes          = src.memoryBytes ? static_cast<int64_t>(*src.memoryBytes) : -1;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetSessions(DracSessionInfoList* out_list) -> DracErrorCode {
+    if (!out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<SessionInfo>> result = GetSessions();
+
+    if (result.has_value()) {
+      Vec<SessionInfo>& sessions = result.value();
+      out_list->count            = sessions.size();
+      out_list->items            = new DracSessionInfo[sessions.size()];
+
+      Span<DracSessionInfo> outItems(out_list->items, out_list->count);
+      usize                 idx = 0;
+
+      for (DracSessionInfo& dst : outItems) {
+        SessionInfo& src = sessions[idx++];
+        dst.type         = static_cast<DracSessionType>(src.type);
+        dst.username     = DupString(src.username);
+        dst.terminal     = DupString(src.terminal);
+        dst.remoteHost   = DupOptionalString(src.remoteHost);
+        dst.loginTime    = src.loginTime ? static_cast<int64_t>(std::chrono::system_clock::to_time_t(*src.loginTime)) : -1;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCurrentUser(DracUserInfo* out_user) -> DracErrorCode {
+    if (!out_user)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<UserInfo> result = GetCurrentUser();
+
+    if (result.has_value()) {
+      UserInfo& val           = result.value();
+      out_user->username      = DupString(val.username);
+      out_user->uid           = val.uid ? static_cast<int64_t>(*val.uid) : -1;
+      out_user->gid           = val.gid ? static_cast<int64_t>(*val.gid) : -1;
+      out_user->sid           = DupOptionalString(val.sid);
+      out_user->homeDirectory = DupString(val.homeDirectory);
+      out_user->shell         = DupOptionalString(val.shell);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetLocale(char** out_str) -> DracErrorCode {
+    if (!out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetLocale();
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetTimezone(DracTimezoneInfo* out_tz) -> DracErrorCode {
+    if (!out_tz)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<TimezoneInfo> result = GetTimezone();
+
+    if (result.has_value()) {
+      TimezoneInfo& val        = result.value();
+      out_tz->name             = DupString(val.name);
+      out_tz->utcOffsetSeconds = val.utcOffsetSeconds;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetKeyboardLayouts(DracKeyboardLayoutList* out_list) -> DracErrorCode {
+    if (!out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<KeyboardLayout>> result = GetKeyboardLayouts();
+
+    if (result.has_value()) {
+      const Vec<KeyboardLayout>& layouts = result.value();
+      out_list->count                    = layouts.size();
+      out_list->items                    = new DracKeyboardLayout[layouts.size()];
+
+      Span<DracKeyboardLayout> outItems(out_list->items, out_list->count);
+      usize                    idx = 0;
+
+      for (DracKeyboardLayout& dst : outItems) {
+        const KeyboardLayout& src = layouts[idx++];
+        dst.name                  = DupString(src.name);
+        dst.variant               = DupOptionalString(src.variant);
+        dst.description           = DupOptionalString(src.description);
+        dst.active                = src.active;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetMemInfo(DracCacheManager* mgr, DracResourceUsage* out_usage) -> DracErrorCode {
+    if (!mgr || !out_usage)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<ResourceUsage> result = GetMemInfo(mgr->inner);
+
+    if (result.has_value()) {
+      ResourceUsage& val    = result.value();
+      out_usage->usedBytes  = val.usedBytes;
+      out_usage->totalBytes = val.totalBytes;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetMemoryDetails(DracCacheManager* mgr, DracMemoryDetails* out_details) -> DracErrorCode {
+    if (!mgr || !out_details)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<MemoryDetails> result = GetMemoryDetails(mgr->inner);
+
+    if (result.has_value()) {
+      MemoryDetails& val          = result.value();
+      out_details->totalBytes     = val.totalBytes;
+      out_details->usedBytes      = val.usedBytes;
+      out_details->freeBytes      = val.freeBytes;
+      out_details->availableBytes = val.availableBytes;
+      out_details->cachedBytes    = val.cachedBytes;
+      out_details->buffersBytes   = val.buffersBytes;
+      out_details->sharedBytes    = val.sharedBytes;
+      out_details->kernelBytes    = val.kernelBytes;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetSwapInfo(DracCacheManager* mgr, DracResourceUsage* out_usage) -> DracErrorCode {
+    if (!mgr || !out_usage)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<ResourceUsage> result = GetSwapInfo(mgr->inner);
+
+    if (result.has_value()) {
+      ResourceUsage& val    = result.value();
+      out_usage->usedBytes  = val.usedBytes;
+      out_usage->totalBytes = val.totalBytes;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCpuCores(DracCacheManager* mgr, DracCPUCores* out_cores) -> DracErrorCode {
+    if (!mgr || !out_cores)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<CPUCores> result = GetCPUCores(mgr->inner);
+
+    if (result.has_value()) {
+      CPUCores& val       = result.value();
+      out_cores->physical = val.physical;
+      out_cores->logical  = val.logical;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCPUDetails(DracCacheManager* mgr, DracCPUDetails* out_details) -> DracErrorCode {
+    if (!mgr || !out_details)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_details = {
+      .architecture = nullptr,
+      .vendor       = nullptr,
+      .family       = -1,
+      .model        = -1,
+      .stepping     = -1,
+      .flags        = nullptr,
+      .flagCount    = 0,
+    };
+
+    Result<CPUDetails> result = GetCPUDetails(mgr->inner);
+
+    if (result.has_value()) {
+      const CPUDetails& details = result.value();
+      out_details->architecture = DupString(details.architecture);
+      out_details->vendor       = DupOptionalString(details.vendor);
+      out_details->family       = details.family ? static_cast<int64_t>(*details.family) : -1;
+      out_details->model        = details.model ? static_cast<int64_t>(*details.model) : -1;
+      out_details->stepping     = details.stepping ? static_cast<int64_t>(*details.stepping) : -1;
+      out_details->flagCount    = details.flags.size();
+      out_details->flags        = new char*[details.flags.size()];
+
+      Span<char*> flags(out_details->flags, out_details->flagCount);
+      usize       idx = 0;
+
+      for (char*& flag : flags)
+        flag = DupString(details.flags[idx++]);
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCPUCaches(DracCacheManager* mgr, DracCPUCacheInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<CPUCacheInfo>> result = GetCPUCaches(mgr->inner);
+
+    if (result.has_value()) {
+      const Vec<CPUCacheInfo>& caches = result.value();
+      out_list->count                 = caches.size();
+      out_list->items                 = new DracCPUCacheInfo[caches.size()];
+
+      Span<DracCPUCacheInfo> outItems(out_list->items, out_list->count);
+      usize                  idx = 0;
+
+      for (DracCPUCacheInfo& dst : outItems) {
+        const CPUCacheInfo& src = caches[idx++];
+        dst.type                = static_cast<DracCPUCacheType>(src.type);
+        dst.level               = src.level;
+        dst.sizeBytes           = src.sizeBytes;
+        dst.lineSizeBytes       = src.lineSizeBytes.value_or(0);
+        dst.sharedCpuCount      = src.sharedCpuCount;
+        dst.instanceCount       = src.instanceCount;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetNumaTopology(DracCacheManager* mgr, DracNumaNodeList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<NumaNode>> result = GetNumaTopology(mgr->inner);
+
+    if (result.has_value()) {
+      const Vec<NumaNode>& nodes = result.value();
+      out_list->count            = nodes.size();
+      out_list->items            = new DracNumaNode[nodes.size()];
+
+      Span<DracNumaNode> outItems(out_list->items, out_list->count);
+      usize              idx = 0;
+
+      for (DracNumaNode& dst : outItems) {
+        const NumaNode& src  = nodes[idx++];
+        dst.id               = src.id;
+        dst.totalMemoryBytes = src.totalMemoryBytes ? static_cast<int64_t>(*src.totalMemoryBytes) : -1;
+        dst.freeMemoryBytes  = src.freeMemoryBytes ? static_cast<int64_t>(*src.freeMemoryBytes) : -1;
+        dst.cpuCount         = src.cpus.size();
+        dst.cpus             = new uint32_t[src.cpus.size()];
+
+        Span<uint32_t> cpus(dst.cpus, dst.cpuCount);
+        usize          cpuIdx = 0;
+
+        for (uint32_t& cpu : cpus)
+          cpu = src.cpus[cpuIdx++];
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCPUVulnerabilities(DracCacheManager* mgr, DracCPUVulnerabilityList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<CPUVulnerability>> result = GetCPUVulnerabilities(mgr->inner);
+
+    if (result.has_value()) {
+      const Vec<CPUVulnerability>& vulnerabilities = result.value();
+      out_list->count                              = vulnerabilities.size();
+      out_list->items                              = new DracCPUVulnerability[vulnerabilities.size()];
+
+      Span<DracCPUVulnerability> outItems(out_list->items, out_list->count);
+      usize                      idx = 0;
+
+      for (DracCPUVulnerability& dst : outItems) {
+        const CPUVulnerability& src = vulnerabilities[idx++];
+        dst.name                    = DupString(src.name);
+        dst.state                   = static_cast<DracCPUVulnerabilityState>(src.state);
+        dst.details                 = DupOptionalString(src.details);
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCPUFrequency(DracCacheManager* mgr, DracCPUFrequency* out_freq) -> DracErrorCode {
+    if (!mgr || !out_freq)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<CPUFrequency> result = GetCPUFrequency(mgr->inner);
+
+    if (result.has_value()) {
+      CPUFrequency& val    = result.value();
+      out_freq->currentMhz = val.currentMhz.value_or(0);
+      out_freq->baseMhz    = val.baseMhz.value_or(0);
+      out_freq->maxMhz     = val.maxMhz.value_or(0);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCPUTemperature(DracCacheManager* mgr, double* out_celsius) -> DracErrorCode {
+    if (!mgr || !out_celsius)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<f64> result = GetCPUTemperature(mgr->inner);
+
+    if (result.has_value()) {
+      *out_celsius = result.value();
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetFans(DracCacheManager* mgr, DracFanInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<FanInfo>> result = GetFans(mgr->inner);
+
+    if (result.has_value()) {
+      const Vec<FanInfo>& fans = result.value();
+      out_list->count          = fans.size();
+      out_list->items          = new DracFanInfo[fans.size()];
+
+      Span<DracFanInfo> outItems(out_list->items, out_list->count);
+      usize             idx = 0;
+
+      for (DracFanInfo& dst : outItems) {
+        const FanInfo& src = fans[idx++];
+        dst.label          = DupString(src.label);
+        dst.rpm            = src.rpm ? static_cast<int32_t>(*src.rpm) : -1;
+        dst.targetRpm      = src.targetRpm ? static_cast<int32_t>(*src.targetRpm) : -1;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetSensors(DracCacheManager* mgr, DracSensorReadingList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<SensorReading>> result = GetSensors(mgr->inner);
+
+    if (result.has_value()) {
+      const Vec<SensorReading>& sensors = result.value();
+      out_list->count                   = sensors.size();
+      out_list->items                   = new DracSensorReading[sensors.size()];
+
+      Span<DracSensorReading> outItems(out_list->items, out_list->count);
+      usize                   idx = 0;
+
+      for (DracSensorReading& dst : outItems) {
+        const SensorReading& src = sensors[idx++];
+        dst.kind                 = static_cast<DracSensorKind>(src.kind);
+        dst.chip                 = DupString(src.chip);
+        dst.label                = DupString(src.label);
+        dst.value                = src.value;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCPUPowerWatts(DracCacheManager* mgr, double* out_watts) -> DracErrorCode {
+    if (!mgr || !out_watts)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<f64> result = GetCPUPowerWatts(mgr->inner);
+
+    if (result.has_value()) {
+      *out_watts = result.value();
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetCPUTimesPerCore(DracCPUTimesList* out_list) -> DracErrorCode {
+    if (!out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<CPUTimes>> result = GetCPUTimesPerCore();
+
+    if (result.has_value()) {
+      Vec<CPUTimes>& times = result.value();
+      out_list->count      = times.size();
+      out_list->items      = new DracCPUTimes[times.size()];
+
+      Span<DracCPUTimes> outItems(out_list->items, out_list->count);
+      usize              idx = 0;
+      for (DracCPUTimes& dst : outItems) {
+        CPUTimes& src = times[idx++];
+        dst.idle      = src.idle;
+        dst.total     = src.total;
+      }
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetOperatingSystem(DracCacheManager* mgr, DracOSInfo* out_info) -> DracErrorCode {
+    if (!mgr || !out_info)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_info = { .name = nullptr, .version = nullptr, .id = nullptr };
+
+    Result<OSInfo> result = GetOperatingSystem(mgr->inner);
+
+    if (result.has_value()) {
+      OSInfo& val       = result.value();
+      out_info->name    = DupString(val.name);
+      out_info->version = DupString(val.version);
+      out_info->id      = DupString(val.id);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetDesktopEnvironment(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetDesktopEnvironment(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetWindowManager(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetWindowManager(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetDisplaySession(DracCacheManager* mgr, DracDisplaySession* out_session) -> DracErrorCode {
+    if (!mgr || !out_session)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<DisplaySession> result = GetDisplaySession(mgr->inner);
+
+    if (result.has_value()) {
+      DisplaySession& val     = result.value();
+      out_session->server     = static_cast<DracDisplayServer>(val.server);
+      out_session->compositor = DupOptionalString(val.compositor);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetThemeInfo(DracCacheManager* mgr, DracThemeInfo* out_theme) -> DracErrorCode {
+    if (!mgr || !out_theme)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<ThemeInfo> result = GetThemeInfo(mgr->inner);
+
+    if (result.has_value()) {
+      ThemeInfo& val         = result.value();
+      out_theme->widgetTheme = DupOptionalString(val.widgetTheme);
+      out_theme->iconTheme   = DupOptionalString(val.iconTheme);
+      out_theme->cursorTheme = DupOptionalString(val.cursorTheme);
+      out_theme->font        = DupOptionalString(val.font);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetDefaultApplications(DracDefaultApplications* out_apps) -> DracErrorCode {
+    if (!out_apps)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_apps = { .editor = nullptr, .browser = nullptr, .terminal = nullptr };
+
+    Result<DefaultApplications> result = GetDefaultApplications();
+
+    if (result.has_value()) {
+      DefaultApplications& val = result.value();
+      out_apps->editor         = DupOptionalString(val.editor);
+      out_apps->browser        = DupOptionalString(val.browser);
+      out_apps->terminal       = DupOptionalString(val.terminal);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetAudioDevices(DracCacheManager* mgr, DracAudioDeviceList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<AudioDevice>> result = GetAudioDevices(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<AudioDevice>& devices = result.value();
+      out_list->count           = devices.size();
+      out_list->items           = new DracAudioDevice[devices.size()];
+
+      Span<DracAudioDevice> outItems(out_list->items, out_list->count);
+      usize                 idx = 0;
+
+      for (DracAudioDevice& dst : outItems) {
+        AudioDevice& src = devices[idx++];
+        dst.direction    = static_cast<DracAudioDirection>(src.direction);
+        dst.id           = DupString(src.id);
+        dst.name         = DupString(src.name);
+        dst.isDefault    = src.isDefault;
+        dst.channels     = src.channels ? static_cast<int32_t>(*src.channels) : -1;
+        dst.sampleRate   = src.sampleRate ? static_cast<int32_t>(*src.sampleRate) : -1;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetVolume(DracCacheManager* mgr, DracVolumeInfo* out_volume) -> DracErrorCode {
+    if (!mgr || !out_volume)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<VolumeInfo> result = GetVolume(mgr->inner);
+
+    if (result.has_value()) {
+      VolumeInfo& val     = result.value();
+      out_volume->percent = val.percent;
+      out_volume->muted   = val.muted;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracSetVolume(const double percent) -> DracErrorCode {
+    if (std::isnan(percent))
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<> result = SetVolume(percent);
+
+    if (result.has_value())
+      return DRAC_SUCCESS;
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracSetMuted(const bool muted) -> DracErrorCode {
+    Result<> result = SetMuted(muted);
+
+    if (result.has_value())
+      return DRAC_SUCCESS;
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetShell(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetShell(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetTerminal(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetTerminal(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetTerminalFont(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetTerminalFont(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetHost(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetHost(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetHostname(char** out_str) -> DracErrorCode {
+    if (!out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetHostname();
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetFQDN(char** out_str) -> DracErrorCode {
+    if (!out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetFQDN();
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetDomainInfo(DracCacheManager* mgr, DracDomainInfo* out_info) -> DracErrorCode {
+    if (!mgr || !out_info)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_info = {
+      .membership    = DRAC_DOMAIN_MEMBERSHIP_UNJOINED,
+      .name          = nullptr,
+      .dnsDomain     = nullptr,
+      .azureAdJoined = false,
+      .azureTenant   = nullptr,
+    };
+
+    Result<DomainInfo> result = GetDomainInfo(mgr->inner);
+
+    if (result.has_value()) {
+      const DomainInfo& info  = result.value();
+      out_info->membership    = static_cast<DracDomainMembership>(info.membership);
+      out_info->name          = DupOptionalString(info.name);
+      out_info->dnsDomain     = DupOptionalString(info.dnsDomain);
+      out_info->azureAdJoined = info.azureAdJoined;
+      out_info->azureTenant   = DupOptionalString(info.azureTenant);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetFirmwareInfo(DracCacheManager* mgr, DracFirmwareInfo* out_info) -> DracErrorCode {
+    if (!mgr || !out_info)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_info = {
+      .boardVendor  = nullptr,
+      .boardModel   = nullptr,
+      .boardVersion = nullptr,
+      .vendor       = nullptr,
+      .version      = nullptr,
+      .releaseDate  = nullptr,
+      .isUefi       = false,
+    };
+
+    Result<FirmwareInfo> result = GetFirmwareInfo(mgr->inner);
+
+    if (result.has_value()) {
+      FirmwareInfo& info     = result.value();
+      out_info->boardVendor  = DupOptionalString(info.boardVendor);
+      out_info->boardModel   = DupOptionalString(info.boardModel);
+      out_info->boardVersion = DupOptionalString(info.boardVersion);
+      out_info->vendor       = DupOptionalString(info.vendor);
+      out_info->version      = DupOptionalString(info.version);
+      out_info->releaseDate  = DupOptionalString(info.releaseDate);
+      out_info->isUefi       = info.isUefi;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetMachineId(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetMachineId(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetHardwareSerial(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetHardwareSerial(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetVirtualization(DracCacheManager* mgr, DracVirtEnv* out_env) -> DracErrorCode {
+    if (!mgr || !out_env)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_env = {
+      .hypervisor       = DRAC_HYPERVISOR_NONE,
+      .container        = DRAC_CONTAINER_NONE,
+      .hypervisorVendor = nullptr,
+    };
+
+    Result<VirtEnv> result = GetVirtualization(mgr->inner);
+
+    if (result.has_value()) {
+      const VirtEnv& env        = result.value();
+      out_env->hypervisor       = static_cast<DracHypervisor>(env.hypervisor);
+      out_env->container        = static_cast<DracContainer>(env.container);
+      out_env->hypervisorVendor = DupOptionalString(env.hypervisorVendor);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetSecurityPlatformInfo(DracCacheManager* mgr, DracSecurityPlatformInfo* out_info) -> DracErrorCode {
+    if (!mgr || !out_info)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_info = {
+      .secureBoot   = DRAC_SECURE_BOOT_UNKNOWN,
+      .tpmPresent   = false,
+      .tpmVersion   = nullptr,
+      .measuredBoot = false,
+    };
+
+    Result<SecurityPlatformInfo> result = GetSecurityPlatformInfo(mgr->inner);
+
+    if (result.has_value()) {
+      const SecurityPlatformInfo& info = result.value();
+      out_info->secureBoot             = static_cast<DracSecureBootState>(info.secureBoot);
+      out_info->tpmPresent             = info.tpmPresent;
+      out_info->tpmVersion             = DupOptionalString(info.tpmVersion);
+      out_info->measuredBoot           = info.measuredBoot;
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetMacStatus(DracCacheManager* mgr, DracMacStatus* out_status) -> DracErrorCode {
+    if (!mgr || !out_status)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_status = {
+      .framework = DRAC_MAC_FRAMEWORK_NONE,
+      .mode      = DRAC_MAC_MODE_DISABLED,
+      .policy    = nullptr,
+    };
+
+    Result<MacStatus> result = GetMacStatus(mgr->inner);
+
+    if (result.has_value()) {
+      const MacStatus& status = result.value();
+      out_status->framework   = static_cast<DracMacFramework>(status.framework);
+      out_status->mode        = static_cast<DracMacMode>(status.mode);
+      out_status->policy      = DupOptionalString(status.policy);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetFirewallStatus(DracCacheManager* mgr, DracFirewallStatus* out_status) -> DracErrorCode {
+    if (!mgr || !out_status)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_status = {
+      .backend      = DRAC_FIREWALL_BACKEND_FIREWALLD,
+      .profiles     = nullptr,
+      .profileCount = 0,
+    };
+
+    Result<FirewallStatus> result = GetFirewallStatus(mgr->inner);
+
+    if (result.has_value()) {
+      const FirewallStatus& status = result.value();
+      out_status->backend          = static_cast<DracFirewallBackend>(status.backend);
+      out_status->profileCount     = status.profiles.size();
+      out_status->profiles         = new DracFirewallProfile[status.profiles.size()];
+
+      Span<DracFirewallProfile> outItems(out_status->profiles, out_status->profileCount);
+      usize                     idx = 0;
+
+      for (DracFirewallProfile& dst : outItems) {
+        const FirewallProfile& src = status.profiles[idx++];
+        dst.name                   = DupString(src.name);
+        dst.enabled                = src.enabled;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetStringRef(DracCacheManager* mgr, const DracStringField field, const char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str || field < DRAC_STRING_CPU_MODEL || field > DRAC_STRING_HARDWARE_SERIAL)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_str = nullptr;
+
+    // Indexed by DracStringField.
+    constexpr Array<Result<String> (*)(CacheManager&), 12> getters {
+      &GetCPUModel,
+      &GetGPUModel,
+      &GetHost,
+      &GetKernelVersion,
+      &GetKernelCmdline,
+      &GetDesktopEnvironment,
+      &GetWindowManager,
+      &GetShell,
+      &GetTerminal,
+      &GetTerminalFont,
+      &GetMachineId,
+      &GetHardwareSerial,
+    };
+
+    Result<String> result = getters.at(static_cast<usize>(field))(mgr->inner);
+
+    if (!result.has_value())
+      return TO_C_ERROR(result.error());
+
+    LockGuard lock(mgr->internMutex);
+
+    auto iter = mgr->internedStrings.find(*result);
+
+    if (iter == mgr->internedStrings.end())
+      iter = mgr->internedStrings.insert(std::move(*result)).first;
+
+    *out_str = iter->c_str();
+    return DRAC_SUCCESS;
+  }
+
+  auto DracCollect(DracCacheManager* mgr, const uint64_t mask, DracFieldSet* out_set) -> DracErrorCode {
+    constexpr uint64_t AllFields = (uint64_t { DRAC_FIELD_BATTERY } << 1) - 1;
+
+    if (!mgr || !out_set || (mask & ~AllFields) != 0)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_set = {
+      .requested          = mask,
+      .errors             = {},
+      .messages           = {},
+      .cpuModel           = nullptr,
+      .gpuModel           = nullptr,
+      .host               = nullptr,
+      .kernelVersion      = nullptr,
+      .operatingSystem    = { .name = nullptr, .version = nullptr, .id = nullptr },
+      .desktopEnvironment = nullptr,
+      .windowManager      = nullptr,
+      .shell              = nullptr,
+      .cpuCores           = { .physical = 0, .logical = 0 },
+      .memory             = { .usedBytes = 0, .totalBytes = 0 },
+      .swap               = { .usedBytes = 0, .totalBytes = 0 },
+      .diskUsage          = { .usedBytes = 0, .totalBytes = 0 },
+      .battery            = { .status = DRAC_BATTERY_UNKNOWN, .percentage = UINT8_MAX, .timeRemainingSecs = -1 },
+    };
+
+    std::ranges::fill(out_set->errors, DRAC_SUCCESS);
+
+    // Each field goes through its own getter, so the set matches what the individual calls return.
+    const auto collect = [&](const DracField field, auto getter, auto* out) -> Unit {
+      if (!(mask & field))
+        return;
+
+      const usize index = std::countr_zero(static_cast<uint64_t>(field));
+
+      LastErrorState         = {};
+      out_set->errors[index] = getter(mgr, out);
+
+      // Taken now, since the next getter's error would replace it.
+      LastError last = std::exchange(LastErrorState, {});
+
+      if (out_set->errors[index] != DRAC_SUCCESS && last.code == out_set->errors[index])
+        out_set->messages[index] = DupOptionalString(last.message);
+    };
+
+    collect(DRAC_FIELD_CPU_MODEL, &DracGetCPUModel, &out_set->cpuModel);
+    collect(DRAC_FIELD_GPU_MODEL, &DracGetGPUModel, &out_set->gpuModel);
+    collect(DRAC_FIELD_HOST, &DracGetHost, &out_set->host);
+    collect(DRAC_FIELD_KERNEL_VERSION, &DracGetKernelVersion, &out_set->kernelVersion);
+    collect(DRAC_FIELD_OPERATING_SYSTEM, &DracGetOperatingSystem, &out_set->operatingSystem);
+    collect(DRAC_FIELD_DESKTOP_ENVIRONMENT, &DracGetDesktopEnvironment, &out_set->desktopEnvironment);
+    collect(DRAC_FIELD_WINDOW_MANAGER, &DracGetWindowManager, &out_set->windowManager);
+    collect(DRAC_FIELD_SHELL, &DracGetShell, &out_set->shell);
+    collect(DRAC_FIELD_CPU_CORES, &DracGetCpuCores, &out_set->cpuCores);
+    collect(DRAC_FIELD_MEMORY, &DracGetMemInfo, &out_set->memory);
+    collect(DRAC_FIELD_SWAP, &DracGetSwapInfo, &out_set->swap);
+    collect(DRAC_FIELD_DISK_USAGE, &DracGetDiskUsage, &out_set->diskUsage);
+    collect(DRAC_FIELD_BATTERY, &DracGetBatteryInfo, &out_set->battery);
+
+    return DRAC_SUCCESS;
+  }
+
+  auto DracFreeFieldSet(DracFieldSet* set) -> void {
+    if (!set)
+      return;
+
+    for (char** str : { &set->cpuModel, &set->gpuModel, &set->host, &set->kernelVersion, &set->desktopEnvironment, &set->windowManager, &set->shell }) {
+      delete[] *str;
+      *str = nullptr;
+    }
+
+    for (char*& message : set->messages) {
+      delete[] message;
+      message = nullptr;
+    }
+
+    DracFreeOSInfo(&set->operatingSystem);
+  }
+
+  auto DracGetCPUModel(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetCPUModel(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetGPUModel(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetGPUModel(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetGPUs(DracCacheManager* mgr, DracGPUInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<GPUInfo>> result = GetGPUs(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<GPUInfo>& gpus = result.value();
+      out_list->count    = gpus.size();
+      out_list->items    = new DracGPUInfo[gpus.size()];
+
+      Span<DracGPUInfo> outItems(out_list->items, out_list->count);
+      usize             idx = 0;
+
+      for (DracGPUInfo& dst : outItems) {
+        GPUInfo& src           = gpus[idx++];
+        dst.vendor             = DupString(src.vendor);
+        dst.model              = DupString(src.model);
+        dst.vramBytes          = src.vramBytes.value_or(0);
+        dst.driver             = DupOptionalString(src.driver);
+        dst.isIntegrated       = src.isIntegrated;
+        dst.temperatureCelsius = src.temperatureCelsius.value_or(std::numeric_limits<f64>::quiet_NaN());
+        dst.powerWatts         = src.powerWatts.value_or(std::numeric_limits<f64>::quiet_NaN());
+        dst.driverVersion      = DupOptionalString(src.driverVersion);
+        dst.vulkanVersion      = DupOptionalString(src.vulkanVersion);
+        dst.openGLVersion      = DupOptionalString(src.openGLVersion);
+        dst.directXVersion     = DupOptionalString(src.directXVersion);
+        dst.metalVersion       = DupOptionalString(src.metalVersion);
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+
+  auto DracGetKernelVersion(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<ResourceUsage> result = GetMemInfo(mgr->inner);
+    Result<String> result = GetKernelVersion(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetKernelCmdline(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
+    if (!mgr || !out_str)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<String> result = GetKernelCmdline(mgr->inner);
+
+    if (result.has_value()) {
+      *out_str = DupString(result.value());
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetKernelModules(DracCacheManager* mgr, DracKernelModuleList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<KernelModule>> result = GetKernelModules(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<KernelModule>& modules = result.value();
+      out_list->count            = modules.size();
+      out_list->items            = new DracKernelModule[modules.size()];
+
+      Span<DracKernelModule> outItems(out_list->items, out_list->count);
+      usize                  idx = 0;
+
+      for (DracKernelModule& dst : outItems) {
+        KernelModule& src = modules[idx++];
+        dst.name          = DupString(src.name);
+        dst.sizeBytes     = src.sizeBytes ? static_cast<int64_t>(*src.sizeBytes) : -1;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetDiskUsage(DracCacheManager* mgr, DracResourceUsage* out_usage) -> DracErrorCode {
+    if (!mgr || !out_usage)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<ResourceUsage> result = GetDiskUsage(mgr->inner);
 
     if (result.has_value()) {
       ResourceUsage& val    = result.value();
@@ -259,180 +2788,604 @@ extern "C" {
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetCpuCores(DracCacheManager* mgr, DracCPUCores* out_cores) -> DracErrorCode {
-    if (!mgr || !out_cores)
+  auto DracGetDisks(DracCacheManager* mgr, DracDiskInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<DiskInfo>> result = GetDisks(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<DiskInfo>& disks = result.value();
+      out_list->count      = disks.size();
+      out_list->items      = new DracDiskInfo[disks.size()];
+
+      Span<DracDiskInfo> outItems(out_list->items, out_list->count);
+      usize              idx = 0;
+
+      for (DracDiskInfo& dst : outItems)
+        CopyDiskInfo(disks[idx++], dst);
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetSystemDisk(DracCacheManager* mgr, DracDiskInfo* out_info) -> DracErrorCode {
+    if (!mgr || !out_info)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_info = {
+      .name             = nullptr,
+      .mountPoint       = nullptr,
+      .filesystem       = nullptr,
+      .driveType        = nullptr,
+      .totalBytes       = 0,
+      .usedBytes        = 0,
+      .totalInodes      = -1,
+      .usedInodes       = -1,
+      .mountOptions     = nullptr,
+      .mountOptionCount = 0,
+      .isReadOnly       = false,
+      .isSystemDrive    = false,
+    };
+
+    Result<DiskInfo> result = GetSystemDisk(mgr->inner);
+
+    if (result.has_value()) {
+      CopyDiskInfo(result.value(), *out_info);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetDiskHealth(const char* device, DracDiskHealth* out_health) -> DracErrorCode {
+    if (!device || !out_health)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_health = {
+      .device             = nullptr,
+      .status             = DRAC_DISK_HEALTH_UNKNOWN,
+      .powerOnHours       = -1,
+      .reallocatedSectors = -1,
+      .temperatureCelsius = std::numeric_limits<f64>::quiet_NaN(),
+      .attributes         = nullptr,
+      .attributeCount     = 0,
+    };
+
+    Result<DiskHealth> result = GetDiskHealth(device);
+
+    if (result.has_value()) {
+      DiskHealth& health             = result.value();
+      out_health->device             = DupString(health.device);
+      out_health->status             = static_cast<DracDiskHealthStatus>(health.status);
+      out_health->powerOnHours       = health.powerOnHours ? static_cast<int64_t>(*health.powerOnHours) : -1;
+      out_health->reallocatedSectors = health.reallocatedSectors ? static_cast<int64_t>(*health.reallocatedSectors) : -1;
+      out_health->temperatureCelsius = health.temperatureCelsius.value_or(std::numeric_limits<f64>::quiet_NaN());
+      out_health->attributeCount     = health.attributes.size();
+      out_health->attributes         = new DracSmartAttribute[health.attributes.size()];
+
+      Span<DracSmartAttribute> outItems(out_health->attributes, out_health->attributeCount);
+      usize                    idx = 0;
+
+      for (DracSmartAttribute& dst : outItems) {
+        SmartAttribute& src = health.attributes[idx++];
+        dst.id              = src.id;
+        dst.name            = DupString(src.name);
+        dst.value           = src.value;
+        dst.worst           = src.worst;
+        dst.threshold       = src.threshold ? static_cast<int32_t>(*src.threshold) : -1;
+        dst.raw             = src.raw;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetDiskTemperatures(DracCacheManager* mgr, DracDiskTemperatureList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<DiskTemperature>> result = GetDiskTemperatures(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<DiskTemperature>& temperatures = result.value();
+      out_list->count                    = temperatures.size();
+      out_list->items                    = new DracDiskTemperature[temperatures.size()];
+
+      Span<DracDiskTemperature> outItems(out_list->items, out_list->count);
+      usize                     idx = 0;
+
+      for (DracDiskTemperature& dst : outItems) {
+        DiskTemperature& src = temperatures[idx++];
+        dst.device           = DupString(src.device);
+        dst.celsius          = src.celsius;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetDiskIOStats(DracCacheManager* mgr, DracDiskIOStatsList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<DiskIOStats>> result = GetDiskIOStats(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<DiskIOStats>& stats = result.value();
+      out_list->count         = stats.size();
+      out_list->items         = new DracDiskIOStats[stats.size()];
+
+      Span<DracDiskIOStats> outItems(out_list->items, out_list->count);
+      usize                 idx = 0;
+
+      for (DracDiskIOStats& dst : outItems) {
+        DiskIOStats& src = stats[idx++];
+        dst.device       = DupString(src.device);
+        dst.readOps      = src.readOps;
+        dst.writeOps     = src.writeOps;
+        dst.readBytes    = src.readBytes;
+        dst.writeBytes   = src.writeBytes;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetNetworkMounts(DracCacheManager* mgr, DracNetworkMountList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<NetworkMount>> result = GetNetworkMounts(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<NetworkMount>& mounts = result.value();
+      out_list->count           = mounts.size();
+      out_list->items           = new DracNetworkMount[mounts.size()];
+
+      Span<DracNetworkMount> outItems(out_list->items, out_list->count);
+      usize                  idx = 0;
+
+      for (DracNetworkMount& dst : outItems) {
+        NetworkMount& src = mounts[idx++];
+        dst.server        = DupString(src.server);
+        dst.exportPath    = DupString(src.exportPath);
+        dst.mountPoint    = DupString(src.mountPoint);
+        dst.protocol      = static_cast<DracNetworkMountProtocol>(src.protocol);
+        dst.state         = static_cast<DracNetworkMountState>(src.state);
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetOutputs(DracCacheManager* mgr, DracDisplayInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<Vec<DisplayInfo>> result = GetOutputs(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<DisplayInfo>& outputs = result.value();
+      out_list->count           = outputs.size();
+      out_list->items           = new DracDisplayInfo[outputs.size()];
+
+      Span<DracDisplayInfo> outItems(out_list->items, out_list->count);
+      usize                 idx = 0;
+      for (DracDisplayInfo& dst : outItems)
+        CopyDisplayInfo(outputs[idx++], dst);
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetPrimaryOutput(DracCacheManager* mgr, DracDisplayInfo* out_info) -> DracErrorCode {
+    if (!mgr || !out_info)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<DisplayInfo> result = GetPrimaryOutput(mgr->inner);
+
+    if (result.has_value()) {
+      CopyDisplayInfo(result.value(), *out_info);
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetBrightness(DracCacheManager* mgr, DracBacklightInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<BacklightInfo>> result = GetBrightness(mgr->inner);
+
+    if (result.has_value()) {
+      const Vec<BacklightInfo>& backlights = result.value();
+      out_list->count                      = backlights.size();
+      out_list->items                      = new DracBacklightInfo[backlights.size()];
+
+      Span<DracBacklightInfo> outItems(out_list->items, out_list->count);
+      usize                   idx = 0;
+
+      for (DracBacklightInfo& dst : outItems) {
+        const BacklightInfo& src = backlights[idx++];
+        dst.device               = DupString(src.device);
+        dst.percent              = src.percent;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetNetworkInterfaces(DracCacheManager* mgr, DracNetworkInterfaceList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    Result<Vec<NetworkInterface>> result = GetNetworkInterfaces(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<NetworkInterface>& ifaces = result.value();
+      out_list->count               = ifaces.size();
+      out_list->items               = new DracNetworkInterface[ifaces.size()];
+
+      Span<DracNetworkInterface> outItems(out_list->items, out_list->count);
+      usize                      idx = 0;
+      for (DracNetworkInterface& dst : outItems) {
+        NetworkInterface& src = ifaces[idx++];
+        dst.name              = DupString(src.name);
+        dst.ipv4Address       = DupOptionalString(src.ipv4Address);
+        dst.ipv6Address       = DupOptionalString(src.ipv6Address);
+        dst.macAddress        = DupOptionalString(src.macAddress);
+        dst.isUp              = src.isUp;
+        dst.isLoopback        = src.isLoopback;
+        dst.linkSpeedMbps     = src.linkSpeedMbps ? static_cast<int64_t>(*src.linkSpeedMbps) : -1;
+        dst.mtu               = src.mtu ? static_cast<int32_t>(*src.mtu) : -1;
+        dst.duplex            = static_cast<DracDuplex>(src.duplex);
+      }
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetNetworkStats(DracCacheManager* mgr, DracInterfaceStatsList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<InterfaceStats>> result = GetNetworkStats(mgr->inner);
+
+    if (result.has_value()) {
+      Vec<InterfaceStats>& stats = result.value();
+      out_list->count            = stats.size();
+      out_list->items            = new DracInterfaceStats[stats.size()];
+
+      Span<DracInterfaceStats> outItems(out_list->items, out_list->count);
+      usize                    idx = 0;
+
+      for (DracInterfaceStats& dst : outItems) {
+        InterfaceStats& src = stats[idx++];
+        dst.name            = DupString(src.name);
+        dst.rxBytes         = src.rxBytes;
+        dst.txBytes         = src.txBytes;
+        dst.rxPackets       = src.rxPackets;
+        dst.txPackets       = src.txPackets;
+        dst.rxErrors        = src.rxErrors;
+        dst.txErrors        = src.txErrors;
+        dst.rxDropped       = src.rxDropped;
+        dst.txDropped       = src.txDropped;
+      }
+
+      return DRAC_SUCCESS;
+    }
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracGetPrimaryNetworkInterface(DracCacheManager* mgr, DracNetworkInterface* out_iface) -> DracErrorCode {
+    if (!mgr || !out_iface)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<CPUCores> result = GetCPUCores(mgr->inner);
+    Result<NetworkInterface> result = GetPrimaryNetworkInterface(mgr->inner);
 
     if (result.has_value()) {
-      CPUCores& val       = result.value();
-      out_cores->physical = val.physical;
-      out_cores->logical  = val.logical;
+      NetworkInterface& iface  = result.value();
+      out_iface->name          = DupString(iface.name);
+      out_iface->ipv4Address   = DupOptionalString(iface.ipv4Address);
+      out_iface->ipv6Address   = DupOptionalString(iface.ipv6Address);
+      out_iface->macAddress    = DupOptionalString(iface.macAddress);
+      out_iface->isUp          = iface.isUp;
+      out_iface->isLoopback    = iface.isLoopback;
+      out_iface->linkSpeedMbps = iface.linkSpeedMbps ? static_cast<int64_t>(*iface.linkSpeedMbps) : -1;
+      out_iface->mtu           = iface.mtu ? static_cast<int32_t>(*iface.mtu) : -1;
+      out_iface->duplex        = static_cast<DracDuplex>(iface.duplex);
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetOperatingSystem(DracCacheManager* mgr, DracOSInfo* out_info) -> DracErrorCode {
+  auto DracGetBluetoothDevices(DracCacheManager* mgr, DracBluetoothInfo* out_info) -> DracErrorCode {
     if (!mgr || !out_info)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    *out_info = { .name = nullptr, .version = nullptr, .id = nullptr };
+    *out_info = { .adapters = nullptr, .adapterCount = 0, .devices = nullptr, .deviceCount = 0 };
 
-    Result<OSInfo> result = GetOperatingSystem(mgr->inner);
+    Result<BluetoothInfo> result = GetBluetoothDevices(mgr->inner);
 
     if (result.has_value()) {
-      OSInfo& val       = result.value();
-      out_info->name    = DupString(val.name);
-      out_info->version = DupString(val.version);
-      out_info->id      = DupString(val.id);
+      BluetoothInfo& info    = result.value();
+      out_info->adapterCount = info.adapters.size();
+      out_info->adapters     = new DracBluetoothAdapter[info.adapters.size()];
+      out_info->deviceCount  = info.devices.size();
+      out_info->devices      = new DracBluetoothDevice[info.devices.size()];
+
+      Span<DracBluetoothAdapter> outAdapters(out_info->adapters, out_info->adapterCount);
+      usize                      idx = 0;
+
+      for (DracBluetoothAdapter& dst : outAdapters) {
+        BluetoothAdapter& src = info.adapters[idx++];
+        dst.name              = DupString(src.name);
+        dst.address           = DupString(src.address);
+        dst.powered           = src.powered;
+      }
+
+      Span<DracBluetoothDevice> outDevices(out_info->devices, out_info->deviceCount);
+      idx = 0;
+
+      for (DracBluetoothDevice& dst : outDevices) {
+        BluetoothDevice& src = info.devices[idx++];
+        dst.name             = DupString(src.name);
+        dst.address          = DupString(src.address);
+        dst.paired           = src.paired;
+        dst.connected        = src.connected;
+        dst.batteryPercent   = src.batteryPercent ? static_cast<int32_t>(*src.batteryPercent) : -1;
+      }
+
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetDesktopEnvironment(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
-    if (!mgr || !out_str)
+  auto DracGetPrinters(DracCacheManager* mgr, DracPrinterInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<String> result = GetDesktopEnvironment(mgr->inner);
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<PrinterInfo>> result = GetPrinters(mgr->inner);
 
     if (result.has_value()) {
-      *out_str = DupString(result.value());
+      const Vec<PrinterInfo>& printers = result.value();
+      out_list->count                  = printers.size();
+      out_list->items                  = new DracPrinterInfo[printers.size()];
+
+      Span<DracPrinterInfo> outItems(out_list->items, out_list->count);
+      usize                 idx = 0;
+
+      for (DracPrinterInfo& dst : outItems) {
+        const PrinterInfo& src = printers[idx++];
+        dst.state              = static_cast<DracPrinterState>(src.state);
+        dst.name               = DupString(src.name);
+        dst.driver             = DupOptionalString(src.driver);
+        dst.connection         = DupOptionalString(src.connection);
+        dst.isDefault          = src.isDefault;
+      }
+
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetWindowManager(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
-    if (!mgr || !out_str)
+  auto DracGetCameras(DracCacheManager* mgr, DracCameraInfoList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<String> result = GetWindowManager(mgr->inner);
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<CameraInfo>> result = GetCameras(mgr->inner);
 
     if (result.has_value()) {
-      *out_str = DupString(result.value());
+      const Vec<CameraInfo>& cameras = result.value();
+      out_list->count                = cameras.size();
+      out_list->items                = new DracCameraInfo[cameras.size()];
+
+      Span<DracCameraInfo> outItems(out_list->items, out_list->count);
+      usize                idx = 0;
+
+      for (DracCameraInfo& dst : outItems) {
+        const CameraInfo& src = cameras[idx++];
+        dst.name              = DupString(src.name);
+        dst.id                = DupString(src.id);
+        dst.inUse             = src.inUse;
+      }
+
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetShell(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
-    if (!mgr || !out_str)
+  auto DracGetWifiInfo(DracCacheManager* mgr, DracWifiInfo* out_info) -> DracErrorCode {
+    if (!mgr || !out_info)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<String> result = GetShell(mgr->inner);
+    Result<WifiInfo> result = GetWifiInfo(mgr->inner);
 
     if (result.has_value()) {
-      *out_str = DupString(result.value());
+      WifiInfo& info          = result.value();
+      out_info->interfaceName = DupString(info.interfaceName);
+      out_info->ssid          = DupOptionalString(info.ssid);
+      out_info->bssid         = DupOptionalString(info.bssid);
+      out_info->signalDbm     = info.signalDbm.value_or(INT32_MIN);
+      out_info->signalPercent = info.signalPercent ? static_cast<int32_t>(*info.signalPercent) : -1;
+      out_info->frequencyMhz  = info.frequencyMhz ? static_cast<int32_t>(*info.frequencyMhz) : -1;
+      out_info->band          = static_cast<DracWifiBand>(info.band);
+      out_info->linkRateMbps  = info.linkRateMbps.value_or(std::numeric_limits<f64>::quiet_NaN());
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetHost(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
-    if (!mgr || !out_str)
+  auto DracGetDefaultGateway(DracCacheManager* mgr, DracGateway* out_gateway) -> DracErrorCode {
+    if (!mgr || !out_gateway)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<String> result = GetHost(mgr->inner);
+    Result<Gateway> result = GetDefaultGateway(mgr->inner);
 
     if (result.has_value()) {
-      *out_str = DupString(result.value());
+      out_gateway->address       = DupString(result->address);
+      out_gateway->interfaceName = DupString(result->interfaceName);
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetCPUModel(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
-    if (!mgr || !out_str)
+  auto DracGetDnsServers(DracCacheManager* mgr, DracStringList* out_list) -> DracErrorCode {
+    if (!mgr || !out_list)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<String> result = GetCPUModel(mgr->inner);
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<String>> result = GetDnsServers(mgr->inner);
 
     if (result.has_value()) {
-      *out_str = DupString(result.value());
+      Vec<String>& servers = result.value();
+      out_list->count      = servers.size();
+      out_list->items      = new char*[servers.size()];
+
+      Span<char*> outItems(out_list->items, out_list->count);
+      usize       idx = 0;
+
+      for (char*& dst : outItems)
+        dst = DupString(servers[idx++]);
+
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetGPUModel(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
-    if (!mgr || !out_str)
+  auto DracGetPublicIp(DracCacheManager* mgr, DracPublicIp* out_ip) -> DracErrorCode {
+    if (!mgr || !out_ip)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<String> result = GetGPUModel(mgr->inner);
+    *out_ip = { .v4 = nullptr, .v6 = nullptr };
+
+#if DRAC_ENABLE_PUBLICIP
+    Result<draconis::services::network::PublicIp> result = draconis::services::network::GetPublicIp(mgr->inner);
 
     if (result.has_value()) {
-      *out_str = DupString(result.value());
+      out_ip->v4 = DupOptionalString(result->v4);
+      out_ip->v6 = DupOptionalString(result->v6);
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
+#else
+    return DRAC_ERROR_NOT_SUPPORTED;
+#endif
   }
 
-  auto DracGetKernelVersion(DracCacheManager* mgr, char** out_str) -> DracErrorCode {
-    if (!mgr || !out_str)
+  auto DracGetCloudInfo(DracCacheManager* mgr, DracCloudInfo* out_info) -> DracErrorCode {
+    if (!mgr || !out_info)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<String> result = GetKernelVersion(mgr->inner);
+    *out_info = { .provider = DRAC_CLOUD_AWS, .instanceType = nullptr, .region = nullptr };
+
+#if DRAC_ENABLE_CLOUDINFO
+    Result<draconis::services::cloud::CloudInfo> result = draconis::services::cloud::GetCloudInfo(mgr->inner);
 
     if (result.has_value()) {
-      *out_str = DupString(result.value());
+      out_info->provider     = static_cast<DracCloudProvider>(result->provider);
+      out_info->instanceType = DupOptionalString(result->instanceType);
+      out_info->region       = DupOptionalString(result->region);
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
+#else
+    return DRAC_ERROR_NOT_SUPPORTED;
+#endif
   }
 
-  auto DracGetDiskUsage(DracCacheManager* mgr, DracResourceUsage* out_usage) -> DracErrorCode {
-    if (!mgr || !out_usage)
+  auto DracGetBatteryInfo(DracCacheManager* mgr, DracBattery* out_battery) -> DracErrorCode {
+    if (!mgr || !out_battery)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<ResourceUsage> result = GetDiskUsage(mgr->inner);
+    Result<Battery> result = GetBatteryInfo(mgr->inner);
 
     if (result.has_value()) {
-      ResourceUsage& val    = result.value();
-      out_usage->usedBytes  = val.usedBytes;
-      out_usage->totalBytes = val.totalBytes;
+      Battery& battery = result.value();
+
+      out_battery->status = static_cast<DracBatteryStatus>(battery.status);
+      out_battery->percentage =
+        battery.percentage.has_value()
+        ? *battery.percentage
+        : UINT8_MAX;
+      out_battery->timeRemainingSecs =
+        battery.timeRemaining.has_value()
+        ? static_cast<int64_t>(battery.timeRemaining->count())
+        : -1;
+
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetDisks(DracCacheManager* mgr, DracDiskInfoList* out_list) -> DracErrorCode {
+  auto DracGetBatteries(DracCacheManager* mgr, DracBatteryDeviceList* out_list) -> DracErrorCode {
     if (!mgr || !out_list)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
     *out_list = { .items = nullptr, .count = 0 };
 
-    Result<Vec<DiskInfo>> result = GetDisks(mgr->inner);
+    Result<Vec<Battery>> result = GetBatteries(mgr->inner);
 
     if (result.has_value()) {
-      Vec<DiskInfo>& disks = result.value();
-      out_list->count      = disks.size();
-      out_list->items      = new DracDiskInfo[disks.size()];
+      const Vec<Battery>& batteries = result.value();
+      out_list->count               = batteries.size();
+      out_list->items               = new DracBatteryDevice[batteries.size()];
 
-      Span<DracDiskInfo> outItems(out_list->items, out_list->count);
-      usize              idx = 0;
+      Span<DracBatteryDevice> outItems(out_list->items, out_list->count);
+      usize                   idx = 0;
 
-      for (DracDiskInfo& dst : outItems) {
-        DiskInfo& src     = disks[idx++];
-        dst.name          = DupString(src.name);
-        dst.mountPoint    = DupString(src.mountPoint);
-        dst.filesystem    = DupString(src.filesystem);
-        dst.driveType     = DupString(src.driveType);
-        dst.totalBytes    = src.totalBytes;
-        dst.usedBytes     = src.usedBytes;
-        dst.isSystemDrive = src.isSystemDrive;
+      for (DracBatteryDevice& dst : outItems) {
+        const Battery& src    = batteries[idx++];
+        dst.name              = DupString(src.name);
+        dst.isPeripheral      = src.isPeripheral;
+        dst.status            = static_cast<DracBatteryStatus>(src.status);
+        dst.percentage        = src.percentage ? *src.percentage : UINT8_MAX;
+        dst.timeRemainingSecs = src.timeRemaining ? static_cast<int64_t>(src.timeRemaining->count()) : -1;
       }
 
       return DRAC_SUCCESS;
@@ -441,165 +3394,320 @@ extern "C" {
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetSystemDisk(DracCacheManager* mgr, DracDiskInfo* out_info) -> DracErrorCode {
-    if (!mgr || !out_info)
+  auto DracGetPowerSource(DracCacheManager* mgr, DracPowerSource* out_source) -> DracErrorCode {
+    if (!mgr || !out_source)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    *out_info = {
-      .name          = nullptr,
-      .mountPoint    = nullptr,
-      .filesystem    = nullptr,
-      .driveType     = nullptr,
-      .totalBytes    = 0,
-      .usedBytes     = 0,
-      .isSystemDrive = false,
-    };
-
-    Result<DiskInfo> result = GetSystemDisk(mgr->inner);
+    Result<PowerSource> result = GetPowerSource(mgr->inner);
 
     if (result.has_value()) {
-      DiskInfo& disk          = result.value();
-      out_info->name          = DupString(disk.name);
-      out_info->mountPoint    = DupString(disk.mountPoint);
-      out_info->filesystem    = DupString(disk.filesystem);
-      out_info->driveType     = DupString(disk.driveType);
-      out_info->totalBytes    = disk.totalBytes;
-      out_info->usedBytes     = disk.usedBytes;
-      out_info->isSystemDrive = disk.isSystemDrive;
+      const PowerSource& source = result.value();
+      out_source->acConnected   = source.acConnected;
+      out_source->chargerWatts  = source.chargerWatts.value_or(std::numeric_limits<f64>::quiet_NaN());
+      out_source->usbPdWatts    = source.usbPdWatts.value_or(std::numeric_limits<f64>::quiet_NaN());
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetOutputs(DracCacheManager* mgr, DracDisplayInfoList* out_list) -> DracErrorCode {
-    if (!mgr || !out_list)
+  auto DracGetPowerProfile(DracCacheManager* mgr, DracPowerProfile* out_profile) -> DracErrorCode {
+    if (!mgr || !out_profile)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<Vec<DisplayInfo>> result = GetOutputs(mgr->inner);
+    *out_profile = { .mode = DRAC_POWER_MODE_UNKNOWN, .name = nullptr, .governor = nullptr };
 
-    if (result.has_value()) {
-      Vec<DisplayInfo>& outputs = result.value();
-      out_list->count           = outputs.size();
-      out_list->items           = new DracDisplayInfo[outputs.size()];
+    Result<PowerProfile> result = GetPowerProfile(mgr->inner);
 
-      Span<DracDisplayInfo> outItems(out_list->items, out_list->count);
-      usize                 idx = 0;
-      for (DracDisplayInfo& dst : outItems) {
-        DisplayInfo& src = outputs[idx++];
-        dst.id           = src.id;
-        dst.width        = src.resolution.width;
-        dst.height       = src.resolution.height;
-        dst.refreshRate  = src.refreshRate;
-        dst.isPrimary    = src.isPrimary;
-      }
+    if (result.has_value()) {
+      const PowerProfile& profile = result.value();
+      out_profile->mode           = static_cast<DracPowerMode>(profile.mode);
+      out_profile->name           = DupOptionalString(profile.name);
+      out_profile->governor       = DupOptionalString(profile.governor);
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetPrimaryOutput(DracCacheManager* mgr, DracDisplayInfo* out_info) -> DracErrorCode {
-    if (!mgr || !out_info)
+  auto DracGetPendingUpdates(DracCacheManager* mgr, uint64_t* out_count) -> DracErrorCode {
+    if (!mgr || !out_count)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<DisplayInfo> result = GetPrimaryOutput(mgr->inner);
+#if DRAC_ENABLE_PACKAGECOUNT
+    Result<u64> result = draconis::services::packages::GetPendingUpdateCount(mgr->inner);
 
     if (result.has_value()) {
-      DisplayInfo& output   = result.value();
-      out_info->id          = output.id;
-      out_info->width       = output.resolution.width;
-      out_info->height      = output.resolution.height;
-      out_info->refreshRate = output.refreshRate;
-      out_info->isPrimary   = output.isPrimary;
+      *out_count = result.value();
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
+#else
+    return DRAC_ERROR_NOT_SUPPORTED;
+#endif
   }
 
-  auto DracGetNetworkInterfaces(DracCacheManager* mgr, DracNetworkInterfaceList* out_list) -> DracErrorCode {
-    if (!mgr || !out_list)
+  struct DracSystemWatcher {
+    SystemWatcher inner;
+  };
+
+  auto DracCreateSystemWatcher(DracSystemWatcher** out_watcher) -> DracErrorCode {
+    if (!out_watcher)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<Vec<NetworkInterface>> result = GetNetworkInterfaces(mgr->inner);
+    *out_watcher = nullptr;
 
-    if (result.has_value()) {
-      Vec<NetworkInterface>& ifaces = result.value();
-      out_list->count               = ifaces.size();
-      out_list->items               = new DracNetworkInterface[ifaces.size()];
+    Result<SystemWatcher> result = SystemWatcher::Create();
 
-      Span<DracNetworkInterface> outItems(out_list->items, out_list->count);
-      usize                      idx = 0;
-      for (DracNetworkInterface& dst : outItems) {
-        NetworkInterface& src = ifaces[idx++];
-        dst.name              = DupString(src.name);
-        dst.ipv4Address       = DupOptionalString(src.ipv4Address);
-        dst.ipv6Address       = DupOptionalString(src.ipv6Address);
-        dst.macAddress        = DupOptionalString(src.macAddress);
-        dst.isUp              = src.isUp;
-        dst.isLoopback        = src.isLoopback;
-      }
+    if (result.has_value()) {
+      *out_watcher = new DracSystemWatcher { .inner = std::move(result.value()) };
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetPrimaryNetworkInterface(DracCacheManager* mgr, DracNetworkInterface* out_iface) -> DracErrorCode {
-    if (!mgr || !out_iface)
+  auto DracDestroySystemWatcher(DracSystemWatcher* watcher) -> void {
+    delete watcher;
+  }
+
+  auto DracWaitSystemEvents(DracSystemWatcher* watcher, uint32_t timeout_ms, DracSystemEventList* out_list) -> DracErrorCode {
+    if (!watcher || !out_list)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<NetworkInterface> result = GetPrimaryNetworkInterface(mgr->inner);
+    *out_list = { .items = nullptr, .count = 0 };
+
+    Result<Vec<SystemEvent>> result = watcher->inner.wait(std::chrono::milliseconds(timeout_ms));
 
     if (result.has_value()) {
-      NetworkInterface& iface = result.value();
-      out_iface->name         = DupString(iface.name);
-      out_iface->ipv4Address  = DupOptionalString(iface.ipv4Address);
-      out_iface->ipv6Address  = DupOptionalString(iface.ipv6Address);
-      out_iface->macAddress   = DupOptionalString(iface.macAddress);
-      out_iface->isUp         = iface.isUp;
-      out_iface->isLoopback   = iface.isLoopback;
+      Vec<SystemEvent>& events = result.value();
+
+      if (events.empty())
+        return DRAC_SUCCESS;
+
+      out_list->count = events.size();
+      out_list->items = new DracSystemEvent[events.size()];
+
+      Span<DracSystemEvent> outItems(out_list->items, out_list->count);
+      usize                 idx = 0;
+
+      for (DracSystemEvent& dst : outItems) {
+        SystemEvent& src = events[idx++];
+        dst.kind         = static_cast<DracSystemEventKind>(static_cast<u8>(src.kind));
+        dst.source       = DupOptionalString(src.source);
+      }
+
       return DRAC_SUCCESS;
     }
 
     return TO_C_ERROR(result.error());
   }
 
-  auto DracGetBatteryInfo(DracCacheManager* mgr, DracBattery* out_battery) -> DracErrorCode {
-    if (!mgr || !out_battery)
+  struct DracSystemEventSubscription {
+    SystemWatcher           watcher;
+    DracSystemEventCallback callback;
+    void*                   userData;
+    std::atomic<bool>       stopping;
+    std::thread             thread;
+  };
+
+  auto DracSubscribeSystemEvents(DracSystemEventCallback callback, void* user_data, DracSystemEventSubscription** out_subscription) -> DracErrorCode {
+    if (!callback || !out_subscription)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    Result<Battery> result = GetBatteryInfo(mgr->inner);
+    *out_subscription = nullptr;
 
-    if (result.has_value()) {
-      Battery& battery = result.value();
+    Result<SystemWatcher> result = SystemWatcher::Create();
 
-      out_battery->status = static_cast<DracBatteryStatus>(battery.status);
-      out_battery->percentage =
-        battery.percentage.has_value()
-        ? *battery.percentage
-        : UINT8_MAX;
-      out_battery->timeRemainingSecs =
-        battery.timeRemaining.has_value()
-        ? static_cast<int64_t>(battery.timeRemaining->count())
-        : -1;
+    if (!result.has_value())
+      return TO_C_ERROR(result.error());
+
+    auto* subscription = new DracSystemEventSubscription {
+      .watcher  = std::move(result.value()),
+      .callback = callback,
+      .userData = user_data,
+      .stopping = false,
+      .thread   = {},
+    };
 
-      return DRAC_SUCCESS;
+    subscription->thread = std::thread([subscription]() -> void {
+      while (!subscription->stopping.load()) {
+        // Unsubscribing interrupts the wait, so the timeout only bounds how long a single wait lasts.
+        Result<Vec<SystemEvent>> events = subscription->watcher.wait(std::chrono::hours(1));
+
+        if (!events.has_value())
+          return;
+
+        for (SystemEvent& event : *events) {
+          const DracSystemEvent cEvent {
+            .kind   = static_cast<DracSystemEventKind>(static_cast<u8>(event.kind)),
+            .source = event.source ? event.source->data() : nullptr,
+          };
+
+          subscription->callback(&cEvent, subscription->userData);
+        }
+      }
+    });
+
+    *out_subscription = subscription;
+    return DRAC_SUCCESS;
+  }
+
+  auto DracUnsubscribeSystemEvents(DracSystemEventSubscription* subscription) -> void {
+    if (!subscription)
+      return;
+
+    // The watcher remembers an interrupt that lands before the thread's next wait(), so this can't be missed.
+    subscription->stopping.store(true);
+    subscription->watcher.interrupt();
+
+    if (subscription->thread.joinable())
+      subscription->thread.join();
+
+    delete subscription;
+  }
+
+#if DRAC_ENABLE_PLUGINS
+  // Fans the updates a plugin instance pushes out to the subscriptions of the handles to it.
+  // Subscriptions share ownership, so they can outlive those handles.
+  struct PluginUpdates {
+    Mutex                        mutex;
+    Vec<DracPluginSubscription*> subscriptions;
+  };
+
+  struct DracPlugin {
+    IInfoProviderPlugin*             inner;
+    String                           name;
+    bool                             ownsInstance;
+    std::shared_future<Result<Unit>> pendingCollect; // Collection still running after its caller stopped waiting
+    SharedPointer<PluginUpdates>     updates;        // Created by the first DracPluginSubscribe
+    UniquePointer<SandboxedPlugin>   sandbox;        // Set by DracPluginSetSandbox
+    Option<String>                   config;         // Last configuration the plugin accepted, for a new sandbox
+    Option<PluginFields>             sandboxFields;  // From the last successful sandboxed collection
+    Option<String>                   sandboxError;   // From the last failed sandboxed collection
+  };
+
+  struct DracPluginSubscription {
+    SharedPointer<PluginUpdates> updates;
+    DracPluginUpdateCallback     callback;
+    void*                        userData;
+    Mutex                        mutex;
+    std::condition_variable      condition;
+    Option<PluginFields>         pending; // Latest update not yet delivered
+    bool                         stopping;
+    std::thread                  thread;
+  };
+
+  struct DracCancelToken {
+    std::atomic<bool> cancelled;
+  };
+
+  static std::once_flag s_staticPluginInitFlag;
+  static size_t         s_staticPluginCount = 0;
+
+  // Handles to plugins owned by the plugin manager. A reload replaces the manager's
+  // instance, so these are re-pointed at the new one afterwards.
+  static Mutex            s_managedPluginsMutex;
+  static Vec<DracPlugin*> s_managedPlugins;
+
+  static auto TrackManagedPlugin(DracPlugin* plugin) -> DracPlugin* {
+    LockGuard lock(s_managedPluginsMutex);
+    s_managedPlugins.push_back(plugin);
+    return plugin;
+  }
+
+  static auto IsCollecting(const DracPlugin* plugin) -> bool {
+    return plugin->pendingCollect.valid() &&
+      plugin->pendingCollect.wait_for(std::chrono::seconds(0)) != std::future_status::ready;
+  }
+
+  // A collection abandoned by DracPluginCollectDataWithTimeout keeps using the instance, so
+  // anything that reconfigures, replaces or destroys it has to wait for that to finish. The
+  // collection may never return, so callers fail instead of blocking while it's running.
+  static auto FinishCollecting(DracPlugin* plugin) -> bool {
+    if (IsCollecting(plugin))
+      return false;
+
+    plugin->pendingCollect = {};
+    return true;
+  }
+
+  static auto StillCollecting() -> DracErrorCode {
+    using draconis::utils::error::DracError;
+    using enum draconis::utils::error::DracErrorCode;
+
+    return TO_C_ERROR(DracError(Timeout, "Plugin is still running an abandoned collection"));
+  }
+
+  // Collects on the calling thread, in the plugin's helper process if the handle is sandboxed.
+  // A non-zero timeout caps the sandbox's own, and the token is checked while the helper runs.
+  static auto Collect(DracPlugin* plugin, const std::chrono::milliseconds timeout = {}, const DracCancelToken* token = nullptr) -> Result<Unit> {
+    if (!plugin->sandbox) {
+      PluginCache pluginCache(std::filesystem::temp_directory_path() / "draconis_plugins");
+      return plugin->inner->collectData(pluginCache);
+    }
+
+    Result<PluginFields> fields = plugin->sandbox->collect(timeout, token ? &token->cancelled : nullptr);
+
+    if (!fields) {
+      plugin->sandboxError = fields.error().message;
+      return Err(fields.error());
+    }
+
+    plugin->sandboxFields = *std::move(fields);
+    plugin->sandboxError  = None;
+    return {};
+  }
+
+  static auto PublishUpdate(PluginUpdates& updates, const PluginFields& fields) -> void {
+    LockGuard lock(updates.mutex);
+
+    for (DracPluginSubscription* subscription : updates.subscriptions) {
+      {
+        LockGuard pendingLock(subscription->mutex);
+        subscription->pending = fields;
+      }
+
+      subscription->condition.notify_one();
     }
+  }
+
+  // Routes the updates the handle's instance pushes to its subscriptions. Called again
+  // whenever the handle is pointed at a new instance.
+  static auto AttachUpdates(const DracPlugin* plugin) -> void {
+    if (!plugin->inner || !plugin->updates)
+      return;
+
+    plugin->inner->setUpdateCallback([updates = plugin->updates](const PluginFields& fields) -> void {
+      PublishUpdate(*updates, fields);
+    });
+  }
+
+  // Re-points every handle after a reload. Handles to the reloaded plugins start over like their
+  // instance, unconfigured and with a new helper process if they're sandboxed.
+  static auto RepointManagedPlugins(const Span<const String> reloaded) -> void {
+    LockGuard lock(s_managedPluginsMutex);
+    auto&     mgr = GetPluginManager();
+
+    for (DracPlugin* plugin : s_managedPlugins) {
+      plugin->inner = mgr.getInfoProviderByName(plugin->name).value_or(nullptr);
+      AttachUpdates(plugin);
 
-    return TO_C_ERROR(result.error());
-  }
+      if (plugin->inner && std::ranges::find(reloaded, plugin->name) == reloaded.end())
+        continue;
 
-#if DRAC_ENABLE_PLUGINS
-  struct DracPlugin {
-    IInfoProviderPlugin* inner;
-    String               name;
-    bool                 ownsInstance;
-  };
+      plugin->config        = None;
+      plugin->sandboxFields = None;
+      plugin->sandboxError  = None;
 
-  static std::once_flag s_staticPluginInitFlag;
-  static size_t         s_staticPluginCount = 0;
+      if (plugin->sandbox) {
+        const Option<std::filesystem::path> library = mgr.getPluginPath(plugin->name);
+
+        plugin->sandbox = library ? std::make_unique<SandboxedPlugin>(*library, plugin->sandbox->getLimits()) : nullptr;
+      }
+    }
+  }
 
   auto DracInitStaticPlugins_CAPI(void) -> size_t {
     std::call_once(s_staticPluginInitFlag, []() {
@@ -627,6 +3735,15 @@ extern "C" {
   #if DRAC_PRECOMPILED_CONFIG
       // Static plugin mode doesn't use the dynamic PluginManager
   #else
+    {
+      // Unloading would pull a library out from under an abandoned collection, so the
+      // plugins are left for the process to tear down instead
+      LockGuard lock(s_managedPluginsMutex);
+
+      if (std::ranges::any_of(s_managedPlugins, IsCollecting))
+        return;
+    }
+
     GetPluginManager().shutdown();
   #endif
   }
@@ -641,8 +3758,77 @@ extern "C" {
   #endif
   }
 
+  auto DracAddDefaultPluginSearchPaths(void) -> void {
+  #if !DRAC_PRECOMPILED_CONFIG
+    GetPluginManager().addDefaultSearchPaths();
+  #endif
+  }
+
   auto DracDiscoverPlugins(void) -> DracPluginInfoList {
-    return { nullptr, 0 };
+    (void)DracInitStaticPlugins_CAPI();
+
+    Vec<PluginDescription> descriptions = GetPluginManager().describePlugins();
+
+    if (descriptions.empty())
+      return { nullptr, 0 };
+
+    DracPluginInfoList list = { .items = new DracPluginInfo[descriptions.size()], .count = descriptions.size() };
+
+    Span<DracPluginInfo> outItems(list.items, list.count);
+    usize                idx = 0;
+
+    for (DracPluginInfo& dst : outItems) {
+      const PluginDescription&  description  = descriptions[idx++];
+      const PluginMetadata&     metadata     = description.metadata;
+      const PluginDependencies& dependencies = metadata.dependencies;
+
+      dst = {
+        .name               = DupString(metadata.name),
+        .version            = DupString(metadata.version),
+        .author             = DupString(metadata.author),
+        .description        = DupString(metadata.description),
+        .type               = static_cast<DracPluginType>(metadata.type),
+        .capabilities       = { .items = new char*[description.capabilities.size()], .count = description.capabilities.size() },
+        .requiresNetwork    = dependencies.requiresNetwork,
+        .requiresFilesystem = dependencies.requiresFilesystem,
+        .requiresAdmin      = dependencies.requiresAdmin,
+        .requiresCaching    = dependencies.requiresCaching,
+        .configSchema       = DupOptionalString(description.configSchema),
+        .isStatic           = description.isStatic,
+      };
+
+      Span<char*> capabilities(dst.capabilities.items, dst.capabilities.count);
+      usize       capIdx = 0;
+
+      for (char*& capability : capabilities)
+        capability = DupString(description.capabilities[capIdx++]);
+    }
+
+    return list;
+  }
+
+  auto DracRegisterPlugin(const DracPluginVTable* vtable) -> DracErrorCode {
+    if (!vtable || !vtable->name || !vtable->create || !vtable->destroy || !vtable->initialize || !vtable->collect || !vtable->fields)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    // Run the generated registrations first, so a later call can't shadow this name.
+    (void)DracInitStaticPlugins_CAPI();
+
+    if (IsStaticPlugin(vtable->name))
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    RegisterStaticPlugin(
+      vtable->name,
+      {
+        .createFunc = [vtable]() -> IPlugin* {
+          void* state = vtable->create();
+          return state ? new VTablePlugin(vtable, state) : nullptr;
+        },
+        .destroyFunc = [](IPlugin* plugin) -> void { delete plugin; },
+      }
+    );
+
+    return DRAC_SUCCESS;
   }
 
   auto DracLoadPlugin(const char* pluginId) -> DracPlugin* {
@@ -683,7 +3869,7 @@ extern "C" {
     if (!opt.has_value())
       return nullptr;
 
-    return new DracPlugin { *opt, std::move(name), false };
+    return TrackManagedPlugin(new DracPlugin { *opt, std::move(name), false });
   #endif
   }
 
@@ -711,26 +3897,136 @@ extern "C" {
     if (!opt.has_value())
       return nullptr;
 
-    return new DracPlugin { *opt, std::move(stem), false };
+    return TrackManagedPlugin(new DracPlugin { *opt, std::move(stem), false });
   }
 
-  auto DracUnloadPlugin(DracPlugin* plugin) -> void {
-    if (!plugin)
-      return;
-
+  // Destroys the handle, and its instance if it owns one.
+  static auto ReleasePlugin(DracPlugin* plugin) -> void {
     if (plugin->ownsInstance && plugin->inner) {
       IPlugin* basePlugin = dynamic_cast<IPlugin*>(plugin->inner);
       if (basePlugin) {
         DestroyStaticPlugin(plugin->name, basePlugin);
       }
     }
+
+    if (!plugin->ownsInstance) {
+      LockGuard lock(s_managedPluginsMutex);
+      std::erase(s_managedPlugins, plugin);
+
+      // The manager keeps the instance, so it stops pushing once no other handle shares these subscriptions.
+      const bool shared = std::ranges::any_of(s_managedPlugins, [plugin](const DracPlugin* other) -> bool {
+        return other->updates == plugin->updates;
+      });
+
+      if (plugin->inner && plugin->updates && !shared)
+        plugin->inner->setUpdateCallback({});
+    }
+
     delete plugin;
   }
 
+  auto DracUnloadPlugin(DracPlugin* plugin) -> void {
+    if (!plugin)
+      return;
+
+    // An abandoned collection still uses the instance, so the handle is released once it returns.
+    // Until then a managed handle stays tracked, which keeps reloads from replacing the instance.
+    if (!FinishCollecting(plugin)) {
+      std::thread([plugin, pending = plugin->pendingCollect]() -> void {
+        pending.wait();
+        ReleasePlugin(plugin);
+      }).detach();
+      return;
+    }
+
+    ReleasePlugin(plugin);
+  }
+
+  auto DracPluginReload(DracPlugin* plugin, DracCacheManager* cache) -> DracErrorCode {
+    if (!plugin || !cache)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    if (!FinishCollecting(plugin))
+      return StillCollecting();
+
+    if (plugin->ownsInstance) {
+      IPlugin* basePlugin = CreateStaticPlugin(plugin->name);
+      auto*    infoPlugin = dynamic_cast<IInfoProviderPlugin*>(basePlugin);
+
+      if (!infoPlugin) {
+        DestroyStaticPlugin(plugin->name, basePlugin);
+        return DRAC_ERROR_INTERNAL_ERROR;
+      }
+
+      DestroyStaticPlugin(plugin->name, plugin->inner);
+      plugin->inner  = infoPlugin;
+      plugin->config = None;
+      AttachUpdates(plugin);
+      return DRAC_SUCCESS;
+    }
+
+  #if DRAC_PRECOMPILED_CONFIG
+    return DRAC_ERROR_NOT_SUPPORTED;
+  #else
+    {
+      // Every handle to this plugin shares the instance the reload destroys
+      LockGuard lock(s_managedPluginsMutex);
+
+      for (DracPlugin* other : s_managedPlugins)
+        if (other->name == plugin->name && !FinishCollecting(other))
+          return StillCollecting();
+    }
+
+    Result<Unit> result = GetPluginManager().reloadPlugin(plugin->name, cache->inner);
+
+    // The old instance is gone even if loading the new one failed
+    RepointManagedPlugins(Span<const String>(&plugin->name, 1));
+
+    if (!result.has_value())
+      return TO_C_ERROR(result.error());
+
+    return plugin->inner ? DRAC_SUCCESS : DRAC_ERROR_NOT_FOUND;
+  #endif
+  }
+
+  auto DracReloadChangedPlugins(DracCacheManager* cache, DracStringList* out_list) -> DracErrorCode {
+    if (!cache || !out_list)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_list = { .items = nullptr, .count = 0 };
+
+  #if !DRAC_PRECOMPILED_CONFIG
+    {
+      LockGuard lock(s_managedPluginsMutex);
+
+      for (DracPlugin* plugin : s_managedPlugins)
+        if (!FinishCollecting(plugin))
+          return StillCollecting();
+    }
+
+    Vec<String> reloaded = GetPluginManager().reloadChangedPlugins(cache->inner);
+    RepointManagedPlugins(reloaded);
+
+    out_list->count = reloaded.size();
+    out_list->items = new char*[reloaded.size()];
+
+    Span<char*> outItems(out_list->items, out_list->count);
+    usize       idx = 0;
+
+    for (char*& dst : outItems)
+      dst = DupString(reloaded[idx++]);
+  #endif
+
+    return DRAC_SUCCESS;
+  }
+
   auto DracPluginInitialize(DracPlugin* plugin, DracCacheManager* cache) -> DracErrorCode {
     if (!plugin || !plugin->inner || !cache)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
+    if (!FinishCollecting(plugin))
+      return StillCollecting();
+
     PluginContext ctx;
     PluginCache   pluginCache(std::filesystem::temp_directory_path() / "draconis_plugins");
     Result<Unit>  result = plugin->inner->initialize(ctx, pluginCache);
@@ -748,23 +4044,38 @@ extern "C" {
     if (!tomlConfig)
       return DRAC_SUCCESS;
 
+    if (!FinishCollecting(plugin))
+      return StillCollecting();
+
+    // A sandboxed plugin's helper has an instance of its own, which needs the configuration too
     Result<Unit> result = plugin->inner->setConfig(StringView(tomlConfig));
 
-    if (result.has_value())
-      return DRAC_SUCCESS;
+    if (result.has_value() && plugin->sandbox)
+      result = plugin->sandbox->setConfig(tomlConfig);
 
-    return TO_C_ERROR(result.error());
+    if (!result.has_value())
+      return TO_C_ERROR(result.error());
+
+    plugin->config = tomlConfig;
+    return DRAC_SUCCESS;
+  }
+
+  auto DracPluginGetConfigSchema(DracPlugin* plugin) -> char* {
+    if (!plugin || !plugin->inner || IsCollecting(plugin))
+      return nullptr;
+
+    return DupOptionalString(plugin->inner->getConfigSchema());
   }
 
   auto DracPluginIsEnabled(DracPlugin* plugin) -> bool {
-    if (!plugin || !plugin->inner)
+    if (!plugin || !plugin->inner || IsCollecting(plugin))
       return false;
 
     return plugin->inner->isEnabled();
   }
 
   auto DracPluginIsReady(DracPlugin* plugin) -> bool {
-    if (!plugin || !plugin->inner)
+    if (!plugin || !plugin->inner || IsCollecting(plugin))
       return false;
 
     return plugin->inner->isReady();
@@ -774,8 +4085,10 @@ extern "C" {
     if (!plugin || !plugin->inner || !cache)
       return DRAC_ERROR_INVALID_ARGUMENT;
 
-    PluginCache  pluginCache(std::filesystem::temp_directory_path() / "draconis_plugins");
-    Result<Unit> result = plugin->inner->collectData(pluginCache);
+    if (!FinishCollecting(plugin))
+      return StillCollecting();
+
+    Result<Unit> result = Collect(plugin);
 
     if (result.has_value())
       return DRAC_SUCCESS;
@@ -783,31 +4096,242 @@ extern "C" {
     return TO_C_ERROR(result.error());
   }
 
-  auto DracPluginGetFields(DracPlugin* plugin) -> DracPluginFieldList {
-    DracPluginFieldList result = { nullptr, 0 };
+  auto DracPluginCollectDataWithTimeout(DracPlugin* plugin, DracCacheManager* cache, uint32_t timeout_ms, DracCancelToken* token) -> DracErrorCode {
+    using draconis::utils::error::DracError;
+    using enum draconis::utils::error::DracErrorCode;
+
+    if (!plugin || !plugin->inner || !cache)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    if (token && token->cancelled.load())
+      return TO_C_ERROR(DracError(Cancelled, "Plugin collection was cancelled"));
+
+    // The helper process is killed on timeout or cancellation, so a sandboxed collection is never
+    // abandoned and runs on this thread
+    if (plugin->sandbox) {
+      Result<Unit> result = Collect(plugin, std::chrono::milliseconds(timeout_ms), token);
+
+      if (result.has_value())
+        return DRAC_SUCCESS;
+
+      return TO_C_ERROR(result.error());
+    }
+
+    // Rejoin a collection an earlier call gave up on rather than running two at once
+    if (!plugin->pendingCollect.valid()) {
+      std::packaged_task<Result<Unit>()> task([plugin]() -> Result<Unit> {
+        return Collect(plugin);
+      });
+
+      plugin->pendingCollect = task.get_future().share();
+      std::thread(std::move(task)).detach();
+    }
+
+    const auto deadline = std::chrono::steady_clock::now() + std::chrono::milliseconds(timeout_ms);
+
+    // Wake up periodically so a cancellation is noticed promptly
+    while (plugin->pendingCollect.wait_for(std::chrono::milliseconds(10)) != std::future_status::ready) {
+      if (token && token->cancelled.load())
+        return TO_C_ERROR(DracError(Cancelled, "Plugin collection was cancelled"));
+
+      if (timeout_ms != 0 && std::chrono::steady_clock::now() >= deadline)
+        return TO_C_ERROR(DracError(Timeout, "Plugin collection timed out"));
+    }
+
+    Result<Unit> result    = plugin->pendingCollect.get();
+    plugin->pendingCollect = {};
+
+    if (result.has_value())
+      return DRAC_SUCCESS;
+
+    return TO_C_ERROR(result.error());
+  }
+
+  auto DracPluginSetSandbox(DracPlugin* plugin, const DracPluginSandbox* sandbox) -> DracErrorCode {
+    using draconis::utils::error::DracError;
+    using enum draconis::utils::error::DracErrorCode;
 
     if (!plugin || !plugin->inner)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    if (!FinishCollecting(plugin))
+      return StillCollecting();
+
+    plugin->sandboxFields = None;
+    plugin->sandboxError  = None;
+
+    if (!sandbox) {
+      plugin->sandbox = nullptr;
+      return DRAC_SUCCESS;
+    }
+
+    // The helper loads the plugin from its library, which static and registered plugins don't have
+    const Option<std::filesystem::path> library = plugin->ownsInstance ? None : GetPluginManager().getPluginPath(plugin->name);
+
+    if (!library)
+      return TO_C_ERROR(DracError(NotSupported, "Only plugins loaded from a library can be sandboxed"));
+
+    const SandboxLimits limits {
+      .maxMemoryBytes = sandbox->maxMemoryBytes,
+      .maxCpuSeconds  = sandbox->maxCpuSeconds,
+      .timeout        = std::chrono::milliseconds(sandbox->timeoutMs),
+    };
+
+    plugin->sandbox = std::make_unique<SandboxedPlugin>(*library, limits, plugin->config);
+    return DRAC_SUCCESS;
+  }
+
+  auto DracCreateCancelToken(void) -> DracCancelToken* {
+    return new DracCancelToken { .cancelled = false };
+  }
+
+  auto DracCancelTokenCancel(DracCancelToken* token) -> void {
+    if (token)
+      token->cancelled.store(true);
+  }
+
+  auto DracCancelTokenIsCancelled(DracCancelToken* token) -> bool {
+    return token && token->cancelled.load();
+  }
+
+  auto DracFreeCancelToken(DracCancelToken* token) -> void {
+    delete token;
+  }
+
+  auto DracPluginGetFields(DracPlugin* plugin) -> DracPluginFieldList {
+    if (!plugin || !plugin->inner || IsCollecting(plugin))
+      return { nullptr, 0 };
+
+    if (plugin->sandbox)
+      return plugin->sandboxFields ? ToCPluginFieldList(*plugin->sandboxFields) : DracPluginFieldList { nullptr, 0 };
+
+    return ToCPluginFieldList(plugin->inner->getFields());
+  }
+
+  auto DracPluginSubscribe(DracPlugin* plugin, DracPluginUpdateCallback callback, void* user_data, DracPluginSubscription** out_subscription) -> DracErrorCode {
+    if (!plugin || !plugin->inner || !callback || !out_subscription)
+      return DRAC_ERROR_INVALID_ARGUMENT;
+
+    *out_subscription = nullptr;
+
+    if (!plugin->updates && !plugin->ownsInstance) {
+      // The instance has a single update callback, so handles to the same managed plugin share their subscriptions.
+      LockGuard lock(s_managedPluginsMutex);
+
+      for (const DracPlugin* other : s_managedPlugins)
+        if (other->inner == plugin->inner && other->updates) {
+          plugin->updates = other->updates;
+          break;
+        }
+    }
+
+    if (!plugin->updates) {
+      if (!FinishCollecting(plugin))
+        return StillCollecting();
+
+      plugin->updates = std::make_shared<PluginUpdates>();
+      AttachUpdates(plugin);
+    }
+
+    auto* subscription = new DracPluginSubscription {
+      .updates   = plugin->updates,
+      .callback  = callback,
+      .userData  = user_data,
+      .mutex     = {},
+      .condition = {},
+      .pending   = None,
+      .stopping  = false,
+      .thread    = {},
+    };
+
+    subscription->thread = std::thread([subscription]() -> void {
+      std::unique_lock lock(subscription->mutex);
+
+      while (true) {
+        subscription->condition.wait(lock, [subscription] { return subscription->stopping || subscription->pending.has_value(); });
+
+        if (subscription->stopping)
+          return;
+
+        const PluginFields fields = std::move(*subscription->pending);
+        subscription->pending     = None;
+        lock.unlock();
+
+        DracPluginFieldList list = ToCPluginFieldList(fields);
+        subscription->callback(&list, subscription->userData);
+        DracFreePluginFieldList(&list);
+
+        lock.lock();
+      }
+    });
+
+    {
+      LockGuard lock(plugin->updates->mutex);
+      plugin->updates->subscriptions.push_back(subscription);
+    }
+
+    *out_subscription = subscription;
+    return DRAC_SUCCESS;
+  }
+
+  auto DracPluginUnsubscribe(DracPluginSubscription* subscription) -> void {
+    if (!subscription)
+      return;
+
+    {
+      LockGuard lock(subscription->updates->mutex);
+      std::erase(subscription->updates->subscriptions, subscription);
+    }
+
+    {
+      LockGuard lock(subscription->mutex);
+      subscription->stopping = true;
+    }
+
+    subscription->condition.notify_one();
+
+    if (subscription->thread.joinable())
+      subscription->thread.join();
+
+    delete subscription;
+  }
+
+  auto DracPluginGetFieldSchema(DracPlugin* plugin) -> DracPluginFieldSchemaList {
+    DracPluginFieldSchemaList result = { .items = nullptr, .count = 0 };
+
+    if (!plugin || !plugin->inner || IsCollecting(plugin))
       return result;
 
-    PluginFields fields = plugin->inner->getFields();
-    result.count        = fields.size();
-    result.items        = new DracPluginField[fields.size()];
+    Vec<PluginFieldSchema> schema = plugin->inner->getFieldSchema();
 
-    size_t idx = 0;
-    for (const auto& [key, value] : fields) {
-      result.items[idx].key   = DupString(key);
-      result.items[idx].value = ToCPluginFieldValue(value);
-      ++idx;
+    if (schema.empty())
+      return result;
+
+    result.count = schema.size();
+    result.items = new DracPluginFieldSchema[schema.size()];
+
+    Span<DracPluginFieldSchema> outItems(result.items, result.count);
+    usize                       idx = 0;
+
+    for (DracPluginFieldSchema& dst : outItems) {
+      const PluginFieldSchema& src = schema[idx++];
+
+      dst = {
+        .name        = DupString(src.name),
+        .type        = static_cast<DracPluginFieldValueType>(src.type),
+        .unit        = DupOptionalString(src.unit),
+        .description = DupString(src.description),
+      };
     }
 
     return result;
   }
 
   auto DracPluginGetLastError(DracPlugin* plugin) -> char* {
-    if (!plugin || !plugin->inner)
+    if (!plugin || !plugin->inner || IsCollecting(plugin))
       return nullptr;
 
-    Option<String> err = plugin->inner->getLastError();
+    Option<String> err = plugin->sandbox ? plugin->sandboxError : plugin->inner->getLastError();
     if (!err.has_value())
       return nullptr;
 
@@ -828,6 +4352,21 @@ extern "C" {
     list->count = 0;
   }
 
+  auto DracFreePluginFieldSchemaList(DracPluginFieldSchemaList* list) -> void {
+    if (!list || !list->items)
+      return;
+
+    for (size_t i = 0; i < list->count; ++i) {
+      delete[] list->items[i].name;
+      delete[] list->items[i].unit;
+      delete[] list->items[i].description;
+    }
+
+    delete[] list->items;
+    list->items = nullptr;
+    list->count = 0;
+  }
+
   auto DracFreePluginInfoList(DracPluginInfoList* list) -> void {
     if (!list || !list->items)
       return;
@@ -837,6 +4376,8 @@ extern "C" {
       delete[] list->items[i].version;
       delete[] list->items[i].author;
       delete[] list->items[i].description;
+      delete[] list->items[i].configSchema;
+      DracFreeStringList(&list->items[i].capabilities);
     }
 
     delete[] list->items;
@@ -849,12 +4390,17 @@ extern "C" {
     int dummy;
   };
 
+  struct DracCancelToken {
+    int dummy;
+  };
+
   auto DracInitStaticPlugins(void) -> size_t {
     return 0;
   }
   auto DracInitPluginManager(void) -> void {}
   auto DracShutdownPluginManager(void) -> void {}
   auto DracAddPluginSearchPath(const char* /*unused*/) -> void {}
+  auto DracAddDefaultPluginSearchPaths(void) -> void {}
 
   auto DracDiscoverPlugins(void) -> DracPluginInfoList {
     return { nullptr, 0 };
@@ -867,6 +4413,10 @@ extern "C" {
     }
   }
 
+  auto DracRegisterPlugin(const DracPluginVTable* /*unused*/) -> DracErrorCode {
+    return DRAC_ERROR_NOT_SUPPORTED;
+  }
+
   auto DracLoadPlugin(const char* /*unused*/) -> DracPlugin* {
     return nullptr;
   }
@@ -877,6 +4427,17 @@ extern "C" {
 
   auto DracUnloadPlugin(DracPlugin* /*unused*/) -> void {}
 
+  auto DracPluginReload(DracPlugin* /*unused*/, DracCacheManager* /*unused*/) -> DracErrorCode {
+    return DRAC_ERROR_NOT_SUPPORTED;
+  }
+
+  auto DracReloadChangedPlugins(DracCacheManager* /*unused*/, DracStringList* out_list) -> DracErrorCode {
+    if (out_list)
+      *out_list = { .items = nullptr, .count = 0 };
+
+    return DRAC_ERROR_NOT_SUPPORTED;
+  }
+
   auto DracPluginInitialize(DracPlugin* /*unused*/, DracCacheManager* /*unused*/) -> DracErrorCode {
     return DRAC_ERROR_NOT_SUPPORTED;
   }
@@ -885,6 +4446,10 @@ extern "C" {
     return DRAC_ERROR_NOT_SUPPORTED;
   }
 
+  auto DracPluginGetConfigSchema(DracPlugin* /*unused*/) -> char* {
+    return nullptr;
+  }
+
   auto DracPluginIsEnabled(DracPlugin* /*unused*/) -> bool {
     return false;
   }
@@ -897,10 +4462,43 @@ extern "C" {
     return DRAC_ERROR_NOT_SUPPORTED;
   }
 
+  auto DracPluginCollectDataWithTimeout(DracPlugin* /*unused*/, DracCacheManager* /*unused*/, uint32_t /*unused*/, DracCancelToken* /*unused*/) -> DracErrorCode {
+    return DRAC_ERROR_NOT_SUPPORTED;
+  }
+
+  auto DracPluginSetSandbox(DracPlugin* /*unused*/, const DracPluginSandbox* /*unused*/) -> DracErrorCode {
+    return DRAC_ERROR_NOT_SUPPORTED;
+  }
+
+  auto DracCreateCancelToken(void) -> DracCancelToken* {
+    return nullptr;
+  }
+
+  auto DracCancelTokenCancel(DracCancelToken* /*unused*/) -> void {}
+
+  auto DracCancelTokenIsCancelled(DracCancelToken* /*unused*/) -> bool {
+    return false;
+  }
+
+  auto DracFreeCancelToken(DracCancelToken* /*unused*/) -> void {}
+
   auto DracPluginGetFields(DracPlugin* /*unused*/) -> DracPluginFieldList {
     return { nullptr, 0 };
   }
 
+  auto DracPluginGetFieldSchema(DracPlugin* /*unused*/) -> DracPluginFieldSchemaList {
+    return { nullptr, 0 };
+  }
+
+  auto DracPluginSubscribe(DracPlugin* /*unused*/, DracPluginUpdateCallback /*unused*/, void* /*unused*/, DracPluginSubscription** out_subscription) -> DracErrorCode {
+    if (out_subscription)
+      *out_subscription = nullptr;
+
+    return DRAC_ERROR_NOT_SUPPORTED;
+  }
+
+  auto DracPluginUnsubscribe(DracPluginSubscription* /*unused*/) -> void {}
+
   auto DracPluginGetLastError(DracPlugin* /*unused*/) -> char* {
     return nullptr;
   }
@@ -911,5 +4509,12 @@ extern "C" {
       list->count = 0;
     }
   }
+
+  auto DracFreePluginFieldSchemaList(DracPluginFieldSchemaList* list) -> void {
+    if (list) {
+      list->items = nullptr;
+      list->count = 0;
+    }
+  }
 #endif
 }
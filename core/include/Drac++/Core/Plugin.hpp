@@ -20,6 +20,7 @@
 
 #include <chrono>
 #include <concepts>
+#include <cstddef>
 #include <filesystem>
 #include <format>
 #include <fstream>
@@ -213,6 +214,11 @@ namespace draconis::core::plugin {
   using PluginFieldArray  = utils::types::Vec<PluginFieldValue>;
   using PluginFieldObject = utils::types::Map<utils::types::String, PluginFieldValue>;
 
+  /**
+   * @brief A point in time reported by a plugin, e.g. when the current track started.
+   */
+  using PluginTimestamp = std::chrono::sys_time<std::chrono::milliseconds>;
+
   using PluginFieldValueBase = utils::types::Variant<
     bool,
     utils::types::i64,
@@ -220,7 +226,9 @@ namespace draconis::core::plugin {
     utils::types::f64,
     utils::types::String,
     PluginFieldArray,
-    PluginFieldObject>;
+    PluginFieldObject,
+    PluginTimestamp,
+    std::nullptr_t>;
 
   struct PluginFieldValue : PluginFieldValueBase {
     using PluginFieldValueBase::PluginFieldValueBase;
@@ -266,6 +274,24 @@ namespace draconis::core::plugin {
           std::format_to(std::back_inserter(result), "{}", inner);
         else if constexpr (std::same_as<T, String>)
           result += inner;
+        else if constexpr (std::same_as<T, PluginTimestamp>) {
+          // Formatted by hand, since chrono's formatter support varies between standard libraries.
+          const auto                        days = std::chrono::floor<std::chrono::days>(inner);
+          const std::chrono::year_month_day date { days };
+          const std::chrono::hh_mm_ss       time { std::chrono::floor<std::chrono::seconds>(inner - days) };
+
+          std::format_to(
+            std::back_inserter(result),
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            static_cast<i32>(date.year()),
+            static_cast<u32>(date.month()),
+            static_cast<u32>(date.day()),
+            time.hours().count(),
+            time.minutes().count(),
+            time.seconds().count()
+          );
+        } else if constexpr (std::same_as<T, std::nullptr_t>)
+          return;
         else if constexpr (std::same_as<T, PluginFieldArray>) {
           for (usize i = 0; i < inner.size(); ++i) {
             if (i > 0)
@@ -295,6 +321,36 @@ namespace draconis::core::plugin {
     return result;
   }
 
+  /**
+   * @brief The kind of value a plugin field holds, in the order of PluginFieldValue's alternatives
+   */
+  enum class PluginFieldType : utils::types::u8 {
+    Bool,
+    I64,
+    U64,
+    F64,
+    String,
+    Array,
+    Object,
+    Timestamp,
+    Null,
+  };
+
+  /**
+   * @brief Describes a field an info provider emits, so hosts can lay out its data before the first collection
+   */
+  struct PluginFieldSchema {
+    utils::types::String                       name;
+    PluginFieldType                            type;
+    utils::types::Option<utils::types::String> unit; ///< e.g. "°C" or "bytes"; None if the value has no unit
+    utils::types::String                       description;
+  };
+
+  /**
+   * @brief Receives the fields an info provider pushes when its data changes on its own
+   */
+  using PluginUpdateCallback = utils::types::Fn<void(const PluginFields&)>;
+
   /**
    * @struct PluginContext
    * @brief Context passed to plugins during initialization
@@ -336,6 +392,14 @@ namespace draconis::core::plugin {
       return {};
     }
 
+    /**
+     * @brief Describe the TOML keys accepted by setConfig()
+     * @return Schema text, or None if the plugin doesn't provide one
+     */
+    [[nodiscard]] virtual auto getConfigSchema() const -> utils::types::Option<utils::types::String> {
+      return utils::types::None;
+    }
+
     virtual auto shutdown() -> utils::types::Unit = 0;
 
     [[nodiscard]] virtual auto isReady() const -> bool = 0;
@@ -381,6 +445,25 @@ namespace draconis::core::plugin {
      */
     [[nodiscard]] virtual auto getFields() const -> PluginFields = 0;
 
+    /**
+     * @brief Describe the fields getFields() returns
+     * @return One entry per field, available before the first collection; empty if the plugin doesn't describe them
+     */
+    [[nodiscard]] virtual auto getFieldSchema() const -> utils::types::Vec<PluginFieldSchema> {
+      return {};
+    }
+
+    /**
+     * @brief Register a callback for pushing fields as they change, rather than waiting to be polled
+     * @param callback Invoked with the plugin's current fields, from any thread; empty to stop pushing
+     * @details Event-driven plugins (e.g. now playing) override this and call the callback whenever their
+     *          data changes. It replaces any previous callback, which must not be called once this returns.
+     *          The default ignores it, for plugins that are only polled.
+     */
+    virtual auto setUpdateCallback(PluginUpdateCallback callback) -> utils::types::Unit {
+      (void)callback;
+    }
+
     /**
      * @brief Get a single-line display string for UI
      * @return Formatted string for display (e.g., "72°F, Clear sky")
@@ -467,7 +550,13 @@ namespace glz {
       std::visit(
         [&](const auto& inner) -> void {
           using Value = std::decay_t<decltype(inner)>;
-          to<JSON, Value>::template op<Opts>(inner, ctx, buffer, index);
+
+          // Written as milliseconds since the Unix epoch, matching the C API.
+          if constexpr (std::same_as<Value, draconis::core::plugin::PluginTimestamp>) {
+            const draconis::utils::types::i64 millis = inner.time_since_epoch().count();
+            to<JSON, draconis::utils::types::i64>::template op<Opts>(millis, ctx, buffer, index);
+          } else
+            to<JSON, Value>::template op<Opts>(inner, ctx, buffer, index);
         },
         static_cast<const draconis::core::plugin::PluginFieldValueBase&>(value)
       );
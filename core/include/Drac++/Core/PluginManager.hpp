@@ -16,6 +16,8 @@
 
 #if DRAC_ENABLE_PLUGINS
 
+  #include <atomic>     // std::atomic
+  #include <chrono>     // std::chrono::milliseconds, std::chrono::steady_clock
   #include <filesystem> // std::filesystem::path
   #include <shared_mutex>
 
@@ -32,11 +34,15 @@ namespace draconis::core::plugin {
   namespace fs = std::filesystem;
 
   using utils::cache::CacheManager;
+  using utils::types::i32;
   using utils::types::Map;
+  using utils::types::None;
   using utils::types::Option;
   using utils::types::Result;
   using utils::types::Span;
   using utils::types::String;
+  using utils::types::u32;
+  using utils::types::u64;
   using utils::types::UniquePointer;
   using utils::types::Unit;
   using utils::types::Vec;
@@ -53,6 +59,7 @@ namespace draconis::core::plugin {
     DynamicLibraryHandle   handle;
     fs::path               path;
     PluginMetadata         metadata;
+    fs::file_time_type     modifiedTime; ///< Library write time when loaded; unset for static plugins
     bool                   isInitialized = false;
     bool                   isReady       = false;
     bool                   isLoaded      = false;
@@ -77,6 +84,85 @@ namespace draconis::core::plugin {
     auto operator=(LoadedPlugin&&) -> LoadedPlugin&      = default;
   };
 
+  /**
+   * @brief What a plugin declares about itself, for listing plugins without initializing them
+   */
+  struct PluginDescription {
+    PluginMetadata metadata;
+    Vec<String>    capabilities; ///< Provider ID of an info provider, or the formats an output plugin writes
+    Option<String> configSchema; ///< TOML keys accepted by setConfig(), if the plugin describes them
+    bool           isStatic = false;
+  };
+
+  /**
+   * @brief Limits applied to a sandboxed plugin; zero leaves that resource unlimited
+   */
+  struct SandboxLimits {
+    u64                       maxMemoryBytes = 0; ///< Memory the helper process may map (Unix) or commit (Windows)
+    u32                       maxCpuSeconds  = 0; ///< CPU time each collection may use
+    std::chrono::milliseconds timeout {};         ///< Wall-clock time a request may take before the helper is killed
+  };
+
+  /**
+   * @brief A dynamic plugin run in a helper process, so a crash, a hang or a runaway allocation
+   *        can't take the host down
+   * @details The helper (draconis-plugin-host, or the executable DRAC_PLUGIN_HOST names) loads the
+   *          plugin's library and initializes its own instance, which keeps its state from one
+   *          collection to the next. It is started on first use and again after it dies, when the
+   *          last configuration setConfig() accepted is applied again. Memory is capped with
+   *          RLIMIT_AS on Unix and a job object on Windows; CPU time with RLIMIT_CPU and the job's
+   *          user-mode time limit respectively.
+   */
+  class SandboxedPlugin {
+    struct Helper;
+
+    fs::path              m_library;
+    SandboxLimits         m_limits;
+    Option<String>        m_config;
+    UniquePointer<Helper> m_helper;
+
+    auto start(std::chrono::steady_clock::time_point deadline, const std::atomic<bool>* cancelled) -> Result<Unit>;
+    auto receive(std::chrono::steady_clock::time_point deadline, const std::atomic<bool>* cancelled) -> Result<String>;
+    auto exchange(const String& request, std::chrono::steady_clock::time_point deadline, const std::atomic<bool>* cancelled) -> Result<String>;
+
+   public:
+    SandboxedPlugin(fs::path library, SandboxLimits limits, Option<String> config = None);
+    ~SandboxedPlugin(); // Kills the helper
+
+    SandboxedPlugin(const SandboxedPlugin&)                    = delete;
+    SandboxedPlugin(SandboxedPlugin&&)                         = delete;
+    auto operator=(const SandboxedPlugin&) -> SandboxedPlugin& = delete;
+    auto operator=(SandboxedPlugin&&) -> SandboxedPlugin&      = delete;
+
+    auto getLibrary() const -> const fs::path& {
+      return m_library;
+    }
+
+    auto getLimits() const -> const SandboxLimits& {
+      return m_limits;
+    }
+
+    /**
+     * @brief Pass configuration to the helper's instance, starting the helper if it isn't running
+     */
+    auto setConfig(const String& tomlConfig) -> Result<Unit>;
+
+    /**
+     * @brief Run collectData() in the helper and return the fields it collected
+     * @details A crash is reported as InternalError, exceeding the CPU limit as ResourceExhausted
+     *          and a failed allocation as OutOfMemory. Running out of time or being cancelled kills
+     *          the helper and fails with Timeout or Cancelled.
+     * @param timeout Caps the sandbox's own timeout if non-zero
+     * @param cancelled Checked every few milliseconds while waiting on the helper, if given
+     */
+    auto collect(std::chrono::milliseconds timeout = {}, const std::atomic<bool>* cancelled = nullptr) -> Result<PluginFields>;
+  };
+
+  /**
+   * @brief Entry point of the draconis-plugin-host helper that SandboxedPlugin starts
+   */
+  auto RunSandboxHost(i32 argc, char* argv[]) -> i32;
+
   /**
    * @brief Get the plugin context with standard paths
    * @return PluginContext with config, cache, and data directories
@@ -110,6 +196,11 @@ namespace draconis::core::plugin {
 
     static auto initializePluginInstance(LoadedPlugin& loadedPlugin, CacheManager& cache) -> Result<Unit>;
 
+    // Loads and initializes a dynamic plugin outside the manager, for the sandbox helper
+    static auto loadSandboxedInstance(const fs::path& library) -> Result<LoadedPlugin>;
+
+    friend auto RunSandboxHost(i32 argc, char* argv[]) -> i32;
+
    public:
     PluginManager(const PluginManager&)                    = delete;
     PluginManager(PluginManager&&)                         = delete;
@@ -129,6 +220,14 @@ namespace draconis::core::plugin {
 
     // Plugin discovery and loading
     auto addSearchPath(const fs::path& path) -> Unit;
+
+    /**
+     * @brief Add the directories plugins are installed to: those in DRAC_PLUGIN_PATH, the XDG data
+     *        directories and the usual library directories on Unix, or %LOCALAPPDATA% and %APPDATA%
+     *        on Windows
+     * @details initialize() already adds them; calling this again re-reads the environment.
+     */
+    auto addDefaultSearchPaths() -> Unit;
     auto getSearchPaths() const -> Span<const fs::path>;
     auto scanForPlugins() -> Result<Unit>;
     auto loadPlugin(
@@ -139,6 +238,19 @@ namespace draconis::core::plugin {
     auto loadPluginsOfType(PluginType type, CacheManager& cache) -> Unit;
     auto unloadPlugin(const String& pluginName) -> Result<Unit>;
 
+    /**
+     * @brief Unload a plugin and load it again, re-reading its library from disk if it is dynamic
+     * @details Pointers to the old instance are invalidated; configuration passed through
+     *          setConfig() is not carried over.
+     */
+    auto reloadPlugin(const String& pluginName, CacheManager& cache) -> Result<Unit>;
+
+    /**
+     * @brief Reload every dynamic plugin whose library was modified since it was loaded
+     * @return Names of the plugins that were reloaded successfully
+     */
+    auto reloadChangedPlugins(CacheManager& cache) -> Vec<String>;
+
     // Plugin access (read-only, thread-safe)
     auto getPlugin(const String& pluginName) const -> Option<IPlugin*>;
     auto getInfoProviderPlugins() const -> Span<IInfoProviderPlugin* const>;
@@ -154,6 +266,20 @@ namespace draconis::core::plugin {
     auto listLoadedPlugins() const -> Vec<PluginMetadata>;
     auto listDiscoveredPlugins() const -> Vec<String>; // Lists all .so/.dll files found
     auto isPluginLoaded(const String& pluginName) const -> bool;
+
+    /**
+     * @brief Library a loaded plugin was read from
+     * @return None for static plugins and plugins that aren't loaded
+     */
+    auto getPluginPath(const String& pluginName) const -> Option<fs::path>;
+
+    /**
+     * @brief Rescan the search paths and describe every static and discovered plugin
+     * @details Plugins that aren't loaded are instantiated briefly to read their metadata,
+     *          without being initialized.
+     * @return Descriptions sorted by name
+     */
+    auto describePlugins() -> Vec<PluginDescription>;
   };
 
   inline auto GetPluginManager() -> PluginManager& {
@@ -183,6 +309,8 @@ namespace draconis::core::plugin {
 
     auto addSearchPath(const std::filesystem::path&) {}
 
+    auto addDefaultSearchPaths() {}
+
     auto getSearchPaths() const -> Vec<fs::path> {
       return {};
     }
@@ -28,11 +28,11 @@
 namespace draconis::core::plugin {
   /**
    * @struct StaticPluginEntry
-   * @brief Entry for a statically compiled plugin
+   * @brief Entry for a statically compiled plugin, or one registered at runtime through the C API
    */
   struct StaticPluginEntry {
-    IPlugin* (*createFunc)();
-    void (*destroyFunc)(IPlugin*);
+    utils::types::Fn<IPlugin*()>     createFunc;
+    utils::types::Fn<void(IPlugin*)> destroyFunc;
   };
 
   /**
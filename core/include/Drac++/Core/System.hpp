@@ -55,6 +55,42 @@ namespace draconis::core::system {
    */
   auto GetMemInfo(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::ResourceUsage>;
 
+  /**
+   * @brief Fetches a detailed breakdown of physical memory.
+   * @param cache The CacheManager instance (unused, memory is always read live).
+   * @return A MemoryDetails struct with free, available, cached, buffers, shared, and kernel bytes.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: Parses `/proc/meminfo` (the same source `free` uses)
+   *  - macOS: `host_statistics64` with `HOST_VM_INFO64`, plus `sysctlbyname("hw.memsize")`
+   *  - Windows: `GlobalMemoryStatusEx` and `GetPerformanceInfo`
+   *
+   * @warning This function can fail if:
+   *  - Linux: Fails to open `/proc/meminfo` / `MemTotal` is missing
+   *  - macOS: `host_page_size`, `sysctlbyname`, or `host_statistics64` fail
+   *  - Windows: `GlobalMemoryStatusEx` or `GetPerformanceInfo` fail
+   */
+  auto GetMemoryDetails(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::MemoryDetails>;
+
+  /**
+   * @brief Fetches swap usage.
+   * @param cache The CacheManager instance (unused, swap is always read live).
+   * @return A ResourceUsage struct with used and total swap bytes.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `sysinfo`
+   *  - macOS: `sysctlbyname("vm.swapusage")`
+   *  - Windows: `GlobalMemoryStatusEx` (page file commit minus physical memory)
+   *
+   * A system with no swap configured reports a total of 0 rather than an error.
+   *
+   * @warning This function can fail if:
+   *  - Linux: `sysinfo` fails
+   *  - macOS: `sysctlbyname` returns -1
+   *  - Windows: `GlobalMemoryStatusEx` fails
+   */
+  auto GetSwapInfo(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::ResourceUsage>;
+
   /**
    * @brief Fetches the OS version.
    * @return The OS version (e.g., "Windows 11", "macOS 26.0 Tahoe", "Ubuntu 24.04.2 LTS", etc.).
@@ -165,6 +201,101 @@ namespace draconis::core::system {
    */
   auto GetWindowManager(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
 
+  /**
+   * @brief Fetches the display server the current process is attached to.
+   * @param cache The cache manager to use for caching the result.
+   * @return A DisplaySession with the server kind and, on Wayland, the compositor name.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: Always `Windows`
+   *  - macOS: Always `Quartz`
+   *  - Linux/BSD: `XDG_SESSION_TYPE`, falling back to `WAYLAND_DISPLAY` / `DISPLAY`, and `Tty` if neither is set
+   *
+   * Unlike GetWindowManager, a Wayland session is reported as such even when `DISPLAY` is also set for Xwayland.
+   * The compositor is left empty if it can't be detected, rather than failing.
+   */
+  auto GetDisplaySession(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::DisplaySession>;
+
+  /**
+   * @brief Fetches the widget, icon, and cursor theme and the system UI font.
+   * @param cache The cache manager to use for caching the result.
+   * @return A ThemeInfo struct; fields the platform doesn't expose are left empty.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux/BSD: KDE's `kdeglobals`/`kcminputrc`, then GTK's `settings.ini`, then `GTK_THEME`/`XCURSOR_THEME`
+   *  - macOS: Light/dark appearance from `AppleInterfaceStyle` (icons, cursor, and font aren't themeable)
+   *  - Windows: Light/dark app mode and cursor scheme from the registry, UI font from `SystemParametersInfoW`
+   *
+   * @warning This function can fail if:
+   *  - Linux/BSD: Neither `XDG_CONFIG_HOME` nor `HOME` is set / no config file sets any of the fields
+   */
+  auto GetThemeInfo(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::ThemeInfo>;
+
+  /**
+   * @brief Fetches the user's default text editor, web browser, and terminal emulator.
+   * @return The applications; ones that aren't configured are left empty. Never cached, since they can be changed at any time.
+   *
+   * @details The editor is taken from `VISUAL` or `EDITOR` on every platform. Otherwise:
+   *  - Linux/BSD: `BROWSER`, then the `x-scheme-handler/https` default in `mimeapps.list`; `TERMINAL`,
+   *    then `xdg-terminals.list`, then KDE's `TerminalApplication`. Desktop entries are resolved to their `Name`.
+   *  - macOS: The LaunchServices handlers for `https` URLs and for shell scripts (`public.unix-executable`)
+   *  - Windows: The `.txt` and `https` associations (`AssocQueryStringW`), and the default terminal
+   *    application from `HKCU\Console\%%Startup`
+   *
+   * @warning This function can fail if none of the three can be determined.
+   */
+  auto GetDefaultApplications() -> utils::types::Result<utils::types::DefaultApplications>;
+
+  /**
+   * @brief Enumerates audio output and input devices.
+   * @param cache The CacheManager instance (unused, devices are always read live).
+   * @return A vector of AudioDevice structs, outputs first, with the default device of each direction marked.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: PulseAudio introspection (also covers PipeWire through `pipewire-pulse`), requires `DRAC_USE_PULSEAUDIO`
+   *  - macOS: CoreAudio `kAudioHardwarePropertyDevices`
+   *  - Windows: WASAPI `IMMDeviceEnumerator` (active endpoints only)
+   *
+   * Monitor sources (loopbacks of outputs) are not reported as inputs.
+   *
+   * @warning This function can fail if:
+   *  - Linux: PulseAudio support is disabled / no PulseAudio or PipeWire server is running
+   *  - macOS: `AudioObjectGetPropertyData` fails
+   *  - Windows: COM initialization or `IMMDeviceEnumerator` creation fails
+   */
+  auto GetAudioDevices(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::AudioDevice>>;
+
+  /**
+   * @brief Fetches the volume and mute state of the default audio output device.
+   * @param cache The CacheManager instance (unused, volume is always read live).
+   * @return A VolumeInfo struct.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: Average channel volume of the PulseAudio default sink, requires `DRAC_USE_PULSEAUDIO`
+   *  - macOS: CoreAudio `kAudioDevicePropertyVolumeScalar` of the default output device
+   *  - Windows: `IAudioEndpointVolume` of the default render endpoint
+   *
+   * @warning This function can fail if:
+   *  - There is no default output device / the device has no volume control (e.g. some HDMI outputs on macOS)
+   *  - Linux: PulseAudio support is disabled / no PulseAudio or PipeWire server is running
+   */
+  auto GetVolume(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::VolumeInfo>;
+
+  /**
+   * @brief Sets the volume of the default audio output device.
+   * @param percent The new volume as a percentage, applied to all channels. Values above 100 are
+   * clamped to 100 except on Linux, where PulseAudio allows software amplification.
+   * @return An error if the volume couldn't be set (see GetVolume for failure cases).
+   */
+  auto SetVolume(utils::types::f64 percent) -> utils::types::Result<>;
+
+  /**
+   * @brief Mutes or unmutes the default audio output device.
+   * @param muted Whether the device should be muted.
+   * @return An error if the mute state couldn't be set (see GetVolume for failure cases).
+   */
+  auto SetMuted(bool muted) -> utils::types::Result<>;
+
   /**
    * @brief Fetches the shell.
    * @return The active shell (e.g., "zsh", "bash", "fish", etc.).
@@ -198,6 +329,39 @@ namespace draconis::core::system {
    */
   auto GetShell(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
 
+  /**
+   * @brief Fetches the terminal emulator the current process is running in.
+   * @param cache The cache manager to use for caching the result.
+   * @return The terminal name (e.g. "kitty", "Windows Terminal", "iTerm2").
+   *
+   * @details Variables exported by the terminal (`TERM_PROGRAM`, `KITTY_WINDOW_ID`, `WT_SESSION`, ...)
+   * are checked first. Otherwise the parent process tree is walked, skipping shells and wrappers such as
+   * `sudo`, and the first other ancestor is reported. Sessions started over SSH are reported as "SSH".
+   *
+   * @warning This function can fail if:
+   *  - Linux/macOS: No known variable is set and the process tree walk reaches init
+   *  - BSD: No known variable is set
+   *  - Windows: The process snapshot can't be taken
+   */
+  auto GetTerminal(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
+
+  /**
+   * @brief Fetches the font configured in the current terminal emulator.
+   * @param cache The cache manager to use for caching the result.
+   * @return The font as "Family Size" (e.g. "JetBrains Mono 11"), or just "Family" if no size is set.
+   *
+   * @details The font is read from the terminal's own configuration:
+   *  - kitty, Alacritty, foot, Konsole: their config files under `XDG_CONFIG_HOME`
+   *  - macOS: iTerm2 preferences
+   *  - Windows: Windows Terminal's `settings.json` defaults
+   *
+   * @warning This function can fail if:
+   *  - The terminal can't be detected (see GetTerminal)
+   *  - The terminal isn't one of the supported ones
+   *  - The config file doesn't exist or doesn't set a font (the terminal's built-in default is not reported)
+   */
+  auto GetTerminalFont(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
+
   /**
    * @brief Fetches the host.
    * @return The host (or hostname if the platform doesn't support the former).
@@ -239,6 +403,161 @@ namespace draconis::core::system {
    */
   auto GetHost(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
 
+  /**
+   * @brief Fetches the machine's network hostname.
+   * @return The hostname (e.g., "archlinux"). Never cached, since it can be changed at any time.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux/macOS/BSD: `gethostname`
+   *  - Windows: `GetComputerNameExW(ComputerNameDnsHostname)`
+   *  - Other: To be implemented
+   *
+   * Unlike GetHost, which reports the hardware model, this is the name the machine goes by on the network.
+   *
+   * @warning This function can fail if the hostname is empty.
+   */
+  auto GetHostname() -> utils::types::Result<utils::types::String>;
+
+  /**
+   * @brief Fetches the machine's fully qualified domain name.
+   * @return The FQDN (e.g., "build01.corp.example.com"). Never cached, since it can be changed at any time.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux/macOS/BSD: The canonical name `getaddrinfo` resolves the hostname to, like `hostname -f`.
+   *    This goes through `/etc/hosts` and DNS, so it may block until the resolver times out.
+   *  - Windows: `GetComputerNameExW(ComputerNameDnsFullyQualified)`, which is the bare hostname outside a domain
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - Linux/macOS/BSD: The hostname doesn't resolve
+   */
+  auto GetFQDN() -> utils::types::Result<utils::types::String>;
+
+  /**
+   * @brief Fetches the machine's Active Directory domain or workgroup membership and Azure AD join state.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The membership, the domain or workgroup name, and the Azure AD tenant if joined.
+   *
+   * @details Membership comes from `NetGetJoinInformation`, the DNS domain from
+   * `GetComputerNameExW(ComputerNameDnsDomain)`, and the Azure AD state from `NetGetAadJoinInformation`.
+   * Devices that are only registered with Azure AD (e.g. through a work account) are not reported as joined.
+   *
+   * Joining or leaving a domain needs a reboot, so the result is cached in memory.
+   *
+   * @warning Only supported on Windows.
+   */
+  auto GetDomainInfo(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::DomainInfo>;
+
+  /**
+   * @brief Fetches the motherboard and BIOS/UEFI firmware information.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The board vendor/model/revision and the firmware vendor, version and release date.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/sys/class/dmi/id` (SMBIOS), or the device tree `model` on boards without DMI
+   *  - macOS: the `IODeviceTree` registry plane (`rom` on Intel, `chosen` on Apple Silicon)
+   *  - Windows: the SMBIOS values mirrored under `HKLM\HARDWARE\DESCRIPTION\System\BIOS`, and `GetFirmwareType`
+   *
+   * The result never changes while the system is running, so it is cached indefinitely.
+   *
+   * @warning Fails with `NotFound` if the platform exposes no board or firmware information at all.
+   */
+  auto GetFirmwareInfo(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::FirmwareInfo>;
+
+  /**
+   * @brief Fetches the operating system's unique machine identifier.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The machine ID (e.g., "4c4c4544004c3510804bb2c04f4e3432").
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/etc/machine-id`, falling back to `/var/lib/dbus/machine-id`
+   *  - macOS: `IOPlatformUUID` of the `IOPlatformExpertDevice` service
+   *  - Windows: `MachineGuid` under `HKLM\SOFTWARE\Microsoft\Cryptography`
+   *
+   * The ID is generated at install time, so it survives hostname changes but not a reinstall.
+   * Cloned VM images share it until it is regenerated.
+   */
+  auto GetMachineId(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
+
+  /**
+   * @brief Fetches the hardware serial number of the system.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The serial number the vendor assigned to the machine (e.g., "C02XK1ABJG5J").
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/sys/class/dmi/id/product_serial`, or the device tree `serial-number` on boards without DMI
+   *  - macOS: `IOPlatformSerialNumber` of the `IOPlatformExpertDevice` service
+   *  - Windows: the system (or baseboard) serial in the raw SMBIOS table from `GetSystemFirmwareTable`
+   *
+   * @warning On Linux the DMI serial is only readable by root; this fails with `PermissionRequired` otherwise.
+   * @warning Fails with `NotFound` if the firmware only reports an OEM placeholder.
+   */
+  auto GetHardwareSerial(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
+
+  /**
+   * @brief Detects the hypervisor and container runtime the process runs under.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The detected hypervisor and container; both are `None` on bare metal.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: the CPUID hypervisor leaf, DMI `sys_vendor`/`product_name` and `/sys/hypervisor/type`;
+   *    containers from `/run/systemd/container`, `/run/.containerenv`, `/.dockerenv`, `/proc/1/cgroup`
+   *    and the kernel release (WSL)
+   *  - macOS: `kern.hv_vmm_present` and `hw.model`; containers are never reported
+   *  - Windows: the CPUID hypervisor leaf confirmed against the SMBIOS system product, and the
+   *    container execution agent service for Windows Server containers (reported as `Other`)
+   *
+   * The result is only cached in memory, since a cache directory can be shared between a host and its containers.
+   */
+  auto GetVirtualization(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::VirtEnv>;
+
+  /**
+   * @brief Fetches the Secure Boot state and the TPM presence, version, and measured-boot log.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The boot-integrity features of the platform.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: the `SecureBoot` EFI variable in efivarfs, `/sys/class/tpm`, and the event log in securityfs
+   *  - Windows: `HKLM\SYSTEM\CurrentControlSet\Control\SecureBoot\State`, `Tbsi_GetDeviceInfo`, and `Tbsi_Get_TCG_Log_Ex`
+   *
+   * The result can only change across a reboot, so it is cached in memory.
+   *
+   * @warning Not supported on macOS, which uses the Secure Enclave instead of a TPM.
+   * @warning On Linux, the event log is usually only visible to root, so `measuredBoot` may be false for other users.
+   */
+  auto GetSecurityPlatformInfo(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::SecurityPlatformInfo>;
+
+  /**
+   * @brief Fetches the status of the mandatory access control framework (SELinux or AppArmor).
+   * @param cache The CacheManager instance to use for caching.
+   * @return The framework present, its mode, and the loaded policy.
+   *
+   * @details The active framework is taken from `/sys/kernel/security/lsm`, falling back to probing
+   * selinuxfs and the AppArmor module parameters. The mode is read fresh on every call, since it can
+   * be switched at runtime (e.g. `setenforce`).
+   *
+   * @warning Only supported on Linux.
+   * @warning AppArmor profile modes are only visible to root; other users see `Enforcing` whenever AppArmor is enabled.
+   */
+  auto GetMacStatus(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::MacStatus>;
+
+  /**
+   * @brief Fetches the host firewall and whether each of its profiles is enabled.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The firewall backend and its profiles.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: a running firewalld, then the ufw configuration, then an enabled nftables.service
+   *  - Windows: the Domain, Private and Public profiles, honoring Group Policy overrides
+   *  - macOS: the Application Firewall global state
+   *
+   * Reading the actual ruleset needs root on Linux, so the state there is inferred from the
+   * configuration and running services instead.
+   *
+   * @warning Returns a `NotFound` error if no supported firewall is installed.
+   */
+  auto GetFirewallStatus(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::FirewallStatus>;
+
   /**
    * @brief Fetches the CPU model.
    * @return The CPU model (e.g., "Intel(R) Core(TM) i7-10750H CPU @ 2.60GHz").
@@ -306,6 +625,220 @@ namespace draconis::core::system {
    */
   auto GetCPUCores(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::CPUCores>;
 
+  /**
+   * @brief Fetches the CPU's architecture, vendor, family/model/stepping, and feature flags.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The CPUDetails struct. Values the platform doesn't expose are left empty.
+   *
+   * @details Obtained differently depending on the platform and architecture:
+   *  - Windows: CPUID on x86/x86_64; the registry and `IsProcessorFeaturePresent` on Arm64
+   *  - macOS: `machdep.cpu.*` sysctls on Intel; `hw.optional.*` sysctls on Apple Silicon
+   *  - Linux: The first processor block of `/proc/cpuinfo`
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - Linux: `/proc/cpuinfo` can't be read
+   *  - macOS/Linux: `uname` fails
+   */
+  auto GetCPUDetails(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::CPUDetails>;
+
+  /**
+   * @brief Fetches the sizes and sharing of the CPU caches.
+   * @param cache The CacheManager instance to use for caching.
+   * @return One entry per cache level and type, ordered by level (L1d, L1i, L2, L3, ...).
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: `GetLogicalProcessorInformationEx(RelationCache)`
+   *  - macOS: `hw.l1dcachesize`, `hw.l1icachesize`, `hw.l2cachesize`, `hw.l3cachesize` and `hw.cacheconfig`
+   *  - Linux: `/sys/devices/system/cpu/cpu<N>/cache/index<M>`, deduplicated by `shared_cpu_list`
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - Windows: `GetLogicalProcessorInformationEx` fails
+   *  - All: No cache information is exposed (common in virtual machines)
+   */
+  auto GetCPUCaches(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::CPUCacheInfo>>;
+
+  /**
+   * @brief Fetches the NUMA nodes, their memory, and which CPUs belong to each.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The nodes, ordered by id. Never cached, since free memory changes constantly.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: `GetLogicalProcessorInformationEx(RelationNumaNode)` and `GetNumaAvailableMemoryNodeEx`; total memory isn't exposed
+   *  - macOS: Macs have a single memory domain, reported as node 0 with `hw.memsize` and every logical CPU
+   *  - Linux: `/sys/devices/system/node/node<N>/cpulist` and `meminfo`
+   *  - Other: To be implemented
+   *
+   * Systems without NUMA report a single node.
+   *
+   * @warning This function can fail if:
+   *  - Windows: `GetLogicalProcessorInformationEx` fails
+   *  - macOS: `hw.memsize` or `hw.logicalcpu` can't be read
+   *  - Linux: The kernel was built without NUMA support (no `/sys/devices/system/node`)
+   */
+  auto GetNumaTopology(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::NumaNode>>;
+
+  /**
+   * @brief Fetches the known CPU vulnerabilities and whether each is mitigated.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The vulnerabilities, ordered by name. Cached in memory, since mitigations only change at boot.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: The kernel VA shadow and speculation control information classes of `NtQuerySystemInformation`,
+   *    as read by Microsoft's SpeculationControl module. Only `meltdown`, `l1tf`, `spectre_v2` and
+   *    `spec_store_bypass` are covered.
+   *  - macOS: Not exposed
+   *  - Linux: Every file in `/sys/devices/system/cpu/vulnerabilities`
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - Windows: Neither information class is supported (Windows without the 2018 speculation control updates)
+   *  - macOS: Always; XNU doesn't report its mitigations
+   *  - Linux: The kernel predates the vulnerabilities directory (older than 4.15)
+   */
+  auto GetCPUVulnerabilities(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::CPUVulnerability>>;
+
+  /**
+   * @brief Fetches the current, base, and maximum CPU clock speeds.
+   * @return The CPUFrequency struct. Values the platform doesn't expose are left empty.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: `CallNtPowerInformation(ProcessorInformation)`
+   *  - macOS: `sysctlbyname("hw.cpufrequency")` / `sysctlbyname("hw.cpufrequency_max")` (Intel only)
+   *  - Linux: `/sys/devices/system/cpu/cpu0/cpufreq`, falls back to `cpu MHz` in `/proc/cpuinfo`
+   *  - Other: To be implemented
+   *
+   * The current frequency changes constantly, so this function is never cached.
+   *
+   * @warning This function can fail if:
+   *  - Windows: `CallNtPowerInformation` fails
+   *  - macOS: None of the frequency sysctls exist (e.g. Apple Silicon)
+   *  - Linux: Neither cpufreq nor `/proc/cpuinfo` report a frequency
+   *  - Other: To be implemented
+   */
+  auto GetCPUFrequency(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::CPUFrequency>;
+
+  /**
+   * @brief Fetches the CPU package temperature.
+   * @return The temperature in degrees Celsius.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: WMI `MSAcpi_ThermalZoneTemperature` (in `root\WMI`)
+   *  - macOS: AppleSMC temperature keys (`TC0P`, `TC0D`, `Tp09`, ...)
+   *  - Linux: hwmon (`coretemp`, `k10temp`, `zenpower`, ...), falls back to `/sys/class/thermal`
+   *  - Other: To be implemented
+   *
+   * The temperature changes constantly, so this function is never cached.
+   *
+   * @warning This function can fail if:
+   *  - Windows: WMI is unavailable / the ACPI thermal zone is not exposed (usually requires administrator rights)
+   *  - macOS: The AppleSMC service can't be opened / none of the known keys are present
+   *  - Linux: No known CPU sensor is found in hwmon or the thermal subsystem
+   *  - Other: To be implemented
+   */
+  auto GetCPUTemperature(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::f64>;
+
+  /**
+   * @brief Fetches the fans reported by the hardware monitoring sensors.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The fans, in no particular order.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: WMI `Win32_Fan` (in `root\CIMV2`), which only reports the desired speed
+   *  - macOS: AppleSMC fan keys (`FNum`, `F0Ac`, `F0Tg`, ...)
+   *  - Linux: The `fanN_input`, `fanN_target` and `fanN_label` files of every hwmon device
+   *  - Other: To be implemented
+   *
+   * Fan speeds change constantly, so this function is never cached.
+   *
+   * @warning This function can fail if:
+   *  - Windows: WMI is unavailable / the firmware doesn't expose any fans (common on desktops)
+   *  - macOS: The AppleSMC service can't be opened / the Mac has no fans (e.g. MacBook Air)
+   *  - Linux: No hwmon driver exposes a fan (e.g. `nct6775` or `thinkpad_acpi` isn't loaded)
+   */
+  auto GetFans(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::FanInfo>>;
+
+  /**
+   * @brief Fetches the power currently drawn by the CPU package(s).
+   * @param cache The CacheManager instance to use for caching.
+   * @return The power draw in watts, summed over every CPU package.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - macOS (Intel): The AppleSMC `PCPC` key
+   *  - macOS (Apple Silicon): The `CPU Energy` channel of IOReport's "Energy Model" group, as `powermetrics` uses
+   *  - Linux: The `energy_uj` counters of the RAPL package zones in `/sys/class/powercap` (Intel, and AMD Zen)
+   *  - Other: To be implemented
+   *
+   * Energy counters only give a rate when sampled twice, so on Linux and Apple Silicon this function
+   * blocks for about 100 ms. It is never cached.
+   *
+   * @warning This function can fail if:
+   *  - Windows: Always; there is no public API for CPU energy counters
+   *  - macOS: Neither the SMC key nor the IOReport channel is available
+   *  - Linux: No RAPL package zone exists / `energy_uj` is only readable by root (the default since Linux 5.10)
+   */
+  auto GetCPUPowerWatts(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::f64>;
+
+  /**
+   * @brief Fetches every temperature, voltage, current, power and fan sensor the platform exposes.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The sensor readings, grouped by chip.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: LibreHardwareMonitor's or OpenHardwareMonitor's WMI namespace if either is running,
+   *    plus the ACPI thermal zones from `MSAcpi_ThermalZoneTemperature`
+   *  - macOS: Every AppleSMC key whose name marks it as a sensor (`T...`, `V...`, `I...`, `P...`, `F<n>Ac`)
+   *  - Linux: Every `temp`, `in`, `curr`, `power` and `fan` channel of every hwmon device, as `sensors` does
+   *  - Other: To be implemented
+   *
+   * Sensor readings change constantly, so this function is never cached.
+   *
+   * @warning This function can fail if:
+   *  - Windows: WMI is unavailable / no thermal zone is exposed and no hardware monitor is running
+   *  - macOS: The AppleSMC service can't be opened
+   *  - Linux: No hwmon device exposes a readable channel
+   */
+  auto GetSensors(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::SensorReading>>;
+
+  /**
+   * @brief Fetches the cumulative time counters for each logical CPU.
+   * @return A vector of CPUTimes, one per logical CPU, in processor order.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: `NtQuerySystemInformation(SystemProcessorPerformanceInformation)`
+   *  - macOS: `host_processor_info(PROCESSOR_CPU_LOAD_INFO)`
+   *  - Linux: Parses the `cpuN` lines of `/proc/stat`
+   *  - Other: To be implemented
+   *
+   * Usage is derived by sampling twice and comparing the deltas, so this
+   * function is never cached.
+   *
+   * @warning This function can fail if:
+   *  - Windows: `NtQuerySystemInformation` fails
+   *  - macOS: `host_processor_info` fails
+   *  - Linux: `/proc/stat` fails to open / contains no per-CPU lines
+   *  - Other: To be implemented
+   *
+   * @code{.cpp}
+   * #include <print>
+   * #include <Drac++/Core/System.hpp>
+   *
+   * int main() {
+   *   Result<Vec<CPUTimes>> times = draconis::core::system::GetCPUTimesPerCore();
+   *
+   *   if (times.has_value()) {
+   *     std::println("Logical CPUs: {}", times.value().size());
+   *   } else {
+   *     std::println("Failed to get CPU times: {}", times.error().message());
+   *   }
+   *
+   *   return 0;
+   * }
+   * @endcode
+   */
+  auto GetCPUTimesPerCore() -> utils::types::Result<utils::types::Vec<utils::types::CPUTimes>>;
+
   /**
    * @brief Fetches the GPU model.
    * @return The GPU model (e.g., "NVIDIA GeForce RTX 3070").
@@ -339,6 +872,40 @@ namespace draconis::core::system {
    */
   auto GetGPUModel(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
 
+  /**
+   * @brief Fetches every graphics adapter in the system.
+   * @return A list of GPUInfo structs, one per adapter.
+   *
+   * @details Not cached, since temperature and power draw change constantly.
+   * Obtained differently depending on the platform:
+   *  - Linux: Walks `/sys/bus/pci/devices` for display-class devices, names them via `pci.ids`,
+   *    reads VRAM from `mem_info_vram_total` (amdgpu only) and the driver from the `driver` symlink.
+   *    GPUs on the root PCI bus are reported as integrated. Temperature and power come from the
+   *    device's hwmon node (amdgpu, nouveau).
+   *  - macOS: `MTLCopyAllDevices`, using `recommendedMaxWorkingSetSize` as VRAM. The SMC GPU
+   *    temperature is attached to the discrete GPU (or the only GPU). Power draw is never reported.
+   *  - Windows: `IDXGIFactory1::EnumAdapters1`, skipping software adapters, with the UMD driver version.
+   *    Temperature comes from `D3DKMT_ADAPTER_PERFDATA` (WDDM 2.7+). Power draw is never reported.
+   *
+   * Driver and graphics API versions are probed once per CacheManager, without creating any windows:
+   *  - Vulkan (Linux/Windows): The Vulkan loader is opened at runtime and its physical devices are matched to
+   *    adapters by PCI vendor and device ID. Its `VkPhysicalDeviceDriverProperties::driverInfo` is preferred
+   *    as the driver version.
+   *  - OpenGL (Linux): A surfaceless core profile context on each `EGL_EXT_device_enumeration` device, matched
+   *    by its DRM node; the driver version falls back to the one in `GL_VERSION`, then `/sys/module/<driver>/version`.
+   *  - OpenGL (macOS): Always 4.1, the last version Apple shipped.
+   *  - DirectX (Windows): The highest feature level `D3D12CreateDevice` accepts, falling back to `D3D11CreateDevice`.
+   *  - Metal (macOS): The highest `MTLGPUFamilyMetal*` family the device supports.
+   *
+   * APIs that can't be probed (missing loader, no driver, headless EGL unavailable) are left empty rather than failing.
+   *
+   * @warning This function can fail if:
+   *  - Linux: `/sys/bus/pci/devices` doesn't exist / no display-class devices are found
+   *  - macOS: No Metal devices are found
+   *  - Windows: `CreateDXGIFactory1` fails / no hardware adapters are found
+   */
+  auto GetGPUs(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::GPUInfo>>;
+
   /**
    * @brief Fetches the kernel version.
    * @return The kernel version (e.g., "6.14.4").
@@ -374,6 +941,30 @@ namespace draconis::core::system {
    */
   auto GetKernelVersion(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
 
+  /**
+   * @brief Fetches the command line the kernel was booted with.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The boot parameters (e.g., "BOOT_IMAGE=/vmlinuz-linux root=UUID=... rw quiet").
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/proc/cmdline`
+   *  - macOS: `sysctlbyname("kern.bootargs")`, usually empty unless boot-args are set in NVRAM
+   *  - Windows: the `SystemStartOptions` value under `HKLM\SYSTEM\CurrentControlSet\Control`
+   */
+  auto GetKernelCmdline(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::String>;
+
+  /**
+   * @brief Fetches the kernel modules currently loaded.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The loaded modules, in the order the platform lists them.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/proc/modules`; modules built into the kernel are not listed
+   *  - macOS: `KextManagerCopyLoadedKextInfo`, listing kernel extensions by bundle identifier
+   *  - Windows: `EnumDeviceDrivers`, listing loaded drivers by file name (without sizes)
+   */
+  auto GetKernelModules(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::KernelModule>>;
+
   /**
    * @brief Fetches the disk usage.
    * @return The ResourceUsage struct containing the used and total disk space in bytes.
@@ -410,6 +1001,74 @@ namespace draconis::core::system {
   auto GetSystemDisk(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::DiskInfo>;
   auto GetDiskByPath(const utils::types::String& path, utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::DiskInfo>;
 
+  /**
+   * @brief Reads the SMART health data of a physical drive.
+   * @param device The whole-disk device, e.g. "/dev/sda" or "nvme0n1" on Linux, or
+   * "PhysicalDrive0" (or `\\.\PhysicalDrive0`) on Windows.
+   * @return The overall verdict, power-on hours, reallocated sector count, temperature and SMART attributes.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: ATA `SMART READ DATA`/`READ THRESHOLDS`/`RETURN STATUS` over `SG_IO`, or the NVMe
+   *    SMART / Health Information log page over `NVME_IOCTL_ADMIN_CMD`
+   *  - Windows: `IOCTL_STORAGE_PREDICT_FAILURE` for ATA drives, or the NVMe health log page through
+   *    `IOCTL_STORAGE_QUERY_PROPERTY`
+   *  - macOS: Not supported
+   *
+   * The data is read from the drive on every call and never cached.
+   *
+   * @warning This function can fail if:
+   *  - The process isn't running as root / Administrator (`PermissionRequired`)
+   *  - The device doesn't exist or doesn't support SMART
+   */
+  auto GetDiskHealth(utils::types::StringView device) -> utils::types::Result<utils::types::DiskHealth>;
+
+  /**
+   * @brief Fetches the temperature of every drive that exposes a sensor.
+   * @param cache The CacheManager instance (unused; temperatures are always read live).
+   * @return One reading per drive.
+   *
+   * @details Unlike GetDiskHealth, this doesn't need elevated privileges:
+   *  - Linux: the `nvme` and `drivetemp` hwmon drivers (the latter must be loaded for SATA drives)
+   *  - Windows: `StorageDeviceTemperatureProperty` through `IOCTL_STORAGE_QUERY_PROPERTY`
+   *  - macOS: Not supported
+   *
+   * @warning Fails with `NotFound` if no drive reports a temperature.
+   */
+  auto GetDiskTemperatures(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::DiskTemperature>>;
+
+  /**
+   * @brief Fetches the I/O counters of every physical drive.
+   * @param cache The CacheManager instance (unused; counters are always read live).
+   * @return Completed read/write operations and bytes transferred per drive.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/proc/diskstats`, for whole disks listed in `/sys/block` (loop and RAM disks are skipped)
+   *  - macOS: the `Statistics` dictionary of each `IOBlockStorageDriver`
+   *  - Windows: `IOCTL_DISK_PERFORMANCE` on each `\\.\PhysicalDriveN`
+   *
+   * The counters are cumulative; sample twice and divide the difference by the
+   * elapsed time to get IOPS or throughput.
+   *
+   * @warning Fails with `NotFound` if no drive reports counters.
+   */
+  auto GetDiskIOStats(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::DiskIOStats>>;
+
+  /**
+   * @brief Fetches the filesystems mounted from remote servers.
+   * @param cache The CacheManager instance (unused; mounts are always read live).
+   * @return The network mounts, which may be empty.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: /proc/mounts (NFS, CIFS/SMB3, SSHFS, davfs2)
+   *  - macOS: getmntinfo (nfs, smbfs, webdav, afpfs and FUSE-based SSHFS)
+   *  - Windows: mapped drives from WNetEnumResource, including remembered but disconnected ones
+   *
+   * The connection state is only known for FUSE mounts on Linux and for mapped drives on Windows.
+   * Probing a kernel NFS or SMB mount whose server is gone can block indefinitely, so those report
+   * `State::Unknown`.
+   */
+  auto GetNetworkMounts(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::NetworkMount>>;
+
   /**
    * @brief Fetches the uptime.
    * @return The uptime in seconds.
@@ -443,6 +1102,214 @@ namespace draconis::core::system {
    */
   auto GetUptime() -> utils::types::Result<std::chrono::seconds>;
 
+  /**
+   * @brief Fetches the time the system booted.
+   * @return The boot time, with one-second resolution.
+   *
+   * @details Read directly from a value the kernel records at boot, so repeated calls return
+   * the same instant rather than drifting the way `now() - GetUptime()` does.
+   *  - Linux: The `btime` line of `/proc/stat`
+   *  - macOS/BSD: `sysctl(CTL_KERN, KERN_BOOTTIME)`
+   *  - Windows: `NtQuerySystemInformation(SystemTimeOfDayInformation)`
+   *
+   * @warning This function can fail if:
+   *  - Linux: `/proc/stat` can't be read or has no `btime` line
+   *  - macOS/BSD: `sysctl` returns -1
+   *  - Windows: `NtQuerySystemInformation` fails
+   */
+  auto GetBootTime() -> utils::types::Result<std::chrono::system_clock::time_point>;
+
+  /**
+   * @brief Fetches the system load averages.
+   * @return The 1, 5, and 15 minute load averages.
+   *
+   * @details Load averages aren't cached, since they change constantly.
+   *  - Linux, macOS, BSD: `getloadavg`
+   *  - Windows: Not supported; Windows doesn't track load averages.
+   *
+   * @warning This function can fail if:
+   *  - Unix: `getloadavg` returns fewer than three samples
+   *  - Windows: Always (`NotSupported`)
+   */
+  auto GetLoadAverage() -> utils::types::Result<utils::types::LoadAverage>;
+
+  /**
+   * @brief Fetches every running process.
+   * @param cache The CacheManager instance (unused, the process table is always read live).
+   * @return A list of ProcessInfo structs, one per process.
+   *
+   * @details CPU usage is averaged over each process's lifetime, so no sampling delay is needed.
+   * Processes that exit mid-scan are skipped. Obtained differently depending on the platform:
+   *  - Linux: Parses `/proc/[pid]/stat` and `/proc/[pid]/cmdline`, resolving the owner with `getpwuid_r`
+   *  - macOS: `proc_listallpids` and `proc_pidinfo`, with the command line from `KERN_PROCARGS2`.
+   *    RSS and CPU usage are only available for processes owned by the current user unless running as root.
+   *  - Windows: `CreateToolhelp32Snapshot`, `GetProcessTimes`, `GetProcessMemoryInfo`, and
+   *    `NtQueryInformationProcess`. Windows has no process states, so `state` is always `Unknown`.
+   *
+   * @warning This function can fail if:
+   *  - Linux: `/proc` can't be read
+   *  - macOS: `proc_listallpids` fails
+   *  - Windows: `CreateToolhelp32Snapshot` or `Process32FirstW` fail
+   */
+  auto GetProcesses(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::ProcessInfo>>;
+
+  /**
+   * @brief Fetches information about a single process.
+   * @param pid The process ID to look up.
+   * @return The process, or a `NotFound` error if it doesn't exist.
+   *
+   * @details Only the requested process is queried, which makes this much cheaper than
+   * `GetProcesses` for polling a known daemon. Uses the same sources as `GetProcesses`;
+   * on Windows the process snapshot is still walked to find the parent PID and image name.
+   */
+  auto GetProcess(utils::types::u32 pid) -> utils::types::Result<utils::types::ProcessInfo>;
+
+  /**
+   * @brief Fetches information about every process with the given executable name.
+   * @param name The executable name to match.
+   * @return The matching processes, which may be empty.
+   *
+   * @details Names are compared before any expensive per-process queries. Obtained differently depending on the platform:
+   *  - Linux: Compares against `/proc/[pid]/comm`, which the kernel truncates to 15 characters
+   *  - macOS: Compares against `proc_name`
+   *  - Windows: Compares against the snapshot's image name, case-insensitively and with or without `.exe`
+   */
+  auto FindProcesses(utils::types::StringView name) -> utils::types::Result<utils::types::Vec<utils::types::ProcessInfo>>;
+
+  /**
+   * @brief Fetches the init system / service manager of the machine.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The init system.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/run/systemd/system` for systemd (as `sd_booted` does), otherwise the name of PID 1 and `/run/openrc`
+   *  - macOS: Always launchd
+   *  - Windows: Always the Service Control Manager
+   */
+  auto GetInitSystem(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::InitSystem>;
+
+  /**
+   * @brief Fetches the state of a single system service.
+   * @param name The service to look up (e.g. "sshd" or "sshd.service", "com.openssh.sshd", "Spooler").
+   * @return The service, or a `NotFound` error if it doesn't exist.
+   *
+   * @details Queries the service manager directly instead of running its command-line tool:
+   *  - Linux (systemd): `ListUnitsByNames` and `GetUnitFileState` over D-Bus. A name without a
+   *    suffix is treated as a `.service` unit, as `systemctl` does.
+   *  - Linux (OpenRC): The state directories under `/run/openrc` and the runlevels in `/etc/runlevels`
+   *  - macOS: The launchd job with that label, from the system domain or else the user's domain
+   *  - Windows: `QueryServiceStatusEx` and `QueryServiceConfigW`
+   *
+   * @warning Other Linux init systems, and systemd without D-Bus support compiled in, return `NotSupported`.
+   */
+  auto GetServiceStatus(utils::types::StringView name) -> utils::types::Result<utils::types::ServiceInfo>;
+
+  /**
+   * @brief Fetches every system service known to the service manager.
+   * @return The services, in no particular order.
+   *
+   * @details Uses the same sources as `GetServiceStatus`. On systemd, unit files that aren't loaded
+   * (e.g. disabled services) are included as `Stopped`; template units (`foo@.service`) are not.
+   * launchd jobs from both the system and the current user's domain are listed.
+   */
+  auto ListServices() -> utils::types::Result<utils::types::Vec<utils::types::ServiceInfo>>;
+
+  /**
+   * @brief Fetches the running containers managed by Docker, Podman, or LXC.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The containers from every runtime that could be reached, or a `NotFound` error if none could.
+   *
+   * @details The Docker and Podman daemons are queried through their HTTP API rather than their CLIs:
+   *  - Linux: Podman's rootful and rootless sockets, then `/var/run/docker.sock` (or a `unix://` `DOCKER_HOST`).
+   *    LXC containers are found from their `lxc.payload.*` cgroups. CPU and memory usage come from each
+   *    container's cgroup v2 directory.
+   *  - macOS: Docker Desktop's sockets (or a `unix://` `DOCKER_HOST`)
+   *  - Windows: Docker Desktop's `docker_engine` named pipe
+   *
+   * @warning The Docker socket is normally only accessible to root and the `docker` group. If every
+   * runtime refused the connection, a `PermissionDenied` error is returned.
+   */
+  auto GetContainers(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::ContainerInfo>>;
+
+  /**
+   * @brief Fetches the logged-in user sessions.
+   * @return The list of sessions, which may be empty.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux/macOS/BSD: Reads `USER_PROCESS` records from the utmpx database. A terminal
+   *    emulator inside a desktop session is reported as its own `Terminal` session.
+   *  - Windows: `WTSEnumerateSessionsW` and `WTSQuerySessionInformationW`, skipping sessions
+   *    with no user attached (such as the services session).
+   *
+   * @warning This function can fail if:
+   *  - Windows: `WTSEnumerateSessionsW` fails
+   */
+  auto GetSessions() -> utils::types::Result<utils::types::Vec<utils::types::SessionInfo>>;
+
+  /**
+   * @brief Fetches the user account the current process runs as.
+   * @return The current user.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux/macOS/BSD: The `getpwuid_r` entry for the effective user ID
+   *  - Windows: `GetUserNameW`, the process token's SID, and `%USERPROFILE%`. There is no login shell, so `shell` is always `None`.
+   *
+   * @warning This function can fail if:
+   *  - Linux/macOS/BSD: The effective user has no passwd entry
+   *  - Windows: `GetUserNameW` or the token query fails
+   */
+  auto GetCurrentUser() -> utils::types::Result<utils::types::UserInfo>;
+
+  /**
+   * @brief Fetches the user's locale.
+   * @return The locale name in the platform's native format (e.g., "en_US.UTF-8" or "en-US").
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `LC_ALL`, `LC_MESSAGES`, or `LANG`, falling back to `LANG` in `/etc/locale.conf`
+   *  - macOS: `LC_ALL`, `LC_MESSAGES`, or `LANG`, falling back to `CFLocaleCopyCurrent`
+   *  - BSD: `LC_ALL`, `LC_MESSAGES`, or `LANG`
+   *  - Windows: `GetUserDefaultLocaleName`
+   *
+   * @warning This function can fail if no locale is configured.
+   */
+  auto GetLocale() -> utils::types::Result<utils::types::String>;
+
+  /**
+   * @brief Fetches the local timezone.
+   * @return The IANA timezone name and the UTC offset currently in effect.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux/macOS/BSD: `TZ`, the `/etc/localtime` symlink, or `/etc/timezone`, with the offset from `localtime_r`
+   *  - Windows: `std::chrono::current_zone`, with the offset from `GetDynamicTimeZoneInformation`.
+   *    Falls back to the Windows zone key name (e.g., "W. Europe Standard Time") if the IANA name can't be resolved.
+   *
+   * @warning This function can fail if the timezone name can't be determined.
+   */
+  auto GetTimezone() -> utils::types::Result<utils::types::TimezoneInfo>;
+
+  /**
+   * @brief Fetches the configured keyboard layouts and which one is active.
+   * @return The layouts in their configured order. Never cached, since the active layout can change at any time.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: `GetKeyboardLayoutList`, with the active layout from the foreground window's thread
+   *    and layout names from `HKLM\SYSTEM\CurrentControlSet\Control\Keyboard Layouts`
+   *  - macOS: `AppleEnabledInputSources` and `AppleSelectedInputSources` in the `com.apple.HIToolbox` preferences
+   *  - Linux: Sway or Hyprland IPC, Plasma's `kxkbrc` with the active layout from `org.kde.KeyboardLayouts`, or the
+   *    X11 `_XKB_RULES_NAMES` root window property, falling back to `/etc/X11/xorg.conf.d/00-keyboard.conf`,
+   *    `/etc/vconsole.conf`, and `/etc/default/keyboard`. Descriptions come from the XKB registry (`evdev.lst`).
+   *  - Other: To be implemented
+   *
+   * Layouts read from configuration files are never marked active. GNOME keeps its input sources in dconf,
+   * so on GNOME Wayland only the system-wide configuration is reported.
+   *
+   * @warning This function can fail if:
+   *  - Windows: `GetKeyboardLayoutList` returns no layouts
+   *  - macOS: No input sources are enabled in the preferences
+   *  - Linux: No compositor or X server exposes its layouts and no configuration file sets one
+   */
+  auto GetKeyboardLayouts() -> utils::types::Result<utils::types::Vec<utils::types::KeyboardLayout>>;
+
   /**
    * @brief Fetches the outputs.
    * @return The outputs.
@@ -452,6 +1319,18 @@ namespace draconis::core::system {
    *  - macOS: `CGGetActiveDisplayList`
    *  - Other: To be implemented
    *
+   * Position, rotation, color depth and connector come from the same source as the mode. The scale factor
+   * and DPI use `GetDpiForMonitor` on Windows, the mode's backing pixel size on macOS, and the output's
+   * scale and physical size on Wayland/X11 (X11 doesn't expose a scale factor, so it's always 1.0 there).
+   *
+   * Manufacturer, model and serial are decoded from the monitor's EDID (RandR's `EDID` property or
+   * `/sys/class/drm` on Linux, the device registry key on Windows). macOS only exposes the vendor ID and
+   * numeric serial through CoreGraphics; Apple Silicon also publishes the model name and full serial.
+   *
+   * Gamut and HDR support also come from the EDID (its primaries and CTA-861 colorimetry/HDR metadata
+   * blocks). Windows reports whether HDR is enabled through its advanced color state and macOS through
+   * NSScreen's EDR headroom. X11 always composites in SDR, and Wayland doesn't expose the HDR state.
+   *
    * @warning This function can fail if:
    *  - Windows: `GetDisplayConfigBufferSizes` fails
    *  - macOS: `CGGetActiveDisplayList` fails
@@ -509,6 +1388,24 @@ namespace draconis::core::system {
    */
   auto GetPrimaryOutput(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::DisplayInfo>;
 
+  /**
+   * @brief Fetches the brightness of every display whose backlight can be read.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The backlights. Never cached, since brightness changes constantly.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: `WmiMonitorBrightness` for built-in panels, `GetMonitorBrightness` (DDC/CI) for external monitors
+   *  - macOS: The private DisplayServices framework, which covers built-in and Apple displays
+   *  - Linux: `/sys/class/backlight`, plus DDC/CI over `/dev/i2c-*` for external monitors
+   *    (needs the `i2c-dev` module and access to the device nodes, unless `ddcci-driver-linux` is loaded)
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - macOS: DisplayServices can't be loaded
+   *  - All: No display exposes its brightness (common on desktops without DDC/CI access)
+   */
+  auto GetBrightness(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::BacklightInfo>>;
+
   /**
    * @brief Fetches the network interfaces.
    * @return The network interfaces.
@@ -518,6 +1415,9 @@ namespace draconis::core::system {
    *  - macOS: `getifaddrs`
    *  - Other: To be implemented
    *
+   * Link speed, duplex mode and MTU come from `/sys/class/net` on Linux, `if_data` and
+   * `SIOCGIFMEDIA` on macOS, and `GetAdaptersAddresses` on Windows (which doesn't report duplex).
+   *
    * @warning This function can fail if:
    *  - Windows: `GetAdaptersAddresses` fails
    *  - macOS: `getifaddrs` fails
@@ -542,6 +1442,23 @@ namespace draconis::core::system {
    */
   auto GetNetworkInterfaces(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::NetworkInterface>>;
 
+  /**
+   * @brief Fetches the traffic counters of every network interface.
+   * @return Received/transmitted bytes, packets, errors and drops per interface.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/proc/net/dev`
+   *  - macOS: `sysctl(NET_RT_IFLIST2)` (64-bit `if_data64` counters)
+   *  - Windows: `GetIfEntry2` for each adapter returned by `GetAdaptersAddresses`
+   *
+   * The counters are cumulative and never cached; sample twice and divide the
+   * difference by the elapsed time to get a transfer rate.
+   *
+   * @warning This function can fail if:
+   *  - The platform's interface statistics can't be read
+   */
+  auto GetNetworkStats(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::InterfaceStats>>;
+
   /**
    * @brief Fetches the primary network interface.
    * @return The primary network interface.
@@ -575,6 +1492,112 @@ namespace draconis::core::system {
    */
   auto GetPrimaryNetworkInterface(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::NetworkInterface>;
 
+  /**
+   * @brief Fetches the Bluetooth adapters and their paired or connected devices.
+   * @return The adapters and devices.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: BlueZ `org.bluez.Adapter1`/`Device1`/`Battery1` objects over D-Bus, requires `DRAC_USE_DBUS`
+   *  - macOS: IOBluetooth `IOBluetoothHostController` and `pairedDevices`
+   *  - Windows: `BluetoothFindFirstRadio`/`BluetoothFindFirstDevice`
+   *
+   * Battery levels are only available on Linux, for devices that expose the BlueZ battery profile.
+   *
+   * @warning This function can fail if:
+   *  - Linux: D-Bus support is disabled / the system bus or BlueZ isn't available
+   *  - Windows: No Bluetooth radio is present
+   */
+  auto GetBluetoothDevices(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::BluetoothInfo>;
+
+  /**
+   * @brief Fetches the print queues, their driver and connection, and their current state.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The printers. Never cached, since the state changes with every job.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux/macOS: A `CUPS-Get-Printers` IPP request over the CUPS domain socket (`CUPS_SERVER` if it's a socket path,
+   *    otherwise `/run/cups/cups.sock`, `/var/run/cups/cups.sock` or `/private/var/run/cupsd`)
+   *  - Windows: `EnumPrintersW` (local and connected printers) and `GetDefaultPrinterW`
+   *  - Other: To be implemented
+   *
+   * On CUPS, the default printer is the user's choice (`LPDEST`, `PRINTER`, or `~/.cups/lpoptions`) if one is set,
+   * otherwise the server default. A system without printers returns an empty list.
+   *
+   * @warning This function can fail if:
+   *  - Linux/macOS: CUPS isn't running or only listens on TCP
+   *  - Windows: `EnumPrintersW` fails (e.g. the print spooler service is stopped)
+   */
+  auto GetPrinters(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::PrinterInfo>>;
+
+  /**
+   * @brief Fetches the video capture devices and whether each one is in use.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The cameras. Never cached, since the in-use flag changes whenever an app starts or stops capturing.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `/dev/video*` nodes whose `VIDIOC_QUERYCAP` reports video capture, named from `/sys/class/video4linux`;
+   *    in use if another process holds the node open (found by scanning `/proc/<pid>/fd`)
+   *  - macOS: AVFoundation `AVCaptureDeviceDiscoverySession` and `isInUseByAnotherApplication`
+   *  - Windows: `KSCATEGORY_VIDEO_CAMERA` interfaces via SetupAPI; in use if an app's webcam entry in the
+   *    `CapabilityAccessManager` consent store has started but not stopped
+   *  - Other: To be implemented
+   *
+   * Listing cameras never starts capture, so it doesn't turn on a camera's indicator light. A system without cameras
+   * returns an empty list.
+   *
+   * @warning The in-use flag is best-effort:
+   *  - Linux: Open file descriptors of other users' processes are only visible to root
+   *  - Windows: Usage is tracked per app rather than per device, so every camera is reported in use while any is
+   */
+  auto GetCameras(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::CameraInfo>>;
+
+  /**
+   * @brief Fetches the connection details of the active Wi-Fi interface.
+   * @return The SSID, BSSID, signal strength, band and link rate.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: Wireless extension ioctls (`SIOCGIWESSID`/`SIOCGIWAP`/`SIOCGIWFREQ`/`SIOCGIWRATE`) and `/proc/net/wireless`
+   *  - macOS: CoreWLAN `CWWiFiClient`
+   *  - Windows: `WlanQueryInterface` (`wlan_intf_opcode_current_connection`/`wlan_intf_opcode_channel_number`)
+   *
+   * Windows only reports signal quality, so the dBm value there is an estimate; elsewhere the
+   * percentage is derived from the dBm value. On macOS the SSID and BSSID are only available
+   * to processes with Location Services permission.
+   *
+   * @warning This function can fail if:
+   *  - There is no wireless interface / no wireless interface is connected
+   */
+  auto GetWifiInfo(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::WifiInfo>;
+
+  /**
+   * @brief Fetches the default gateway.
+   * @return The gateway address and the interface it is reached through.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: The default entry of `/proc/net/route`, falling back to `/proc/net/ipv6_route`
+   *  - macOS: SystemConfiguration `State:/Network/Global/IPv4` (`State:/Network/Global/IPv6` as a fallback)
+   *  - Windows: `GetBestRoute` towards a public address
+   *
+   * @warning This function can fail if:
+   *  - There is no default route (e.g. the machine is offline)
+   */
+  auto GetDefaultGateway(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Gateway>;
+
+  /**
+   * @brief Fetches the DNS servers the system resolver is configured to use.
+   * @return The server addresses, in resolver order.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: `nameserver` lines of `/etc/resolv.conf`; when that only points at the systemd-resolved
+   *    stub listener, the upstream servers from `/run/systemd/resolve/resolv.conf` are returned instead
+   *  - macOS: SystemConfiguration `State:/Network/Global/DNS`, falling back to `/etc/resolv.conf`
+   *  - Windows: The DNS servers of every connected adapter, from `GetAdaptersAddresses`
+   *
+   * @warning This function can fail if:
+   *  - No DNS servers are configured
+   */
+  auto GetDnsServers(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::String>>;
+
   /**
    * @brief Fetches the battery information.
    * @return The battery information.
@@ -608,6 +1631,130 @@ namespace draconis::core::system {
    */
   auto GetBatteryInfo(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Battery>;
 
+  /**
+   * @brief Fetches every battery, including those of peripherals where the platform reports them.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The batteries, each identified by `Battery::name`. Never cached.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: The battery device class (`IOCTL_BATTERY_QUERY_STATUS`); peripherals aren't covered
+   *  - macOS: `IOPSCopyPowerSourcesInfo`, plus the `BatteryPercent` of HID peripherals such as the Magic Mouse
+   *  - Linux: Every `Battery` in `/sys/class/power_supply`; those with `scope` "Device" are peripherals
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - All: No battery is present
+   */
+  auto GetBatteries(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::Vec<utils::types::Battery>>;
+
+  /**
+   * @brief Fetches whether external power is connected, and how much the charger can supply.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The power source information. Never cached.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: `GetSystemPowerStatus`; charger wattage isn't exposed
+   *  - macOS: `IOPSGetProvidingPowerSourceType` and `IOPSCopyExternalPowerAdapterDetails`
+   *  - Linux: Online `Mains`/`USB` supplies in `/sys/class/power_supply`, with wattage from `voltage_max` × `current_max`
+   *    and USB-PD detected from `usb_type`
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - Linux: No external power supply is exposed (common on desktops)
+   *  - Windows: The AC line status is unknown
+   */
+  auto GetPowerSource(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::PowerSource>;
+
+  /**
+   * @brief Fetches the active power profile and CPU frequency governor.
+   * @param cache The CacheManager instance to use for caching.
+   * @return The power profile. Never cached, since users switch profiles at runtime.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Windows: The active power plan (`PowerGetActiveScheme`)
+   *  - macOS: Low Power Mode (`NSProcessInfo.lowPowerModeEnabled`)
+   *  - Linux: power-profiles-daemon over D-Bus, falling back to `/sys/firmware/acpi/platform_profile`;
+   *    the governor comes from `/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor`
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - Windows: `PowerGetActiveScheme` fails
+   *  - Linux: Neither a power profile nor a cpufreq governor is available
+   */
+  auto GetPowerProfile(utils::cache::CacheManager& cache) -> utils::types::Result<utils::types::PowerProfile>;
+
+  /**
+   * @brief Fetches a summary of the machine's hardware, for detecting that it changed between runs.
+   * @return The system, board, CPU and GPU model identifiers, joined. Never cached, since it's what
+   *         validates the cache.
+   *
+   * @details Contains model names and IDs only, never serial numbers, so it identifies a hardware configuration
+   * rather than a specific machine. Pass it to CacheManager::setFingerprint() to have persistent cache entries
+   * discarded when the hardware changes. Obtained differently depending on the platform:
+   *  - Windows: The BIOS and `CentralProcessor\0` registry keys, and `EnumDisplayDevicesW`
+   *  - macOS: `hw.model` and `machdep.cpu.brand_string` (`sysctlbyname`)
+   *  - Linux: `/sys/class/dmi/id`, `/sys/devices/system/cpu/modalias`, and display-class devices in `/sys/bus/pci/devices`
+   *  - Other: To be implemented
+   *
+   * @warning This function can fail if:
+   *  - Any platform: None of the identifiers could be read
+   */
+  auto GetHardwareFingerprint() -> utils::types::Result<utils::types::String>;
+
+  /**
+   * @brief Receives change notifications from the operating system, so rare events don't have to be polled for.
+   *
+   * @details Obtained differently depending on the platform:
+   *  - Linux: Kernel uevents (`NETLINK_KOBJECT_UEVENT`) for USB devices, disks, `power_supply` and `drm`,
+   *    and `RTM_NEWLINK`/`RTM_DELLINK` messages (`NETLINK_ROUTE`) for link state
+   *  - macOS: IOKit matching notifications, `IOPSNotificationCreateRunLoopSource`,
+   *    `CGDisplayRegisterReconfigurationCallback`, and SystemConfiguration `State:/Network/Interface/.../Link` keys
+   *  - Windows: `WM_DEVICECHANGE`, `WM_POWERBROADCAST`, and `WM_DISPLAYCHANGE` on a hidden window, and `NotifyIpInterfaceChange`
+   *  - Other: To be implemented
+   *
+   * Events that arrive between calls to wait() are queued by the OS (or, on macOS and Windows, by the watcher's
+   * notification thread), so none are lost while the caller handles a batch.
+   */
+  class SystemWatcher {
+   public:
+    /**
+     * @brief Subscribes to the platform's change notifications.
+     *
+     * @warning This function can fail if:
+     *  - Linux: A netlink socket can't be opened or bound
+     *  - macOS: An IOKit notification can't be registered
+     *  - Windows: The hidden window can't be created, or `NotifyIpInterfaceChange` fails
+     */
+    static auto Create() -> utils::types::Result<SystemWatcher>;
+
+    SystemWatcher(const SystemWatcher&)                    = delete;
+    auto operator=(const SystemWatcher&) -> SystemWatcher& = delete;
+    SystemWatcher(SystemWatcher&&) noexcept;
+    auto operator=(SystemWatcher&&) noexcept -> SystemWatcher&;
+    ~SystemWatcher();
+
+    /**
+     * @brief Blocks until at least one event arrives or `timeout` elapses.
+     * @return The events received, oldest first; empty if the timeout elapsed first.
+     */
+    auto wait(std::chrono::milliseconds timeout) -> utils::types::Result<utils::types::Vec<utils::types::SystemEvent>>;
+
+    /**
+     * @brief Makes a wait() in progress on another thread return early, with whatever events it has so far.
+     *
+     * If no wait() is in progress, the next one returns immediately instead. This is the only member that may be
+     * called while another thread is inside wait().
+     */
+    auto interrupt() -> utils::types::Unit;
+
+   private:
+    struct Impl;
+
+    explicit SystemWatcher(utils::types::UniquePointer<Impl> impl);
+
+    utils::types::UniquePointer<Impl> m_impl;
+  };
+
 #ifdef __linux__
   namespace linux {
     /**
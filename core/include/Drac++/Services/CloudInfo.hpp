@@ -0,0 +1,52 @@
+#pragma once
+
+#if DRAC_ENABLE_CLOUDINFO
+
+  #include "../Utils/CacheManager.hpp"
+  #include "../Utils/Types.hpp"
+
+namespace draconis::services::cloud {
+  namespace types = ::draconis::utils::types;
+  namespace cache = ::draconis::utils::cache;
+
+  /**
+   * @struct CloudInfo
+   * @brief The cloud provider this machine runs on, and the instance it runs as.
+   */
+  struct CloudInfo {
+    enum class Provider : types::u8 {
+      Aws,          ///< Amazon Web Services (EC2).
+      Gcp,          ///< Google Cloud Platform (Compute Engine).
+      Azure,        ///< Microsoft Azure.
+      Hetzner,      ///< Hetzner Cloud.
+      DigitalOcean, ///< DigitalOcean Droplets.
+      Oracle,       ///< Oracle Cloud Infrastructure.
+    } provider; ///< Provider hosting this machine.
+
+    types::Option<types::String> instanceType; ///< Instance type (e.g. "t3.micro", "e2-medium", "Standard_B2s"), if the provider exposes it.
+    types::Option<types::String> region;       ///< Region (e.g. "us-east-1", "us-central1", "westeurope").
+  };
+
+  /**
+   * @brief Identifies the cloud provider and the instance type and region of this machine.
+   *
+   * @details The provider is recognized from the SMBIOS strings the hypervisor sets (via sysfs
+   * on Linux, the registry on Windows), so machines that aren't cloud instances never touch the
+   * network. Only then is that provider's instance metadata service at `169.254.169.254` asked
+   * for the instance type and region, with a one-second timeout per request. If the metadata
+   * service is unreachable (e.g. blocked from inside a container), the provider is still
+   * reported, without instance type or region. Results are cached for a day.
+   *
+   * Azure VMs running Windows can't be told apart from local Hyper-V VMs by SMBIOS alone, so
+   * those are confirmed by reaching the Azure metadata service.
+   *
+   * @note This may make outbound network requests, so it is only compiled in when the
+   * `cloudinfo` build option is enabled.
+   *
+   * @return Result containing the cloud information, or a DracError with `NotFound` if this
+   * isn't a recognized cloud instance.
+   */
+  auto GetCloudInfo(cache::CacheManager& cache) -> types::Result<CloudInfo>;
+} // namespace draconis::services::cloud
+
+#endif // DRAC_ENABLE_CLOUDINFO
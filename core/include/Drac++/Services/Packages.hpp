@@ -112,6 +112,20 @@ namespace draconis::services::packages {
    */
   auto GetIndividualCounts(cache::CacheManager& cache, Manager enabledPackageManagers) -> types::Result<types::Map<types::String, types::u64>>;
 
+  /**
+   * @brief Gets the number of pending updates reported by the system package manager.
+   *
+   * @details Only local metadata is consulted, so the result is as fresh as the last
+   * repository sync. Results are cached for an hour, since querying is comparatively slow.
+   *  - Linux: `apt-get -s upgrade`, `dnf --cacheonly check-update`, and `pacman -Qu`, summed over whichever are installed
+   *  - macOS: `LastRecommendedUpdatesAvailable` from the `softwareupdate` preferences
+   *  - Windows: `winget upgrade`
+   *
+   * @return Result containing the number of pending updates on success,
+   * or a DracError if no supported package manager is available.
+   */
+  auto GetPendingUpdateCount(cache::CacheManager& cache) -> types::Result<types::u64>;
+
   /**
    * @brief Gets package count from a database using SQLite.
    * @param cache The CacheManager instance to use for caching.
@@ -0,0 +1,37 @@
+#pragma once
+
+#if DRAC_ENABLE_PUBLICIP
+
+  #include "../Utils/CacheManager.hpp"
+  #include "../Utils/Types.hpp"
+
+namespace draconis::services::network {
+  namespace types = ::draconis::utils::types;
+  namespace cache = ::draconis::utils::cache;
+
+  /**
+   * @struct PublicIp
+   * @brief The external addresses this machine is seen as on the internet.
+   */
+  struct PublicIp {
+    types::Option<types::String> v4; ///< Public IPv4 address, if the machine has IPv4 connectivity.
+    types::Option<types::String> v6; ///< Public IPv6 address, if the machine has IPv6 connectivity.
+  };
+
+  /**
+   * @brief Looks up the public IPv4 and IPv6 addresses of this machine.
+   *
+   * @details Sends a plain HTTP request to `api.ipify.org` (IPv4) and `api6.ipify.org` (IPv6),
+   * each with a short connect/read timeout. Results are cached for 30 minutes, so repeated
+   * calls (and repeated CLI runs) don't hit the network every time.
+   *
+   * @note This makes outbound network requests, so it is only compiled in when the
+   * `publicip` build option is enabled.
+   *
+   * @return Result containing the addresses on success, or a DracError if neither
+   * address family could be resolved.
+   */
+  auto GetPublicIp(cache::CacheManager& cache) -> types::Result<PublicIp>;
+} // namespace draconis::services::network
+
+#endif // DRAC_ENABLE_PUBLICIP
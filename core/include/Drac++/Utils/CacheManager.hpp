@@ -1,5 +1,6 @@
 #pragma once
 
+#include <algorithm>
 #include <any>
 #include <atomic>
 #include <chrono>
@@ -57,6 +58,108 @@ namespace draconis::utils::cache {
     }
   };
 
+  /**
+   * @brief Groups cache keys by the kind of data they hold, so TTLs can be set per group.
+   *
+   * Memory, disk, battery, process and other live metrics are never cached, so they have no domain.
+   */
+  enum class CacheDomain : types::u8 {
+    Cpu,      ///< CPU model, cores, details, caches and vulnerabilities.
+    Gpu,      ///< GPU model and graphics API devices.
+    Network,  ///< Primary network interface and public IP.
+    System,   ///< OS version, kernel, kernel command line and init system.
+    Hardware, ///< Host model, firmware, serial, machine ID, virtualization, security platform and cloud.
+    Desktop,  ///< Desktop environment, window manager, display session, displays, theme, terminal and shell.
+    Packages, ///< Package counts and pending updates.
+  };
+
+  /**
+   * @brief Every key the library caches under, with its domain.
+   *
+   * Keys are `<platform>_<name>` (e.g. `linux_cpu_details`) or, for services, a service-specific name.
+   * A new getOrSet() key has to be added here (or to CACHE_KEY_PREFIX_DOMAINS) to be covered by domain TTLs and invalidation.
+   */
+  inline constexpr types::Array<types::Pair<types::StringView, CacheDomain>, 100> CACHE_KEY_DOMAINS = [] {
+    using enum CacheDomain;
+
+    // clang-format off
+    return types::Array<types::Pair<types::StringView, CacheDomain>, 100> {{
+      // BSD
+      { "bsd_os_info", System }, { "bsd_kernel_version", System }, { "bsd_wm", Desktop }, { "bsd_display_session", Desktop },
+      { "bsd_theme_info", Desktop }, { "bsd_desktop_environment", Desktop }, { "bsd_shell", Desktop }, { "bsd_terminal", Desktop },
+      { "bsd_terminal_font", Desktop }, { "bsd_host", Hardware },
+      // Haiku
+      { "haiku_os_info", System }, { "haiku_kernel_version", System }, { "haiku_wm", Desktop }, { "haiku_desktop_environment", Desktop },
+      { "haiku_shell", Desktop }, { "haiku_host", Hardware },
+      // Linux
+      { "linux_distro_id", System }, { "linux_os_version", System }, { "linux_kernel_version", System }, { "linux_kernel_cmdline", System },
+      { "linux_init_system", System }, { "linux_wm", Desktop }, { "linux_display_session", Desktop }, { "linux_theme_info", Desktop },
+      { "linux_desktop_environment", Desktop }, { "linux_shell", Desktop }, { "linux_terminal", Desktop }, { "linux_terminal_font", Desktop },
+      { "linux_host", Hardware }, { "linux_firmware_info", Hardware }, { "linux_machine_id", Hardware }, { "linux_hardware_serial", Hardware },
+      { "linux_virtualization", Hardware }, { "linux_security_platform", Hardware }, { "linux_cpu_details", Cpu }, { "linux_cpu_caches", Cpu },
+      { "linux_cpu_vulnerabilities", Cpu }, { "linux_gpu_model", Gpu }, { "linux_vulkan_devices", Gpu }, { "linux_opengl_devices", Gpu },
+      { "linux_network_interfaces", Network }, { "linux_primary_network_interface", Network },
+      // macOS
+      { "macos_os_info", System }, { "macos_kernel", System }, { "macos_kernel_cmdline", System }, { "macos_wm", Desktop },
+      { "macos_shell", Desktop }, { "macos_terminal", Desktop }, { "macos_terminal_font", Desktop }, { "macos_primary_output", Desktop },
+      { "macos_outputs", Desktop }, { "macos_host", Hardware }, { "macos_firmware_info", Hardware }, { "macos_machine_id", Hardware },
+      { "macos_hardware_serial", Hardware }, { "macos_virtualization", Hardware }, { "macos_cpu_model", Cpu }, { "macos_cpu_cores", Cpu },
+      { "macos_cpu_details", Cpu }, { "macos_cpu_caches", Cpu }, { "macos_gpu", Gpu }, { "macos_network_interfaces", Network },
+      { "macos_primary_network_interface", Network }, { "homebrew_total", Packages },
+      // Serenity
+      { "serenity_os_info", System }, { "serenity_kernel_version", System }, { "serenity_wm", Desktop }, { "serenity_desktop_environment", Desktop },
+      { "serenity_shell", Desktop }, { "serenity_host", Hardware },
+      // Windows
+      { "windows_os_version", System }, { "windows_kernel_version", System }, { "windows_kernel_cmdline", System }, { "windows_wm", Desktop },
+      { "windows_theme_info", Desktop }, { "windows_desktop_environment", Desktop }, { "windows_shell", Desktop }, { "windows_terminal", Desktop },
+      { "windows_terminal_font", Desktop }, { "windows_host", Hardware }, { "windows_firmware_info", Hardware }, { "windows_machine_id", Hardware },
+      { "windows_hardware_serial", Hardware }, { "windows_virtualization", Hardware }, { "windows_security_platform", Hardware },
+      { "windows_domain_info", Hardware }, { "windows_cpu_model", Cpu }, { "windows_cpu_cores", Cpu }, { "windows_cpu_details", Cpu },
+      { "windows_cpu_caches", Cpu }, { "windows_cpu_vulnerabilities", Cpu }, { "windows_gpu_model", Gpu }, { "windows_vulkan_devices", Gpu },
+      { "windows_primary_network_interface", Network }, { "windows_chocolatey_count", Packages }, { "windows_scoop_count", Packages },
+      { "windows_winget_count", Packages },
+      // Services
+      { "pkg_pending_updates", Packages }, { "public_ip", Network }, { "cloud_info", Hardware },
+    }};
+    // clang-format on
+  }();
+
+  /**
+   * @brief Domains of the keys that are generated from an ID, by the prefix the ID is appended to.
+   */
+  inline constexpr types::Array<types::Pair<types::StringView, CacheDomain>, 2> CACHE_KEY_PREFIX_DOMAINS {{
+    { "pkg_count_", CacheDomain::Packages },
+    { "windows_d3d_feature_level_", CacheDomain::Gpu },
+  }};
+
+  /**
+   * @brief Classifies a cache key by the data it holds.
+   *
+   * @return The key's domain, or None for keys the library doesn't know about.
+   */
+  inline auto GetCacheDomain(const types::StringView key) -> types::Option<CacheDomain> {
+    for (const auto& [known, domain] : CACHE_KEY_DOMAINS)
+      if (key == known)
+        return domain;
+
+    for (const auto& [prefix, domain] : CACHE_KEY_PREFIX_DOMAINS)
+      if (key.size() > prefix.size() && key.starts_with(prefix))
+        return domain;
+
+    return types::None;
+  }
+
+  /**
+   * @brief A snapshot of a CacheManager's effectiveness, for diagnosing whether caching is working.
+   */
+  struct CacheStats {
+    types::u64   hits    = 0; ///< Lookups answered from memory, disk, or another caller's concurrent fetch.
+    types::u64   misses  = 0; ///< Lookups that fetched from the system.
+    types::usize entries = 0; ///< Values currently held in memory.
+
+    types::Map<CacheDomain, system_clock::time_point> lastRefresh; ///< When each domain last fetched from the system.
+  };
+
   class CacheManager {
    public:
     /*!
@@ -99,14 +202,52 @@ namespace draconis::utils::cache {
       m_globalPolicy = policy;
     }
 
+    /**
+     * @brief Sets the hardware fingerprint that on-disk entries are tagged with.
+     *
+     * Entries read from disk that were written under a different fingerprint are discarded, so values such as the
+     * GPU model are refetched after a hardware change even if they never expire. Usually the result of
+     * core::system::GetHardwareFingerprint(). Until one is set, entries are tagged with an empty fingerprint.
+     *
+     * @param fingerprint The current hardware fingerprint.
+     */
+    auto setFingerprint(types::String fingerprint) -> types::Unit {
+      types::LockGuard lock(m_cacheMutex);
+      m_fingerprint = std::move(fingerprint);
+    }
+
+    /**
+     * @brief Overrides the TTL of every key in `domain`, regardless of the policy its getter uses.
+     *
+     * The TTL is measured from when each value was stored, so it applies to values already cached too.
+     * Each getter's cache location is kept.
+     *
+     * @param domain The domain to override.
+     * @param ttl How long values stay fresh, or None for them to never expire.
+     */
+    auto setDomainTtl(const CacheDomain domain, const types::Option<seconds> ttl) -> types::Unit {
+      types::LockGuard lock(m_cacheMutex);
+      m_domainTtls.insert_or_assign(domain, ttl);
+    }
+
+    /**
+     * @brief Removes an override set with setDomainTtl(), so the domain's getters use their own policies again.
+     */
+    auto resetDomainTtl(const CacheDomain domain) -> types::Unit {
+      types::LockGuard lock(m_cacheMutex);
+      m_domainTtls.erase(domain);
+    }
+
     template <typename T>
     struct CacheEntry {
       T                         data;
-      types::Option<types::u64> expires; // store as UNIX timestamp (seconds since epoch), None if no expiry
+      types::Option<types::u64> expires;     // store as UNIX timestamp (seconds since epoch), None if no expiry
+      types::String             fingerprint; // hardware fingerprint the entry was written under, see setFingerprint()
     };
 
     struct MemoryCacheEntry {
       std::any                 data;
+      system_clock::time_point stored;
       system_clock::time_point expires;
     };
 
@@ -129,6 +270,8 @@ namespace draconis::utils::cache {
         static_assert(std::copy_constructible<T>, "Cached values must be copy constructible");
 
         CachePolicy                    policy;
+        types::String                  fingerprint;
+        bool                           domainOverride   = false;
         types::u64                     keyGeneration    = 0;
         types::u64                     globalGeneration = 0;
         std::shared_ptr<InFlightEntry> inFlight;
@@ -136,12 +279,23 @@ namespace draconis::utils::cache {
         // Claim this key's fetch slot. Waiters share the leader's typed result.
         for (;;) {
           std::unique_lock lock(m_cacheMutex);
-          policy = overridePolicy.value_or(m_globalPolicy);
+          policy      = overridePolicy.value_or(m_globalPolicy);
+          fingerprint = m_fingerprint;
+
+          if (const types::Option<types::Option<seconds>> domainTtl = getDomainTtlLocked(key)) {
+            policy.ttl     = *domainTtl;
+            domainOverride = true;
+          }
 
           if (const auto iter = m_inMemoryCache.find(key); iter != m_inMemoryCache.end()) {
-            if (system_clock::now() >= iter->second.expires) {
+            const system_clock::time_point expiry = domainOverride
+              ? expiryAfter(iter->second.stored, policy.ttl)
+              : iter->second.expires;
+
+            if (system_clock::now() >= expiry) {
               m_inMemoryCache.erase(iter);
             } else if (const auto* value = std::any_cast<T>(&iter->second.data)) {
+              m_hits.fetch_add(1, std::memory_order_relaxed);
               return *value;
             } else {
               m_inMemoryCache.erase(iter);
@@ -152,8 +306,10 @@ namespace draconis::utils::cache {
             inFlight = iter->second;
             inFlight->completedCondition.wait(lock, [&inFlight] { return inFlight->completed; });
 
-            if (const auto* outcome = std::any_cast<types::Result<T>>(&inFlight->outcome))
+            if (const auto* outcome = std::any_cast<types::Result<T>>(&inFlight->outcome)) {
+              m_hits.fetch_add(1, std::memory_order_relaxed);
               return *outcome;
+            }
 
             // A simultaneous caller used this key with a different type.
             continue;
@@ -180,15 +336,25 @@ namespace draconis::utils::cache {
 
               if (types::Option<types::String> fileContents = readCacheFile(*filePath)) {
                 CacheEntry<T> entry;
-                if (glz::read_beve(entry, *fileContents) == glz::error_code::none) {
-                  const system_clock::time_point expiry = entry.expires.has_value()
+                if (glz::read_beve(entry, *fileContents) == glz::error_code::none && entry.fingerprint == fingerprint) {
+                  system_clock::time_point expiry = entry.expires.has_value()
                     ? system_clock::time_point(seconds(*entry.expires))
                     : system_clock::time_point::max();
 
+                  // The file's modification time is when the value was stored.
+                  system_clock::time_point stored = system_clock::now();
+                  std::error_code          timeError;
+                  if (const fs::file_time_type written = fs::last_write_time(*filePath, timeError); !timeError)
+                    stored = std::chrono::time_point_cast<system_clock::duration>(std::chrono::file_clock::to_sys(written));
+
+                  if (domainOverride)
+                    expiry = timeError ? system_clock::time_point::min() : expiryAfter(stored, policy.ttl);
+
                   if (system_clock::now() < expiry) {
                     validDiskEntry              = true;
                     types::Result<T> diskResult = entry.data;
-                    publishMemoryIfCurrent(key, entry.data, expiry, keyGeneration, globalGeneration);
+                    m_hits.fetch_add(1, std::memory_order_relaxed);
+                    publishMemoryIfCurrent(key, entry.data, stored, expiry, keyGeneration, globalGeneration);
                     complete(&diskResult);
                     return diskResult;
                   }
@@ -202,6 +368,8 @@ namespace draconis::utils::cache {
             }
           }
 
+          m_misses.fetch_add(1, std::memory_order_relaxed);
+
           types::Result<T> fetchedResult = fetcher();
 
           if (!fetchedResult) {
@@ -209,19 +377,25 @@ namespace draconis::utils::cache {
             return fetchedResult;
           }
 
+          const system_clock::time_point stored = system_clock::now();
+
+          if (const types::Option<CacheDomain> domain = GetCacheDomain(key)) {
+            types::LockGuard lock(m_cacheMutex);
+            m_lastRefresh.insert_or_assign(*domain, stored);
+          }
+          const system_clock::time_point expiry = expiryAfter(stored, policy.ttl);
+
           types::Option<types::u64> expiryTimestamp;
-          system_clock::time_point  expiry = system_clock::time_point::max();
-          if (policy.ttl.has_value()) {
-            expiry          = system_clock::now() + *policy.ttl;
+          if (policy.ttl.has_value())
             expiryTimestamp = duration_cast<seconds>(expiry.time_since_epoch()).count();
-          }
 
-          const bool published = publishMemoryIfCurrent(key, *fetchedResult, expiry, keyGeneration, globalGeneration);
+          const bool published = publishMemoryIfCurrent(key, *fetchedResult, stored, expiry, keyGeneration, globalGeneration);
 
           if (published && policy.location != CacheLocation::InMemory && filePath) {
             CacheEntry<T> newEntry {
-              .data    = *fetchedResult,
-              .expires = expiryTimestamp
+              .data        = *fetchedResult,
+              .expires     = expiryTimestamp,
+              .fingerprint = fingerprint,
             };
 
             types::String binaryBuffer;
@@ -247,6 +421,22 @@ namespace draconis::utils::cache {
       return getOrSet<T>(key, types::None, std::forward<Fetcher>(fetcher));
     }
 
+    /**
+     * @brief Returns lookup counts since the manager was created, and when each domain was last refreshed.
+     *
+     * Lookups made with ignoreCache set aren't counted.
+     */
+    auto getStats() -> CacheStats {
+      types::LockGuard lock(m_cacheMutex);
+
+      return {
+        .hits        = m_hits.load(std::memory_order_relaxed),
+        .misses      = m_misses.load(std::memory_order_relaxed),
+        .entries     = m_inMemoryCache.size(),
+        .lastRefresh = m_lastRefresh,
+      };
+    }
+
     /**
      * @brief Remove a cached entry corresponding to the given key.
      *
@@ -272,6 +462,70 @@ namespace draconis::utils::cache {
       }
     }
 
+    /**
+     * @brief Remove every cached entry in `domain`, in memory and on disk.
+     *
+     * Use this after something the domain describes is known to have changed, e.g. a network interface coming up.
+     * A fetch already in progress for the domain still returns its value, but doesn't cache it. Only files named
+     * after a key in CACHE_KEY_DOMAINS or CACHE_KEY_PREFIX_DOMAINS are removed from disk.
+     *
+     * @param domain The domain to invalidate.
+     */
+    auto invalidateDomain(const CacheDomain domain) -> types::Unit {
+      if constexpr (DRAC_ENABLE_CACHING) {
+        types::LockGuard lock(m_cacheMutex);
+
+        const auto inDomain = [domain](const types::StringView key) -> bool { return GetCacheDomain(key) == domain; };
+
+        for (auto iter = m_inMemoryCache.begin(); iter != m_inMemoryCache.end();)
+          if (inDomain(iter->first)) {
+            ++m_keyGenerations[iter->first];
+            iter = m_inMemoryCache.erase(iter);
+          } else {
+            ++iter;
+          }
+
+        for (const auto& [key, inFlight] : m_inFlight)
+          if (inDomain(key))
+            ++m_keyGenerations[key];
+
+        // The cache directories can hold files that aren't cache entries, so only the domain's own keys are removed.
+        for (const auto& [known, keyDomain] : CACHE_KEY_DOMAINS) {
+          if (keyDomain != domain)
+            continue;
+
+          const types::String key(known);
+          ++m_keyGenerations[key];
+
+          for (const CacheLocation loc : { CacheLocation::TempDirectory, CacheLocation::Persistent })
+            if (const types::Option<fs::path> filePath = getCacheFilePath(key, loc); filePath) {
+              if (std::error_code errc; fs::is_regular_file(*filePath, errc) && !errc)
+                fs::remove(*filePath, errc);
+            }
+        }
+
+        // Keys generated from an ID have to be found by name. They can't contain a path separator, so every
+        // cache file is directly inside its directory.
+        for (const fs::path& directory : { getTempCacheDir(), getPersistentCacheDir() }) {
+          std::error_code error;
+          for (const fs::directory_entry& entry : fs::directory_iterator(directory, error)) {
+            const types::String key = entry.path().filename().string();
+
+            const bool isGenerated = std::ranges::any_of(CACHE_KEY_PREFIX_DOMAINS, [&key, domain](const auto& prefixDomain) -> bool {
+              return prefixDomain.second == domain && key.size() > prefixDomain.first.size() && key.starts_with(prefixDomain.first);
+            });
+
+            if (std::error_code fileError; isGenerated && entry.is_regular_file(fileError) && !fileError) {
+              ++m_keyGenerations[key];
+              fs::remove(entry.path(), fileError);
+            }
+          }
+        }
+      } else {
+        (void)domain;
+      }
+    }
+
     /**
      * @brief Remove **all** cached data – both in-memory and on-disk.
      *
@@ -295,7 +549,13 @@ namespace draconis::utils::cache {
     }
 
    private:
-    CachePolicy m_globalPolicy;
+    CachePolicy                                     m_globalPolicy;
+    types::Map<CacheDomain, types::Option<seconds>> m_domainTtls;
+    types::String                                   m_fingerprint;
+
+    std::atomic<types::u64>                           m_hits   = 0;
+    std::atomic<types::u64>                           m_misses = 0;
+    types::Map<CacheDomain, system_clock::time_point> m_lastRefresh;
 
     // Typed values avoid deserializing BEVE data on every in-memory hit.
     types::UnorderedMap<types::String, MemoryCacheEntry>               m_inMemoryCache;
@@ -307,6 +567,19 @@ namespace draconis::utils::cache {
 
     static inline std::atomic<types::u64> m_tempFileCounter = 0;
 
+    static auto expiryAfter(const system_clock::time_point stored, const types::Option<seconds>& ttl) -> system_clock::time_point {
+      return ttl.has_value() ? stored + *ttl : system_clock::time_point::max();
+    }
+
+    // None if the key's domain has no override; otherwise the overriding TTL, which is itself None for never.
+    auto getDomainTtlLocked(const types::String& key) const -> types::Option<types::Option<seconds>> {
+      if (const types::Option<CacheDomain> domain = GetCacheDomain(key))
+        if (const auto iter = m_domainTtls.find(*domain); iter != m_domainTtls.end())
+          return iter->second;
+
+      return types::None;
+    }
+
     auto getKeyGenerationLocked(const types::String& key) const -> types::u64 {
       if (const auto iter = m_keyGenerations.find(key); iter != m_keyGenerations.end())
         return iter->second;
@@ -325,6 +598,7 @@ namespace draconis::utils::cache {
     auto publishMemoryIfCurrent(
       const types::String&           key,
       const T&                       value,
+      const system_clock::time_point stored,
       const system_clock::time_point expiry,
       const types::u64               keyGeneration,
       const types::u64               globalGeneration
@@ -333,7 +607,7 @@ namespace draconis::utils::cache {
       if (!isGenerationCurrentLocked(key, keyGeneration, globalGeneration))
         return false;
 
-      m_inMemoryCache.insert_or_assign(key, MemoryCacheEntry { .data = value, .expires = expiry });
+      m_inMemoryCache.insert_or_assign(key, MemoryCacheEntry { .data = value, .stored = stored, .expires = expiry });
       return true;
     }
 
@@ -520,10 +794,22 @@ namespace glz {
 
     // clang-format off
     static constexpr detail::Object value = object(
-      "id",          &T::id,
-      "resolution",  &T::resolution,
-      "refreshRate", &T::refreshRate,
-      "isPrimary",   &T::isPrimary
+      "id",           &T::id,
+      "resolution",   &T::resolution,
+      "refreshRate",  &T::refreshRate,
+      "isPrimary",    &T::isPrimary,
+      "position",     &T::position,
+      "scaleFactor",  &T::scaleFactor,
+      "dpi",          &T::dpi,
+      "colorDepth",   &T::colorDepth,
+      "rotation",     &T::rotation,
+      "connector",    &T::connector,
+      "manufacturer", &T::manufacturer,
+      "model",        &T::model,
+      "serial",       &T::serial,
+      "gamut",        &T::gamut,
+      "hdrSupported", &T::hdrSupported,
+      "hdrEnabled",   &T::hdrEnabled
     );
     // clang-format on
   };
@@ -535,10 +821,17 @@ namespace glz {
     static constexpr detail::Object value = object("width", &T::width, "height", &T::height);
   };
 
+  template <>
+  struct meta<draconis::utils::types::DisplayInfo::Position> {
+    using T = draconis::utils::types::DisplayInfo::Position;
+
+    static constexpr detail::Object value = object("x", &T::x, "y", &T::y);
+  };
+
   template <typename Tp>
   struct meta<draconis::utils::cache::CacheManager::CacheEntry<Tp>> {
     using T = draconis::utils::cache::CacheManager::CacheEntry<Tp>;
 
-    static constexpr detail::Object value = object("data", &T::data, "expires", &T::expires);
+    static constexpr detail::Object value = object("data", &T::data, "expires", &T::expires, "fingerprint", &T::fingerprint);
   };
 } // namespace glz
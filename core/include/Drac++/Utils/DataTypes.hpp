@@ -32,13 +32,100 @@ namespace draconis::utils::types {
   };
 
   struct DiskInfo {
-    String name;          // Drive/device name (e.g., "C:", "/dev/sda1")
-    String mountPoint;    // Mount path (e.g., "C:\", "/home")
-    String filesystem;    // Filesystem type (e.g., "NTFS", "ext4", "APFS")
-    String driveType;     // Drive type (e.g., "Fixed", "Removable", "CD-ROM", "Network", "RAM Disk")
-    u64    totalBytes;    // Total capacity
-    u64    usedBytes;     // Used space
-    bool   isSystemDrive; // Whether this is the system/boot drive
+    String      name;          // Drive/device name (e.g., "C:", "/dev/sda1")
+    String      mountPoint;    // Mount path (e.g., "C:\", "/home")
+    String      filesystem;    // Filesystem type (e.g., "NTFS", "ext4", "APFS")
+    String      driveType;     // Drive type (e.g., "Fixed", "Removable", "CD-ROM", "Network", "RAM Disk")
+    u64         totalBytes;    // Total capacity
+    u64         usedBytes;     // Used space
+    Option<u64> totalInodes;   // Total inodes (None on filesystems without a fixed inode table, e.g. NTFS or btrfs)
+    Option<u64> usedInodes;    // Used inodes
+    Vec<String> mountOptions;  // Mount options in fstab style (e.g., "rw", "noatime", "nosuid")
+    bool        isReadOnly;    // Whether the filesystem is mounted read-only
+    bool        isSystemDrive; // Whether this is the system/boot drive
+  };
+
+  /**
+   * @struct NetworkMount
+   * @brief A filesystem mounted from a remote server (NFS/SMB export, SSHFS, mapped drive, ...).
+   */
+  struct NetworkMount {
+    String server;     ///< Host name or address of the server, without any user name.
+    String exportPath; ///< Path of the export or share on the server, e.g. "/srv/media" or "share".
+    String mountPoint; ///< Where it's mounted locally, e.g. "/mnt/media" or "Z:".
+
+    enum class Protocol : u8 {
+      Unknown, ///< A network filesystem not listed below (e.g. AFP or CephFS).
+      Nfs,     ///< NFS (any version).
+      Smb,     ///< SMB/CIFS.
+      Sshfs,   ///< SSHFS over FUSE.
+      WebDav,  ///< WebDAV (davfs2, the macOS webdav client, the Windows WebClient service).
+    } protocol; ///< Protocol used to access the server.
+
+    enum class State : u8 {
+      Unknown,      ///< The platform can't tell without blocking on the server.
+      Connected,    ///< The mount is live.
+      Disconnected, ///< The mount exists but the connection to the server is gone.
+    } state; ///< Connection state.
+  };
+
+  /**
+   * @struct SmartAttribute
+   * @brief A single ATA SMART attribute.
+   */
+  struct SmartAttribute {
+    u8         id;        ///< Attribute ID, e.g. 5 for the reallocated sector count.
+    String     name;      ///< Conventional attribute name, e.g. "Reallocated_Sector_Ct".
+    u8         value;     ///< Current normalized value (higher is better).
+    u8         worst;     ///< Worst normalized value ever recorded.
+    Option<u8> threshold; ///< Failure threshold for the normalized value, if it could be read.
+    u64        raw;       ///< Raw 48-bit value; its meaning is vendor- and attribute-specific.
+  };
+
+  /**
+   * @struct DiskHealth
+   * @brief SMART health information of a physical drive.
+   *
+   * ATA drives report a table of attributes; NVMe drives report a fixed health
+   * log instead, so `attributes` is empty for them.
+   */
+  struct DiskHealth {
+    String device; ///< The device the data was read from.
+
+    enum class Status : u8 {
+      Unknown, ///< The drive didn't report an overall verdict.
+      Passed,  ///< The drive considers itself healthy.
+      Failing, ///< The drive predicts an imminent failure (or an NVMe critical warning is set).
+    } status; ///< Overall health verdict.
+
+    Option<u64>         powerOnHours;       ///< Total time the drive has been powered on.
+    Option<u64>         reallocatedSectors; ///< Number of sectors remapped to spare area (ATA only).
+    Option<f64>         temperatureCelsius; ///< Current drive temperature (NVMe composite, or ATA attribute 194).
+    Vec<SmartAttribute> attributes;         ///< Raw SMART attribute table (ATA only).
+  };
+
+  /**
+   * @struct DiskTemperature
+   * @brief The current temperature of a physical drive.
+   */
+  struct DiskTemperature {
+    String device;  ///< The drive the sensor belongs to, e.g. "nvme0" or "sda" on Linux, or "PhysicalDrive0" on Windows.
+    f64    celsius; ///< Current temperature in degrees Celsius.
+  };
+
+  /**
+   * @struct DiskIOStats
+   * @brief Cumulative I/O counters of a physical drive.
+   *
+   * Counters are totals since boot (or since the drive was attached), so rates
+   * such as IOPS are obtained by sampling twice and taking the difference.
+   */
+  struct DiskIOStats {
+    String device;     ///< The drive, e.g. "sda" or "nvme0n1" on Linux, "disk0" on macOS, or "PhysicalDrive0" on Windows.
+    u64    readOps;    ///< Completed read operations.
+    u64    writeOps;   ///< Completed write operations.
+    u64    readBytes;  ///< Bytes read.
+    u64    writeBytes; ///< Bytes written.
   };
 
   /**
@@ -57,6 +144,24 @@ namespace draconis::utils::types {
       : usedBytes(usedBytes), totalBytes(totalBytes) {}
   };
 
+  /**
+   * @struct MemoryDetails
+   * @brief Represents a detailed breakdown of physical memory.
+   *
+   * Mirrors the columns reported by `free`. Values a platform doesn't
+   * track are reported as 0.
+   */
+  struct MemoryDetails {
+    u64 totalBytes;     ///< Total usable physical memory.
+    u64 usedBytes;      ///< Memory in use (total minus available).
+    u64 freeBytes;      ///< Completely unused memory.
+    u64 availableBytes; ///< Memory available for new allocations without swapping.
+    u64 cachedBytes;    ///< Memory used by the page cache.
+    u64 buffersBytes;   ///< Memory used by kernel block device buffers.
+    u64 sharedBytes;    ///< Memory used by shared memory and tmpfs.
+    u64 kernelBytes;    ///< Memory reserved by the kernel that can't be reclaimed.
+  };
+
   /**
    * @struct MediaInfo
    * @brief Holds structured metadata about currently playing media.
@@ -90,6 +195,144 @@ namespace draconis::utils::types {
       : physical(physical), logical(logical) {}
   };
 
+  /**
+   * @struct CPUFrequency
+   * @brief Represents the clock speeds of a CPU in MHz.
+   *
+   * Platforms don't expose every value, so each one is optional.
+   */
+  struct CPUFrequency {
+    Option<u64> currentMhz; ///< Current clock speed of the first core.
+    Option<u64> baseMhz;    ///< Base (nominal) clock speed.
+    Option<u64> maxMhz;     ///< Maximum (boost) clock speed.
+
+    CPUFrequency() = default;
+
+    CPUFrequency(Option<u64> currentMhz, Option<u64> baseMhz, Option<u64> maxMhz)
+      : currentMhz(currentMhz), baseMhz(baseMhz), maxMhz(maxMhz) {}
+  };
+
+  /**
+   * @struct CPUDetails
+   * @brief Represents the identity and instruction set extensions of a CPU.
+   *
+   * Feature flags use the names from Linux's `/proc/cpuinfo` on every platform, so NEON is reported as "asimd" on AArch64.
+   */
+  struct CPUDetails {
+    String         architecture; ///< Instruction set architecture (e.g. "x86_64", "aarch64").
+    Option<String> vendor;       ///< CPUID vendor string or Arm implementer (e.g. "GenuineIntel", "AuthenticAMD", "Qualcomm").
+    Option<u32>    family;       ///< Family on x86, architecture version on Arm.
+    Option<u32>    model;        ///< Model on x86, part number on Arm.
+    Option<u32>    stepping;     ///< Stepping on x86, revision on Arm.
+    Vec<String>    flags;        ///< Supported extensions (e.g. "avx2", "avx512f", "asimd", "sve").
+  };
+
+  /**
+   * @struct CPUCacheInfo
+   * @brief Represents one level of the CPU cache hierarchy.
+   *
+   * Instances of the same level and type are grouped, so a CPU with a private L2 per core reports a single L2 entry
+   * whose instance count matches its core count.
+   */
+  struct CPUCacheInfo {
+    enum class Type : u8 {
+      Data,        ///< Data cache (L1d).
+      Instruction, ///< Instruction cache (L1i).
+      Unified,     ///< Holds both data and instructions (usually L2 and beyond).
+    } type; ///< What the cache holds.
+
+    u8          level;          ///< Cache level (1 for L1, 2 for L2, ...).
+    u64         sizeBytes;      ///< Size of a single instance.
+    Option<u32> lineSizeBytes;  ///< Cache line size.
+    u32         sharedCpuCount; ///< Logical CPUs sharing one instance (e.g. the threads of a core, or every CPU of a complex).
+    u32         instanceCount;  ///< Number of instances in the system.
+  };
+
+  /**
+   * @struct NumaNode
+   * @brief Represents a NUMA node: a set of CPUs and the memory local to them.
+   */
+  struct NumaNode {
+    u32         id;               ///< Node number, as used by `numactl` and the OS.
+    Option<u64> totalMemoryBytes; ///< Memory attached to the node.
+    Option<u64> freeMemoryBytes;  ///< Unused memory on the node.
+    Vec<u32>    cpus;             ///< Logical CPU numbers belonging to the node, in ascending order.
+  };
+
+  /**
+   * @struct CPUVulnerability
+   * @brief Represents a CPU vulnerability and the state of its mitigation.
+   */
+  struct CPUVulnerability {
+    String name; ///< Linux's name for the vulnerability (e.g. "meltdown", "spectre_v2", "retbleed").
+
+    enum class State : u8 {
+      Unknown,     ///< The OS doesn't know, or its report couldn't be interpreted.
+      NotAffected, ///< The CPU isn't affected.
+      Mitigated,   ///< The CPU is affected, but a mitigation is active.
+      Vulnerable,  ///< The CPU is affected and not mitigated.
+    } state; ///< The mitigation state.
+
+    Option<String> details; ///< The OS's description (e.g. "Mitigation: PTI", "Vulnerable: No microcode").
+  };
+
+  /**
+   * @struct CPUTimes
+   * @brief Represents the cumulative time counters for a single logical CPU.
+   *
+   * The values are monotonic counters in platform-specific units (jiffies,
+   * Mach ticks, or 100ns intervals). Only the difference between two samples
+   * is meaningful.
+   */
+  struct CPUTimes {
+    u64 idle;  ///< Time spent idle (including I/O wait where reported).
+    u64 total; ///< Total time across all states.
+
+    CPUTimes() = default;
+
+    CPUTimes(const u64& idle, const u64& total)
+      : idle(idle), total(total) {}
+  };
+
+  /**
+   * @struct LoadAverage
+   * @brief Represents the system load averages over 1, 5 and 15 minutes.
+   */
+  struct LoadAverage {
+    f64 oneMinute;      ///< Load average over the last minute.
+    f64 fiveMinutes;    ///< Load average over the last 5 minutes.
+    f64 fifteenMinutes; ///< Load average over the last 15 minutes.
+
+    LoadAverage() = default;
+
+    LoadAverage(const f64& oneMinute, const f64& fiveMinutes, const f64& fifteenMinutes)
+      : oneMinute(oneMinute), fiveMinutes(fiveMinutes), fifteenMinutes(fifteenMinutes) {}
+  };
+
+  /**
+   * @struct GPUInfo
+   * @brief Represents a single graphics adapter.
+   */
+  struct GPUInfo {
+    String         vendor;             ///< GPU vendor (e.g., "NVIDIA", "AMD", "Intel", "Apple").
+    String         model;              ///< GPU model name (e.g., "GeForce RTX 4090").
+    Option<u64>    vramBytes;          ///< Dedicated video memory in bytes, if known.
+    Option<String> driver;             ///< Kernel driver name or driver version, if known.
+    bool           isIntegrated;       ///< Whether the GPU is integrated into the CPU/SoC.
+    Option<f64>    temperatureCelsius; ///< Current GPU temperature, if exposed by the platform.
+    Option<f64>    powerWatts;         ///< Current GPU power draw, if exposed by the platform.
+    Option<String> driverVersion;      ///< User-facing driver version (e.g., "Mesa 24.1.3", "552.44"), if known.
+    Option<String> vulkanVersion;      ///< Highest Vulkan version the driver supports (e.g., "1.3.280"), if a Vulkan driver is installed.
+    Option<String> openGLVersion;      ///< Highest OpenGL version, preferring the core profile (e.g., "4.6"), if known.
+    Option<String> directXVersion;     ///< Highest Direct3D feature level (e.g., "12_2"); Windows only.
+    Option<String> metalVersion;       ///< Highest Metal feature set (e.g., "3"); macOS only.
+
+    GPUInfo() = default;
+
+    GPUInfo(String vendor, String model, Option<u64> vramBytes, Option<String> driver, bool isIntegrated, Option<f64> temperatureCelsius = None, Option<f64> powerWatts = None)
+      : vendor(std::move(vendor)), model(std::move(model)), vramBytes(vramBytes), driver(std::move(driver)), isIntegrated(isIntegrated), temperatureCelsius(temperatureCelsius), powerWatts(powerWatts) {}
+  };
+
   /**
    * @struct DisplayInfo
    * @brief Represents a display or monitor device.
@@ -107,6 +350,43 @@ namespace draconis::utils::types {
     f64  refreshRate; ///< Refresh rate in Hz.
     bool isPrimary;   ///< Whether the display is the primary display.
 
+    struct Position {
+      i32 x; ///< Horizontal offset of the top-left corner in the virtual desktop.
+      i32 y; ///< Vertical offset of the top-left corner in the virtual desktop.
+    } position {}; ///< Position in the virtual desktop, in pixels.
+
+    f64         scaleFactor = 1.0; ///< Scaling factor applied by the compositor/OS (e.g. 1.5 for 150%).
+    Option<f64> dpi;               ///< Physical pixel density, if the display reports its physical size.
+    Option<u8>  colorDepth;        ///< Color bits per pixel of the current mode, excluding padding (24 for 8 bits per channel, 30 for 10).
+    u16         rotation = 0;      ///< Clockwise rotation in degrees (0, 90, 180 or 270).
+
+    enum class Connector : u8 {
+      Unknown,     ///< Connector type couldn't be determined.
+      Internal,    ///< Built-in panel whose link type isn't exposed.
+      EDP,         ///< Embedded DisplayPort (most laptop panels).
+      LVDS,        ///< LVDS (older laptop panels).
+      DSI,         ///< MIPI DSI (phones, tablets, SBCs).
+      HDMI,        ///< HDMI.
+      DisplayPort, ///< DisplayPort (including USB-C/Thunderbolt alt mode).
+      DVI,         ///< DVI.
+      VGA,         ///< Analog VGA.
+      Virtual,     ///< Virtual or software output (VMs, remote sessions).
+    } connector = Connector::Unknown; ///< Physical connector type.
+
+    Option<String> manufacturer; ///< Monitor manufacturer (e.g. "Dell"), from the EDID or the platform's display API.
+    Option<String> model;        ///< Monitor model name (e.g. "DELL U2723QE").
+    Option<String> serial;       ///< Monitor serial number, if the display reports one.
+
+    enum class Gamut : u8 {
+      Unknown, ///< Gamut couldn't be determined.
+      SRGB,    ///< sRGB / BT.709.
+      P3,      ///< DCI-P3 / Display P3.
+      BT2020,  ///< BT.2020.
+    } gamut = Gamut::Unknown; ///< Widest color gamut the display advertises (HDR monitors generally advertise BT.2020 input).
+
+    bool         hdrSupported = false; ///< Whether the display accepts HDR (PQ or HLG) signals.
+    Option<bool> hdrEnabled;           ///< Whether HDR output is currently enabled, if the platform exposes it.
+
     DisplayInfo() = default;
 
     DisplayInfo(const usize& identifier, const Resolution& resolution, const f64& refreshRate, const bool& isPrimary)
@@ -118,12 +398,20 @@ namespace draconis::utils::types {
    * @brief Represents a network interface.
    */
   struct NetworkInterface {
-    String         name;        ///< Network interface name.
-    Option<String> ipv4Address; ///< Network interface IPv4 address.
-    Option<String> ipv6Address; ///< Network interface IPv6 address.
-    Option<String> macAddress;  ///< Network interface MAC address.
-    bool           isUp;        ///< Whether the network interface is up.
-    bool           isLoopback;  ///< Whether the network interface is a loopback interface.
+    String         name;          ///< Network interface name.
+    Option<String> ipv4Address;   ///< Network interface IPv4 address.
+    Option<String> ipv6Address;   ///< Network interface IPv6 address.
+    Option<String> macAddress;    ///< Network interface MAC address.
+    bool           isUp;          ///< Whether the network interface is up.
+    bool           isLoopback;    ///< Whether the network interface is a loopback interface.
+    Option<u64>    linkSpeedMbps; ///< Negotiated link speed in Mbit/s, if the link is up and the driver reports it.
+    Option<u32>    mtu;           ///< Maximum transmission unit in bytes.
+
+    enum class Duplex : u8 {
+      Unknown, ///< Duplex mode couldn't be determined (or doesn't apply, e.g. wireless links).
+      Half,    ///< Half duplex.
+      Full,    ///< Full duplex.
+    } duplex = Duplex::Unknown; ///< Negotiated duplex mode.
 
     NetworkInterface() = default;
 
@@ -131,6 +419,25 @@ namespace draconis::utils::types {
       : name(std::move(name)), ipv4Address(std::move(ipv4Address)), ipv6Address(std::move(ipv6Address)), macAddress(std::move(macAddress)), isUp(isUp), isLoopback(isLoopback) {}
   };
 
+  /**
+   * @struct InterfaceStats
+   * @brief Cumulative traffic counters of a network interface.
+   *
+   * Counters are totals since the interface was brought up (or the system
+   * booted), so rates are obtained by sampling twice and taking the difference.
+   */
+  struct InterfaceStats {
+    String name;      ///< Network interface name; matches NetworkInterface::name.
+    u64    rxBytes;   ///< Bytes received.
+    u64    txBytes;   ///< Bytes transmitted.
+    u64    rxPackets; ///< Packets received.
+    u64    txPackets; ///< Packets transmitted.
+    u64    rxErrors;  ///< Receive errors.
+    u64    txErrors;  ///< Transmit errors.
+    u64    rxDropped; ///< Received packets that were dropped.
+    u64    txDropped; ///< Outgoing packets that were dropped.
+  };
+
   /**
    * @struct Battery
    * @brief Represents a battery.
@@ -144,8 +451,10 @@ namespace draconis::utils::types {
       NotPresent,  ///< No battery present.
     } status;      ///< Current battery status.
 
-    Option<u8>                   percentage;    ///< Battery charge percentage (0-100).
-    Option<std::chrono::seconds> timeRemaining; ///< Estimated time remaining in seconds, if available.
+    Option<u8>                   percentage;           ///< Battery charge percentage (0-100).
+    Option<std::chrono::seconds> timeRemaining;        ///< Estimated time remaining in seconds, if available.
+    String                       name;                 ///< Identifier of the battery (e.g. "BAT0", "InternalBattery-0"). Empty if the platform only reports an aggregate.
+    bool                         isPeripheral = false; ///< Whether the battery powers a peripheral (e.g. a mouse or headset) rather than the system.
 
     Battery() = default;
 
@@ -153,6 +462,530 @@ namespace draconis::utils::types {
       : status(status), percentage(percentage), timeRemaining(timeRemaining) {}
   };
 
+  /**
+   * @struct ProcessInfo
+   * @brief Represents a running process.
+   */
+  struct ProcessInfo {
+    enum class State : u8 {
+      Unknown,   ///< Process state is unknown or not reported by the platform.
+      Running,   ///< Process is running or runnable.
+      Sleeping,  ///< Process is in an interruptible sleep.
+      DiskSleep, ///< Process is in an uninterruptible (usually I/O) wait.
+      Stopped,   ///< Process is stopped or being traced.
+      Zombie,    ///< Process has exited but hasn't been reaped.
+      Idle,      ///< Process (usually a kernel thread) is idle.
+    } state;     ///< Current process state.
+
+    u32            pid;        ///< Process ID.
+    u32            ppid;       ///< Parent process ID.
+    String         name;       ///< Executable name.
+    String         cmdline;    ///< Full command line, with arguments separated by spaces. Empty if inaccessible.
+    f64            cpuPercent; ///< Average CPU usage over the process's lifetime, where 100 is one full core.
+    u64            rssBytes;   ///< Resident set size in bytes.
+    Option<String> user;       ///< Name of the owning user, if it could be resolved.
+  };
+
+  /**
+   * @struct SessionInfo
+   * @brief Represents a logged-in user session.
+   */
+  struct SessionInfo {
+    enum class Type : u8 {
+      Unknown,   ///< Session type couldn't be determined.
+      Graphical, ///< Local graphical (X11, Wayland, or console desktop) session.
+      Terminal,  ///< Local text terminal session.
+      Remote,    ///< Remote session (SSH, telnet, or RDP).
+    } type;      ///< Kind of session.
+
+    String                                        username;   ///< Name of the logged-in user.
+    String                                        terminal;   ///< TTY, seat, or window station name (e.g., "tty1", "pts/0", "Console").
+    Option<String>                                remoteHost; ///< Host the session is connected from, for remote sessions.
+    Option<std::chrono::system_clock::time_point> loginTime;  ///< When the session started, if known.
+  };
+
+  /**
+   * @struct DisplaySession
+   * @brief Represents the display server the current process is attached to.
+   */
+  struct DisplaySession {
+    enum class Server : u8 {
+      Unknown, ///< Display server couldn't be determined.
+      Wayland, ///< Wayland session (X11 clients run through Xwayland).
+      X11,     ///< Native X11 session.
+      Windows, ///< Windows Desktop Window Manager.
+      Quartz,  ///< macOS Quartz Compositor.
+      Tty,     ///< Text console, no display server.
+    } server;  ///< Kind of display server.
+
+    Option<String> compositor; ///< Wayland compositor name (e.g., "KWin", "Hyprland"), if on Wayland and detectable.
+  };
+
+  /**
+   * @struct ThemeInfo
+   * @brief Represents the desktop appearance settings of the current user.
+   *
+   * Each field is empty if the platform or desktop doesn't expose it.
+   */
+  struct ThemeInfo {
+    Option<String> widgetTheme; ///< GTK theme, Qt widget style, or light/dark mode (e.g., "Breeze", "Adwaita", "Dark").
+    Option<String> iconTheme;   ///< Icon theme (e.g., "Papirus").
+    Option<String> cursorTheme; ///< Cursor theme or scheme (e.g., "breeze_cursors").
+    Option<String> font;        ///< System UI font as "Family Size" (e.g., "Noto Sans 10").
+  };
+
+  /**
+   * @struct DefaultApplications
+   * @brief Represents the applications the user has chosen for editing text, browsing the web, and running a shell.
+   *
+   * Each field is empty if the user hasn't configured one that can be detected.
+   */
+  struct DefaultApplications {
+    Option<String> editor;   ///< Editor command or name (e.g., "nvim", "code --wait", "Notepad").
+    Option<String> browser;  ///< Web browser name or command (e.g., "Firefox", "Google Chrome").
+    Option<String> terminal; ///< Terminal emulator name or command (e.g., "kitty", "Windows Terminal").
+  };
+
+  /**
+   * @struct AudioDevice
+   * @brief Represents an audio output (sink) or input (source) device.
+   */
+  struct AudioDevice {
+    enum class Direction : u8 {
+      Output, ///< Playback device (speakers, headphones, HDMI).
+      Input,  ///< Capture device (microphone, line-in).
+    } direction; ///< Whether the device plays or captures audio.
+
+    String      id;         ///< Stable backend identifier (PulseAudio name, CoreAudio UID, or WASAPI endpoint ID).
+    String      name;       ///< Human-readable device name.
+    bool        isDefault;  ///< Whether this is the default device for its direction.
+    Option<u32> channels;   ///< Number of channels, if known.
+    Option<u32> sampleRate; ///< Sample rate in Hz, if known.
+  };
+
+  /**
+   * @struct VolumeInfo
+   * @brief Represents the volume of the default audio output device.
+   */
+  struct VolumeInfo {
+    f64  percent; ///< Volume as a percentage; may exceed 100 where the backend allows amplification.
+    bool muted;   ///< Whether the device is muted.
+  };
+
+  /**
+   * @struct BluetoothAdapter
+   * @brief Represents a local Bluetooth controller.
+   */
+  struct BluetoothAdapter {
+    String name;    ///< Adapter name (alias on BlueZ, radio name on Windows).
+    String address; ///< Hardware address, e.g. "AA:BB:CC:DD:EE:FF".
+    bool   powered; ///< Whether the radio is switched on.
+  };
+
+  /**
+   * @struct BluetoothDevice
+   * @brief Represents a remote Bluetooth device known to the system.
+   */
+  struct BluetoothDevice {
+    String     name;           ///< Device name (user alias if one is set).
+    String     address;        ///< Hardware address, e.g. "AA:BB:CC:DD:EE:FF".
+    bool       paired;         ///< Whether the device is paired with this system.
+    bool       connected;      ///< Whether the device is currently connected.
+    Option<u8> batteryPercent; ///< Battery level reported by the device, if available.
+  };
+
+  /**
+   * @struct BluetoothInfo
+   * @brief Bluetooth adapters and the paired or connected devices they know about.
+   */
+  struct BluetoothInfo {
+    Vec<BluetoothAdapter> adapters; ///< Local controllers.
+    Vec<BluetoothDevice>  devices;  ///< Paired or connected remote devices.
+  };
+
+  /**
+   * @struct PrinterInfo
+   * @brief Represents a print queue known to the system.
+   */
+  struct PrinterInfo {
+    enum class State : u8 {
+      Unknown,  ///< State could not be determined.
+      Idle,     ///< Ready and not printing.
+      Printing, ///< Processing a job.
+      Error,    ///< Stopped, paused, offline, or reporting an error condition (e.g. out of paper).
+    } state = State::Unknown; ///< Current state of the queue.
+
+    String         name;       ///< Queue name, as passed to the print commands.
+    Option<String> driver;     ///< Driver or make and model (e.g. "HP LaserJet Pro M404").
+    Option<String> connection; ///< Device URI on CUPS (e.g. "usb://HP/...", "ipp://..."), or the port name on Windows.
+    bool           isDefault;  ///< Whether this is the user's default printer.
+  };
+
+  /**
+   * @struct CameraInfo
+   * @brief Represents a video capture device, such as a webcam.
+   */
+  struct CameraInfo {
+    String name;  ///< Device name (e.g. "Integrated Camera", "FaceTime HD Camera").
+    String id;    ///< Stable identifier: the device node on Linux, the AVFoundation unique ID on macOS, or the interface path on Windows.
+    bool   inUse; ///< Whether another process is currently capturing from the device.
+  };
+
+  /**
+   * @struct WifiInfo
+   * @brief Represents the connection of the active wireless interface.
+   *
+   * Fields the platform doesn't report (or hides, e.g. the SSID on macOS
+   * without location permission) are left empty.
+   */
+  struct WifiInfo {
+    String         interfaceName; ///< Name of the wireless interface; matches NetworkInterface::name.
+    Option<String> ssid;          ///< Network name.
+    Option<String> bssid;         ///< Access point hardware address, e.g. "AA:BB:CC:DD:EE:FF".
+    Option<i32>    signalDbm;     ///< Received signal strength in dBm.
+    Option<u8>     signalPercent; ///< Signal quality as a percentage.
+    Option<u32>    frequencyMhz;  ///< Channel centre frequency in MHz.
+
+    enum class Band : u8 {
+      Unknown, ///< Band couldn't be determined.
+      GHz2_4,  ///< 2.4 GHz.
+      GHz5,    ///< 5 GHz.
+      GHz6,    ///< 6 GHz.
+    } band; ///< Frequency band of the current channel.
+
+    Option<f64> linkRateMbps; ///< Current transmit link rate in Mbit/s.
+  };
+
+  /**
+   * @struct Gateway
+   * @brief Represents the next hop of the system's default route.
+   *
+   * The IPv4 default route is preferred; the IPv6 one is used only when
+   * there is no IPv4 default route.
+   */
+  struct Gateway {
+    String address;       ///< Gateway address, e.g. "192.168.1.1" or "fe80::1".
+    String interfaceName; ///< Interface the default route goes through; matches NetworkInterface::name.
+  };
+
+  /**
+   * @struct UserInfo
+   * @brief Represents the user account the current process runs as.
+   *
+   * POSIX systems identify users by uid/gid, while Windows uses a SID,
+   * so only the fields relevant to the platform are set.
+   */
+  struct UserInfo {
+    String         username;      ///< Login name.
+    Option<u32>    uid;           ///< User ID (POSIX only).
+    Option<u32>    gid;           ///< Primary group ID (POSIX only).
+    Option<String> sid;           ///< Security identifier, e.g. "S-1-5-21-..." (Windows only).
+    String         homeDirectory; ///< Path to the user's home or profile directory.
+    Option<String> shell;         ///< Path to the login shell, if the platform has one.
+  };
+
+  /**
+   * @struct TimezoneInfo
+   * @brief Represents the system's local timezone.
+   */
+  struct TimezoneInfo {
+    String name;             ///< IANA timezone name (e.g., "Europe/Berlin").
+    i32    utcOffsetSeconds; ///< Offset from UTC currently in effect, including DST.
+  };
+
+  /**
+   * @struct KeyboardLayout
+   * @brief Represents a configured keyboard layout (XKB layout, Windows input locale, macOS input source).
+   */
+  struct KeyboardLayout {
+    String         name;        ///< Layout identifier (e.g., "us", "en-US", "U.S.").
+    Option<String> variant;     ///< Layout variant, if one is set (e.g., "dvorak").
+    Option<String> description; ///< Human-readable name, if the platform provides one.
+    bool           active;      ///< Whether this is the layout currently in use.
+  };
+
+  /**
+   * @struct FirmwareInfo
+   * @brief Represents the motherboard and the system firmware (BIOS/UEFI).
+   *
+   * Board fields that only contain an OEM placeholder (e.g. "To Be Filled By O.E.M.")
+   * are left empty.
+   */
+  struct FirmwareInfo {
+    Option<String> boardVendor;  ///< Motherboard manufacturer, e.g. "ASUSTeK COMPUTER INC.".
+    Option<String> boardModel;   ///< Motherboard model, e.g. "ROG STRIX B550-F GAMING".
+    Option<String> boardVersion; ///< Motherboard revision, e.g. "Rev 1.xx".
+    Option<String> vendor;       ///< Firmware vendor, e.g. "American Megatrends Inc.".
+    Option<String> version;      ///< Firmware version, e.g. "2803".
+    Option<String> releaseDate;  ///< Firmware release date as the firmware reports it, e.g. "04/27/2022".
+    bool           isUefi;       ///< Whether the system booted through UEFI rather than legacy BIOS.
+  };
+
+  /**
+   * @struct VirtEnv
+   * @brief Represents the virtualization and containerization the process runs under.
+   *
+   * The two are independent: a Docker container inside a KVM guest reports both. Bare metal
+   * is reported as `Hypervisor::None` with `Container::None`.
+   */
+  struct VirtEnv {
+    enum class Hypervisor : u8 {
+      None,       ///< Running on bare metal.
+      Kvm,        ///< Linux KVM (including QEMU with KVM acceleration).
+      Qemu,       ///< QEMU with software emulation (TCG).
+      HyperV,     ///< Microsoft Hyper-V (including WSL2 and Azure).
+      VMware,     ///< VMware Workstation, Fusion, or ESXi.
+      Parallels,  ///< Parallels Desktop.
+      VirtualBox, ///< Oracle VirtualBox.
+      Xen,        ///< Xen.
+      Apple,      ///< Apple Virtualization framework.
+      Other,      ///< A hypervisor was detected, but isn't one of the above.
+    } hypervisor = Hypervisor::None; ///< Hypervisor the system runs under.
+
+    enum class Container : u8 {
+      None,   ///< Not running in a container.
+      Docker, ///< Docker.
+      Podman, ///< Podman.
+      Lxc,    ///< LXC / LXD / Incus.
+      Wsl,    ///< Windows Subsystem for Linux.
+      Other,  ///< A container was detected, but isn't one of the above.
+    } container = Container::None; ///< Container runtime the process runs in.
+
+    Option<String> hypervisorVendor; ///< Raw vendor identification (e.g. the CPUID signature "KVMKVMKVM"), if one was found.
+  };
+
+  /**
+   * @struct SecurityPlatformInfo
+   * @brief Represents the boot-integrity features of the platform: Secure Boot and the TPM.
+   */
+  struct SecurityPlatformInfo {
+    enum class SecureBoot : u8 {
+      Unknown,     ///< The state couldn't be read.
+      Unsupported, ///< The system booted through legacy BIOS, where Secure Boot doesn't exist.
+      Disabled,    ///< UEFI boot with Secure Boot turned off (or in setup mode).
+      Enabled,     ///< UEFI boot with Secure Boot enforcing signatures.
+    } secureBoot = SecureBoot::Unknown; ///< Secure Boot state of the current boot.
+
+    bool           tpmPresent   = false; ///< Whether a TPM is available to the operating system.
+    Option<String> tpmVersion;           ///< TPM specification version ("2.0" or "1.2"), if a TPM is present and reports it.
+    bool           measuredBoot = false; ///< Whether the firmware recorded a TPM event log of the boot (measured boot).
+  };
+
+  /**
+   * @struct MacStatus
+   * @brief Represents the mandatory access control (MAC) framework the kernel enforces, e.g. SELinux or AppArmor.
+   */
+  struct MacStatus {
+    enum class Framework : u8 {
+      None,     ///< No supported MAC framework is present.
+      SELinux,  ///< Security-Enhanced Linux.
+      AppArmor, ///< AppArmor.
+    } framework = Framework::None; ///< Framework present on the system.
+
+    enum class Mode : u8 {
+      Disabled,   ///< Present but not enforcing or logging anything.
+      Permissive, ///< Denials are only logged (SELinux permissive, AppArmor complain mode).
+      Enforcing,  ///< Denials are enforced.
+    } mode = Mode::Disabled; ///< Current mode of the framework.
+
+    Option<String> policy; ///< Loaded SELinux policy type (e.g. "targeted"); AppArmor has no single policy name, so None there.
+  };
+
+  /**
+   * @struct FirewallProfile
+   * @brief A set of firewall rules that can be switched on or off as a unit.
+   */
+  struct FirewallProfile {
+    String name;    ///< Profile name, e.g. "Public" (Windows), "public" (firewalld zone), or "default".
+    bool   enabled; ///< Whether the profile is active.
+  };
+
+  /**
+   * @struct FirewallStatus
+   * @brief Represents the host firewall and whether each of its profiles is enabled.
+   */
+  struct FirewallStatus {
+    enum class Backend : u8 {
+      Firewalld,       ///< firewalld; the profile is the default zone.
+      Ufw,             ///< Uncomplicated Firewall.
+      Nftables,        ///< A plain nftables ruleset loaded by nftables.service.
+      WindowsFirewall, ///< Windows Defender Firewall, with Domain, Private and Public profiles.
+      Alf,             ///< The macOS Application Firewall.
+    } backend; ///< Firewall managing the host.
+
+    Vec<FirewallProfile> profiles; ///< Profiles of the firewall; a firewall without profiles reports a single "default" one.
+  };
+
+  /**
+   * @struct DomainInfo
+   * @brief Represents the machine's Active Directory domain or workgroup membership, and its Azure AD (Entra ID) join state.
+   */
+  struct DomainInfo {
+    enum class Membership : u8 {
+      Unjoined,  ///< Neither in a workgroup nor joined to a domain.
+      Workgroup, ///< A member of a workgroup.
+      Domain,    ///< Joined to an Active Directory domain.
+    } membership = Membership::Unjoined; ///< How the machine belongs to a network.
+
+    Option<String> name;                  ///< NetBIOS name of the domain or workgroup (e.g., "CORP", "WORKGROUP").
+    Option<String> dnsDomain;             ///< DNS name of the Active Directory domain (e.g., "corp.example.com"), if joined to one.
+    bool           azureAdJoined = false; ///< Whether the machine is joined to Azure AD, either alone or hybrid with a domain.
+    Option<String> azureTenant;           ///< Display name of the Azure AD tenant, if joined to one.
+  };
+
+  /**
+   * @struct KernelModule
+   * @brief A loadable kernel module (Linux), kernel extension (macOS), or driver (Windows) currently loaded.
+   */
+  struct KernelModule {
+    String      name;      ///< Module name, e.g. "nvidia" (Linux), "com.apple.iokit.IOUSBFamily" (macOS), or "nvlddmkm.sys" (Windows).
+    Option<u64> sizeBytes; ///< Size of the module in memory, if the platform reports it.
+  };
+
+  /**
+   * @struct InitSystem
+   * @brief Represents the init system / service manager running as the root of the process tree.
+   */
+  struct InitSystem {
+    enum class Kind : u8 {
+      Unknown,               ///< Not one of the systems below (e.g. a container entrypoint running as PID 1).
+      Systemd,               ///< systemd.
+      OpenRC,                ///< OpenRC, either with openrc-init or on top of SysV init.
+      Runit,                 ///< runit.
+      S6,                    ///< s6 / s6-rc.
+      Dinit,                 ///< Dinit.
+      SysVinit,              ///< Plain SysV init.
+      Launchd,               ///< macOS launchd.
+      ServiceControlManager, ///< The Windows Service Control Manager.
+    } kind; ///< Which init system is running.
+
+    String name; ///< Name of the process running as init (e.g. "systemd", "launchd", "services.exe").
+  };
+
+  /**
+   * @struct ServiceInfo
+   * @brief Represents a system service (systemd unit, OpenRC service, launchd job, or Windows service).
+   */
+  struct ServiceInfo {
+    enum class State : u8 {
+      Unknown,  ///< The service manager reported a state not listed below.
+      Running,  ///< The service is active.
+      Stopped,  ///< The service is not running.
+      Starting, ///< The service is being started (or reloaded).
+      Stopping, ///< The service is being stopped.
+      Failed,   ///< The service stopped because of an error.
+    } state; ///< Current state of the service.
+
+    String         name;        ///< Name used to address the service (e.g. "sshd.service", "com.openssh.sshd", "Spooler").
+    Option<String> description; ///< Human-readable description or display name, if the service manager has one.
+    Option<bool>   enabled;     ///< Whether the service starts at boot, if the service manager tracks that.
+  };
+
+  /**
+   * @struct ContainerInfo
+   * @brief Represents a running container managed by Docker, Podman, or LXC.
+   */
+  struct ContainerInfo {
+    enum class Runtime : u8 {
+      Docker, ///< Docker Engine (or Docker Desktop).
+      Podman, ///< Podman, rootful or rootless.
+      Lxc,    ///< LXC.
+    } runtime; ///< Runtime managing the container.
+
+    enum class State : u8 {
+      Unknown,    ///< The runtime reported a state not listed below.
+      Running,    ///< The container's processes are running.
+      Paused,     ///< The container's processes are frozen.
+      Restarting, ///< The container is being restarted.
+    } state; ///< Current state of the container.
+
+    String         id;          ///< Runtime-assigned container ID (the container name for LXC).
+    String         name;        ///< Container name, without Docker's leading slash.
+    Option<String> image;       ///< Image the container was created from. Not available for LXC.
+    Option<f64>    cpuPercent;  ///< Average CPU usage over the container's lifetime, where 100.0 is one full core. Linux only.
+    Option<u64>    memoryBytes; ///< Memory charged to the container's cgroup. Linux only.
+  };
+
+  /**
+   * @struct FanInfo
+   * @brief Represents a fan reported by a hardware monitoring sensor.
+   */
+  struct FanInfo {
+    String      label;     ///< Sensor label (e.g. "CPU Fan", "thinkpad fan1", "Fan 1").
+    Option<u32> rpm;       ///< Current speed in RPM. Stopped fans report 0; not available from WMI.
+    Option<u32> targetRpm; ///< Speed the fan controller is aiming for, if it exposes one.
+  };
+
+  /**
+   * @struct SensorReading
+   * @brief Represents one channel of a hardware monitoring chip, as listed by `sensors`.
+   */
+  struct SensorReading {
+    enum class Kind : u8 {
+      Temperature, ///< Degrees Celsius.
+      Voltage,     ///< Volts.
+      Current,     ///< Amperes.
+      Power,       ///< Watts.
+      Fan,         ///< Revolutions per minute.
+    } kind; ///< What the sensor measures, which also determines the unit of `value`.
+
+    String chip;  ///< Chip or driver the sensor belongs to (e.g. "coretemp", "nct6798", "AppleSMC").
+    String label; ///< Channel label (e.g. "Package id 0", "in0", "TC0P").
+    f64    value; ///< Current reading, in the unit implied by `kind`.
+  };
+
+  /**
+   * @struct PowerProfile
+   * @brief Represents the active power profile and CPU frequency governor.
+   */
+  struct PowerProfile {
+    enum class Mode : u8 {
+      Unknown,     ///< No profile is active, or the platform's profile doesn't map onto the others.
+      PowerSaver,  ///< Favours battery life (e.g. "power-saver", Windows "Power saver", macOS Low Power Mode).
+      Balanced,    ///< The platform default.
+      Performance, ///< Favours performance over power draw.
+    } mode; ///< The active profile.
+
+    Option<String> name;     ///< The platform's name for the profile (e.g. "power-saver", "Ultimate Performance").
+    Option<String> governor; ///< The cpufreq scaling governor of the first CPU (e.g. "powersave", "schedutil"). Linux only.
+  };
+
+  /**
+   * @struct PowerSource
+   * @brief Represents the external power supply the system is drawing from.
+   */
+  struct PowerSource {
+    bool        acConnected = false; ///< Whether external power (mains or USB) is connected.
+    Option<f64> chargerWatts;        ///< Rated or negotiated wattage of the connected charger, if reported.
+    Option<f64> usbPdWatts;          ///< Power negotiated over USB Power Delivery, if the charger is a USB-PD source.
+  };
+
+  /**
+   * @struct BacklightInfo
+   * @brief Represents the brightness of a display's backlight.
+   */
+  struct BacklightInfo {
+    String device;  ///< Backlight or display name (e.g. "intel_backlight", "DP-1", "Built-in Display").
+    f64    percent; ///< Current brightness, from 0 to 100.
+  };
+
+  /**
+   * @struct SystemEvent
+   * @brief A change the operating system reported to a SystemWatcher.
+   */
+  struct SystemEvent {
+    enum class Kind : u8 {
+      DeviceAdded,    ///< A USB device or disk was attached.
+      DeviceRemoved,  ///< A USB device or disk was detached.
+      BatteryChanged, ///< A battery's charge or status changed, or external power was connected or disconnected.
+      NetworkUp,      ///< A network interface gained its link.
+      NetworkDown,    ///< A network interface lost its link, or was removed.
+      DisplayChanged, ///< A display was connected, disconnected, or reconfigured.
+    } kind; ///< What changed.
+
+    Option<String> source; ///< The device, power supply, or interface that changed, if the platform says which.
+  };
+
   /**
    * @struct BytesToGiB
    * @brief Represents a value in bytes converted to gibibytes.
@@ -35,6 +35,7 @@ namespace draconis::utils::error {
     ResourceExhausted,  ///< System resource limit reached (not memory).
     Timeout,            ///< An operation timed out (e.g., waiting for IPC reply).
     UnavailableFeature, ///< Feature not present on this hardware/OS.
+    Cancelled,          ///< The operation was cancelled before it finished. Out of order so the C API's values stay put.
   };
 
   /**
@@ -212,6 +212,12 @@ auto main(const i32 argc, CStr* argv[]) -> i32 try {
 
   cache.setGlobalPolicy(CachePolicy::tempDirectory());
 
+#if defined(__linux__) || defined(__APPLE__) || defined(_WIN32)
+  // Discards persisted values such as the GPU model if the hardware changed since they were cached.
+  if (Result<String> fingerprint = GetHardwareFingerprint())
+    cache.setFingerprint(std::move(*fingerprint));
+#endif
+
   if (opts.clearCache) {
     const usize removedCount = cache.invalidateAll(true);
 
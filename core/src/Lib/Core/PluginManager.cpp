@@ -7,9 +7,12 @@
 
 #if DRAC_ENABLE_PLUGINS
 
-  #include <format>   // std::format
-  #include <optional> // std::optional
-  #include <string>   // std::string
+  #include <array>     // std::array
+  #include <charconv>  // std::from_chars
+  #include <cstring>   // std::memcpy, std::strerror
+  #include <format>    // std::format
+  #include <optional>  // std::optional
+  #include <string>    // std::string
 
   #include <Drac++/Core/PluginManager.hpp>
   #include <Drac++/Core/StaticPlugins.hpp>
@@ -22,13 +25,29 @@
   #ifdef _WIN32
     #include <windows.h>
   #else
-    #include <dlfcn.h> // dlopen, dlsym, dlclose
+    #include <cerrno>         // errno
+    #include <csignal>        // kill, signal, SIGKILL, SIGXCPU
+    #include <dlfcn.h>        // dlopen, dlsym, dlclose
+    #include <fcntl.h>        // fcntl
+    #include <poll.h>         // poll
+    #include <spawn.h>        // posix_spawn
+    #include <sys/resource.h> // getrlimit, setrlimit, getrusage
+    #include <sys/socket.h>   // socketpair, send, recv
+    #include <sys/wait.h>     // waitpid
+    #include <unistd.h>       // close
+
+extern char** environ; // NOLINT(readability-redundant-declaration)
   #endif
 
 namespace draconis::core::plugin {
   namespace {
+    using utils::error::DracError;
     using utils::error::DracErrorCode;
+    using utils::types::f64;
+    using utils::types::i64;
     using utils::types::StringView;
+    using utils::types::u8;
+    using utils::types::usize;
     using enum DracErrorCode;
 
     // Platform-specific plugin file extension
@@ -40,54 +59,68 @@ namespace draconis::core::plugin {
     constexpr StringView PLUGIN_EXTENSION = ".so";
   #endif
 
-    // Default search paths for plugins
-    auto GetDefaultPluginPaths() -> const Vec<fs::path>& {
-      static const Vec<fs::path> DEFAULT_PLUGIN_PATHS = []() -> Vec<fs::path> {
-        using draconis::utils::env::GetEnv;
+    auto GetDataDir() -> fs::path;
+
+    // Appends the non-empty entries of a PATH-style list
+    auto AppendPathList(Vec<fs::path>& paths, const String& value, const char separator) -> Unit {
+      for (String::size_type start = 0; start <= value.size();) {
+        String::size_type end = value.find(separator, start);
+        if (end == String::npos)
+          end = value.size();
+        if (end > start)
+          paths.emplace_back(value.substr(start, end - start));
+        start = end + 1;
+      }
+    }
+
+    // Default search paths for plugins, read from the environment on each call
+    auto GetDefaultPluginPaths() -> Vec<fs::path> {
+      using draconis::utils::env::GetEnv;
 
-        Vec<fs::path> paths;
+      Vec<fs::path> paths;
 
-        // Paths from DRAC_PLUGIN_PATH take priority (colon-separated on Unix,
-        // semicolon-separated on Windows). Needed on systems without the FHS
-        // directories below (e.g. Nix) and handy for plugin development.
+      // Paths from DRAC_PLUGIN_PATH take priority (colon-separated on Unix,
+      // semicolon-separated on Windows). Needed on systems without the FHS
+      // directories below (e.g. Nix) and handy for plugin development.
   #ifdef _WIN32
-        constexpr char PATH_SEPARATOR = ';';
+      constexpr char PATH_SEPARATOR = ';';
   #else
-        constexpr char PATH_SEPARATOR = ':';
+      constexpr char PATH_SEPARATOR = ':';
   #endif
-        if (auto result = GetEnv("DRAC_PLUGIN_PATH")) {
-          const String& value = *result;
-          for (String::size_type start = 0; start <= value.size();) {
-            String::size_type end = value.find(PATH_SEPARATOR, start);
-            if (end == String::npos)
-              end = value.size();
-            if (end > start)
-              paths.emplace_back(value.substr(start, end - start));
-            start = end + 1;
-          }
-        }
+      if (auto result = GetEnv("DRAC_PLUGIN_PATH"))
+        AppendPathList(paths, *result, PATH_SEPARATOR);
 
   #ifdef _WIN32
-        if (auto result = GetEnv("LOCALAPPDATA"))
-          paths.push_back(fs::path(*result) / "draconis++" / "plugins");
+      if (auto result = GetEnv("LOCALAPPDATA"))
+        paths.push_back(fs::path(*result) / "draconis++" / "plugins");
 
-        if (auto result = GetEnv("APPDATA"))
-          paths.push_back(fs::path(*result) / "draconis++" / "plugins");
+      if (auto result = GetEnv("APPDATA"))
+        paths.push_back(fs::path(*result) / "draconis++" / "plugins");
 
-        if (auto result = GetEnv("USERPROFILE"))
-          paths.push_back(fs::path(*result) / ".config" / "draconis++" / "plugins");
+      if (auto result = GetEnv("USERPROFILE"))
+        paths.push_back(fs::path(*result) / ".config" / "draconis++" / "plugins");
 
-        paths.push_back(fs::current_path() / "plugins");
+      paths.push_back(fs::current_path() / "plugins");
   #else
-        paths.push_back(fs::path("/usr/local/lib/draconis++/plugins"));
-        paths.push_back(fs::path("/usr/lib/draconis++/plugins"));
-        paths.push_back(fs::path(getenv("HOME") ? getenv("HOME") : "") / ".local/lib/draconis++/plugins");
-        paths.push_back(fs::current_path() / "plugins");
+      // $XDG_DATA_HOME/draconis++/plugins, for plugins installed by the user
+      paths.push_back(GetDataDir() / "plugins");
+
+      paths.push_back(fs::path("/usr/local/lib/draconis++/plugins"));
+      paths.push_back(fs::path("/usr/lib/draconis++/plugins"));
+      paths.push_back(fs::path(getenv("HOME") ? getenv("HOME") : "") / ".local/lib/draconis++/plugins");
+
+      // $XDG_DATA_DIRS/draconis++/plugins, where package managers may install them
+      Vec<fs::path> dataDirs;
+      if (auto result = GetEnv("XDG_DATA_DIRS"))
+        AppendPathList(dataDirs, *result, PATH_SEPARATOR);
+      if (dataDirs.empty())
+        dataDirs = { "/usr/local/share", "/usr/share" };
+      for (const fs::path& dataDir : dataDirs)
+        paths.push_back(dataDir / "draconis++" / "plugins");
+
+      paths.push_back(fs::current_path() / "plugins");
   #endif
-        return paths;
-      }();
-
-      return DEFAULT_PLUGIN_PATHS;
+      return paths;
     }
 
     // Get the base config directory for draconis++
@@ -139,6 +172,335 @@ namespace draconis::core::plugin {
       return GetConfigDir() / "data";
   #endif
     }
+
+    auto DescribePlugin(const IPlugin& plugin, const bool isStatic) -> PluginDescription {
+      PluginDescription description {
+        .metadata     = plugin.getMetadata(),
+        .capabilities = {},
+        .configSchema = plugin.getConfigSchema(),
+        .isStatic     = isStatic,
+      };
+
+      if (const auto* infoProvider = dynamic_cast<const IInfoProviderPlugin*>(&plugin))
+        description.capabilities.push_back(infoProvider->getProviderId());
+      else if (const auto* outputFormat = dynamic_cast<const IOutputFormatPlugin*>(&plugin)) {
+        const Span<const String> formatNames = outputFormat->getFormatNames();
+        description.capabilities.assign(formatNames.begin(), formatNames.end());
+      }
+
+      return description;
+    }
+
+    // A sandboxed plugin's helper talks to its host over a socket on Unix and a pair of pipes on
+    // Windows. Each message is a u64 length followed by the body. Requests start with their kind,
+    // responses with a status byte followed by any result or by an error code and message. Values
+    // are tagged with their PluginFieldType.
+    constexpr char SANDBOX_OK    = 0;
+    constexpr char SANDBOX_ERROR = 1;
+
+    constexpr char SANDBOX_CONFIGURE = 0;
+    constexpr char SANDBOX_COLLECT   = 1;
+
+  #ifdef _WIN32
+    using SandboxPipe = HANDLE;
+
+    constexpr SandboxPipe NO_PIPE = nullptr;
+  #else
+    using SandboxPipe = int;
+
+    constexpr SandboxPipe NO_PIPE = -1;
+
+    // Descriptor the helper finds its end of the socket on
+    constexpr int SANDBOX_CHANNEL_FD = 3;
+
+    // Sending to a helper that died has to fail rather than raise SIGPIPE in the host
+    #ifdef MSG_NOSIGNAL
+    constexpr int SANDBOX_SEND_FLAGS = MSG_NOSIGNAL;
+    #else
+    constexpr int SANDBOX_SEND_FLAGS = 0;
+    #endif
+  #endif
+
+    template <typename T>
+    auto EncodeRaw(String& out, const T value) -> Unit {
+      out.append(reinterpret_cast<const char*>(&value), sizeof(T)); // NOLINT(cppcoreguidelines-pro-type-reinterpret-cast)
+    }
+
+    auto EncodeString(String& out, const StringView value) -> Unit {
+      EncodeRaw<u64>(out, value.size());
+      out.append(value);
+    }
+
+    auto EncodeFields(String& out, const PluginFields& fields) -> Unit;
+
+    auto EncodeValue(String& out, const PluginFieldValue& value) -> Unit {
+      out.push_back(static_cast<char>(value.index()));
+
+      std::visit(
+        [&out](const auto& inner) {
+          using T = std::decay_t<decltype(inner)>;
+
+          if constexpr (std::same_as<T, String>)
+            EncodeString(out, inner);
+          else if constexpr (std::same_as<T, PluginFieldArray>) {
+            EncodeRaw<u64>(out, inner.size());
+            for (const PluginFieldValue& item : inner)
+              EncodeValue(out, item);
+          } else if constexpr (std::same_as<T, PluginFieldObject>)
+            EncodeFields(out, inner);
+          else if constexpr (std::same_as<T, PluginTimestamp>)
+            EncodeRaw(out, inner.time_since_epoch().count());
+          else if constexpr (!std::same_as<T, std::nullptr_t>)
+            EncodeRaw(out, inner);
+        },
+        static_cast<const PluginFieldValueBase&>(value)
+      );
+    }
+
+    auto EncodeFields(String& out, const PluginFields& fields) -> Unit {
+      EncodeRaw<u64>(out, fields.size());
+      for (const auto& [key, value] : fields) {
+        EncodeString(out, key);
+        EncodeValue(out, value);
+      }
+    }
+
+    auto EncodeError(const DracError& error) -> String {
+      String out(1, SANDBOX_ERROR);
+      out.push_back(static_cast<char>(error.code));
+      EncodeString(out, error.message);
+      return out;
+    }
+
+    // Reads back what the Encode* functions wrote, failing on truncated or malformed input.
+    class SandboxReader {
+      StringView m_data;
+
+     public:
+      explicit SandboxReader(const StringView data) : m_data(data) {}
+
+      // Whatever hasn't been read yet
+      [[nodiscard]] auto rest() const -> StringView {
+        return m_data;
+      }
+
+      template <typename T>
+      auto raw() -> Option<T> {
+        if (m_data.size() < sizeof(T))
+          return None;
+        T value;
+        std::memcpy(&value, m_data.data(), sizeof(T));
+        m_data.remove_prefix(sizeof(T));
+        return value;
+      }
+
+      auto string() -> Option<String> {
+        Option<u64> size = raw<u64>();
+        if (!size || m_data.size() < *size)
+          return None;
+        String value(m_data.substr(0, *size));
+        m_data.remove_prefix(*size);
+        return value;
+      }
+
+      auto value() -> Option<PluginFieldValue> {
+        Option<char> tag = raw<char>();
+        if (!tag)
+          return None;
+
+        switch (static_cast<PluginFieldType>(*tag)) {
+          case PluginFieldType::Bool:
+            // Read as a byte; copying an arbitrary byte into a bool is undefined
+            if (Option<u8> value = raw<u8>())
+              return PluginFieldValue(*value != 0);
+            return None;
+          case PluginFieldType::I64:
+            if (Option<i64> value = raw<i64>())
+              return PluginFieldValue(*value);
+            return None;
+          case PluginFieldType::U64:
+            if (Option<u64> value = raw<u64>())
+              return PluginFieldValue(*value);
+            return None;
+          case PluginFieldType::F64:
+            if (Option<f64> value = raw<f64>())
+              return PluginFieldValue(*value);
+            return None;
+          case PluginFieldType::String:
+            if (Option<String> value = string())
+              return PluginFieldValue(std::move(*value));
+            return None;
+          case PluginFieldType::Array: {
+            Option<u64> size = raw<u64>();
+            if (!size)
+              return None;
+            PluginFieldArray array;
+            for (u64 i = 0; i < *size; ++i) {
+              Option<PluginFieldValue> item = value();
+              if (!item)
+                return None;
+              array.push_back(std::move(*item));
+            }
+            return PluginFieldValue(std::move(array));
+          }
+          case PluginFieldType::Object:
+            if (Option<PluginFields> object = fields())
+              return PluginFieldValue(std::move(*object));
+            return None;
+          case PluginFieldType::Timestamp:
+            if (Option<PluginTimestamp::rep> value = raw<PluginTimestamp::rep>())
+              return PluginFieldValue(PluginTimestamp(PluginTimestamp::duration(*value)));
+            return None;
+          case PluginFieldType::Null:
+            return PluginFieldValue(nullptr);
+        }
+
+        return None;
+      }
+
+      auto fields() -> Option<PluginFields> {
+        Option<u64> size = raw<u64>();
+        if (!size)
+          return None;
+        PluginFields fields;
+        for (u64 i = 0; i < *size; ++i) {
+          Option<String>           key   = string();
+          Option<PluginFieldValue> value = key ? this->value() : None;
+          if (!value)
+            return None;
+          fields.insert_or_assign(std::move(*key), std::move(*value));
+        }
+        return fields;
+      }
+    };
+
+    // Sends a message, failing if the other end has gone away
+    auto SendMessage(const SandboxPipe pipe, const StringView body) -> bool {
+      String message;
+      EncodeString(message, body);
+
+      for (StringView pending = message; !pending.empty();) {
+  #ifdef _WIN32
+        DWORD written = 0;
+        if (!WriteFile(pipe, pending.data(), static_cast<DWORD>(std::min<usize>(pending.size(), MAXDWORD)), &written, nullptr) || written == 0)
+          return false;
+  #else
+        const ssize_t written = send(pipe, pending.data(), pending.size(), SANDBOX_SEND_FLAGS);
+        if (written < 0 && errno == EINTR)
+          continue;
+        if (written <= 0)
+          return false;
+  #endif
+        pending.remove_prefix(static_cast<usize>(written));
+      }
+
+      return true;
+    }
+
+    // Appends whatever arrives within waitMs (or whenever it arrives, if negative) to the buffer,
+    // failing once the other end has gone away
+    auto ReceiveSome(const SandboxPipe pipe, String& buffer, const int waitMs) -> bool {
+      std::array<char, 4096> chunk {};
+
+  #ifdef _WIN32
+      if (waitMs >= 0) {
+        DWORD available = 0;
+        if (!PeekNamedPipe(pipe, nullptr, 0, nullptr, &available, nullptr))
+          return false;
+        if (available == 0) {
+          Sleep(static_cast<DWORD>(waitMs));
+          return true;
+        }
+      }
+
+      DWORD count = 0;
+      if (!ReadFile(pipe, chunk.data(), static_cast<DWORD>(chunk.size()), &count, nullptr) || count == 0)
+        return false;
+  #else
+      pollfd input { .fd = pipe, .events = POLLIN, .revents = 0 };
+      if (const int ready = poll(&input, 1, waitMs); ready <= 0)
+        return ready == 0 || errno == EINTR;
+
+      const ssize_t count = recv(pipe, chunk.data(), chunk.size(), 0);
+      if (count < 0)
+        return errno == EINTR;
+      if (count == 0)
+        return false;
+  #endif
+
+      buffer.append(chunk.data(), static_cast<usize>(count));
+      return true;
+    }
+
+    // Removes the first complete message from the front of the buffer
+    auto TakeMessage(String& buffer) -> Option<String> {
+      Option<String> body = SandboxReader(buffer).string();
+      if (body)
+        buffer.erase(0, sizeof(u64) + body->size());
+      return body;
+    }
+
+    auto ParseU64(const StringView text) -> Option<u64> {
+      u64 value = 0;
+      if (const auto [end, errc] = std::from_chars(text.data(), text.data() + text.size(), value); errc != std::errc() || end != text.data() + text.size())
+        return None;
+      return value;
+    }
+
+    // The helper executable: DRAC_PLUGIN_HOST if set, otherwise where the build installed it
+    auto FindSandboxHost() -> Result<fs::path> {
+      if (Result<String> path = utils::env::GetEnv("DRAC_PLUGIN_HOST"))
+        return fs::path(*path);
+  #ifdef DRAC_PLUGIN_HOST_PATH
+      return fs::path(DRAC_PLUGIN_HOST_PATH);
+  #else
+      ERR(NotFound, "This build has no plugin host; set DRAC_PLUGIN_HOST to the path of draconis-plugin-host");
+  #endif
+    }
+
+    // When a request given `timeout` has to be answered by; never for zero
+    auto Deadline(const std::chrono::milliseconds timeout) -> std::chrono::steady_clock::time_point {
+      if (timeout.count() <= 0)
+        return std::chrono::steady_clock::time_point::max();
+      return std::chrono::steady_clock::now() + timeout;
+    }
+
+    // Runs a plugin call in the helper, reporting whatever it throws as an error
+    template <typename Func>
+    auto GuardPluginCall(Func&& func) -> Result<Unit> {
+      try {
+        return std::forward<Func>(func)();
+      } catch (const std::bad_alloc&) {
+        ERR(OutOfMemory, "Plugin ran out of memory");
+      } catch (const std::exception& e) {
+        ERR_FMT(InternalError, "Plugin threw: {}", e.what());
+      } catch (...) {
+        ERR(InternalError, "Plugin threw an unknown exception");
+      }
+    }
+
+  #ifndef _WIN32
+    // Lets the helper use `seconds` more CPU time than it has so far, or lifts the limit for zero.
+    // Only the soft limit moves, since an unprivileged process can't raise its hard limit again.
+    auto LimitCpuTime(const u32 seconds) -> Unit {
+      rlimit cpu {};
+      if (getrlimit(RLIMIT_CPU, &cpu) != 0)
+        return;
+
+      if (seconds == 0)
+        cpu.rlim_cur = cpu.rlim_max;
+      else {
+        rusage usage {};
+        getrusage(RUSAGE_SELF, &usage);
+
+        // Rounded up, so a collection gets at least the time it was given
+        const rlim_t used = static_cast<rlim_t>(usage.ru_utime.tv_sec + usage.ru_stime.tv_sec) + 1;
+        cpu.rlim_cur      = std::min<rlim_t>(used + seconds, cpu.rlim_max);
+      }
+
+      setrlimit(RLIMIT_CPU, &cpu);
+    }
+  #endif
   } // namespace
 
   auto GetPluginContext() -> PluginContext {
@@ -149,6 +511,492 @@ namespace draconis::core::plugin {
     };
   }
 
+  // A running helper process; destroying it kills the process
+  struct SandboxedPlugin::Helper {
+  #ifdef _WIN32
+    HANDLE process = nullptr;
+    HANDLE job     = nullptr;
+    i64    cpuEnd  = 0; // Job user time in 100ns ticks at which the current collection runs out, or 0
+  #else
+    pid_t pid = -1;
+  #endif
+    SandboxPipe requests  = NO_PIPE; // Written by the host
+    SandboxPipe responses = NO_PIPE; // Read by the host
+    String      buffer;       // Response bytes not yet taken as a message
+
+    Helper() = default;
+
+    Helper(const Helper&)                    = delete;
+    Helper(Helper&&)                         = delete;
+    auto operator=(const Helper&) -> Helper& = delete;
+    auto operator=(Helper&&) -> Helper&      = delete;
+
+    ~Helper() {
+  #ifdef _WIN32
+      if (process) {
+        TerminateJobObject(job, 1);
+        WaitForSingleObject(process, INFINITE);
+        CloseHandle(process);
+      }
+      if (job)
+        CloseHandle(job);
+      if (requests != NO_PIPE)
+        CloseHandle(requests);
+      if (responses != NO_PIPE)
+        CloseHandle(responses);
+  #else
+      if (pid > 0) {
+        kill(pid, SIGKILL);
+        while (waitpid(pid, nullptr, 0) < 0 && errno == EINTR) {}
+      }
+      if (requests != NO_PIPE)
+        close(requests);
+  #endif
+    }
+
+    static auto spawn(const fs::path& library, const SandboxLimits& limits) -> Result<UniquePointer<Helper>> {
+      Result<fs::path> hostPath = FindSandboxHost();
+      if (!hostPath)
+        return Err(hostPath.error());
+
+      const fs::path& host   = *hostPath;
+      auto            helper = std::make_unique<Helper>();
+
+  #ifdef _WIN32
+      SECURITY_ATTRIBUTES inheritable { .nLength = sizeof(SECURITY_ATTRIBUTES), .lpSecurityDescriptor = nullptr, .bInheritHandle = TRUE };
+
+      HANDLE requestsIn   = nullptr;
+      HANDLE responsesOut = nullptr;
+
+      if (!CreatePipe(&requestsIn, &helper->requests, &inheritable, 0))
+        ERR_FMT(IoError, "Failed to create a pipe for the plugin host: Error Code {}", GetLastError());
+
+      if (!CreatePipe(&helper->responses, &responsesOut, &inheritable, 0)) {
+        CloseHandle(requestsIn);
+        ERR_FMT(IoError, "Failed to create a pipe for the plugin host: Error Code {}", GetLastError());
+      }
+
+      // Only the helper's ends are inherited, and only by the helper
+      SetHandleInformation(helper->requests, HANDLE_FLAG_INHERIT, 0);
+      SetHandleInformation(helper->responses, HANDLE_FLAG_INHERIT, 0);
+
+      std::array<HANDLE, 2> inherited { requestsIn, responsesOut };
+
+      SIZE_T attributesSize = 0;
+      InitializeProcThreadAttributeList(nullptr, 1, 0, &attributesSize);
+
+      Vec<char> attributesBuffer(attributesSize);
+      auto*     attributes = reinterpret_cast<LPPROC_THREAD_ATTRIBUTE_LIST>(attributesBuffer.data()); // NOLINT(cppcoreguidelines-pro-type-reinterpret-cast)
+
+      InitializeProcThreadAttributeList(attributes, 1, 0, &attributesSize);
+      UpdateProcThreadAttribute(attributes, 0, PROC_THREAD_ATTRIBUTE_HANDLE_LIST, inherited.data(), sizeof(inherited), nullptr, nullptr);
+
+      STARTUPINFOEXW startup {};
+      startup.StartupInfo.cb  = sizeof(startup);
+      startup.lpAttributeList = attributes;
+
+      // NOLINTBEGIN(cppcoreguidelines-pro-type-reinterpret-cast)
+      std::wstring commandLine = std::format(
+        L"\"{}\" \"{}\" {} {} {}",
+        host.wstring(),
+        library.wstring(),
+        limits.maxMemoryBytes,
+        reinterpret_cast<std::uintptr_t>(requestsIn),
+        reinterpret_cast<std::uintptr_t>(responsesOut)
+      );
+      // NOLINTEND(cppcoreguidelines-pro-type-reinterpret-cast)
+
+      PROCESS_INFORMATION info {};
+
+      // Started suspended so it is in the job before the plugin gets to run
+      const BOOL created = CreateProcessW(
+        host.wstring().c_str(),
+        commandLine.data(),
+        nullptr,
+        nullptr,
+        TRUE,
+        CREATE_SUSPENDED | CREATE_NO_WINDOW | EXTENDED_STARTUPINFO_PRESENT,
+        nullptr,
+        nullptr,
+        &startup.StartupInfo,
+        &info
+      );
+      const DWORD createError = GetLastError();
+
+      DeleteProcThreadAttributeList(attributes);
+      CloseHandle(requestsIn);
+      CloseHandle(responsesOut);
+
+      if (!created)
+        ERR_FMT(NotFound, "Failed to start the plugin host '{}': Error Code {}", host.string(), createError);
+
+      helper->process = info.hProcess;
+      helper->job     = CreateJobObjectW(nullptr, nullptr);
+
+      // Closing the job when the host exits takes the helper with it
+      JOBOBJECT_EXTENDED_LIMIT_INFORMATION jobLimits {};
+      jobLimits.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+
+      if (limits.maxMemoryBytes != 0) {
+        jobLimits.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PROCESS_MEMORY;
+        jobLimits.ProcessMemoryLimit               = static_cast<SIZE_T>(limits.maxMemoryBytes);
+      }
+
+      if (!helper->job ||
+          !SetInformationJobObject(helper->job, JobObjectExtendedLimitInformation, &jobLimits, sizeof(jobLimits)) ||
+          !AssignProcessToJobObject(helper->job, info.hProcess)) {
+        const DWORD jobError = GetLastError();
+        TerminateProcess(info.hProcess, 1);
+        CloseHandle(info.hThread);
+        ERR_FMT(InternalError, "Failed to put the plugin host in a job object: Error Code {}", jobError);
+      }
+
+      ResumeThread(info.hThread);
+      CloseHandle(info.hThread);
+  #else
+      std::array<int, 2> fds {};
+      if (socketpair(AF_UNIX, SOCK_STREAM, 0, fds.data()) != 0)
+        ERR_FMT(IoError, "Failed to create a socket for the plugin host: {}", std::strerror(errno));
+
+      helper->requests  = fds[0];
+      helper->responses = fds[0];
+
+      // Duplicating a descriptor onto itself wouldn't clear close-on-exec in the helper
+      if (fds[1] == SANDBOX_CHANNEL_FD) {
+        fds[1] = fcntl(SANDBOX_CHANNEL_FD, F_DUPFD_CLOEXEC, SANDBOX_CHANNEL_FD + 1);
+        close(SANDBOX_CHANNEL_FD);
+      }
+
+      // Neither end may leak into other processes; the helper's copy on SANDBOX_CHANNEL_FD stays open
+      fcntl(fds[0], F_SETFD, FD_CLOEXEC);
+      fcntl(fds[1], F_SETFD, FD_CLOEXEC);
+
+    #ifdef SO_NOSIGPIPE
+      const int noSigPipe = 1;
+      setsockopt(fds[0], SOL_SOCKET, SO_NOSIGPIPE, &noSigPipe, sizeof(noSigPipe));
+    #endif
+
+      const String channel = std::to_string(SANDBOX_CHANNEL_FD);
+
+      Vec<String> args { host.string(), library.string(), std::to_string(limits.maxMemoryBytes), channel, channel };
+      Vec<char*>  argv;
+
+      for (String& arg : args)
+        argv.push_back(arg.data());
+      argv.push_back(nullptr);
+
+      posix_spawn_file_actions_t actions;
+      posix_spawn_file_actions_init(&actions);
+      posix_spawn_file_actions_adddup2(&actions, fds[1], SANDBOX_CHANNEL_FD);
+
+      const int spawnError = posix_spawn(&helper->pid, host.c_str(), &actions, nullptr, argv.data(), environ);
+
+      posix_spawn_file_actions_destroy(&actions);
+      close(fds[1]);
+
+      if (spawnError != 0) {
+        helper->pid = -1;
+        ERR_FMT(NotFound, "Failed to start the plugin host '{}': {}", host.string(), std::strerror(spawnError));
+      }
+  #endif
+
+      return helper;
+    }
+
+  #ifdef _WIN32
+    // Gives the next collection `seconds` of user-mode time on top of what the job has used, or
+    // lifts the limit for zero
+    auto limitCpuTime(const u32 seconds) -> Unit {
+      JOBOBJECT_EXTENDED_LIMIT_INFORMATION jobLimits {};
+      if (!QueryInformationJobObject(job, JobObjectExtendedLimitInformation, &jobLimits, sizeof(jobLimits), nullptr))
+        return;
+
+      cpuEnd = 0;
+
+      if (seconds == 0)
+        jobLimits.BasicLimitInformation.LimitFlags &= ~static_cast<DWORD>(JOB_OBJECT_LIMIT_JOB_TIME);
+      else {
+        JOBOBJECT_BASIC_ACCOUNTING_INFORMATION accounting {};
+        QueryInformationJobObject(job, JobObjectBasicAccountingInformation, &accounting, sizeof(accounting), nullptr);
+
+        // The limit is counted on top of the time the job has already used
+        jobLimits.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_TIME;
+        jobLimits.BasicLimitInformation.PerJobUserTimeLimit.QuadPart = static_cast<i64>(seconds) * 10'000'000;
+
+        cpuEnd = accounting.TotalUserTime.QuadPart + jobLimits.BasicLimitInformation.PerJobUserTimeLimit.QuadPart;
+      }
+
+      SetInformationJobObject(job, JobObjectExtendedLimitInformation, &jobLimits, sizeof(jobLimits));
+    }
+  #endif
+
+    // Waits for a helper that closed its end to exit and describes why it did
+    auto exited(const u32 maxCpuSeconds) -> DracError {
+  #ifdef _WIN32
+      WaitForSingleObject(process, INFINITE);
+
+      DWORD exitCode = 0;
+      GetExitCodeProcess(process, &exitCode);
+
+      JOBOBJECT_BASIC_ACCOUNTING_INFORMATION accounting {};
+      QueryInformationJobObject(job, JobObjectBasicAccountingInformation, &accounting, sizeof(accounting), nullptr);
+
+      if (cpuEnd != 0 && accounting.TotalUserTime.QuadPart >= cpuEnd)
+        return { ResourceExhausted, std::format("Plugin exceeded its CPU time limit of {}s", maxCpuSeconds) };
+
+      return { InternalError, std::format("Plugin host exited with code {:#x}", exitCode) };
+  #else
+      int status = 0;
+      while (waitpid(pid, &status, 0) < 0 && errno == EINTR) {}
+      pid = -1;
+
+      if (WIFSIGNALED(status)) {
+        const int signal = WTERMSIG(status);
+
+        if (signal == SIGXCPU)
+          return { ResourceExhausted, std::format("Plugin exceeded its CPU time limit of {}s", maxCpuSeconds) };
+
+        return { InternalError, std::format("Plugin crashed with signal {} ({})", signal, strsignal(signal)) };
+      }
+
+      return { InternalError, std::format("Plugin host exited with status {}", WIFEXITED(status) ? WEXITSTATUS(status) : -1) };
+  #endif
+    }
+  };
+
+  SandboxedPlugin::SandboxedPlugin(fs::path library, const SandboxLimits limits, Option<String> config)
+    : m_library(std::move(library)), m_limits(limits), m_config(std::move(config)) {}
+
+  SandboxedPlugin::~SandboxedPlugin() = default;
+
+  auto SandboxedPlugin::start(const std::chrono::steady_clock::time_point deadline, const std::atomic<bool>* cancelled) -> Result<Unit> {
+    if (m_helper)
+      return {};
+
+    Result<UniquePointer<Helper>> helper = Helper::spawn(m_library, m_limits);
+    if (!helper)
+      return Err(helper.error());
+
+    m_helper = *std::move(helper);
+
+    // The helper reports whether it loaded and initialized the plugin before taking requests
+    TRY_VOID(receive(deadline, cancelled));
+
+    if (m_config) {
+      String request(1, SANDBOX_CONFIGURE);
+      EncodeString(request, *m_config);
+      TRY_VOID(exchange(request, deadline, cancelled));
+    }
+
+    return {};
+  }
+
+  auto SandboxedPlugin::receive(const std::chrono::steady_clock::time_point deadline, const std::atomic<bool>* cancelled) -> Result<String> {
+    for (;;) {
+      if (Option<String> response = TakeMessage(m_helper->buffer)) {
+        SandboxReader reader(*response);
+
+        switch (reader.raw<char>().value_or(-1)) {
+          case SANDBOX_OK:
+            return String(reader.rest());
+          case SANDBOX_ERROR: {
+            Option<u8>     code    = reader.raw<u8>();
+            Option<String> message = reader.string();
+            if (code && message)
+              ERR(static_cast<DracErrorCode>(*code), std::move(*message));
+            break;
+          }
+          default:
+            break;
+        }
+
+        m_helper.reset();
+        ERR(InternalError, "Plugin host sent a malformed response");
+      }
+
+      if (cancelled && cancelled->load()) {
+        m_helper.reset();
+        ERR(Cancelled, "Plugin collection was cancelled");
+      }
+
+      if (std::chrono::steady_clock::now() >= deadline) {
+        m_helper.reset();
+        ERR(Timeout, "Plugin did not respond in time");
+      }
+
+      // Short waits, so a cancellation or the deadline is noticed promptly
+      if (!ReceiveSome(m_helper->responses, m_helper->buffer, 10)) {
+        DracError error = m_helper->exited(m_limits.maxCpuSeconds);
+        m_helper.reset();
+        return Err(std::move(error));
+      }
+    }
+  }
+
+  auto SandboxedPlugin::exchange(const String& request, const std::chrono::steady_clock::time_point deadline, const std::atomic<bool>* cancelled) -> Result<String> {
+    // A helper that died takes its unread response, if any, with it
+    if (!SendMessage(m_helper->requests, request)) {
+      DracError error = m_helper->exited(m_limits.maxCpuSeconds);
+      m_helper.reset();
+      return Err(std::move(error));
+    }
+
+    return receive(deadline, cancelled);
+  }
+
+  auto SandboxedPlugin::setConfig(const String& tomlConfig) -> Result<Unit> {
+    const std::chrono::steady_clock::time_point deadline = Deadline(m_limits.timeout);
+
+    TRY_VOID(start(deadline, nullptr));
+
+    String request(1, SANDBOX_CONFIGURE);
+    EncodeString(request, tomlConfig);
+    TRY_VOID(exchange(request, deadline, nullptr));
+
+    m_config = tomlConfig;
+    return {};
+  }
+
+  auto SandboxedPlugin::collect(const std::chrono::milliseconds timeout, const std::atomic<bool>* cancelled) -> Result<PluginFields> {
+    std::chrono::milliseconds limit = m_limits.timeout;
+
+    if (timeout.count() > 0 && (limit.count() == 0 || timeout < limit))
+      limit = timeout;
+
+    const std::chrono::steady_clock::time_point deadline = Deadline(limit);
+
+    TRY_VOID(start(deadline, cancelled));
+
+  #ifdef _WIN32
+    m_helper->limitCpuTime(m_limits.maxCpuSeconds);
+  #endif
+
+    // The helper applies the CPU limit itself on Unix
+    String request(1, SANDBOX_COLLECT);
+    EncodeRaw<u32>(request, m_limits.maxCpuSeconds);
+
+    Result<String> response = exchange(request, deadline, cancelled);
+
+  #ifdef _WIN32
+    if (m_helper)
+      m_helper->limitCpuTime(0);
+  #endif
+
+    if (!response)
+      return Err(response.error());
+
+    if (Option<PluginFields> fields = SandboxReader(*response).fields())
+      return *std::move(fields);
+
+    m_helper.reset();
+    ERR(InternalError, "Plugin host sent malformed fields");
+  }
+
+  auto RunSandboxHost(const i32 argc, char* argv[]) -> i32 {
+    // Arguments: the plugin library, the memory limit, and the host's request and response
+    // channels (a descriptor on Unix, inherited pipe handles on Windows)
+    const Span<char*> args(argv, static_cast<usize>(argc));
+
+    if (args.size() != 5)
+      return 2;
+
+    const Option<u64> maxMemoryBytes = ParseU64(args[2]);
+    const Option<u64> requestsArg    = ParseU64(args[3]);
+    const Option<u64> responsesArg   = ParseU64(args[4]);
+
+    if (!maxMemoryBytes || !requestsArg || !responsesArg)
+      return 2;
+
+  #ifdef _WIN32
+    // The job object caps memory on Windows
+    // NOLINTBEGIN(performance-no-int-to-ptr, cppcoreguidelines-pro-type-reinterpret-cast)
+    const auto requests  = reinterpret_cast<SandboxPipe>(static_cast<std::uintptr_t>(*requestsArg));
+    const auto responses = reinterpret_cast<SandboxPipe>(static_cast<std::uintptr_t>(*responsesArg));
+    // NOLINTEND(performance-no-int-to-ptr, cppcoreguidelines-pro-type-reinterpret-cast)
+  #else
+    const auto requests  = static_cast<SandboxPipe>(*requestsArg);
+    const auto responses = static_cast<SandboxPipe>(*responsesArg);
+
+    if (*maxMemoryBytes != 0) {
+      const rlimit memory { .rlim_cur = *maxMemoryBytes, .rlim_max = *maxMemoryBytes };
+      setrlimit(RLIMIT_AS, &memory);
+    }
+
+    // The host may ignore SIGXCPU, and ignored signals stay ignored across exec
+    signal(SIGXCPU, SIG_DFL);
+  #endif
+
+    Result<LoadedPlugin> plugin = PluginManager::loadSandboxedInstance(fs::path(args[1]));
+
+    if (!plugin) {
+      (void)SendMessage(responses, EncodeError(plugin.error()));
+      return 1;
+    }
+
+    if (!SendMessage(responses, String(1, SANDBOX_OK)))
+      return 1;
+
+    auto&       infoProvider = dynamic_cast<IInfoProviderPlugin&>(*plugin->instance);
+    PluginCache cache(GetPluginContext().cacheDir);
+
+    for (String buffer;;) {
+      Option<String> request = TakeMessage(buffer);
+
+      if (!request) {
+        // Until the host closes its end
+        if (!ReceiveSome(requests, buffer, -1))
+          break;
+        continue;
+      }
+
+      SandboxReader reader(*request);
+      String        response;
+
+      switch (reader.raw<char>().value_or(-1)) {
+        case SANDBOX_CONFIGURE: {
+          Option<String> tomlConfig = reader.string();
+          Result<Unit>   result     = tomlConfig ? GuardPluginCall([&]() -> Result<Unit> { return infoProvider.setConfig(*tomlConfig); })
+                                                 : Err(DracError(InvalidArgument, "Malformed configure request"));
+
+          response = result ? String(1, SANDBOX_OK) : EncodeError(result.error());
+          break;
+        }
+        case SANDBOX_COLLECT: {
+  #ifndef _WIN32
+          LimitCpuTime(reader.raw<u32>().value_or(0));
+  #endif
+          Result<Unit> result = GuardPluginCall([&]() -> Result<Unit> {
+            TRY_VOID(infoProvider.collectData(cache));
+
+            response.assign(1, SANDBOX_OK);
+            EncodeFields(response, infoProvider.getFields());
+            return {};
+          });
+  #ifndef _WIN32
+          LimitCpuTime(0);
+  #endif
+
+          if (!result)
+            response = EncodeError(result.error());
+          break;
+        }
+        default:
+          response = EncodeError(DracError(InvalidArgument, "Unknown sandbox request"));
+          break;
+      }
+
+      if (!SendMessage(responses, response))
+        break;
+    }
+
+    if (plugin->isReady)
+      plugin->instance->shutdown();
+
+    // The process is about to exit, which unloads the library anyway
+    (void)plugin->instance.release();
+    return 0;
+  }
+
   PluginManager::~PluginManager() {
     shutdown();
   }
@@ -171,9 +1019,7 @@ namespace draconis::core::plugin {
       return {};
     }
 
-    // Add default search paths
-    for (const fs::path& path : GetDefaultPluginPaths())
-      addSearchPath(path);
+    addDefaultSearchPaths();
 
     {
       std::unique_lock<std::shared_mutex> lock(m_mutex);
@@ -239,6 +1085,11 @@ namespace draconis::core::plugin {
     }
   }
 
+  auto PluginManager::addDefaultSearchPaths() -> Unit {
+    for (const fs::path& path : GetDefaultPluginPaths())
+      addSearchPath(path);
+  }
+
   auto PluginManager::getSearchPaths() const -> Span<const fs::path> {
     std::shared_lock<std::shared_mutex> lock(m_mutex);
     return m_pluginSearchPaths;
@@ -357,6 +1208,9 @@ namespace draconis::core::plugin {
     LoadedPlugin loadedPlugin;
     loadedPlugin.path = pluginPath;
 
+    std::error_code errc;
+    loadedPlugin.modifiedTime = fs::last_write_time(pluginPath, errc);
+
     if (Result<DynamicLibraryHandle> handleResult = loadDynamicLibrary(pluginPath); !handleResult)
       return std::unexpected(handleResult.error());
     else
@@ -486,6 +1340,53 @@ namespace draconis::core::plugin {
     return {};
   }
 
+  auto PluginManager::reloadPlugin(const String& pluginName, CacheManager& cache) -> Result<Unit> {
+    TRY_VOID(unloadPlugin(pluginName));
+
+    // Pick up a library that was renamed or moved between search paths
+    {
+      std::unique_lock<std::shared_mutex> lock(m_mutex);
+      if (auto scanResult = scanForPlugins(); !scanResult)
+        warn_log("Failed to scan for plugins: {}", scanResult.error().message);
+    }
+
+    debug_log("Reloading plugin '{}'", pluginName);
+    return loadPlugin(pluginName, cache);
+  }
+
+  auto PluginManager::reloadChangedPlugins(CacheManager& cache) -> Vec<String> {
+    Vec<String> changed;
+
+    {
+      std::shared_lock<std::shared_mutex> lock(m_mutex);
+      for (const auto& [name, loadedPlugin] : m_plugins) {
+        if (!loadedPlugin.isLoaded || loadedPlugin.handle == nullptr)
+          continue;
+
+        std::error_code errc;
+        const auto      modifiedTime = fs::last_write_time(loadedPlugin.path, errc);
+
+        // A library that is briefly missing is likely being rewritten; check again next time
+        if (!errc && modifiedTime != loadedPlugin.modifiedTime)
+          changed.push_back(name);
+      }
+    }
+
+    Vec<String> reloaded;
+    reloaded.reserve(changed.size());
+
+    for (String& name : changed) {
+      if (auto result = reloadPlugin(name, cache); !result) {
+        warn_log("Failed to reload plugin '{}': {}", name, result.error().message);
+        continue;
+      }
+
+      reloaded.push_back(std::move(name));
+    }
+
+    return reloaded;
+  }
+
   auto PluginManager::getPlugin(const String& pluginName) const -> Option<IPlugin*> {
     std::shared_lock<std::shared_mutex> lock(m_mutex);
     if (const auto iter = m_plugins.find(pluginName); iter != m_plugins.end())
@@ -547,6 +1448,71 @@ namespace draconis::core::plugin {
     return iter != m_plugins.end() && iter->second.isLoaded;
   }
 
+  auto PluginManager::getPluginPath(const String& pluginName) const -> Option<fs::path> {
+    std::shared_lock<std::shared_mutex> lock(m_mutex);
+    if (const auto iter = m_plugins.find(pluginName); iter != m_plugins.end() && iter->second.handle)
+      return iter->second.path;
+
+    return std::nullopt;
+  }
+
+  auto PluginManager::describePlugins() -> Vec<PluginDescription> {
+    std::unique_lock<std::shared_mutex> lock(m_mutex);
+    Vec<PluginDescription>              descriptions;
+
+    if (auto result = scanForPlugins(); !result)
+      debug_log("Failed to rescan plugin search paths: {}", result.error().message);
+
+    const auto loadedInstance = [&](const String& name) -> IPlugin* {
+      const auto iter = m_plugins.find(name);
+      return iter != m_plugins.end() && iter->second.isLoaded ? iter->second.instance.get() : nullptr;
+    };
+
+    for (const auto& [name, entry] : GetStaticPluginRegistry()) {
+      if (IPlugin* instance = loadedInstance(name)) {
+        descriptions.push_back(DescribePlugin(*instance, true));
+        continue;
+      }
+
+      if (IPlugin* instance = entry.createFunc()) {
+        descriptions.push_back(DescribePlugin(*instance, true));
+        entry.destroyFunc(instance);
+      }
+    }
+
+    for (const auto& [name, path] : m_discoveredPlugins) {
+      // Loading by name prefers the static plugin, so the library is never used
+      if (IsStaticPlugin(name))
+        continue;
+
+      if (IPlugin* instance = loadedInstance(name)) {
+        descriptions.push_back(DescribePlugin(*instance, false));
+        continue;
+      }
+
+      Result<DynamicLibraryHandle> handle = loadDynamicLibrary(path);
+
+      if (!handle) {
+        debug_log("Failed to describe plugin '{}': {}", name, handle.error().message);
+        continue;
+      }
+
+      syncPluginLogLevel(*handle);
+
+      if (Result<IPlugin* (*)()> createFunc = getCreatePluginFunc(*handle))
+        if (UniquePointer<IPlugin> instance((*createFunc)()); instance)
+          descriptions.push_back(DescribePlugin(*instance, false));
+
+      unloadDynamicLibrary(*handle);
+    }
+
+    std::ranges::sort(descriptions, [](const PluginDescription& descA, const PluginDescription& descB) {
+      return descA.metadata.name < descB.metadata.name;
+    });
+
+    return descriptions;
+  }
+
   auto PluginManager::loadDynamicLibrary(const fs::path& path) -> Result<DynamicLibraryHandle> {
   #ifdef _WIN32
     HMODULE handle = LoadLibraryA(path.string().c_str());
@@ -652,6 +1618,37 @@ namespace draconis::core::plugin {
 
     return {};
   }
+
+  auto PluginManager::loadSandboxedInstance(const fs::path& library) -> Result<LoadedPlugin> {
+    LoadedPlugin loadedPlugin;
+    loadedPlugin.path = library;
+
+    if (Result<DynamicLibraryHandle> handleResult = loadDynamicLibrary(library); !handleResult)
+      return std::unexpected(handleResult.error());
+    else
+      loadedPlugin.handle = *handleResult;
+
+    syncPluginLogLevel(loadedPlugin.handle);
+
+    if (Result<IPlugin* (*)()> createFuncResult = getCreatePluginFunc(loadedPlugin.handle); !createFuncResult)
+      return std::unexpected(createFuncResult.error());
+    else
+      loadedPlugin.instance.reset((*createFuncResult)());
+
+    if (!loadedPlugin.instance)
+      ERR_FMT(InternalError, "Failed to create instance for plugin '{}'", library.string());
+
+    loadedPlugin.metadata = loadedPlugin.instance->getMetadata();
+    loadedPlugin.isLoaded = true;
+
+    if (!dynamic_cast<IInfoProviderPlugin*>(loadedPlugin.instance.get()))
+      ERR_FMT(InvalidArgument, "Plugin '{}' is not an info provider", loadedPlugin.metadata.name);
+
+    CacheManager cache;
+    TRY_VOID(initializePluginInstance(loadedPlugin, cache));
+
+    return loadedPlugin;
+  }
 } // namespace draconis::core::plugin
 
 #endif // DRAC_ENABLE_PLUGINS
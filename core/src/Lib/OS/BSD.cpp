@@ -29,7 +29,7 @@
 // clang-format on
 
 using namespace draconis::utils::types;
-using draconis::utils::cache::CacheManager;
+using draconis::utils::cache::CacheManager, draconis::utils::cache::CachePolicy;
 using draconis::utils::env::GetEnv;
 using draconis::utils::error::DracError;
 using enum draconis::utils::error::DracErrorCode;
@@ -193,6 +193,24 @@ namespace {
     return String(compositorNameView);
   #endif
   }
+
+  // Converts statfs `f_flags` into fstab-style mount options, matching what Linux reports.
+  auto MountOptionsFromFlags(const u64 flags) -> Vec<String> {
+    Vec<String> options;
+
+    options.emplace_back((flags & MNT_RDONLY) != 0 ? "ro" : "rw");
+
+    if ((flags & MNT_NOSUID) != 0)
+      options.emplace_back("nosuid");
+    if ((flags & MNT_NOEXEC) != 0)
+      options.emplace_back("noexec");
+    if ((flags & MNT_SYNCHRONOUS) != 0)
+      options.emplace_back("sync");
+    if ((flags & MNT_NOATIME) != 0)
+      options.emplace_back("noatime");
+
+    return options;
+  }
 } // namespace
 
 namespace draconis::core::system {
@@ -271,6 +289,26 @@ namespace draconis::core::system {
     });
   }
 
+  auto GetDisplaySession(CacheManager& cache) -> Result<DisplaySession> {
+    return cache.getOrSet<DisplaySession>("bsd_display_session", CachePolicy::inMemory(), []() -> Result<DisplaySession> {
+      DisplaySession session { .server = os::unix_shared::GetDisplayServerFromEnv(), .compositor = None };
+
+      if (session.server == DisplaySession::Server::Wayland)
+        if (Result<String> compositor = GetWaylandCompositor())
+          session.compositor = *std::move(compositor);
+
+      return session;
+    });
+  }
+
+  auto GetThemeInfo(CacheManager& cache) -> Result<ThemeInfo> {
+    return cache.getOrSet<ThemeInfo>("bsd_theme_info", CachePolicy::tempDirectory(), os::unix_shared::GetThemeInfoFromConfig);
+  }
+
+  auto GetDefaultApplications() -> Result<DefaultApplications> {
+    return os::unix_shared::GetDefaultApplicationsFromConfig();
+  }
+
   auto GetDesktopEnvironment(CacheManager& cache) -> Result<String> {
     return cache.getOrSet<String>("bsd_desktop_environment", []() -> Result<String> {
       if (!GetEnv("DISPLAY") && !GetEnv("WAYLAND_DISPLAY") && !GetEnv("XDG_SESSION_TYPE"))
@@ -318,6 +356,21 @@ namespace draconis::core::system {
     });
   }
 
+  auto GetTerminal(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("bsd_terminal", CachePolicy::inMemory(), []() -> Result<String> {
+      if (Option<String> terminal = os::unix_shared::GetTerminalFromEnv())
+        return *terminal;
+
+      return Err(DracError(NotFound, "Could not detect the terminal emulator from the environment"));
+    });
+  }
+
+  auto GetTerminalFont(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("bsd_terminal_font", CachePolicy::inMemory(), [&cache]() -> Result<String> {
+      return os::unix_shared::GetTerminalFontFromConfig(TRY(GetTerminal(cache)));
+    });
+  }
+
   auto GetHost(CacheManager& cache) -> Result<String> {
     return cache.getOrSet<String>("bsd_host", []() -> Result<String> {
       Array<char, 256> buffer {};
@@ -358,6 +411,49 @@ namespace draconis::core::system {
     });
   }
 
+  auto GetLoadAverage() -> Result<LoadAverage> {
+    return os::unix_shared::GetLoadAverage();
+  }
+
+  auto GetBootTime() -> Result<std::chrono::system_clock::time_point> {
+    Array<i32, 2> mib = { CTL_KERN, KERN_BOOTTIME };
+
+    struct timeval boottime;
+    usize          len = sizeof(boottime);
+
+    if (sysctl(mib.data(), mib.size(), &boottime, &len, nullptr, 0) == -1)
+      ERR_FMT(ApiUnavailable, "sysctl(CTL_KERN, KERN_BOOTTIME) failed: {}", std::strerror(errno));
+
+    return std::chrono::system_clock::from_time_t(boottime.tv_sec);
+  }
+
+  auto GetSessions() -> Result<Vec<SessionInfo>> {
+    return os::unix_shared::GetSessions();
+  }
+
+  auto GetCurrentUser() -> Result<UserInfo> {
+    return os::unix_shared::GetCurrentUser();
+  }
+
+  auto GetLocale() -> Result<String> {
+    if (Option<String> locale = os::unix_shared::GetLocaleFromEnv())
+      return std::move(*locale);
+
+    ERR(NotFound, "No locale is configured");
+  }
+
+  auto GetTimezone() -> Result<TimezoneInfo> {
+    return os::unix_shared::GetTimezone();
+  }
+
+  auto GetHostname() -> Result<String> {
+    return os::unix_shared::GetHostname();
+  }
+
+  auto GetFQDN() -> Result<String> {
+    return os::unix_shared::GetFQDN();
+  }
+
   auto GetDiskUsage(CacheManager& /*cache*/) -> Result<ResourceUsage> {
     return os::unix_shared::GetRootDiskUsage();
   }
@@ -392,6 +488,10 @@ namespace draconis::core::system {
         .driveType     = String("Local"),
         .totalBytes    = totalBytes,
         .usedBytes     = totalBytes - freeBytes,
+        .totalInodes   = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files)) : None,
+        .usedInodes    = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files) - static_cast<u64>(fs.f_ffree)) : None,
+        .mountOptions  = MountOptionsFromFlags(fs.f_flags),
+        .isReadOnly    = (fs.f_flags & MNT_RDONLY) != 0,
         .isSystemDrive = (std::strcmp(fs.f_mntonname, "/") == 0),
       });
     }
@@ -415,6 +515,10 @@ namespace draconis::core::system {
       .driveType     = String("Local"),
       .totalBytes    = totalBytes,
       .usedBytes     = totalBytes - freeBytes,
+      .totalInodes   = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files)) : None,
+      .usedInodes    = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files) - static_cast<u64>(fs.f_ffree)) : None,
+      .mountOptions  = MountOptionsFromFlags(fs.f_flags),
+      .isReadOnly    = (fs.f_flags & MNT_RDONLY) != 0,
       .isSystemDrive = true,
     };
   }
@@ -438,6 +542,10 @@ namespace draconis::core::system {
       .driveType     = String("Local"),
       .totalBytes    = totalBytes,
       .usedBytes     = totalBytes - freeBytes,
+      .totalInodes   = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files)) : None,
+      .usedInodes    = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files) - static_cast<u64>(fs.f_ffree)) : None,
+      .mountOptions  = MountOptionsFromFlags(fs.f_flags),
+      .isReadOnly    = (fs.f_flags & MNT_RDONLY) != 0,
       .isSystemDrive = (std::strcmp(fs.f_mntonname, "/") == 0),
     };
   }
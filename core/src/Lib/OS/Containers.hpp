@@ -0,0 +1,120 @@
+/**
+ * @file Containers.hpp
+ * @brief Shared querying of the Docker Engine API for the Linux, macOS, and Windows backends.
+ *
+ * @details Docker and Podman (through its Docker-compatible API) both answer `GET /containers/json`
+ * with the same JSON, so only the transport differs between platforms: a Unix socket on Linux and
+ * macOS, a named pipe on Windows. This header maps:
+ * - The container list response to `ContainerInfo` entries
+ * - The API's lowercase state names to `ContainerInfo::State`
+ */
+
+#pragma once
+
+#include <glaze/core/meta.hpp> // glz::detail::Object, glz::object
+#include <glaze/json/read.hpp> // glz::read, glz::format_error
+
+#include <Drac++/Utils/DataTypes.hpp>
+#include <Drac++/Utils/Error.hpp>
+#include <Drac++/Utils/Types.hpp>
+
+#include "Services/Http.hpp"
+
+namespace draconis::os::containers {
+  namespace types = ::draconis::utils::types;
+  namespace http  = ::draconis::services::http;
+
+  using enum ::draconis::utils::error::DracErrorCode;
+
+  using Runtime = types::ContainerInfo::Runtime;
+  using State   = types::ContainerInfo::State;
+
+  inline constexpr types::StringView LIST_PATH         = "/containers/json"; ///< Lists running (and paused) containers only.
+  inline constexpr types::usize      MAX_LIST_RESPONSE = 1 << 20;            ///< Each container costs a few KiB of labels and mounts.
+
+  // Only the keys needed from each entry of the container list; everything else is ignored.
+  struct ApiContainer {
+    types::String             id;
+    types::Vec<types::String> names;
+    types::String             image;
+    types::String             state;
+
+    // NOLINTBEGIN(readability-identifier-naming)
+    struct glaze {
+      using T = ApiContainer;
+      static constexpr glz::detail::Object value = glz::object("Id", &T::id, "Names", &T::names, "Image", &T::image, "State", &T::state);
+    };
+    // NOLINTEND(readability-identifier-naming)
+  };
+
+  /**
+   * @brief Maps the `State` field of the container list to a container state.
+   * @param state The API's state name, e.g. "running".
+   * @return The state, or `State::Unknown` for states a running container can't be in.
+   */
+  [[nodiscard]] constexpr auto StateFromApi(const types::StringView state) -> State {
+    if (state == "running")
+      return State::Running;
+
+    if (state == "paused")
+      return State::Paused;
+
+    if (state == "restarting")
+      return State::Restarting;
+
+    return State::Unknown;
+  }
+
+  /**
+   * @brief Converts a response to `GET /containers/json` into container entries.
+   * @param response The HTTP response from the daemon.
+   * @param runtime The runtime the daemon belongs to.
+   * @return The containers, or an error if the daemon refused the request or sent malformed JSON.
+   */
+  [[nodiscard]] inline auto ParseContainerList(const http::Response& response, const Runtime runtime) -> types::Result<types::Vec<types::ContainerInfo>> {
+    if (response.status != 200)
+      ERR_FMT(ApiUnavailable, "Container list request failed with status {}", response.status);
+
+    types::Vec<ApiContainer> entries;
+
+    if (const glz::error_ctx errorContext = glz::read<glz::opts { .error_on_unknown_keys = false }>(entries, response.body))
+      ERR_FMT(ParseError, "Failed to parse container list: {}", glz::format_error(errorContext, response.body));
+
+    types::Vec<types::ContainerInfo> containers;
+    containers.reserve(entries.size());
+
+    for (ApiContainer& entry : entries) {
+      // Docker reports names with a leading slash, a leftover of container links.
+      types::String name = entry.names.empty() ? entry.id.substr(0, 12) : std::move(entry.names.front());
+
+      if (name.starts_with('/'))
+        name.erase(0, 1);
+
+      containers.push_back({
+        .runtime     = runtime,
+        .state       = StateFromApi(entry.state),
+        .id          = std::move(entry.id),
+        .name        = std::move(name),
+        .image       = entry.image.empty() ? types::None : types::Option<types::String>(std::move(entry.image)),
+        .cpuPercent  = types::None,
+        .memoryBytes = types::None,
+      });
+    }
+
+    return containers;
+  }
+
+#ifndef _WIN32
+  /**
+   * @brief Lists the running containers of the daemon listening on a Unix socket.
+   * @param socketPath Path of the daemon's API socket, e.g. "/var/run/docker.sock".
+   * @param runtime The runtime the daemon belongs to.
+   * @return The containers, or a `NotFound`/`PermissionDenied` error if the socket couldn't be connected to.
+   */
+  [[nodiscard]] inline auto ListFromSocket(const types::StringView socketPath, const Runtime runtime) -> types::Result<types::Vec<types::ContainerInfo>> {
+    const http::Response response = TRY(http::RequestUnix(socketPath, LIST_PATH, MAX_LIST_RESPONSE));
+
+    return ParseContainerList(response, runtime);
+  }
+#endif
+} // namespace draconis::os::containers
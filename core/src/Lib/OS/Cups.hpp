@@ -0,0 +1,312 @@
+/**
+ * @file Cups.hpp
+ * @brief Shared querying of the CUPS scheduler for the Linux and macOS backends.
+ *
+ * @details CUPS answers IPP requests on a local domain socket on both platforms, so only the
+ * socket path differs. Rather than depending on libcups, this header speaks just enough IPP
+ * (RFC 8010) to send a `CUPS-Get-Printers` request and read the printer attribute groups of the
+ * reply. It maps:
+ * - `printer-state` and `printer-state-reasons` to `PrinterInfo::State`
+ * - The `CUPS_PRINTER_DEFAULT` bit of `printer-type`, or the user's own default, to `isDefault`
+ */
+
+#pragma once
+
+#include <algorithm> // std::ranges::any_of
+#include <array>     // std::array
+#include <cstdlib>   // std::getenv
+#include <fstream>   // std::ifstream
+
+#include <Drac++/Utils/DataTypes.hpp>
+#include <Drac++/Utils/Env.hpp>
+#include <Drac++/Utils/Error.hpp>
+#include <Drac++/Utils/Types.hpp>
+
+#include "Services/Http.hpp"
+
+namespace draconis::os::cups {
+  namespace types = ::draconis::utils::types;
+  namespace http  = ::draconis::services::http;
+
+  using enum ::draconis::utils::error::DracErrorCode;
+
+  using State = types::PrinterInfo::State;
+
+  inline constexpr types::usize MAX_RESPONSE = 1024 * 1024; ///< Every attribute of every queue easily fits in this.
+
+  // Delimiter tags start an attribute group; value tags precede each attribute value (RFC 8010, section 3.5).
+  inline constexpr types::u8 TAG_OPERATION_ATTRIBUTES = 0x01;
+  inline constexpr types::u8 TAG_END_OF_ATTRIBUTES    = 0x03;
+  inline constexpr types::u8 TAG_PRINTER_ATTRIBUTES   = 0x04;
+  inline constexpr types::u8 TAG_LAST_DELIMITER       = 0x0F;
+  inline constexpr types::u8 TAG_KEYWORD              = 0x44;
+  inline constexpr types::u8 TAG_CHARSET              = 0x47;
+  inline constexpr types::u8 TAG_NATURAL_LANGUAGE     = 0x48;
+
+  inline constexpr types::u16 CUPS_GET_PRINTERS    = 0x4002;
+  inline constexpr types::u16 STATUS_NOT_FOUND     = 0x0406;  ///< client-error-not-found, returned when no queues exist.
+  inline constexpr types::u32 CUPS_PRINTER_DEFAULT = 0x20000; ///< printer-type bit set on the server's default queue.
+
+  // printer-state enum values (RFC 8011, section 5.4.12).
+  inline constexpr types::u32 PRINTER_STATE_IDLE       = 3;
+  inline constexpr types::u32 PRINTER_STATE_PROCESSING = 4;
+  inline constexpr types::u32 PRINTER_STATE_STOPPED    = 5;
+
+  /**
+   * @brief Builds the body of a `CUPS-Get-Printers` request asking for the attributes that are decoded.
+   */
+  [[nodiscard]] inline auto BuildGetPrintersRequest() -> types::String {
+    types::String request;
+
+    const auto appendU16 = [&request](const types::u16 value) -> void {
+      request.push_back(static_cast<char>(value >> 8));
+      request.push_back(static_cast<char>(value & 0xFF));
+    };
+
+    // Additional values of a multi-valued attribute are sent with an empty name.
+    const auto appendAttribute = [&](const types::u8 tag, const types::StringView name, const types::StringView value) -> void {
+      request.push_back(static_cast<char>(tag));
+      appendU16(static_cast<types::u16>(name.size()));
+      request.append(name);
+      appendU16(static_cast<types::u16>(value.size()));
+      request.append(value);
+    };
+
+    // Version 2.0, the operation, and request ID 1.
+    appendU16(0x0200);
+    appendU16(CUPS_GET_PRINTERS);
+    request.append({ 0, 0, 0, 1 });
+
+    request.push_back(static_cast<char>(TAG_OPERATION_ATTRIBUTES));
+    appendAttribute(TAG_CHARSET, "attributes-charset", "utf-8");
+    appendAttribute(TAG_NATURAL_LANGUAGE, "attributes-natural-language", "en");
+
+    constexpr std::array<types::StringView, 6> requested = {
+      "printer-name", "printer-make-and-model", "device-uri", "printer-state", "printer-state-reasons", "printer-type",
+    };
+
+    for (types::usize idx = 0; idx < requested.size(); ++idx)
+      appendAttribute(TAG_KEYWORD, idx == 0 ? "requested-attributes" : "", requested.at(idx));
+
+    request.push_back(static_cast<char>(TAG_END_OF_ATTRIBUTES));
+
+    return request;
+  }
+
+  /**
+   * @brief Maps a queue's `printer-state` and `printer-state-reasons` to its state.
+   * @param state The printer-state enum value.
+   * @param reasons The printer-state-reasons keywords, e.g. "media-empty-error".
+   */
+  [[nodiscard]] inline auto StateFromIpp(const types::u32 state, const types::Vec<types::String>& reasons) -> State {
+    // Reasons carry a severity suffix; "-report" and "-warning" conditions don't stop printing.
+    const bool failing = std::ranges::any_of(reasons, [](const types::String& reason) -> bool {
+      return reason.ends_with("-error") || reason.starts_with("offline") || reason == "paused";
+    });
+
+    if (failing || state == PRINTER_STATE_STOPPED)
+      return State::Error;
+
+    if (state == PRINTER_STATE_PROCESSING)
+      return State::Printing;
+
+    if (state == PRINTER_STATE_IDLE)
+      return State::Idle;
+
+    return State::Unknown;
+  }
+
+  /**
+   * @brief Converts the body of a `CUPS-Get-Printers` response into printers.
+   * @param body The IPP response.
+   * @return The printers, with `isDefault` set from the server's default, or an error if the response is malformed.
+   */
+  [[nodiscard]] inline auto ParseGetPrintersResponse(const types::StringView body) -> types::Result<types::Vec<types::PrinterInfo>> {
+    // Header: version (2 bytes), status code (2 bytes), request ID (4 bytes).
+    constexpr types::usize headerSize = 8;
+
+    const auto readU16 = [&body](const types::usize offset) -> types::u16 {
+      return static_cast<types::u16>((static_cast<types::u8>(body[offset]) << 8) | static_cast<types::u8>(body[offset + 1]));
+    };
+
+    if (body.size() < headerSize)
+      ERR(ParseError, "CUPS sent a truncated IPP response");
+
+    if (const types::u16 status = readU16(2); status == STATUS_NOT_FOUND)
+      return types::Vec<types::PrinterInfo> {};
+    else if (status > 0x00FF)
+      ERR_FMT(ApiUnavailable, "CUPS-Get-Printers failed with IPP status 0x{:04x}", status);
+
+    struct Queue {
+      types::String             name;
+      types::String             makeAndModel;
+      types::String             deviceUri;
+      types::u32                state = 0;
+      types::u32                type  = 0;
+      types::Vec<types::String> reasons;
+    };
+
+    types::Vec<Queue> queues;
+    Queue*            current = nullptr;
+    types::String     attribute;
+
+    for (types::usize offset = headerSize; offset < body.size();) {
+      const auto tag = static_cast<types::u8>(body[offset++]);
+
+      if (tag == TAG_END_OF_ATTRIBUTES)
+        break;
+
+      if (tag <= TAG_LAST_DELIMITER) {
+        current = tag == TAG_PRINTER_ATTRIBUTES ? &queues.emplace_back() : nullptr;
+        continue;
+      }
+
+      if (offset + 2 > body.size())
+        ERR(ParseError, "CUPS sent a truncated IPP attribute");
+
+      const types::u16 nameLength = readU16(offset);
+
+      if (offset + 2 + nameLength + 2 > body.size())
+        ERR(ParseError, "CUPS sent a truncated IPP attribute");
+
+      // An empty name marks an additional value of the previous attribute.
+      if (nameLength > 0)
+        attribute = body.substr(offset + 2, nameLength);
+
+      offset += 2 + nameLength;
+
+      const types::u16 valueLength = readU16(offset);
+
+      if (offset + 2 + valueLength > body.size())
+        ERR(ParseError, "CUPS sent a truncated IPP value");
+
+      const types::StringView value = body.substr(offset + 2, valueLength);
+
+      offset += 2 + valueLength;
+
+      if (!current)
+        continue;
+
+      // Integers and enums are 4-byte big-endian values.
+      const auto integer = [&value]() -> types::u32 {
+        types::u32 result = 0;
+
+        if (value.size() == 4)
+          for (const char byte : value)
+            result = (result << 8) | static_cast<types::u8>(byte);
+
+        return result;
+      };
+
+      if (attribute == "printer-name" && nameLength > 0)
+        current->name = value;
+      else if (attribute == "printer-make-and-model" && nameLength > 0)
+        current->makeAndModel = value;
+      else if (attribute == "device-uri" && nameLength > 0)
+        current->deviceUri = value;
+      else if (attribute == "printer-state")
+        current->state = integer();
+      else if (attribute == "printer-type")
+        current->type = integer();
+      else if (attribute == "printer-state-reasons" && value != "none")
+        current->reasons.emplace_back(value);
+    }
+
+    types::Vec<types::PrinterInfo> printers;
+    printers.reserve(queues.size());
+
+    for (Queue& queue : queues) {
+      if (queue.name.empty())
+        continue;
+
+      printers.push_back({
+        .state      = StateFromIpp(queue.state, queue.reasons),
+        .name       = std::move(queue.name),
+        .driver     = queue.makeAndModel.empty() ? types::None : types::Option<types::String>(std::move(queue.makeAndModel)),
+        .connection = queue.deviceUri.empty() ? types::None : types::Option<types::String>(std::move(queue.deviceUri)),
+        .isDefault  = (queue.type & CUPS_PRINTER_DEFAULT) != 0,
+      });
+    }
+
+    return printers;
+  }
+
+  /**
+   * @brief Reads the user's own default printer, which takes precedence over the server's.
+   * @return The queue name, without any instance suffix, or None if the user hasn't chosen one.
+   *
+   * @details Checked in the same order as the CUPS command-line tools: `LPDEST`, `PRINTER`, then
+   * the `Default` line of `~/.cups/lpoptions`.
+   */
+  [[nodiscard]] inline auto GetUserDefault() -> types::Option<types::String> {
+    using draconis::utils::env::GetEnv;
+
+    // Instances are written as "queue/instance"; only the queue identifies the printer.
+    const auto stripInstance = [](types::String name) -> types::String {
+      return name.substr(0, name.find('/'));
+    };
+
+    for (const types::PCStr variable : { "LPDEST", "PRINTER" })
+      if (types::Result<types::String> value = GetEnv(variable); value && !value->empty())
+        return stripInstance(*std::move(value));
+
+    const types::Result<types::String> home = GetEnv("HOME");
+
+    if (!home)
+      return types::None;
+
+    std::ifstream lpoptions(std::format("{}/.cups/lpoptions", *home));
+
+    for (types::String line; std::getline(lpoptions, line);)
+      if (line.starts_with("Default ")) {
+        types::String name = line.substr(8);
+        return stripInstance(name.substr(0, name.find(' ')));
+      }
+
+    return types::None;
+  }
+
+  /**
+   * @brief Lists the print queues of the local CUPS scheduler.
+   * @return The printers, or a `NotFound` error if no CUPS socket accepts connections.
+   */
+  [[nodiscard]] inline auto GetPrinters() -> types::Result<types::Vec<types::PrinterInfo>> {
+    using draconis::utils::env::GetEnv;
+
+    types::Vec<types::String> socketPaths;
+
+    // CUPS_SERVER may also name a host, which would need TCP; only socket paths are honored.
+    if (types::Result<types::String> server = GetEnv("CUPS_SERVER"); server && server->starts_with('/'))
+      socketPaths.push_back(*std::move(server));
+
+    for (const types::PCStr path : { "/run/cups/cups.sock", "/var/run/cups/cups.sock", "/private/var/run/cupsd" })
+      socketPaths.emplace_back(path);
+
+    const types::String request = BuildGetPrintersRequest();
+
+    for (const types::String& socketPath : socketPaths) {
+      types::Result<http::Response> response = http::PostUnix(socketPath, "/", "application/ipp", request, MAX_RESPONSE);
+
+      if (!response) {
+        if (response.error().code == NotFound)
+          continue;
+
+        return types::Err(response.error());
+      }
+
+      if (response->status != 200)
+        ERR_FMT(ApiUnavailable, "CUPS request failed with status {}", response->status);
+
+      types::Vec<types::PrinterInfo> printers = TRY(ParseGetPrintersResponse(response->body));
+
+      if (const types::Option<types::String> userDefault = GetUserDefault())
+        if (std::ranges::any_of(printers, [&](const types::PrinterInfo& printer) -> bool { return printer.name == *userDefault; }))
+          for (types::PrinterInfo& printer : printers)
+            printer.isDefault = printer.name == *userDefault;
+
+      return printers;
+    }
+
+    ERR(NotFound, "CUPS is not running");
+  }
+} // namespace draconis::os::cups
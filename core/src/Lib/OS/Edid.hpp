@@ -0,0 +1,270 @@
+/**
+ * @file Edid.hpp
+ * @brief Shared decoding of EDID blocks for the Linux, Windows and macOS backends.
+ *
+ * @details Linux and Windows can hand back the raw EDID a monitor advertises (RandR's `EDID`
+ * output property and `/sys/class/drm` on Linux, the monitor's device registry key on
+ * Windows), so only the retrieval is platform-specific; macOS only exposes the packed
+ * manufacturer ID. This header decodes:
+ * - The three-letter PNP manufacturer ID, mapped to a readable vendor name where well known
+ * - The monitor name (0xFC) and serial number (0xFF) display descriptors of the base block
+ * - The numeric serial number of the base block, used when no serial descriptor is present
+ * - The color primaries of the base block, and the colorimetry and HDR static metadata data
+ *   blocks of CTA-861 extension blocks
+ */
+
+#pragma once
+
+#include <algorithm>
+#include <cmath>
+#include <format>
+
+#include <Drac++/Utils/DataTypes.hpp>
+#include <Drac++/Utils/Types.hpp>
+
+namespace draconis::os::edid {
+  namespace types = ::draconis::utils::types;
+
+  inline constexpr types::usize BLOCK_SIZE = 128; ///< Size of the base block and of every extension block.
+
+  // The base block holds four 18-byte descriptors starting at offset 54.
+  inline constexpr types::usize DESCRIPTOR_OFFSET = 54;
+  inline constexpr types::usize DESCRIPTOR_SIZE   = 18;
+  inline constexpr types::usize DESCRIPTOR_COUNT  = 4;
+
+  // CTA-861 extension blocks hold a collection of tagged data blocks; extended tags (7) carry their real tag in the next byte.
+  inline constexpr types::u8 CTA_EXTENSION_TAG       = 0x02;
+  inline constexpr types::u8 EXTENDED_TAG            = 7;
+  inline constexpr types::u8 COLORIMETRY_TAG         = 0x05;
+  inline constexpr types::u8 HDR_STATIC_METADATA_TAG = 0x06;
+
+  /**
+   * @brief Identity of a monitor, as advertised in its EDID.
+   */
+  struct Identity {
+    types::Option<types::String> manufacturer; ///< Vendor name (e.g. "Dell"), or the raw PNP ID if it isn't well known.
+    types::Option<types::String> model;        ///< Monitor name descriptor (e.g. "DELL U2723QE").
+    types::Option<types::String> serial;       ///< Serial number descriptor, or the numeric serial of the base block.
+  };
+
+  /**
+   * @brief Color capabilities of a monitor, as advertised in its EDID.
+   */
+  struct ColorCapabilities {
+    types::DisplayInfo::Gamut gamut        = types::DisplayInfo::Gamut::Unknown; ///< Widest of the native primaries and the accepted colorimetry.
+    bool                      hdrSupported = false;                              ///< Whether the monitor accepts PQ (SMPTE ST 2084) or HLG signals.
+  };
+
+  /**
+   * @brief Maps a three-letter PNP manufacturer ID to a readable vendor name.
+   * @param pnpId The PNP ID, e.g. "DEL".
+   * @return The vendor name, or the ID itself if it isn't in the table.
+   */
+  [[nodiscard]] constexpr auto VendorName(const types::StringView pnpId) -> types::StringView {
+    // clang-format off
+    constexpr types::Array<types::Pair<types::StringView, types::StringView>, 32> vendors {{
+      { "AAC", "AcerView"        },
+      { "ACI", "ASUS"            },
+      { "ACR", "Acer"            },
+      { "AOC", "AOC"             },
+      { "APP", "Apple"           },
+      { "AUO", "AU Optronics"    },
+      { "AUS", "ASUS"            },
+      { "BNQ", "BenQ"            },
+      { "BOE", "BOE"             },
+      { "CMN", "Chimei Innolux"  },
+      { "DEL", "Dell"            },
+      { "ENC", "EIZO"            },
+      { "GBT", "Gigabyte"        },
+      { "GSM", "LG"              },
+      { "HPN", "HP"              },
+      { "HSD", "HannStar"        },
+      { "HWP", "HP"              },
+      { "IVM", "iiyama"          },
+      { "LEN", "Lenovo"          },
+      { "LGD", "LG Display"      },
+      { "MEI", "Panasonic"       },
+      { "MSI", "MSI"             },
+      { "NEC", "NEC"             },
+      { "PHL", "Philips"         },
+      { "SAM", "Samsung"         },
+      { "SDC", "Samsung Display" },
+      { "SEC", "Samsung"         },
+      { "SHP", "Sharp"           },
+      { "SNY", "Sony"            },
+      { "VIZ", "Vizio"           },
+      { "VSC", "ViewSonic"       },
+      { "XMI", "Xiaomi"          },
+    }};
+    // clang-format on
+
+    for (const auto& [id, name] : vendors)
+      if (id == pnpId)
+        return name;
+
+    return pnpId;
+  }
+
+  /**
+   * @brief Decodes a packed PNP manufacturer ID and maps it to a vendor name.
+   * @param packedId Three 5-bit letters (1 = 'A'), as stored big-endian in EDID bytes 8-9.
+   * @return The vendor name (or the three letters if it isn't well known), or None if the ID isn't valid.
+   */
+  [[nodiscard]] inline auto ManufacturerName(const types::u16 packedId) -> types::Option<types::String> {
+    const types::Array<char, 3> pnpId = {
+      static_cast<char>('A' - 1 + ((packedId >> 10) & 0x1F)),
+      static_cast<char>('A' - 1 + ((packedId >> 5) & 0x1F)),
+      static_cast<char>('A' - 1 + (packedId & 0x1F)),
+    };
+
+    if (!std::ranges::all_of(pnpId, [](const char letter) { return letter >= 'A' && letter <= 'Z'; }))
+      return types::None;
+
+    return types::String(VendorName(types::StringView(pnpId.data(), pnpId.size())));
+  }
+
+  /**
+   * @brief Decodes the text of a display descriptor.
+   * @param descriptor The 18-byte descriptor.
+   * @return The text (bytes 5-17, terminated by a newline and padded with spaces), or None if it's empty.
+   */
+  [[nodiscard]] inline auto DescriptorText(const types::Span<const types::u8> descriptor) -> types::Option<types::String> {
+    types::String text;
+
+    for (const types::u8 byte : descriptor.subspan(5)) {
+      if (byte == '\n' || byte == '\0')
+        break;
+
+      text.push_back(static_cast<char>(byte));
+    }
+
+    while (!text.empty() && text.back() == ' ')
+      text.pop_back();
+
+    if (text.empty())
+      return types::None;
+
+    return text;
+  }
+
+  /**
+   * @brief Checks that the data holds at least a base block starting with the EDID header.
+   * @param edid The EDID.
+   * @return True if the base block is present.
+   */
+  [[nodiscard]] inline auto HasBaseBlock(const types::Span<const types::u8> edid) -> bool {
+    constexpr types::Array<types::u8, 8> header = { 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00 };
+
+    return edid.size() >= BLOCK_SIZE && std::ranges::equal(edid.first(header.size()), header);
+  }
+
+  /**
+   * @brief Decodes the manufacturer, model name and serial number of an EDID.
+   * @param edid The EDID, at least the 128-byte base block.
+   * @return The identity, or None if the data doesn't start with a valid EDID header.
+   */
+  [[nodiscard]] inline auto ParseIdentity(const types::Span<const types::u8> edid) -> types::Option<Identity> {
+    if (!HasBaseBlock(edid))
+      return types::None;
+
+    Identity identity;
+
+    identity.manufacturer = ManufacturerName(static_cast<types::u16>((edid[8] << 8) | edid[9]));
+
+    for (types::usize i = 0; i < DESCRIPTOR_COUNT; ++i) {
+      const types::Span<const types::u8> descriptor = edid.subspan(DESCRIPTOR_OFFSET + (i * DESCRIPTOR_SIZE), DESCRIPTOR_SIZE);
+
+      // Display descriptors (as opposed to detailed timings) start with a zero pixel clock.
+      if (descriptor[0] != 0 || descriptor[1] != 0)
+        continue;
+
+      if (descriptor[3] == 0xFC)
+        identity.model = DescriptorText(descriptor);
+      else if (descriptor[3] == 0xFF)
+        identity.serial = DescriptorText(descriptor);
+    }
+
+    // Many panels leave the numeric serial zeroed or fill it with a placeholder pattern.
+    const types::u32 serialNumber = edid[12] | (edid[13] << 8) | (edid[14] << 16) | (static_cast<types::u32>(edid[15]) << 24);
+
+    if (!identity.serial && serialNumber != 0 && serialNumber != 0x01010101)
+      identity.serial = std::format("{}", serialNumber);
+
+    return identity;
+  }
+
+  /**
+   * @brief Decodes the color gamut and HDR support of an EDID.
+   * @param edid The EDID, including any extension blocks.
+   * @return The color capabilities, or None if the data doesn't start with a valid EDID header.
+   */
+  [[nodiscard]] inline auto ParseColorCapabilities(const types::Span<const types::u8> edid) -> types::Option<ColorCapabilities> {
+    using enum types::DisplayInfo::Gamut;
+
+    if (!HasBaseBlock(edid))
+      return types::None;
+
+    ColorCapabilities capabilities;
+
+    // Chromaticity coordinates are 10-bit fractions, with the high 8 bits in bytes 27-32 and the low 2 bits packed into bytes 25-26.
+    const auto coordinate = [&edid](const types::usize high, const types::usize low, const types::u32 shift) -> types::f64 {
+      return static_cast<types::f64>((edid[high] << 2) | ((edid[low] >> shift) & 0x3)) / 1024.0;
+    };
+
+    const types::f64 redX   = coordinate(27, 25, 6);
+    const types::f64 redY   = coordinate(28, 25, 4);
+    const types::f64 greenX = coordinate(29, 25, 2);
+    const types::f64 greenY = coordinate(30, 25, 0);
+    const types::f64 blueX  = coordinate(31, 26, 6);
+    const types::f64 blueY  = coordinate(32, 26, 4);
+
+    // Area of the primaries' triangle in CIE 1931 xy: sRGB covers about 0.112, DCI-P3 0.152 and BT.2020 0.212.
+    const types::f64 area = std::abs((redX * (greenY - blueY)) + (greenX * (blueY - redY)) + (blueX * (redY - greenY))) / 2.0;
+
+    if (area >= 0.19)
+      capabilities.gamut = BT2020;
+    else if (area >= 0.135)
+      capabilities.gamut = P3;
+    else if (area > 0.0)
+      capabilities.gamut = SRGB;
+
+    const types::usize extensionCount = std::min<types::usize>(edid[126], (edid.size() / BLOCK_SIZE) - 1);
+
+    for (types::usize extension = 1; extension <= extensionCount; ++extension) {
+      const types::Span<const types::u8> block = edid.subspan(extension * BLOCK_SIZE, BLOCK_SIZE);
+
+      if (block[0] != CTA_EXTENSION_TAG)
+        continue;
+
+      // Data blocks run from byte 4 up to the offset of the first detailed timing, stored in byte 2.
+      const types::usize collectionEnd = std::min<types::usize>(block[2], BLOCK_SIZE);
+
+      for (types::usize offset = 4; offset < collectionEnd;) {
+        const types::u8    tag    = block[offset] >> 5;
+        const types::usize length = block[offset] & 0x1F;
+
+        if (offset + 1 + length > collectionEnd)
+          break;
+
+        if (tag == EXTENDED_TAG && length >= 2) {
+          const types::Span<const types::u8> payload = block.subspan(offset + 2, length - 1);
+
+          if (block[offset + 1] == COLORIMETRY_TAG) {
+            // Bits 6-7 of the first byte are BT.2020 YCC and RGB; bit 7 of the second is DCI-P3 (CTA-861-G).
+            if ((payload[0] & 0xC0) != 0)
+              capabilities.gamut = BT2020;
+            else if (payload.size() >= 2 && (payload[1] & 0x80) != 0)
+              capabilities.gamut = std::max(capabilities.gamut, P3);
+          } else if (block[offset + 1] == HDR_STATIC_METADATA_TAG) {
+            // Bits 2-3 of the supported EOTFs are SMPTE ST 2084 (PQ) and HLG.
+            capabilities.hdrSupported = capabilities.hdrSupported || (payload[0] & 0x0C) != 0;
+          }
+        }
+
+        offset += 1 + length;
+      }
+    }
+
+    return capabilities;
+  }
+} // namespace draconis::os::edid
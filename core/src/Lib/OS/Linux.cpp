@@ -2,9 +2,11 @@
 
   #include <algorithm>
   #include <arpa/inet.h>          // inet_ntop
+  #include <cctype>               // std::isdigit
   #include <chrono>               // std::chrono::minutes
   #include <cpuid.h>              // __get_cpuid
-  #include <cstring>              // std::strlen
+  #include <cstring>              // std::{memcpy, strlen}
+  #include <dlfcn.h>              // dlopen, dlsym
   #include <expected>             // std::{unexpected, expected}
   #include <fcntl.h>              // open, O_RDONLY, O_CLOEXEC
   #include <filesystem>           // std::filesystem::{current_path, directory_entry, directory_iterator, etc.}
@@ -13,25 +15,42 @@
   #include <glaze/beve/read.hpp>  // glz::read_beve
   #include <glaze/beve/write.hpp> // glz::write_beve
   #include <ifaddrs.h>            // getifaddrs, freeifaddrs, ifaddrs
+  #include <limits>               // std::numeric_limits
+  #include <linux/i2c-dev.h>      // I2C_SLAVE
   #include <linux/if_packet.h>    // sockaddr_ll
   #include <linux/limits.h>       // PATH_MAX
+  #include <linux/netlink.h>      // sockaddr_nl, nlmsghdr, NETLINK_KOBJECT_UEVENT, NETLINK_ROUTE
+  #include <linux/nvme_ioctl.h>   // nvme_admin_cmd, NVME_IOCTL_ADMIN_CMD
+  #include <linux/rtnetlink.h>    // ifinfomsg, RTM_NEWLINK, RTM_DELLINK, RTMGRP_LINK, IFLA_IFNAME
+  #include <linux/videodev2.h>    // v4l2_capability, VIDIOC_QUERYCAP, V4L2_CAP_VIDEO_CAPTURE
   #include <map>                  // std::map
   #include <matchit.hpp>          // matchit::{is, is_not, is_any, etc.}
   #include <mntent.h>             // setmntent, getmntent, endmntent
   #include <net/if.h>             // IFF_UP, IFF_LOOPBACK
+  #include <net/route.h>          // RTF_UP, RTF_GATEWAY
   #include <netdb.h>              // getnameinfo, NI_NUMERICHOST
   #include <netinet/in.h>         // sockaddr_in
+  #include <poll.h>               // poll, pollfd
+  #include <scsi/sg.h>            // sg_io_hdr_t, SG_IO, SG_DXFER_FROM_DEV, SG_DXFER_NONE
   #include <ranges>               // std::views::{common, split, values}
   #include <sstream>              // std::istringstream
   #include <string>               // std::{getline, string (String)}
   #include <string_view>          // std::string_view (StringView)
+  #include <sys/eventfd.h>        // eventfd, EFD_CLOEXEC, EFD_NONBLOCK
+  #include <sys/ioctl.h>          // ioctl
   #include <sys/mman.h>           // mmap, munmap
   #include <sys/socket.h>         // ucred, getsockopt, SOL_SOCKET, SO_PEERCRED
   #include <sys/stat.h>           // fstat
   #include <sys/sysinfo.h>        // sysinfo (for GetMemInfo)
-  #include <unistd.h>             // readlink
+  #include <sys/un.h>             // sockaddr_un
+  #include <thread>               // std::this_thread::sleep_for
+  #include <tuple>                // std::{tie, tuple}
+  #include <unistd.h>             // access, readlink
   #include <utility>              // std::move
 
+  // linux/wireless.h pulls in linux/if.h, which only defers to glibc's definitions if net/if.h was included first.
+  #include <linux/wireless.h> // iwreq, SIOCGIWESSID, SIOCGIWAP, SIOCGIWFREQ, SIOCGIWRATE
+
   #include "Drac++/Core/System.hpp"
   #include "Drac++/Services/Packages.hpp"
 
@@ -48,8 +67,21 @@
   #if DRAC_USE_XCB
     #include "Wrappers/XCB.hpp"
   #endif
+  #if DRAC_USE_PULSEAUDIO
+    #include <pulse/pulseaudio.h> // pa_mainloop, pa_context, pa_context_get_{server_info,sink_info_list,source_info_list}
+  #endif
+  #if DRAC_USE_DBUS
+    #include "Wrappers/DBus.hpp"
+  #endif
 
+  #include "OS/Containers.hpp"
+  #include "OS/Cups.hpp"
+  #include "OS/Edid.hpp"
+  #include "OS/Smart.hpp"
   #include "OS/Unix.hpp"
+  #include "OS/Virtualization.hpp"
+  #include "OS/Vulkan.hpp"
+  #include "OS/Xkb.hpp"
 
 using draconis::utils::error::DracError;
 using enum draconis::utils::error::DracErrorCode;
@@ -64,10 +96,10 @@ extern "C" auto issetugid() -> usize { return 0; } // NOLINT(readability-identif
 
 namespace {
   template <std::integral T>
-  constexpr auto TryParse(StringView sview) -> Option<T> {
+  constexpr auto TryParse(StringView sview, const i32 base = 10) -> Option<T> {
     T value;
 
-    auto [ptr, ec] = std::from_chars(sview.begin(), sview.end(), value);
+    auto [ptr, ec] = std::from_chars(sview.begin(), sview.end(), value, base);
 
     if (ec == std::errc() && ptr == sview.end())
       return value;
@@ -196,27 +228,53 @@ namespace {
   }
   #endif
 
-  constexpr auto CleanGpuModelName(String vendor, String device) -> String {
+  constexpr auto TrimWhitespace(String& str) -> void {
+    if (const usize pos = str.find_last_not_of(" \t\n\r"); pos != String::npos)
+      str.erase(pos + 1);
+    if (const usize pos = str.find_first_not_of(" \t\n\r"); pos != String::npos)
+      str.erase(0, pos);
+  }
+
+  constexpr auto CleanGpuVendorName(String vendor) -> String {
     if (vendor.find("[AMD/ATI]") != String::npos)
       vendor = "AMD";
     else if (const usize pos = vendor.find(' '); pos != String::npos)
       vendor = vendor.substr(0, pos);
 
+    TrimWhitespace(vendor);
+
+    return vendor;
+  }
+
+  constexpr auto CleanGpuDeviceName(String device) -> String {
     if (const usize openPos = device.find('['); openPos != String::npos)
       if (const usize closePos = device.find(']', openPos); closePos != String::npos)
         device = device.substr(openPos + 1, closePos - openPos - 1);
 
-    constexpr auto trim = [](String& str) -> void {
-      if (const usize pos = str.find_last_not_of(" \t\n\r"); pos != String::npos)
-        str.erase(pos + 1);
-      if (const usize pos = str.find_first_not_of(" \t\n\r"); pos != String::npos)
-        str.erase(0, pos);
-    };
+    TrimWhitespace(device);
+
+    return device;
+  }
+
+  constexpr auto CleanGpuModelName(String vendor, String device) -> String {
+    return std::format("{} {}", CleanGpuVendorName(std::move(vendor)), CleanGpuDeviceName(std::move(device)));
+  }
+
+  constexpr auto LookupGpuVendorId(const StringView vendorId) -> Option<StringView> {
+    // clang-format off
+    constexpr Array<Pair<StringView, StringView>, 3> vendorMap = {{
+      { "0x1002", "AMD" },
+      { "0x10de", "NVIDIA" },
+      { "0x8086", "Intel" },
+    }};
+    // clang-format on
 
-    trim(vendor);
-    trim(device);
+    const auto* iter = std::ranges::find_if(vendorMap, [&](const auto& pair) -> bool { return pair.first == vendorId; });
 
-    return std::format("{} {}", vendor, device);
+    if (iter != vendorMap.end())
+      return iter->second;
+
+    return None;
   }
 
   #if DRAC_USE_XCB
@@ -303,6 +361,72 @@ namespace {
     return String(nameData, length);
   }
 
+  auto InternX11EdidAtom(xcb::Connection* conn) -> xcb::Atom {
+    using namespace xcb;
+
+    const ReplyGuard<IntAtomReply> reply(InternAtomReply(conn, InternAtom(conn, 1, std::strlen("EDID"), "EDID"), nullptr));
+
+    return reply ? reply->atom : NONE;
+  }
+
+  auto ApplyX11OutputDetails(
+    DisplayInfo&                        display,
+    xcb::Connection*                    conn,
+    const xcb::Screen&                  screen,
+    const xcb::RandrOutput              output,
+    const xcb::Atom                     edidAtom,
+    const xcb::RandrGetOutputInfoReply* outputInfo,
+    const xcb::RandrGetCrtcInfoReply*   crtcInfo
+  ) -> Unit {
+    using namespace xcb;
+
+    display.position   = { .x = crtcInfo->x, .y = crtcInfo->y };
+    display.colorDepth = screen.root_depth;
+
+    // RandR rotations are counter-clockwise; DisplayInfo reports clockwise degrees.
+    if (crtcInfo->rotation & RANDR_ROTATE_90)
+      display.rotation = 270;
+    else if (crtcInfo->rotation & RANDR_ROTATE_180)
+      display.rotation = 180;
+    else if (crtcInfo->rotation & RANDR_ROTATE_270)
+      display.rotation = 90;
+
+    // The physical size describes the unrotated panel, so compare it against the matching pixel axis.
+    const usize horizontalPixels = display.rotation % 180 == 0 ? display.resolution.width : display.resolution.height;
+    display.dpi                  = draconis::os::unix_shared::DpiFromPhysicalSize(horizontalPixels, outputInfo->mm_width);
+
+    const StringView outputName(reinterpret_cast<const char*>(GetOutputInfoName(outputInfo)), GetOutputInfoNameLength(outputInfo));
+    display.connector = draconis::os::unix_shared::ConnectorFromOutputName(outputName);
+
+    // The X server composites everything in SDR, even on monitors that accept HDR signals.
+    display.hdrEnabled = false;
+
+    // The driver publishes the monitor's EDID as an output property (the atom only exists once one has).
+    if (edidAtom == NONE)
+      return;
+
+    // 256 32-bit units covers the base block plus seven extension blocks.
+    const ReplyGuard<RandrGetOutputPropertyReply> edidReply(
+      GetOutputPropertyReply(conn, GetOutputProperty(conn, output, edidAtom, ATOM_ANY, 0, 256), nullptr)
+    );
+
+    if (!edidReply || edidReply->format != 8)
+      return;
+
+    const Span<const u8> edid(GetOutputPropertyData(edidReply.get()), static_cast<usize>(GetOutputPropertyDataLength(edidReply.get())));
+
+    if (Option<draconis::os::edid::Identity> identity = draconis::os::edid::ParseIdentity(edid)) {
+      display.manufacturer = std::move(identity->manufacturer);
+      display.model        = std::move(identity->model);
+      display.serial       = std::move(identity->serial);
+    }
+
+    if (Option<draconis::os::edid::ColorCapabilities> color = draconis::os::edid::ParseColorCapabilities(edid)) {
+      display.gamut        = color->gamut;
+      display.hdrSupported = color->hdrSupported;
+    }
+  }
+
   auto GetX11Displays() -> Result<Vec<DisplayInfo>> {
     using namespace xcb;
 
@@ -347,6 +471,7 @@ namespace {
       GetOutputPrimaryReply(conn.get(), GetOutputPrimary(conn.get(), screen->root), nullptr)
     );
     const RandrOutput primaryOutput = primaryOutputReply ? primaryOutputReply->output : NONE;
+    const Atom        edidAtom      = InternX11EdidAtom(conn.get());
 
     for (i32 i = 0; i < outputCount; ++i) {
       const ReplyGuard<RandrGetOutputInfoReply> outputInfoReply(
@@ -381,12 +506,14 @@ namespace {
       if (isPrimary)
         primaryIndex = static_cast<int>(displays.size());
 
-      displays.emplace_back(
+      DisplayInfo& display = displays.emplace_back(
         *std::next(outputs, i),
         DisplayInfo::Resolution { .width = crtcInfoReply->width, .height = crtcInfoReply->height },
         refreshRate,
         isPrimary
       );
+
+      ApplyX11OutputDetails(display, conn.get(), *screen, *std::next(outputs, i), edidAtom, outputInfoReply.get(), crtcInfoReply.get());
     }
 
     // If no display was marked as primary, set the first one as primary
@@ -457,12 +584,61 @@ namespace {
       }
     }
 
-    return DisplayInfo(
+    DisplayInfo display(
       primaryOutput,
       DisplayInfo::Resolution { .width = crtcInfoReply->width, .height = crtcInfoReply->height },
       refreshRate,
       true
     );
+
+    ApplyX11OutputDetails(display, conn.get(), *screen, primaryOutput, InternX11EdidAtom(conn.get()), outputInfoReply.get(), crtcInfoReply.get());
+
+    return display;
+  }
+
+  auto GetX11KeyboardLayouts() -> Result<Vec<KeyboardLayout>> {
+    using namespace xcb;
+
+    const DisplayGuard conn;
+    if (!conn)
+      ERR(ApiUnavailable, "Failed to connect to X server");
+
+    Screen* screen = conn.rootScreen();
+    if (!screen)
+      ERR(NotFound, "Failed to get X root screen");
+
+    constexpr StringView rulesNamesAtomName = "_XKB_RULES_NAMES";
+
+    const ReplyGuard<IntAtomReply> rulesNamesAtom(
+      InternAtomReply(conn.get(), InternAtom(conn.get(), 1, static_cast<u16>(rulesNamesAtomName.size()), rulesNamesAtomName.data()), nullptr)
+    );
+
+    if (!rulesNamesAtom || rulesNamesAtom->atom == NONE)
+      ERR(NotFound, "X server does not set _XKB_RULES_NAMES");
+
+    const ReplyGuard<GetPropReply> rulesNamesReply(GetPropertyReply(
+      conn.get(),
+      GetProperty(conn.get(), 0, screen->root, rulesNamesAtom->atom, ATOM_STRING, 0, 1024),
+      nullptr
+    ));
+
+    if (!rulesNamesReply || rulesNamesReply->type != ATOM_STRING || GetPropertyValueLength(rulesNamesReply.get()) == 0)
+      ERR(NotFound, "Failed to get _XKB_RULES_NAMES property");
+
+    // Clients that haven't enabled the XKB extension get the active group in bits 13-14 of the core modifier state.
+    const ReplyGuard<QueryPtrReply> pointerReply(QueryPointerReply(conn.get(), QueryPointer(conn.get(), screen->root), nullptr));
+
+    const Option<usize> activeGroup = pointerReply ? Option<usize>((pointerReply->mask >> 13) & 3) : None;
+
+    Vec<KeyboardLayout> layouts = draconis::os::xkb::ParseRulesNames(
+      StringView(static_cast<const char*>(GetPropertyValue(rulesNamesReply.get())), static_cast<usize>(GetPropertyValueLength(rulesNamesReply.get()))),
+      activeGroup
+    );
+
+    if (layouts.empty())
+      ERR(NotFound, "_XKB_RULES_NAMES does not name any layouts");
+
+    return layouts;
   }
   #else
   auto GetX11WindowManager() -> Result<String> {
@@ -476,6 +652,10 @@ namespace {
   auto GetX11PrimaryDisplay() -> Result<DisplayInfo> {
     ERR(NotSupported, "XCB (X11) support not available");
   }
+
+  auto GetX11KeyboardLayouts() -> Result<Vec<KeyboardLayout>> {
+    ERR(NotSupported, "XCB (X11) support not available");
+  }
   #endif
 
   #if DRAC_USE_WAYLAND
@@ -586,6 +766,172 @@ namespace {
   }
   #endif
 
+  /**
+   * @brief Connects to a compositor's IPC socket.
+   * @return The connected socket, which the caller must close. Reads time out after a second.
+   */
+  auto ConnectIpcSocket(const StringView socketPath) -> Result<i32> {
+    sockaddr_un address {};
+    address.sun_family = AF_UNIX;
+
+    if (socketPath.size() >= sizeof(address.sun_path))
+      ERR_FMT(InvalidArgument, "Socket path {} is too long", socketPath);
+
+    std::memcpy(static_cast<char*>(address.sun_path), socketPath.data(), socketPath.size());
+
+    const i32 sock = socket(AF_UNIX, SOCK_STREAM | SOCK_CLOEXEC, 0);
+
+    if (sock == -1)
+      ERR_FMT(IoError, "Failed to create a socket for {}", socketPath);
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - connect takes the generic sockaddr type.
+    if (connect(sock, reinterpret_cast<const sockaddr*>(&address), sizeof(address)) != 0) {
+      const i32 error = errno;
+      close(sock);
+
+      if (error == EACCES)
+        ERR_FMT(PermissionDenied, "Permission denied connecting to {}", socketPath);
+
+      ERR_FMT(NotFound, "Nothing is listening on {}", socketPath);
+    }
+
+    // A compositor that's stuck must not hang the caller.
+    constexpr timeval timeout { .tv_sec = 1, .tv_usec = 0 };
+    setsockopt(sock, SOL_SOCKET, SO_RCVTIMEO, &timeout, sizeof(timeout));
+
+    return sock;
+  }
+
+  /**
+   * @brief Reads from a socket until `count` bytes have arrived, the peer closes the connection, or the read times out.
+   */
+  auto ReceiveUpTo(const i32 sock, const usize count) -> String {
+    String            data;
+    Array<char, 4096> buffer {};
+
+    while (data.size() < count) {
+      const isize received = recv(sock, buffer.data(), std::min(buffer.size(), count - data.size()), 0);
+
+      if (received <= 0)
+        break;
+
+      data.append(buffer.data(), static_cast<usize>(received));
+    }
+
+    return data;
+  }
+
+  auto GetSwayKeyboardLayouts(const Vec<draconis::os::xkb::RegistryEntry>& registry) -> Result<Vec<KeyboardLayout>> {
+    using draconis::utils::env::GetEnv;
+
+    // i3-ipc messages start with a magic string, followed by the payload length and message type as native-endian u32s.
+    constexpr StringView IPC_MAGIC       = "i3-ipc";
+    constexpr usize      IPC_HEADER_SIZE = IPC_MAGIC.size() + (2 * sizeof(u32));
+    constexpr u32        GET_INPUTS      = 100;
+
+    const Result<String> socketPath = GetEnv("SWAYSOCK");
+
+    if (!socketPath)
+      ERR(NotFound, "SWAYSOCK is not set");
+
+    const i32 sock = TRY(ConnectIpcSocket(*socketPath));
+
+    Array<char, IPC_HEADER_SIZE> request {};
+    constexpr u32                requestLength = 0;
+
+    std::memcpy(request.data(), IPC_MAGIC.data(), IPC_MAGIC.size());
+    std::memcpy(&request.at(IPC_MAGIC.size()), &requestLength, sizeof(u32));
+    std::memcpy(&request.at(IPC_MAGIC.size() + sizeof(u32)), &GET_INPUTS, sizeof(u32));
+
+    if (send(sock, request.data(), request.size(), MSG_NOSIGNAL) != static_cast<isize>(request.size())) {
+      close(sock);
+      ERR_FMT(IoError, "Failed to send request to {}", *socketPath);
+    }
+
+    const String header      = ReceiveUpTo(sock, IPC_HEADER_SIZE);
+    u32          replyLength = 0;
+
+    if (header.size() == IPC_HEADER_SIZE && header.starts_with(IPC_MAGIC))
+      std::memcpy(&replyLength, &header.at(IPC_MAGIC.size()), sizeof(u32));
+
+    const String payload = ReceiveUpTo(sock, replyLength);
+
+    close(sock);
+
+    if (replyLength == 0 || payload.size() != replyLength)
+      ERR(ParseError, "Malformed reply from Sway IPC");
+
+    return draconis::os::xkb::ParseSwayInputs(payload, registry);
+  }
+
+  auto GetHyprlandKeyboardLayouts(const Vec<draconis::os::xkb::RegistryEntry>& registry) -> Result<Vec<KeyboardLayout>> {
+    using draconis::utils::env::GetEnv;
+
+    constexpr usize MAX_REPLY = 1024 * 1024;
+
+    const Result<String> signature = GetEnv("HYPRLAND_INSTANCE_SIGNATURE");
+
+    if (!signature)
+      ERR(NotFound, "HYPRLAND_INSTANCE_SIGNATURE is not set");
+
+    // Hyprland 0.40 moved its sockets from /tmp/hypr into the runtime directory.
+    String socketPath = std::format("/tmp/hypr/{}/.socket.sock", *signature);
+
+    if (const Result<String> runtimeDir = GetEnv("XDG_RUNTIME_DIR")) {
+      String          runtimePath = std::format("{}/hypr/{}/.socket.sock", *runtimeDir, *signature);
+      std::error_code errc;
+
+      if (fs::exists(runtimePath, errc))
+        socketPath = std::move(runtimePath);
+    }
+
+    const i32 sock = TRY(ConnectIpcSocket(socketPath));
+
+    // The "j/" prefix asks for JSON; Hyprland closes the connection once the reply is sent.
+    constexpr StringView request = "j/devices";
+
+    if (send(sock, request.data(), request.size(), MSG_NOSIGNAL) != static_cast<isize>(request.size())) {
+      close(sock);
+      ERR_FMT(IoError, "Failed to send request to {}", socketPath);
+    }
+
+    const String reply = ReceiveUpTo(sock, MAX_REPLY);
+
+    close(sock);
+
+    return draconis::os::xkb::ParseHyprlandDevices(reply, registry);
+  }
+
+  /**
+   * @brief Reads the layouts Plasma applies from `kxkbrc`, with the active one from its keyboard daemon over D-Bus.
+   */
+  auto GetKdeKeyboardLayouts() -> Result<Vec<KeyboardLayout>> {
+    using draconis::os::unix_shared::ReadIniValue;
+
+    const fs::path kxkbrc = TRY(draconis::os::unix_shared::GetConfigHome()) / "kxkbrc";
+
+    // Unless Use=true, Plasma leaves the layouts to the X server or the compositor's defaults.
+    const Option<String> layouts = ReadIniValue(kxkbrc, "Layout", "LayoutList");
+
+    if (ReadIniValue(kxkbrc, "Layout", "Use") != "true" || !layouts || layouts->empty())
+      ERR(NotFound, "Plasma does not manage the keyboard layouts");
+
+    Option<usize> activeIndex;
+
+  #if DRAC_USE_DBUS
+    if (const Result<dbus::Connection> connection = dbus::Connection::session())
+      if (const Result<dbus::Message> request = dbus::NewMethodCall("org.kde.keyboard", "/Layouts", "org.kde.KeyboardLayouts", "getLayout"))
+        if (const Result<dbus::Message> reply = connection->call(request->get())) {
+          dbus_uint32_t index = 0;
+
+          if (dbus_message_get_args(reply->get(), nullptr, DBUS_TYPE_UINT32, &index, DBUS_TYPE_INVALID))
+            activeIndex = index;
+        }
+  #endif
+
+    return draconis::os::xkb::SplitLayouts(*layouts, ReadIniValue(kxkbrc, "Layout", "VariantList").value_or(""), activeIndex);
+  }
+
   auto CollectNetworkInterfaces() -> Result<Map<String, NetworkInterface>> {
     ifaddrs* ifaddrList = nullptr;
     if (getifaddrs(&ifaddrList) == -1)
@@ -641,142 +987,1879 @@ namespace {
     if (interfaceMap.empty())
       ERR(NotFound, "No network interfaces found");
 
+    for (auto& [name, interface] : interfaceMap) {
+      using matchit::match, matchit::is, matchit::_;
+
+      const fs::path sysPath = fs::path("/sys/class/net") / name;
+
+      interface.mtu = ReadSysFile(sysPath / "mtu")
+                        .transform([](const String& mtu) -> Option<u32> { return TryParse<u32>(mtu); })
+                        .value_or(None);
+
+      // "speed" reads as -1 (or fails with EINVAL) while the link is down or for virtual devices.
+      interface.linkSpeedMbps = ReadSysFile(sysPath / "speed")
+                                  .transform([](const String& speed) -> Option<u64> { return TryParse<u64>(speed); })
+                                  .value_or(None);
+
+      if (Result<String> duplex = ReadSysFile(sysPath / "duplex"))
+        interface.duplex = match(*duplex)(
+          is | "full" = NetworkInterface::Duplex::Full,
+          is | "half" = NetworkInterface::Duplex::Half,
+          is | _      = NetworkInterface::Duplex::Unknown
+        );
+    }
+
     return interfaceMap;
   }
-} // namespace
 
-namespace draconis::core::system {
-  using draconis::utils::cache::CacheManager;
-  using draconis::utils::env::GetEnv;
+  auto ReadWirelessSignal(const StringView interfaceName) -> Option<i32> {
+    std::ifstream wirelessFile("/proc/net/wireless");
 
-  namespace linux {
-    auto GetDistroID(CacheManager& cache) -> Result<String> {
-      return cache.getOrSet<String>("linux_distro_id", []() -> Result<String> {
-        std::ifstream file("/etc/os-release");
+    if (!wirelessFile.is_open())
+      return None;
 
-        if (!file.is_open())
-          ERR(NotFound, "Failed to open /etc/os-release");
+    String line;
 
-        String line;
+    // Skip the two header lines
+    std::getline(wirelessFile, line);
+    std::getline(wirelessFile, line);
 
-        while (std::getline(file, line)) {
-          if (StringView(line).starts_with("ID=")) {
-            String value = line.substr(3);
+    while (std::getline(wirelessFile, line)) {
+      const usize colonPos = line.find(':');
 
-            if ((value.length() >= 2 && value.front() == '"' && value.back() == '"') || (value.length() >= 2 && value.front() == '\'' && value.back() == '\''))
-              value = value.substr(1, value.length() - 2);
+      if (colonPos == String::npos)
+        continue;
 
-            if (value.empty())
-              ERR(ParseError, "ID value is empty or only quotes in /etc/os-release");
+      StringView name(line.data(), colonPos);
+      name.remove_prefix(std::min(name.find_first_not_of(' '), name.size()));
 
-            return String(value);
-          }
-        }
+      if (name != interfaceName)
+        continue;
 
-        ERR(NotFound, "ID line not found in /etc/os-release");
-      });
+      std::istringstream iss(line.substr(colonPos + 1));
+      String             status;
+      f64                link  = 0;
+      f64                level = 0;
+
+      // Values are printed with a trailing '.' when they were updated since the last read, which >> ignores.
+      if (!(iss >> status >> link >> level))
+        return None;
+
+      // Some older drivers report the level as an unsigned byte rather than a signed dBm value.
+      const i32 dbm = static_cast<i32>(level);
+
+      return dbm > 63 ? dbm - 256 : dbm;
     }
-  } // namespace linux
 
-  auto GetOperatingSystem(CacheManager& cache) -> Result<OSInfo> {
-    return cache.getOrSet<OSInfo>("linux_os_version", []() -> Result<OSInfo> {
-      std::ifstream file("/etc/os-release");
+    return None;
+  }
 
-      if (!file.is_open())
-        ERR(NotFound, "Failed to open /etc/os-release");
+  auto ReadWirelessInfo(const String& interfaceName) -> Result<WifiInfo> {
+    const i32 sock = socket(AF_INET, SOCK_DGRAM | SOCK_CLOEXEC, 0);
 
-      String osName, osVersion, osId;
+    if (sock < 0)
+      ERR_FMT(InternalError, "socket() failed: {}", strerror(errno));
 
-      String line;
+    const auto query = [&](const unsigned long request, iwreq& req) -> bool {
+      std::strncpy(req.ifr_name, interfaceName.c_str(), IFNAMSIZ - 1);
+      return ioctl(sock, request, &req) == 0;
+    };
 
-      const auto parseValue = [&](String& val) -> void {
-        if (val.length() >= 2 && ((val.front() == '"' && val.back() == '"') || (val.front() == '\'' && val.back() == '\'')))
-          val = val.substr(1, val.length() - 2);
-      };
+    WifiInfo info {
+      .interfaceName = interfaceName,
+      .ssid          = None,
+      .bssid         = None,
+      .signalDbm     = None,
+      .signalPercent = None,
+      .frequencyMhz  = None,
+      .band          = WifiInfo::Band::Unknown,
+      .linkRateMbps  = None,
+    };
 
-      while (std::getline(file, line)) {
-        const StringView lineView = line;
+    Array<char, IW_ESSID_MAX_SIZE + 1> essid {};
 
-        if (lineView.starts_with("NAME=")) {
-          osName = lineView.substr(5);
-          parseValue(osName);
-        } else if (lineView.starts_with("VERSION=")) {
-          osVersion = lineView.substr(8);
-          parseValue(osVersion);
-        } else if (lineView.starts_with("ID=")) {
-          osId = lineView.substr(3);
-          parseValue(osId);
-        } else if (lineView.starts_with("PRETTY_NAME=") && osName.empty()) {
-          osName = lineView.substr(12);
-          parseValue(osName);
-        } else if (lineView.starts_with("VERSION_ID=") && osVersion.empty()) {
-          osVersion = lineView.substr(11);
-          parseValue(osVersion);
-        }
-      }
+    iwreq req {};
+    req.u.essid.pointer = essid.data();
+    req.u.essid.length  = essid.size();
 
-      if (osId.empty())
-        ERR(NotFound, "ID not found in /etc/os-release");
+    if (query(SIOCGIWESSID, req) && req.u.essid.length > 0)
+      info.ssid = String(essid.data(), std::min<usize>(req.u.essid.length, IW_ESSID_MAX_SIZE));
 
-      if (osName.empty())
-        ERR(NotFound, "NAME or PRETTY_NAME not found in /etc/os-release");
+    req = {};
 
-      if (osVersion.empty())
-        osVersion = "";
+    if (query(SIOCGIWAP, req)) {
+      // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
+      const auto* mac = reinterpret_cast<const u8*>(req.u.ap_addr.sa_data);
 
-      return OSInfo(osName, osVersion, osId);
-    });
-  }
+      // An all-zero BSSID means the interface isn't associated with an access point.
+      if (std::ranges::any_of(Span<const u8>(mac, 6), [](const u8 byte) -> bool { return byte != 0; }))
+        info.bssid = std::format("{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}", mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]);
+    }
 
-  auto GetMemInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
-    struct sysinfo info;
+    req = {};
 
-    if (sysinfo(&info) != 0)
-      ERR(ApiUnavailable, "sysinfo call failed");
+    // Drivers report either a frequency (m * 10^e Hz) or, with e == 0 and a small mantissa, a channel number.
+    if (query(SIOCGIWFREQ, req) && !(req.u.freq.e == 0 && req.u.freq.m < 1000)) {
+      f64 hertz = req.u.freq.m;
 
-    if (info.mem_unit == 0)
-      ERR(PlatformSpecific, "sysinfo.mem_unit is 0, cannot calculate memory");
+      for (i16 exponent = 0; exponent < req.u.freq.e; ++exponent)
+        hertz *= 10;
 
-    return ResourceUsage((info.totalram - info.freeram - info.bufferram) * info.mem_unit, info.totalram * info.mem_unit);
-  }
+      const u32 mhz = static_cast<u32>(hertz / 1e6);
 
-  auto GetWindowManager(CacheManager& cache) -> Result<String> {
-    // NOLINTNEXTLINE(misc-redundant-expression) - compile-time values are not always redundant
-    if constexpr (!DRAC_USE_WAYLAND && !DRAC_USE_XCB)
-      ERR(NotSupported, "Wayland or XCB support not available");
+      info.frequencyMhz = mhz;
+      info.band         = mhz < 3000 ? WifiInfo::Band::GHz2_4 : mhz < 5925 ? WifiInfo::Band::GHz5 : WifiInfo::Band::GHz6;
+    }
 
-    return cache.getOrSet<String>("linux_wm", [&]() -> Result<String> {
-      if (GetEnv("WAYLAND_DISPLAY"))
-        return GetWaylandCompositor();
+    req = {};
 
-      if (GetEnv("DISPLAY"))
-        return GetX11WindowManager();
+    if (query(SIOCGIWRATE, req) && req.u.bitrate.value > 0)
+      info.linkRateMbps = static_cast<f64>(req.u.bitrate.value) / 1e6;
 
-      ERR(NotFound, "No display server detected");
-    });
-  }
+    close(sock);
 
-  auto GetDesktopEnvironment(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("linux_desktop_environment", []() -> Result<String> {
-      Result<String> xdgEnvResult = GetEnv("XDG_CURRENT_DESKTOP");
+    if (!info.ssid && !info.bssid)
+      ERR_FMT(NotFound, "Wireless interface {} is not connected", interfaceName);
 
-      if (xdgEnvResult) {
-        String xdgDesktopSz = String(*xdgEnvResult);
+    if (const Option<i32> dbm = ReadWirelessSignal(interfaceName)) {
+      info.signalDbm     = *dbm;
+      info.signalPercent = draconis::os::unix_shared::SignalPercentFromDbm(*dbm);
+    }
 
-        if (const usize colonPos = xdgDesktopSz.find(':'); colonPos != String::npos)
-          xdgDesktopSz.resize(colonPos);
+    return info;
+  }
 
-        return xdgDesktopSz;
-      }
+  #if DRAC_USE_PULSEAUDIO
+  /**
+   * @brief A blocking connection to the PulseAudio (or pipewire-pulse) server.
+   *
+   * Owns a private mainloop, so introspection calls can be run to completion
+   * synchronously without a background thread.
+   */
+  class PulseConnection {
+   public:
+    static auto connect() -> Result<PulseConnection> {
+      PulseConnection connection;
 
-      Result<String> desktopSessionResult = GetEnv("DESKTOP_SESSION");
+      connection.m_mainloop = pa_mainloop_new();
 
-      if (desktopSessionResult)
-        return *desktopSessionResult;
+      if (!connection.m_mainloop)
+        ERR(ResourceExhausted, "pa_mainloop_new failed");
 
-      ERR_FMT(ApiUnavailable, "Failed to get desktop session: {}", desktopSessionResult.error().message);
-    });
-  }
+      connection.m_context = pa_context_new(pa_mainloop_get_api(connection.m_mainloop), "draconis++");
+
+      if (!connection.m_context)
+        ERR(ResourceExhausted, "pa_context_new failed");
+
+      // Never spawn a server just to answer a query.
+      if (pa_context_connect(connection.m_context, nullptr, PA_CONTEXT_NOAUTOSPAWN, nullptr) < 0)
+        ERR_FMT(ApiUnavailable, "Failed to connect to PulseAudio: {}", pa_strerror(pa_context_errno(connection.m_context)));
+
+      while (true) {
+        const pa_context_state_t state = pa_context_get_state(connection.m_context);
+
+        if (state == PA_CONTEXT_READY)
+          break;
+
+        if (!PA_CONTEXT_IS_GOOD(state))
+          ERR_FMT(ApiUnavailable, "PulseAudio connection failed: {}", pa_strerror(pa_context_errno(connection.m_context)));
+
+        if (pa_mainloop_iterate(connection.m_mainloop, 1, nullptr) < 0)
+          ERR(ApiUnavailable, "PulseAudio mainloop iteration failed");
+      }
+
+      return connection;
+    }
+
+    PulseConnection(PulseConnection&& other) noexcept
+      : m_mainloop(std::exchange(other.m_mainloop, nullptr)), m_context(std::exchange(other.m_context, nullptr)) {}
+
+    PulseConnection(const PulseConnection&)                    = delete;
+    auto operator=(const PulseConnection&) -> PulseConnection& = delete;
+    auto operator=(PulseConnection&&) -> PulseConnection&      = delete;
+
+    ~PulseConnection() {
+      if (m_context) {
+        pa_context_disconnect(m_context);
+        pa_context_unref(m_context);
+      }
+
+      if (m_mainloop)
+        pa_mainloop_free(m_mainloop);
+    }
+
+    [[nodiscard]] auto context() const -> pa_context* {
+      return m_context;
+    }
+
+    /**
+     * @brief Iterates the mainloop until the operation finishes, then releases it.
+     */
+    auto run(pa_operation* operation) const -> Result<> {
+      if (!operation)
+        ERR_FMT(ApiUnavailable, "PulseAudio operation failed: {}", pa_strerror(pa_context_errno(m_context)));
+
+      while (pa_operation_get_state(operation) == PA_OPERATION_RUNNING)
+        if (pa_mainloop_iterate(m_mainloop, 1, nullptr) < 0) {
+          pa_operation_cancel(operation);
+          pa_operation_unref(operation);
+          ERR(ApiUnavailable, "PulseAudio mainloop iteration failed");
+        }
+
+      pa_operation_unref(operation);
+      return {};
+    }
+
+   private:
+    pa_mainloop* m_mainloop = nullptr;
+    pa_context*  m_context  = nullptr;
+
+    PulseConnection() = default;
+  };
+
+  auto GetPulseAudioDevices() -> Result<Vec<AudioDevice>> {
+    struct Query {
+      String           defaultSink;
+      String           defaultSource;
+      Vec<AudioDevice> devices;
+    } query;
+
+    const PulseConnection connection = TRY(PulseConnection::connect());
+
+    TRY_VOID(connection.run(pa_context_get_server_info(
+      connection.context(),
+      [](pa_context* /*context*/, const pa_server_info* info, void* userdata) -> void {
+        auto* query = static_cast<Query*>(userdata);
+
+        if (info && info->default_sink_name)
+          query->defaultSink = info->default_sink_name;
+
+        if (info && info->default_source_name)
+          query->defaultSource = info->default_source_name;
+      },
+      &query
+    )));
+
+    TRY_VOID(connection.run(pa_context_get_sink_info_list(
+      connection.context(),
+      [](pa_context* /*context*/, const pa_sink_info* info, const i32 eol, void* userdata) -> void {
+        if (eol > 0 || !info)
+          return;
+
+        auto* query = static_cast<Query*>(userdata);
+
+        query->devices.push_back({
+          .direction  = AudioDevice::Direction::Output,
+          .id         = info->name,
+          .name       = info->description ? info->description : info->name,
+          .isDefault  = query->defaultSink == info->name,
+          .channels   = info->sample_spec.channels,
+          .sampleRate = info->sample_spec.rate,
+        });
+      },
+      &query
+    )));
+
+    TRY_VOID(connection.run(pa_context_get_source_info_list(
+      connection.context(),
+      [](pa_context* /*context*/, const pa_source_info* info, const i32 eol, void* userdata) -> void {
+        // Every sink has a ".monitor" source that loops back its output; those aren't real inputs.
+        if (eol > 0 || !info || info->monitor_of_sink != PA_INVALID_INDEX)
+          return;
+
+        auto* query = static_cast<Query*>(userdata);
+
+        query->devices.push_back({
+          .direction  = AudioDevice::Direction::Input,
+          .id         = info->name,
+          .name       = info->description ? info->description : info->name,
+          .isDefault  = query->defaultSource == info->name,
+          .channels   = info->sample_spec.channels,
+          .sampleRate = info->sample_spec.rate,
+        });
+      },
+      &query
+    )));
+
+    return std::move(query.devices);
+  }
+
+  /**
+   * @brief The default sink's name, channel count, and current volume state.
+   */
+  struct PulseSinkState {
+    String     name;
+    u8         channels = 0;
+    VolumeInfo volume   = { .percent = 0.0, .muted = false };
+  };
+
+  auto GetPulseDefaultSink(const PulseConnection& connection) -> Result<PulseSinkState> {
+    PulseSinkState sink;
+
+    TRY_VOID(connection.run(pa_context_get_server_info(
+      connection.context(),
+      [](pa_context* /*context*/, const pa_server_info* info, void* userdata) -> void {
+        if (info && info->default_sink_name)
+          static_cast<PulseSinkState*>(userdata)->name = info->default_sink_name;
+      },
+      &sink
+    )));
+
+    if (sink.name.empty())
+      ERR(NotFound, "PulseAudio has no default sink");
+
+    TRY_VOID(connection.run(pa_context_get_sink_info_by_name(
+      connection.context(),
+      sink.name.c_str(),
+      [](pa_context* /*context*/, const pa_sink_info* info, const i32 eol, void* userdata) -> void {
+        if (eol > 0 || !info)
+          return;
+
+        auto* sink = static_cast<PulseSinkState*>(userdata);
+
+        sink->channels       = info->volume.channels;
+        sink->volume.percent = static_cast<f64>(pa_cvolume_avg(&info->volume)) * 100.0 / PA_VOLUME_NORM;
+        sink->volume.muted   = info->mute != 0;
+      },
+      &sink
+    )));
+
+    if (sink.channels == 0)
+      ERR_FMT(NotFound, "PulseAudio sink '{}' disappeared while querying it", sink.name);
+
+    return sink;
+  }
+
+  auto GetPulseAudioVolume() -> Result<VolumeInfo> {
+    const PulseConnection connection = TRY(PulseConnection::connect());
+
+    return TRY(GetPulseDefaultSink(connection)).volume;
+  }
+
+  auto SetPulseAudioVolume(const f64 percent) -> Result<> {
+    const PulseConnection connection = TRY(PulseConnection::connect());
+    const PulseSinkState  sink       = TRY(GetPulseDefaultSink(connection));
+
+    pa_cvolume volume;
+    pa_cvolume_set(&volume, sink.channels, static_cast<pa_volume_t>(std::max(percent, 0.0) / 100.0 * PA_VOLUME_NORM));
+
+    return connection.run(pa_context_set_sink_volume_by_name(connection.context(), sink.name.c_str(), &volume, nullptr, nullptr));
+  }
+
+  auto SetPulseAudioMuted(const bool muted) -> Result<> {
+    const PulseConnection connection = TRY(PulseConnection::connect());
+    const PulseSinkState  sink       = TRY(GetPulseDefaultSink(connection));
+
+    return connection.run(pa_context_set_sink_mute_by_name(connection.context(), sink.name.c_str(), muted ? 1 : 0, nullptr, nullptr));
+  }
+  #else
+  auto GetPulseAudioDevices() -> Result<Vec<AudioDevice>> {
+    ERR(NotSupported, "PulseAudio support not available");
+  }
+
+  auto GetPulseAudioVolume() -> Result<VolumeInfo> {
+    ERR(NotSupported, "PulseAudio support not available");
+  }
+
+  auto SetPulseAudioVolume(const f64 /*percent*/) -> Result<> {
+    ERR(NotSupported, "PulseAudio support not available");
+  }
+
+  auto SetPulseAudioMuted(const bool /*muted*/) -> Result<> {
+    ERR(NotSupported, "PulseAudio support not available");
+  }
+  #endif
+
+  #if DRAC_USE_DBUS
+  auto GetBlueZDevices() -> Result<BluetoothInfo> {
+    const dbus::Connection     connection = TRY(dbus::Connection::system());
+    const dbus::ManagedObjects objects    = TRY(connection.getManagedObjects("org.bluez"));
+
+    BluetoothInfo info;
+
+    for (const auto& [path, interfaces] : objects) {
+      if (const auto adapter = interfaces.find("org.bluez.Adapter1"); adapter != interfaces.end()) {
+        const dbus::Properties& props = adapter->second;
+
+        info.adapters.push_back({
+          .name    = dbus::GetProperty<String>(props, "Alias").value_or(path),
+          .address = dbus::GetProperty<String>(props, "Address").value_or(""),
+          .powered = dbus::GetProperty<bool>(props, "Powered").value_or(false),
+        });
+
+        continue;
+      }
+
+      const auto device = interfaces.find("org.bluez.Device1");
+
+      if (device == interfaces.end())
+        continue;
+
+      const dbus::Properties& props = device->second;
+
+      const bool paired    = dbus::GetProperty<bool>(props, "Paired").value_or(false);
+      const bool connected = dbus::GetProperty<bool>(props, "Connected").value_or(false);
+
+      // BlueZ also exposes every device seen during discovery; only report the ones actually in use.
+      if (!paired && !connected)
+        continue;
+
+      Option<u8> batteryPercent = None;
+
+      if (const auto battery = interfaces.find("org.bluez.Battery1"); battery != interfaces.end())
+        if (const Option<i64> percentage = dbus::GetProperty<i64>(battery->second, "Percentage"))
+          batteryPercent = static_cast<u8>(std::clamp<i64>(*percentage, 0, 100));
+
+      String address = dbus::GetProperty<String>(props, "Address").value_or("");
+
+      info.devices.push_back({
+        .name           = dbus::GetProperty<String>(props, "Alias").value_or(address),
+        .address        = std::move(address),
+        .paired         = paired,
+        .connected      = connected,
+        .batteryPercent = batteryPercent,
+      });
+    }
+
+    if (info.adapters.empty())
+      ERR(NotFound, "No Bluetooth adapters found");
+
+    return info;
+  }
+  #else
+  auto GetBlueZDevices() -> Result<BluetoothInfo> {
+    ERR(NotSupported, "D-Bus support not available");
+  }
+  #endif
+
+  auto ReadProcessInfo(const u32 pid, const f64 uptimeSecs, UnorderedMap<uid_t, Option<String>>& userNames) -> Result<ProcessInfo> {
+    using matchit::match, matchit::is, matchit::or_, matchit::_;
+    using enum ProcessInfo::State;
+
+    const fs::path procPath = fs::path("/proc") / std::to_string(pid);
+
+    std::ifstream statFile(procPath / "stat");
+
+    if (!statFile.is_open())
+      ERR_FMT(NotFound, "Process {} does not exist", pid);
+
+    String stat;
+    std::getline(statFile, stat);
+
+    // The command name is wrapped in parentheses and may itself contain spaces or
+    // parentheses, so everything after the *last* closing parenthesis is the field list.
+    const usize openPos  = stat.find('(');
+    const usize closePos = stat.rfind(')');
+
+    if (openPos == String::npos || closePos == String::npos || closePos + 2 >= stat.size())
+      ERR_FMT(ParseError, "Malformed /proc/{}/stat", pid);
+
+    // Field indices below are relative to the state field (field 3 in proc(5)).
+    Vec<StringView> fields;
+
+    for (auto part : StringView(stat).substr(closePos + 2) | std::views::split(' '))
+      fields.emplace_back(part.begin(), part.end());
+
+    if (fields.size() < 22)
+      ERR_FMT(ParseError, "Too few fields in /proc/{}/stat", pid);
+
+    static const u64 ClockTicks = static_cast<u64>(sysconf(_SC_CLK_TCK));
+    static const u64 PageSize   = static_cast<u64>(sysconf(_SC_PAGESIZE));
+
+    const u64 cpuTicks   = TryParse<u64>(fields[11]).value_or(0) + TryParse<u64>(fields[12]).value_or(0);
+    const u64 startTicks = TryParse<u64>(fields[19]).value_or(0);
+    const f64 ageSecs    = uptimeSecs - (static_cast<f64>(startTicks) / static_cast<f64>(ClockTicks));
+
+    ProcessInfo info {};
+
+    info.pid        = pid;
+    info.ppid       = TryParse<u32>(fields[1]).value_or(0);
+    info.name       = stat.substr(openPos + 1, closePos - openPos - 1);
+    info.rssBytes   = TryParse<u64>(fields[21]).value_or(0) * PageSize;
+    info.cpuPercent = ageSecs > 0.0 ? (static_cast<f64>(cpuTicks) / static_cast<f64>(ClockTicks)) / ageSecs * 100.0 : 0.0;
+    info.state      = match(fields[0].front())(
+      is | 'R'           = Running,
+      is | 'S'           = Sleeping,
+      is | 'D'           = DiskSleep,
+      is | or_('T', 't') = Stopped,
+      is | or_('Z', 'X') = Zombie,
+      is | 'I'           = Idle,
+      is | _             = Unknown
+    );
+
+    // Arguments are NUL-separated; kernel threads have an empty cmdline.
+    if (std::ifstream cmdlineFile(procPath / "cmdline", std::ios::binary); cmdlineFile) {
+      info.cmdline.assign(std::istreambuf_iterator<char>(cmdlineFile), std::istreambuf_iterator<char>());
+
+      while (!info.cmdline.empty() && info.cmdline.back() == '\0')
+        info.cmdline.pop_back();
+
+      std::ranges::replace(info.cmdline, '\0', ' ');
+    }
+
+    if (struct stat procStat {}; ::stat(procPath.c_str(), &procStat) == 0) {
+      auto iter = userNames.find(procStat.st_uid);
+
+      if (iter == userNames.end())
+        iter = userNames.emplace(procStat.st_uid, draconis::os::unix_shared::GetUserNameFromUid(procStat.st_uid)).first;
+
+      info.user = iter->second;
+    }
+
+    return info;
+  }
+
+  auto ReadUptimeSeconds() -> Result<f64> {
+    std::ifstream file("/proc/uptime");
+    f64           uptime = 0.0;
+
+    if (!(file >> uptime))
+      ERR(IoError, "Failed to read /proc/uptime");
+
+    return uptime;
+  }
+
+  // Finds the cgroup v2 directory a container's processes were placed in, which depends on the
+  // runtime and (for Docker) on whether it uses the systemd or cgroupfs cgroup driver.
+  auto FindContainerCgroup(const ContainerInfo& container) -> Option<fs::path> {
+    using enum ContainerInfo::Runtime;
+
+    const fs::path root = "/sys/fs/cgroup";
+    const uid_t    uid  = getuid();
+
+    Vec<fs::path> candidates;
+
+    switch (container.runtime) {
+      case Docker:
+        candidates = { root / "system.slice" / std::format("docker-{}.scope", container.id), root / "docker" / container.id };
+        break;
+      case Podman:
+        candidates = {
+          root / "machine.slice" / std::format("libpod-{}.scope", container.id),
+          root / "user.slice" / std::format("user-{}.slice", uid) / std::format("user@{}.service", uid) / "user.slice" / std::format("libpod-{}.scope", container.id),
+        };
+        break;
+      case Lxc: candidates = { root / std::format("lxc.payload.{}", container.name) }; break;
+    }
+
+    for (fs::path& candidate : candidates)
+      if (std::error_code errc; fs::is_directory(candidate, errc))
+        return std::move(candidate);
+
+    return None;
+  }
+
+  // Fills in memory and CPU usage from the container's cgroup. CPU time is averaged over the
+  // lifetime of the container's first process, the same way ProcessInfo::cpuPercent is.
+  auto ReadContainerUsage(ContainerInfo& container, const f64 uptimeSecs) -> void {
+    const Option<fs::path> cgroup = FindContainerCgroup(container);
+
+    if (!cgroup)
+      return;
+
+    if (Result<String> memory = ReadSysFile(*cgroup / "memory.current"))
+      container.memoryBytes = TryParse<u64>(*memory);
+
+    Option<u64> usageUsec;
+
+    if (std::ifstream cpuStat(*cgroup / "cpu.stat"); cpuStat) {
+      String key;
+      u64    value = 0;
+
+      while (cpuStat >> key >> value)
+        if (key == "usage_usec") {
+          usageUsec = value;
+          break;
+        }
+    }
+
+    u32 initPid = 0;
+
+    if (std::ifstream procs(*cgroup / "cgroup.procs"); !usageUsec || !(procs >> initPid))
+      return;
+
+    std::ifstream statFile(fs::path("/proc") / std::to_string(initPid) / "stat");
+    String        stat;
+
+    if (!std::getline(statFile, stat))
+      return;
+
+    // Same layout as in ReadProcessInfo: start time is the 20th field after the command name.
+    const usize closePos = stat.rfind(')');
+
+    if (closePos == String::npos || closePos + 2 >= stat.size())
+      return;
+
+    Vec<StringView> fields;
+
+    for (auto part : StringView(stat).substr(closePos + 2) | std::views::split(' '))
+      fields.emplace_back(part.begin(), part.end());
+
+    if (fields.size() < 20)
+      return;
+
+    static const u64 ClockTicks = static_cast<u64>(sysconf(_SC_CLK_TCK));
+
+    const f64 ageSecs = uptimeSecs - (static_cast<f64>(TryParse<u64>(fields[19]).value_or(0)) / static_cast<f64>(ClockTicks));
+
+    if (ageSecs > 0.0)
+      container.cpuPercent = (static_cast<f64>(*usageUsec) / 1'000'000.0) / ageSecs * 100.0;
+  }
+
+  // LXC has no daemon to ask, but every running container has a cgroup named after it.
+  auto ListLxcContainers() -> Vec<ContainerInfo> {
+    Vec<ContainerInfo> containers;
+    std::error_code    errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/fs/cgroup", errc)) {
+      const String dirName = entry.path().filename().string();
+
+      if (!dirName.starts_with("lxc.payload.") || !entry.is_directory(errc))
+        continue;
+
+      String name = dirName.substr(std::char_traits<char>::length("lxc.payload."));
+
+      // A frozen cgroup is how `lxc-freeze` pauses a container.
+      const bool frozen = ReadSysFile(entry.path() / "cgroup.freeze").value_or("0") == "1";
+
+      containers.push_back({
+        .runtime     = ContainerInfo::Runtime::Lxc,
+        .state       = frozen ? ContainerInfo::State::Paused : ContainerInfo::State::Running,
+        .id          = name,
+        .name        = std::move(name),
+        .image       = None,
+        .cpuPercent  = None,
+        .memoryBytes = None,
+      });
+    }
+
+    return containers;
+  }
+
+  constexpr u8 SMART_READ_DATA       = 0xD0;
+  constexpr u8 SMART_READ_THRESHOLDS = 0xD1;
+  constexpr u8 SMART_RETURN_STATUS   = 0xDA;
+
+  /**
+   * @brief Issues an ATA SMART command through the SCSI ATA PASS-THROUGH (16) translation.
+   *
+   * libata and most USB-SATA bridges implement the translation, so this works for every
+   * drive that shows up as /dev/sdX. Commands that read data fill @p data; RETURN STATUS
+   * instead reports its result in the LBA registers, which come back in @p sense.
+   */
+  auto SendSmartCommand(const i32 filedesc, const u8 feature, Span<u8> data, Span<u8> sense) -> Result<> {
+    const bool readsData = !data.empty();
+
+    Array<u8, 16> cdb {};
+    cdb[0]  = 0x85;                            // ATA PASS-THROUGH (16)
+    cdb[1]  = readsData ? (4 << 1) : (3 << 1); // Protocol: PIO data-in / non-data
+    cdb[2]  = readsData ? 0x0E : 0x20;         // Data-in, length in sectors / return the registers (CK_COND)
+    cdb[4]  = feature;                         // SMART subcommand
+    cdb[6]  = readsData ? 1 : 0;               // Sector count
+    cdb[10] = 0x4F;                            // LBA mid: SMART signature
+    cdb[12] = 0xC2;                            // LBA high: SMART signature
+    cdb[14] = 0xB0;                            // Command: SMART
+
+    sg_io_hdr_t header {};
+    header.interface_id    = 'S';
+    header.dxfer_direction = readsData ? SG_DXFER_FROM_DEV : SG_DXFER_NONE;
+    header.cmd_len         = cdb.size();
+    header.cmdp            = cdb.data();
+    header.dxfer_len       = static_cast<u32>(data.size());
+    header.dxferp          = data.data();
+    header.mx_sb_len       = static_cast<u8>(sense.size());
+    header.sbp             = sense.data();
+    header.timeout         = 3000;
+
+    if (ioctl(filedesc, SG_IO, &header) == -1) {
+      if (errno == EPERM || errno == EACCES)
+        ERR(PermissionRequired, "Reading SMART data requires root privileges");
+
+      ERR_FMT(IoError, "SG_IO failed: {}", std::strerror(errno));
+    }
+
+    // CK_COND always reports a check condition, so only data transfers are expected to succeed cleanly.
+    if (readsData && (header.masked_status != 0 || header.host_status != 0))
+      ERR(NotSupported, "The drive rejected the SMART command (SMART unsupported or disabled)");
+
+    return {};
+  }
+
+  auto ReadAtaHealth(const i32 filedesc) -> Result<DiskHealth> {
+    Array<u8, draconis::os::smart::SMART_PAGE_SIZE> data {};
+    Array<u8, draconis::os::smart::SMART_PAGE_SIZE> thresholds {};
+    Array<u8, 32>                                  sense {};
+
+    TRY(SendSmartCommand(filedesc, SMART_READ_DATA, data, sense));
+
+    const bool hasThresholds = SendSmartCommand(filedesc, SMART_READ_THRESHOLDS, thresholds, sense).has_value();
+
+    DiskHealth health = draconis::os::smart::ParseAtaSmartData(data, hasThresholds ? Option<Span<const u8>>(thresholds) : None);
+
+    // The drive's own verdict comes back in an ATA Status Return sense descriptor (0x09) as LBA mid/high.
+    sense = {};
+
+    if (SendSmartCommand(filedesc, SMART_RETURN_STATUS, {}, sense) && (sense[0] & 0x7F) == 0x72 && sense[8] == 0x09) {
+      const u8 lbaMid  = sense[17];
+      const u8 lbaHigh = sense[19];
+
+      if (lbaMid == 0x4F && lbaHigh == 0xC2)
+        health.status = DiskHealth::Status::Passed;
+      else if (lbaMid == 0xF4 && lbaHigh == 0x2C)
+        health.status = DiskHealth::Status::Failing;
+    }
+
+    return health;
+  }
+
+  auto ReadNvmeHealth(const i32 filedesc) -> Result<DiskHealth> {
+    Array<u8, draconis::os::smart::SMART_PAGE_SIZE> log {};
+
+    nvme_admin_cmd command {};
+    command.opcode   = 0x02;       // Get Log Page
+    command.nsid     = 0xFFFFFFFF; // Controller-wide
+    // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
+    command.addr     = reinterpret_cast<u64>(log.data());
+    command.data_len = static_cast<u32>(log.size());
+    command.cdw10    = (((log.size() / 4) - 1) << 16) | 0x02; // Number of dwords - 1, SMART / Health Information log
+
+    if (ioctl(filedesc, NVME_IOCTL_ADMIN_CMD, &command) != 0) {
+      if (errno == EPERM || errno == EACCES)
+        ERR(PermissionRequired, "Reading the NVMe health log requires root privileges");
+
+      ERR_FMT(IoError, "NVMe Get Log Page failed: {}", errno != 0 ? std::strerror(errno) : "controller returned an error");
+    }
+
+    return draconis::os::smart::ParseNvmeHealthLog(log);
+  }
+
+  auto DetectInitSystem() -> InitSystem {
+    using enum InitSystem::Kind;
+
+    // comm is readable by everyone, unlike the /proc/1/exe link.
+    String pid1 = ReadSysFile("/proc/1/comm").value_or("");
+
+    std::error_code errc;
+
+    // sd_booted() checks the same directory, which systemd creates early on and nothing else does.
+    if (fs::exists("/run/systemd/system", errc))
+      return { .kind = Systemd, .name = std::move(pid1) };
+
+    // OpenRC usually runs on top of SysV init, so PID 1 alone doesn't reveal it.
+    if (pid1 == "openrc-init" || fs::exists("/run/openrc/softlevel", errc))
+      return { .kind = OpenRC, .name = std::move(pid1) };
+
+    using matchit::match, matchit::is, matchit::_;
+
+    const InitSystem::Kind kind = match(StringView(pid1))(
+      is | "runit"     = Runit,
+      is | "s6-svscan" = S6,
+      is | "dinit"     = Dinit,
+      is | "init"      = SysVinit,
+      is | _           = Unknown
+    );
+
+    return { .kind = kind, .name = std::move(pid1) };
+  }
+
+  auto GetOpenRCService(const StringView name) -> Result<ServiceInfo> {
+    using enum ServiceInfo::State;
+
+    if (name.empty() || name.contains('/'))
+      ERR_FMT(InvalidArgument, "Invalid OpenRC service name '{}'", name);
+
+    const fs::path script = fs::path("/etc/init.d") / name;
+
+    std::error_code errc;
+
+    if (!fs::exists(script, errc))
+      ERR_FMT(NotFound, "No OpenRC service named '{}'", name);
+
+    ServiceInfo service { .state = Stopped, .name = String(name), .description = None, .enabled = false };
+
+    // OpenRC records the state of each service as a symlink in a per-state directory.
+    // clang-format off
+    constexpr Array<Pair<PCStr, ServiceInfo::State>, 4> stateDirs = {{
+      { "started",  Running  },
+      { "starting", Starting },
+      { "stopping", Stopping },
+      { "failed",   Failed   },
+    }};
+    // clang-format on
+
+    for (const auto& [dir, state] : stateDirs)
+      if (fs::exists(fs::path("/run/openrc") / dir / name, errc)) {
+        service.state = state;
+        break;
+      }
+
+    // Init scripts describe themselves with a description="..." variable.
+    if (std::ifstream scriptFile(script); scriptFile.is_open())
+      for (String line; std::getline(scriptFile, line);) {
+        if (!line.starts_with("description="))
+          continue;
+
+        String value = line.substr(12);
+
+        if (value.size() >= 2 && (value.front() == '"' || value.front() == '\'') && value.back() == value.front())
+          value = value.substr(1, value.size() - 2);
+
+        if (!value.empty())
+          service.description = std::move(value);
+
+        break;
+      }
+
+    // A service is enabled when it's been added to any runlevel (e.g. "default" or "boot").
+    for (const fs::directory_entry& runlevel : fs::directory_iterator("/etc/runlevels", errc))
+      if (fs::exists(runlevel.path() / name, errc)) {
+        service.enabled = true;
+        break;
+      }
+
+    return service;
+  }
+
+  auto ListOpenRCServices() -> Result<Vec<ServiceInfo>> {
+    Vec<ServiceInfo> services;
+
+    std::error_code errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/etc/init.d", errc)) {
+      const String name = entry.path().filename().string();
+
+      // Gentoo keeps its shell helpers (functions.sh) next to the init scripts.
+      if (name.ends_with(".sh"))
+        continue;
+
+      if (Result<ServiceInfo> service = GetOpenRCService(name))
+        services.push_back(std::move(*service));
+    }
+
+    if (errc)
+      ERR_FMT(IoError, "Failed to list /etc/init.d: {}", errc.message());
+
+    return services;
+  }
+
+  #if DRAC_USE_DBUS
+  constexpr PCStr SYSTEMD_SERVICE = "org.freedesktop.systemd1";
+  constexpr PCStr SYSTEMD_PATH    = "/org/freedesktop/systemd1";
+  constexpr PCStr SYSTEMD_MANAGER = "org.freedesktop.systemd1.Manager";
+
+  auto ServiceStateFromSystemd(const StringView activeState) -> ServiceInfo::State {
+    using matchit::match, matchit::is, matchit::or_, matchit::_;
+    using enum ServiceInfo::State;
+
+    return match(activeState)(
+      is | "active"                       = Running,
+      is | "inactive"                     = Stopped,
+      is | or_("activating", "reloading") = Starting,
+      is | "deactivating"                 = Stopping,
+      is | "failed"                       = Failed,
+      is | _                              = Unknown
+    );
+  }
+
+  // "static", "indirect" and "generated" units have no install state of their own, so they're left unknown.
+  auto EnabledFromUnitFileState(const StringView state) -> Option<bool> {
+    if (state == "enabled" || state == "enabled-runtime" || state == "alias")
+      return true;
+
+    if (state == "disabled" || state == "masked" || state == "masked-runtime")
+      return false;
+
+    return None;
+  }
+
+  /**
+   * @brief Reads the a(ssssssouso) unit list returned by ListUnitsByNames and ListUnitsByPatterns.
+   * @details Only the name, description, load state and active state are used. Units systemd
+   * couldn't find a unit file for (load state "not-found") are skipped.
+   */
+  auto ReadSystemdUnits(DBusMessage* reply) -> Vec<ServiceInfo> {
+    Vec<ServiceInfo> services;
+
+    DBusMessageIter root;
+
+    if (!dbus_message_iter_init(reply, &root) || dbus_message_iter_get_arg_type(&root) != DBUS_TYPE_ARRAY)
+      return services;
+
+    DBusMessageIter units;
+    dbus_message_iter_recurse(&root, &units);
+
+    while (dbus_message_iter_get_arg_type(&units) == DBUS_TYPE_STRUCT) {
+      DBusMessageIter unit;
+      dbus_message_iter_recurse(&units, &unit);
+
+      Array<PCStr, 4> fields {};
+
+      for (PCStr& field : fields) {
+        if (dbus_message_iter_get_arg_type(&unit) != DBUS_TYPE_STRING)
+          break;
+
+        dbus_message_iter_get_basic(&unit, &field);
+        dbus_message_iter_next(&unit);
+      }
+
+      const auto& [name, description, loadState, activeState] = fields;
+
+      if (name && description && loadState && activeState && StringView(loadState) != "not-found")
+        services.push_back({
+          .state       = ServiceStateFromSystemd(activeState),
+          .name        = name,
+          .description = *description ? Option<String>(description) : None,
+          .enabled     = None,
+        });
+
+      dbus_message_iter_next(&units);
+    }
+
+    return services;
+  }
+
+  auto GetSystemdService(const StringView name) -> Result<ServiceInfo> {
+    constexpr Array<StringView, 11> unitSuffixes = {
+      ".service", ".socket", ".target", ".timer", ".mount", ".automount", ".path", ".swap", ".slice", ".scope", ".device",
+    };
+
+    // Like systemctl, treat a name without a unit type suffix as a service.
+    const bool   hasSuffix = std::ranges::any_of(unitSuffixes, [&](const StringView suffix) { return name.ends_with(suffix); });
+    const String unitName  = hasSuffix ? String(name) : std::format("{}.service", name);
+
+    const dbus::Connection connection = TRY(dbus::Connection::system());
+    const dbus::Message    request    = TRY(dbus::NewMethodCall(SYSTEMD_SERVICE, SYSTEMD_PATH, SYSTEMD_MANAGER, "ListUnitsByNames"));
+
+    DBusMessageIter args;
+    dbus_message_iter_init_append(request.get(), &args);
+
+    if (const Array<PCStr, 1> names = { unitName.c_str() }; !dbus::AppendStringArray(&args, names))
+      ERR(OutOfMemory, "Failed to append D-Bus method arguments");
+
+    const dbus::Message reply = TRY(connection.call(request.get()));
+
+    Vec<ServiceInfo> units = ReadSystemdUnits(reply.get());
+
+    if (units.empty())
+      ERR_FMT(NotFound, "No systemd unit named '{}'", unitName);
+
+    ServiceInfo service = std::move(units.front());
+
+    // Fails for units without a unit file (e.g. transient ones), which just leaves `enabled` unknown.
+    if (Result<dbus::Message> stateRequest = dbus::NewMethodCall(SYSTEMD_SERVICE, SYSTEMD_PATH, SYSTEMD_MANAGER, "GetUnitFileState")) {
+      PCStr unit = unitName.c_str();
+
+      if (dbus_message_append_args(stateRequest->get(), DBUS_TYPE_STRING, &unit, DBUS_TYPE_INVALID))
+        if (Result<dbus::Message> stateReply = connection.call(stateRequest->get())) {
+          PCStr state = nullptr;
+
+          if (dbus_message_get_args(stateReply->get(), nullptr, DBUS_TYPE_STRING, &state, DBUS_TYPE_INVALID))
+            service.enabled = EnabledFromUnitFileState(state);
+        }
+    }
+
+    return service;
+  }
+
+  auto ListSystemdServices() -> Result<Vec<ServiceInfo>> {
+    constexpr Array<PCStr, 1> patterns = { "*.service" };
+
+    const dbus::Connection connection = TRY(dbus::Connection::system());
+
+    // Both methods take a list of states to filter by (empty for all) and a list of name patterns.
+    const auto callWithPatterns = [&](const PCStr method) -> Result<dbus::Message> {
+      const dbus::Message request = TRY(dbus::NewMethodCall(SYSTEMD_SERVICE, SYSTEMD_PATH, SYSTEMD_MANAGER, method));
+
+      DBusMessageIter args;
+      dbus_message_iter_init_append(request.get(), &args);
+
+      if (!dbus::AppendStringArray(&args, {}) || !dbus::AppendStringArray(&args, patterns))
+        ERR(OutOfMemory, "Failed to append D-Bus method arguments");
+
+      return connection.call(request.get());
+    };
+
+    const dbus::Message unitsReply = TRY(callWithPatterns("ListUnitsByPatterns"));
+
+    Vec<ServiceInfo> services = ReadSystemdUnits(unitsReply.get());
+
+    // Only loaded units are listed above; the unit files add whether each starts at boot, and the services that aren't loaded.
+    Result<dbus::Message> filesReply = callWithPatterns("ListUnitFilesByPatterns");
+
+    if (!filesReply) {
+      debug_at(filesReply.error());
+      return services;
+    }
+
+    UnorderedMap<String, Option<bool>> unitFiles;
+
+    DBusMessageIter root;
+
+    if (dbus_message_iter_init(filesReply->get(), &root) && dbus_message_iter_get_arg_type(&root) == DBUS_TYPE_ARRAY) {
+      DBusMessageIter files;
+      dbus_message_iter_recurse(&root, &files);
+
+      // a(ss): unit file path, unit file state
+      while (dbus_message_iter_get_arg_type(&files) == DBUS_TYPE_STRUCT) {
+        DBusMessageIter file;
+        dbus_message_iter_recurse(&files, &file);
+
+        PCStr path  = nullptr;
+        PCStr state = nullptr;
+
+        dbus_message_iter_get_basic(&file, &path);
+
+        if (path && dbus_message_iter_next(&file))
+          dbus_message_iter_get_basic(&file, &state);
+
+        // Templates (foo@.service) can't run by themselves; their instances show up as loaded units.
+        if (path && state)
+          if (String name = fs::path(path).filename().string(); !name.ends_with("@.service"))
+            unitFiles.emplace(std::move(name), EnabledFromUnitFileState(state));
+
+        dbus_message_iter_next(&files);
+      }
+    }
+
+    for (ServiceInfo& service : services)
+      if (const auto iter = unitFiles.find(service.name); iter != unitFiles.end()) {
+        service.enabled = iter->second;
+        unitFiles.erase(iter);
+      }
+
+    for (auto& [name, enabled] : unitFiles)
+      services.push_back({ .state = ServiceInfo::State::Stopped, .name = name, .description = None, .enabled = enabled });
+
+    return services;
+  }
+  #endif
+
+  // Maps both power-profiles-daemon's profile names and the ACPI platform profile choices.
+  auto PowerModeFromName(const StringView name) -> PowerProfile::Mode {
+    using matchit::match, matchit::is, matchit::or_, matchit::_;
+    using enum PowerProfile::Mode;
+
+    return match(name)(
+      is | or_("power-saver", "low-power", "cool", "quiet") = PowerSaver,
+      is | "balanced"                                       = Balanced,
+      is | or_("performance", "balanced-performance")       = Performance,
+      is | _                                                = Unknown
+    );
+  }
+
+  // Reads the brightness (VCP feature 0x10) of the monitor on an I2C bus using DDC/CI.
+  auto ReadDdcBrightness(const fs::path& bus) -> Option<f64> {
+    constexpr u8  DDC_ADDRESS     = 0x37;
+    constexpr u8  HOST_ADDRESS    = 0x51;
+    constexpr u8  DISPLAY_ADDRESS = 0x6E;
+    constexpr u8  GET_VCP         = 0x01;
+    constexpr u8  GET_VCP_REPLY   = 0x02;
+    constexpr u8  BRIGHTNESS_CODE = 0x10;
+    constexpr u8  LENGTH_FLAG     = 0x80;
+    constexpr u32 REPLY_DELAY_MS  = 40; ///< The minimum wait the DDC/CI spec requires before reading a reply.
+
+    const i32 filedesc = open(bus.c_str(), O_RDWR | O_CLOEXEC);
+
+    if (filedesc == -1)
+      return None;
+
+    Option<f64> percent;
+
+    // The checksum XORs every byte, including the destination address the kernel adds.
+    Array<u8, 5> request = { HOST_ADDRESS, LENGTH_FLAG | 2, GET_VCP, BRIGHTNESS_CODE, 0 };
+    request[4]           = DISPLAY_ADDRESS ^ request[0] ^ request[1] ^ request[2] ^ request[3];
+
+    if (ioctl(filedesc, I2C_SLAVE, DDC_ADDRESS) == 0 && write(filedesc, request.data(), request.size()) == static_cast<ssize_t>(request.size())) {
+      std::this_thread::sleep_for(std::chrono::milliseconds(REPLY_DELAY_MS));
+
+      // Source address, length, opcode, result, feature code, type, max (2 bytes), current (2 bytes), checksum.
+      Array<u8, 11> reply {};
+
+      if (read(filedesc, reply.data(), reply.size()) == static_cast<ssize_t>(reply.size()) && reply[2] == GET_VCP_REPLY && reply[3] == 0 && reply[4] == BRIGHTNESS_CODE) {
+        const u16 maximum = static_cast<u16>((reply[6] << 8) | reply[7]);
+        const u16 current = static_cast<u16>((reply[8] << 8) | reply[9]);
+
+        if (maximum > 0)
+          percent = std::min(100.0, static_cast<f64>(current) * 100.0 / maximum);
+      }
+    }
+
+    close(filedesc);
+
+    return percent;
+  }
+
+  // Reads a power_supply entry of type "Battery".
+  auto ReadBattery(const fs::path& batteryPath) -> Battery {
+    using matchit::match, matchit::is, matchit::_;
+    using enum Battery::Status;
+
+    // Read battery percentage
+    Option<u8> percentage =
+      ReadSysFile(batteryPath / "capacity")
+        .transform([](const String& capacityStr) -> Option<u8> {
+          return TryParse<u8>(capacityStr);
+        })
+        .value_or(None);
+
+    // Read battery status
+    Battery::Status status =
+      ReadSysFile(batteryPath / "status")
+        .transform([percentage](const String& statusStr) -> Battery::Status {
+          return match(statusStr)(
+            is | "Charging"     = Charging,
+            is | "Discharging"  = Discharging,
+            is | "Full"         = Full,
+            is | "Not charging" = (percentage && *percentage == 100 ? Full : Discharging),
+            is | _              = Unknown
+          );
+        })
+        .value_or(Unknown);
+
+    Option<std::chrono::seconds> timeRemaining;
+
+    if (status == Charging || status == Discharging)
+      timeRemaining =
+        ReadSysFile(
+          batteryPath / std::format("/time_to_{}now", status == Discharging ? "empty" : "full")
+        )
+          .transform([](const String& timeStr) -> Option<std::chrono::seconds> {
+            if (Option<i32> timeMinutes = TryParse<i32>(timeStr); timeMinutes && *timeMinutes > 0)
+              return std::chrono::minutes(*timeMinutes);
+
+            return None;
+          })
+          .value_or(None);
+
+    Battery battery(status, percentage, timeRemaining);
+
+    // Batteries of HID peripherals (hid-*, hidpp_battery_*), including Bluetooth ones, are scoped to the device they power.
+    battery.name         = batteryPath.filename().string();
+    battery.isPeripheral = ReadSysFile(batteryPath / "scope").value_or("") == "Device";
+
+    return battery;
+  }
+
+  auto ReadSupplyWatts(const fs::path& supplyPath) -> Option<f64> {
+    // Both are in micro-units; prefer the advertised maximum, since *_now follows the load.
+    constexpr Array<Pair<PCStr, PCStr>, 2> attributes {{
+      { "voltage_max", "current_max" },
+      { "voltage_now", "current_now" },
+    }};
+
+    const auto readMicro = [&supplyPath](PCStr file) -> Option<u64> {
+      return ReadSysFile(supplyPath / file)
+        .transform([](const String& value) -> Option<u64> { return TryParse<u64>(value); })
+        .value_or(None);
+    };
+
+    for (const auto& [voltageFile, currentFile] : attributes) {
+      const Option<u64> microvolts = readMicro(voltageFile);
+      const Option<u64> microamps  = readMicro(currentFile);
+
+      if (microvolts && microamps && *microvolts > 0 && *microamps > 0)
+        return static_cast<f64>(*microvolts) * static_cast<f64>(*microamps) / 1e12;
+    }
+
+    return None;
+  }
+
+  // Parses a kernel CPU list such as "0-7,16-23".
+  auto ParseCpuList(const StringView list) -> Vec<u32> {
+    Vec<u32> cpus;
+
+    for (const auto& part : list | std::views::split(',')) {
+      const StringView range(part.begin(), part.end());
+      const usize      dashPos = range.find('-');
+
+      const Option<u32> first = TryParse<u32>(range.substr(0, dashPos));
+      const Option<u32> last  = dashPos == StringView::npos ? first : TryParse<u32>(range.substr(dashPos + 1));
+
+      if (first && last)
+        for (u32 cpu = *first; cpu <= *last; ++cpu)
+          cpus.push_back(cpu);
+    }
+
+    return cpus;
+  }
+
+  // Whether a V4L2 node captures video, as opposed to the metadata, codec, or output nodes in the same class.
+  auto IsVideoCaptureNode(const fs::path& device, const fs::path& sysPath) -> bool {
+    // Opening a node doesn't start streaming, so this never turns on the camera's indicator light.
+    const i32 filedesc = open(device.c_str(), O_RDONLY | O_NONBLOCK | O_CLOEXEC);
+
+    // Users outside the "video" group can't open the node; UVC cameras put their capture node at index 0.
+    if (filedesc == -1)
+      return ReadSysFile(sysPath / "index").value_or("") == "0";
+
+    v4l2_capability capability {};
+
+    const bool queried = ioctl(filedesc, VIDIOC_QUERYCAP, &capability) == 0;
+
+    close(filedesc);
+
+    if (!queried)
+      return false;
+
+    // device_caps describes this node alone; capabilities covers every node of the physical device.
+    const u32 caps = (capability.capabilities & V4L2_CAP_DEVICE_CAPS) ? capability.device_caps : capability.capabilities;
+
+    return (caps & (V4L2_CAP_VIDEO_CAPTURE | V4L2_CAP_VIDEO_CAPTURE_MPLANE)) != 0 &&
+      (caps & (V4L2_CAP_VIDEO_M2M | V4L2_CAP_VIDEO_M2M_MPLANE)) == 0;
+  }
+
+  // Lists the files under a directory that other processes hold open, the same way fuser finds them.
+  auto GetFilesOpenElsewhere(const StringView directory) -> Vec<String> {
+    Vec<String>  openFiles;
+    const String self = std::to_string(getpid());
+
+    std::error_code errc;
+
+    for (const fs::directory_entry& process : fs::directory_iterator("/proc", errc)) {
+      const String pid = process.path().filename().string();
+
+      if (pid == self || !TryParse<u32>(pid))
+        continue;
+
+      // Other users' descriptors aren't readable without privileges; those processes are skipped.
+      std::error_code fdErrc;
+
+      for (const fs::directory_entry& descriptor : fs::directory_iterator(process.path() / "fd", fdErrc)) {
+        std::error_code linkErrc;
+        String          target = fs::read_symlink(descriptor.path(), linkErrc).string();
+
+        if (!linkErrc && target.starts_with(directory) && std::ranges::find(openFiles, target) == openFiles.end())
+          openFiles.push_back(std::move(target));
+      }
+    }
+
+    return openFiles;
+  }
+
+  // What a GPU's OpenGL driver reports, keyed by the PCI address of its DRM node.
+  struct OpenGLDevice {
+    String         pciAddress;
+    String         version;
+    Option<String> driverVersion;
+  };
+
+  // Creates a surfaceless context on each GPU through EGL's device platform, so no display server is needed.
+  auto GetOpenGLDevices() -> Result<Vec<OpenGLDevice>> {
+    // NOLINTBEGIN(readability-identifier-naming) - Mirrors the EGL ABI.
+    using VoidFunction          = void (*)();
+    using GetProcAddressFn      = VoidFunction (*)(PCStr);
+    using QueryDevicesFn        = u32 (*)(i32, RawPointer*, i32*);
+    using QueryDeviceStringFn   = PCStr (*)(RawPointer, i32);
+    using GetPlatformDisplayFn  = RawPointer (*)(u32, RawPointer, const i32*);
+    using InitializeFn          = u32 (*)(RawPointer, i32*, i32*);
+    using TerminateFn           = u32 (*)(RawPointer);
+    using BindApiFn             = u32 (*)(u32);
+    using QueryApiFn            = u32 (*)();
+    using ChooseConfigFn        = u32 (*)(RawPointer, const i32*, RawPointer*, i32, i32*);
+    using CreateContextFn       = RawPointer (*)(RawPointer, RawPointer, RawPointer, const i32*);
+    using DestroyContextFn      = u32 (*)(RawPointer, RawPointer);
+    using MakeCurrentFn         = u32 (*)(RawPointer, RawPointer, RawPointer, RawPointer);
+    using GetCurrentFn          = RawPointer (*)();
+    using GetCurrentSurfaceFn   = RawPointer (*)(i32);
+    using GetStringFn           = const unsigned char* (*)(u32);
+    // NOLINTEND(readability-identifier-naming)
+
+    constexpr u32 PLATFORM_DEVICE = 0x313F;
+    constexpr u32 OPENGL_API      = 0x30A2;
+    constexpr u32 GL_VERSION      = 0x1F02;
+
+    constexpr i32 NONE                            = 0x3038;
+    constexpr i32 DRM_DEVICE_FILE                 = 0x3233;
+    constexpr i32 RENDERABLE_TYPE                 = 0x3040;
+    constexpr i32 OPENGL_BIT                      = 0x0008;
+    constexpr i32 DRAW                            = 0x3059;
+    constexpr i32 READ                            = 0x305A;
+    constexpr i32 CONTEXT_MAJOR_VERSION           = 0x3098;
+    constexpr i32 CONTEXT_MINOR_VERSION           = 0x30FB;
+    constexpr i32 CONTEXT_OPENGL_PROFILE_MASK     = 0x30FD;
+    constexpr i32 CONTEXT_OPENGL_CORE_PROFILE_BIT = 0x1;
+
+    static RawPointer Egl = dlopen("libEGL.so.1", RTLD_LAZY | RTLD_LOCAL);
+
+    if (!Egl)
+      ERR(NotFound, "libEGL is not installed");
+
+    // NOLINTBEGIN(*-pro-type-reinterpret-cast) - Entry points are resolved as untyped procedures.
+    const auto getProcAddress = reinterpret_cast<GetProcAddressFn>(dlsym(Egl, "eglGetProcAddress"));
+
+    if (!getProcAddress)
+      ERR(ApiUnavailable, "libEGL doesn't export eglGetProcAddress");
+
+    const auto queryDevices       = reinterpret_cast<QueryDevicesFn>(getProcAddress("eglQueryDevicesEXT"));
+    const auto queryDeviceString  = reinterpret_cast<QueryDeviceStringFn>(getProcAddress("eglQueryDeviceStringEXT"));
+    const auto getPlatformDisplay = reinterpret_cast<GetPlatformDisplayFn>(getProcAddress("eglGetPlatformDisplayEXT"));
+    const auto initialize         = reinterpret_cast<InitializeFn>(dlsym(Egl, "eglInitialize"));
+    const auto terminate          = reinterpret_cast<TerminateFn>(dlsym(Egl, "eglTerminate"));
+    const auto bindApi            = reinterpret_cast<BindApiFn>(dlsym(Egl, "eglBindAPI"));
+    const auto queryApi           = reinterpret_cast<QueryApiFn>(dlsym(Egl, "eglQueryAPI"));
+    const auto chooseConfig       = reinterpret_cast<ChooseConfigFn>(dlsym(Egl, "eglChooseConfig"));
+    const auto createContext      = reinterpret_cast<CreateContextFn>(dlsym(Egl, "eglCreateContext"));
+    const auto destroyContext     = reinterpret_cast<DestroyContextFn>(dlsym(Egl, "eglDestroyContext"));
+    const auto makeCurrent        = reinterpret_cast<MakeCurrentFn>(dlsym(Egl, "eglMakeCurrent"));
+    const auto getCurrentDisplay  = reinterpret_cast<GetCurrentFn>(dlsym(Egl, "eglGetCurrentDisplay"));
+    const auto getCurrentContext  = reinterpret_cast<GetCurrentFn>(dlsym(Egl, "eglGetCurrentContext"));
+    const auto getCurrentSurface  = reinterpret_cast<GetCurrentSurfaceFn>(dlsym(Egl, "eglGetCurrentSurface"));
+    const auto getString          = reinterpret_cast<GetStringFn>(getProcAddress("glGetString"));
+    // NOLINTEND(*-pro-type-reinterpret-cast)
+
+    if (!queryDevices || !queryDeviceString || !getPlatformDisplay || !initialize || !terminate || !bindApi || !queryApi ||
+        !chooseConfig || !createContext || !destroyContext || !makeCurrent || !getCurrentDisplay || !getCurrentContext ||
+        !getCurrentSurface || !getString)
+      ERR(ApiUnavailable, "libEGL doesn't support device enumeration");
+
+    Array<RawPointer, 16> devices {};
+    i32                   deviceCount = 0;
+
+    if (!queryDevices(static_cast<i32>(devices.size()), devices.data(), &deviceCount))
+      ERR(ApiUnavailable, "eglQueryDevicesEXT failed");
+
+    // The caller's thread may already have a context current; it's restored once probing is done.
+    const u32        previousApi     = queryApi();
+    const RawPointer previousDisplay = getCurrentDisplay();
+    const RawPointer previousContext = getCurrentContext();
+    const RawPointer previousDraw    = getCurrentSurface(DRAW);
+    const RawPointer previousRead    = getCurrentSurface(READ);
+
+    constexpr Array<i32, 3> configAttribs = { RENDERABLE_TYPE, OPENGL_BIT, NONE };
+
+    // Asking for 3.2 core yields the highest core version the driver has; drivers without one get a compatibility context.
+    constexpr Array<i32, 7> coreAttribs = { CONTEXT_MAJOR_VERSION, 3, CONTEXT_MINOR_VERSION, 2, CONTEXT_OPENGL_PROFILE_MASK, CONTEXT_OPENGL_CORE_PROFILE_BIT, NONE };
+
+    Vec<OpenGLDevice> openGLDevices;
+
+    for (const RawPointer device : Span(devices.data(), static_cast<usize>(std::max(deviceCount, 0)))) {
+      // Software renderers have no DRM node, and aren't GPUs anyway.
+      const PCStr drmFile = queryDeviceString(device, DRM_DEVICE_FILE);
+
+      if (!drmFile)
+        continue;
+
+      std::error_code errc;
+      const fs::path  pciDevice = fs::canonical(fs::path("/sys/class/drm") / fs::path(drmFile).filename() / "device", errc);
+
+      if (errc)
+        continue;
+
+      const RawPointer display = getPlatformDisplay(PLATFORM_DEVICE, device, nullptr);
+
+      if (!display || !initialize(display, nullptr, nullptr))
+        continue;
+
+      Option<String> glVersion;
+
+      if (bindApi(OPENGL_API)) {
+        // A missing config is fine on drivers with EGL_KHR_no_config_context; context creation fails otherwise.
+        RawPointer config      = nullptr;
+        i32        configCount = 0;
+        chooseConfig(display, configAttribs.data(), &config, 1, &configCount);
+
+        RawPointer context = createContext(display, config, nullptr, coreAttribs.data());
+
+        if (!context)
+          context = createContext(display, config, nullptr, nullptr);
+
+        if (context) {
+          // EGL_KHR_surfaceless_context lets the context be made current without a surface.
+          if (makeCurrent(display, nullptr, nullptr, context)) {
+            // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - GL strings are unsigned char.
+            if (const unsigned char* version = getString(GL_VERSION))
+              glVersion = reinterpret_cast<PCStr>(version);
+
+            makeCurrent(display, nullptr, nullptr, nullptr);
+          }
+
+          destroyContext(display, context);
+        }
+      }
+
+      terminate(display);
+
+      if (!glVersion)
+        continue;
+
+      // "4.6 (Core Profile) Mesa 24.1.3" or "4.6.0 NVIDIA 550.78": keep major.minor, then the driver after the profile.
+      const usize versionEnd = glVersion->find(' ');
+      String      version    = glVersion->substr(0, versionEnd);
+
+      if (const usize minorEnd = version.find('.', version.find('.') + 1); minorEnd != String::npos)
+        version.resize(minorEnd);
+
+      String driver = versionEnd == String::npos ? String() : glVersion->substr(versionEnd + 1);
+
+      for (const StringView prefix : { "(Core Profile) ", "(Compatibility Profile) ", "NVIDIA " })
+        if (driver.starts_with(prefix))
+          driver.erase(0, prefix.size());
+
+      openGLDevices.push_back({
+        .pciAddress    = pciDevice.filename().string(),
+        .version       = std::move(version),
+        .driverVersion = driver.empty() ? None : Option<String>(std::move(driver)),
+      });
+    }
+
+    if (previousContext)
+      makeCurrent(previousDisplay, previousDraw, previousRead, previousContext);
+
+    bindApi(previousApi);
+
+    return openGLDevices;
+  }
+
+  /**
+   * @brief Opens a non-blocking netlink socket subscribed to the given multicast groups.
+   */
+  auto OpenNetlinkSocket(const i32 protocol, const u32 groups) -> Result<i32> {
+    const i32 sock = socket(AF_NETLINK, SOCK_DGRAM | SOCK_NONBLOCK | SOCK_CLOEXEC, protocol);
+
+    if (sock < 0)
+      ERR_FMT(InternalError, "Failed to open netlink socket: {}", strerror(errno));
+
+    sockaddr_nl address {};
+    address.nl_family = AF_NETLINK;
+    address.nl_groups = groups;
+
+    if (bind(sock, reinterpret_cast<const sockaddr*>(&address), sizeof(address)) < 0) { // NOLINT(cppcoreguidelines-pro-type-reinterpret-cast)
+      const i32 bindErrno = errno;
+      close(sock);
+      ERR_FMT(bindErrno == EPERM ? PermissionDenied : InternalError, "Failed to bind netlink socket: {}", strerror(bindErrno));
+    }
+
+    return sock;
+  }
+
+  /**
+   * @brief Turns a kernel uevent ("ACTION@DEVPATH\0KEY=VALUE\0...") into the event it represents, if it's one we report.
+   */
+  auto ParseUevent(const StringView message) -> Option<SystemEvent> {
+    Map<StringView, StringView> fields;
+
+    // The leading "ACTION@DEVPATH" header has no '=' and is repeated by the ACTION and DEVPATH fields.
+    for (auto entryRange : message | std::views::split('\0')) {
+      const StringView entry(entryRange.begin(), entryRange.end());
+
+      if (const usize equals = entry.find('='); equals != StringView::npos)
+        fields.emplace(entry.substr(0, equals), entry.substr(equals + 1));
+    }
+
+    const auto field = [&fields](const StringView key) -> StringView {
+      const auto iter = fields.find(key);
+      return iter == fields.end() ? StringView {} : iter->second;
+    };
+
+    const StringView action    = field("ACTION");
+    const StringView subsystem = field("SUBSYSTEM");
+    const StringView devPath   = field("DEVPATH");
+    const StringView devName   = field("DEVNAME");
+    const StringView name      = devName.empty() ? devPath.substr(devPath.rfind('/') + 1) : devName;
+
+    if (subsystem == "power_supply") {
+      const StringView supply = field("POWER_SUPPLY_NAME");
+      return SystemEvent { .kind = SystemEvent::Kind::BatteryChanged, .source = String(supply.empty() ? name : supply) };
+    }
+
+    // Connector hotplug and mode changes both arrive as a "change" of the card.
+    if (subsystem == "drm" && action == "change")
+      return SystemEvent { .kind = SystemEvent::Kind::DisplayChanged, .source = String(name) };
+
+    // A single USB device produces events for each of its interfaces and the drivers bound to them; only the
+    // device itself is reported. Loop and RAM disks come and go with snaps and images, not hardware.
+    const bool isDevice = (subsystem == "usb" && field("DEVTYPE") == "usb_device") ||
+      (subsystem == "block" && field("DEVTYPE") == "disk" && !name.starts_with("loop") && !name.starts_with("ram") && !name.starts_with("zram"));
+
+    if (isDevice && action == "add")
+      return SystemEvent { .kind = SystemEvent::Kind::DeviceAdded, .source = String(name) };
+
+    if (isDevice && action == "remove")
+      return SystemEvent { .kind = SystemEvent::Kind::DeviceRemoved, .source = String(name) };
+
+    return None;
+  }
+
+  /**
+   * @brief Turns the RTM_NEWLINK/RTM_DELLINK messages in a netlink datagram into link up/down events.
+   * @param linkUp The last state seen for each interface, so messages for other attribute changes are ignored.
+   */
+  auto ParseLinkMessages(Span<const u8> datagram, Map<String, bool>& linkUp, Vec<SystemEvent>& events) -> Unit {
+    // NOLINTBEGIN(cppcoreguidelines-pro-type-reinterpret-cast, cppcoreguidelines-pro-bounds-pointer-arithmetic) - netlink message walking
+    auto remaining = static_cast<i32>(datagram.size());
+
+    for (const auto* header = reinterpret_cast<const nlmsghdr*>(datagram.data()); NLMSG_OK(header, remaining); header = NLMSG_NEXT(header, remaining)) {
+      if (header->nlmsg_type != RTM_NEWLINK && header->nlmsg_type != RTM_DELLINK)
+        continue;
+
+      const auto* info = static_cast<const ifinfomsg*>(NLMSG_DATA(header));
+
+      if ((info->ifi_flags & IFF_LOOPBACK) != 0)
+        continue;
+
+      String name;
+      i32    attrsLength = static_cast<i32>(IFLA_PAYLOAD(header));
+
+      for (const auto* attr = IFLA_RTA(info); RTA_OK(attr, attrsLength); attr = RTA_NEXT(attr, attrsLength))
+        if (attr->rta_type == IFLA_IFNAME)
+          name = static_cast<PCStr>(RTA_DATA(attr));
+
+      if (name.empty())
+        continue;
+
+      if (header->nlmsg_type == RTM_DELLINK) {
+        if (const auto iter = linkUp.find(name); iter != linkUp.end()) {
+          if (iter->second)
+            events.push_back({ .kind = SystemEvent::Kind::NetworkDown, .source = name });
+
+          linkUp.erase(iter);
+        }
+
+        continue;
+      }
+
+      const bool up = (info->ifi_flags & IFF_UP) != 0 && (info->ifi_flags & IFF_RUNNING) != 0;
+
+      const auto [iter, inserted] = linkUp.try_emplace(name, up);
+
+      // A new interface that starts out down isn't a state change anyone would act on.
+      if (inserted ? !up : iter->second == up)
+        continue;
+
+      iter->second = up;
+      events.push_back({ .kind = up ? SystemEvent::Kind::NetworkUp : SystemEvent::Kind::NetworkDown, .source = std::move(name) });
+    }
+    // NOLINTEND(cppcoreguidelines-pro-type-reinterpret-cast, cppcoreguidelines-pro-bounds-pointer-arithmetic)
+  }
+} // namespace
+
+namespace draconis::core::system {
+  using draconis::utils::cache::CacheManager, draconis::utils::cache::CachePolicy;
+  using draconis::utils::env::GetEnv;
+
+  namespace linux {
+    auto GetDistroID(CacheManager& cache) -> Result<String> {
+      return cache.getOrSet<String>("linux_distro_id", []() -> Result<String> {
+        std::ifstream file("/etc/os-release");
+
+        if (!file.is_open())
+          ERR(NotFound, "Failed to open /etc/os-release");
+
+        String line;
+
+        while (std::getline(file, line)) {
+          if (StringView(line).starts_with("ID=")) {
+            String value = line.substr(3);
+
+            if ((value.length() >= 2 && value.front() == '"' && value.back() == '"') || (value.length() >= 2 && value.front() == '\'' && value.back() == '\''))
+              value = value.substr(1, value.length() - 2);
+
+            if (value.empty())
+              ERR(ParseError, "ID value is empty or only quotes in /etc/os-release");
+
+            return String(value);
+          }
+        }
+
+        ERR(NotFound, "ID line not found in /etc/os-release");
+      });
+    }
+  } // namespace linux
+
+  auto GetOperatingSystem(CacheManager& cache) -> Result<OSInfo> {
+    return cache.getOrSet<OSInfo>("linux_os_version", []() -> Result<OSInfo> {
+      std::ifstream file("/etc/os-release");
+
+      if (!file.is_open())
+        ERR(NotFound, "Failed to open /etc/os-release");
+
+      String osName, osVersion, osId;
+
+      String line;
+
+      const auto parseValue = [&](String& val) -> void {
+        if (val.length() >= 2 && ((val.front() == '"' && val.back() == '"') || (val.front() == '\'' && val.back() == '\'')))
+          val = val.substr(1, val.length() - 2);
+      };
+
+      while (std::getline(file, line)) {
+        const StringView lineView = line;
+
+        if (lineView.starts_with("NAME=")) {
+          osName = lineView.substr(5);
+          parseValue(osName);
+        } else if (lineView.starts_with("VERSION=")) {
+          osVersion = lineView.substr(8);
+          parseValue(osVersion);
+        } else if (lineView.starts_with("ID=")) {
+          osId = lineView.substr(3);
+          parseValue(osId);
+        } else if (lineView.starts_with("PRETTY_NAME=") && osName.empty()) {
+          osName = lineView.substr(12);
+          parseValue(osName);
+        } else if (lineView.starts_with("VERSION_ID=") && osVersion.empty()) {
+          osVersion = lineView.substr(11);
+          parseValue(osVersion);
+        }
+      }
+
+      if (osId.empty())
+        ERR(NotFound, "ID not found in /etc/os-release");
+
+      if (osName.empty())
+        ERR(NotFound, "NAME or PRETTY_NAME not found in /etc/os-release");
+
+      if (osVersion.empty())
+        osVersion = "";
+
+      return OSInfo(osName, osVersion, osId);
+    });
+  }
+
+  auto GetMemInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
+    struct sysinfo info;
+
+    if (sysinfo(&info) != 0)
+      ERR(ApiUnavailable, "sysinfo call failed");
+
+    if (info.mem_unit == 0)
+      ERR(PlatformSpecific, "sysinfo.mem_unit is 0, cannot calculate memory");
+
+    return ResourceUsage((info.totalram - info.freeram - info.bufferram) * info.mem_unit, info.totalram * info.mem_unit);
+  }
+
+  auto GetMemoryDetails(CacheManager& /*cache*/) -> Result<MemoryDetails> {
+    std::ifstream file("/proc/meminfo");
+
+    if (!file.is_open())
+      ERR(NotFound, "Failed to open /proc/meminfo");
+
+    // All values in /proc/meminfo are reported in kB.
+    Option<u64> memTotal, memFree, memAvailable, buffers, cached, sReclaimable, sUnreclaim, shmem, kernelStack, pageTables;
+
+    String line;
+
+    while (std::getline(file, line)) {
+      const usize colonPos = line.find(':');
+
+      if (colonPos == String::npos)
+        continue;
+
+      const StringView key  = StringView(line).substr(0, colonPos);
+      StringView       rest = StringView(line).substr(colonPos + 1);
+
+      rest.remove_prefix(std::min(rest.find_first_not_of(' '), rest.size()));
+      rest = rest.substr(0, rest.find(' '));
+
+      const Option<u64> kib = TryParse<u64>(rest);
+
+      if (key == "MemTotal")
+        memTotal = kib;
+      else if (key == "MemFree")
+        memFree = kib;
+      else if (key == "MemAvailable")
+        memAvailable = kib;
+      else if (key == "Buffers")
+        buffers = kib;
+      else if (key == "Cached")
+        cached = kib;
+      else if (key == "SReclaimable")
+        sReclaimable = kib;
+      else if (key == "SUnreclaim")
+        sUnreclaim = kib;
+      else if (key == "Shmem")
+        shmem = kib;
+      else if (key == "KernelStack")
+        kernelStack = kib;
+      else if (key == "PageTables")
+        pageTables = kib;
+    }
+
+    if (!memTotal)
+      ERR(ParseError, "MemTotal not found in /proc/meminfo");
+
+    constexpr u64 kib = 1024;
+
+    MemoryDetails details {};
+
+    // `free` counts reclaimable slab memory as part of the cache, so do the same here.
+    details.totalBytes     = *memTotal * kib;
+    details.freeBytes      = memFree.value_or(0) * kib;
+    details.buffersBytes   = buffers.value_or(0) * kib;
+    details.cachedBytes    = (cached.value_or(0) + sReclaimable.value_or(0)) * kib;
+    details.sharedBytes    = shmem.value_or(0) * kib;
+    details.kernelBytes    = (sUnreclaim.value_or(0) + kernelStack.value_or(0) + pageTables.value_or(0)) * kib;
+    details.availableBytes = memAvailable.transform([](const u64 val) -> u64 { return val * kib; }).value_or(std::min(details.freeBytes + details.buffersBytes + details.cachedBytes, details.totalBytes));
+    details.usedBytes      = details.totalBytes - std::min(details.availableBytes, details.totalBytes);
+
+    return details;
+  }
+
+  auto GetSwapInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
+    struct sysinfo info;
+
+    if (sysinfo(&info) != 0)
+      ERR(ApiUnavailable, "sysinfo call failed");
+
+    if (info.mem_unit == 0)
+      ERR(PlatformSpecific, "sysinfo.mem_unit is 0, cannot calculate swap");
+
+    return ResourceUsage((info.totalswap - info.freeswap) * info.mem_unit, info.totalswap * info.mem_unit);
+  }
+
+  auto GetWindowManager(CacheManager& cache) -> Result<String> {
+    // NOLINTNEXTLINE(misc-redundant-expression) - compile-time values are not always redundant
+    if constexpr (!DRAC_USE_WAYLAND && !DRAC_USE_XCB)
+      ERR(NotSupported, "Wayland or XCB support not available");
+
+    return cache.getOrSet<String>("linux_wm", [&]() -> Result<String> {
+      if (GetEnv("WAYLAND_DISPLAY"))
+        return GetWaylandCompositor();
+
+      if (GetEnv("DISPLAY"))
+        return GetX11WindowManager();
+
+      ERR(NotFound, "No display server detected");
+    });
+  }
+
+  auto GetDisplaySession(CacheManager& cache) -> Result<DisplaySession> {
+    return cache.getOrSet<DisplaySession>("linux_display_session", CachePolicy::inMemory(), []() -> Result<DisplaySession> {
+      DisplaySession session { .server = os::unix_shared::GetDisplayServerFromEnv(), .compositor = None };
+
+      if (session.server == DisplaySession::Server::Wayland)
+        if (Result<String> compositor = GetWaylandCompositor())
+          session.compositor = *std::move(compositor);
+
+      return session;
+    });
+  }
+
+  auto GetThemeInfo(CacheManager& cache) -> Result<ThemeInfo> {
+    return cache.getOrSet<ThemeInfo>("linux_theme_info", CachePolicy::tempDirectory(), os::unix_shared::GetThemeInfoFromConfig);
+  }
+
+  auto GetDefaultApplications() -> Result<DefaultApplications> {
+    return os::unix_shared::GetDefaultApplicationsFromConfig();
+  }
+
+  auto GetAudioDevices(CacheManager& /*cache*/) -> Result<Vec<AudioDevice>> {
+    return GetPulseAudioDevices();
+  }
+
+  auto GetVolume(CacheManager& /*cache*/) -> Result<VolumeInfo> {
+    return GetPulseAudioVolume();
+  }
+
+  auto SetVolume(const f64 percent) -> Result<> {
+    return SetPulseAudioVolume(percent);
+  }
+
+  auto SetMuted(const bool muted) -> Result<> {
+    return SetPulseAudioMuted(muted);
+  }
+
+  auto GetBluetoothDevices(CacheManager& /*cache*/) -> Result<BluetoothInfo> {
+    return GetBlueZDevices();
+  }
+
+  auto GetPrinters(CacheManager& /*cache*/) -> Result<Vec<PrinterInfo>> {
+    return os::cups::GetPrinters();
+  }
+
+  auto GetCameras(CacheManager& /*cache*/) -> Result<Vec<CameraInfo>> {
+    const fs::path sysClass = "/sys/class/video4linux";
+
+    Vec<Pair<u32, CameraInfo>> cameras;
+
+    if (std::error_code errc; fs::exists(sysClass, errc)) {
+      const Vec<String> openNodes = GetFilesOpenElsewhere("/dev/video");
+
+      for (const fs::directory_entry& entry : fs::directory_iterator(sysClass, errc)) {
+        const String      node   = entry.path().filename().string();
+        const Option<u32> number = node.starts_with("video") ? TryParse<u32>(StringView(node).substr(5)) : None;
+
+        if (!number)
+          continue;
+
+        String device = std::format("/dev/{}", node);
+
+        if (!IsVideoCaptureNode(device, entry.path()))
+          continue;
+
+        const bool inUse = std::ranges::find(openNodes, device) != openNodes.end();
+
+        cameras.emplace_back(
+          *number,
+          CameraInfo {
+            .name  = ReadSysFile(entry.path() / "name").value_or(node),
+            .id    = std::move(device),
+            .inUse = inUse,
+          }
+        );
+      }
+    }
+
+    // Directory order is arbitrary; number order puts video10 after video2.
+    std::ranges::sort(cameras, {}, &Pair<u32, CameraInfo>::first);
+
+    Vec<CameraInfo> result;
+    result.reserve(cameras.size());
+
+    for (CameraInfo& camera : cameras | std::views::values)
+      result.push_back(std::move(camera));
+
+    return result;
+  }
+
+  auto GetWifiInfo(CacheManager& /*cache*/) -> Result<WifiInfo> {
+    const fs::path netPath = "/sys/class/net";
+
+    std::error_code errc;
+    bool            foundWireless = false;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator(netPath, errc)) {
+      // cfg80211 drivers expose "phy80211"; "wireless" only exists with wireless extensions compat enabled.
+      if (!fs::exists(entry.path() / "phy80211") && !fs::exists(entry.path() / "wireless"))
+        continue;
+
+      foundWireless = true;
+
+      Result<WifiInfo> info = ReadWirelessInfo(entry.path().filename().string());
+
+      if (info)
+        return info;
+
+      debug_at(info.error());
+    }
+
+    if (!foundWireless)
+      ERR(NotFound, "No wireless network interfaces found");
+
+    ERR(NotFound, "No wireless network interface is connected");
+  }
+
+  auto GetDesktopEnvironment(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("linux_desktop_environment", []() -> Result<String> {
+      Result<String> xdgEnvResult = GetEnv("XDG_CURRENT_DESKTOP");
+
+      if (xdgEnvResult) {
+        String xdgDesktopSz = String(*xdgEnvResult);
+
+        if (const usize colonPos = xdgDesktopSz.find(':'); colonPos != String::npos)
+          xdgDesktopSz.resize(colonPos);
+
+        return xdgDesktopSz;
+      }
+
+      Result<String> desktopSessionResult = GetEnv("DESKTOP_SESSION");
+
+      if (desktopSessionResult)
+        return *desktopSessionResult;
+
+      ERR_FMT(ApiUnavailable, "Failed to get desktop session: {}", desktopSessionResult.error().message);
+    });
+  }
 
   auto GetShell(CacheManager& cache) -> Result<String> {
     return cache.getOrSet<String>("linux_shell", []() -> Result<String> {
@@ -792,199 +2875,1480 @@ namespace draconis::core::system {
           }};
           // clang-format on
 
-          for (const auto& [exe, name] : shellMap)
-            if (shellPath == exe)
-              return String(name);
+          for (const auto& [exe, name] : shellMap)
+            if (shellPath == exe)
+              return String(name);
+
+          if (const usize lastSlash = shellPath.find_last_of('/'); lastSlash != String::npos)
+            return shellPath.substr(lastSlash + 1);
+
+          return shellPath;
+        })
+        .transform([](const String& shellPath) -> String {
+          return shellPath;
+        });
+    });
+  }
+
+  auto GetTerminal(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("linux_terminal", CachePolicy::inMemory(), []() -> Result<String> {
+      if (Option<String> terminal = os::unix_shared::GetTerminalFromEnv())
+        return *terminal;
+
+      return os::unix_shared::FindTerminalInProcessTree(getppid(), [](const pid_t pid) -> Option<Pair<String, pid_t>> {
+        std::ifstream statFile(std::format("/proc/{}/stat", pid));
+        String        stat;
+
+        if (!std::getline(statFile, stat))
+          return None;
+
+        // Same layout as in ReadProcessInfo: "pid (comm) state ppid ...".
+        const usize openPos  = stat.find('(');
+        const usize closePos = stat.rfind(')');
+
+        if (openPos == String::npos || closePos == String::npos || closePos + 4 >= stat.size())
+          return None;
+
+        const StringView  rest      = StringView(stat).substr(closePos + 4);
+        const Option<i32> parentPid = TryParse<i32>(rest.substr(0, rest.find(' ')));
+
+        if (!parentPid)
+          return None;
+
+        return Pair<String, pid_t>(stat.substr(openPos + 1, closePos - openPos - 1), *parentPid);
+      });
+    });
+  }
+
+  auto GetTerminalFont(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("linux_terminal_font", CachePolicy::inMemory(), [&cache]() -> Result<String> {
+      return os::unix_shared::GetTerminalFontFromConfig(TRY(GetTerminal(cache)));
+    });
+  }
+
+  auto GetHost(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("linux_host", CachePolicy::neverExpire(), []() -> Result<String> {
+      constexpr PCStr primaryPath  = "/sys/class/dmi/id/product_family";
+      constexpr PCStr fallbackPath = "/sys/class/dmi/id/product_name";
+
+      auto readFirstLine = [&](const String& path) -> Result<String> {
+        std::ifstream file(path);
+        String        line;
+
+        if (!file.is_open()) {
+          if (errno == EACCES)
+            ERR_FMT(PermissionDenied, "Permission denied when opening DMI product identifier file '{}'", path);
+
+          ERR_FMT(NotFound, "Failed to open DMI product identifier file '{}'", path);
+        }
+
+        if (!std::getline(file, line) || line.empty())
+          ERR_FMT(ParseError, "DMI product identifier file ('{}') is empty", path);
+
+        return line;
+      };
+
+      Result<String> primaryResult = readFirstLine(primaryPath);
+
+      if (primaryResult)
+        return primaryResult;
+
+      DracError primaryError = primaryResult.error();
+
+      Result<String> fallbackResult = readFirstLine(fallbackPath);
+
+      if (fallbackResult)
+        return fallbackResult;
+
+      DracError fallbackError = fallbackResult.error();
+
+      ERR_FMT(
+        NotFound,
+        "Failed to get host identifier. Primary ('{}'): {}. Fallback ('{}'): {}",
+        primaryPath,
+        primaryError.message,
+        fallbackPath,
+        fallbackError.message
+      );
+    });
+  }
+
+  auto GetFirmwareInfo(CacheManager& cache) -> Result<FirmwareInfo> {
+    return cache.getOrSet<FirmwareInfo>("linux_firmware_info", CachePolicy::neverExpire(), []() -> Result<FirmwareInfo> {
+      // clang-format off
+      constexpr Array<StringView, 6> placeholders = {
+        "To Be Filled By O.E.M.", "To be filled by O.E.M.", "Default string", "Not Applicable", "N/A", "None",
+      };
+      // clang-format on
+
+      const auto readDmi = [&](const PCStr name) -> Option<String> {
+        Result<String> value = ReadSysFile(fs::path("/sys/class/dmi/id") / name);
+
+        if (!value || value->empty() || std::ranges::find(placeholders, StringView(*value)) != placeholders.end())
+          return None;
+
+        return *value;
+      };
+
+      FirmwareInfo info {
+        .boardVendor  = readDmi("board_vendor"),
+        .boardModel   = readDmi("board_name"),
+        .boardVersion = readDmi("board_version"),
+        .vendor       = readDmi("bios_vendor"),
+        .version      = readDmi("bios_version"),
+        .releaseDate  = readDmi("bios_date"),
+        .isUefi       = fs::exists("/sys/firmware/efi"),
+      };
+
+      // Most ARM boards have no SMBIOS tables, but name themselves in the device tree (NUL-terminated).
+      if (!info.boardModel)
+        if (Result<String> model = ReadSysFile("/sys/firmware/devicetree/base/model"); model && !model->empty())
+          info.boardModel = model->substr(0, model->find('\0'));
+
+      if (!info.boardVendor && !info.boardModel && !info.vendor && !info.version)
+        ERR(NotFound, "No DMI or device tree firmware information available");
+
+      return info;
+    });
+  }
+
+  auto GetMachineId(CacheManager& cache) -> Result<String> {
+    // Kept in memory only: regenerating the ID (e.g. after cloning a VM image) must not leave a stale value on disk.
+    return cache.getOrSet<String>("linux_machine_id", CachePolicy::inMemory(), []() -> Result<String> {
+      // /var/lib/dbus/machine-id predates systemd and is still the only copy on some non-systemd distros.
+      for (const PCStr path : { "/etc/machine-id", "/var/lib/dbus/machine-id" })
+        if (Result<String> machineId = ReadSysFile(path); machineId && !machineId->empty())
+          return *machineId;
+
+      ERR(NotFound, "Neither /etc/machine-id nor /var/lib/dbus/machine-id contains a machine ID");
+    });
+  }
+
+  auto GetHardwareSerial(CacheManager& cache) -> Result<String> {
+    // Kept in memory only, so a serial read as root never ends up in a cache file.
+    return cache.getOrSet<String>("linux_hardware_serial", CachePolicy::inMemory(), []() -> Result<String> {
+      constexpr PCStr dmiPath = "/sys/class/dmi/id/product_serial";
+
+      // clang-format off
+      constexpr Array<StringView, 6> placeholders = {
+        "To Be Filled By O.E.M.", "To be filled by O.E.M.", "Default string", "System Serial Number", "Not Specified", "0123456789",
+      };
+      // clang-format on
+
+      if (access(dmiPath, R_OK) == -1 && (errno == EACCES || errno == EPERM))
+        ERR_FMT(PermissionRequired, "Reading {} requires root privileges", dmiPath);
+
+      if (Result<String> serial = ReadSysFile(dmiPath)) {
+        if (serial->empty() || std::ranges::find(placeholders, StringView(*serial)) != placeholders.end())
+          ERR(NotFound, "The firmware only reports a placeholder hardware serial number");
+
+        return *serial;
+      }
+
+      // Boards without SMBIOS (e.g. the Raspberry Pi) expose the SoC serial in the device tree instead (NUL-terminated).
+      if (Result<String> serial = ReadSysFile("/sys/firmware/devicetree/base/serial-number"); serial && !serial->empty())
+        if (String trimmed = serial->substr(0, serial->find('\0')); !trimmed.empty())
+          return trimmed;
+
+      ERR(NotFound, "No DMI or device tree serial number available");
+    });
+  }
+
+  auto GetVirtualization(CacheManager& cache) -> Result<VirtEnv> {
+    // Kept in memory only: a home directory bind-mounted into a container would otherwise hand it the host's answer.
+    return cache.getOrSet<VirtEnv>("linux_virtualization", CachePolicy::inMemory(), []() -> Result<VirtEnv> {
+      using Hypervisor = VirtEnv::Hypervisor;
+      using Container  = VirtEnv::Container;
+
+      VirtEnv env;
+
+  #if DRAC_ARCH_X86_64 || DRAC_ARCH_X86
+      u32 eax = 0, ebx = 0, ecx = 0, edx = 0;
+
+      if (__get_cpuid(os::virt::CPUID_FEATURE_LEAF, &eax, &ebx, &ecx, &edx) && (ecx & os::virt::CPUID_HYPERVISOR_BIT)) {
+        // __get_cpuid rejects leaves above the basic maximum, so the hypervisor range has to be queried directly.
+        __cpuid(os::virt::CPUID_HYPERVISOR_LEAF, eax, ebx, ecx, edx);
+
+        Array<char, 12> signature {};
+        std::memcpy(&signature[0], &ebx, sizeof(ebx));
+        std::memcpy(&signature[4], &ecx, sizeof(ecx));
+        std::memcpy(&signature[8], &edx, sizeof(edx));
+
+        const StringView view(signature.data(), signature.size());
+
+        env.hypervisor       = os::virt::HypervisorFromCpuidSignature(view);
+        env.hypervisorVendor = String(view.substr(0, view.find('\0')));
+      }
+  #endif
+
+      // Xen PV guests don't set the CPUID bit, and ARM has no CPUID at all.
+      if (env.hypervisor == Hypervisor::None) {
+        if (Result<String> type = ReadSysFile("/sys/hypervisor/type"); type && *type == "xen") {
+          env.hypervisor       = Hypervisor::Xen;
+          env.hypervisorVendor = "Xen";
+        } else {
+          const String manufacturer = ReadSysFile("/sys/class/dmi/id/sys_vendor").value_or("");
+          const String productName  = ReadSysFile("/sys/class/dmi/id/product_name").value_or("");
+
+          env.hypervisor = os::virt::HypervisorFromDmi(manufacturer, productName);
+
+          if (env.hypervisor != Hypervisor::None)
+            env.hypervisorVendor = manufacturer;
+        }
+      }
+
+      // WSL1 runs without a hypervisor, and WSL2's utility VM is an implementation detail, so both count as a container.
+      if (Result<String> release = ReadSysFile("/proc/sys/kernel/osrelease"); release && (release->contains("microsoft") || release->contains("Microsoft"))) {
+        env.container = Container::Wsl;
+        return env;
+      }
+
+      // Written by systemd-nspawn, LXC and Podman, and by systemd itself when it runs as PID 1 in a container.
+      if (Result<String> name = ReadSysFile("/run/systemd/container"); name && !name->empty()) {
+        env.container = os::virt::ContainerFromName(*name);
+        return env;
+      }
+
+      if (Result<String> name = GetEnv("container"); name && !name->empty()) {
+        env.container = os::virt::ContainerFromName(*name);
+        return env;
+      }
+
+      if (fs::exists("/run/.containerenv")) {
+        env.container = Container::Podman;
+        return env;
+      }
+
+      if (fs::exists("/.dockerenv")) {
+        env.container = Container::Docker;
+        return env;
+      }
+
+      // Only useful on cgroup v1 hosts; with cgroup namespaces (the v2 default) PID 1 just sees "0::/".
+      if (std::ifstream cgroups("/proc/1/cgroup"); cgroups.is_open())
+        for (String line; std::getline(cgroups, line);) {
+          if (line.contains("/docker"))
+            env.container = Container::Docker;
+          else if (line.contains("/libpod"))
+            env.container = Container::Podman;
+          else if (line.contains("/lxc"))
+            env.container = Container::Lxc;
+          else
+            continue;
+
+          break;
+        }
+
+      return env;
+    });
+  }
+
+  auto GetSecurityPlatformInfo(CacheManager& cache) -> Result<SecurityPlatformInfo> {
+    return cache.getOrSet<SecurityPlatformInfo>("linux_security_platform", CachePolicy::inMemory(), []() -> Result<SecurityPlatformInfo> {
+      using SecureBoot = SecurityPlatformInfo::SecureBoot;
+
+      SecurityPlatformInfo info;
+
+      std::error_code errc;
+
+      if (!fs::exists("/sys/firmware/efi", errc)) {
+        info.secureBoot = SecureBoot::Unsupported;
+      } else {
+        // efivarfs prefixes the variable data with its 4-byte attribute mask; the value itself is a single byte.
+        constexpr PCStr secureBootVar = "/sys/firmware/efi/efivars/SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+        Array<char, 5> data {};
+
+        if (std::ifstream file(secureBootVar, std::ios::binary); file.read(data.data(), data.size()))
+          info.secureBoot = data[4] == 1 ? SecureBoot::Enabled : SecureBoot::Disabled;
+        else if (!fs::exists(secureBootVar, errc) && fs::exists("/sys/firmware/efi/efivars", errc))
+          // Firmware that doesn't implement Secure Boot simply doesn't define the variable.
+          info.secureBoot = SecureBoot::Disabled;
+      }
+
+      const fs::path tpmPath = "/sys/class/tpm/tpm0";
+
+      info.tpmPresent = fs::exists(tpmPath, errc);
+
+      if (info.tpmPresent) {
+        // tpm_version_major only exists since Linux 5.6; older kernels only expose the resource manager for TPM 2.0.
+        if (Result<String> major = ReadSysFile(tpmPath / "tpm_version_major"))
+          info.tpmVersion = *major == "2" ? "2.0" : "1.2";
+        else if (fs::exists("/dev/tpmrm0", errc))
+          info.tpmVersion = "2.0";
+        else if (fs::exists(tpmPath / "device" / "caps", errc))
+          info.tpmVersion = "1.2";
+      }
+
+      // securityfs is mounted root-only on most distros, so a missing log may just mean it couldn't be seen.
+      info.measuredBoot = fs::exists("/sys/kernel/security/tpm0/binary_bios_measurements", errc);
+
+      return info;
+    });
+  }
+
+  auto GetMacStatus(CacheManager& /*cache*/) -> Result<MacStatus> {
+    using Framework = MacStatus::Framework;
+    using Mode      = MacStatus::Mode;
+
+    MacStatus status;
+
+    // The SELinux policy type loaded at boot, e.g. SELINUXTYPE=targeted. The kernel doesn't expose the name itself.
+    const auto readSelinuxPolicy = []() -> Option<String> {
+      std::ifstream config("/etc/selinux/config");
+
+      for (String line; std::getline(config, line);)
+        if (line.starts_with("SELINUXTYPE="))
+          if (String value = line.substr(12); !value.empty())
+            return value;
+
+      return None;
+    };
+
+    // Lists the LSMs initialized at boot, e.g. "capability,landlock,yama,apparmor" (Linux 5.1+).
+    const String lsms = ReadSysFile("/sys/kernel/security/lsm").value_or("");
+
+    std::error_code errc;
+
+    if (lsms.contains("selinux") || fs::exists("/sys/fs/selinux", errc)) {
+      status.framework = Framework::SELinux;
+      status.policy    = readSelinuxPolicy();
+
+      // Without selinuxfs mounted, SELinux was disabled at runtime and isn't doing anything.
+      if (Result<String> enforce = ReadSysFile("/sys/fs/selinux/enforce"))
+        status.mode = *enforce == "1" ? Mode::Enforcing : Mode::Permissive;
+
+      return status;
+    }
+
+    if (Result<String> enabled = ReadSysFile("/sys/module/apparmor/parameters/enabled"); lsms.contains("apparmor") || enabled) {
+      status.framework = Framework::AppArmor;
+
+      if (enabled && *enabled != "Y")
+        return status;
+
+      status.mode = Mode::Enforcing;
+
+      // One line per loaded profile, e.g. "/usr/bin/man (enforce)". Only readable by root.
+      if (std::ifstream profiles("/sys/kernel/security/apparmor/profiles"); profiles.is_open()) {
+        bool anyEnforcing   = false;
+        bool anyComplaining = false;
+
+        for (String line; std::getline(profiles, line);) {
+          anyEnforcing   |= line.ends_with("(enforce)");
+          anyComplaining |= line.ends_with("(complain)");
+        }
+
+        if (!anyEnforcing)
+          status.mode = anyComplaining ? Mode::Permissive : Mode::Disabled;
+      }
+
+      return status;
+    }
+
+    // The userspace tools are installed, but the kernel was booted without SELinux (e.g. selinux=0).
+    if (fs::exists("/etc/selinux/config", errc)) {
+      status.framework = Framework::SELinux;
+      status.policy    = readSelinuxPolicy();
+    }
+
+    return status;
+  }
+
+  auto GetDomainInfo(CacheManager& /*cache*/) -> Result<DomainInfo> {
+    ERR(NotSupported, "Domain membership is only reported on Windows");
+  }
+
+  auto GetFirewallStatus(CacheManager& /*cache*/) -> Result<FirewallStatus> {
+    using Backend = FirewallStatus::Backend;
+
+    // Returns the value of a KEY=value line, with optional quotes removed.
+    const auto readConfigValue = [](const PCStr path, const StringView key) -> Option<String> {
+      std::ifstream config(path);
+
+      for (String line; std::getline(config, line);) {
+        if (!line.starts_with(key) || line.size() <= key.size() || line[key.size()] != '=')
+          continue;
+
+        String value = line.substr(key.size() + 1);
+
+        if (value.size() >= 2 && value.front() == '"' && value.back() == '"')
+          value = value.substr(1, value.size() - 2);
+
+        return value;
+      }
+
+      return None;
+    };
+
+    std::error_code errc;
+
+    bool firewalldRunning = false;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/proc", errc))
+      if (Result<String> comm = ReadSysFile(entry.path() / "comm"); comm && *comm == "firewalld") {
+        firewalldRunning = true;
+        break;
+      }
+
+    const auto firewalld = [&](const bool running) -> FirewallStatus {
+      return {
+        .backend  = Backend::Firewalld,
+        .profiles = { { .name = readConfigValue("/etc/firewalld/firewalld.conf", "DefaultZone").value_or("public"), .enabled = running } },
+      };
+    };
+
+    // firewalld manages nftables itself, so when it runs it is the firewall regardless of what else is installed.
+    if (firewalldRunning)
+      return firewalld(true);
+
+    if (Option<String> enabled = readConfigValue("/etc/ufw/ufw.conf", "ENABLED"))
+      return FirewallStatus {
+        .backend  = Backend::Ufw,
+        .profiles = { { .name = "default", .enabled = *enabled == "yes" } },
+      };
+
+    // Debian and Arch enable it under sysinit.target, Fedora under multi-user.target.
+    for (const PCStr wants : { "/etc/systemd/system/sysinit.target.wants/nftables.service", "/etc/systemd/system/multi-user.target.wants/nftables.service" })
+      if (fs::exists(wants, errc))
+        return FirewallStatus {
+          .backend  = Backend::Nftables,
+          .profiles = { { .name = "default", .enabled = true } },
+        };
+
+    if (fs::exists("/etc/firewalld/firewalld.conf", errc))
+      return firewalld(false);
+
+    ERR(NotFound, "No firewalld, ufw or nftables firewall is configured");
+  }
+
+  auto GetCPUModel(CacheManager& /*cache*/) -> Result<String> {
+    Array<u32, 4>   cpuInfo;
+    Array<char, 49> brandString = { 0 };
+
+    __get_cpuid(0x80000000, cpuInfo.data(), &cpuInfo[1], &cpuInfo[2], &cpuInfo[3]);
+    const u32 maxFunction = cpuInfo[0];
+
+    if (maxFunction < 0x80000004)
+      ERR(NotSupported, "CPU does not support brand string");
+
+    for (u32 i = 0; i < 3; ++i) {
+      __get_cpuid(0x80000002 + i, cpuInfo.data(), &cpuInfo[1], &cpuInfo[2], &cpuInfo[3]);
+      std::memcpy(&brandString.at(i * 16), cpuInfo.data(), sizeof(cpuInfo));
+    }
+
+    String result(brandString.data());
+
+    result.erase(result.find_last_not_of(" \t\n\r") + 1);
+
+    if (result.empty())
+      ERR(InternalError, "Failed to get CPU model string via CPUID");
+
+    return result;
+  }
+
+  auto GetCPUCores(CacheManager& /*cache*/) -> Result<CPUCores> {
+    u32 eax = 0, ebx = 0, ecx = 0, edx = 0;
+
+    __get_cpuid(0x0, &eax, &ebx, &ecx, &edx);
+    const u32 maxLeaf   = eax;
+    const u32 vendorEbx = ebx;
+
+    u32 logicalCores  = 0;
+    u32 physicalCores = 0;
+
+    if (maxLeaf >= 0xB) {
+      u32 threadsPerCore = 0;
+      for (u32 subleaf = 0;; ++subleaf) {
+        __get_cpuid_count(0xB, subleaf, &eax, &ebx, &ecx, &edx);
+        if (ebx == 0)
+          break;
+
+        const u32 levelType         = (ecx >> 8) & 0xFF;
+        const u32 processorsAtLevel = ebx & 0xFFFF;
+
+        if (levelType == 1) // SMT (Hyper-Threading) level
+          threadsPerCore = processorsAtLevel;
+
+        if (levelType == 2) // Core level
+          logicalCores = processorsAtLevel;
+      }
+
+      if (logicalCores > 0 && threadsPerCore > 0)
+        physicalCores = logicalCores / threadsPerCore;
+    }
+
+    if (physicalCores == 0 || logicalCores == 0) {
+      __get_cpuid(0x1, &eax, &ebx, &ecx, &edx);
+      logicalCores                 = (ebx >> 16) & 0xFF;
+      const bool hasHyperthreading = (edx & (1 << 28)) != 0;
+
+      if (hasHyperthreading) {
+        constexpr u32 vendorIntel = 0x756e6547; // "Genu"ine"Intel"
+        constexpr u32 vendorAmd   = 0x68747541; // "Auth"entic"AMD"
+
+        if (vendorEbx == vendorIntel && maxLeaf >= 0x4) {
+          __get_cpuid_count(0x4, 0, &eax, &ebx, &ecx, &edx);
+          physicalCores = ((eax >> 26) & 0x3F) + 1;
+        } else if (vendorEbx == vendorAmd) {
+          __get_cpuid(0x80000000, &eax, &ebx, &ecx, &edx); // Get max extended leaf
+          if (eax >= 0x80000008) {
+            __get_cpuid(0x80000008, &eax, &ebx, &ecx, &edx);
+            physicalCores = (ecx & 0xFF) + 1;
+          }
+        }
+      } else {
+        physicalCores = logicalCores;
+      }
+    }
+
+    if (physicalCores == 0 && logicalCores > 0)
+      physicalCores = logicalCores;
+
+    if (physicalCores == 0 || logicalCores == 0)
+      ERR(InternalError, "Failed to determine core counts via CPUID");
+
+    return CPUCores(physicalCores, logicalCores);
+  }
+
+  auto GetCPUDetails(CacheManager& cache) -> Result<CPUDetails> {
+    return cache.getOrSet<CPUDetails>("linux_cpu_details", CachePolicy::neverExpire(), []() -> Result<CPUDetails> {
+      std::ifstream cpuinfo("/proc/cpuinfo");
+
+      if (!cpuinfo.is_open())
+        ERR(NotFound, "Failed to open /proc/cpuinfo");
+
+      CPUDetails details;
+      details.architecture = TRY(os::unix_shared::GetMachineName());
+
+      // Arm cores identify their designer by implementer code rather than a vendor string.
+      // clang-format off
+      constexpr Array<Pair<u32, PCStr>, 8> armImplementers = {{
+        { 0x41,       "ARM" },
+        { 0x42,  "Broadcom" },
+        { 0x48, "HiSilicon" },
+        { 0x4E,    "NVIDIA" },
+        { 0x51,  "Qualcomm" },
+        { 0x61,     "Apple" },
+        { 0x6D, "Microsoft" },
+        { 0xC0,    "Ampere" },
+      }};
+      // clang-format on
+
+      String line;
+
+      // Every processor repeats the same fields, so the first block is enough.
+      while (std::getline(cpuinfo, line) && !line.empty()) {
+        const usize colonPos = line.find(':');
+
+        if (colonPos == String::npos)
+          continue;
+
+        String key   = line.substr(0, colonPos);
+        String value = line.substr(colonPos + 1);
+
+        TrimWhitespace(key);
+        TrimWhitespace(value);
+
+        // x86 reports decimal values, Arm reports hexadecimal ones ("0x41").
+        const auto parseValue = [&value]() -> Option<u32> {
+          return value.starts_with("0x") ? TryParse<u32>(StringView(value).substr(2), 16) : TryParse<u32>(value);
+        };
+
+        if (key == "vendor_id") {
+          details.vendor = value;
+        } else if (key == "CPU implementer") {
+          const Option<u32> implementer = parseValue();
+          const auto        known       = std::ranges::find_if(armImplementers, [&implementer](const Pair<u32, PCStr>& entry) {
+            return implementer == entry.first;
+          });
+
+          details.vendor = known != armImplementers.end() ? String(known->second) : value;
+        } else if (key == "cpu family" || key == "CPU architecture") {
+          details.family = parseValue();
+        } else if (key == "model" || key == "CPU part") {
+          details.model = parseValue();
+        } else if (key == "stepping" || key == "CPU revision") {
+          details.stepping = parseValue();
+        } else if (key == "flags" || key == "Features") {
+          for (const auto& flag : value | std::views::split(' '))
+            if (!flag.empty())
+              details.flags.emplace_back(flag.begin(), flag.end());
+        }
+      }
+
+      return details;
+    });
+  }
+
+  auto GetCPUCaches(CacheManager& cache) -> Result<Vec<CPUCacheInfo>> {
+    return cache.getOrSet<Vec<CPUCacheInfo>>("linux_cpu_caches", CachePolicy::neverExpire(), []() -> Result<Vec<CPUCacheInfo>> {
+      using matchit::match, matchit::is, matchit::_;
+      using enum CPUCacheInfo::Type;
+
+      const auto readNumber = [](const fs::path& path) -> Option<u64> {
+        return ReadSysFile(path)
+          .transform([](const String& value) -> Option<u64> { return TryParse<u64>(value); })
+          .value_or(None);
+      };
+
+      // Sizes are reported with a unit suffix, e.g. "48K" or "32768K".
+      const auto readSize = [](const fs::path& path) -> Option<u64> {
+        return ReadSysFile(path)
+          .transform([](const String& value) -> Option<u64> {
+            StringView digits     = value;
+            u64        multiplier = 1;
+
+            if (digits.ends_with('K'))
+              multiplier = 1024;
+            else if (digits.ends_with('M'))
+              multiplier = 1024 * 1024;
+
+            if (multiplier != 1)
+              digits.remove_suffix(1);
+
+            return TryParse<u64>(digits).transform([multiplier](const u64 size) -> u64 { return size * multiplier; });
+          })
+          .value_or(None);
+      };
+
+      // Every CPU lists the caches it can use, so an instance is identified by the CPUs sharing it.
+      std::map<std::tuple<u8, CPUCacheInfo::Type, String>, CPUCacheInfo> instances;
+      std::error_code                                                    errc;
+
+      for (const fs::directory_entry& cpuEntry : fs::directory_iterator("/sys/devices/system/cpu", errc)) {
+        const String cpuName = cpuEntry.path().filename().string();
+
+        if (!cpuName.starts_with("cpu") || !TryParse<u32>(StringView(cpuName).substr(3)))
+          continue;
+
+        std::error_code indexErrc;
+
+        for (const fs::directory_entry& indexEntry : fs::directory_iterator(cpuEntry.path() / "cache", indexErrc)) {
+          const fs::path& indexPath = indexEntry.path();
+
+          if (!indexPath.filename().string().starts_with("index"))
+            continue;
+
+          const Option<u64> level = readNumber(indexPath / "level");
+          const Option<u64> size  = readSize(indexPath / "size");
+
+          if (!level || !size || *level > std::numeric_limits<u8>::max())
+            continue;
+
+          const CPUCacheInfo::Type type = match(ReadSysFile(indexPath / "type").value_or(""))(
+            is | "Data"        = Data,
+            is | "Instruction" = Instruction,
+            is | _             = Unified
+          );
+
+          const auto [instance, inserted] = instances.try_emplace(
+            { static_cast<u8>(*level), type, ReadSysFile(indexPath / "shared_cpu_list").value_or(cpuName) },
+            CPUCacheInfo {
+              .type           = type,
+              .level          = static_cast<u8>(*level),
+              .sizeBytes      = *size,
+              .lineSizeBytes  = readNumber(indexPath / "coherency_line_size").transform([](const u64 lineSize) -> u32 { return static_cast<u32>(lineSize); }),
+              .sharedCpuCount = 0,
+              .instanceCount  = 1,
+            }
+          );
+
+          ++instance->second.sharedCpuCount;
+        }
+      }
+
+      // The map is ordered by level and type, so the grouped entries come out in L1d, L1i, L2, L3 order.
+      Vec<CPUCacheInfo> caches;
+
+      for (const CPUCacheInfo& instance : instances | std::views::values) {
+        if (!caches.empty() && caches.back().level == instance.level && caches.back().type == instance.type) {
+          ++caches.back().instanceCount;
+          caches.back().sharedCpuCount = std::max(caches.back().sharedCpuCount, instance.sharedCpuCount);
+        } else {
+          caches.push_back(instance);
+        }
+      }
+
+      if (caches.empty())
+        ERR(NotFound, "No CPU cache information found in /sys/devices/system/cpu");
+
+      return caches;
+    });
+  }
+
+  auto GetNumaTopology(CacheManager& /*cache*/) -> Result<Vec<NumaNode>> {
+    Vec<NumaNode>   nodes;
+    std::error_code errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/devices/system/node", errc)) {
+      const String name = entry.path().filename().string();
+
+      if (!name.starts_with("node"))
+        continue;
+
+      const Option<u32> nodeId = TryParse<u32>(StringView(name).substr(4));
+
+      if (!nodeId)
+        continue;
+
+      NumaNode node { .id = *nodeId, .totalMemoryBytes = None, .freeMemoryBytes = None, .cpus = ParseCpuList(ReadSysFile(entry.path() / "cpulist").value_or("")) };
+
+      // Lines look like "Node 0 MemTotal:       32768000 kB".
+      std::ifstream meminfo(entry.path() / "meminfo");
+      String        line;
+
+      while (std::getline(meminfo, line)) {
+        std::istringstream stream(line);
+        String             nodeLabel;
+        String             nodeNumber;
+        String             key;
+        u64                kibibytes = 0;
+
+        if (!(stream >> nodeLabel >> nodeNumber >> key >> kibibytes))
+          continue;
+
+        if (key == "MemTotal:")
+          node.totalMemoryBytes = kibibytes * 1024;
+        else if (key == "MemFree:")
+          node.freeMemoryBytes = kibibytes * 1024;
+      }
+
+      nodes.push_back(std::move(node));
+    }
+
+    if (nodes.empty())
+      ERR(NotFound, "No NUMA nodes found in /sys/devices/system/node");
+
+    std::ranges::sort(nodes, {}, &NumaNode::id);
+
+    return nodes;
+  }
+
+  auto GetCPUVulnerabilities(CacheManager& cache) -> Result<Vec<CPUVulnerability>> {
+    return cache.getOrSet<Vec<CPUVulnerability>>("linux_cpu_vulnerabilities", CachePolicy::inMemory(), []() -> Result<Vec<CPUVulnerability>> {
+      using enum CPUVulnerability::State;
+
+      Vec<CPUVulnerability> vulnerabilities;
+      std::error_code       errc;
+
+      for (const fs::directory_entry& entry : fs::directory_iterator("/sys/devices/system/cpu/vulnerabilities", errc)) {
+        Result<String> status = ReadSysFile(entry.path());
+
+        if (!status)
+          continue;
+
+        // e.g. "Not affected", "Mitigation: PTI", "Vulnerable: eIBRS with unprivileged eBPF".
+        // itlb_multihit prefixes its state with "KVM: ".
+        StringView state = *status;
+
+        if (state.starts_with("KVM: "))
+          state.remove_prefix(5);
+
+        CPUVulnerability vulnerability {
+          .name    = entry.path().filename().string(),
+          .state   = Unknown,
+          .details = std::move(*status),
+        };
+
+        if (state.starts_with("Not affected"))
+          vulnerability.state = NotAffected;
+        else if (state.starts_with("Mitigation"))
+          vulnerability.state = Mitigated;
+        else if (state.starts_with("Vulnerable"))
+          vulnerability.state = Vulnerable;
+
+        vulnerabilities.push_back(std::move(vulnerability));
+      }
+
+      if (vulnerabilities.empty())
+        ERR(NotFound, "No CPU vulnerability information found in /sys/devices/system/cpu/vulnerabilities");
+
+      std::ranges::sort(vulnerabilities, {}, &CPUVulnerability::name);
+
+      return vulnerabilities;
+    });
+  }
+
+  auto GetCPUFrequency(CacheManager& /*cache*/) -> Result<CPUFrequency> {
+    const fs::path cpufreqPath = "/sys/devices/system/cpu/cpu0/cpufreq";
+
+    // cpufreq reports values in kHz
+    const auto readMhz = [&](const StringView name) -> Option<u64> {
+      return ReadSysFile(cpufreqPath / name)
+        .transform([](const String& value) -> Option<u64> {
+          return TryParse<u64>(value).transform([](const u64 khz) -> u64 { return khz / 1000; });
+        })
+        .value_or(None);
+    };
+
+    Option<u64> currentMhz = readMhz("scaling_cur_freq");
+
+    if (!currentMhz) {
+      std::ifstream cpuinfo("/proc/cpuinfo");
+      String        line;
+
+      while (std::getline(cpuinfo, line))
+        if (line.starts_with("cpu MHz"))
+          if (const usize colonPos = line.find(':'); colonPos != String::npos) {
+            currentMhz = static_cast<u64>(std::strtod(line.c_str() + colonPos + 1, nullptr));
+            break;
+          }
+    }
+
+    CPUFrequency frequency(currentMhz, readMhz("base_frequency"), readMhz("cpuinfo_max_freq"));
+
+    if (!frequency.currentMhz && !frequency.baseMhz && !frequency.maxMhz)
+      ERR(NotFound, "No CPU frequency information found in cpufreq or /proc/cpuinfo");
+
+    return frequency;
+  }
+
+  auto GetCPUTemperature(CacheManager& /*cache*/) -> Result<f64> {
+    // clang-format off
+    constexpr Array<StringView, 5> cpuSensorNames = {
+      "coretemp", "k10temp", "zenpower", "cpu_thermal", "cpu-thermal",
+    };
+    // clang-format on
+
+    // hwmon reports temperatures in millidegrees Celsius
+    const auto readCelsius = [](const fs::path& path) -> Option<f64> {
+      return ReadSysFile(path)
+        .transform([](const String& value) -> Option<f64> {
+          return TryParse<i64>(value).transform([](const i64 milli) -> f64 { return static_cast<f64>(milli) / 1000.0; });
+        })
+        .value_or(None);
+    };
+
+    std::error_code errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/hwmon", errc)) {
+      Result<String> name = ReadSysFile(entry.path() / "name");
+
+      if (!name || std::ranges::find(cpuSensorNames, StringView(*name)) == cpuSensorNames.end())
+        continue;
+
+      // Prefer the package/die sensor when labels are available
+      for (u32 i = 1; i <= 16; ++i)
+        if (Result<String> label = ReadSysFile(entry.path() / std::format("temp{}_label", i)); label && (label->starts_with("Package") || *label == "Tctl" || *label == "Tdie"))
+          if (Option<f64> temp = readCelsius(entry.path() / std::format("temp{}_input", i)))
+            return *temp;
+
+      if (Option<f64> temp = readCelsius(entry.path() / "temp1_input"))
+        return *temp;
+    }
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/thermal", errc)) {
+      if (!entry.path().filename().string().starts_with("thermal_zone"))
+        continue;
+
+      if (Result<String> type = ReadSysFile(entry.path() / "type"); !type || (*type != "x86_pkg_temp" && !type->contains("cpu")))
+        continue;
+
+      if (Option<f64> temp = readCelsius(entry.path() / "temp"))
+        return *temp;
+    }
+
+    ERR(NotFound, "No CPU temperature sensor found in hwmon or thermal subsystem");
+  }
+
+  auto GetFans(CacheManager& /*cache*/) -> Result<Vec<FanInfo>> {
+    const auto readRpm = [](const fs::path& path) -> Option<u32> {
+      return ReadSysFile(path).transform([](const String& value) -> Option<u32> { return TryParse<u32>(value); }).value_or(None);
+    };
+
+    Vec<FanInfo>    fans;
+    std::error_code errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/hwmon", errc)) {
+      const String chip = ReadSysFile(entry.path() / "name").value_or(entry.path().filename().string());
+
+      // Channels are numbered from 1 but can have gaps, so look at every fanN_input present.
+      for (const fs::directory_entry& file : fs::directory_iterator(entry.path(), errc)) {
+        const String fileName = file.path().filename().string();
+
+        if (!fileName.starts_with("fan") || !fileName.ends_with("_input"))
+          continue;
+
+        const String channel = fileName.substr(3, fileName.size() - std::char_traits<char>::length("fan_input"));
+
+        // Headers with nothing plugged in fail to read rather than reporting 0.
+        const Option<u32> rpm = readRpm(file.path());
+
+        if (!rpm)
+          continue;
+
+        fans.push_back({
+          .label     = ReadSysFile(entry.path() / std::format("fan{}_label", channel)).value_or(std::format("{} fan{}", chip, channel)),
+          .rpm       = rpm,
+          .targetRpm = readRpm(entry.path() / std::format("fan{}_target", channel)),
+        });
+      }
+    }
+
+    if (fans.empty())
+      ERR(NotFound, "No fan sensors found in hwmon");
+
+    std::ranges::sort(fans, {}, &FanInfo::label);
+
+    return fans;
+  }
+
+  auto GetCPUPowerWatts(CacheManager& /*cache*/) -> Result<f64> {
+    constexpr std::chrono::milliseconds sampleInterval(100);
+
+    struct Package {
+      fs::path energyPath;
+      u64      maxEnergyUj; ///< The counter wraps back to zero here.
+      u64      startUj;
+    };
+
+    const auto readMicrojoules = [](const fs::path& path) -> Option<u64> {
+      return ReadSysFile(path).transform([](const String& value) -> Option<u64> { return TryParse<u64>(value); }).value_or(None);
+    };
+
+    Vec<Package>    packages;
+    bool            denied = false;
+    std::error_code errc;
+
+    // Each CPU package is a top-level zone (intel-rapl:0, intel-rapl:1, ...); the nested zones are its
+    // cores, uncore and DRAM. The kernel uses the intel-rapl name for AMD's compatible interface too.
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/powercap", errc)) {
+      const String zone = entry.path().filename().string();
+
+      if (!zone.starts_with("intel-rapl:") || std::ranges::count(zone, ':') != 1)
+        continue;
+
+      // Laptops also have a top-level "psys" zone covering the whole platform.
+      if (!ReadSysFile(entry.path() / "name").value_or("").starts_with("package"))
+        continue;
+
+      const fs::path energyPath = entry.path() / "energy_uj";
+
+      // Since the PLATYPUS side-channel fixes, the counter is only readable by root.
+      if (access(energyPath.c_str(), R_OK) != 0) {
+        denied |= errno == EACCES;
+        continue;
+      }
+
+      if (const Option<u64> start = readMicrojoules(energyPath))
+        packages.push_back({
+          .energyPath  = energyPath,
+          .maxEnergyUj = readMicrojoules(entry.path() / "max_energy_range_uj").value_or(0),
+          .startUj     = *start,
+        });
+    }
+
+    if (packages.empty()) {
+      if (denied)
+        ERR(PermissionDenied, "Reading the RAPL energy counters requires root");
+
+      ERR(NotFound, "No RAPL package zones found in /sys/class/powercap");
+    }
+
+    const auto startTime = std::chrono::steady_clock::now();
+
+    std::this_thread::sleep_for(sampleInterval);
+
+    u64 consumedUj = 0;
+
+    for (const Package& package : packages)
+      if (const Option<u64> end = readMicrojoules(package.energyPath))
+        consumedUj += *end >= package.startUj ? *end - package.startUj : *end + package.maxEnergyUj - package.startUj;
+
+    const f64 elapsedSecs = std::chrono::duration<f64>(std::chrono::steady_clock::now() - startTime).count();
+
+    return (static_cast<f64>(consumedUj) / 1'000'000.0) / elapsedSecs;
+  }
+
+  auto GetSensors(CacheManager& /*cache*/) -> Result<Vec<SensorReading>> {
+    using enum SensorReading::Kind;
+
+    struct ChannelType {
+      StringView          prefix;
+      StringView          suffix;
+      SensorReading::Kind kind;
+      f64                 scale; ///< Converts the raw sysfs value into the reported unit.
+    };
+
+    // power*_average is only used by drivers that don't provide an instantaneous power*_input.
+    // clang-format off
+    constexpr Array<ChannelType, 6> channelTypes {{
+      { "temp",  "_input",   Temperature, 1e-3 }, // millidegrees Celsius
+      { "in",    "_input",   Voltage,     1e-3 }, // millivolts
+      { "curr",  "_input",   Current,     1e-3 }, // milliamperes
+      { "power", "_input",   Power,       1e-6 }, // microwatts
+      { "power", "_average", Power,       1e-6 }, // microwatts
+      { "fan",   "_input",   Fan,         1.0  }, // RPM
+    }};
+    // clang-format on
+
+    Vec<SensorReading> sensors;
+    std::error_code    errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/hwmon", errc)) {
+      const String chip = ReadSysFile(entry.path() / "name").value_or(entry.path().filename().string());
+
+      for (const fs::directory_entry& file : fs::directory_iterator(entry.path(), errc)) {
+        const String fileName = file.path().filename().string();
+
+        for (const ChannelType& type : channelTypes) {
+          if (!fileName.starts_with(type.prefix) || !fileName.ends_with(type.suffix))
+            continue;
+
+          // The channel is whatever sits between the prefix and suffix, e.g. "1" in "temp1_input".
+          const StringView channel = StringView(fileName).substr(type.prefix.size(), fileName.size() - type.prefix.size() - type.suffix.size());
+
+          if (channel.empty() || !std::ranges::all_of(channel, [](const char chr) { return std::isdigit(static_cast<unsigned char>(chr)) != 0; }))
+            continue;
+
+          if (type.suffix == "_average" && fs::exists(entry.path() / std::format("{}{}_input", type.prefix, channel), errc))
+            continue;
+
+          const Option<i64> raw = ReadSysFile(file.path()).transform([](const String& value) -> Option<i64> { return TryParse<i64>(value); }).value_or(None);
+
+          if (!raw)
+            continue;
+
+          sensors.push_back({
+            .kind  = type.kind,
+            .chip  = chip,
+            .label = ReadSysFile(entry.path() / std::format("{}{}_label", type.prefix, channel)).value_or(std::format("{}{}", type.prefix, channel)),
+            .value = static_cast<f64>(*raw) * type.scale,
+          });
+        }
+      }
+    }
+
+    if (sensors.empty())
+      ERR(NotFound, "No sensors found in hwmon");
+
+    std::ranges::sort(sensors, [](const SensorReading& lhs, const SensorReading& rhs) {
+      return std::tie(lhs.chip, lhs.kind, lhs.label) < std::tie(rhs.chip, rhs.kind, rhs.label);
+    });
+
+    return sensors;
+  }
+
+  auto GetCPUTimesPerCore() -> Result<Vec<CPUTimes>> {
+    std::ifstream file("/proc/stat");
+
+    if (!file.is_open())
+      ERR(NotFound, "Failed to open /proc/stat");
+
+    Vec<CPUTimes> times;
+    String        line;
+
+    while (std::getline(file, line)) {
+      // Skip the aggregate "cpu " line; per-core lines are "cpu0", "cpu1", ...
+      if (!line.starts_with("cpu") || line.size() < 4 || line[3] == ' ')
+        continue;
+
+      std::istringstream iss(line);
+      String             label;
+      u64                user = 0, nice = 0, system = 0, idle = 0, iowait = 0, irq = 0, softirq = 0, steal = 0;
+
+      if (!(iss >> label >> user >> nice >> system >> idle >> iowait >> irq >> softirq >> steal))
+        ERR_FMT(ParseError, "Failed to parse /proc/stat line: {}", line);
+
+      times.emplace_back(idle + iowait, user + nice + system + idle + iowait + irq + softirq + steal);
+    }
+
+    if (times.empty())
+      ERR(NotFound, "No per-CPU lines found in /proc/stat");
+
+    return times;
+  }
+
+  auto GetGPUModel(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("linux_gpu_model", CachePolicy::neverExpire(), []() -> Result<String> {
+      const fs::path pciPath = "/sys/bus/pci/devices";
+
+      if (!fs::exists(pciPath))
+        ERR(NotFound, "PCI device path '/sys/bus/pci/devices' not found.");
+
+      for (const fs::directory_entry& entry : fs::directory_iterator(pciPath)) {
+        if (Result<String> classIdRes = ReadSysFile(entry.path() / "class"); !classIdRes || !classIdRes->starts_with("0x03"))
+          continue;
+
+        Result<String> vendorIdRes = ReadSysFile(entry.path() / "vendor");
+        Result<String> deviceIdRes = ReadSysFile(entry.path() / "device");
+
+        if (vendorIdRes && deviceIdRes)
+          if (Result<Pair<String, String>> pciNames = LookupPciNames(*vendorIdRes, *deviceIdRes))
+            return CleanGpuModelName(std::move(pciNames->first), std::move(pciNames->second));
+
+        if (vendorIdRes)
+          if (Option<StringView> vendorName = LookupGpuVendorId(*vendorIdRes))
+            return String(*vendorName);
+      }
+
+      ERR(NotFound, "No compatible GPU found in /sys/bus/pci/devices.");
+    });
+  }
+
+  auto GetGPUs(CacheManager& cache) -> Result<Vec<GPUInfo>> {
+    const fs::path pciPath = "/sys/bus/pci/devices";
+
+    if (!fs::exists(pciPath))
+      ERR(NotFound, "PCI device path '/sys/bus/pci/devices' not found.");
+
+    // Probing loads every driver, and the versions can't change without a reboot or driver reload, so the results are kept.
+    const Vec<os::vulkan::Device> vulkanDevices =
+      cache.getOrSet<Vec<os::vulkan::Device>>("linux_vulkan_devices", CachePolicy::inMemory(), os::vulkan::GetDevices).value_or(Vec<os::vulkan::Device> {});
+    const Vec<OpenGLDevice> openGLDevices =
+      cache.getOrSet<Vec<OpenGLDevice>>("linux_opengl_devices", CachePolicy::inMemory(), GetOpenGLDevices).value_or(Vec<OpenGLDevice> {});
+
+    const auto readSysValue = [](const fs::path& path) -> Option<u64> {
+      return ReadSysFile(path)
+        .transform([](const String& value) -> Option<u64> { return TryParse<u64>(value); })
+        .value_or(None);
+    };
+
+    Vec<GPUInfo> gpus;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator(pciPath)) {
+      if (Result<String> classIdRes = ReadSysFile(entry.path() / "class"); !classIdRes || !classIdRes->starts_with("0x03"))
+        continue;
+
+      Result<String> vendorIdRes = ReadSysFile(entry.path() / "vendor");
+      Result<String> deviceIdRes = ReadSysFile(entry.path() / "device");
+
+      if (!vendorIdRes || !deviceIdRes)
+        continue;
+
+      GPUInfo gpu;
+
+      if (Result<Pair<String, String>> pciNames = LookupPciNames(*vendorIdRes, *deviceIdRes)) {
+        gpu.vendor = CleanGpuVendorName(std::move(pciNames->first));
+        gpu.model  = CleanGpuDeviceName(std::move(pciNames->second));
+      } else {
+        gpu.vendor = String(LookupGpuVendorId(*vendorIdRes).value_or(*vendorIdRes));
+        gpu.model  = std::format("Device {}", *deviceIdRes);
+      }
+
+      // Only amdgpu exposes VRAM through sysfs; other drivers need vendor tooling.
+      gpu.vramBytes = readSysValue(entry.path() / "mem_info_vram_total");
+
+      if (std::error_code errc; const fs::path driverLink = fs::read_symlink(entry.path() / "driver", errc); !errc)
+        gpu.driver = driverLink.filename().string();
+
+      const Option<u32> vendorId = TryParse<u32>(StringView(*vendorIdRes).substr(2), 16);
+      const Option<u32> deviceId = TryParse<u32>(StringView(*deviceIdRes).substr(2), 16);
+
+      if (const auto vulkanDevice = std::ranges::find_if(vulkanDevices, [&](const os::vulkan::Device& device) -> bool { return device.vendorId == vendorId && device.deviceId == deviceId; });
+          vulkanDevice != vulkanDevices.end()) {
+        gpu.vulkanVersion = vulkanDevice->apiVersion;
+        gpu.driverVersion = vulkanDevice->driverVersion;
+      }
+
+      if (const auto openGLDevice = std::ranges::find(openGLDevices, entry.path().filename().string(), &OpenGLDevice::pciAddress); openGLDevice != openGLDevices.end()) {
+        gpu.openGLVersion = openGLDevice->version;
+
+        if (!gpu.driverVersion)
+          gpu.driverVersion = openGLDevice->driverVersion;
+      }
+
+      // Out-of-tree modules like nvidia carry the same version as their userspace driver.
+      if (!gpu.driverVersion && gpu.driver)
+        if (Result<String> moduleVersion = ReadSysFile(fs::path("/sys/module") / *gpu.driver / "version"))
+          gpu.driverVersion = *std::move(moduleVersion);
+
+      // Integrated GPUs sit directly on the root bus (0000:00:xx.x), while discrete
+      // cards are always behind a PCIe bridge.
+      gpu.isIntegrated = entry.path().filename().string().substr(5, 2) == "00";
+
+      // amdgpu and nouveau register a hwmon device under the PCI device. Temperatures are
+      // in millidegrees Celsius and power in microwatts.
+      if (std::error_code errc; fs::exists(entry.path() / "hwmon", errc))
+        for (const fs::directory_entry& hwmon : fs::directory_iterator(entry.path() / "hwmon", errc)) {
+          if (!gpu.temperatureCelsius)
+            gpu.temperatureCelsius = readSysValue(hwmon.path() / "temp1_input").transform([](const u64 milli) -> f64 { return static_cast<f64>(milli) / 1000.0; });
+
+          if (!gpu.powerWatts)
+            gpu.powerWatts = readSysValue(hwmon.path() / "power1_average")
+                               .or_else([&]() -> Option<u64> { return readSysValue(hwmon.path() / "power1_input"); })
+                               .transform([](const u64 micro) -> f64 { return static_cast<f64>(micro) / 1'000'000.0; });
+        }
+
+      gpus.push_back(std::move(gpu));
+    }
+
+    if (gpus.empty())
+      ERR(NotFound, "No compatible GPU found in /sys/bus/pci/devices.");
+
+    return gpus;
+  }
+
+  auto GetUptime() -> Result<std::chrono::seconds> {
+    return os::unix_shared::GetUptimeLinux();
+  }
+
+  auto GetBootTime() -> Result<std::chrono::system_clock::time_point> {
+    std::ifstream file("/proc/stat");
+
+    if (!file.is_open())
+      ERR(NotFound, "Failed to open /proc/stat");
+
+    String line;
+
+    while (std::getline(file, line))
+      if (line.starts_with("btime "))
+        if (const Option<i64> btime = TryParse<i64>(StringView(line).substr(6)))
+          return std::chrono::system_clock::from_time_t(static_cast<std::time_t>(*btime));
+
+    ERR(ParseError, "Failed to find btime in /proc/stat");
+  }
+
+  auto GetLoadAverage() -> Result<LoadAverage> {
+    return os::unix_shared::GetLoadAverage();
+  }
+
+  auto GetProcesses(CacheManager& /*cache*/) -> Result<Vec<ProcessInfo>> {
+    const f64 uptimeSecs = TRY(ReadUptimeSeconds());
+
+    UnorderedMap<uid_t, Option<String>> userNames;
+    Vec<ProcessInfo>                    processes;
+
+    std::error_code errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/proc", errc)) {
+      const Option<u32> pid = TryParse<u32>(entry.path().filename().string());
+
+      if (!pid)
+        continue;
+
+      // Processes can exit between listing /proc and reading their files, so skip failures.
+      if (Result<ProcessInfo> info = ReadProcessInfo(*pid, uptimeSecs, userNames))
+        processes.push_back(std::move(*info));
+    }
 
-          if (const usize lastSlash = shellPath.find_last_of('/'); lastSlash != String::npos)
-            return shellPath.substr(lastSlash + 1);
+    if (errc)
+      ERR_FMT(IoError, "Failed to list /proc: {}", errc.message());
 
-          return shellPath;
-        })
-        .transform([](const String& shellPath) -> String {
-          return shellPath;
-        });
-    });
+    return processes;
   }
 
-  auto GetHost(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("linux_host", []() -> Result<String> {
-      constexpr PCStr primaryPath  = "/sys/class/dmi/id/product_family";
-      constexpr PCStr fallbackPath = "/sys/class/dmi/id/product_name";
+  auto GetProcess(const u32 pid) -> Result<ProcessInfo> {
+    UnorderedMap<uid_t, Option<String>> userNames;
 
-      auto readFirstLine = [&](const String& path) -> Result<String> {
-        std::ifstream file(path);
-        String        line;
+    return ReadProcessInfo(pid, TRY(ReadUptimeSeconds()), userNames);
+  }
 
-        if (!file.is_open()) {
-          if (errno == EACCES)
-            ERR_FMT(PermissionDenied, "Permission denied when opening DMI product identifier file '{}'", path);
+  auto FindProcesses(const StringView name) -> Result<Vec<ProcessInfo>> {
+    // The kernel truncates comm to 15 characters, so compare against the same prefix.
+    constexpr usize commLength = 15;
 
-          ERR_FMT(NotFound, "Failed to open DMI product identifier file '{}'", path);
-        }
+    const StringView target     = name.substr(0, commLength);
+    const f64        uptimeSecs = TRY(ReadUptimeSeconds());
 
-        if (!std::getline(file, line) || line.empty())
-          ERR_FMT(ParseError, "DMI product identifier file ('{}') is empty", path);
+    UnorderedMap<uid_t, Option<String>> userNames;
+    Vec<ProcessInfo>                    processes;
 
-        return line;
-      };
+    std::error_code errc;
 
-      Result<String> primaryResult = readFirstLine(primaryPath);
+    for (const fs::directory_entry& entry : fs::directory_iterator("/proc", errc)) {
+      const Option<u32> pid = TryParse<u32>(entry.path().filename().string());
 
-      if (primaryResult)
-        return primaryResult;
+      if (!pid)
+        continue;
 
-      DracError primaryError = primaryResult.error();
+      // Reading comm alone is much cheaper than parsing stat and cmdline for every process.
+      if (Result<String> comm = ReadSysFile(entry.path() / "comm"); !comm || *comm != target)
+        continue;
 
-      Result<String> fallbackResult = readFirstLine(fallbackPath);
+      if (Result<ProcessInfo> info = ReadProcessInfo(*pid, uptimeSecs, userNames))
+        processes.push_back(std::move(*info));
+    }
 
-      if (fallbackResult)
-        return fallbackResult;
+    if (errc)
+      ERR_FMT(IoError, "Failed to list /proc: {}", errc.message());
 
-      DracError fallbackError = fallbackResult.error();
+    return processes;
+  }
 
-      ERR_FMT(
-        NotFound,
-        "Failed to get host identifier. Primary ('{}'): {}. Fallback ('{}'): {}",
-        primaryPath,
-        primaryError.message,
-        fallbackPath,
-        fallbackError.message
-      );
+  auto GetInitSystem(CacheManager& cache) -> Result<InitSystem> {
+    return cache.getOrSet<InitSystem>("linux_init_system", CachePolicy::inMemory(), []() -> Result<InitSystem> {
+      return DetectInitSystem();
     });
   }
 
-  auto GetCPUModel(CacheManager& /*cache*/) -> Result<String> {
-    Array<u32, 4>   cpuInfo;
-    Array<char, 49> brandString = { 0 };
+  auto GetServiceStatus(const StringView name) -> Result<ServiceInfo> {
+    const InitSystem init = DetectInitSystem();
 
-    __get_cpuid(0x80000000, cpuInfo.data(), &cpuInfo[1], &cpuInfo[2], &cpuInfo[3]);
-    const u32 maxFunction = cpuInfo[0];
+    if (init.kind == InitSystem::Kind::Systemd) {
+  #if DRAC_USE_DBUS
+      return GetSystemdService(name);
+  #else
+      ERR(NotSupported, "Querying systemd services requires D-Bus support");
+  #endif
+    }
 
-    if (maxFunction < 0x80000004)
-      ERR(NotSupported, "CPU does not support brand string");
+    if (init.kind == InitSystem::Kind::OpenRC)
+      return GetOpenRCService(name);
 
-    for (u32 i = 0; i < 3; ++i) {
-      __get_cpuid(0x80000002 + i, cpuInfo.data(), &cpuInfo[1], &cpuInfo[2], &cpuInfo[3]);
-      std::memcpy(&brandString.at(i * 16), cpuInfo.data(), sizeof(cpuInfo));
-    }
+    ERR_FMT(NotSupported, "Querying services is not supported for init system '{}'", init.name);
+  }
 
-    String result(brandString.data());
+  auto ListServices() -> Result<Vec<ServiceInfo>> {
+    const InitSystem init = DetectInitSystem();
 
-    result.erase(result.find_last_not_of(" \t\n\r") + 1);
+    if (init.kind == InitSystem::Kind::Systemd) {
+  #if DRAC_USE_DBUS
+      return ListSystemdServices();
+  #else
+      ERR(NotSupported, "Querying systemd services requires D-Bus support");
+  #endif
+    }
 
-    if (result.empty())
-      ERR(InternalError, "Failed to get CPU model string via CPUID");
+    if (init.kind == InitSystem::Kind::OpenRC)
+      return ListOpenRCServices();
 
-    return result;
+    ERR_FMT(NotSupported, "Querying services is not supported for init system '{}'", init.name);
   }
 
-  auto GetCPUCores(CacheManager& /*cache*/) -> Result<CPUCores> {
-    u32 eax = 0, ebx = 0, ecx = 0, edx = 0;
+  auto GetContainers(CacheManager& /*cache*/) -> Result<Vec<ContainerInfo>> {
+    using enum ContainerInfo::Runtime;
 
-    __get_cpuid(0x0, &eax, &ebx, &ecx, &edx);
-    const u32 maxLeaf   = eax;
-    const u32 vendorEbx = ebx;
+    Vec<ContainerInfo> containers;
+    bool               reached = false;
+    bool               denied  = false;
 
-    u32 logicalCores  = 0;
-    u32 physicalCores = 0;
+    // podman-docker symlinks /var/run/docker.sock to Podman's socket, so containers already seen
+    // through a Podman socket are skipped when the Docker socket lists them again.
+    const auto collect = [&](const StringView socketPath, const ContainerInfo::Runtime runtime) {
+      Result<Vec<ContainerInfo>> listed = os::containers::ListFromSocket(socketPath, runtime);
 
-    if (maxLeaf >= 0xB) {
-      u32 threadsPerCore = 0;
-      for (u32 subleaf = 0;; ++subleaf) {
-        __get_cpuid_count(0xB, subleaf, &eax, &ebx, &ecx, &edx);
-        if (ebx == 0)
-          break;
+      if (!listed) {
+        denied |= listed.error().code == PermissionDenied;
+        return;
+      }
 
-        const u32 levelType         = (ecx >> 8) & 0xFF;
-        const u32 processorsAtLevel = ebx & 0xFFFF;
+      reached = true;
 
-        if (levelType == 1) // SMT (Hyper-Threading) level
-          threadsPerCore = processorsAtLevel;
+      for (ContainerInfo& container : *listed)
+        if (std::ranges::none_of(containers, [&](const ContainerInfo& seen) { return seen.id == container.id; }))
+          containers.push_back(std::move(container));
+    };
 
-        if (levelType == 2) // Core level
-          logicalCores = processorsAtLevel;
-      }
+    collect("/run/podman/podman.sock", Podman);
 
-      if (logicalCores > 0 && threadsPerCore > 0)
-        physicalCores = logicalCores / threadsPerCore;
-    }
+    if (const Result<String> runtimeDir = GetEnv("XDG_RUNTIME_DIR"))
+      collect(std::format("{}/podman/podman.sock", *runtimeDir), Podman);
 
-    if (physicalCores == 0 || logicalCores == 0) {
-      __get_cpuid(0x1, &eax, &ebx, &ecx, &edx);
-      logicalCores                 = (ebx >> 16) & 0xFF;
-      const bool hasHyperthreading = (edx & (1 << 28)) != 0;
+    if (const Result<String> dockerHost = GetEnv("DOCKER_HOST"); dockerHost && dockerHost->starts_with("unix://"))
+      collect(StringView(*dockerHost).substr(std::char_traits<char>::length("unix://")), Docker);
+    else
+      collect("/var/run/docker.sock", Docker);
 
-      if (hasHyperthreading) {
-        constexpr u32 vendorIntel = 0x756e6547; // "Genu"ine"Intel"
-        constexpr u32 vendorAmd   = 0x68747541; // "Auth"entic"AMD"
+    Vec<ContainerInfo> lxcContainers = ListLxcContainers();
 
-        if (vendorEbx == vendorIntel && maxLeaf >= 0x4) {
-          __get_cpuid_count(0x4, 0, &eax, &ebx, &ecx, &edx);
-          physicalCores = ((eax >> 26) & 0x3F) + 1;
-        } else if (vendorEbx == vendorAmd) {
-          __get_cpuid(0x80000000, &eax, &ebx, &ecx, &edx); // Get max extended leaf
-          if (eax >= 0x80000008) {
-            __get_cpuid(0x80000008, &eax, &ebx, &ecx, &edx);
-            physicalCores = (ecx & 0xFF) + 1;
-          }
-        }
-      } else {
-        physicalCores = logicalCores;
-      }
+    if (!reached && lxcContainers.empty()) {
+      if (denied)
+        ERR(PermissionDenied, "Permission denied connecting to the container runtime");
+
+      ERR(NotFound, "No container runtime is running");
     }
 
-    if (physicalCores == 0 && logicalCores > 0)
-      physicalCores = logicalCores;
+    std::ranges::move(lxcContainers, std::back_inserter(containers));
 
-    if (physicalCores == 0 || logicalCores == 0)
-      ERR(InternalError, "Failed to determine core counts via CPUID");
+    if (const Result<f64> uptimeSecs = ReadUptimeSeconds())
+      for (ContainerInfo& container : containers)
+        ReadContainerUsage(container, *uptimeSecs);
 
-    return CPUCores(physicalCores, logicalCores);
+    return containers;
   }
 
-  auto GetGPUModel(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("linux_gpu_model", []() -> Result<String> {
-      const fs::path pciPath = "/sys/bus/pci/devices";
+  auto GetSessions() -> Result<Vec<SessionInfo>> {
+    return os::unix_shared::GetSessions();
+  }
 
-      if (!fs::exists(pciPath))
-        ERR(NotFound, "PCI device path '/sys/bus/pci/devices' not found.");
+  auto GetCurrentUser() -> Result<UserInfo> {
+    return os::unix_shared::GetCurrentUser();
+  }
 
-      // clang-format off
-      const Array<Pair<StringView, StringView>, 3> fallbackVendorMap = {{
-        { "0x1002", "AMD" },
-        { "0x10de", "NVIDIA" },
-        { "0x8086", "Intel" },
-      }};
-      // clang-format on
+  auto GetLocale() -> Result<String> {
+    if (Option<String> locale = os::unix_shared::GetLocaleFromEnv())
+      return std::move(*locale);
 
-      for (const fs::directory_entry& entry : fs::directory_iterator(pciPath)) {
-        if (Result<String> classIdRes = ReadSysFile(entry.path() / "class"); !classIdRes || !classIdRes->starts_with("0x03"))
-          continue;
+    // systemd-localed writes the system-wide default here.
+    if (std::ifstream file("/etc/locale.conf"); file) {
+      String line;
 
-        Result<String> vendorIdRes = ReadSysFile(entry.path() / "vendor");
-        Result<String> deviceIdRes = ReadSysFile(entry.path() / "device");
+      while (std::getline(file, line)) {
+        if (!line.starts_with("LANG="))
+          continue;
 
-        if (vendorIdRes && deviceIdRes)
-          if (Result<Pair<String, String>> pciNames = LookupPciNames(*vendorIdRes, *deviceIdRes))
-            return CleanGpuModelName(std::move(pciNames->first), std::move(pciNames->second));
+        String value = line.substr(5);
 
-        if (vendorIdRes) {
-          const auto* iter = std::ranges::find_if(fallbackVendorMap, [&](const auto& pair) -> auto {
-            return pair.first == *vendorIdRes;
-          });
+        if (value.size() >= 2 && value.front() == '"' && value.back() == '"')
+          value = value.substr(1, value.size() - 2);
 
-          if (iter != fallbackVendorMap.end())
-            return String(iter->second);
-        }
+        if (!value.empty())
+          return value;
       }
+    }
 
-      ERR(NotFound, "No compatible GPU found in /sys/bus/pci/devices.");
-    });
+    ERR(NotFound, "No locale is configured");
   }
 
-  auto GetUptime() -> Result<std::chrono::seconds> {
-    return os::unix_shared::GetUptimeLinux();
+  auto GetTimezone() -> Result<TimezoneInfo> {
+    return os::unix_shared::GetTimezone();
+  }
+
+  auto GetHostname() -> Result<String> {
+    return os::unix_shared::GetHostname();
+  }
+
+  auto GetFQDN() -> Result<String> {
+    return os::unix_shared::GetFQDN();
+  }
+
+  auto GetKeyboardLayouts() -> Result<Vec<KeyboardLayout>> {
+    const Vec<os::xkb::RegistryEntry> registry = os::xkb::ReadRegistry();
+
+    // Sway and Hyprland track the active layout themselves and already report descriptions.
+    if (GetEnv("SWAYSOCK")) {
+      Result<Vec<KeyboardLayout>> layouts = GetSwayKeyboardLayouts(registry);
+
+      if (layouts)
+        return layouts;
+
+      debug_at(layouts.error());
+    }
+
+    if (GetEnv("HYPRLAND_INSTANCE_SIGNATURE")) {
+      Result<Vec<KeyboardLayout>> layouts = GetHyprlandKeyboardLayouts(registry);
+
+      if (layouts)
+        return layouts;
+
+      debug_at(layouts.error());
+    }
+
+    Result<Vec<KeyboardLayout>> layouts = Err(DracError(NotFound, "No keyboard layout source detected"));
+
+    if (const Result<String> desktop = GetEnv("XDG_CURRENT_DESKTOP"); desktop && desktop->contains("KDE")) {
+      layouts = GetKdeKeyboardLayouts();
+
+      if (!layouts)
+        debug_at(layouts.error());
+    }
+
+    if (!layouts && GetEnv("DISPLAY")) {
+      layouts = GetX11KeyboardLayouts();
+
+      if (!layouts)
+        debug_at(layouts.error());
+    }
+
+    if (!layouts)
+      layouts = os::xkb::GetLayoutsFromConfig();
+
+    if (layouts)
+      os::xkb::Describe(*layouts, registry);
+
+    return layouts;
   }
 
   auto GetKernelVersion(CacheManager& cache) -> Result<String> {
@@ -993,62 +4357,248 @@ namespace draconis::core::system {
     });
   }
 
-  auto GetDiskUsage(CacheManager& /*cache*/) -> Result<ResourceUsage> {
-    return os::unix_shared::GetRootDiskUsage();
+  auto GetKernelCmdline(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("linux_kernel_cmdline", CachePolicy::inMemory(), []() -> Result<String> {
+      return ReadSysFile("/proc/cmdline");
+    });
+  }
+
+  auto GetKernelModules(CacheManager& /*cache*/) -> Result<Vec<KernelModule>> {
+    std::ifstream modulesFile("/proc/modules");
+
+    if (!modulesFile.is_open())
+      ERR(NotFound, "Failed to open /proc/modules (kernel built without module support?)");
+
+    Vec<KernelModule> modules;
+
+    // Each line reads "name size refcount dependencies state address", e.g. "zfs 6356992 6 spl, Live 0x0".
+    for (String line; std::getline(modulesFile, line);) {
+      std::istringstream stream(line);
+      String             name;
+      u64                size = 0;
+
+      if (!(stream >> name))
+        continue;
+
+      modules.push_back({ .name = std::move(name), .sizeBytes = (stream >> size) ? Option<u64>(size) : None });
+    }
+
+    return modules;
+  }
+
+  auto GetDiskUsage(CacheManager& /*cache*/) -> Result<ResourceUsage> {
+    return os::unix_shared::GetRootDiskUsage();
+  }
+
+  auto GetDisks(CacheManager& /*cache*/) -> Result<Vec<DiskInfo>> {
+    FILE* mtab = setmntent("/proc/mounts", "r");
+    if (mtab == nullptr)
+      ERR(IoError, "setmntent(\"/proc/mounts\") failed: could not open mount table");
+
+    UniquePointer<FILE, decltype(&endmntent)> mountTable(mtab, &endmntent);
+
+    Vec<DiskInfo> disks;
+
+    struct mntent     entryBuffer {};
+    Array<char, 4096> mountBuffer {};
+
+    while (getmntent_r(mtab, &entryBuffer, mountBuffer.data(), static_cast<i32>(mountBuffer.size())) != nullptr) {
+      const StringView filesystemName = entryBuffer.mnt_fsname;
+
+      // Skip virtual filesystems — real block devices start with '/'
+      if (!filesystemName.starts_with('/'))
+        continue;
+
+      struct statvfs stat;
+      if (statvfs(entryBuffer.mnt_dir, &stat) == -1)
+        continue;
+
+      const u64 totalBytes = static_cast<u64>(stat.f_blocks) * static_cast<u64>(stat.f_frsize);
+      const u64 freeBytes  = static_cast<u64>(stat.f_bfree) * static_cast<u64>(stat.f_frsize);
+
+      if (totalBytes == 0)
+        continue;
+
+      // The mount table has the complete option string, including filesystem-specific options.
+      Vec<String> mountOptions;
+
+      for (auto option : StringView(entryBuffer.mnt_opts) | std::views::split(','))
+        if (!option.empty())
+          mountOptions.emplace_back(option.begin(), option.end());
+
+      disks.push_back(DiskInfo {
+        .name          = String(entryBuffer.mnt_fsname),
+        .mountPoint    = String(entryBuffer.mnt_dir),
+        .filesystem    = String(entryBuffer.mnt_type),
+        .driveType     = String("Local"),
+        .totalBytes    = totalBytes,
+        .usedBytes     = totalBytes - freeBytes,
+        .totalInodes   = stat.f_files != 0 ? Option<u64>(stat.f_files) : None,
+        .usedInodes    = stat.f_files != 0 ? Option<u64>(stat.f_files - stat.f_ffree) : None,
+        .mountOptions  = std::move(mountOptions),
+        .isReadOnly    = (stat.f_flag & ST_RDONLY) != 0,
+        .isSystemDrive = (std::strcmp(entryBuffer.mnt_dir, "/") == 0),
+      });
+    }
+
+    return disks;
+  }
+
+  auto GetSystemDisk(CacheManager& /*cache*/) -> Result<DiskInfo> {
+    return os::unix_shared::GetDiskInfoAt("/");
+  }
+
+  auto GetDiskByPath(const String& path, CacheManager& /*cache*/) -> Result<DiskInfo> {
+    if (path.empty())
+      ERR(InvalidArgument, "Path cannot be empty");
+
+    return os::unix_shared::GetDiskInfoAt(path.c_str());
+  }
+
+  auto GetDiskHealth(const StringView device) -> Result<DiskHealth> {
+    if (device.empty())
+      ERR(InvalidArgument, "Device cannot be empty");
+
+    const fs::path path = device.starts_with('/') ? fs::path(device) : fs::path("/dev") / device;
+
+    const i32 filedesc = open(path.c_str(), O_RDONLY | O_NONBLOCK | O_CLOEXEC);
+
+    if (filedesc == -1) {
+      if (errno == EACCES || errno == EPERM)
+        ERR_FMT(PermissionRequired, "Opening {} requires root privileges", path.string());
+
+      ERR_FMT(NotFound, "Failed to open {}: {}", path.string(), std::strerror(errno));
+    }
+
+    Result<DiskHealth> health = path.filename().string().starts_with("nvme") ? ReadNvmeHealth(filedesc) : ReadAtaHealth(filedesc);
+
+    close(filedesc);
+
+    if (health)
+      health->device = path.string();
+
+    return health;
   }
 
-  auto GetDisks(CacheManager& /*cache*/) -> Result<Vec<DiskInfo>> {
+  auto GetNetworkMounts(CacheManager& /*cache*/) -> Result<Vec<NetworkMount>> {
     FILE* mtab = setmntent("/proc/mounts", "r");
     if (mtab == nullptr)
       ERR(IoError, "setmntent(\"/proc/mounts\") failed: could not open mount table");
 
     UniquePointer<FILE, decltype(&endmntent)> mountTable(mtab, &endmntent);
 
-    Vec<DiskInfo> disks;
+    Vec<NetworkMount> mounts;
 
     struct mntent     entryBuffer {};
     Array<char, 4096> mountBuffer {};
 
     while (getmntent_r(mtab, &entryBuffer, mountBuffer.data(), static_cast<i32>(mountBuffer.size())) != nullptr) {
-      const StringView filesystemName = entryBuffer.mnt_fsname;
+      Option<NetworkMount> mount = os::unix_shared::ParseNetworkMount(entryBuffer.mnt_fsname, entryBuffer.mnt_type, entryBuffer.mnt_dir);
 
-      // Skip virtual filesystems — real block devices start with '/'
-      if (!filesystemName.starts_with('/'))
+      if (!mount)
         continue;
 
-      struct statvfs stat;
-      if (statvfs(entryBuffer.mnt_dir, &stat) == -1)
+      // A FUSE mount whose daemon lost its connection fails fast with ENOTCONN. Kernel NFS/CIFS
+      // clients can instead block for minutes on a dead server, so those aren't probed.
+      if (StringView(entryBuffer.mnt_type).starts_with("fuse.")) {
+        // Other users' FUSE mounts fail with EACCES instead, which says nothing about the connection.
+        if (struct statvfs stat; statvfs(entryBuffer.mnt_dir, &stat) == 0)
+          mount->state = NetworkMount::State::Connected;
+        else if (errno == ENOTCONN)
+          mount->state = NetworkMount::State::Disconnected;
+      }
+
+      mounts.push_back(std::move(*mount));
+    }
+
+    return mounts;
+  }
+
+  auto GetDiskTemperatures(CacheManager& /*cache*/) -> Result<Vec<DiskTemperature>> {
+    Vec<DiskTemperature> temperatures;
+    std::error_code      errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/hwmon", errc)) {
+      Result<String> name = ReadSysFile(entry.path() / "name");
+
+      if (!name || (*name != "nvme" && *name != "drivetemp"))
         continue;
 
-      const u64 totalBytes = static_cast<u64>(stat.f_blocks) * static_cast<u64>(stat.f_frsize);
-      const u64 freeBytes  = static_cast<u64>(stat.f_bfree) * static_cast<u64>(stat.f_frsize);
+      // temp1 is the NVMe composite sensor, and the only sensor drivetemp registers.
+      Option<i64> milli = ReadSysFile(entry.path() / "temp1_input")
+                            .transform([](const String& value) -> Option<i64> { return TryParse<i64>(value); })
+                            .value_or(None);
 
-      if (totalBytes == 0)
+      if (!milli)
         continue;
 
-      disks.push_back(DiskInfo {
-        .name          = String(entryBuffer.mnt_fsname),
-        .mountPoint    = String(entryBuffer.mnt_dir),
-        .filesystem    = String(entryBuffer.mnt_type),
-        .driveType     = String("Local"),
-        .totalBytes    = totalBytes,
-        .usedBytes     = totalBytes - freeBytes,
-        .isSystemDrive = (std::strcmp(entryBuffer.mnt_dir, "/") == 0),
+      // The nvme hwmon device hangs off the controller (nvme0); drivetemp hangs off the SCSI
+      // device, whose block device (sda) is listed under it.
+      const fs::path device = fs::canonical(entry.path() / "device", errc);
+
+      if (errc)
+        continue;
+
+      String deviceName = device.filename().string();
+
+      if (*name == "drivetemp")
+        for (const fs::directory_entry& block : fs::directory_iterator(device / "block", errc)) {
+          deviceName = block.path().filename().string();
+          break;
+        }
+
+      temperatures.push_back({
+        .device  = std::move(deviceName),
+        .celsius = static_cast<f64>(*milli) / 1000.0,
       });
     }
 
-    return disks;
-  }
+    if (temperatures.empty())
+      ERR(NotFound, "No drive temperature sensors found in hwmon (is the drivetemp module loaded?)");
 
-  auto GetSystemDisk(CacheManager& /*cache*/) -> Result<DiskInfo> {
-    return os::unix_shared::GetDiskInfoAt("/");
+    return temperatures;
   }
 
-  auto GetDiskByPath(const String& path, CacheManager& /*cache*/) -> Result<DiskInfo> {
-    if (path.empty())
-      ERR(InvalidArgument, "Path cannot be empty");
+  auto GetDiskIOStats(CacheManager& /*cache*/) -> Result<Vec<DiskIOStats>> {
+    std::ifstream statsFile("/proc/diskstats");
 
-    return os::unix_shared::GetDiskInfoAt(path.c_str());
+    if (!statsFile.is_open())
+      ERR(NotFound, "Failed to open /proc/diskstats");
+
+    // /proc/diskstats always counts in 512-byte sectors, whatever the drive's real sector size.
+    constexpr u64 SECTOR_SIZE = 512;
+
+    Vec<DiskIOStats> stats;
+    String           line;
+
+    // "major minor name reads merged sectorsRead msReading writes merged sectorsWritten ..."
+    while (std::getline(statsFile, line)) {
+      std::istringstream iss(line);
+      u64                major = 0;
+      u64                minor = 0;
+      String             name;
+      Array<u64, 7>      fields {};
+
+      if (!(iss >> major >> minor >> name >> fields[0] >> fields[1] >> fields[2] >> fields[3] >> fields[4] >> fields[5] >> fields[6]))
+        continue;
+
+      // Partitions are listed too, but only whole disks have an entry in /sys/block.
+      if (name.starts_with("loop") || name.starts_with("ram") || !fs::exists(fs::path("/sys/block") / name))
+        continue;
+
+      stats.push_back({
+        .device     = std::move(name),
+        .readOps    = fields[0],
+        .writeOps   = fields[4],
+        .readBytes  = fields[2] * SECTOR_SIZE,
+        .writeBytes = fields[6] * SECTOR_SIZE,
+      });
+    }
+
+    if (stats.empty())
+      ERR(NotFound, "No disks found in /proc/diskstats");
+
+    return stats;
   }
 
   auto GetOutputs(CacheManager& /*cache*/) -> Result<Vec<DisplayInfo>> {
@@ -1095,6 +4645,61 @@ namespace draconis::core::system {
     ERR(NotFound, "No display server detected");
   }
 
+  auto GetBrightness(CacheManager& /*cache*/) -> Result<Vec<BacklightInfo>> {
+    const auto readLevel = [](const fs::path& path) -> Option<u64> {
+      return ReadSysFile(path).transform([](const String& value) -> Option<u64> { return TryParse<u64>(value); }).value_or(None);
+    };
+
+    Vec<BacklightInfo> backlights;
+    bool               hasDdcDriver = false;
+    std::error_code    errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/backlight", errc)) {
+      const String name = entry.path().filename().string();
+
+      // actual_brightness is what the hardware reports; brightness is only the last value requested.
+      const Option<u64> current = readLevel(entry.path() / "actual_brightness").or_else([&]() -> Option<u64> { return readLevel(entry.path() / "brightness"); });
+      const Option<u64> maximum = readLevel(entry.path() / "max_brightness");
+
+      if (!current || !maximum || *maximum == 0)
+        continue;
+
+      hasDdcDriver |= name.starts_with("ddcci");
+
+      backlights.push_back({ .device = name, .percent = std::min(100.0, static_cast<f64>(*current) * 100.0 / static_cast<f64>(*maximum)) });
+    }
+
+    // ddcci-driver-linux already exposes external monitors as backlights, and talking to them directly would race it.
+    if (!hasDdcDriver)
+      for (const fs::directory_entry& connector : fs::directory_iterator("/sys/class/drm", errc)) {
+        // Connectors are named card<N>-<type>-<index>; built-in panels are covered by their backlight.
+        const String name  = connector.path().filename().string();
+        const usize  dash  = name.find('-');
+        const String label = dash == String::npos ? "" : name.substr(dash + 1);
+
+        if (label.empty() || label.starts_with("eDP") || label.starts_with("LVDS") || label.starts_with("DSI"))
+          continue;
+
+        if (ReadSysFile(connector.path() / "status").value_or("") != "connected")
+          continue;
+
+        const fs::path ddcBus = fs::read_symlink(connector.path() / "ddc", errc);
+
+        if (errc)
+          continue;
+
+        if (const Option<f64> percent = ReadDdcBrightness(fs::path("/dev") / ddcBus.filename()))
+          backlights.push_back({ .device = label, .percent = *percent });
+      }
+
+    if (backlights.empty())
+      ERR(NotFound, "No display exposes its brightness");
+
+    std::ranges::sort(backlights, {}, &BacklightInfo::device);
+
+    return backlights;
+  }
+
   auto GetNetworkInterfaces(CacheManager& cache) -> Result<Vec<NetworkInterface>> {
     return cache.getOrSet<Vec<NetworkInterface>>("linux_network_interfaces", []() -> Result<Vec<NetworkInterface>> {
       Map<String, NetworkInterface> interfaceMap = TRY(CollectNetworkInterfaces());
@@ -1108,6 +4713,50 @@ namespace draconis::core::system {
     });
   }
 
+  auto GetNetworkStats(CacheManager& /*cache*/) -> Result<Vec<InterfaceStats>> {
+    std::ifstream devFile("/proc/net/dev");
+
+    if (!devFile.is_open())
+      ERR(NotFound, "Failed to open /proc/net/dev");
+
+    Vec<InterfaceStats> stats;
+    String              line;
+
+    // Two header lines, then "iface: rx{bytes packets errs drop fifo frame compressed multicast} tx{bytes packets errs drop fifo colls carrier compressed}"
+    std::getline(devFile, line);
+    std::getline(devFile, line);
+
+    while (std::getline(devFile, line)) {
+      const usize colonPos = line.find(':');
+
+      if (colonPos == String::npos)
+        continue;
+
+      std::istringstream iss(line.substr(colonPos + 1));
+      Array<u64, 12>     fields {};
+
+      if (!(iss >> fields[0] >> fields[1] >> fields[2] >> fields[3] >> fields[4] >> fields[5] >> fields[6] >> fields[7] >> fields[8] >> fields[9] >> fields[10] >> fields[11]))
+        continue;
+
+      stats.push_back({
+        .name      = String(draconis::os::unix_shared::TrimConfigLine(StringView(line).substr(0, colonPos))),
+        .rxBytes   = fields[0],
+        .txBytes   = fields[8],
+        .rxPackets = fields[1],
+        .txPackets = fields[9],
+        .rxErrors  = fields[2],
+        .txErrors  = fields[10],
+        .rxDropped = fields[3],
+        .txDropped = fields[11],
+      });
+    }
+
+    if (stats.empty())
+      ERR(NotFound, "No interfaces found in /proc/net/dev");
+
+    return stats;
+  }
+
   auto GetPrimaryNetworkInterface(CacheManager& cache) -> Result<NetworkInterface> {
     return cache.getOrSet<NetworkInterface>("linux_primary_network_interface", []() -> Result<NetworkInterface> {
       // Gather full interface list first
@@ -1153,10 +4802,109 @@ namespace draconis::core::system {
     });
   }
 
-  auto GetBatteryInfo(CacheManager& /*cache*/) -> Result<Battery> {
-    using matchit::match, matchit::is, matchit::_;
-    using enum Battery::Status;
+  auto GetDefaultGateway(CacheManager& /*cache*/) -> Result<Gateway> {
+    // Columns: Iface Destination Gateway Flags RefCnt Use Metric Mask MTU Window IRTT
+    if (std::ifstream routeFile("/proc/net/route"); routeFile.is_open()) {
+      String line;
+      std::getline(routeFile, line); // skip header
+
+      Option<Gateway> best;
+      u32             bestMetric = 0;
+
+      while (std::getline(routeFile, line)) {
+        std::istringstream iss(line);
+        String             iface, dest, gateway, flags;
+        u32                refcnt = 0, use = 0, metric = 0;
+
+        if (!(iss >> iface >> dest >> gateway >> flags >> refcnt >> use >> metric) || dest != "00000000")
+          continue;
+
+        Option<u32> gatewayAddr = TryParse<u32>(gateway, 16);
+        Option<u32> routeFlags  = TryParse<u32>(flags, 16);
+
+        if (!gatewayAddr || !routeFlags || (*routeFlags & RTF_UP) == 0 || (*routeFlags & RTF_GATEWAY) == 0)
+          continue;
+
+        if (best && metric >= bestMetric)
+          continue;
+
+        // The kernel prints the address as the raw (network-order) 32-bit value, so it can be used as s_addr directly.
+        in_addr                      addr { .s_addr = *gatewayAddr };
+        Array<char, INET_ADDRSTRLEN> buf {};
+
+        if (inet_ntop(AF_INET, &addr, buf.data(), buf.size()) == nullptr)
+          continue;
+
+        best       = Gateway { .address = buf.data(), .interfaceName = iface };
+        bestMetric = metric;
+      }
+
+      if (best)
+        return *best;
+    }
+
+    // Columns: Destination DestPrefix Source SourcePrefix NextHop Metric RefCnt Use Flags Iface
+    if (std::ifstream routeFile("/proc/net/ipv6_route"); routeFile.is_open()) {
+      String line;
+
+      Option<Gateway> best;
+      u32             bestMetric = 0;
+
+      while (std::getline(routeFile, line)) {
+        std::istringstream iss(line);
+        String             dest, destPrefix, src, srcPrefix, nextHop, metricHex, refcnt, use, flags, iface;
+
+        if (!(iss >> dest >> destPrefix >> src >> srcPrefix >> nextHop >> metricHex >> refcnt >> use >> flags >> iface))
+          continue;
+
+        if (dest != String(32, '0') || destPrefix != "00" || nextHop == String(32, '0') || nextHop.size() != 32 || iface == "lo")
+          continue;
+
+        Option<u32> metric = TryParse<u32>(metricHex, 16);
+
+        if (!metric || (best && *metric >= bestMetric))
+          continue;
+
+        in6_addr addr {};
+        bool     valid = true;
+
+        for (usize i = 0; i < 16 && valid; ++i)
+          if (Option<u8> byte = TryParse<u8>(StringView(nextHop).substr(i * 2, 2), 16))
+            addr.s6_addr[i] = *byte;
+          else
+            valid = false;
 
+        Array<char, INET6_ADDRSTRLEN> buf {};
+
+        if (!valid || inet_ntop(AF_INET6, &addr, buf.data(), buf.size()) == nullptr)
+          continue;
+
+        best       = Gateway { .address = buf.data(), .interfaceName = iface };
+        bestMetric = *metric;
+      }
+
+      if (best)
+        return *best;
+    }
+
+    ERR(NotFound, "No default route found in /proc/net/route or /proc/net/ipv6_route");
+  }
+
+  auto GetDnsServers(CacheManager& /*cache*/) -> Result<Vec<String>> {
+    Vec<String> servers = TRY(draconis::os::unix_shared::ReadResolvConfNameservers("/etc/resolv.conf"));
+
+    // With systemd-resolved, resolv.conf usually only points at its local stub listener; the real upstream servers live in a separate file.
+    if (servers.size() == 1 && servers.front() == "127.0.0.53")
+      if (Result<Vec<String>> upstream = draconis::os::unix_shared::ReadResolvConfNameservers("/run/systemd/resolve/resolv.conf"); upstream && !upstream->empty())
+        servers = *std::move(upstream);
+
+    if (servers.empty())
+      ERR(NotFound, "No nameserver entries found in /etc/resolv.conf");
+
+    return servers;
+  }
+
+  auto GetBatteryInfo(CacheManager& /*cache*/) -> Result<Battery> {
     PCStr powerSupplyPath = "/sys/class/power_supply";
 
     if (!fs::exists(powerSupplyPath))
@@ -1173,45 +4921,243 @@ namespace draconis::core::system {
     if (batteryPath.empty())
       ERR(NotFound, "No battery found in power supply directory");
 
-    // Read battery percentage
-    Option<u8> percentage =
-      ReadSysFile(batteryPath / "capacity")
-        .transform([](const String& capacityStr) -> Option<u8> {
-          return TryParse<u8>(capacityStr);
-        })
-        .value_or(None);
+    return ReadBattery(batteryPath);
+  }
 
-    // Read battery status
-    Battery::Status status =
-      ReadSysFile(batteryPath / "status")
-        .transform([percentage](const String& statusStr) -> Battery::Status {
-          return match(statusStr)(
-            is | "Charging"     = Charging,
-            is | "Discharging"  = Discharging,
-            is | "Full"         = Full,
-            is | "Not charging" = (percentage && *percentage == 100 ? Full : Discharging),
-            is | _              = Unknown
-          );
-        })
-        .value_or(Unknown);
+  auto GetBatteries(CacheManager& /*cache*/) -> Result<Vec<Battery>> {
+    Vec<Battery>    batteries;
+    std::error_code errc;
 
-    if (status != Charging && status != Discharging)
-      return Battery(status, percentage, None);
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/power_supply", errc))
+      if (ReadSysFile(entry.path() / "type").value_or("") == "Battery")
+        batteries.push_back(ReadBattery(entry.path()));
 
-    return Battery(
-      status,
-      percentage,
-      ReadSysFile(
-        batteryPath / std::format("/time_to_{}now", status == Discharging ? "empty" : "full")
-      )
-        .transform([](const String& timeStr) -> Option<std::chrono::seconds> {
-          if (Option<i32> timeMinutes = TryParse<i32>(timeStr); timeMinutes && *timeMinutes > 0)
-            return std::chrono::minutes(*timeMinutes);
+    if (batteries.empty())
+      ERR(NotFound, "No battery found in power supply directory");
 
-          return None;
-        })
-        .value_or(None)
-    );
+    // System batteries first, in BAT0, BAT1 order.
+    std::ranges::sort(batteries, [](const Battery& lhs, const Battery& rhs) {
+      return std::tie(lhs.isPeripheral, lhs.name) < std::tie(rhs.isPeripheral, rhs.name);
+    });
+
+    return batteries;
+  }
+
+  auto GetPowerSource(CacheManager& /*cache*/) -> Result<PowerSource> {
+    PowerSource     source;
+    bool            found = false;
+    std::error_code errc;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/class/power_supply", errc)) {
+      const String type = ReadSysFile(entry.path() / "type").value_or("");
+
+      if (type != "Mains" && type != "USB")
+        continue;
+
+      found = true;
+
+      if (ReadSysFile(entry.path() / "online").value_or("0") != "1")
+        continue;
+
+      source.acConnected = true;
+
+      const Option<f64> watts = ReadSupplyWatts(entry.path());
+
+      if (!watts)
+        continue;
+
+      // The active mode is bracketed, e.g. "C [PD] PD_PPS".
+      if (ReadSysFile(entry.path() / "usb_type").value_or("").contains("[PD"))
+        source.usbPdWatts = std::max(source.usbPdWatts.value_or(0.0), *watts);
+
+      source.chargerWatts = std::max(source.chargerWatts.value_or(0.0), *watts);
+    }
+
+    if (!found)
+      ERR(NotFound, "No external power supply found in power supply directory");
+
+    return source;
+  }
+
+  auto GetPowerProfile(CacheManager& /*cache*/) -> Result<PowerProfile> {
+    Option<String> name;
+
+  #if DRAC_USE_DBUS
+    if (Result<dbus::Connection> connection = dbus::Connection::system()) {
+      // power-profiles-daemon moved under UPower's name in 0.20, keeping the old one as an alias for a while.
+      constexpr Array<Pair<PCStr, PCStr>, 2> daemons {{
+        { "org.freedesktop.UPower.PowerProfiles", "/org/freedesktop/UPower/PowerProfiles" },
+        {             "net.hadess.PowerProfiles",             "/net/hadess/PowerProfiles" },
+      }};
+
+      for (const auto& [service, path] : daemons)
+        if (Result<dbus::Value> value = connection->getProperty(service, path, service, "ActiveProfile")) {
+          if (const String* profile = std::get_if<String>(&*value))
+            name = *profile;
+
+          break;
+        }
+    }
+  #endif
+
+    // Without the daemon, the firmware profile is what it would have set anyway.
+    if (!name)
+      if (Result<String> profile = ReadSysFile("/sys/firmware/acpi/platform_profile"))
+        name = std::move(*profile);
+
+    Option<String> governor;
+
+    if (Result<String> scalingGovernor = ReadSysFile("/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor"))
+      governor = std::move(*scalingGovernor);
+
+    if (!name && !governor)
+      ERR(NotFound, "No power profile or cpufreq governor is available");
+
+    return PowerProfile {
+      .mode     = name ? PowerModeFromName(*name) : PowerProfile::Mode::Unknown,
+      .name     = std::move(name),
+      .governor = std::move(governor),
+    };
+  }
+
+  auto GetHardwareFingerprint() -> Result<String> {
+    String fingerprint;
+
+    for (const PCStr file : { "sys_vendor", "product_name", "board_name" })
+      if (Result<String> value = ReadSysFile(fs::path("/sys/class/dmi/id") / file))
+        fingerprint += std::format("{};", *value);
+
+    // Vendor, family and model. The feature flags that follow depend on the kernel, so they're left out.
+    if (Result<String> modalias = ReadSysFile("/sys/devices/system/cpu/modalias"))
+      fingerprint += std::format("{};", StringView(*modalias).substr(0, modalias->find(":feature:")));
+
+    std::error_code error;
+    Vec<String>     gpus;
+
+    for (const fs::directory_entry& entry : fs::directory_iterator("/sys/bus/pci/devices", error)) {
+      if (Result<String> classId = ReadSysFile(entry.path() / "class"); !classId || !classId->starts_with("0x03"))
+        continue;
+
+      Result<String> vendorId = ReadSysFile(entry.path() / "vendor");
+      Result<String> deviceId = ReadSysFile(entry.path() / "device");
+
+      if (vendorId && deviceId)
+        gpus.push_back(std::format("{}:{}", *vendorId, *deviceId));
+    }
+
+    // Directory order isn't guaranteed, and the fingerprint must be stable.
+    std::ranges::sort(gpus);
+
+    for (const String& gpu : gpus)
+      fingerprint += std::format("{};", gpu);
+
+    if (fingerprint.empty())
+      ERR(NotFound, "No hardware identifiers found in /sys");
+
+    return fingerprint;
+  }
+
+  struct SystemWatcher::Impl {
+    i32 ueventSocket = -1;
+    i32 routeSocket  = -1;
+    i32 wakeEvent    = -1;
+
+    Map<String, bool> linkUp;
+
+    Impl() = default;
+
+    Impl(const Impl&)                    = delete;
+    Impl(Impl&&)                         = delete;
+    auto operator=(const Impl&) -> Impl& = delete;
+    auto operator=(Impl&&) -> Impl&      = delete;
+
+    ~Impl() {
+      if (ueventSocket >= 0)
+        close(ueventSocket);
+
+      if (routeSocket >= 0)
+        close(routeSocket);
+
+      if (wakeEvent >= 0)
+        close(wakeEvent);
+    }
+  };
+
+  SystemWatcher::SystemWatcher(UniquePointer<Impl> impl) : m_impl(std::move(impl)) {}
+  SystemWatcher::SystemWatcher(SystemWatcher&&) noexcept                    = default;
+  auto SystemWatcher::operator=(SystemWatcher&&) noexcept -> SystemWatcher& = default;
+  SystemWatcher::~SystemWatcher()                                           = default;
+
+  auto SystemWatcher::Create() -> Result<SystemWatcher> {
+    auto impl = std::make_unique<Impl>();
+
+    // Group 1 carries the kernel's own uevents; udev rebroadcasts processed ones on group 2.
+    impl->ueventSocket = TRY(OpenNetlinkSocket(NETLINK_KOBJECT_UEVENT, 1));
+    impl->routeSocket  = TRY(OpenNetlinkSocket(NETLINK_ROUTE, RTMGRP_LINK));
+    impl->wakeEvent    = eventfd(0, EFD_CLOEXEC | EFD_NONBLOCK);
+
+    if (impl->wakeEvent < 0)
+      ERR_FMT(ResourceExhausted, "eventfd() failed: {}", strerror(errno));
+
+    // Seed the link states, so the first message about an interface that's already up isn't reported as it coming up.
+    if (draconis::os::unix_shared::IfAddrsGuard addresses; addresses.init())
+      for (const ifaddrs* ifa = addresses.get(); ifa != nullptr; ifa = ifa->ifa_next)
+        if (ifa->ifa_name != nullptr && !draconis::os::unix_shared::IsLoopback(ifa->ifa_flags))
+          impl->linkUp[ifa->ifa_name] = (ifa->ifa_flags & IFF_UP) != 0 && (ifa->ifa_flags & IFF_RUNNING) != 0;
+
+    return SystemWatcher(std::move(impl));
+  }
+
+  auto SystemWatcher::wait(const std::chrono::milliseconds timeout) -> Result<Vec<SystemEvent>> {
+    using std::chrono::steady_clock;
+
+    const steady_clock::time_point deadline = steady_clock::now() + timeout;
+
+    Vec<SystemEvent> events;
+    Array<u8, 16384> buffer {};
+    Array<pollfd, 3> fds {{
+      { .fd = m_impl->ueventSocket, .events = POLLIN, .revents = 0 },
+      {  .fd = m_impl->routeSocket, .events = POLLIN, .revents = 0 },
+      {    .fd = m_impl->wakeEvent, .events = POLLIN, .revents = 0 },
+    }};
+
+    // Most uevents are for devices we don't report, so keep waiting until one we do arrives.
+    while (events.empty()) {
+      const auto remaining = std::chrono::duration_cast<std::chrono::milliseconds>(deadline - steady_clock::now());
+
+      if (remaining.count() <= 0)
+        break;
+
+      const i32 ready = poll(fds.data(), fds.size(), static_cast<i32>(std::min<i64>(remaining.count(), std::numeric_limits<i32>::max())));
+
+      if (ready < 0) {
+        if (errno == EINTR)
+          continue;
+
+        ERR_FMT(InternalError, "poll() on netlink sockets failed: {}", strerror(errno));
+      }
+
+      if (ready == 0)
+        break;
+
+      ssize_t length = 0;
+
+      while ((length = recv(m_impl->ueventSocket, buffer.data(), buffer.size(), 0)) > 0)
+        if (Option<SystemEvent> event = ParseUevent(StringView(reinterpret_cast<PCStr>(buffer.data()), static_cast<usize>(length)))) // NOLINT(cppcoreguidelines-pro-type-reinterpret-cast)
+          events.push_back(std::move(*event));
+
+      while ((length = recv(m_impl->routeSocket, buffer.data(), buffer.size(), 0)) > 0)
+        ParseLinkMessages(Span<const u8>(buffer.data(), static_cast<usize>(length)), m_impl->linkUp, events);
+
+      if (eventfd_t count = 0; (fds[2].revents & POLLIN) != 0 && eventfd_read(m_impl->wakeEvent, &count) == 0)
+        break;
+    }
+
+    return events;
+  }
+
+  auto SystemWatcher::interrupt() -> Unit {
+    eventfd_write(m_impl->wakeEvent, 1);
   }
 } // namespace draconis::core::system
 
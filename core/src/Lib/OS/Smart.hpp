@@ -0,0 +1,173 @@
+/**
+ * @file Smart.hpp
+ * @brief Shared decoding of SMART data for the Linux and Windows backends.
+ *
+ * @details Both platforms hand back the raw data structures defined by the ATA and NVMe
+ * specifications, so only the transport (SG_IO / NVMe admin ioctls on Linux, storage IOCTLs
+ * on Windows) is platform-specific. This header decodes:
+ * - The 512-byte ATA SMART READ DATA and READ THRESHOLDS pages
+ * - The 512-byte NVMe SMART / Health Information log page (log identifier 02h)
+ */
+
+#pragma once
+
+#include <algorithm>
+#include <utility>
+
+#include <Drac++/Utils/DataTypes.hpp>
+#include <Drac++/Utils/Types.hpp>
+
+namespace draconis::os::smart {
+  namespace types = ::draconis::utils::types;
+
+  inline constexpr types::usize SMART_PAGE_SIZE = 512; ///< Size of every page decoded here.
+
+  // ATA SMART attribute table: 30 entries of 12 bytes, starting at offset 2 of the page.
+  inline constexpr types::usize ATA_ATTRIBUTE_OFFSET = 2;
+  inline constexpr types::usize ATA_ATTRIBUTE_SIZE   = 12;
+  inline constexpr types::usize ATA_ATTRIBUTE_COUNT  = 30;
+
+  /**
+   * @brief Gets the conventional (smartmontools) name of an ATA SMART attribute.
+   * @param id The attribute ID.
+   * @return The name, or "Unknown_Attribute" for IDs without a well-established meaning.
+   */
+  [[nodiscard]] constexpr auto AttributeName(const types::u8 id) -> types::StringView {
+    // clang-format off
+    constexpr types::Array<types::Pair<types::u8, types::StringView>, 26> names {{
+      {   1, "Raw_Read_Error_Rate"         },
+      {   3, "Spin_Up_Time"                },
+      {   4, "Start_Stop_Count"            },
+      {   5, "Reallocated_Sector_Ct"       },
+      {   7, "Seek_Error_Rate"             },
+      {   9, "Power_On_Hours"              },
+      {  10, "Spin_Retry_Count"            },
+      {  12, "Power_Cycle_Count"           },
+      { 177, "Wear_Leveling_Count"         },
+      { 187, "Reported_Uncorrect"          },
+      { 188, "Command_Timeout"             },
+      { 190, "Airflow_Temperature_Cel"     },
+      { 192, "Power-Off_Retract_Count"     },
+      { 193, "Load_Cycle_Count"            },
+      { 194, "Temperature_Celsius"         },
+      { 195, "Hardware_ECC_Recovered"      },
+      { 196, "Reallocated_Event_Count"     },
+      { 197, "Current_Pending_Sector"      },
+      { 198, "Offline_Uncorrectable"       },
+      { 199, "UDMA_CRC_Error_Count"        },
+      { 200, "Multi_Zone_Error_Rate"       },
+      { 231, "SSD_Life_Left"               },
+      { 233, "Media_Wearout_Indicator"     },
+      { 241, "Total_LBAs_Written"          },
+      { 242, "Total_LBAs_Read"             },
+      { 243, "Total_LBAs_Written_Expanded" },
+    }};
+    // clang-format on
+
+    for (const auto& [attributeId, name] : names)
+      if (attributeId == id)
+        return name;
+
+    return "Unknown_Attribute";
+  }
+
+  /**
+   * @brief Reads a little-endian unsigned integer of up to 8 bytes.
+   */
+  [[nodiscard]] constexpr auto ReadLittleEndian(const types::Span<const types::u8> bytes) -> types::u64 {
+    types::u64 value = 0;
+
+    for (types::usize i = std::min<types::usize>(bytes.size(), 8); i > 0; --i)
+      value = (value << 8) | bytes[i - 1];
+
+    return value;
+  }
+
+  /**
+   * @brief Decodes the ATA SMART attribute table into a DiskHealth.
+   * @param data The SMART READ DATA page.
+   * @param thresholds The SMART READ THRESHOLDS page, if it could be read.
+   * @return The attributes, with the power-on hours, reallocated sector count and temperature filled in.
+   *         The status is derived from the thresholds, and left Unknown without them.
+   */
+  [[nodiscard]] inline auto ParseAtaSmartData(const types::Span<const types::u8> data, const types::Option<types::Span<const types::u8>> thresholds) -> types::DiskHealth {
+    types::DiskHealth health {};
+    health.status = types::DiskHealth::Status::Unknown;
+
+    if (data.size() < SMART_PAGE_SIZE)
+      return health;
+
+    bool belowThreshold = false;
+
+    for (types::usize idx = 0; idx < ATA_ATTRIBUTE_COUNT; ++idx) {
+      const types::Span<const types::u8> entry = data.subspan(ATA_ATTRIBUTE_OFFSET + (idx * ATA_ATTRIBUTE_SIZE), ATA_ATTRIBUTE_SIZE);
+
+      // Unused slots have an ID of 0.
+      if (entry[0] == 0)
+        continue;
+
+      types::SmartAttribute attribute {
+        .id        = entry[0],
+        .name      = types::String(AttributeName(entry[0])),
+        .value     = entry[3],
+        .worst     = entry[4],
+        .threshold = types::None,
+        .raw       = ReadLittleEndian(entry.subspan(5, 6)),
+      };
+
+      // The threshold page uses the same layout, with the threshold in place of the current value.
+      if (thresholds && thresholds->size() >= SMART_PAGE_SIZE) {
+        const types::Span<const types::u8> thresholdEntry = thresholds->subspan(ATA_ATTRIBUTE_OFFSET + (idx * ATA_ATTRIBUTE_SIZE), ATA_ATTRIBUTE_SIZE);
+
+        if (thresholdEntry[0] == attribute.id) {
+          attribute.threshold = thresholdEntry[1];
+          belowThreshold |= thresholdEntry[1] != 0 && attribute.value <= thresholdEntry[1];
+        }
+      }
+
+      // Vendors pack extra data into the upper raw bytes of these two; the count itself is the low 32 bits.
+      if (attribute.id == 9)
+        health.powerOnHours = attribute.raw & 0xFFFFFFFF;
+      else if (attribute.id == 5)
+        health.reallocatedSectors = attribute.raw & 0xFFFFFFFF;
+
+      // The current temperature is the lowest raw byte; the others often hold the min/max seen.
+      // Attribute 190 is the airflow temperature, which some drives report instead of 194.
+      if (attribute.id == 194 || (attribute.id == 190 && !health.temperatureCelsius))
+        if (const types::u64 celsius = attribute.raw & 0xFF; celsius != 0)
+          health.temperatureCelsius = static_cast<types::f64>(celsius);
+
+      health.attributes.push_back(std::move(attribute));
+    }
+
+    if (thresholds)
+      health.status = belowThreshold ? types::DiskHealth::Status::Failing : types::DiskHealth::Status::Passed;
+
+    return health;
+  }
+
+  /**
+   * @brief Decodes the NVMe SMART / Health Information log page into a DiskHealth.
+   * @param log The log page.
+   * @return The status, power-on hours and composite temperature; NVMe has no attribute table or reallocated sector count.
+   */
+  [[nodiscard]] inline auto ParseNvmeHealthLog(const types::Span<const types::u8> log) -> types::DiskHealth {
+    types::DiskHealth health {};
+    health.status = types::DiskHealth::Status::Unknown;
+
+    if (log.size() < SMART_PAGE_SIZE)
+      return health;
+
+    // Byte 0 is the critical warning bitmap (spare below threshold, temperature, reliability, read-only, ...).
+    health.status = log[0] == 0 ? types::DiskHealth::Status::Passed : types::DiskHealth::Status::Failing;
+
+    // Bytes 1-2 are the composite temperature in Kelvin, with 0 meaning it isn't reported.
+    if (const types::u64 kelvin = ReadLittleEndian(log.subspan(1, 2)); kelvin != 0)
+      health.temperatureCelsius = static_cast<types::f64>(kelvin) - 273.15;
+
+    // Power-on hours is a 128-bit counter at offset 128; the low 64 bits are plenty.
+    health.powerOnHours = ReadLittleEndian(log.subspan(128, 8));
+
+    return health;
+  }
+} // namespace draconis::os::smart
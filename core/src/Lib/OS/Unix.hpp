@@ -9,20 +9,41 @@
  * Key features:
  * - Disk usage via statvfs
  * - Kernel version via uname
+ * - Hostname and FQDN via gethostname and getaddrinfo
  * - Network interface enumeration via getifaddrs
  * - Environment variable utilities
+ * - User name lookup via getpwuid_r
+ * - Login session enumeration via utmpx
+ * - Locale and timezone lookup via the environment and /etc/localtime
+ * - Terminal emulator and font detection via the environment, process tree, and config files
+ * - Display server detection via XDG_SESSION_TYPE
+ * - Display connector, pixel density and DRM EDID helpers for the X11 and Wayland backends
+ * - Widget, icon, and cursor theme detection via KDE and GTK config files
+ * - Default editor, browser, and terminal detection via the environment and freedesktop config files
+ * - DNS server lookup via resolv.conf
+ * - Network mount detection from mount table entries
  */
 
 #pragma once
 
 #if !defined(_WIN32)
 
+  #include <algorithm>
   #include <cerrno>
   #include <chrono>
+  #include <cstdlib>
   #include <cstring>
+  #include <ctime>
+  #include <filesystem>
   #include <format>
+  #include <fstream>
+  #include <iterator>
+  #include <netdb.h>
+  #include <pwd.h>
+  #include <ranges>
   #include <sys/statvfs.h>
   #include <sys/utsname.h>
+  #include <unistd.h>
   #include <utility>
 
   #if defined(__linux__)
@@ -30,6 +51,7 @@
   #endif
 
   #include <Drac++/Utils/DataTypes.hpp>
+  #include <Drac++/Utils/Env.hpp>
   #include <Drac++/Utils/Error.hpp>
   #include <Drac++/Utils/Types.hpp>
 
@@ -44,6 +66,13 @@
     #define DRAC_HAS_IFADDRS 0
   #endif
 
+  #if __has_include(<utmpx.h>)
+    #define DRAC_HAS_UTMPX 1
+    #include <utmpx.h>
+  #else
+    #define DRAC_HAS_UTMPX 0
+  #endif
+
 namespace draconis::os::unix_shared {
   namespace types = ::draconis::utils::types;
   namespace error = ::draconis::utils::error;
@@ -79,6 +108,36 @@ namespace draconis::os::unix_shared {
     return GetDiskUsageAt("/");
   }
 
+  /**
+   * @brief Converts statvfs flags into fstab-style mount options.
+   * @param flags The `f_flag` field of a statvfs result.
+   * @return "ro" or "rw", followed by any other options the flags expose.
+   */
+  [[nodiscard]] inline auto MountOptionsFromFlags(const unsigned long flags) -> types::Vec<types::String> {
+    types::Vec<types::String> options;
+
+    options.emplace_back((flags & ST_RDONLY) != 0 ? "ro" : "rw");
+
+    if ((flags & ST_NOSUID) != 0)
+      options.emplace_back("nosuid");
+
+  #if defined(__linux__)
+    // GNU extensions; other platforms only define the two POSIX flags.
+    if ((flags & ST_NODEV) != 0)
+      options.emplace_back("nodev");
+    if ((flags & ST_NOEXEC) != 0)
+      options.emplace_back("noexec");
+    if ((flags & ST_SYNCHRONOUS) != 0)
+      options.emplace_back("sync");
+    if ((flags & ST_NOATIME) != 0)
+      options.emplace_back("noatime");
+    if ((flags & ST_RELATIME) != 0)
+      options.emplace_back("relatime");
+  #endif
+
+    return options;
+  }
+
   /**
    * @brief Gets detailed disk information for a given path.
    * @param path The filesystem path to query.
@@ -103,6 +162,10 @@ namespace draconis::os::unix_shared {
       .driveType     = types::String { "Unknown" }, // Drive type requires platform-specific code
       .totalBytes    = totalBytes,
       .usedBytes     = totalBytes - freeBytes,
+      .totalInodes   = stat.f_files != 0 ? types::Option<types::u64>(stat.f_files) : types::None,
+      .usedInodes    = stat.f_files != 0 ? types::Option<types::u64>(stat.f_files - stat.f_ffree) : types::None,
+      .mountOptions  = MountOptionsFromFlags(stat.f_flag),
+      .isReadOnly    = (stat.f_flag & ST_RDONLY) != 0,
       .isSystemDrive = (std::strcmp(path, "/") == 0),
     };
   }
@@ -158,6 +221,52 @@ namespace draconis::os::unix_shared {
     return types::String(uts.machine);
   }
 
+  /**
+   * @brief Gets the machine's hostname via gethostname.
+   * @return Hostname as configured (e.g., "archlinux", "MacBook-Pro.local"), or an error.
+   */
+  [[nodiscard]] inline auto GetHostname() -> types::Result<types::String> {
+    // POSIX caps hostnames at 255 bytes; gethostname may not terminate a truncated one.
+    types::Array<char, 256> buffer {};
+
+    if (gethostname(buffer.data(), buffer.size() - 1) != 0)
+      return types::Err(error::DracError(InternalError, std::format("gethostname() failed: {} (errno {})", std::strerror(errno), errno)));
+
+    if (buffer.front() == '\0')
+      return types::Err(error::DracError(NotFound, "gethostname() returned an empty hostname"));
+
+    return types::String(buffer.data());
+  }
+
+  /**
+   * @brief Gets the machine's fully qualified domain name, like `hostname -f`.
+   * @return The canonical name the resolver returns for the hostname (e.g., "build01.corp.example.com"), or an error.
+   *
+   * @details The lookup goes through the system resolver, so it honours /etc/hosts, and may block on DNS.
+   */
+  [[nodiscard]] inline auto GetFQDN() -> types::Result<types::String> {
+    types::Result<types::String> hostname = GetHostname();
+
+    if (!hostname)
+      return types::Err(hostname.error());
+
+    // The field order of addrinfo differs between glibc and the BSDs, so it can't use designated initializers.
+    addrinfo hints {};
+    hints.ai_flags  = AI_CANONNAME;
+    hints.ai_family = AF_UNSPEC;
+
+    addrinfo* result = nullptr;
+
+    if (const types::i32 status = getaddrinfo(hostname->c_str(), nullptr, &hints, &result); status != 0)
+      return types::Err(error::DracError(NotFound, std::format("getaddrinfo() failed to resolve '{}': {}", *hostname, gai_strerror(status))));
+
+    types::String fqdn = result->ai_canonname ? types::String(result->ai_canonname) : *hostname;
+
+    freeaddrinfo(result);
+
+    return fqdn;
+  }
+
   /**
    * @brief Gets full uname information as a struct.
    * @return Struct containing sysname, release, version, and machine.
@@ -299,7 +408,951 @@ namespace draconis::os::unix_shared {
   }
   #endif // DRAC_HAS_IFADDRS
 
+  /**
+   * @brief Resolves a user ID to its login name via getpwuid_r.
+   * @param uid The user ID to look up.
+   * @return The login name, or None if the user has no passwd entry.
+   */
+  [[nodiscard]] inline auto GetUserNameFromUid(const uid_t uid) -> types::Option<types::String> {
+    struct passwd  pwd {};
+    struct passwd* result = nullptr;
+
+    types::Array<char, 1024> buffer {};
+
+    if (getpwuid_r(uid, &pwd, buffer.data(), buffer.size(), &result) != 0 || result == nullptr || result->pw_name == nullptr)
+      return types::None;
+
+    return types::String(result->pw_name);
+  }
+
+  #if DRAC_HAS_UTMPX
+  /**
+   * @brief Gets the logged-in user sessions from the utmpx database.
+   * @return List of sessions, or an error.
+   *
+   * @details Only `USER_PROCESS` records are reported. The session type is inferred from
+   * the record: a remote host means SSH/telnet, a line or host starting with ':' means an
+   * X11/Wayland display, and anything else is a terminal.
+   */
+  [[nodiscard]] inline auto GetSessions() -> types::Result<types::Vec<types::SessionInfo>> {
+    using types::SessionInfo;
+
+    types::Vec<SessionInfo> sessions;
+
+    // utmpx keeps a global cursor, so always start from the beginning and close afterwards.
+    setutxent();
+
+    while (const utmpx* entry = getutxent()) {
+      if (entry->ut_type != USER_PROCESS)
+        continue;
+
+      // The utmpx fields are fixed-size and not guaranteed to be null-terminated.
+      const types::String username(entry->ut_user, strnlen(entry->ut_user, sizeof(entry->ut_user)));
+      const types::String line(entry->ut_line, strnlen(entry->ut_line, sizeof(entry->ut_line)));
+      const types::String host(entry->ut_host, strnlen(entry->ut_host, sizeof(entry->ut_host)));
+
+      if (username.empty())
+        continue;
+
+      SessionInfo::Type type = SessionInfo::Type::Terminal;
+
+      if (line.starts_with(':') || host.starts_with(':'))
+        type = SessionInfo::Type::Graphical;
+      else if (!host.empty())
+        type = SessionInfo::Type::Remote;
+
+      sessions.push_back({
+        .type       = type,
+        .username   = username,
+        .terminal   = line,
+        .remoteHost = type == SessionInfo::Type::Remote ? types::Option<types::String>(host) : types::None,
+        .loginTime  = std::chrono::system_clock::from_time_t(entry->ut_tv.tv_sec),
+      });
+    }
+
+    endutxent();
+
+    return sessions;
+  }
+  #endif // DRAC_HAS_UTMPX
+
+  /**
+   * @brief Gets the passwd entry for the effective user via getpwuid_r.
+   * @return UserInfo with the username, uid/gid, home directory and login shell, or an error.
+   */
+  [[nodiscard]] inline auto GetCurrentUser() -> types::Result<types::UserInfo> {
+    struct passwd  pwd {};
+    struct passwd* result = nullptr;
+
+    // Unlike GetUserNameFromUid, the home and shell paths also live in this buffer.
+    types::Array<char, 4096> buffer {};
+
+    const uid_t uid = geteuid();
+
+    if (const int err = getpwuid_r(uid, &pwd, buffer.data(), buffer.size(), &result); err != 0)
+      return types::Err(error::DracError(InternalError, std::format("getpwuid_r({}) failed: {} (errno {})", uid, std::strerror(err), err)));
+
+    if (result == nullptr || result->pw_name == nullptr)
+      return types::Err(error::DracError(NotFound, std::format("No passwd entry for uid {}", uid)));
+
+    return types::UserInfo {
+      .username      = types::String(result->pw_name),
+      .uid           = static_cast<types::u32>(uid),
+      .gid           = static_cast<types::u32>(result->pw_gid),
+      .sid           = types::None,
+      .homeDirectory = result->pw_dir != nullptr ? types::String(result->pw_dir) : types::String {},
+      .shell         = result->pw_shell != nullptr && *result->pw_shell != '\0' ? types::Option<types::String>(result->pw_shell) : types::None,
+    };
+  }
+
+  /**
+   * @brief Gets the locale from the POSIX locale environment variables.
+   * @return The first non-empty value of LC_ALL, LC_MESSAGES, or LANG (e.g., "en_US.UTF-8"), or None.
+   *
+   * @note The variables are checked in the same precedence order that setlocale() uses for messages.
+   */
+  [[nodiscard]] inline auto GetLocaleFromEnv() -> types::Option<types::String> {
+    using draconis::utils::env::GetEnv;
+
+    for (const char* name : { "LC_ALL", "LC_MESSAGES", "LANG" })
+      if (types::Result<types::String> value = GetEnv(name); value && !value->empty())
+        return std::move(*value);
+
+    return types::None;
+  }
+
+  /**
+   * @brief Gets the local timezone's IANA name and current UTC offset.
+   * @return TimezoneInfo struct, or an error.
+   *
+   * @details The name comes from the TZ environment variable if it names a zone,
+   * otherwise from the /etc/localtime symlink target (or /etc/timezone on older
+   * Debian-based systems). The offset comes from localtime_r, so it includes DST.
+   */
+  [[nodiscard]] inline auto GetTimezone() -> types::Result<types::TimezoneInfo> {
+    namespace fs = std::filesystem;
+
+    using draconis::utils::env::GetEnv;
+
+    // Zone files live under a "zoneinfo" directory on every supported platform,
+    // e.g. /usr/share/zoneinfo/Europe/Berlin or /var/db/timezone/zoneinfo/Europe/Berlin.
+    const auto zoneFromPath = [](const types::StringView path) -> types::Option<types::String> {
+      constexpr types::StringView marker = "zoneinfo/";
+
+      if (const types::usize pos = path.rfind(marker); pos != types::StringView::npos && pos + marker.size() < path.size())
+        return types::String(path.substr(pos + marker.size()));
+
+      return types::None;
+    };
+
+    types::Option<types::String> name;
+
+    if (types::Result<types::String> tzEnv = GetEnv("TZ"); tzEnv && !tzEnv->empty()) {
+      const types::StringView tz = tzEnv->starts_with(':') ? types::StringView(*tzEnv).substr(1) : types::StringView(*tzEnv);
+
+      if (tz.starts_with('/'))
+        name = zoneFromPath(tz);
+      else if (!tz.empty())
+        name = types::String(tz);
+    }
+
+    if (!name) {
+      std::error_code errc;
+
+      if (const fs::path target = fs::read_symlink("/etc/localtime", errc); !errc)
+        name = zoneFromPath(target.string());
+    }
+
+    if (!name) {
+      if (std::ifstream file("/etc/timezone"); file) {
+        types::String line;
+
+        if (std::getline(file, line) && !line.empty())
+          name = std::move(line);
+      }
+    }
+
+    if (!name)
+      return types::Err(error::DracError(NotFound, "Could not determine the local timezone name"));
+
+    const std::time_t now = std::time(nullptr);
+    std::tm           local {};
+
+    if (localtime_r(&now, &local) == nullptr)
+      return types::Err(error::DracError(InternalError, std::format("localtime_r() failed: {} (errno {})", std::strerror(errno), errno)));
+
+    return types::TimezoneInfo {
+      .name             = std::move(*name),
+      .utcOffsetSeconds = static_cast<types::i32>(local.tm_gmtoff),
+    };
+  }
+
+  /**
+   * @brief Detects the display server of the current session from the environment.
+   * @return The display server kind; `Tty` if no graphical session variables are set.
+   *
+   * @details `XDG_SESSION_TYPE` is set by the login manager and is authoritative. Without it,
+   * `WAYLAND_DISPLAY` is checked before `DISPLAY`, since Xwayland sets `DISPLAY` on Wayland too.
+   */
+  [[nodiscard]] inline auto GetDisplayServerFromEnv() -> types::DisplaySession::Server {
+    using draconis::utils::env::GetEnv;
+    using enum types::DisplaySession::Server;
+
+    if (const types::Result<types::String> sessionType = GetEnv("XDG_SESSION_TYPE")) {
+      if (*sessionType == "wayland")
+        return Wayland;
+      if (*sessionType == "x11")
+        return X11;
+      if (*sessionType == "tty")
+        return Tty;
+    }
+
+    if (GetEnv("WAYLAND_DISPLAY"))
+      return Wayland;
+
+    if (GetEnv("DISPLAY"))
+      return X11;
+
+    return Tty;
+  }
+
+  /**
+   * @brief Detects the terminal emulator from variables it exports to child processes.
+   * @return A friendly terminal name, or None if no known variable is set.
+   *
+   * @note This is checked before walking the process tree, since it also works across
+   * `sudo`, `tmux` and `ssh` boundaries where the tree walk would stop early.
+   */
+  [[nodiscard]] inline auto GetTerminalFromEnv() -> types::Option<types::String> {
+    using draconis::utils::env::GetEnv;
+
+    if (types::Result<types::String> termProgram = GetEnv("TERM_PROGRAM"); termProgram && !termProgram->empty()) {
+      // clang-format off
+      constexpr types::Array<types::Pair<types::StringView, types::StringView>, 5> termProgramMap {{
+        { "Apple_Terminal", "Terminal.app" },
+        {      "iTerm.app",       "iTerm2" },
+        {         "vscode",      "VS Code" },
+        {        "ghostty",      "Ghostty" },
+        {           "tmux",         "tmux" },
+      }};
+      // clang-format on
+
+      for (const auto& [program, name] : termProgramMap)
+        if (*termProgram == program)
+          return types::String(name);
+
+      return std::move(*termProgram);
+    }
+
+    // clang-format off
+    constexpr types::Array<types::Pair<const char*, types::StringView>, 7> envMarkers {{
+      {       "KITTY_WINDOW_ID",          "kitty" },
+      {   "ALACRITTY_WINDOW_ID",      "Alacritty" },
+      {       "KONSOLE_VERSION",        "Konsole" },
+      { "GNOME_TERMINAL_SCREEN", "GNOME Terminal" },
+      {              "TILIX_ID",          "Tilix" },
+      {       "TERMINATOR_UUID",     "Terminator" },
+      {           "WEZTERM_PANE",        "WezTerm" },
+    }};
+    // clang-format on
+
+    for (const auto& [variable, name] : envMarkers)
+      if (GetEnv(variable))
+        return types::String(name);
+
+    return types::None;
+  }
+
+  /**
+   * @brief Walks up the process tree to find the terminal emulator hosting a process.
+   * @param startPid The process to start from (usually the parent of the current process).
+   * @param readProcess Callable taking a pid and returning its name and parent pid, or None.
+   * @return A friendly terminal name, or an error if the walk reaches init without finding one.
+   *
+   * @details Shells and privilege/session wrappers are skipped; the first other ancestor is
+   * taken to be the terminal. Reaching `sshd` reports an SSH session.
+   */
+  template <typename ProcessReader>
+  [[nodiscard]] auto FindTerminalInProcessTree(pid_t startPid, ProcessReader&& readProcess) -> types::Result<types::String> {
+    // clang-format off
+    constexpr types::Array<types::StringView, 17> skippedProcesses {
+      "bash", "zsh", "fish", "sh", "dash", "ksh", "mksh", "tcsh", "csh", "nu", "xonsh", "elvish",
+      "sudo", "doas", "su", "login", "env",
+    };
+
+    constexpr types::Array<types::Pair<types::StringView, types::StringView>, 12> terminalMap {{
+      { "gnome-terminal-", "GNOME Terminal" },
+      { "gnome-terminal-server", "GNOME Terminal" },
+      { "kgx", "Console" },
+      { "konsole", "Konsole" },
+      { "alacritty", "Alacritty" },
+      { "foot", "foot" },
+      { "footclient", "foot" },
+      { "wezterm-gui", "WezTerm" },
+      { "xfce4-terminal", "Xfce Terminal" },
+      { "urxvt", "rxvt-unicode" },
+      { "ghostty", "Ghostty" },
+      { "ptyxis-agent", "Ptyxis" },
+    }};
+    // clang-format on
+
+    // Deep enough for nested shells and wrappers, while guarding against a corrupted tree.
+    constexpr types::i32 maxDepth = 16;
+
+    pid_t pid = startPid;
+
+    for (types::i32 depth = 0; pid > 1 && depth < maxDepth; depth++) {
+      const auto process = readProcess(pid);
+
+      if (!process)
+        break;
+
+      const auto& [name, parentPid] = *process;
+
+      // Login shells show up as "-bash", and some shells rename themselves after their full path.
+      types::StringView baseName = name;
+
+      if (baseName.starts_with('-'))
+        baseName.remove_prefix(1);
+
+      if (const types::usize slash = baseName.rfind('/'); slash != types::StringView::npos)
+        baseName.remove_prefix(slash + 1);
+
+      if (std::ranges::find(skippedProcesses, baseName) != skippedProcesses.end()) {
+        pid = parentPid;
+        continue;
+      }
+
+      if (baseName.starts_with("sshd"))
+        return types::String("SSH");
+
+      for (const auto& [processName, friendly] : terminalMap)
+        if (baseName == processName)
+          return types::String(friendly);
+
+      return types::String(baseName);
+    }
+
+    return types::Err(error::DracError(NotFound, "No terminal emulator found in the process tree"));
+  }
+
+  /**
+   * @brief Resolves the user's configuration directory.
+   * @return `XDG_CONFIG_HOME` if set, otherwise `$HOME/.config`.
+   */
+  [[nodiscard]] inline auto GetConfigHome() -> types::Result<std::filesystem::path> {
+    using draconis::utils::env::GetEnv;
+
+    if (types::Result<types::String> xdgConfig = GetEnv("XDG_CONFIG_HOME"); xdgConfig && !xdgConfig->empty())
+      return std::filesystem::path(*xdgConfig);
+
+    if (types::Result<types::String> home = GetEnv("HOME"))
+      return std::filesystem::path(*home) / ".config";
+
+    return types::Err(error::DracError(ConfigurationError, "Neither XDG_CONFIG_HOME nor HOME is set"));
+  }
+
+  /**
+   * @brief Strips leading and trailing whitespace (including a trailing '\r') from a config line.
+   */
+  [[nodiscard]] constexpr auto TrimConfigLine(types::StringView text) -> types::StringView {
+    while (!text.empty() && (text.front() == ' ' || text.front() == '\t'))
+      text.remove_prefix(1);
+
+    while (!text.empty() && (text.back() == ' ' || text.back() == '\t' || text.back() == '\r'))
+      text.remove_suffix(1);
+
+    return text;
+  }
+
+  /**
+   * @brief Converts a Qt-serialized font ("Family,pointSize,pixelSize,...") to "Family Size".
+   */
+  [[nodiscard]] inline auto ParseQtFont(const types::StringView font) -> types::String {
+    const types::usize      comma  = font.find(',');
+    const types::StringView family = font.substr(0, comma);
+
+    if (comma == types::StringView::npos)
+      return types::String(family);
+
+    const types::StringView size = font.substr(comma + 1, font.find(',', comma + 1) - (comma + 1));
+
+    return size.empty() ? types::String(family) : std::format("{} {}", family, size);
+  }
+
+  /**
+   * @brief Reads a single value from an INI-style file (as used by GTK and KDE).
+   * @param path The file to read.
+   * @param section The section name without brackets, e.g. "Settings".
+   * @param key The key to look up within that section.
+   * @return The value, or None if the file, section, or key doesn't exist.
+   */
+  [[nodiscard]] inline auto ReadIniValue(const std::filesystem::path& path, const types::StringView section, const types::StringView key) -> types::Option<types::String> {
+    std::ifstream file(path);
+
+    if (!file)
+      return types::None;
+
+    types::String line;
+    bool          inSection = false;
+
+    while (std::getline(file, line)) {
+      const types::StringView view = TrimConfigLine(line);
+
+      if (view.starts_with('[')) {
+        inSection = view == std::format("[{}]", section);
+        continue;
+      }
+
+      if (!inSection || !view.starts_with(key))
+        continue;
+
+      const types::StringView rest = TrimConfigLine(view.substr(key.size()));
+
+      if (rest.starts_with('='))
+        return types::String(TrimConfigLine(rest.substr(1)));
+    }
+
+    return types::None;
+  }
+
+  /**
+   * @brief Reads the configured font of a terminal emulator from its config file.
+   * @param terminal The friendly terminal name returned by the terminal detection.
+   * @return The font as "Family Size" (or just "Family" if no size is set), or an error.
+   *
+   * @details Supports kitty (`kitty.conf`), Alacritty (`alacritty.toml`), foot (`foot.ini`),
+   * and Konsole (the default `.profile`). Only the first matching key is used; includes and
+   * per-OS overrides aren't followed.
+   */
+  [[nodiscard]] inline auto GetTerminalFontFromConfig(const types::StringView terminal) -> types::Result<types::String> {
+    namespace fs = std::filesystem;
+
+    using draconis::utils::env::GetEnv;
+
+    const types::Result<fs::path> configHomeResult = GetConfigHome();
+
+    if (!configHomeResult)
+      return types::Err(configHomeResult.error());
+
+    const fs::path& configHome = *configHomeResult;
+
+    const auto unquote = [](types::StringView text) -> types::StringView {
+      if (text.size() >= 2 && (text.front() == '"' || text.front() == '\'') && text.back() == text.front())
+        return text.substr(1, text.size() - 2);
+      return text;
+    };
+
+    const auto joinFont = [](const types::StringView family, const types::StringView size) -> types::String {
+      return size.empty() ? types::String(family) : std::format("{} {}", family, size);
+    };
+
+    if (terminal == "kitty") {
+      std::ifstream file(configHome / "kitty" / "kitty.conf");
+
+      if (!file)
+        return types::Err(error::DracError(NotFound, "kitty.conf not found"));
+
+      types::String line;
+      types::String family;
+      types::String size;
+
+      // kitty.conf lines are "key value"; later keys override earlier ones.
+      while (std::getline(file, line)) {
+        const types::StringView view = TrimConfigLine(line);
+
+        if (view.starts_with("font_family "))
+          family = TrimConfigLine(view.substr(12));
+        else if (view.starts_with("font_size "))
+          size = TrimConfigLine(view.substr(10));
+      }
+
+      if (family.empty())
+        return types::Err(error::DracError(NotFound, "kitty.conf does not set font_family"));
+
+      return joinFont(family, size);
+    }
+
+    if (terminal == "Alacritty") {
+      std::ifstream file(configHome / "alacritty" / "alacritty.toml");
+
+      if (!file)
+        return types::Err(error::DracError(NotFound, "alacritty.toml not found"));
+
+      types::String line;
+      types::String section;
+      types::String family;
+      types::String size;
+
+      // Only `family` under [font.normal] and `size` under [font] are needed, so a
+      // line-based scan is enough; inline tables like `normal = { family = ... }` aren't supported.
+      while (std::getline(file, line)) {
+        const types::StringView view = TrimConfigLine(line);
+
+        if (view.starts_with('[')) {
+          section = view;
+          continue;
+        }
+
+        const types::usize equals = view.find('=');
+
+        if (equals == types::StringView::npos)
+          continue;
+
+        const types::StringView key   = TrimConfigLine(view.substr(0, equals));
+        const types::StringView value = unquote(TrimConfigLine(view.substr(equals + 1)));
+
+        if (section == "[font.normal]" && key == "family")
+          family = value;
+        else if (section == "[font]" && key == "size")
+          size = value;
+      }
+
+      if (family.empty())
+        return types::Err(error::DracError(NotFound, "alacritty.toml does not set font.normal.family"));
+
+      return joinFont(family, size);
+    }
+
+    if (terminal == "foot") {
+      std::ifstream file(configHome / "foot" / "foot.ini");
+
+      if (!file)
+        return types::Err(error::DracError(NotFound, "foot.ini not found"));
+
+      types::String line;
+
+      // foot uses fontconfig patterns, e.g. "font=JetBrains Mono:size=11".
+      while (std::getline(file, line)) {
+        const types::StringView view = TrimConfigLine(line);
+
+        if (!view.starts_with("font="))
+          continue;
+
+        const types::StringView pattern = TrimConfigLine(view.substr(5));
+        const types::StringView family  = pattern.substr(0, pattern.find(':'));
+        types::StringView       size;
+
+        if (const types::usize sizePos = pattern.find(":size="); sizePos != types::StringView::npos)
+          size = pattern.substr(sizePos + 6, pattern.find(':', sizePos + 6) - (sizePos + 6));
+
+        return joinFont(family, size);
+      }
+
+      return types::Err(error::DracError(NotFound, "foot.ini does not set font"));
+    }
+
+    if (terminal == "Konsole") {
+      const types::Option<types::String> profileName = ReadIniValue(configHome / "konsolerc", "Desktop Entry", "DefaultProfile");
+
+      if (!profileName)
+        return types::Err(error::DracError(NotFound, "konsolerc does not set a default profile"));
+
+      fs::path dataHome;
+
+      if (types::Result<types::String> xdgData = GetEnv("XDG_DATA_HOME"); xdgData && !xdgData->empty())
+        dataHome = *xdgData;
+      else if (types::Result<types::String> home = GetEnv("HOME"))
+        dataHome = fs::path(*home) / ".local" / "share";
+
+      if (types::Option<types::String> font = ReadIniValue(dataHome / "konsole" / *profileName, "Appearance", "Font"))
+        return ParseQtFont(*font);
+
+      return types::Err(error::DracError(NotFound, std::format("Konsole profile '{}' does not set a font", *profileName)));
+    }
+
+    return types::Err(error::DracError(NotSupported, std::format("Reading the font of '{}' is not supported", terminal)));
+  }
+
+  /**
+   * @brief Reads the widget, icon, and cursor theme and UI font from KDE and GTK config files.
+   * @return The theme info; fields not set by any config are left empty.
+   *
+   * @details On KDE (per `XDG_CURRENT_DESKTOP`), `kdeglobals` and `kcminputrc` are read first.
+   * Remaining fields come from GTK's `settings.ini` (GTK 3, then GTK 4), and finally from the
+   * `GTK_THEME` and `XCURSOR_THEME` overrides. GNOME keeps its settings in dconf, which is only
+   * covered when mirrored into `settings.ini`.
+   */
+  [[nodiscard]] inline auto GetThemeInfoFromConfig() -> types::Result<types::ThemeInfo> {
+    namespace fs = std::filesystem;
+
+    using draconis::utils::env::GetEnv;
+
+    const types::Result<fs::path> configHomeResult = GetConfigHome();
+
+    if (!configHomeResult)
+      return types::Err(configHomeResult.error());
+
+    const fs::path& configHome = *configHomeResult;
+
+    types::ThemeInfo theme;
+
+    const auto fillFrom = [](types::Option<types::String>& field, types::Option<types::String> value) -> void {
+      if (!field && value && !value->empty())
+        field = std::move(value);
+    };
+
+    if (const types::Result<types::String> desktop = GetEnv("XDG_CURRENT_DESKTOP"); desktop && desktop->contains("KDE")) {
+      const fs::path kdeglobals = configHome / "kdeglobals";
+
+      fillFrom(theme.widgetTheme, ReadIniValue(kdeglobals, "KDE", "widgetStyle"));
+      fillFrom(theme.iconTheme, ReadIniValue(kdeglobals, "Icons", "Theme"));
+      fillFrom(theme.cursorTheme, ReadIniValue(configHome / "kcminputrc", "Mouse", "cursorTheme"));
+
+      if (types::Option<types::String> font = ReadIniValue(kdeglobals, "General", "font"))
+        fillFrom(theme.font, ParseQtFont(*font));
+    }
+
+    for (const types::StringView gtkDir : { "gtk-3.0", "gtk-4.0" }) {
+      const fs::path settings = configHome / gtkDir / "settings.ini";
+
+      fillFrom(theme.widgetTheme, ReadIniValue(settings, "Settings", "gtk-theme-name"));
+      fillFrom(theme.iconTheme, ReadIniValue(settings, "Settings", "gtk-icon-theme-name"));
+      fillFrom(theme.cursorTheme, ReadIniValue(settings, "Settings", "gtk-cursor-theme-name"));
+      fillFrom(theme.font, ReadIniValue(settings, "Settings", "gtk-font-name"));
+    }
+
+    // GTK_THEME may carry a variant suffix, e.g. "Adwaita:dark".
+    if (types::Result<types::String> gtkTheme = GetEnv("GTK_THEME"))
+      fillFrom(theme.widgetTheme, gtkTheme->substr(0, gtkTheme->find(':')));
+
+    if (types::Result<types::String> cursorTheme = GetEnv("XCURSOR_THEME"))
+      fillFrom(theme.cursorTheme, *std::move(cursorTheme));
+
+    if (!theme.widgetTheme && !theme.iconTheme && !theme.cursorTheme && !theme.font)
+      return types::Err(error::DracError(NotFound, "No theme settings found in KDE or GTK configuration"));
+
+    return theme;
+  }
+
+  /**
+   * @brief Reads the editor configured through `VISUAL`, falling back to `EDITOR`.
+   * @return The command as set (e.g., "nvim", "code --wait"), or None if neither is set.
+   */
+  [[nodiscard]] inline auto GetEditorFromEnv() -> types::Option<types::String> {
+    using draconis::utils::env::GetEnv;
+
+    for (const types::PCStr name : { "VISUAL", "EDITOR" })
+      if (types::Result<types::String> editor = GetEnv(name); editor && !editor->empty())
+        return *std::move(editor);
+
+    return types::None;
+  }
+
+  /**
+   * @brief Lists the XDG data directories, most important first.
+   * @return `XDG_DATA_HOME` (or `$HOME/.local/share`), followed by `XDG_DATA_DIRS` (or `/usr/local/share:/usr/share`).
+   */
+  [[nodiscard]] inline auto GetDataDirs() -> types::Vec<std::filesystem::path> {
+    using draconis::utils::env::GetEnv;
+
+    types::Vec<std::filesystem::path> dirs;
+
+    if (types::Result<types::String> xdgData = GetEnv("XDG_DATA_HOME"); xdgData && !xdgData->empty())
+      dirs.emplace_back(*xdgData);
+    else if (types::Result<types::String> home = GetEnv("HOME"))
+      dirs.emplace_back(std::filesystem::path(*home) / ".local" / "share");
+
+    types::Result<types::String> dataDirs = GetEnv("XDG_DATA_DIRS");
+
+    if (!dataDirs || dataDirs->empty())
+      dataDirs = types::String("/usr/local/share:/usr/share");
+
+    for (const auto dir : std::views::split(*dataDirs, ':'))
+      if (!dir.empty())
+        dirs.emplace_back(types::StringView(dir.begin(), dir.end()));
+
+    return dirs;
+  }
+
+  /**
+   * @brief Resolves a desktop entry ID (e.g. "firefox.desktop") to the application's name.
+   * @return The entry's `Name` (e.g. "Firefox"), or the ID without its ".desktop" suffix if the entry can't be found.
+   */
+  [[nodiscard]] inline auto GetDesktopEntryName(const types::StringView desktopId) -> types::String {
+    for (const std::filesystem::path& dir : GetDataDirs())
+      if (types::Option<types::String> name = ReadIniValue(dir / "applications" / desktopId, "Desktop Entry", "Name"); name && !name->empty())
+        return *std::move(name);
+
+    return types::String(desktopId.substr(0, desktopId.rfind(".desktop")));
+  }
+
+  /**
+   * @brief Detects the default editor, web browser, and terminal emulator from the environment and freedesktop configuration.
+   * @return The applications found; ones that aren't configured anywhere are left empty.
+   *
+   * @details The editor comes from `VISUAL` or `EDITOR`. The browser comes from `BROWSER`, then the
+   * `x-scheme-handler/https` default in `mimeapps.list`. The terminal comes from `TERMINAL`, then
+   * `xdg-terminals.list` (the xdg-terminal-exec proposal), then KDE's `TerminalApplication` on Plasma.
+   */
+  [[nodiscard]] inline auto GetDefaultApplicationsFromConfig() -> types::Result<types::DefaultApplications> {
+    namespace fs = std::filesystem;
+
+    using draconis::utils::env::GetEnv;
+
+    types::DefaultApplications apps;
+    apps.editor = GetEditorFromEnv();
+
+    const types::Result<fs::path> configHome = GetConfigHome();
+
+    // BROWSER may hold a colon-separated list of fallbacks, the first of which is preferred.
+    if (types::Result<types::String> browser = GetEnv("BROWSER"); browser && !browser->empty()) {
+      apps.browser = browser->substr(0, browser->find(':'));
+    } else {
+      // The user's associations override the system's, in the order the MIME applications spec lists them.
+      types::Vec<fs::path> mimeLists;
+
+      if (configHome)
+        mimeLists.push_back(*configHome / "mimeapps.list");
+
+      mimeLists.emplace_back("/etc/xdg/mimeapps.list");
+
+      for (const fs::path& dir : GetDataDirs())
+        mimeLists.push_back(dir / "applications" / "mimeapps.list");
+
+      for (const fs::path& mimeList : mimeLists) {
+        types::Option<types::String> handler = ReadIniValue(mimeList, "Default Applications", "x-scheme-handler/https");
+
+        if (!handler)
+          handler = ReadIniValue(mimeList, "Default Applications", "x-scheme-handler/http");
+
+        if (!handler)
+          continue;
+
+        // Several desktop IDs can be listed; the first one that's installed wins, and that's almost always the first.
+        if (const types::StringView desktopId = TrimConfigLine(types::StringView(*handler).substr(0, handler->find(';'))); !desktopId.empty()) {
+          apps.browser = GetDesktopEntryName(desktopId);
+          break;
+        }
+      }
+    }
+
+    if (types::Result<types::String> terminal = GetEnv("TERMINAL"); terminal && !terminal->empty())
+      apps.terminal = *std::move(terminal);
+
+    if (!apps.terminal && configHome) {
+      if (std::ifstream file(*configHome / "xdg-terminals.list"); file) {
+        types::String line;
+
+        while (std::getline(file, line)) {
+          const types::StringView entry = TrimConfigLine(line);
+
+          if (entry.empty() || entry.starts_with('#'))
+            continue;
+
+          // Entries may name a specific desktop action, e.g. "kitty.desktop:new-window".
+          apps.terminal = GetDesktopEntryName(entry.substr(0, entry.find(':')));
+          break;
+        }
+      }
+    }
+
+    if (!apps.terminal && configHome)
+      if (const types::Result<types::String> desktop = GetEnv("XDG_CURRENT_DESKTOP"); desktop && desktop->contains("KDE"))
+        if (types::Option<types::String> terminal = ReadIniValue(*configHome / "kdeglobals", "General", "TerminalApplication"); terminal && !terminal->empty())
+          apps.terminal = *std::move(terminal);
+
+    if (!apps.editor && !apps.browser && !apps.terminal)
+      return types::Err(error::DracError(NotFound, "No default editor, browser, or terminal is configured"));
+
+    return apps;
+  }
+
+  /**
+   * @brief Maps a received signal strength to a 0-100 quality percentage.
+   * @param dbm Signal strength in dBm.
+   * @return -100 dBm and below map to 0, -50 dBm and above map to 100, linearly in between.
+   */
+  [[nodiscard]] inline auto SignalPercentFromDbm(const types::i32 dbm) -> types::u8 {
+    return static_cast<types::u8>(std::clamp(2 * (dbm + 100), 0, 100));
+  }
+
+  /**
+   * @brief Maps a kernel/RandR/compositor output name (e.g. "eDP-1", "HDMI-A-1", "DP1") to its connector type.
+   * @param name The output name.
+   * @return The connector type, or Connector::Unknown if the name doesn't start with a known prefix.
+   */
+  [[nodiscard]] inline auto ConnectorFromOutputName(const types::StringView name) -> types::DisplayInfo::Connector {
+    using enum types::DisplayInfo::Connector;
+
+    // clang-format off
+    constexpr types::Array<types::Pair<types::StringView, types::DisplayInfo::Connector>, 10> prefixes = {{
+      { "eDP",         EDP         },
+      { "LVDS",        LVDS        },
+      { "DSI",         DSI         },
+      { "HDMI",        HDMI        },
+      { "DP",          DisplayPort },
+      { "DisplayPort", DisplayPort },
+      { "DVI",         DVI         },
+      { "VGA",         VGA         },
+      { "Virtual",     Virtual     },
+      { "VIRTUAL",     Virtual     },
+    }};
+    // clang-format on
+
+    for (const auto& [prefix, connector] : prefixes)
+      if (name.starts_with(prefix))
+        return connector;
+
+    return Unknown;
+  }
+
+  /**
+   * @brief Computes a display's physical pixel density.
+   * @param pixels Horizontal resolution in pixels.
+   * @param millimeters Physical width in millimeters.
+   * @return Pixels per inch, or None if the physical size is unknown (0) or implausibly small (projectors, some VMs report 1-10 mm).
+   */
+  [[nodiscard]] inline auto DpiFromPhysicalSize(const types::usize pixels, const types::u32 millimeters) -> types::Option<types::f64> {
+    if (pixels == 0 || millimeters < 20)
+      return types::None;
+
+    return static_cast<types::f64>(pixels) * 25.4 / static_cast<types::f64>(millimeters);
+  }
+
+  /**
+   * @brief Reads the `nameserver` entries of a resolv.conf-style file.
+   * @param path Path to the file, usually /etc/resolv.conf.
+   * @return The server addresses in file order (without duplicates), or an error if the file can't be read.
+   */
+  [[nodiscard]] inline auto ReadResolvConfNameservers(const types::PCStr path) -> types::Result<types::Vec<types::String>> {
+    std::ifstream file(path);
+
+    if (!file)
+      return types::Err(error::DracError(NotFound, std::format("Failed to open {}", path)));
+
+    types::Vec<types::String> servers;
+    types::String             line;
+
+    while (std::getline(file, line)) {
+      types::StringView entry = TrimConfigLine(line);
+
+      if (!entry.starts_with("nameserver") || entry.size() <= 10 || (entry[10] != ' ' && entry[10] != '\t'))
+        continue;
+
+      entry = TrimConfigLine(entry.substr(10));
+
+      // Anything after the address (e.g. a trailing comment) isn't part of it.
+      if (const types::usize end = entry.find_first_of(" \t#;"); end != types::StringView::npos)
+        entry = entry.substr(0, end);
+
+      if (!entry.empty() && std::ranges::find(servers, entry) == servers.end())
+        servers.emplace_back(entry);
+    }
+
+    return servers;
+  }
+
+  /**
+   * @brief Builds a NetworkMount from a mount table entry, if it is one.
+   * @param source The mounted source, e.g. "server:/srv/media", "//user@server/share" or "https://host/dav".
+   * @param fsType The filesystem type as the mount table reports it.
+   * @param mountPoint The local mount point.
+   * @return The mount with its state left Unknown, or None for local filesystems.
+   */
+  [[nodiscard]] inline auto ParseNetworkMount(const types::StringView source, const types::StringView fsType, const types::StringView mountPoint)
+    -> types::Option<types::NetworkMount> {
+    using enum types::NetworkMount::Protocol;
+
+    types::NetworkMount::Protocol protocol = Unknown;
+
+    if (fsType == "nfs" || fsType == "nfs4")
+      protocol = Nfs;
+    else if (fsType == "cifs" || fsType == "smb3" || fsType == "smbfs")
+      protocol = Smb;
+    else if (fsType == "fuse.sshfs" || ((fsType == "macfuse" || fsType == "osxfuse") && source.contains(':')))
+      protocol = Sshfs;
+    else if (fsType == "davfs" || fsType == "webdav")
+      protocol = WebDav;
+    else if (fsType != "afpfs" && fsType != "ceph" && fsType != "glusterfs" && fsType != "fuse.glusterfs")
+      return types::None;
+
+    types::StringView server;
+    types::StringView exportPath;
+
+    if (const types::usize scheme = source.find("://"); scheme != types::StringView::npos) {
+      // WebDAV URL: scheme://[user@]host[:port]/path
+      const types::StringView rest  = source.substr(scheme + 3);
+      const types::usize      slash = rest.find('/');
+
+      server     = rest.substr(0, slash);
+      exportPath = slash == types::StringView::npos ? "/" : rest.substr(slash);
+    } else if (source.starts_with("//")) {
+      // UNC-style SMB source: //[user@]server/share[/path]
+      const types::StringView rest  = source.substr(2);
+      const types::usize      slash = rest.find('/');
+
+      server     = rest.substr(0, slash);
+      exportPath = slash == types::StringView::npos ? "" : rest.substr(slash + 1);
+    } else {
+      // NFS and SSHFS: [user@]host:path, with IPv6 hosts in brackets
+      const types::usize bracket = source.starts_with('[') ? source.find(']') : 0;
+      const types::usize colon   = source.find(':', bracket == types::StringView::npos ? 0 : bracket);
+
+      server     = source.substr(0, colon);
+      exportPath = colon == types::StringView::npos ? "" : source.substr(colon + 1);
+    }
+
+    // Strip credentials; only the host identifies the server.
+    if (const types::usize at = server.rfind('@'); at != types::StringView::npos)
+      server = server.substr(at + 1);
+
+    if (server.starts_with('[') && server.ends_with(']'))
+      server = server.substr(1, server.size() - 2);
+
+    return types::NetworkMount {
+      .server     = types::String(server),
+      .exportPath = types::String(exportPath),
+      .mountPoint = types::String(mountPoint),
+      .protocol   = protocol,
+      .state      = types::NetworkMount::State::Unknown,
+    };
+  }
+
+  #if defined(__linux__) || defined(__APPLE__) || defined(__FreeBSD__) || defined(__DragonFly__) || defined(__NetBSD__) || defined(__OpenBSD__)
+  /**
+   * @brief Gets the 1, 5, and 15 minute load averages via getloadavg.
+   * @return LoadAverage struct, or an error.
+   */
+  [[nodiscard]] inline auto GetLoadAverage() -> types::Result<types::LoadAverage> {
+    types::Array<double, 3> loads {};
+
+    if (getloadavg(loads.data(), static_cast<int>(loads.size())) != static_cast<int>(loads.size()))
+      return types::Err(error::DracError(InternalError, "getloadavg() failed to return all three averages"));
+
+    return types::LoadAverage(loads[0], loads[1], loads[2]);
+  }
+  #endif
+
   #if defined(__linux__)
+  /**
+   * @brief Reads the EDID of a DRM connector from sysfs.
+   * @param connectorName The connector name used by the kernel and Wayland compositors (e.g. "DP-1", "HDMI-A-1").
+   * @return The raw EDID, or None if no card has a connector by that name or nothing is attached to it.
+   */
+  [[nodiscard]] inline auto ReadDrmEdid(const types::StringView connectorName) -> types::Option<types::Vec<types::u8>> {
+    std::error_code errc;
+
+    // Connector directories are named card<N>-<connector>, e.g. card1-DP-1.
+    for (const std::filesystem::directory_entry& entry : std::filesystem::directory_iterator("/sys/class/drm", errc)) {
+      const types::String     entryName = entry.path().filename().string();
+      const types::usize      dashPos   = entryName.find('-');
+      const types::StringView entryView(entryName);
+
+      if (!entryName.starts_with("card") || dashPos == types::String::npos || entryView.substr(dashPos + 1) != connectorName)
+        continue;
+
+      std::ifstream file(entry.path() / "edid", std::ios::binary);
+
+      types::Vec<types::u8> edid { std::istreambuf_iterator<char>(file), std::istreambuf_iterator<char>() };
+
+      if (!edid.empty())
+        return edid;
+    }
+
+    return types::None;
+  }
+
   /**
    * @brief Gets system uptime on Linux via sysinfo.
    * @return Uptime in seconds, or an error.
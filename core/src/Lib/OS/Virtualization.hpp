@@ -0,0 +1,115 @@
+/**
+ * @file Virtualization.hpp
+ * @brief Shared identification of hypervisors and container runtimes for the Linux and Windows backends.
+ *
+ * @details Both backends gather their evidence differently (sysfs and marker files on Linux, the
+ * registry on Windows), but CPUID and the SMBIOS strings read the same on either. This header maps:
+ * - The 12-byte hypervisor vendor signature from CPUID leaf 0x40000000
+ * - The SMBIOS system manufacturer / product name pair
+ * - The container names used by `/run/systemd/container` and the `container` environment variable
+ */
+
+#pragma once
+
+#include <Drac++/Utils/DataTypes.hpp>
+#include <Drac++/Utils/Types.hpp>
+
+namespace draconis::os::virt {
+  namespace types = ::draconis::utils::types;
+
+  using Hypervisor = types::VirtEnv::Hypervisor;
+  using Container  = types::VirtEnv::Container;
+
+  inline constexpr types::u32 CPUID_FEATURE_LEAF    = 0x1;        ///< ECX bit 31 is set when running under a hypervisor.
+  inline constexpr types::u32 CPUID_HYPERVISOR_BIT  = 1U << 31;   ///< The "hypervisor present" bit in leaf 1 ECX.
+  inline constexpr types::u32 CPUID_HYPERVISOR_LEAF = 0x40000000; ///< Vendor signature in EBX, ECX, EDX.
+
+  /**
+   * @brief Identifies a hypervisor from its CPUID vendor signature.
+   * @param signature The 12 bytes of EBX, ECX and EDX from leaf 0x40000000, trailing NULs allowed.
+   * @return The hypervisor, or `Hypervisor::Other` for unrecognized signatures.
+   */
+  [[nodiscard]] constexpr auto HypervisorFromCpuidSignature(types::StringView signature) -> Hypervisor {
+    if (const types::usize end = signature.find('\0'); end != types::StringView::npos)
+      signature = signature.substr(0, end);
+
+    // clang-format off
+    constexpr types::Array<types::Pair<types::StringView, Hypervisor>, 9> signatures {{
+      { "KVMKVMKVM",    Hypervisor::Kvm        },
+      { "Linux KVM Hv", Hypervisor::Kvm        }, // KVM exposing Hyper-V enlightenments
+      { "TCGTCGTCGTCG", Hypervisor::Qemu       },
+      { "Microsoft Hv", Hypervisor::HyperV     },
+      { "VMwareVMware", Hypervisor::VMware     },
+      { "prl hyperv  ", Hypervisor::Parallels  },
+      { " lrpepyh  vr", Hypervisor::Parallels  }, // Byte-swapped signature used by older Parallels releases
+      { "VBoxVBoxVBox", Hypervisor::VirtualBox },
+      { "XenVMMXenVMM", Hypervisor::Xen        },
+    }};
+    // clang-format on
+
+    for (const auto& [known, hypervisor] : signatures)
+      if (signature == known)
+        return hypervisor;
+
+    return Hypervisor::Other;
+  }
+
+  /**
+   * @brief Identifies a hypervisor from the SMBIOS system manufacturer and product name.
+   * @param manufacturer The system manufacturer (Linux `sys_vendor`, Windows `SystemManufacturer`).
+   * @param productName The system product name (Linux `product_name`, Windows `SystemProductName`).
+   * @return The hypervisor, or `Hypervisor::None` if the strings describe physical hardware.
+   *
+   * @details Used where CPUID isn't available (ARM) or doesn't tell the whole story. Note that
+   * QEMU reports itself as the manufacturer regardless of whether KVM acceleration is used.
+   */
+  [[nodiscard]] constexpr auto HypervisorFromDmi(const types::StringView manufacturer, const types::StringView productName) -> Hypervisor {
+    if (manufacturer.contains("QEMU"))
+      return Hypervisor::Qemu;
+
+    if (manufacturer.contains("VMware") || productName.starts_with("VMware"))
+      return Hypervisor::VMware;
+
+    if (manufacturer.contains("Parallels"))
+      return Hypervisor::Parallels;
+
+    if (productName == "VirtualBox")
+      return Hypervisor::VirtualBox;
+
+    if (manufacturer == "Xen")
+      return Hypervisor::Xen;
+
+    // Surface devices share the manufacturer, so the product name is what marks a Hyper-V guest.
+    if (manufacturer == "Microsoft Corporation" && productName == "Virtual Machine")
+      return Hypervisor::HyperV;
+
+    if (manufacturer == "Apple Inc." && productName.starts_with("Apple Virtualization"))
+      return Hypervisor::Apple;
+
+    return Hypervisor::None;
+  }
+
+  /**
+   * @brief Identifies a container runtime from the name it advertises.
+   * @param name Contents of `/run/systemd/container` or the `container` environment variable.
+   * @return The container runtime, `Container::Other` for unrecognized names, or `Container::None` if empty.
+   */
+  [[nodiscard]] constexpr auto ContainerFromName(const types::StringView name) -> Container {
+    if (name.empty())
+      return Container::None;
+
+    if (name == "docker")
+      return Container::Docker;
+
+    if (name == "podman")
+      return Container::Podman;
+
+    if (name == "lxc" || name == "lxc-libvirt" || name == "incus")
+      return Container::Lxc;
+
+    if (name == "wsl")
+      return Container::Wsl;
+
+    return Container::Other;
+  }
+} // namespace draconis::os::virt
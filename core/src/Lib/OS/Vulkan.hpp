@@ -0,0 +1,266 @@
+/**
+ * @file Vulkan.hpp
+ * @brief Shared querying of the Vulkan loader for the Linux and Windows backends.
+ *
+ * @details The loader is opened at runtime, so systems without a Vulkan driver build and run the same.
+ * Only the entry points and the leading structure fields that are read are declared here, which keeps
+ * the Vulkan headers out of the build. For each physical device, it reports:
+ * - The PCI vendor and device IDs, for matching devices to the adapters found by other means
+ * - The highest Vulkan version the driver supports
+ * - The driver version, from `VkPhysicalDeviceDriverProperties` (Vulkan 1.2) or the vendor's packing of `driverVersion`
+ */
+
+#pragma once
+
+#include <algorithm> // std::min
+#include <format>    // std::format
+
+#ifdef _WIN32
+  #include <windows.h> // LoadLibraryExW, GetProcAddress
+#else
+  #include <dlfcn.h> // dlopen, dlsym
+#endif
+
+#include <Drac++/Utils/Error.hpp>
+#include <Drac++/Utils/Types.hpp>
+
+namespace draconis::os::vulkan {
+  namespace types = ::draconis::utils::types;
+
+  using enum ::draconis::utils::error::DracErrorCode;
+
+  /**
+   * @brief What the driver reports about one physical device.
+   */
+  struct Device {
+    types::u32                   vendorId;      ///< PCI vendor ID (e.g. 0x10DE), or a Khronos ID for non-PCI devices.
+    types::u32                   deviceId;      ///< PCI device ID.
+    types::String                apiVersion;    ///< Highest supported Vulkan version (e.g. "1.3.280").
+    types::Option<types::String> driverVersion; ///< User-facing driver version (e.g. "Mesa 24.1.3", "552.44").
+  };
+
+  inline constexpr types::u32 VENDOR_NVIDIA = 0x10DE;
+  inline constexpr types::u32 VENDOR_INTEL  = 0x8086;
+
+  inline constexpr types::u32 API_VERSION_1_0 = 1U << 22;
+  inline constexpr types::u32 API_VERSION_1_1 = API_VERSION_1_0 | (1U << 12);
+  inline constexpr types::u32 API_VERSION_1_2 = API_VERSION_1_0 | (2U << 12);
+
+  inline constexpr types::i32 STRUCTURE_TYPE_APPLICATION_INFO                  = 0;
+  inline constexpr types::i32 STRUCTURE_TYPE_INSTANCE_CREATE_INFO              = 1;
+  inline constexpr types::i32 STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2      = 1000059001;
+  inline constexpr types::i32 STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES = 1000196000;
+
+  // NOLINTBEGIN(readability-identifier-naming) - Mirrors the Vulkan ABI.
+  using Instance       = struct VkInstance_T*;
+  using PhysicalDevice = struct VkPhysicalDevice_T*;
+
+  struct ApplicationInfo {
+    types::i32   sType;
+    const void*  pNext;
+    types::PCStr pApplicationName;
+    types::u32   applicationVersion;
+    types::PCStr pEngineName;
+    types::u32   engineVersion;
+    types::u32   apiVersion;
+  };
+
+  struct InstanceCreateInfo {
+    types::i32             sType;
+    const void*            pNext;
+    types::u32             flags;
+    const ApplicationInfo* pApplicationInfo;
+    types::u32             enabledLayerCount;
+    const types::PCStr*    ppEnabledLayerNames;
+    types::u32             enabledExtensionCount;
+    const types::PCStr*    ppEnabledExtensionNames;
+  };
+
+  // VkPhysicalDeviceProperties. The limits and sparse properties after the identifying fields aren't read.
+  struct PhysicalDeviceProperties {
+    types::u32                  apiVersion;
+    types::u32                  driverVersion;
+    types::u32                  vendorID;
+    types::u32                  deviceID;
+    types::i32                  deviceType;
+    types::Array<char, 256>     deviceName;
+    types::Array<types::u8, 16> pipelineCacheUUID;
+    alignas(8) types::Array<types::u8, 528> limitsAndSparseProperties;
+  };
+
+  static_assert(sizeof(PhysicalDeviceProperties) == 824, "PhysicalDeviceProperties must match VkPhysicalDeviceProperties");
+
+  struct PhysicalDeviceProperties2 {
+    types::i32               sType;
+    void*                    pNext;
+    PhysicalDeviceProperties properties;
+  };
+
+  struct PhysicalDeviceDriverProperties {
+    types::i32                 sType;
+    void*                      pNext;
+    types::i32                 driverID;
+    types::Array<char, 256>    driverName;
+    types::Array<char, 256>    driverInfo;
+    types::Array<types::u8, 4> conformanceVersion;
+  };
+
+  using VoidFunction                   = void (*)();
+  using GetInstanceProcAddrFn          = VoidFunction (*)(Instance, types::PCStr);
+  using EnumerateInstanceVersionFn     = types::i32 (*)(types::u32*);
+  using CreateInstanceFn               = types::i32 (*)(const InstanceCreateInfo*, const void*, Instance*);
+  using DestroyInstanceFn              = void (*)(Instance, const void*);
+  using EnumeratePhysicalDevicesFn     = types::i32 (*)(Instance, types::u32*, PhysicalDevice*);
+  using GetPhysicalDevicePropertiesFn  = void (*)(PhysicalDevice, PhysicalDeviceProperties*);
+  using GetPhysicalDeviceProperties2Fn = void (*)(PhysicalDevice, PhysicalDeviceProperties2*);
+  // NOLINTEND(readability-identifier-naming)
+
+  /**
+   * @brief Formats a packed Vulkan version as "major.minor.patch".
+   */
+  [[nodiscard]] inline auto FormatApiVersion(const types::u32 version) -> types::String {
+    return std::format("{}.{}.{}", (version >> 22) & 0x7F, (version >> 12) & 0x3FF, version & 0xFFF);
+  }
+
+  /**
+   * @brief Decodes `VkPhysicalDeviceProperties::driverVersion`, whose packing is up to each vendor.
+   */
+  [[nodiscard]] inline auto FormatDriverVersion(const types::u32 vendorId, const types::u32 version) -> types::String {
+    // NVIDIA packs 10/8/8/6 bits; the last field is unused, and a zero third field is omitted like in "552.44".
+    if (vendorId == VENDOR_NVIDIA) {
+      const types::u32 patch = (version >> 6) & 0xFF;
+
+      return patch == 0 ? std::format("{}.{:02}", version >> 22, (version >> 14) & 0xFF)
+                        : std::format("{}.{:02}.{:02}", version >> 22, (version >> 14) & 0xFF, patch);
+    }
+
+#ifdef _WIN32
+    // Intel's Windows driver packs the last two fields of its "31.0.101.5333" version into 18/14 bits.
+    if (vendorId == VENDOR_INTEL)
+      return std::format("{}.{}", version >> 14, version & 0x3FFF);
+#endif
+
+    return FormatApiVersion(version);
+  }
+
+  /**
+   * @brief Opens the Vulkan loader once and resolves its only exported entry point that's needed.
+   * @return `vkGetInstanceProcAddr`, or nullptr if no loader is installed.
+   */
+  [[nodiscard]] inline auto LoadGetInstanceProcAddr() -> GetInstanceProcAddrFn {
+#ifdef _WIN32
+    // Restricted to System32, where the loader is always installed, so a planted DLL can't be picked up instead.
+    static const HMODULE Loader = LoadLibraryExW(L"vulkan-1.dll", nullptr, LOAD_LIBRARY_SEARCH_SYSTEM32);
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - Entry points are resolved as untyped procedures.
+    return Loader ? reinterpret_cast<GetInstanceProcAddrFn>(GetProcAddress(Loader, "vkGetInstanceProcAddr")) : nullptr;
+#else
+    static types::RawPointer Loader = dlopen("libvulkan.so.1", RTLD_LAZY | RTLD_LOCAL);
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - Entry points are resolved as untyped procedures.
+    return Loader ? reinterpret_cast<GetInstanceProcAddrFn>(dlsym(Loader, "vkGetInstanceProcAddr")) : nullptr;
+#endif
+  }
+
+  /**
+   * @brief Lists the physical devices of every installed Vulkan driver.
+   * @return The devices, or an error if no loader is installed or an instance can't be created.
+   *
+   * @details Creating an instance loads every installed driver, which can take a noticeable fraction of a
+   * second, so callers should cache the result.
+   */
+  [[nodiscard]] inline auto GetDevices() -> types::Result<types::Vec<Device>> {
+    const GetInstanceProcAddrFn getInstanceProcAddr = LoadGetInstanceProcAddr();
+
+    if (!getInstanceProcAddr)
+      ERR(NotFound, "The Vulkan loader is not installed");
+
+    // NOLINTBEGIN(*-pro-type-reinterpret-cast) - Entry points are resolved as untyped procedures.
+    const auto createInstance = reinterpret_cast<CreateInstanceFn>(getInstanceProcAddr(nullptr, "vkCreateInstance"));
+
+    if (!createInstance)
+      ERR(ApiUnavailable, "The Vulkan loader doesn't provide vkCreateInstance");
+
+    // Vulkan 1.0 loaders lack vkEnumerateInstanceVersion and reject instances that ask for anything newer.
+    types::u32 loaderVersion = API_VERSION_1_0;
+
+    if (const auto enumerateInstanceVersion = reinterpret_cast<EnumerateInstanceVersionFn>(getInstanceProcAddr(nullptr, "vkEnumerateInstanceVersion")))
+      if (enumerateInstanceVersion(&loaderVersion) != 0)
+        loaderVersion = API_VERSION_1_0;
+
+    const ApplicationInfo appInfo {
+      .sType              = STRUCTURE_TYPE_APPLICATION_INFO,
+      .pNext              = nullptr,
+      .pApplicationName   = "draconis++",
+      .applicationVersion = 0,
+      .pEngineName        = nullptr,
+      .engineVersion      = 0,
+      .apiVersion         = std::min(loaderVersion, API_VERSION_1_2),
+    };
+
+    const InstanceCreateInfo createInfo {
+      .sType                   = STRUCTURE_TYPE_INSTANCE_CREATE_INFO,
+      .pNext                   = nullptr,
+      .flags                   = 0,
+      .pApplicationInfo        = &appInfo,
+      .enabledLayerCount       = 0,
+      .ppEnabledLayerNames     = nullptr,
+      .enabledExtensionCount   = 0,
+      .ppEnabledExtensionNames = nullptr,
+    };
+
+    Instance instance = nullptr;
+
+    if (const types::i32 result = createInstance(&createInfo, nullptr, &instance); result != 0)
+      ERR_FMT(ApiUnavailable, "vkCreateInstance failed with VkResult {}", result);
+
+    const auto destroyInstance          = reinterpret_cast<DestroyInstanceFn>(getInstanceProcAddr(instance, "vkDestroyInstance"));
+    const auto enumeratePhysicalDevices = reinterpret_cast<EnumeratePhysicalDevicesFn>(getInstanceProcAddr(instance, "vkEnumeratePhysicalDevices"));
+    const auto getProperties            = reinterpret_cast<GetPhysicalDevicePropertiesFn>(getInstanceProcAddr(instance, "vkGetPhysicalDeviceProperties"));
+
+    // Core since Vulkan 1.1, so only instances that asked for 1.1 or newer can resolve it.
+    const auto getProperties2 = appInfo.apiVersion >= API_VERSION_1_1 ? reinterpret_cast<GetPhysicalDeviceProperties2Fn>(getInstanceProcAddr(instance, "vkGetPhysicalDeviceProperties2")) : nullptr;
+    // NOLINTEND(*-pro-type-reinterpret-cast)
+
+    types::Vec<Device>         devices;
+    types::Vec<PhysicalDevice> physicalDevices;
+    types::u32                 count = 0;
+
+    // VK_INCOMPLETE (a positive result) still fills the array, in case a device appeared in between.
+    if (enumeratePhysicalDevices && getProperties && enumeratePhysicalDevices(instance, &count, nullptr) == 0) {
+      physicalDevices.resize(count);
+
+      if (enumeratePhysicalDevices(instance, &count, physicalDevices.data()) < 0)
+        count = 0;
+
+      physicalDevices.resize(std::min<types::usize>(count, physicalDevices.size()));
+    }
+
+    for (PhysicalDevice physicalDevice : physicalDevices) {
+      PhysicalDeviceProperties2      properties { .sType = STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2, .pNext = nullptr, .properties = {} };
+      PhysicalDeviceDriverProperties driverProperties { .sType = STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES, .pNext = nullptr };
+
+      getProperties(physicalDevice, &properties.properties);
+
+      // The driver properties only exist from Vulkan 1.2, and chaining them to an older device is invalid.
+      if (getProperties2 && properties.properties.apiVersion >= API_VERSION_1_2) {
+        properties.pNext = &driverProperties;
+        getProperties2(physicalDevice, &properties);
+      }
+
+      const types::String driverInfo(driverProperties.driverInfo.data());
+
+      devices.push_back({
+        .vendorId      = properties.properties.vendorID,
+        .deviceId      = properties.properties.deviceID,
+        .apiVersion    = FormatApiVersion(properties.properties.apiVersion),
+        .driverVersion = driverInfo.empty() ? FormatDriverVersion(properties.properties.vendorID, properties.properties.driverVersion) : driverInfo,
+      });
+    }
+
+    if (destroyInstance)
+      destroyInstance(instance, nullptr);
+
+    return devices;
+  }
+} // namespace draconis::os::vulkan
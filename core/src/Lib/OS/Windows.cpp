@@ -24,24 +24,76 @@
     #include <intrin.h> // __cpuid (MSVC/Clang-cl intrinsic)
   #endif
 
-  #include <dxgi.h>       // IDXGIFactory, IDXGIAdapter, DXGI_ADAPTER_DESC
-  #include <ranges>       // std::ranges::find_if, std::ranges::views::transform
-  #include <sysinfoapi.h> // GetLogicalProcessorInformationEx, RelationProcessorCore, PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX, KAFFINITY
-  #include <tlhelp32.h>   // CreateToolhelp32Snapshot, PROCESSENTRY32W, Process32FirstW, Process32NextW, TH32CS_SNAPPROCESS
-  #include <winerror.h>   // DXGI_ERROR_NOT_FOUND, ERROR_FILE_NOT_FOUND, FAILED
-  #include <winuser.h>    // EnumDisplayMonitors, GetMonitorInfoW, MonitorFromWindow, EnumDisplaySettingsW
+  #include <algorithm>                          // std::min, std::ranges::equal
+  #include <batclass.h>                         // BATTERY_QUERY_INFORMATION, BATTERY_STATUS, IOCTL_BATTERY_QUERY_TAG, IOCTL_BATTERY_QUERY_INFORMATION, IOCTL_BATTERY_QUERY_STATUS
+  #include <bit>                                // std::popcount
+  #include <cctype>                             // std::tolower
+  #include <chrono>                             // std::chrono::current_zone
+  #include <condition_variable>                 // std::condition_variable
+  #include <d3d11.h>                            // PFN_D3D11_CREATE_DEVICE, D3D11_SDK_VERSION
+  #include <d3d12.h>                            // PFN_D3D12_CREATE_DEVICE, ID3D12Device, D3D12_FEATURE_DATA_FEATURE_LEVELS
+  #include <dbt.h>                              // DBT_DEVICEARRIVAL, DBT_DEVICEREMOVECOMPLETE, DEV_BROADCAST_DEVICEINTERFACE_W
+  #include <dxgi.h>                             // IDXGIFactory, IDXGIAdapter, DXGI_ADAPTER_DESC
+  #include <fstream>                            // std::ifstream
+  #include <future>                             // std::promise
+  #include <highlevelmonitorconfigurationapi.h> // GetMonitorBrightness
+  #include <limits>                             // std::numeric_limits
+  #include <lm.h>                               // NetGetJoinInformation, NetGetAadJoinInformation, NetApiBufferFree
+  #include <lmcons.h>                           // UNLEN
+  #include <physicalmonitorenumerationapi.h>    // GetNumberOfPhysicalMonitorsFromHMONITOR, GetPhysicalMonitorsFromHMONITOR, DestroyPhysicalMonitors
+  #include <powerbase.h>                        // CallNtPowerInformation
+  #include <powersetting.h>                     // PowerGetActiveScheme, PowerReadFriendlyName
+  #include <psapi.h>                            // EnumDeviceDrivers, GetDeviceDriverBaseNameW, GetPerformanceInfo, PERFORMANCE_INFORMATION
+  #include <shlwapi.h>                          // AssocQueryStringW
+  #include <sddl.h>                             // ConvertSidToStringSidW
+  #include <setupapi.h>                         // SetupDiGetClassDevsW, SetupDiEnumDeviceInterfaces, SetupDiGetDeviceInterfaceDetailW
+  #include <shellscalingapi.h>                  // GetDpiForMonitor, MDT_EFFECTIVE_DPI, MDT_RAW_DPI
+  #include <stdexcept>                          // std::runtime_error
+  #include <ranges>                             // std::ranges::find_if, std::ranges::views::transform
+  #include <sysinfoapi.h>                       // GetLogicalProcessorInformationEx, RelationProcessorCore, PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX, KAFFINITY
+  #include <tbs.h>                              // Tbsi_GetDeviceInfo, Tbsi_Get_TCG_Log_Ex, TPM_DEVICE_INFO
+  #include <wbemidl.h>                          // IWbemLocator, IWbemServices, IEnumWbemClassObject, IWbemClassObject
+  #include <thread>                       // std::thread
+  #include <tlhelp32.h>                         // CreateToolhelp32Snapshot, PROCESSENTRY32W, Process32FirstW, Process32NextW, TH32CS_SNAPPROCESS
+  #include <tuple>                              // std::tie
+  #include <winerror.h>                         // DXGI_ERROR_NOT_FOUND, ERROR_FILE_NOT_FOUND, FAILED
+  #include <winioctl.h>                         // DISK_PERFORMANCE, IOCTL_DISK_PERFORMANCE, IOCTL_STORAGE_QUERY_PROPERTY, IOCTL_STORAGE_PREDICT_FAILURE, STORAGE_PROTOCOL_SPECIFIC_DATA
+  #include <winnetwk.h>                         // WNetOpenEnumW, WNetEnumResourceW, NETRESOURCEW
+  #include <winspool.h>                         // EnumPrintersW, GetDefaultPrinterW, PRINTER_INFO_2W
+  #include <winsvc.h>                           // OpenSCManagerW, OpenServiceW, QueryServiceStatusEx, QueryServiceConfigW, EnumServicesStatusExW
+  #include <winternl.h>                         // NtQuerySystemInformation, SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION
+  #include <d3dkmthk.h>                         // D3DKMTOpenAdapterFromLuid, D3DKMTQueryAdapterInfo, D3DKMT_ADAPTER_PERFDATA (needs NTSTATUS from winternl.h)
+  #include <winuser.h>                          // EnumDisplayMonitors, GetMonitorInfoW, MonitorFromWindow, EnumDisplaySettingsW, GetKeyboardLayoutList
+  #include <wtsapi32.h>                         // WTSEnumerateSessionsW, WTSQuerySessionInformationW, WTSFreeMemory
 
   // Core Winsock headers
   #include <winsock2.h> // AF_INET, AF_UNSPEC, sockaddr_in
   #include <ws2tcpip.h> // inet_ntop, inet_pton
 
   // IP Helper API headers
-  #include <iphlpapi.h> // GetAdaptersAddresses, GetBestRoute
+  #include <iphlpapi.h> // GetAdaptersAddresses, GetBestRoute, GetIfEntry2, GetIfTable2, NotifyIpInterfaceChange
   #include <iptypes.h>  // GAA_FLAG_INCLUDE_PREFIX, IP_ADAPTER_ADDRESSES, IP_ADAPTER_UNICAST_ADDRESS
 
+  // Bluetooth headers
+  #include <bluetoothapis.h> // BluetoothFindFirstRadio, BluetoothGetRadioInfo, BluetoothFindFirstDevice
+
+  // Native Wi-Fi headers
+  #include <wlanapi.h> // WlanOpenHandle, WlanEnumInterfaces, WlanQueryInterface, WLAN_CONNECTION_ATTRIBUTES
+
   // COM smart pointer support
   #include <wrl/client.h> // Microsoft::WRL::ComPtr
 
+  // Core Audio headers. initguid.h must come first so the PKEY_* constants are defined in this TU.
+  // clang-format off
+  #include <initguid.h>
+  #include <mmdeviceapi.h>                  // IMMDeviceEnumerator, IMMDevice, PKEY_AudioEngine_DeviceFormat
+  #include <endpointvolume.h>               // IAudioEndpointVolume
+  #include <functiondiscoverykeys_devpkey.h> // PKEY_Device_FriendlyName
+  // clang-format on
+
+  #include <glaze/core/meta.hpp> // glz::detail::Object, glz::object
+  #include <glaze/json/read.hpp> // glz::read, glz::format_error
+
   #include "Drac++/Core/System.hpp"
 
   #if DRAC_ENABLE_PACKAGECOUNT
@@ -53,6 +105,12 @@
   #include "Drac++/Utils/Error.hpp"
   #include "Drac++/Utils/Types.hpp"
 
+  #include "OS/Containers.hpp"
+  #include "OS/Edid.hpp"
+  #include "OS/Smart.hpp"
+  #include "OS/Virtualization.hpp"
+  #include "OS/Vulkan.hpp"
+
 namespace {
   using draconis::utils::error::DracError;
   using enum draconis::utils::error::DracErrorCode;
@@ -64,9 +122,52 @@ namespace {
     constexpr PWCStr PRODUCT_NAME        = L"ProductName";
     constexpr PWCStr DISPLAY_VERSION     = L"DisplayVersion";
     constexpr PWCStr SYSTEM_FAMILY       = L"SystemFamily";
+    constexpr PWCStr SYSTEM_MANUFACTURER = L"SystemManufacturer";
     constexpr PWCStr SYSTEM_PRODUCT_NAME = L"SystemProductName";
 
+    // Registry values mirroring the SMBIOS baseboard and firmware information
+    constexpr PWCStr BASEBOARD_MANUFACTURER = L"BaseBoardManufacturer";
+    constexpr PWCStr BASEBOARD_PRODUCT      = L"BaseBoardProduct";
+    constexpr PWCStr BASEBOARD_VERSION      = L"BaseBoardVersion";
+    constexpr PWCStr BIOS_VENDOR            = L"BIOSVendor";
+    constexpr PWCStr BIOS_VERSION           = L"BIOSVersion";
+    constexpr PWCStr BIOS_RELEASE_DATE      = L"BIOSReleaseDate";
+
+    // Per-installation identifier generated by Windows setup
+    constexpr PWCStr MACHINE_GUID = L"MachineGuid";
+
+    // Built-in power plans, as listed by `powercfg /list`
+    constexpr GUID POWER_SAVER_SCHEME          = { 0xa1841308, 0x3541, 0x4fab, { 0xbc, 0x81, 0xf7, 0x15, 0x56, 0xf2, 0x0b, 0x4a } };
+    constexpr GUID BALANCED_SCHEME             = { 0x381b4222, 0xf694, 0x41f0, { 0x96, 0x85, 0xff, 0x5b, 0xb2, 0x60, 0xdf, 0x2e } };
+    constexpr GUID HIGH_PERFORMANCE_SCHEME     = { 0x8c5e7fda, 0xe8bf, 0x4a96, { 0x9a, 0x85, 0xa6, 0xe2, 0x3a, 0x8c, 0x63, 0x5c } };
+    constexpr GUID ULTIMATE_PERFORMANCE_SCHEME = { 0xe9a42b02, 0xd5df, 0x448d, { 0xaa, 0x00, 0x03, 0xf1, 0x47, 0x49, 0xeb, 0x61 } };
+
+    // GUID_DEVCLASS_BATTERY, which is also the interface class battery drivers register
+    constexpr GUID BATTERY_DEVICE_CLASS = { 0x72631e54, 0x78a4, 0x11d0, { 0xbc, 0xf7, 0x00, 0xaa, 0x00, 0xb7, 0xb3, 0x2a } };
+
+    // KSCATEGORY_VIDEO_CAMERA, registered by camera drivers since Windows 10; ksmedia.h only declares it for kernel streaming clients
+    constexpr GUID VIDEO_CAMERA_INTERFACE = { 0xe5323777, 0xf976, 0x4f5b, { 0x9b, 0x55, 0xb9, 0x46, 0x99, 0xc4, 0x6e, 0x44 } };
+
+    // GUID_DEVINTERFACE_USB_DEVICE and GUID_DEVINTERFACE_DISK, the hotplug notifications SystemWatcher subscribes to
+    constexpr GUID USB_DEVICE_INTERFACE = { 0xa5dcbf10, 0x6530, 0x11d2, { 0x90, 0x1f, 0x00, 0xc0, 0x4f, 0xb9, 0x51, 0xed } };
+    constexpr GUID DISK_INTERFACE       = { 0x53f56307, 0xb6bf, 0x11d0, { 0x94, 0xf2, 0x00, 0xa0, 0xc9, 0x1e, 0xfb, 0x8b } };
+
+    // NtQuerySystemInformation classes read by Microsoft's SpeculationControl module, which winternl.h doesn't name
+    constexpr auto KERNEL_VA_SHADOW_INFORMATION    = static_cast<SYSTEM_INFORMATION_CLASS>(196);
+    constexpr auto SPECULATION_CONTROL_INFORMATION = static_cast<SYSTEM_INFORMATION_CLASS>(201);
+
     // clang-format off
+    // IsProcessorFeaturePresent values for Arm64, named as in Linux's /proc/cpuinfo. Spelled out since older SDKs lack the SVE ones.
+    constexpr Array<Pair<DWORD, PCStr>, 7> armProcessorFeatures = {{
+      { 19,   "asimd" }, // PF_ARM_NEON_INSTRUCTIONS_AVAILABLE
+      { 30,     "aes" }, // PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE
+      { 31,   "crc32" }, // PF_ARM_V8_CRC32_INSTRUCTIONS_AVAILABLE
+      { 34, "atomics" }, // PF_ARM_V81_ATOMIC_INSTRUCTIONS_AVAILABLE
+      { 43, "asimddp" }, // PF_ARM_V82_DP_INSTRUCTIONS_AVAILABLE
+      { 46,     "sve" }, // PF_ARM_SVE_INSTRUCTIONS_AVAILABLE
+      { 47,    "sve2" }, // PF_ARM_SVE2_INSTRUCTIONS_AVAILABLE
+    }};
+
     constexpr Array<Pair<StringView, StringView>, 5> windowsShellMap = {{
       {      "cmd",     "Command Prompt" },
       { "powershell",       "PowerShell" },
@@ -85,6 +186,18 @@ namespace {
       { "dash",      "dash" },
     }};
 
+    constexpr Array<Pair<StringView, StringView>, 9> windowsTerminalMap = {{
+      { "windowsterminal", "Windows Terminal" },
+      {     "wezterm-gui",          "WezTerm" },
+      {       "alacritty",        "Alacritty" },
+      {          "mintty",           "mintty" },
+      {            "code",          "VS Code" },
+      {           "hyper",            "Hyper" },
+      {           "tabby",            "Tabby" },
+      {         "conemu64",           "ConEmu" },
+      {         "conhost",  "Windows Console" },
+    }};
+
     constexpr Array<Pair<StringView, StringView>, 4> windowManagerMap = {{
       {     "glazewm",   "GlazeWM" },
       {    "komorebi",  "Komorebi" },
@@ -206,6 +319,26 @@ namespace {
       ERR_FMT(ParseError, "Registry value exists but is not a string type. Type is: {}", type);
     }
 
+    // Reads one of the computer's names, sizing the buffer with a first call.
+    auto GetComputerNameUTF8(const COMPUTER_NAME_FORMAT format) -> Result<String> {
+      DWORD size = 0;
+
+      if (GetComputerNameExW(format, nullptr, &size) || GetLastError() != ERROR_MORE_DATA)
+        ERR_FMT(ApiUnavailable, "GetComputerNameExW failed with error code {}", GetLastError());
+
+      WString name(size, L'\0');
+
+      if (!GetComputerNameExW(format, name.data(), &size))
+        ERR_FMT(ApiUnavailable, "GetComputerNameExW failed with error code {}", GetLastError());
+
+      name.resize(size);
+
+      if (name.empty())
+        ERR(NotFound, "The computer has no name of the requested format");
+
+      return ConvertWStringToUTF8(name);
+    }
+
   } // namespace helpers
 
   namespace cache {
@@ -597,502 +730,3559 @@ namespace {
     }
   } // namespace shell
 
-  auto GetDiskInfoForDrive(const String& driveRoot, CacheManager& /*cache*/) -> Result<DiskInfo> {
-    DiskInfo disk;
+  namespace terminal {
+    using glz::detail::Object, glz::object;
 
-    // Set name and mount point (same for Windows drives)
-    disk.name       = driveRoot;
-    disk.mountPoint = driveRoot;
+    // Only the keys needed from Windows Terminal's settings.json; everything else is ignored.
+    struct WindowsTerminalSettings {
+      struct Font {
+        Option<String> face;
+        Option<f64>    size;
 
-    // Get drive type
-    UINT driveType = GetDriveTypeA(driveRoot.c_str());
-    switch (driveType) {
-      case DRIVE_FIXED:
-        disk.driveType = "Fixed";
-        break;
-      case DRIVE_REMOVABLE:
-        disk.driveType = "Removable";
-        break;
-      case DRIVE_CDROM:
-        disk.driveType = "CD-ROM";
-        break;
-      case DRIVE_REMOTE:
-        disk.driveType = "Network";
-        break;
-      case DRIVE_RAMDISK:
-        disk.driveType = "RAM Disk";
-        break;
-      default:
-        disk.driveType = "Unknown";
-        break;
-    }
+        // NOLINTBEGIN(readability-identifier-naming)
+        struct glaze {
+          using T = Font;
+          static constexpr Object value = object("face", &T::face, "size", &T::size);
+        };
+        // NOLINTEND(readability-identifier-naming)
+      };
 
-    // Get filesystem type
-    Array<char, MAX_PATH> filesystem = {};
-    if (GetVolumeInformationA(driveRoot.c_str(), nullptr, 0, nullptr, nullptr, nullptr, filesystem.data(), MAX_PATH))
-      disk.filesystem = filesystem.data();
-    else
-      disk.filesystem = "Unknown";
+      struct Defaults {
+        Font font;
 
-    // Get disk space information
-    ULARGE_INTEGER freeBytes, totalBytes, totalFreeBytes;
-    if (GetDiskFreeSpaceExA(driveRoot.c_str(), &freeBytes, &totalBytes, &totalFreeBytes)) {
-      disk.totalBytes = totalBytes.QuadPart;
-      disk.usedBytes  = totalBytes.QuadPart - freeBytes.QuadPart;
-    } else {
-      // If we can't get space info, set to 0
-      disk.totalBytes = 0;
-      disk.usedBytes  = 0;
-    }
+        // NOLINTBEGIN(readability-identifier-naming)
+        struct glaze {
+          using T = Defaults;
+          static constexpr Object value = object("font", &T::font);
+        };
+        // NOLINTEND(readability-identifier-naming)
+      };
 
-    // Get system drive for comparison
-    Array<char, MAX_PATH> systemDir = {};
-    GetSystemDirectoryA(systemDir.data(), MAX_PATH);
-    char systemDrive = systemDir.front();
+      struct Profiles {
+        Defaults defaults;
 
-    // Check if this is the system drive
-    disk.isSystemDrive = (driveRoot[0] == systemDrive);
+        // NOLINTBEGIN(readability-identifier-naming)
+        struct glaze {
+          using T = Profiles;
+          static constexpr Object value = object("defaults", &T::defaults);
+        };
+        // NOLINTEND(readability-identifier-naming)
+      };
 
-    return disk;
-  }
-} // namespace
+      Profiles profiles;
 
-namespace draconis::core::system {
-  using namespace draconis::utils::types;
-  using draconis::utils::cache::CacheManager;
-  using namespace cache;
-  using namespace constants;
-  using namespace helpers;
+      // NOLINTBEGIN(readability-identifier-naming)
+      struct glaze {
+        using T = WindowsTerminalSettings;
+        static constexpr Object value = object("profiles", &T::profiles);
+      };
+      // NOLINTEND(readability-identifier-naming)
+    };
 
-  auto GetMemInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
-    // Passed to GlobalMemoryStatusEx to retrieve memory information.
-    // dwLength is required to be set as per WinAPI.
-    MEMORYSTATUSEX memInfo;
-    memInfo.dwLength = sizeof(MEMORYSTATUSEX);
+    auto GetWindowsTerminalFont() -> Result<String> {
+      using draconis::utils::env::GetEnv;
 
-    if (GlobalMemoryStatusEx(&memInfo))
-      return ResourceUsage(memInfo.ullTotalPhys - memInfo.ullAvailPhys, memInfo.ullTotalPhys);
+      const String localAppData = TRY(GetEnv("LOCALAPPDATA"));
+      const String settingsPath = std::format(R"({}\Packages\Microsoft.WindowsTerminal_8wekyb3d8bbwe\LocalState\settings.json)", localAppData);
 
-    ERR_FMT(ApiUnavailable, "GlobalMemoryStatusEx failed with error code {}", GetLastError());
-  }
+      std::ifstream file(settingsPath);
 
-  auto GetOperatingSystem(CacheManager& cache) -> Result<OSInfo> {
-    return cache.getOrSet<OSInfo>("windows_os_version", []() -> Result<OSInfo> {
-      // Windows is weird about its versioning scheme, and Windows 11 is still
-      // considered Windows 10 in the registry. We have to manually check if
-      // the actual version is Windows 11 by checking the build number.
-      constexpr PWCStr windows10 = L"Windows 10";
-      constexpr PWCStr windows11 = L"Windows 11";
+      if (!file)
+        ERR_FMT(NotFound, "Could not open {}", settingsPath);
 
-      constexpr usize windowsLen = std::char_traits<WCStr>::length(windows10);
+      const String buffer((std::istreambuf_iterator<char>(file)), std::istreambuf_iterator<char>());
 
-      const RegistryCache& registry = RegistryCache::getInstance();
+      WindowsTerminalSettings settings;
 
-      HKEY currentVersionKey = registry.getCurrentVersionKey();
+      if (const glz::error_ctx errorContext = glz::read<glz::opts { .error_on_unknown_keys = false }>(settings, buffer))
+        ERR_FMT(ParseError, "Failed to parse {}: {}", settingsPath, glz::format_error(errorContext, buffer));
 
-      if (!currentVersionKey)
-        ERR(NotFound, "Failed to open registry key");
+      const WindowsTerminalSettings::Font& font = settings.profiles.defaults.font;
 
-      WString productName = TRY(GetRegistryValue(currentVersionKey, PRODUCT_NAME));
+      // Windows Terminal falls back to Cascadia Mono 12 when the defaults don't override it.
+      return std::format("{} {}", font.face.value_or("Cascadia Mono"), font.size.value_or(12.0));
+    }
+  } // namespace terminal
 
-      if (productName.empty())
-        ERR(NotFound, "ProductName not found in registry");
+  namespace audio {
+    /**
+     * @brief Runs a callback against the volume control of the default render endpoint.
+     * @param callback Invoked with the endpoint's IAudioEndpointVolume; its Result is returned as-is.
+     */
+    template <typename Callback>
+    auto WithDefaultEndpointVolume(Callback&& callback) -> std::invoke_result_t<Callback, IAudioEndpointVolume*> {
+      using Microsoft::WRL::ComPtr;
 
-      // Build 22000+ of Windows are all considered Windows 11, so we can safely replace the product name
-      // if it's currently "Windows 10" and the build number is greater than or equal to 22000.
-      if (const Result<u64> buildNumberOpt = OsVersionCache::getInstance().getBuildNumber())
-        if (const u64 buildNumber = *buildNumberOpt; buildNumber >= 22000)
-          if (const size_t pos = productName.find(windows10); pos != WString::npos) {
-            // Make sure we're not replacing a substring of a larger string. Should never happen,
-            // but if it ever does, we'll just leave the product name unchanged.
-            const bool startBoundary = (pos == 0 || !iswalnum(productName.at(pos - 1)));
-            const bool endBoundary   = (pos + windowsLen == productName.length() || !iswalnum(productName.at(pos + windowsLen)));
+      const HRESULT initResult = CoInitializeEx(nullptr, COINIT_MULTITHREADED);
 
-            if (startBoundary && endBoundary)
-              productName.replace(pos, windowsLen, windows11);
-          }
+      if (FAILED(initResult) && initResult != RPC_E_CHANGED_MODE)
+        ERR_FMT(ApiUnavailable, "CoInitializeEx failed with HRESULT {:#x}", static_cast<u32>(initResult));
 
-      // Append the display version if it exists.
-      WString displayVersion = TRY(GetRegistryValue(currentVersionKey, DISPLAY_VERSION));
+      // Only balance the CoInitializeEx call if it actually took effect on this thread.
+      const bool shouldUninitialize = SUCCEEDED(initResult);
 
-      String productNameUTF8 = TRY(ConvertWStringToUTF8(productName));
+      const auto query = [&]() -> std::invoke_result_t<Callback, IAudioEndpointVolume*> {
+        ComPtr<IMMDeviceEnumerator> enumerator;
 
-      String displayVersionUTF8 = TRY(ConvertWStringToUTF8(displayVersion));
+        if (FAILED(CoCreateInstance(__uuidof(MMDeviceEnumerator), nullptr, CLSCTX_INPROC_SERVER, IID_PPV_ARGS(&enumerator))))
+          ERR(ApiUnavailable, "Failed to create MMDeviceEnumerator");
 
-      return OSInfo(productNameUTF8, displayVersionUTF8, "windows");
-    });
-  }
+        ComPtr<IMMDevice> device;
 
-  auto GetHost(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("windows_host", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
-      // Read from BIOS registry key which contains system product information
-      HKEY biosKey = nullptr;
+        if (FAILED(enumerator->GetDefaultAudioEndpoint(eRender, eConsole, &device)))
+          ERR(NotFound, "No default audio output device");
 
-      if (RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"HARDWARE\\DESCRIPTION\\System\\BIOS", 0, KEY_READ, &biosKey) != ERROR_SUCCESS)
-        ERR(NotFound, "Failed to open BIOS registry key");
+        ComPtr<IAudioEndpointVolume> endpointVolume;
 
-      RegistryKey biosKeyGuard(biosKey);
+        if (FAILED(device->Activate(__uuidof(IAudioEndpointVolume), CLSCTX_INPROC_SERVER, nullptr, &endpointVolume)))
+          ERR(NotSupported, "The default output device has no volume control");
 
-      // Try SystemFamily first (e.g., "ASUS TUF Gaming F15"), then fall back to SystemProductName
-      if (Result<WString> systemFamily = GetRegistryValue(biosKey, SYSTEM_FAMILY); systemFamily)
-        return ConvertWStringToUTF8(*systemFamily);
+        return callback(endpointVolume.Get());
+      };
 
-      if (Result<WString> productName = GetRegistryValue(biosKey, SYSTEM_PRODUCT_NAME); productName)
-        return ConvertWStringToUTF8(*productName);
+      auto result = query();
 
-      ERR(NotFound, "Failed to get system family or product name from BIOS registry");
-    });
-  }
+      if (shouldUninitialize)
+        CoUninitialize();
 
-  auto GetKernelVersion(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("windows_kernel_version", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
-      // See the OsVersionCache class for how the version data is retrieved.
-      const auto& [majorVersion, minorVersion, buildNumber] = TRY(OsVersionCache::getInstance().getVersionData());
+      return result;
+    }
+  } // namespace audio
 
-      return std::format("{}.{}.{}", majorVersion, minorVersion, buildNumber);
-    });
-  }
+  namespace process {
+    using cache::HandleWrapper;
 
-  auto GetWindowManager(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("windows_wm", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
-      if (!cache::ProcessTreeCache::getInstance().initialize())
-        ERR(PlatformSpecific, "Failed to initialize process tree cache");
+    // Not exposed by winternl.h, but documented for NtQueryInformationProcess since Windows 8.1.
+    constexpr auto ProcessCommandLineInformation = static_cast<PROCESSINFOCLASS>(60);
 
-      for (const auto& [parentPid, baseExeNameLower] : cache::ProcessTreeCache::getInstance().getProcessMap() | std::views::values) {
-        const StringView processName = baseExeNameLower;
+    auto QueryCommandLine(HANDLE process) -> Option<String> {
+      ULONG length = 0;
+      NtQueryInformationProcess(process, ProcessCommandLineInformation, nullptr, 0, &length);
 
-        if (
-          const auto mapIter =
-            std::ranges::find_if(windowManagerMap, [&](const Pair<StringView, StringView>& pair) -> bool {
-              return processName == pair.first;
-            });
-          mapIter != std::ranges::end(windowManagerMap)
-        )
-          return String(mapIter->second);
-      }
+      if (length < sizeof(UNICODE_STRING))
+        return None;
 
-      return "DWM";
-    });
-  }
+      // The result is a UNICODE_STRING whose buffer points into the same allocation.
+      Vec<BYTE> buffer(length);
 
-  auto GetDesktopEnvironment(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("windows_desktop_environment", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
-      // Windows doesn't really have the concept of a desktop environment,
-      // so our next best bet is just displaying the UI design based on the build number.
+      if (NtQueryInformationProcess(process, ProcessCommandLineInformation, buffer.data(), length, &length) < 0)
+        return None;
 
-      u64 build = TRY(OsVersionCache::getInstance().getBuildNumber());
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - the buffer is laid out as a UNICODE_STRING by the kernel
+      const auto* cmdline = reinterpret_cast<const UNICODE_STRING*>(buffer.data());
 
-      if (build >= 15063)
-        return "Fluent";
+      if (cmdline->Buffer == nullptr || cmdline->Length == 0)
+        return None;
 
-      if (build >= 9200)
-        return "Metro";
+      if (Result<String> converted = helpers::ConvertWStringToUTF8(WString(cmdline->Buffer, cmdline->Length / sizeof(WCHAR))))
+        return *converted;
 
-      if (build >= 6000)
-        return "Aero";
+      return None;
+    }
 
-      return "Classic";
-    });
-  }
+    auto QueryUser(HANDLE process) -> Option<String> {
+      HANDLE rawToken = nullptr;
 
-  auto GetShell(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("windows_shell", draconis::utils::cache::CachePolicy::tempDirectory(), []() -> Result<String> {
-      using draconis::utils::env::GetEnv;
-      using shell::FindShellInProcessTree;
+      if (!OpenProcessToken(process, TOKEN_QUERY, &rawToken))
+        return None;
 
-      // MSYS2 environments automatically set the MSYSTEM environment variable.
-      if (const Result<String> msystemResult = GetEnv("MSYSTEM"); msystemResult && !msystemResult->empty()) {
-        String shellPath;
+      const HandleWrapper<HANDLE> token(rawToken);
 
-        // The SHELL environment variable should basically always be set.
-        if (const Result<String> shellResult = GetEnv("SHELL"); shellResult && !shellResult->empty())
-          shellPath = *shellResult;
+      DWORD length = 0;
+      GetTokenInformation(token.get(), TokenUser, nullptr, 0, &length);
 
-        if (!shellPath.empty()) {
-          // Get the executable name from the path.
-          const usize lastSlash = shellPath.find_last_of("\\/");
-          String      shellExe  = (lastSlash != String::npos) ? shellPath.substr(lastSlash + 1) : shellPath;
+      if (length == 0)
+        return None;
 
-          std::ranges::transform(shellExe, shellExe.begin(), [](const u8 character) { return std::tolower(character); });
+      Vec<BYTE> buffer(length);
 
-          // Remove the .exe extension if it exists.
-          if (shellExe.ends_with(".exe"))
-            shellExe.resize(shellExe.length() - 4);
+      if (!GetTokenInformation(token.get(), TokenUser, buffer.data(), length, &length))
+        return None;
 
-          // Check if the executable name matches any shell in the map.
-          if (
-            const auto iter =
-              std::ranges::find_if(msysShellMap, [&](const Pair<StringView, StringView>& pair) -> bool {
-                return StringView { shellExe } == pair.first;
-              });
-            iter != std::ranges::end(msysShellMap)
-          )
-            return String(iter->second);
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - the buffer is laid out as a TOKEN_USER by the kernel
+      const auto* tokenUser = reinterpret_cast<const TOKEN_USER*>(buffer.data());
 
-          // If the executable name doesn't match any shell in the map, we might as well just return it as is.
-          return shellExe;
-        }
+      Array<WCHAR, 256> name {};
+      Array<WCHAR, 256> domain {};
+      DWORD             nameLength   = name.size();
+      DWORD             domainLength = domain.size();
+      SID_NAME_USE      sidType {};
 
-        // If the SHELL environment variable is not set, we can fall back to checking the process tree.
-        // This is slower, but if we don't have the SHELL variable there's not much else we can do.
-        if (Result<String> shellResult = FindShellInProcessTree(GetCurrentProcessId(), msysShellMap))
-          return *std::move(shellResult);
+      if (!LookupAccountSidW(nullptr, tokenUser->User.Sid, name.data(), &nameLength, domain.data(), &domainLength, &sidType))
+        return None;
 
-        // If we still can't find it, return "Unknown"
-        return String("Unknown");
-      }
+      if (Result<String> converted = helpers::ConvertWStringToUTF8(WString(name.data(), nameLength)))
+        return *converted;
 
-      // Normal windows shell environments don't set any environment variables we can check,
-      // so we have to check the process tree instead.
-      if (Result<String> shellResult = FindShellInProcessTree(GetCurrentProcessId(), windowsShellMap))
-        return *std::move(shellResult);
+      return None;
+    }
 
-      // If we can't find a shell, return "Unknown" instead of failing
-      return String("Unknown");
-    });
-  }
+    auto FileTimeToU64(const FILETIME& time) -> u64 {
+      return (static_cast<u64>(time.dwHighDateTime) << 32) | time.dwLowDateTime;
+    }
 
-  auto GetDiskUsage(CacheManager& /*cache*/) -> Result<ResourceUsage> {
-    // GetDiskFreeSpaceExW is a pretty old function and doesn't use native 64-bit integers,
-    // so we have to use ULARGE_INTEGER instead. It's basically a union that holds either a
-    // 64-bit integer or two 32-bit integers.
-    ULARGE_INTEGER freeBytes, totalBytes;
+    auto ReadProcessInfo(const PROCESSENTRY32W& entry) -> ProcessInfo {
+      ProcessInfo info {};
 
-    // Get the disk usage for the C: drive.
-    if (FAILED(GetDiskFreeSpaceExW(L"C:\\\\", nullptr, &totalBytes, &freeBytes)))
-      ERR(IoError, "Failed to get disk usage");
+      info.pid   = entry.th32ProcessID;
+      info.ppid  = entry.th32ParentProcessID;
+      info.name  = helpers::ConvertWStringToUTF8(entry.szExeFile).value_or("");
+      info.state = ProcessInfo::State::Unknown;
 
-    // Calculate the used bytes by subtracting the free bytes from the total bytes.
-    // QuadPart corresponds to the 64-bit integer in the union. (LowPart/HighPart are for the 32-bit integers.)
-    return ResourceUsage(totalBytes.QuadPart - freeBytes.QuadPart, totalBytes.QuadPart);
-  }
+      // Windows has no process-level state, and protected and system processes refuse
+      // even limited access, so the remaining fields are filled in on a best-effort basis.
+      const HandleWrapper<HANDLE> handle(OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_VM_READ, FALSE, entry.th32ProcessID));
 
-  auto GetDisks(CacheManager& cache) -> Result<Vec<DiskInfo>> {
-    Array<char, MAX_PATH> drives = {};
+      if (!handle)
+        return info;
 
-    DWORD size = GetLogicalDriveStringsA(MAX_PATH, drives.data());
+      if (PROCESS_MEMORY_COUNTERS counters {}; GetProcessMemoryInfo(handle.get(), &counters, sizeof(counters)))
+        info.rssBytes = counters.WorkingSetSize;
 
-    if (size == 0)
+      if (FILETIME creation, exit, kernel, user; GetProcessTimes(handle.get(), &creation, &exit, &kernel, &user)) {
+        FILETIME now;
+        GetSystemTimeAsFileTime(&now);
+
+        // All values are in 100ns intervals.
+        const u64 cpuTime = FileTimeToU64(kernel) + FileTimeToU64(user);
+        const u64 age     = FileTimeToU64(now) - FileTimeToU64(creation);
+
+        info.cpuPercent = age > 0 ? static_cast<f64>(cpuTime) / static_cast<f64>(age) * 100.0 : 0.0;
+      }
+
+      info.cmdline = QueryCommandLine(handle.get()).value_or("");
+      info.user    = QueryUser(handle.get());
+
+      return info;
+    }
+  } // namespace process
+
+  namespace storage {
+    // NVMe log identifier for the SMART / Health Information page.
+    constexpr DWORD NVME_LOG_HEALTH_INFO = 0x02;
+
+    // Upper bound when probing \\.\PhysicalDriveN; numbering can have gaps after drives are removed.
+    constexpr u32 MAX_PHYSICAL_DRIVES = 32;
+
+    auto IoctlError(const StringView operation) -> DracError {
+      const DWORD error = GetLastError();
+
+      if (error == ERROR_ACCESS_DENIED)
+        return DracError(PermissionRequired, std::format("{} requires administrator privileges", operation));
+
+      return DracError(PlatformSpecific, std::format("{} failed with error code: {}", operation, error));
+    }
+
+    auto GetBusType(HANDLE device) -> Result<STORAGE_BUS_TYPE> {
+      STORAGE_PROPERTY_QUERY query {};
+      query.PropertyId = StorageDeviceProperty;
+      query.QueryType  = PropertyStandardQuery;
+
+      STORAGE_DEVICE_DESCRIPTOR descriptor {};
+      DWORD                     bytesReturned = 0;
+
+      if (!DeviceIoControl(device, IOCTL_STORAGE_QUERY_PROPERTY, &query, sizeof(query), &descriptor, sizeof(descriptor), &bytesReturned, nullptr))
+        return Err(IoctlError("IOCTL_STORAGE_QUERY_PROPERTY"));
+
+      return descriptor.BusType;
+    }
+
+    auto ReadNvmeHealth(HANDLE device) -> Result<DiskHealth> {
+      // The query and the returned descriptor share one buffer: header, protocol data, then the log page itself.
+      constexpr usize bufferSize = offsetof(STORAGE_PROPERTY_QUERY, AdditionalParameters) + sizeof(STORAGE_PROTOCOL_SPECIFIC_DATA) + draconis::os::smart::SMART_PAGE_SIZE;
+
+      Vec<u8> buffer(bufferSize, 0);
+
+      // NOLINTBEGIN(*-pro-type-reinterpret-cast)
+      auto* query        = reinterpret_cast<STORAGE_PROPERTY_QUERY*>(buffer.data());
+      auto* protocolData = reinterpret_cast<STORAGE_PROTOCOL_SPECIFIC_DATA*>(query->AdditionalParameters);
+      // NOLINTEND(*-pro-type-reinterpret-cast)
+
+      query->PropertyId = StorageDeviceProtocolSpecificProperty;
+      query->QueryType  = PropertyStandardQuery;
+
+      protocolData->ProtocolType                = ProtocolTypeNvme;
+      protocolData->DataType                    = NVMeDataTypeLogPage;
+      protocolData->ProtocolDataRequestValue    = NVME_LOG_HEALTH_INFO;
+      protocolData->ProtocolDataRequestSubValue = 0;
+      protocolData->ProtocolDataOffset          = sizeof(STORAGE_PROTOCOL_SPECIFIC_DATA);
+      protocolData->ProtocolDataLength          = draconis::os::smart::SMART_PAGE_SIZE;
+
+      DWORD bytesReturned = 0;
+
+      if (!DeviceIoControl(device, IOCTL_STORAGE_QUERY_PROPERTY, buffer.data(), static_cast<DWORD>(buffer.size()), buffer.data(), static_cast<DWORD>(buffer.size()), &bytesReturned, nullptr))
+        return Err(IoctlError("IOCTL_STORAGE_QUERY_PROPERTY"));
+
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+      const auto* descriptor = reinterpret_cast<const STORAGE_PROTOCOL_DATA_DESCRIPTOR*>(buffer.data());
+
+      const usize logOffset = offsetof(STORAGE_PROTOCOL_DATA_DESCRIPTOR, ProtocolSpecificData) + descriptor->ProtocolSpecificData.ProtocolDataOffset;
+
+      if (descriptor->ProtocolSpecificData.ProtocolDataLength < draconis::os::smart::SMART_PAGE_SIZE || logOffset + draconis::os::smart::SMART_PAGE_SIZE > buffer.size())
+        ERR(ParseError, "NVMe health log page was truncated");
+
+      return draconis::os::smart::ParseNvmeHealthLog(Span<const u8>(buffer).subspan(logOffset, draconis::os::smart::SMART_PAGE_SIZE));
+    }
+
+    auto ReadAtaHealth(HANDLE device) -> Result<DiskHealth> {
+      STORAGE_PREDICT_FAILURE prediction {};
+      DWORD                   bytesReturned = 0;
+
+      if (!DeviceIoControl(device, IOCTL_STORAGE_PREDICT_FAILURE, nullptr, 0, &prediction, sizeof(prediction), &bytesReturned, nullptr))
+        return Err(IoctlError("IOCTL_STORAGE_PREDICT_FAILURE"));
+
+      // The vendor-specific block is the SMART READ DATA page. Thresholds aren't exposed here,
+      // but the driver has already compared against them to produce PredictFailure.
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+      DiskHealth health = draconis::os::smart::ParseAtaSmartData(Span<const u8>(reinterpret_cast<const u8*>(prediction.VendorSpecific), sizeof(prediction.VendorSpecific)), None);
+
+      health.status = prediction.PredictFailure ? DiskHealth::Status::Failing : DiskHealth::Status::Passed;
+
+      return health;
+    }
+
+    auto ReadTemperature(HANDLE device) -> Option<f64> {
+      STORAGE_PROPERTY_QUERY query {};
+      query.PropertyId = StorageDeviceTemperatureProperty;
+      query.QueryType  = PropertyStandardQuery;
+
+      STORAGE_TEMPERATURE_DATA_DESCRIPTOR descriptor {};
+      DWORD                               bytesReturned = 0;
+
+      if (!DeviceIoControl(device, IOCTL_STORAGE_QUERY_PROPERTY, &query, sizeof(query), &descriptor, sizeof(descriptor), &bytesReturned, nullptr) || descriptor.InfoCount == 0)
+        return None;
+
+      // The entry with index 0 is the temperature of the device as a whole.
+      return static_cast<f64>(descriptor.TemperatureInfo[0].Temperature);
+    }
+  } // namespace storage
+
+  namespace smbios {
+    // Table signature for GetSystemFirmwareTable ("RSMB" as a big-endian DWORD).
+    constexpr DWORD RSMB_SIGNATURE = ('R' << 24) | ('S' << 16) | ('M' << 8) | 'B';
+
+    // Size of the RawSMBIOSData header that precedes the structure table.
+    constexpr usize RAW_HEADER_SIZE = 8;
+
+    constexpr u8 TYPE_SYSTEM      = 1;
+    constexpr u8 TYPE_BASEBOARD   = 2;
+    constexpr u8 TYPE_END         = 127;
+    constexpr u8 SERIAL_OFFSET    = 0x07; // Same offset in both the system and baseboard structures
+    constexpr u8 MIN_HEADER_BYTES = 4;
+
+    // Returns the serial number string of the first structure of the given type, if it has a non-empty one.
+    auto FindSerialNumber(const Span<const u8> table, const u8 type) -> Option<String> {
+      usize offset = 0;
+
+      while (offset + MIN_HEADER_BYTES <= table.size()) {
+        const u8    structType = table[offset];
+        const u8    length     = table[offset + 1];
+        const usize strings    = offset + length;
+
+        if (length < MIN_HEADER_BYTES || strings > table.size() || structType == TYPE_END)
+          return None;
+
+        // The formatted area is followed by NUL-terminated strings, ended by an extra NUL.
+        usize next = strings;
+        while (next + 1 < table.size() && (table[next] != 0 || table[next + 1] != 0))
+          ++next;
+        next += 2;
+
+        // A truncated table may lack the final double NUL.
+        const usize limit = std::min(next, table.size());
+
+        if (structType == type && length > SERIAL_OFFSET) {
+          u8    index = table[offset + SERIAL_OFFSET]; // 1-based, 0 means "no string"
+          usize start = strings;
+
+          while (index > 1 && start < limit) {
+            while (start < limit && table[start] != 0)
+              ++start;
+            ++start;
+            --index;
+          }
+
+          if (index == 0 || start >= limit)
+            return None;
+
+          const Span<const u8> bytes = table.subspan(start, limit - start);
+          const String         serial(bytes.begin(), std::ranges::find(bytes, 0));
+
+          if (const usize end = serial.find_last_not_of(' '); end != String::npos)
+            return serial.substr(0, end + 1);
+
+          return None;
+        }
+
+        offset = next;
+      }
+
+      return None;
+    }
+  } // namespace smbios
+
+  namespace scm {
+    using ServiceHandle = UniquePointer<SC_HANDLE__, decltype(&CloseServiceHandle)>;
+
+    auto StateFromStatus(const SERVICE_STATUS_PROCESS& status) -> ServiceInfo::State {
+      switch (status.dwCurrentState) {
+        case SERVICE_RUNNING:          return ServiceInfo::State::Running;
+        case SERVICE_START_PENDING:
+        case SERVICE_CONTINUE_PENDING: return ServiceInfo::State::Starting;
+        case SERVICE_STOP_PENDING:
+        case SERVICE_PAUSE_PENDING:    return ServiceInfo::State::Stopping;
+        case SERVICE_PAUSED:           return ServiceInfo::State::Stopped;
+        case SERVICE_STOPPED:
+          // A stopped service that exited with an error is the SCM's equivalent of a failed unit.
+          return status.dwWin32ExitCode == NO_ERROR || status.dwWin32ExitCode == ERROR_SERVICE_NEVER_STARTED
+            ? ServiceInfo::State::Stopped
+            : ServiceInfo::State::Failed;
+        default: return ServiceInfo::State::Unknown;
+      }
+    }
+
+    auto ToWide(const StringView str) -> WString {
+      if (str.empty())
+        return {};
+
+      const i32 sizeNeeded = MultiByteToWideChar(CP_UTF8, 0, str.data(), static_cast<i32>(str.size()), nullptr, 0);
+
+      WString wide(static_cast<usize>(sizeNeeded), L'\0');
+      MultiByteToWideChar(CP_UTF8, 0, str.data(), static_cast<i32>(str.size()), wide.data(), sizeNeeded);
+
+      return wide;
+    }
+
+    // Reads the display name and start type, which are only exposed through the service configuration.
+    auto ReadConfig(SC_HANDLE service, ServiceInfo& info) -> void {
+      DWORD bytesNeeded = 0;
+      QueryServiceConfigW(service, nullptr, 0, &bytesNeeded);
+
+      if (GetLastError() != ERROR_INSUFFICIENT_BUFFER || bytesNeeded == 0)
+        return;
+
+      Vec<BYTE> buffer(bytesNeeded);
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - The SCM writes a QUERY_SERVICE_CONFIGW header into the buffer.
+      auto* config = reinterpret_cast<QUERY_SERVICE_CONFIGW*>(buffer.data());
+
+      if (!QueryServiceConfigW(service, config, bytesNeeded, &bytesNeeded))
+        return;
+
+      if (config->lpDisplayName && *config->lpDisplayName)
+        if (Result<String> displayName = helpers::ConvertWStringToUTF8(config->lpDisplayName); displayName && *displayName != info.name)
+          info.description = std::move(*displayName);
+
+      switch (config->dwStartType) {
+        case SERVICE_BOOT_START:
+        case SERVICE_SYSTEM_START:
+        case SERVICE_AUTO_START: info.enabled = true; break;
+        case SERVICE_DEMAND_START:
+        case SERVICE_DISABLED:   info.enabled = false; break;
+        default:                 break;
+      }
+    }
+  } // namespace scm
+
+  namespace gpu {
+    // Not named by SDKs older than Windows 10 21H2.
+    constexpr auto FEATURE_LEVEL_12_2 = static_cast<D3D_FEATURE_LEVEL>(0xc200);
+
+    constexpr Array<D3D_FEATURE_LEVEL, 9> FEATURE_LEVELS = {
+      FEATURE_LEVEL_12_2,
+      D3D_FEATURE_LEVEL_12_1,
+      D3D_FEATURE_LEVEL_12_0,
+      D3D_FEATURE_LEVEL_11_1,
+      D3D_FEATURE_LEVEL_11_0,
+      D3D_FEATURE_LEVEL_10_1,
+      D3D_FEATURE_LEVEL_10_0,
+      D3D_FEATURE_LEVEL_9_3,
+      D3D_FEATURE_LEVEL_9_1,
+    };
+
+    // Formats a feature level the way DxDiag does, e.g. 0xc200 as "12_2".
+    auto FormatFeatureLevel(const D3D_FEATURE_LEVEL level) -> String {
+      return std::format("{}_{}", (static_cast<u32>(level) >> 12) & 0xF, (static_cast<u32>(level) >> 8) & 0xF);
+    }
+
+    // Finds the highest Direct3D feature level an adapter supports. Devices are created without any swap chain, so nothing is shown.
+    auto GetMaxFeatureLevel(IDXGIAdapter1* adapter) -> Result<String> {
+      // Loaded at runtime so the library still starts on systems without the D3D12 runtime.
+      static const HMODULE D3D12 = LoadLibraryExW(L"d3d12.dll", nullptr, LOAD_LIBRARY_SEARCH_SYSTEM32);
+      static const HMODULE D3D11 = LoadLibraryExW(L"d3d11.dll", nullptr, LOAD_LIBRARY_SEARCH_SYSTEM32);
+
+      // NOLINTBEGIN(*-pro-type-reinterpret-cast) - Entry points are resolved as untyped procedures.
+      const auto createD3D12Device = D3D12 ? reinterpret_cast<PFN_D3D12_CREATE_DEVICE>(GetProcAddress(D3D12, "D3D12CreateDevice")) : nullptr;
+      const auto createD3D11Device = D3D11 ? reinterpret_cast<PFN_D3D11_CREATE_DEVICE>(GetProcAddress(D3D11, "D3D11CreateDevice")) : nullptr;
+      // NOLINTEND(*-pro-type-reinterpret-cast)
+
+      if (Microsoft::WRL::ComPtr<ID3D12Device> device; createD3D12Device && SUCCEEDED(createD3D12Device(adapter, D3D_FEATURE_LEVEL_11_0, IID_PPV_ARGS(&device)))) {
+        // Runtimes that predate 12_2 reject the whole query, so it's retried without it.
+        for (const usize first : { 0UZ, 1UZ }) {
+          D3D12_FEATURE_DATA_FEATURE_LEVELS query {
+            .NumFeatureLevels         = static_cast<UINT>(FEATURE_LEVELS.size() - first),
+            .pFeatureLevelsRequested  = &FEATURE_LEVELS.at(first),
+            .MaxSupportedFeatureLevel = {},
+          };
+
+          if (SUCCEEDED(device->CheckFeatureSupport(D3D12_FEATURE_FEATURE_LEVELS, &query, sizeof(query))))
+            return FormatFeatureLevel(query.MaxSupportedFeatureLevel);
+        }
+      }
+
+      // D3D11 on Windows 7 rejects 11_1, and 12_x levels are left to D3D12. A null device only reports the level.
+      if (createD3D11Device)
+        for (const usize first : { 3UZ, 4UZ })
+          if (D3D_FEATURE_LEVEL level {}; SUCCEEDED(createD3D11Device(adapter, D3D_DRIVER_TYPE_UNKNOWN, nullptr, 0, &FEATURE_LEVELS.at(first), static_cast<UINT>(FEATURE_LEVELS.size() - first), D3D11_SDK_VERSION, nullptr, &level, nullptr)))
+            return FormatFeatureLevel(level);
+
+      ERR(ApiUnavailable, "The adapter supports neither Direct3D 12 nor Direct3D 11");
+    }
+  } // namespace gpu
+
+  namespace display {
+    auto ConnectorFromOutputTechnology(const DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY technology) -> DisplayInfo::Connector {
+      using enum DisplayInfo::Connector;
+
+      switch (technology) {
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HD15:                 return VGA;
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DVI:                  return DVI;
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HDMI:                 return HDMI;
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_LVDS:                 return LVDS;
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EXTERNAL: return DisplayPort;
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED: return EDP;
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_UDI_EMBEDDED:
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL:             return Internal;
+        case DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INDIRECT_VIRTUAL:     return Virtual;
+        default:                                                   return Unknown;
+      }
+    }
+
+    // Monitor device paths look like \\?\DISPLAY#<hardware ID>#<instance>#{interface GUID}; the PnP manager keeps the EDID under the matching Enum key.
+    auto ReadMonitorEdid(const WStringView devicePath) -> Option<Vec<u8>> {
+      Vec<WStringView> parts;
+
+      for (const auto part : devicePath | std::views::split(L'#'))
+        parts.emplace_back(part.begin(), part.end());
+
+      if (parts.size() < 3)
+        return None;
+
+      const WString keyPath = std::format(L"SYSTEM\\CurrentControlSet\\Enum\\DISPLAY\\{}\\{}\\Device Parameters", parts[1], parts[2]);
+
+      DWORD dataSize = 0;
+
+      if (RegGetValueW(HKEY_LOCAL_MACHINE, keyPath.c_str(), L"EDID", RRF_RT_REG_BINARY, nullptr, nullptr, &dataSize) != ERROR_SUCCESS || dataSize == 0)
+        return None;
+
+      Vec<u8> edid(dataSize);
+
+      if (RegGetValueW(HKEY_LOCAL_MACHINE, keyPath.c_str(), L"EDID", RRF_RT_REG_BINARY, nullptr, edid.data(), &dataSize) != ERROR_SUCCESS)
+        return None;
+
+      edid.resize(dataSize);
+
+      return edid;
+    }
+
+    auto ApplyMonitorDetails(DisplayInfo& display, const DISPLAYCONFIG_PATH_INFO& path) -> void {
+      DISPLAYCONFIG_TARGET_DEVICE_NAME targetName {};
+      targetName.header.type      = DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME;
+      targetName.header.size      = sizeof(targetName);
+      targetName.header.adapterId = path.targetInfo.adapterId;
+      targetName.header.id        = path.targetInfo.id;
+
+      if (DisplayConfigGetDeviceInfo(&targetName.header) != ERROR_SUCCESS)
+        return;
+
+      // The friendly name comes from the EDID too, but it's the only identity available when the registry copy can't be read.
+      if (targetName.monitorFriendlyDeviceName[0] != L'\0')
+        if (Result<String> friendlyName = helpers::ConvertWStringToUTF8(targetName.monitorFriendlyDeviceName))
+          display.model = std::move(*friendlyName);
+
+      const Option<Vec<u8>> edid = ReadMonitorEdid(targetName.monitorDevicePath);
+
+      if (!edid)
+        return;
+
+      if (Option<draconis::os::edid::Identity> identity = draconis::os::edid::ParseIdentity(*edid)) {
+        display.manufacturer = std::move(identity->manufacturer);
+        display.serial       = std::move(identity->serial);
+
+        if (identity->model)
+          display.model = std::move(identity->model);
+      }
+
+      if (Option<draconis::os::edid::ColorCapabilities> color = draconis::os::edid::ParseColorCapabilities(*edid)) {
+        display.gamut        = color->gamut;
+        display.hdrSupported = color->hdrSupported;
+      }
+    }
+
+    // NOLINTBEGIN(*-pro-type-union-access)
+    auto ApplyPathDetails(DisplayInfo& display, const DISPLAYCONFIG_PATH_INFO& path, const Span<const DISPLAYCONFIG_MODE_INFO> modes) -> void {
+      // DISPLAYCONFIG_ROTATION values count clockwise quarter turns, starting at 1 for no rotation.
+      display.rotation  = static_cast<u16>((path.targetInfo.rotation - DISPLAYCONFIG_ROTATION_IDENTITY) * 90);
+      display.connector = ConnectorFromOutputTechnology(path.targetInfo.outputTechnology);
+
+      ApplyMonitorDetails(display, path);
+
+      if (path.sourceInfo.modeInfoIdx >= modes.size() || modes[path.sourceInfo.modeInfoIdx].infoType != DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE)
+        return;
+
+      const DISPLAYCONFIG_SOURCE_MODE& sourceMode = modes[path.sourceInfo.modeInfoIdx].sourceMode;
+
+      display.position = { .x = sourceMode.position.x, .y = sourceMode.position.y };
+
+      switch (sourceMode.pixelFormat) {
+        case DISPLAYCONFIG_PIXELFORMAT_8BPP:  display.colorDepth = 8; break;
+        case DISPLAYCONFIG_PIXELFORMAT_16BPP: display.colorDepth = 16; break;
+        case DISPLAYCONFIG_PIXELFORMAT_24BPP:
+        case DISPLAYCONFIG_PIXELFORMAT_32BPP: display.colorDepth = 24; break;
+        default:                              break;
+      }
+
+      // 10-bit and HDR modes still use a 32bpp desktop format, so prefer the per-channel depth of the link when it's reported.
+      DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO colorInfo {};
+      colorInfo.header.type      = DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO;
+      colorInfo.header.size      = sizeof(colorInfo);
+      colorInfo.header.adapterId = path.targetInfo.adapterId;
+      colorInfo.header.id        = path.targetInfo.id;
+
+      if (DisplayConfigGetDeviceInfo(&colorInfo.header) == ERROR_SUCCESS) {
+        if (colorInfo.bitsPerColorChannel > 0)
+          display.colorDepth = static_cast<u8>(colorInfo.bitsPerColorChannel * 3);
+
+        // Advanced color is also reported for SDR panels with automatic color management, which "enforce" wide color instead of HDR.
+        const bool sdrWideColor = colorInfo.wideColorEnforced != 0;
+
+        display.hdrSupported = display.hdrSupported || (colorInfo.advancedColorSupported != 0 && !sdrWideColor);
+        display.hdrEnabled   = colorInfo.advancedColorEnabled != 0 && !sdrWideColor;
+      }
+
+      // DPI-unaware threads get 96 DPI for every monitor (and scaled coordinates), so query as per-monitor aware.
+      const DPI_AWARENESS_CONTEXT previousContext = SetThreadDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2);
+
+      if (HMONITOR monitor = MonitorFromPoint({ .x = sourceMode.position.x, .y = sourceMode.position.y }, MONITOR_DEFAULTTONULL)) {
+        UINT dpiX = 0;
+        UINT dpiY = 0;
+
+        if (SUCCEEDED(GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &dpiX, &dpiY)) && dpiX > 0)
+          display.scaleFactor = static_cast<f64>(dpiX) / USER_DEFAULT_SCREEN_DPI;
+
+        if (SUCCEEDED(GetDpiForMonitor(monitor, MDT_RAW_DPI, &dpiX, &dpiY)) && dpiX > 0)
+          display.dpi = static_cast<f64>(dpiX);
+      }
+
+      if (previousContext)
+        SetThreadDpiAwarenessContext(previousContext);
+    }
+    // NOLINTEND(*-pro-type-union-access)
+  } // namespace display
+
+  namespace keyboard {
+    using cache::RegistryKey;
+
+    constexpr PWCStr LAYOUTS_KEY = L"SYSTEM\\CurrentControlSet\\Control\\Keyboard Layouts";
+
+    // Maps an input locale handle to its keyboard layout identifier (KLID), e.g. "00010409" for US-Dvorak.
+    auto LayoutIdFromHkl(const UINT_PTR hkl) -> WString {
+      const WORD deviceId = HIWORD(hkl);
+
+      // IMEs are registered under the full handle.
+      if ((deviceId & 0xF000) == 0xE000)
+        return std::format(L"{:08X}", static_cast<u32>(hkl));
+
+      // Non-default layouts of a language carry the "Layout Id" of their registry key instead of the KLID.
+      if ((deviceId & 0xF000) == 0xF000) {
+        const WString layoutId = std::format(L"{:04X}", deviceId & 0x0FFF);
+
+        HKEY layoutsKey = nullptr;
+
+        if (RegOpenKeyExW(HKEY_LOCAL_MACHINE, LAYOUTS_KEY, 0, KEY_READ, &layoutsKey) != ERROR_SUCCESS)
+          return {};
+
+        RegistryKey layoutsKeyGuard(layoutsKey);
+
+        Array<WCStr, 16> klid {};
+
+        for (DWORD idx = 0;; ++idx) {
+          DWORD klidLength = klid.size();
+
+          if (RegEnumKeyExW(layoutsKey, idx, klid.data(), &klidLength, nullptr, nullptr, nullptr, nullptr) != ERROR_SUCCESS)
+            return {};
+
+          Array<WCStr, 16> candidateId {};
+          DWORD            dataSize = candidateId.size() * sizeof(WCStr);
+
+          if (RegGetValueW(layoutsKey, klid.data(), L"Layout Id", RRF_RT_REG_SZ, nullptr, candidateId.data(), &dataSize) == ERROR_SUCCESS &&
+              _wcsicmp(candidateId.data(), layoutId.c_str()) == 0)
+            return klid.data();
+        }
+      }
+
+      return std::format(L"0000{:04X}", deviceId);
+    }
+
+    auto LayoutFromHkl(const HKL hkl, const bool active) -> KeyboardLayout {
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - HKL packs the language and layout IDs into a handle.
+      const auto value  = reinterpret_cast<UINT_PTR>(hkl);
+      const WORD langId = LOWORD(value);
+
+      KeyboardLayout layout {
+        .name        = std::format("{:04X}", langId),
+        .variant     = None,
+        .description = None,
+        .active      = active,
+      };
+
+      if (Array<WCStr, LOCALE_NAME_MAX_LENGTH> localeName {}; LCIDToLocaleName(MAKELCID(langId, SORT_DEFAULT), localeName.data(), static_cast<int>(localeName.size()), 0) > 0) {
+        if (Result<String> name = helpers::ConvertWStringToUTF8(WString(localeName.data())); name && !name->empty())
+          layout.name = *std::move(name);
+
+        if (Array<WCStr, 256> displayName {}; GetLocaleInfoEx(localeName.data(), LOCALE_SLOCALIZEDDISPLAYNAME, displayName.data(), static_cast<int>(displayName.size())) > 0)
+          if (Result<String> description = helpers::ConvertWStringToUTF8(WString(displayName.data())); description && !description->empty())
+            layout.description = *std::move(description);
+      }
+
+      // A language's default layout shares its ID; anything else (e.g. Dvorak, or a German keyboard for English) is reported as the variant.
+      if (HIWORD(value) == langId)
+        return layout;
+
+      const WString klid = LayoutIdFromHkl(value);
+
+      Array<WCStr, 256> layoutText {};
+      DWORD             dataSize = layoutText.size() * sizeof(WCStr);
+
+      if (!klid.empty() &&
+          RegGetValueW(HKEY_LOCAL_MACHINE, std::format(L"{}\\{}", LAYOUTS_KEY, klid).c_str(), L"Layout Text", RRF_RT_REG_SZ, nullptr, layoutText.data(), &dataSize) == ERROR_SUCCESS)
+        if (Result<String> variant = helpers::ConvertWStringToUTF8(WString(layoutText.data())); variant && !variant->empty())
+          layout.variant = *std::move(variant);
+
+      return layout;
+    }
+  } // namespace keyboard
+
+  namespace camera {
+    using cache::RegistryKey;
+
+    constexpr PWCStr CONSENT_STORE_KEY = L"Software\\Microsoft\\Windows\\CurrentVersion\\CapabilityAccessManager\\ConsentStore\\webcam";
+
+    // Windows records each app's camera sessions here; one that has started but not stopped is still capturing.
+    auto AnyAppCapturing(const HKEY parent) -> bool {
+      Array<WCStr, 256> appName {};
+
+      for (DWORD idx = 0;; ++idx) {
+        DWORD nameLength = appName.size();
+
+        if (RegEnumKeyExW(parent, idx, appName.data(), &nameLength, nullptr, nullptr, nullptr, nullptr) != ERROR_SUCCESS)
+          return false;
+
+        HKEY appKey = nullptr;
+
+        if (RegOpenKeyExW(parent, appName.data(), 0, KEY_READ, &appKey) != ERROR_SUCCESS)
+          continue;
+
+        RegistryKey appKeyGuard(appKey);
+
+        // Unpackaged programs are grouped one level deeper, keyed by their executable path.
+        if (_wcsicmp(appName.data(), L"NonPackaged") == 0) {
+          if (AnyAppCapturing(appKey))
+            return true;
+
+          continue;
+        }
+
+        u64   started = 0;
+        u64   stopped = 0;
+        DWORD size    = sizeof(u64);
+
+        if (RegGetValueW(appKey, nullptr, L"LastUsedTimeStart", RRF_RT_REG_QWORD, nullptr, &started, &size) != ERROR_SUCCESS)
+          continue;
+
+        size = sizeof(u64);
+
+        if (RegGetValueW(appKey, nullptr, L"LastUsedTimeStop", RRF_RT_REG_QWORD, nullptr, &stopped, &size) == ERROR_SUCCESS && started != 0 && stopped == 0)
+          return true;
+      }
+    }
+
+    // Per-user apps are recorded under HKCU; services and other users' apps under HKLM.
+    auto IsAnyCameraInUse() -> bool {
+      for (const HKEY root : { HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE }) {
+        HKEY storeKey = nullptr;
+
+        if (RegOpenKeyExW(root, CONSENT_STORE_KEY, 0, KEY_READ, &storeKey) != ERROR_SUCCESS)
+          continue;
+
+        RegistryKey storeKeyGuard(storeKey);
+
+        if (AnyAppCapturing(storeKey))
+          return true;
+      }
+
+      return false;
+    }
+  } // namespace camera
+
+  auto GetDiskInfoForDrive(const String& driveRoot, CacheManager& /*cache*/) -> Result<DiskInfo> {
+    DiskInfo disk;
+
+    // Set name and mount point (same for Windows drives)
+    disk.name       = driveRoot;
+    disk.mountPoint = driveRoot;
+
+    // Get drive type
+    UINT driveType = GetDriveTypeA(driveRoot.c_str());
+    switch (driveType) {
+      case DRIVE_FIXED:
+        disk.driveType = "Fixed";
+        break;
+      case DRIVE_REMOVABLE:
+        disk.driveType = "Removable";
+        break;
+      case DRIVE_CDROM:
+        disk.driveType = "CD-ROM";
+        break;
+      case DRIVE_REMOTE:
+        disk.driveType = "Network";
+        break;
+      case DRIVE_RAMDISK:
+        disk.driveType = "RAM Disk";
+        break;
+      default:
+        disk.driveType = "Unknown";
+        break;
+    }
+
+    // Get filesystem type and flags
+    Array<char, MAX_PATH> filesystem = {};
+    DWORD                 fsFlags    = 0;
+    if (GetVolumeInformationA(driveRoot.c_str(), nullptr, 0, nullptr, nullptr, &fsFlags, filesystem.data(), MAX_PATH))
+      disk.filesystem = filesystem.data();
+    else
+      disk.filesystem = "Unknown";
+
+    // Windows has no mount options as such; report the volume flags that correspond to them.
+    disk.isReadOnly = (fsFlags & FILE_READ_ONLY_VOLUME) != 0;
+    disk.mountOptions.emplace_back(disk.isReadOnly ? "ro" : "rw");
+
+    if ((fsFlags & FILE_VOLUME_IS_COMPRESSED) != 0)
+      disk.mountOptions.emplace_back("compress");
+
+    // Get disk space information
+    ULARGE_INTEGER freeBytes, totalBytes, totalFreeBytes;
+    if (GetDiskFreeSpaceExA(driveRoot.c_str(), &freeBytes, &totalBytes, &totalFreeBytes)) {
+      disk.totalBytes = totalBytes.QuadPart;
+      disk.usedBytes  = totalBytes.QuadPart - freeBytes.QuadPart;
+    } else {
+      // If we can't get space info, set to 0
+      disk.totalBytes = 0;
+      disk.usedBytes  = 0;
+    }
+
+    // NTFS, ReFS and FAT have no fixed inode table, so the inode counts stay empty.
+
+    // Get system drive for comparison
+    Array<char, MAX_PATH> systemDir = {};
+    GetSystemDirectoryA(systemDir.data(), MAX_PATH);
+    char systemDrive = systemDir.front();
+
+    // Check if this is the system drive
+    disk.isSystemDrive = (driveRoot[0] == systemDrive);
+
+    return disk;
+  }
+} // namespace
+
+namespace draconis::core::system {
+  using namespace draconis::utils::types;
+  using draconis::utils::cache::CacheManager;
+  using namespace cache;
+  using namespace constants;
+  using namespace helpers;
+
+  auto GetMemInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
+    // Passed to GlobalMemoryStatusEx to retrieve memory information.
+    // dwLength is required to be set as per WinAPI.
+    MEMORYSTATUSEX memInfo;
+    memInfo.dwLength = sizeof(MEMORYSTATUSEX);
+
+    if (GlobalMemoryStatusEx(&memInfo))
+      return ResourceUsage(memInfo.ullTotalPhys - memInfo.ullAvailPhys, memInfo.ullTotalPhys);
+
+    ERR_FMT(ApiUnavailable, "GlobalMemoryStatusEx failed with error code {}", GetLastError());
+  }
+
+  auto GetMemoryDetails(CacheManager& /*cache*/) -> Result<MemoryDetails> {
+    MEMORYSTATUSEX memInfo;
+    memInfo.dwLength = sizeof(MEMORYSTATUSEX);
+
+    if (!GlobalMemoryStatusEx(&memInfo))
+      ERR_FMT(ApiUnavailable, "GlobalMemoryStatusEx failed with error code {}", GetLastError());
+
+    // GetPerformanceInfo reports its counters in pages rather than bytes.
+    PERFORMANCE_INFORMATION perfInfo;
+    perfInfo.cb = sizeof(PERFORMANCE_INFORMATION);
+
+    if (!GetPerformanceInfo(&perfInfo, sizeof(PERFORMANCE_INFORMATION)))
+      ERR_FMT(ApiUnavailable, "GetPerformanceInfo failed with error code {}", GetLastError());
+
+    const u64 pageSize = perfInfo.PageSize;
+
+    MemoryDetails details {};
+
+    // Windows counts standby (cached) pages as available, so "free" excludes them.
+    details.totalBytes     = memInfo.ullTotalPhys;
+    details.availableBytes = memInfo.ullAvailPhys;
+    details.usedBytes      = memInfo.ullTotalPhys - memInfo.ullAvailPhys;
+    details.cachedBytes    = perfInfo.SystemCache * pageSize;
+    details.freeBytes      = details.availableBytes - std::min(details.cachedBytes, details.availableBytes);
+    details.kernelBytes    = perfInfo.KernelNonpaged * pageSize;
+
+    return details;
+  }
+
+  auto GetSwapInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
+    MEMORYSTATUSEX memInfo;
+    memInfo.dwLength = sizeof(MEMORYSTATUSEX);
+
+    if (!GlobalMemoryStatusEx(&memInfo))
+      ERR_FMT(ApiUnavailable, "GlobalMemoryStatusEx failed with error code {}", GetLastError());
+
+    // The "page file" figures are actually the commit limit, which is physical memory
+    // plus every page file. Subtracting the physical side leaves just the page files.
+    const u64 totalSwap     = memInfo.ullTotalPageFile - std::min(memInfo.ullTotalPhys, memInfo.ullTotalPageFile);
+    const u64 committed     = memInfo.ullTotalPageFile - memInfo.ullAvailPageFile;
+    const u64 physicalInUse = memInfo.ullTotalPhys - memInfo.ullAvailPhys;
+    const u64 usedSwap      = std::min(committed - std::min(physicalInUse, committed), totalSwap);
+
+    return ResourceUsage(usedSwap, totalSwap);
+  }
+
+  auto GetOperatingSystem(CacheManager& cache) -> Result<OSInfo> {
+    return cache.getOrSet<OSInfo>("windows_os_version", []() -> Result<OSInfo> {
+      // Windows is weird about its versioning scheme, and Windows 11 is still
+      // considered Windows 10 in the registry. We have to manually check if
+      // the actual version is Windows 11 by checking the build number.
+      constexpr PWCStr windows10 = L"Windows 10";
+      constexpr PWCStr windows11 = L"Windows 11";
+
+      constexpr usize windowsLen = std::char_traits<WCStr>::length(windows10);
+
+      const RegistryCache& registry = RegistryCache::getInstance();
+
+      HKEY currentVersionKey = registry.getCurrentVersionKey();
+
+      if (!currentVersionKey)
+        ERR(NotFound, "Failed to open registry key");
+
+      WString productName = TRY(GetRegistryValue(currentVersionKey, PRODUCT_NAME));
+
+      if (productName.empty())
+        ERR(NotFound, "ProductName not found in registry");
+
+      // Build 22000+ of Windows are all considered Windows 11, so we can safely replace the product name
+      // if it's currently "Windows 10" and the build number is greater than or equal to 22000.
+      if (const Result<u64> buildNumberOpt = OsVersionCache::getInstance().getBuildNumber())
+        if (const u64 buildNumber = *buildNumberOpt; buildNumber >= 22000)
+          if (const size_t pos = productName.find(windows10); pos != WString::npos) {
+            // Make sure we're not replacing a substring of a larger string. Should never happen,
+            // but if it ever does, we'll just leave the product name unchanged.
+            const bool startBoundary = (pos == 0 || !iswalnum(productName.at(pos - 1)));
+            const bool endBoundary   = (pos + windowsLen == productName.length() || !iswalnum(productName.at(pos + windowsLen)));
+
+            if (startBoundary && endBoundary)
+              productName.replace(pos, windowsLen, windows11);
+          }
+
+      // Append the display version if it exists.
+      WString displayVersion = TRY(GetRegistryValue(currentVersionKey, DISPLAY_VERSION));
+
+      String productNameUTF8 = TRY(ConvertWStringToUTF8(productName));
+
+      String displayVersionUTF8 = TRY(ConvertWStringToUTF8(displayVersion));
+
+      return OSInfo(productNameUTF8, displayVersionUTF8, "windows");
+    });
+  }
+
+  auto GetHost(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_host", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
+      // Read from BIOS registry key which contains system product information
+      HKEY biosKey = nullptr;
+
+      if (RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"HARDWARE\\DESCRIPTION\\System\\BIOS", 0, KEY_READ, &biosKey) != ERROR_SUCCESS)
+        ERR(NotFound, "Failed to open BIOS registry key");
+
+      RegistryKey biosKeyGuard(biosKey);
+
+      // Try SystemFamily first (e.g., "ASUS TUF Gaming F15"), then fall back to SystemProductName
+      if (Result<WString> systemFamily = GetRegistryValue(biosKey, SYSTEM_FAMILY); systemFamily)
+        return ConvertWStringToUTF8(*systemFamily);
+
+      if (Result<WString> productName = GetRegistryValue(biosKey, SYSTEM_PRODUCT_NAME); productName)
+        return ConvertWStringToUTF8(*productName);
+
+      ERR(NotFound, "Failed to get system family or product name from BIOS registry");
+    });
+  }
+
+  auto GetFirmwareInfo(CacheManager& cache) -> Result<FirmwareInfo> {
+    return cache.getOrSet<FirmwareInfo>("windows_firmware_info", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<FirmwareInfo> {
+      HKEY biosKey = nullptr;
+
+      if (RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"HARDWARE\\DESCRIPTION\\System\\BIOS", 0, KEY_READ, &biosKey) != ERROR_SUCCESS)
+        ERR(NotFound, "Failed to open BIOS registry key");
+
+      RegistryKey biosKeyGuard(biosKey);
+
+      // clang-format off
+      constexpr Array<StringView, 4> placeholders = {
+        "To Be Filled By O.E.M.", "To be filled by O.E.M.", "Default string", "Not Applicable",
+      };
+      // clang-format on
+
+      const auto readValue = [&](const PWCStr name) -> Option<String> {
+        Result<String> value = GetRegistryValue(biosKey, name).and_then([](const WString& wide) -> Result<String> { return ConvertWStringToUTF8(wide); });
+
+        if (!value || value->empty() || std::ranges::find(placeholders, StringView(*value)) != placeholders.end())
+          return None;
+
+        return *value;
+      };
+
+      FIRMWARE_TYPE firmwareType = FirmwareTypeUnknown;
+      GetFirmwareType(&firmwareType);
+
+      return FirmwareInfo {
+        .boardVendor  = readValue(BASEBOARD_MANUFACTURER),
+        .boardModel   = readValue(BASEBOARD_PRODUCT),
+        .boardVersion = readValue(BASEBOARD_VERSION),
+        .vendor       = readValue(BIOS_VENDOR),
+        .version      = readValue(BIOS_VERSION),
+        .releaseDate  = readValue(BIOS_RELEASE_DATE),
+        .isUefi       = firmwareType == FirmwareTypeUefi,
+      };
+    });
+  }
+
+  auto GetMachineId(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_machine_id", draconis::utils::cache::CachePolicy::inMemory(), []() -> Result<String> {
+      HKEY cryptographyKey = nullptr;
+
+      // The value only exists in the 64-bit view, so 32-bit builds must opt out of registry redirection.
+      if (RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"SOFTWARE\\Microsoft\\Cryptography", 0, KEY_READ | KEY_WOW64_64KEY, &cryptographyKey) != ERROR_SUCCESS)
+        ERR(NotFound, "Failed to open Cryptography registry key");
+
+      RegistryKey cryptographyKeyGuard(cryptographyKey);
+
+      return ConvertWStringToUTF8(TRY(GetRegistryValue(cryptographyKey, MACHINE_GUID)));
+    });
+  }
+
+  auto GetHardwareSerial(CacheManager& cache) -> Result<String> {
+    // Kept in memory only, so the serial never ends up in a cache file.
+    return cache.getOrSet<String>("windows_hardware_serial", draconis::utils::cache::CachePolicy::inMemory(), []() -> Result<String> {
+      const UINT size = GetSystemFirmwareTable(smbios::RSMB_SIGNATURE, 0, nullptr, 0);
+
+      if (size <= smbios::RAW_HEADER_SIZE)
+        ERR_FMT(ApiUnavailable, "GetSystemFirmwareTable failed with error code: {}", GetLastError());
+
+      Vec<u8> buffer(size);
+
+      if (GetSystemFirmwareTable(smbios::RSMB_SIGNATURE, 0, buffer.data(), size) != size)
+        ERR_FMT(ApiUnavailable, "GetSystemFirmwareTable failed with error code: {}", GetLastError());
+
+      // clang-format off
+      constexpr Array<StringView, 6> placeholders = {
+        "To Be Filled By O.E.M.", "To be filled by O.E.M.", "Default string", "System Serial Number", "Not Specified", "0123456789",
+      };
+      // clang-format on
+
+      const Span<const u8> table = Span<const u8>(buffer).subspan(smbios::RAW_HEADER_SIZE);
+
+      // Many desktop boards leave the system serial blank and only fill in the baseboard one.
+      for (const u8 type : { smbios::TYPE_SYSTEM, smbios::TYPE_BASEBOARD })
+        if (Option<String> serial = smbios::FindSerialNumber(table, type); serial && std::ranges::find(placeholders, StringView(*serial)) == placeholders.end())
+          return *serial;
+
+      ERR(NotFound, "SMBIOS reports no hardware serial number");
+    });
+  }
+
+  auto GetVirtualization(CacheManager& cache) -> Result<VirtEnv> {
+    return cache.getOrSet<VirtEnv>("windows_virtualization", draconis::utils::cache::CachePolicy::inMemory(), []() -> Result<VirtEnv> {
+      using Hypervisor = VirtEnv::Hypervisor;
+
+      VirtEnv env;
+
+      // SMBIOS system strings, which the firmware of every common hypervisor fills in with its own name.
+      String manufacturer;
+      String productName;
+
+      if (HKEY biosKey = nullptr; RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"HARDWARE\\DESCRIPTION\\System\\BIOS", 0, KEY_READ, &biosKey) == ERROR_SUCCESS) {
+        RegistryKey biosKeyGuard(biosKey);
+
+        const auto readValue = [&](const PWCStr name) -> String {
+          return GetRegistryValue(biosKey, name).and_then([](const WString& wide) -> Result<String> { return ConvertWStringToUTF8(wide); }).value_or("");
+        };
+
+        manufacturer = readValue(SYSTEM_MANUFACTURER);
+        productName  = readValue(SYSTEM_PRODUCT_NAME);
+      }
+
+      const Hypervisor dmiHypervisor = draconis::os::virt::HypervisorFromDmi(manufacturer, productName);
+
+      if constexpr (DRAC_ARCH_X86_64 || DRAC_ARCH_X86) {
+        Array<i32, 4> cpuInfo = {};
+
+  #if defined(_MSC_VER) || (defined(__clang__) && defined(_WIN32))
+        // Use __cpuidex to avoid conflict with Clang's __cpuid macro from cpuid.h
+        __cpuidex(cpuInfo.data(), static_cast<i32>(draconis::os::virt::CPUID_FEATURE_LEAF), 0);
+  #else
+        __cpuid(draconis::os::virt::CPUID_FEATURE_LEAF, cpuInfo[0], cpuInfo[1], cpuInfo[2], cpuInfo[3]);
+  #endif
+
+        if (static_cast<u32>(cpuInfo[2]) & draconis::os::virt::CPUID_HYPERVISOR_BIT) {
+  #if defined(_MSC_VER) || (defined(__clang__) && defined(_WIN32))
+          __cpuidex(cpuInfo.data(), static_cast<i32>(draconis::os::virt::CPUID_HYPERVISOR_LEAF), 0);
+  #else
+          __cpuid(draconis::os::virt::CPUID_HYPERVISOR_LEAF, cpuInfo[0], cpuInfo[1], cpuInfo[2], cpuInfo[3]);
+  #endif
+
+          // The signature is spread over EBX, ECX and EDX, in that order.
+          Array<char, 12> signature {};
+          std::memcpy(signature.data(), &cpuInfo[1], sizeof(signature));
+
+          const StringView view(signature.data(), signature.size());
+
+          env.hypervisor       = draconis::os::virt::HypervisorFromCpuidSignature(view);
+          env.hypervisorVendor = String(view.substr(0, view.find('\0')));
+        }
+      }
+
+      // With virtualization-based security (Memory Integrity, Credential Guard) enabled, Windows itself runs in
+      // Hyper-V's root partition, so the CPUID leaf says Hyper-V on bare metal too. KVM and others also present
+      // the Hyper-V signature to Windows guests. The SMBIOS strings tell these cases apart.
+      if (env.hypervisor == Hypervisor::HyperV && dmiHypervisor != Hypervisor::HyperV) {
+        env.hypervisor       = dmiHypervisor;
+        env.hypervisorVendor = dmiHypervisor == Hypervisor::None ? None : Option<String>(manufacturer);
+      } else if (env.hypervisor == Hypervisor::None && dmiHypervisor != Hypervisor::None) {
+        env.hypervisor       = dmiHypervisor;
+        env.hypervisorVendor = manufacturer;
+      }
+
+      // Windows Server containers run the container execution agent; the runtime that started them isn't visible from inside.
+      if (HKEY serviceKey = nullptr; RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"SYSTEM\\CurrentControlSet\\Services\\cexecsvc", 0, KEY_READ, &serviceKey) == ERROR_SUCCESS) {
+        RegCloseKey(serviceKey);
+        env.container = VirtEnv::Container::Other;
+      }
+
+      return env;
+    });
+  }
+
+  auto GetSecurityPlatformInfo(CacheManager& cache) -> Result<SecurityPlatformInfo> {
+    return cache.getOrSet<SecurityPlatformInfo>("windows_security_platform", draconis::utils::cache::CachePolicy::inMemory(), []() -> Result<SecurityPlatformInfo> {
+      using SecureBoot = SecurityPlatformInfo::SecureBoot;
+
+      SecurityPlatformInfo info;
+
+      FIRMWARE_TYPE firmwareType = FirmwareTypeUnknown;
+
+      if (!GetFirmwareType(&firmwareType)) {
+        info.secureBoot = SecureBoot::Unknown;
+      } else if (firmwareType != FirmwareTypeUefi) {
+        info.secureBoot = SecureBoot::Unsupported;
+      } else {
+        DWORD enabled  = 0;
+        DWORD dataSize = sizeof(enabled);
+
+        // The value is missing on UEFI firmware that doesn't implement Secure Boot at all.
+        const LSTATUS status = RegGetValueW(
+          HKEY_LOCAL_MACHINE, L"SYSTEM\\CurrentControlSet\\Control\\SecureBoot\\State", L"UEFISecureBootEnabled", RRF_RT_REG_DWORD, nullptr, &enabled, &dataSize
+        );
+
+        if (status == ERROR_SUCCESS)
+          info.secureBoot = enabled != 0 ? SecureBoot::Enabled : SecureBoot::Disabled;
+        else if (status == ERROR_FILE_NOT_FOUND)
+          info.secureBoot = SecureBoot::Disabled;
+      }
+
+      // TBS reports TPM_VERSION_UNKNOWN / TBS_E_TPM_NOT_FOUND when the TPM is absent or disabled in firmware.
+      if (TPM_DEVICE_INFO deviceInfo {}; Tbsi_GetDeviceInfo(sizeof(deviceInfo), &deviceInfo) == TBS_SUCCESS) {
+        if (deviceInfo.tpmVersion == TPM_VERSION_20) {
+          info.tpmPresent = true;
+          info.tpmVersion = "2.0";
+        } else if (deviceInfo.tpmVersion == TPM_VERSION_12) {
+          info.tpmPresent = true;
+          info.tpmVersion = "1.2";
+        }
+      }
+
+      // Passing no buffer only asks for the size of the current boot's event log, which is empty without measured boot.
+      if (info.tpmPresent) {
+        UINT32 logSize = 0;
+
+        if (Tbsi_Get_TCG_Log_Ex(TBS_TCGLOG_SRTM_CURRENT, nullptr, &logSize) == TBS_SUCCESS)
+          info.measuredBoot = logSize > 0;
+      }
+
+      return info;
+    });
+  }
+
+  auto GetMacStatus(CacheManager& /*cache*/) -> Result<MacStatus> {
+    ERR(NotSupported, "SELinux and AppArmor are not available on Windows");
+  }
+
+  auto GetDomainInfo(CacheManager& cache) -> Result<DomainInfo> {
+    return cache.getOrSet<DomainInfo>("windows_domain_info", draconis::utils::cache::CachePolicy::inMemory(), []() -> Result<DomainInfo> {
+      using enum DomainInfo::Membership;
+
+      DomainInfo info {};
+
+      PWSTR                joinName   = nullptr;
+      NETSETUP_JOIN_STATUS joinStatus = NetSetupUnknownStatus;
+
+      if (const NET_API_STATUS status = NetGetJoinInformation(nullptr, &joinName, &joinStatus); status != NERR_Success)
+        ERR_FMT(ApiUnavailable, "NetGetJoinInformation failed with error code {}", status);
+
+      if (joinName) {
+        if (Result<String> name = helpers::ConvertWStringToUTF8(joinName); name && !name->empty())
+          info.name = *std::move(name);
+
+        NetApiBufferFree(joinName);
+      }
+
+      switch (joinStatus) {
+        case NetSetupDomainName:    info.membership = Domain; break;
+        case NetSetupWorkgroupName: info.membership = Workgroup; break;
+        default:                    info.membership = Unjoined; break;
+      }
+
+      if (info.membership == Domain)
+        if (Result<String> dnsDomain = helpers::GetComputerNameUTF8(ComputerNameDnsDomain))
+          info.dnsDomain = *std::move(dnsDomain);
+
+      // Returns null when the machine isn't joined or only has a work account registered.
+      PDSREG_JOIN_INFO aadInfo = nullptr;
+
+      if (SUCCEEDED(NetGetAadJoinInformation(nullptr, &aadInfo)) && aadInfo) {
+        info.azureAdJoined = aadInfo->joinType == DSREG_DEVICE_JOIN;
+
+        if (info.azureAdJoined && aadInfo->pszTenantDisplayName)
+          if (Result<String> tenant = helpers::ConvertWStringToUTF8(aadInfo->pszTenantDisplayName); tenant && !tenant->empty())
+            info.azureTenant = *std::move(tenant);
+
+        NetFreeAadJoinInformation(aadInfo);
+      }
+
+      return info;
+    });
+  }
+
+  auto GetFirewallStatus(CacheManager& /*cache*/) -> Result<FirewallStatus> {
+    // clang-format off
+    constexpr Array<Pair<PCStr, PWCStr>, 3> profileKeys = {{
+      { "Domain",  L"DomainProfile"   },
+      { "Private", L"StandardProfile" },
+      { "Public",  L"PublicProfile"   },
+    }};
+    // clang-format on
+
+    const auto readEnabled = [](const WString& path) -> Option<bool> {
+      DWORD enabled  = 0;
+      DWORD dataSize = sizeof(enabled);
+
+      if (RegGetValueW(HKEY_LOCAL_MACHINE, path.c_str(), L"EnableFirewall", RRF_RT_REG_DWORD, nullptr, &enabled, &dataSize) != ERROR_SUCCESS)
+        return None;
+
+      return enabled != 0;
+    };
+
+    FirewallStatus status { .backend = FirewallStatus::Backend::WindowsFirewall, .profiles = {} };
+
+    for (const auto& [name, key] : profileKeys) {
+      // A Group Policy setting takes precedence over the local one.
+      Option<bool> enabled = readEnabled(WString(L"SOFTWARE\\Policies\\Microsoft\\WindowsFirewall\\") + key);
+
+      if (!enabled)
+        enabled = readEnabled(WString(L"SYSTEM\\CurrentControlSet\\Services\\SharedAccess\\Parameters\\FirewallPolicy\\") + key);
+
+      if (enabled)
+        status.profiles.push_back({ .name = name, .enabled = *enabled });
+    }
+
+    if (status.profiles.empty())
+      ERR(NotFound, "Windows Firewall profile settings not found in the registry");
+
+    return status;
+  }
+
+  auto GetKernelVersion(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_kernel_version", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
+      // See the OsVersionCache class for how the version data is retrieved.
+      const auto& [majorVersion, minorVersion, buildNumber] = TRY(OsVersionCache::getInstance().getVersionData());
+
+      return std::format("{}.{}.{}", majorVersion, minorVersion, buildNumber);
+    });
+  }
+
+  auto GetKernelCmdline(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_kernel_cmdline", draconis::utils::cache::CachePolicy::inMemory(), []() -> Result<String> {
+      HKEY controlKey = nullptr;
+
+      if (RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"SYSTEM\\CurrentControlSet\\Control", 0, KEY_READ, &controlKey) != ERROR_SUCCESS)
+        ERR(NotFound, "Failed to open HKLM\\SYSTEM\\CurrentControlSet\\Control");
+
+      RegistryKey controlKeyGuard(controlKey);
+
+      // The boot manager writes the options with a leading space, e.g. " NOEXECUTE=OPTIN  FVEBOOT=2617344".
+      String options = TRY(ConvertWStringToUTF8(TRY(GetRegistryValue(controlKey, L"SystemStartOptions"))));
+
+      if (const usize start = options.find_first_not_of(' '); start != String::npos)
+        options.erase(0, start);
+      else
+        options.clear();
+
+      return options;
+    });
+  }
+
+  auto GetKernelModules(CacheManager& /*cache*/) -> Result<Vec<KernelModule>> {
+    DWORD bytesNeeded = 0;
+
+    if (!EnumDeviceDrivers(nullptr, 0, &bytesNeeded) || bytesNeeded == 0)
+      ERR_FMT(PlatformSpecific, "EnumDeviceDrivers failed: {}", GetLastError());
+
+    // Drivers can load between the two calls, so leave room for a few more.
+    Vec<LPVOID> driverBases((bytesNeeded / sizeof(LPVOID)) + 16);
+
+    if (!EnumDeviceDrivers(driverBases.data(), static_cast<DWORD>(driverBases.size() * sizeof(LPVOID)), &bytesNeeded))
+      ERR_FMT(PlatformSpecific, "EnumDeviceDrivers failed: {}", GetLastError());
+
+    driverBases.resize(std::min<usize>(driverBases.size(), bytesNeeded / sizeof(LPVOID)));
+
+    Vec<KernelModule> modules;
+    modules.reserve(driverBases.size());
+
+    for (LPVOID base : driverBases) {
+      Array<WCHAR, MAX_PATH> name {};
+
+      if (GetDeviceDriverBaseNameW(base, name.data(), static_cast<DWORD>(name.size())) == 0)
+        continue;
+
+      if (Result<String> utf8Name = ConvertWStringToUTF8(name.data()))
+        modules.push_back({ .name = std::move(*utf8Name), .sizeBytes = None });
+    }
+
+    return modules;
+  }
+
+  auto GetWindowManager(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_wm", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
+      if (!cache::ProcessTreeCache::getInstance().initialize())
+        ERR(PlatformSpecific, "Failed to initialize process tree cache");
+
+      for (const auto& [parentPid, baseExeNameLower] : cache::ProcessTreeCache::getInstance().getProcessMap() | std::views::values) {
+        const StringView processName = baseExeNameLower;
+
+        if (
+          const auto mapIter =
+            std::ranges::find_if(windowManagerMap, [&](const Pair<StringView, StringView>& pair) -> bool {
+              return processName == pair.first;
+            });
+          mapIter != std::ranges::end(windowManagerMap)
+        )
+          return String(mapIter->second);
+      }
+
+      return "DWM";
+    });
+  }
+
+  auto GetDisplaySession(CacheManager& /*cache*/) -> Result<DisplaySession> {
+    return DisplaySession { .server = DisplaySession::Server::Windows, .compositor = None };
+  }
+
+  auto GetThemeInfo(CacheManager& cache) -> Result<ThemeInfo> {
+    return cache.getOrSet<ThemeInfo>("windows_theme_info", draconis::utils::cache::CachePolicy::tempDirectory(), []() -> Result<ThemeInfo> {
+      ThemeInfo theme;
+
+      // AppsUseLightTheme is missing on builds before 1809, which only had the light theme.
+      DWORD useLightTheme = 1;
+      DWORD dataSize      = sizeof(useLightTheme);
+
+      RegGetValueW(HKEY_CURRENT_USER, L"Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize", L"AppsUseLightTheme", RRF_RT_REG_DWORD, nullptr, &useLightTheme, &dataSize);
+
+      theme.widgetTheme = useLightTheme != 0 ? "Light" : "Dark";
+
+      // The default value of the Cursors key holds the active scheme name; it's empty for "Windows Default".
+      if (HKEY cursorsKey = nullptr; RegOpenKeyExW(HKEY_CURRENT_USER, L"Control Panel\\Cursors", 0, KEY_READ, &cursorsKey) == ERROR_SUCCESS) {
+        RegistryKey cursorsKeyGuard(cursorsKey);
+
+        if (Result<WString> scheme = GetRegistryValue(cursorsKey, L""); scheme && !scheme->empty())
+          if (Result<String> schemeName = ConvertWStringToUTF8(*scheme))
+            theme.cursorTheme = *std::move(schemeName);
+      }
+
+      // The message font is what Explorer and most dialogs use for body text.
+      if (NONCLIENTMETRICSW metrics { .cbSize = sizeof(NONCLIENTMETRICSW) }; SystemParametersInfoW(SPI_GETNONCLIENTMETRICS, sizeof(metrics), &metrics, 0)) {
+        if (Result<String> face = ConvertWStringToUTF8(metrics.lfMessageFont.lfFaceName)) {
+          // lfHeight is in logical units at 96 DPI for DPI-unaware callers; convert to points.
+          const i32 points = std::abs(metrics.lfMessageFont.lfHeight) * 72 / 96;
+
+          theme.font = std::format("{} {}", *face, points);
+        }
+      }
+
+      return theme;
+    });
+  }
+
+  auto GetDefaultApplications() -> Result<DefaultApplications> {
+    using draconis::utils::env::GetEnv;
+
+    // Reads the name of the application that opens a file type or URL scheme, e.g. "Notepad" for ".txt".
+    const auto associatedApp = [](const ASSOCF flags, const PCWSTR association) -> Option<String> {
+      DWORD size = 0;
+
+      if (AssocQueryStringW(flags, ASSOCSTR_FRIENDLYAPPNAME, association, L"open", nullptr, &size) != S_FALSE || size <= 1)
+        return None;
+
+      WString name(size, L'\0');
+
+      if (FAILED(AssocQueryStringW(flags, ASSOCSTR_FRIENDLYAPPNAME, association, L"open", name.data(), &size)))
+        return None;
+
+      // The size includes the terminator.
+      name.resize(size - 1);
+
+      if (Result<String> converted = ConvertWStringToUTF8(name))
+        return *std::move(converted);
+
+      return None;
+    };
+
+    DefaultApplications apps;
+
+    for (const PCStr name : { "VISUAL", "EDITOR" }) {
+      if (Result<String> editor = GetEnv(name); editor && !editor->empty()) {
+        apps.editor = *std::move(editor);
+        break;
+      }
+    }
+
+    if (!apps.editor)
+      apps.editor = associatedApp(ASSOCF_INIT_IGNOREUNKNOWN, L".txt");
+
+    apps.browser = associatedApp(ASSOCF_IS_PROTOCOL, L"https");
+
+    // Windows 11 hands console apps to the terminal chosen in Settings; "Let Windows decide" stores an all-zero GUID.
+    Array<WCHAR, 64> delegation {};
+    DWORD            dataSize = sizeof(delegation);
+
+    if (RegGetValueW(HKEY_CURRENT_USER, L"Console\\%%Startup", L"DelegationTerminal", RRF_RT_REG_SZ, nullptr, delegation.data(), &dataSize) == ERROR_SUCCESS) {
+      const WStringView terminal(delegation.data());
+
+      if (terminal == L"{2EACA947-7F5F-4CFA-BA87-8F7FBEEFBE69}")
+        apps.terminal = "Windows Terminal";
+      else if (terminal == L"{E12CFF52-A866-4C77-9A90-F570A7AA2C6B}")
+        apps.terminal = "Windows Terminal Preview";
+      else if (terminal == L"{B23D10C0-E52E-411E-9D5B-C09FDF709C7D}")
+        apps.terminal = "Windows Console Host";
+    }
+
+    if (!apps.editor && !apps.browser && !apps.terminal)
+      ERR(NotFound, "No default editor, browser, or terminal is configured");
+
+    return apps;
+  }
+
+  auto GetAudioDevices(CacheManager& /*cache*/) -> Result<Vec<AudioDevice>> {
+    using Microsoft::WRL::ComPtr;
+
+    const HRESULT initResult = CoInitializeEx(nullptr, COINIT_MULTITHREADED);
+
+    if (FAILED(initResult) && initResult != RPC_E_CHANGED_MODE)
+      ERR_FMT(ApiUnavailable, "CoInitializeEx failed with HRESULT {:#x}", static_cast<u32>(initResult));
+
+    // Only balance the CoInitializeEx call if it actually took effect on this thread.
+    const bool shouldUninitialize = SUCCEEDED(initResult);
+
+    const auto query = [&]() -> Result<Vec<AudioDevice>> {
+      ComPtr<IMMDeviceEnumerator> enumerator;
+
+      if (FAILED(CoCreateInstance(__uuidof(MMDeviceEnumerator), nullptr, CLSCTX_INPROC_SERVER, IID_PPV_ARGS(&enumerator))))
+        ERR(ApiUnavailable, "Failed to create MMDeviceEnumerator");
+
+      Vec<AudioDevice> devices;
+
+      for (const auto& [dataFlow, direction] : { Pair { eRender, AudioDevice::Direction::Output }, Pair { eCapture, AudioDevice::Direction::Input } }) {
+        // The endpoint ID of the default console device, used to mark the matching entry.
+        WString defaultId;
+
+        if (ComPtr<IMMDevice> defaultDevice; SUCCEEDED(enumerator->GetDefaultAudioEndpoint(dataFlow, eConsole, &defaultDevice))) {
+          if (LPWSTR id = nullptr; SUCCEEDED(defaultDevice->GetId(&id))) {
+            defaultId = id;
+            CoTaskMemFree(id);
+          }
+        }
+
+        ComPtr<IMMDeviceCollection> collection;
+
+        if (FAILED(enumerator->EnumAudioEndpoints(dataFlow, DEVICE_STATE_ACTIVE, &collection)))
+          continue;
+
+        UINT count = 0;
+        collection->GetCount(&count);
+
+        for (UINT i = 0; i < count; i++) {
+          ComPtr<IMMDevice> device;
+
+          if (FAILED(collection->Item(i, &device)))
+            continue;
+
+          LPWSTR id = nullptr;
+
+          if (FAILED(device->GetId(&id)))
+            continue;
+
+          const WString endpointId = id;
+          CoTaskMemFree(id);
+
+          AudioDevice audioDevice {
+            .direction  = direction,
+            .id         = ConvertWStringToUTF8(endpointId).value_or(""),
+            .name       = {},
+            .isDefault  = endpointId == defaultId,
+            .channels   = None,
+            .sampleRate = None,
+          };
+
+          if (ComPtr<IPropertyStore> properties; SUCCEEDED(device->OpenPropertyStore(STGM_READ, &properties))) {
+            PROPVARIANT value;
+
+            PropVariantInit(&value);
+            if (SUCCEEDED(properties->GetValue(PKEY_Device_FriendlyName, &value)) && value.vt == VT_LPWSTR)
+              audioDevice.name = ConvertWStringToUTF8(value.pwszVal).value_or("");
+            PropVariantClear(&value);
+
+            // The shared-mode mix format the audio engine runs the endpoint at.
+            PropVariantInit(&value);
+            if (SUCCEEDED(properties->GetValue(PKEY_AudioEngine_DeviceFormat, &value)) && value.vt == VT_BLOB && value.blob.cbSize >= sizeof(WAVEFORMATEX)) {
+              const auto* format = reinterpret_cast<const WAVEFORMATEX*>(value.blob.pBlobData); // NOLINT(*-pro-type-reinterpret-cast)
+
+              audioDevice.channels   = format->nChannels;
+              audioDevice.sampleRate = format->nSamplesPerSec;
+            }
+            PropVariantClear(&value);
+          }
+
+          devices.push_back(std::move(audioDevice));
+        }
+      }
+
+      return devices;
+    };
+
+    Result<Vec<AudioDevice>> result = query();
+
+    if (shouldUninitialize)
+      CoUninitialize();
+
+    return result;
+  }
+
+  auto GetVolume(CacheManager& /*cache*/) -> Result<VolumeInfo> {
+    return audio::WithDefaultEndpointVolume([](IAudioEndpointVolume* endpointVolume) -> Result<VolumeInfo> {
+      f32  level = 0.0F;
+      BOOL muted = FALSE;
+
+      if (FAILED(endpointVolume->GetMasterVolumeLevelScalar(&level)) || FAILED(endpointVolume->GetMute(&muted)))
+        ERR(ApiUnavailable, "Failed to read the endpoint volume");
+
+      return VolumeInfo { .percent = static_cast<f64>(level) * 100.0, .muted = muted != FALSE };
+    });
+  }
+
+  auto SetVolume(const f64 percent) -> Result<> {
+    return audio::WithDefaultEndpointVolume([percent](IAudioEndpointVolume* endpointVolume) -> Result<> {
+      if (FAILED(endpointVolume->SetMasterVolumeLevelScalar(static_cast<f32>(std::clamp(percent, 0.0, 100.0) / 100.0), nullptr)))
+        ERR(ApiUnavailable, "Failed to set the endpoint volume");
+
+      return {};
+    });
+  }
+
+  auto SetMuted(const bool muted) -> Result<> {
+    return audio::WithDefaultEndpointVolume([muted](IAudioEndpointVolume* endpointVolume) -> Result<> {
+      if (FAILED(endpointVolume->SetMute(muted ? TRUE : FALSE, nullptr)))
+        ERR(ApiUnavailable, "Failed to set the endpoint mute state");
+
+      return {};
+    });
+  }
+
+  auto GetBluetoothDevices(CacheManager& /*cache*/) -> Result<BluetoothInfo> {
+    const auto formatAddress = [](const BLUETOOTH_ADDRESS& address) -> String {
+      const BYTE* bytes = address.rgBytes;
+
+      return std::format("{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}", bytes[5], bytes[4], bytes[3], bytes[2], bytes[1], bytes[0]);
+    };
+
+    BluetoothInfo info;
+
+    BLUETOOTH_FIND_RADIO_PARAMS radioParams = { .dwSize = sizeof(BLUETOOTH_FIND_RADIO_PARAMS) };
+    HANDLE                      radio       = nullptr;
+    HBLUETOOTH_RADIO_FIND       radioFind   = BluetoothFindFirstRadio(&radioParams, &radio);
+
+    if (!radioFind)
+      ERR(NotFound, "No Bluetooth radios found");
+
+    do {
+      BLUETOOTH_RADIO_INFO radioInfo = { .dwSize = sizeof(BLUETOOTH_RADIO_INFO) };
+
+      if (BluetoothGetRadioInfo(radio, &radioInfo) == ERROR_SUCCESS) {
+        info.adapters.push_back({
+          .name    = helpers::ConvertWStringToUTF8(radioInfo.szName).value_or("Bluetooth"),
+          .address = formatAddress(radioInfo.address),
+          // Windows has no direct "powered" query; a radio that's switched off isn't connectable either.
+          .powered = BluetoothIsConnectable(radio) != FALSE,
+        });
+
+        BLUETOOTH_DEVICE_SEARCH_PARAMS searchParams = {
+          .dwSize               = sizeof(BLUETOOTH_DEVICE_SEARCH_PARAMS),
+          .fReturnAuthenticated = TRUE,
+          .fReturnRemembered    = TRUE,
+          .fReturnUnknown       = FALSE,
+          .fReturnConnected     = TRUE,
+          .fIssueInquiry        = FALSE,
+          .cTimeoutMultiplier   = 0,
+          .hRadio               = radio,
+        };
+
+        BLUETOOTH_DEVICE_INFO deviceInfo = { .dwSize = sizeof(BLUETOOTH_DEVICE_INFO) };
+
+        if (HBLUETOOTH_DEVICE_FIND deviceFind = BluetoothFindFirstDevice(&searchParams, &deviceInfo)) {
+          do {
+            String address = formatAddress(deviceInfo.Address);
+
+            info.devices.push_back({
+              .name           = helpers::ConvertWStringToUTF8(deviceInfo.szName).value_or(address),
+              .address        = std::move(address),
+              .paired         = deviceInfo.fAuthenticated != FALSE || deviceInfo.fRemembered != FALSE,
+              .connected      = deviceInfo.fConnected != FALSE,
+              .batteryPercent = None,
+            });
+          } while (BluetoothFindNextDevice(deviceFind, &deviceInfo));
+
+          BluetoothFindDeviceClose(deviceFind);
+        }
+      }
+
+      CloseHandle(radio);
+    } while (BluetoothFindNextRadio(radioFind, &radio));
+
+    BluetoothFindRadioClose(radioFind);
+
+    return info;
+  }
+
+  auto GetPrinters(CacheManager& /*cache*/) -> Result<Vec<PrinterInfo>> {
+    constexpr DWORD flags = PRINTER_ENUM_LOCAL | PRINTER_ENUM_CONNECTIONS;
+
+    // Statuses that keep jobs from printing until someone intervenes.
+    constexpr DWORD errorStatus = PRINTER_STATUS_ERROR | PRINTER_STATUS_PAUSED | PRINTER_STATUS_OFFLINE | PRINTER_STATUS_NOT_AVAILABLE |
+      PRINTER_STATUS_PAPER_JAM | PRINTER_STATUS_PAPER_OUT | PRINTER_STATUS_PAPER_PROBLEM | PRINTER_STATUS_NO_TONER |
+      PRINTER_STATUS_DOOR_OPEN | PRINTER_STATUS_USER_INTERVENTION | PRINTER_STATUS_OUTPUT_BIN_FULL;
+
+    DWORD bytesNeeded = 0;
+    DWORD count       = 0;
+
+    // The first call only reports the buffer size; it fails with ERROR_INSUFFICIENT_BUFFER unless there are no printers.
+    if (!EnumPrintersW(flags, nullptr, 2, nullptr, 0, &bytesNeeded, &count)) {
+      if (const DWORD error = GetLastError(); error == RPC_S_SERVER_UNAVAILABLE)
+        ERR(ApiUnavailable, "The print spooler service is not running");
+      else if (error != ERROR_INSUFFICIENT_BUFFER)
+        ERR_FMT(PlatformSpecific, "EnumPrintersW failed with error {}", error);
+    }
+
+    if (bytesNeeded == 0)
+      return Vec<PrinterInfo> {};
+
+    Vec<BYTE> buffer(bytesNeeded);
+
+    if (!EnumPrintersW(flags, nullptr, 2, buffer.data(), bytesNeeded, &bytesNeeded, &count))
+      ERR_FMT(PlatformSpecific, "EnumPrintersW failed with error {}", GetLastError());
+
+    Option<String> defaultPrinter;
+    DWORD          defaultLength = 0;
+
+    GetDefaultPrinterW(nullptr, &defaultLength);
+
+    if (defaultLength > 0) {
+      WString name(defaultLength, L'\0');
+
+      if (GetDefaultPrinterW(name.data(), &defaultLength)) {
+        name.resize(defaultLength - 1);
+
+        if (Result<String> converted = helpers::ConvertWStringToUTF8(name))
+          defaultPrinter = *std::move(converted);
+      }
+    }
+
+    const auto optionalString = [](const PWCStr value) -> Option<String> {
+      if (!value || *value == L'\0')
+        return None;
+
+      Result<String> converted = helpers::ConvertWStringToUTF8(value);
+
+      return converted ? Option<String>(*std::move(converted)) : None;
+    };
+
+    Vec<PrinterInfo> printers;
+    printers.reserve(count);
+
+    for (const PRINTER_INFO_2W& printer : Span(reinterpret_cast<const PRINTER_INFO_2W*>(buffer.data()), count)) {
+      Result<String> name = helpers::ConvertWStringToUTF8(printer.pPrinterName);
+
+      if (!name)
+        continue;
+
+      PrinterInfo::State state = PrinterInfo::State::Idle;
+
+      if (printer.Status & errorStatus)
+        state = PrinterInfo::State::Error;
+      else if (printer.Status & (PRINTER_STATUS_PRINTING | PRINTER_STATUS_PROCESSING) || printer.cJobs > 0)
+        state = PrinterInfo::State::Printing;
+
+      const bool isDefault = defaultPrinter == *name;
+
+      printers.push_back({
+        .state      = state,
+        .name       = *std::move(name),
+        .driver     = optionalString(printer.pDriverName),
+        .connection = optionalString(printer.pPortName),
+        .isDefault  = isDefault,
+      });
+    }
+
+    return printers;
+  }
+
+  auto GetCameras(CacheManager& /*cache*/) -> Result<Vec<CameraInfo>> {
+    const HDEVINFO deviceInfo = SetupDiGetClassDevsW(&VIDEO_CAMERA_INTERFACE, nullptr, nullptr, DIGCF_PRESENT | DIGCF_DEVICEINTERFACE);
+
+    if (deviceInfo == INVALID_HANDLE_VALUE)
+      ERR_FMT(ApiUnavailable, "SetupDiGetClassDevsW failed with error code {}", GetLastError());
+
+    const UniquePointer<void, decltype(&SetupDiDestroyDeviceInfoList)> deviceInfoDeleter(deviceInfo, &SetupDiDestroyDeviceInfoList);
+
+    const bool inUse = camera::IsAnyCameraInUse();
+
+    Vec<CameraInfo> cameras;
+
+    SP_DEVICE_INTERFACE_DATA interfaceData { .cbSize = sizeof(SP_DEVICE_INTERFACE_DATA) };
+
+    for (DWORD index = 0; SetupDiEnumDeviceInterfaces(deviceInfo, nullptr, &VIDEO_CAMERA_INTERFACE, index, &interfaceData); ++index) {
+      DWORD detailSize = 0;
+      SetupDiGetDeviceInterfaceDetailW(deviceInfo, &interfaceData, nullptr, 0, &detailSize, nullptr);
+
+      if (detailSize == 0)
+        continue;
+
+      Vec<u8> detailBuffer(detailSize);
+      auto*   detail = reinterpret_cast<PSP_DEVICE_INTERFACE_DETAIL_DATA_W>(detailBuffer.data());
+      detail->cbSize = sizeof(SP_DEVICE_INTERFACE_DETAIL_DATA_W);
+
+      SP_DEVINFO_DATA deviceData { .cbSize = sizeof(SP_DEVINFO_DATA) };
+
+      if (!SetupDiGetDeviceInterfaceDetailW(deviceInfo, &interfaceData, detail, detailSize, nullptr, &deviceData))
+        continue;
+
+      Result<String> id = helpers::ConvertWStringToUTF8(detail->DevicePath);
+
+      if (!id)
+        continue;
+
+      // Drivers don't always set a friendly name; the device description is the fallback Device Manager uses too.
+      Option<String> name;
+
+      for (const DWORD property : { SPDRP_FRIENDLYNAME, SPDRP_DEVICEDESC }) {
+        Array<WCStr, 256> buffer {};
+
+        if (SetupDiGetDeviceRegistryPropertyW(deviceInfo, &deviceData, property, nullptr, reinterpret_cast<PBYTE>(buffer.data()), static_cast<DWORD>(buffer.size() * sizeof(WCStr)), nullptr))
+          if (Result<String> converted = helpers::ConvertWStringToUTF8(WString(buffer.data())); converted && !converted->empty()) {
+            name = *std::move(converted);
+            break;
+          }
+      }
+
+      cameras.push_back({
+        .name  = name.value_or(*id),
+        .id    = *std::move(id),
+        .inUse = inUse,
+      });
+    }
+
+    return cameras;
+  }
+
+  auto GetWifiInfo(CacheManager& /*cache*/) -> Result<WifiInfo> {
+    DWORD  negotiatedVersion = 0;
+    HANDLE client            = nullptr;
+
+    if (WlanOpenHandle(2, nullptr, &negotiatedVersion, &client) != ERROR_SUCCESS)
+      ERR(ApiUnavailable, "WlanOpenHandle failed; the WLAN AutoConfig service may not be running");
+
+    const UniquePointer<void, decltype([](HANDLE handle) -> void { WlanCloseHandle(handle, nullptr); })> clientGuard(client);
+
+    PWLAN_INTERFACE_INFO_LIST interfaceList = nullptr;
+
+    if (WlanEnumInterfaces(client, nullptr, &interfaceList) != ERROR_SUCCESS)
+      ERR(ApiUnavailable, "WlanEnumInterfaces failed");
+
+    const UniquePointer<WLAN_INTERFACE_INFO_LIST, decltype(&WlanFreeMemory)> interfaceListGuard(interfaceList, &WlanFreeMemory);
+
+    if (interfaceList->dwNumberOfItems == 0)
+      ERR(NotFound, "No wireless network interfaces found");
+
+    for (const WLAN_INTERFACE_INFO& wlanInterface : Span<const WLAN_INTERFACE_INFO>(interfaceList->InterfaceInfo, interfaceList->dwNumberOfItems)) {
+      if (wlanInterface.isState != wlan_interface_state_connected)
+        continue;
+
+      DWORD                       dataSize   = 0;
+      PWLAN_CONNECTION_ATTRIBUTES connection = nullptr;
+
+      if (WlanQueryInterface(client, &wlanInterface.InterfaceGuid, wlan_intf_opcode_current_connection, nullptr, &dataSize, reinterpret_cast<PVOID*>(&connection), nullptr) != ERROR_SUCCESS)
+        continue;
+
+      const UniquePointer<WLAN_CONNECTION_ATTRIBUTES, decltype(&WlanFreeMemory)> connectionGuard(connection, &WlanFreeMemory);
+
+      const WLAN_ASSOCIATION_ATTRIBUTES& association = connection->wlanAssociationAttributes;
+      const DOT11_SSID&                  ssid        = association.dot11Ssid;
+      const UCHAR*                       bssid       = association.dot11Bssid;
+
+      // Windows reports quality as 0-100, which it maps linearly from -100 to -50 dBm.
+      const u8 quality = static_cast<u8>(std::min<ULONG>(association.wlanSignalQuality, 100));
+
+      // NetworkInterface names adapters by their GUID string, e.g. "{4D36E972-E325-11CE-BFC1-08002BE10318}".
+      Array<WCHAR, 39> guidString {};
+      StringFromGUID2(wlanInterface.InterfaceGuid, guidString.data(), static_cast<int>(guidString.size()));
+
+      WifiInfo info {
+        .interfaceName = helpers::ConvertWStringToUTF8(guidString.data()).value_or(""),
+        .ssid          = String(reinterpret_cast<const char*>(ssid.ucSSID), std::min<ULONG>(ssid.uSSIDLength, DOT11_SSID_MAX_LENGTH)),
+        .bssid         = std::format("{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}", bssid[0], bssid[1], bssid[2], bssid[3], bssid[4], bssid[5]),
+        .signalDbm     = (quality / 2) - 100,
+        .signalPercent = quality,
+        .frequencyMhz  = None,
+        .band          = WifiInfo::Band::Unknown,
+        .linkRateMbps  = association.ulTxRate > 0 ? Option<f64>(static_cast<f64>(association.ulTxRate) / 1000.0) : None, // Reported in Kbps
+      };
+
+      PULONG channel = nullptr;
+
+      if (WlanQueryInterface(client, &wlanInterface.InterfaceGuid, wlan_intf_opcode_channel_number, nullptr, &dataSize, reinterpret_cast<PVOID*>(&channel), nullptr) == ERROR_SUCCESS) {
+        const ULONG number = *channel;
+        WlanFreeMemory(channel);
+
+        // Channel numbers alone can't distinguish 5 GHz from 6 GHz; assume 5 GHz for anything above 14.
+        if (number >= 1 && number <= 14) {
+          info.band         = WifiInfo::Band::GHz2_4;
+          info.frequencyMhz = number == 14 ? 2484 : 2407 + (5 * number);
+        } else if (number > 14) {
+          info.band         = WifiInfo::Band::GHz5;
+          info.frequencyMhz = 5000 + (5 * number);
+        }
+      }
+
+      return info;
+    }
+
+    ERR(NotFound, "No wireless network interface is connected");
+  }
+
+  auto GetDesktopEnvironment(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_desktop_environment", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
+      // Windows doesn't really have the concept of a desktop environment,
+      // so our next best bet is just displaying the UI design based on the build number.
+
+      u64 build = TRY(OsVersionCache::getInstance().getBuildNumber());
+
+      if (build >= 15063)
+        return "Fluent";
+
+      if (build >= 9200)
+        return "Metro";
+
+      if (build >= 6000)
+        return "Aero";
+
+      return "Classic";
+    });
+  }
+
+  auto GetShell(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_shell", draconis::utils::cache::CachePolicy::tempDirectory(), []() -> Result<String> {
+      using draconis::utils::env::GetEnv;
+      using shell::FindShellInProcessTree;
+
+      // MSYS2 environments automatically set the MSYSTEM environment variable.
+      if (const Result<String> msystemResult = GetEnv("MSYSTEM"); msystemResult && !msystemResult->empty()) {
+        String shellPath;
+
+        // The SHELL environment variable should basically always be set.
+        if (const Result<String> shellResult = GetEnv("SHELL"); shellResult && !shellResult->empty())
+          shellPath = *shellResult;
+
+        if (!shellPath.empty()) {
+          // Get the executable name from the path.
+          const usize lastSlash = shellPath.find_last_of("\\/");
+          String      shellExe  = (lastSlash != String::npos) ? shellPath.substr(lastSlash + 1) : shellPath;
+
+          std::ranges::transform(shellExe, shellExe.begin(), [](const u8 character) { return std::tolower(character); });
+
+          // Remove the .exe extension if it exists.
+          if (shellExe.ends_with(".exe"))
+            shellExe.resize(shellExe.length() - 4);
+
+          // Check if the executable name matches any shell in the map.
+          if (
+            const auto iter =
+              std::ranges::find_if(msysShellMap, [&](const Pair<StringView, StringView>& pair) -> bool {
+                return StringView { shellExe } == pair.first;
+              });
+            iter != std::ranges::end(msysShellMap)
+          )
+            return String(iter->second);
+
+          // If the executable name doesn't match any shell in the map, we might as well just return it as is.
+          return shellExe;
+        }
+
+        // If the SHELL environment variable is not set, we can fall back to checking the process tree.
+        // This is slower, but if we don't have the SHELL variable there's not much else we can do.
+        if (Result<String> shellResult = FindShellInProcessTree(GetCurrentProcessId(), msysShellMap))
+          return *std::move(shellResult);
+
+        // If we still can't find it, return "Unknown"
+        return String("Unknown");
+      }
+
+      // Normal windows shell environments don't set any environment variables we can check,
+      // so we have to check the process tree instead.
+      if (Result<String> shellResult = FindShellInProcessTree(GetCurrentProcessId(), windowsShellMap))
+        return *std::move(shellResult);
+
+      // If we can't find a shell, return "Unknown" instead of failing
+      return String("Unknown");
+    });
+  }
+
+  auto GetTerminal(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_terminal", draconis::utils::cache::CachePolicy::inMemory(), []() -> Result<String> {
+      using draconis::utils::env::GetEnv;
+      using shell::FindShellInProcessTree;
+
+      // Windows Terminal sets WT_SESSION for every tab, which also survives across conhost.
+      if (GetEnv("WT_SESSION"))
+        return String("Windows Terminal");
+
+      // Terminals ported from Unix (WezTerm, VS Code, mintty) still set TERM_PROGRAM.
+      if (Result<String> termProgram = GetEnv("TERM_PROGRAM"); termProgram && !termProgram->empty())
+        return *std::move(termProgram);
+
+      if (Result<String> terminalResult = FindShellInProcessTree(GetCurrentProcessId(), windowsTerminalMap))
+        return *std::move(terminalResult);
+
+      // Console programs without a known host are attached to the classic console.
+      return String("Windows Console");
+    });
+  }
+
+  auto GetTerminalFont(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_terminal_font", draconis::utils::cache::CachePolicy::inMemory(), [&cache]() -> Result<String> {
+      const String terminalName = TRY(GetTerminal(cache));
+
+      if (terminalName != "Windows Terminal")
+        ERR_FMT(NotSupported, "Reading the font of '{}' is not supported", terminalName);
+
+      return terminal::GetWindowsTerminalFont();
+    });
+  }
+
+  auto GetDiskUsage(CacheManager& /*cache*/) -> Result<ResourceUsage> {
+    // GetDiskFreeSpaceExW is a pretty old function and doesn't use native 64-bit integers,
+    // so we have to use ULARGE_INTEGER instead. It's basically a union that holds either a
+    // 64-bit integer or two 32-bit integers.
+    ULARGE_INTEGER freeBytes, totalBytes;
+
+    // Get the disk usage for the C: drive.
+    if (FAILED(GetDiskFreeSpaceExW(L"C:\\\\", nullptr, &totalBytes, &freeBytes)))
+      ERR(IoError, "Failed to get disk usage");
+
+    // Calculate the used bytes by subtracting the free bytes from the total bytes.
+    // QuadPart corresponds to the 64-bit integer in the union. (LowPart/HighPart are for the 32-bit integers.)
+    return ResourceUsage(totalBytes.QuadPart - freeBytes.QuadPart, totalBytes.QuadPart);
+  }
+
+  auto GetDisks(CacheManager& cache) -> Result<Vec<DiskInfo>> {
+    Array<char, MAX_PATH> drives = {};
+
+    DWORD size = GetLogicalDriveStringsA(MAX_PATH, drives.data());
+
+    if (size == 0)
       ERR(IoError, "Failed to get logical drive strings");
 
-    Vec<DiskInfo> disks;
+    Vec<DiskInfo> disks;
+
+    usize index = 0;
+    while (index < MAX_PATH && drives.at(index) != '\0') {
+      const char* drive = std::addressof(drives.at(index));
+
+      const Result<DiskInfo> diskInfo = GetDiskInfoForDrive(drive, cache);
+      if (diskInfo)
+        disks.push_back(*diskInfo);
+
+      // Move to next drive string (skip null terminator)
+      index += static_cast<usize>(strlen(drive)) + 1;
+    }
+
+    return disks;
+  }
+
+  auto GetSystemDisk(CacheManager& cache) -> Result<DiskInfo> {
+    // Get the system drive letter directly
+    Array<char, MAX_PATH> systemDir = {};
+    if (GetSystemDirectoryA(systemDir.data(), MAX_PATH) == 0)
+      ERR(IoError, "Failed to get system directory");
+
+    char   systemDrive = systemDir.front();
+    String driveStr    = String(1, systemDrive) + ":\\";
+
+    return GetDiskInfoForDrive(driveStr, cache);
+  }
+
+  auto GetDiskByPath(const String& path, CacheManager& cache) -> Result<DiskInfo> {
+    if (path.empty())
+      ERR(InvalidArgument, "Path cannot be empty");
+
+    char driveLetter = path[0];
+    if (path.length() >= 2 && path[1] == ':') {
+      driveLetter = path[0];
+    } else {
+      Array<char, MAX_PATH> cwd = {};
+      if (GetCurrentDirectoryA(MAX_PATH, cwd.data()) == 0)
+        ERR(IoError, "Failed to get current directory");
+
+      driveLetter = cwd[0];
+    }
+
+    if (driveLetter >= 'a' && driveLetter <= 'z') {
+      const int temp = static_cast<int>(driveLetter) - 'a' + 'A';
+      driveLetter    = static_cast<char>(temp);
+    }
+
+    String driveRoot = String(1, driveLetter) + ":\\";
+
+    return GetDiskInfoForDrive(driveRoot, cache);
+  }
+
+  auto GetDiskHealth(const StringView device) -> Result<DiskHealth> {
+    if (device.empty())
+      ERR(InvalidArgument, "Device cannot be empty");
+
+    // Accept both "PhysicalDrive0" and "\\.\PhysicalDrive0".
+    const String path = device.starts_with("\\\\") ? String(device) : std::format("\\\\.\\{}", device);
+
+    const cache::HandleWrapper<HANDLE> handle(CreateFileA(path.c_str(), GENERIC_READ | GENERIC_WRITE, FILE_SHARE_READ | FILE_SHARE_WRITE, nullptr, OPEN_EXISTING, 0, nullptr));
+
+    if (!handle) {
+      const DWORD error = GetLastError();
+
+      if (error == ERROR_ACCESS_DENIED)
+        ERR_FMT(PermissionRequired, "Opening {} requires administrator privileges", path);
+
+      ERR_FMT(NotFound, "Failed to open {}: error code {}", path, error);
+    }
+
+    const STORAGE_BUS_TYPE busType = TRY(storage::GetBusType(handle.get()));
+
+    DiskHealth health = TRY(busType == BusTypeNvme ? storage::ReadNvmeHealth(handle.get()) : storage::ReadAtaHealth(handle.get()));
+
+    health.device = path;
+
+    return health;
+  }
+
+  auto GetDiskTemperatures(CacheManager& /*cache*/) -> Result<Vec<DiskTemperature>> {
+    Vec<DiskTemperature> temperatures;
+
+    for (u32 index = 0; index < storage::MAX_PHYSICAL_DRIVES; ++index) {
+      const String name = std::format("PhysicalDrive{}", index);
+
+      // Querying properties needs no access rights, so this works without elevation.
+      const cache::HandleWrapper<HANDLE> handle(CreateFileA(std::format("\\\\.\\{}", name).c_str(), 0, FILE_SHARE_READ | FILE_SHARE_WRITE, nullptr, OPEN_EXISTING, 0, nullptr));
+
+      if (!handle)
+        continue;
+
+      if (const Option<f64> celsius = storage::ReadTemperature(handle.get()))
+        temperatures.push_back({ .device = name, .celsius = *celsius });
+    }
+
+    if (temperatures.empty())
+      ERR(NotFound, "No drive reported a temperature");
+
+    return temperatures;
+  }
+
+  auto GetDiskIOStats(CacheManager& /*cache*/) -> Result<Vec<DiskIOStats>> {
+    Vec<DiskIOStats> stats;
+
+    for (u32 index = 0; index < storage::MAX_PHYSICAL_DRIVES; ++index) {
+      const String name = std::format("PhysicalDrive{}", index);
+
+      // IOCTL_DISK_PERFORMANCE needs no access rights, so this works without elevation.
+      const cache::HandleWrapper<HANDLE> handle(CreateFileA(std::format("\\\\.\\{}", name).c_str(), 0, FILE_SHARE_READ | FILE_SHARE_WRITE, nullptr, OPEN_EXISTING, 0, nullptr));
+
+      if (!handle)
+        continue;
+
+      DISK_PERFORMANCE performance {};
+      DWORD            bytesReturned = 0;
+
+      if (!DeviceIoControl(handle.get(), IOCTL_DISK_PERFORMANCE, nullptr, 0, &performance, sizeof(performance), &bytesReturned, nullptr))
+        continue;
+
+      stats.push_back({
+        .device     = name,
+        .readOps    = performance.ReadCount,
+        .writeOps   = performance.WriteCount,
+        .readBytes  = static_cast<u64>(performance.BytesRead.QuadPart),
+        .writeBytes = static_cast<u64>(performance.BytesWritten.QuadPart),
+      });
+    }
+
+    if (stats.empty())
+      ERR(NotFound, "No drive reported I/O counters (are disk performance counters disabled?)");
+
+    return stats;
+  }
+
+  auto GetNetworkMounts(CacheManager& /*cache*/) -> Result<Vec<NetworkMount>> {
+    // Provider names of the SMB, NFS and WebDAV clients that ship with Windows.
+    const auto protocolFor = [](const PCWSTR provider) -> NetworkMount::Protocol {
+      const WStringView name = provider ? WStringView(provider) : WStringView();
+
+      if (name == L"Microsoft Windows Network")
+        return NetworkMount::Protocol::Smb;
+      if (name == L"NFS Network")
+        return NetworkMount::Protocol::Nfs;
+      if (name == L"Web Client Network")
+        return NetworkMount::Protocol::WebDav;
+
+      return NetworkMount::Protocol::Unknown;
+    };
+
+    Vec<NetworkMount> mounts;
+
+    // Connected resources come first so that a remembered drive which is also connected is reported as such;
+    // anything only remembered is a mapped drive whose server is currently unreachable.
+    for (const DWORD scope : { RESOURCE_CONNECTED, RESOURCE_REMEMBERED }) {
+      HANDLE enumHandle = nullptr;
+
+      if (WNetOpenEnumW(scope, RESOURCETYPE_DISK, 0, nullptr, &enumHandle) != NO_ERROR)
+        continue;
+
+      const UniquePointer<void, decltype([](HANDLE handle) -> void { WNetCloseEnum(handle); })> enumGuard(enumHandle);
+
+      Vec<u8> buffer(16 * 1024);
+
+      while (true) {
+        DWORD count = std::numeric_limits<DWORD>::max();
+        DWORD size  = static_cast<DWORD>(buffer.size());
+
+        const DWORD status = WNetEnumResourceW(enumHandle, &count, buffer.data(), &size);
+
+        if (status == ERROR_MORE_DATA) {
+          buffer.resize(size);
+          continue;
+        }
+
+        if (status != NO_ERROR)
+          break;
+
+        // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+        for (const NETRESOURCEW& resource : Span<const NETRESOURCEW>(reinterpret_cast<const NETRESOURCEW*>(buffer.data()), count)) {
+          if (resource.lpRemoteName == nullptr)
+            continue;
+
+          const String remote     = helpers::ConvertWStringToUTF8(resource.lpRemoteName).value_or("");
+          const String mountPoint = resource.lpLocalName ? helpers::ConvertWStringToUTF8(resource.lpLocalName).value_or("") : "";
+
+          // Remembered connections always have a drive letter.
+          if (scope == RESOURCE_REMEMBERED && std::ranges::any_of(mounts, [&](const NetworkMount& mount) -> bool { return mount.mountPoint == mountPoint; }))
+            continue;
+
+          // Remote names are UNC paths: \\server\share[\path]
+          const StringView unc       = StringView(remote).substr(remote.starts_with("\\\\") ? 2 : 0);
+          const usize      separator = unc.find('\\');
+
+          mounts.push_back({
+            .server     = String(unc.substr(0, separator)),
+            .exportPath = separator == StringView::npos ? "" : String(unc.substr(separator + 1)),
+            .mountPoint = mountPoint,
+            .protocol   = protocolFor(resource.lpProvider),
+            .state      = scope == RESOURCE_CONNECTED ? NetworkMount::State::Connected : NetworkMount::State::Disconnected,
+          });
+        }
+      }
+    }
+
+    return mounts;
+  }
+
+  auto GetCPUModel(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_cpu_model", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
+      /*
+       * This function attempts to get the CPU model name on Windows in two ways:
+       * 1. Using __cpuid on x86/x86_64 platforms (much more direct and efficient).
+       * 2. Reading from the registry on all platforms (slower, but more reliable).
+       */
+      if constexpr (DRAC_ARCH_X86_64 || DRAC_ARCH_X86) {
+        /*
+         * The CPUID instruction is used to get the CPU model name on x86/x86_64 platforms.
+         * 1. First, we call CPUID with leaf 0x80000000 to ask the CPU if it supports the
+         *    extended functions needed to retrieve the brand string.
+         * 2. If it does, we then make three more calls to retrieve the 48-byte brand
+         *    string, which the CPU provides in three 16-byte chunks.
+         *
+         * (In this context, a "leaf" is basically just an action we ask the CPU to perform.)
+         */
+
+        // Array to hold the raw 32-bit values from the EAX, EBX, ECX, and EDX registers.
+        Array<i32, 4> cpuInfo = {};
+
+        // Buffer to hold the raw 48-byte brand string + null terminator.
+        Array<char, 49> brandString = {};
+
+        // Step 1: Check for brand string support. The result is returned in EAX (cpuInfo[0]).
+  #if defined(_MSC_VER) || (defined(__clang__) && defined(_WIN32))
+        // Use __cpuidex to avoid conflict with Clang's __cpuid macro from cpuid.h
+        __cpuidex(cpuInfo.data(), static_cast<i32>(0x80000000), 0);
+  #else
+        __cpuid(0x80000000, cpuInfo[0], cpuInfo[1], cpuInfo[2], cpuInfo[3]);
+  #endif
+
+        // We must have extended functions support (functions up to 0x80000004).
+        if (const u32 maxFunction = cpuInfo[0]; maxFunction >= 0x80000004) {
+          // Retrieve the brand string in three 16-byte parts.
+          for (u32 i = 0; i < 3; i++) {
+            // Call leaves 0x80000002, 0x80000003, and 0x80000004. Each call
+            // returns a 16-byte chunk of the brand string.
+  #if defined(_MSC_VER) || (defined(__clang__) && defined(_WIN32))
+            // Use __cpuidex to avoid conflict with Clang's __cpuid macro from cpuid.h
+            __cpuidex(cpuInfo.data(), static_cast<i32>(0x80000002 + i), 0);
+  #else
+            __cpuid(0x80000002 + i, cpuInfo[0], cpuInfo[1], cpuInfo[2], cpuInfo[3]);
+  #endif
+
+            // Copy the chunk into the brand string buffer.
+            std::memcpy(&brandString.at(i * 16), cpuInfo.data(), sizeof(cpuInfo));
+          }
+
+          String result(brandString.data());
+
+          // Clean up any possible trailing whitespace.
+          while (!result.empty() && std::isspace(result.back()))
+            result.pop_back();
+
+          // We're done if we got a valid, non-empty string.
+          // Otherwise, fallback to querying the registry.
+          if (!result.empty())
+            return result;
+        }
+      } else {
+        /*
+         * If the CPUID instruction fails/is unsupported on the target architecture,
+         * we fallback to querying the registry. This is a lot more reliable than
+         * querying the CPU itself and supports all architectures, but it's also slower.
+         */
+
+        HKEY hKey = nullptr;
+
+        // This key contains information about the processor.
+        if (FAILED(RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"HARDWARE\\DESCRIPTION\\System\\CentralProcessor\\0", 0, KEY_READ, &hKey))) {
+          // Get the processor name value from the registry key.
+          Result<WString> processorNameW = GetRegistryValue(hKey, L"ProcessorNameString");
+
+          // Ensure the key is closed to prevent leaks.
+          RegCloseKey(hKey);
+
+          // The registry returns wide strings so we have to convert to UTF-8 before returning.
+          if (processorNameW)
+            return ConvertWStringToUTF8(*processorNameW);
+        }
+      }
+
+      // At this point, there's no other good method to get the CPU model on Windows.
+      // Using WMI is useless because it just calls the same registry key we're already using.
+      ERR(NotFound, "All methods to get CPU model failed on this platform");
+    });
+  }
+
+  auto GetCPUCores(CacheManager& cache) -> Result<CPUCores> {
+    return cache.getOrSet<CPUCores>("windows_cpu_cores", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<CPUCores> {
+      const DWORD logicalProcessors = GetActiveProcessorCount(ALL_PROCESSOR_GROUPS);
+      if (logicalProcessors == 0)
+        ERR_FMT(ApiUnavailable, "GetActiveProcessorCount failed with error code {}", GetLastError());
+
+      DWORD bufferSize = 0;
+
+      if (GetLogicalProcessorInformationEx(RelationProcessorCore, nullptr, &bufferSize) == FALSE && GetLastError() != ERROR_INSUFFICIENT_BUFFER)
+        ERR_FMT(ApiUnavailable, "GetLogicalProcessorInformationEx (size query) failed with error code {}", GetLastError());
+
+      Array<BYTE, 1024> buffer {};
+
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+      if (GetLogicalProcessorInformationEx(RelationProcessorCore, reinterpret_cast<PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>(buffer.data()), &bufferSize) == FALSE)
+        ERR_FMT(ApiUnavailable, "GetLogicalProcessorInformationEx (data retrieval) failed with error code {}", GetLastError());
+
+      DWORD      physicalCores = 0;
+      DWORD      offset        = 0;
+      Span<BYTE> bufferSpan(buffer);
+
+      while (offset < bufferSize) {
+        physicalCores++;
+
+        // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+        const auto* current = reinterpret_cast<PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>(&bufferSpan[offset]);
+        offset += current->Size;
+      }
+
+      return CPUCores(static_cast<u16>(physicalCores), static_cast<u16>(logicalProcessors));
+    });
+  }
+
+  auto GetCPUDetails(CacheManager& cache) -> Result<CPUDetails> {
+    return cache.getOrSet<CPUDetails>("windows_cpu_details", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<CPUDetails> {
+      using matchit::match, matchit::is, matchit::_;
+
+      SYSTEM_INFO systemInfo;
+      GetNativeSystemInfo(&systemInfo);
+
+      CPUDetails details;
+
+      details.architecture = match(systemInfo.wProcessorArchitecture)(
+        is | PROCESSOR_ARCHITECTURE_AMD64 = String("x86_64"),
+        is | PROCESSOR_ARCHITECTURE_ARM64 = String("aarch64"),
+        is | PROCESSOR_ARCHITECTURE_INTEL = String("x86"),
+        is | PROCESSOR_ARCHITECTURE_ARM   = String("arm"),
+        is | _                            = String("unknown")
+      );
+
+      if constexpr (DRAC_ARCH_X86_64 || DRAC_ARCH_X86) {
+        const auto cpuid = [](const u32 leaf, const u32 subleaf) -> Array<u32, 4> {
+          Array<i32, 4> cpuInfo = {};
+
+  #if defined(_MSC_VER) || (defined(__clang__) && defined(_WIN32))
+          // Use __cpuidex to avoid conflict with Clang's __cpuid macro from cpuid.h
+          __cpuidex(cpuInfo.data(), static_cast<i32>(leaf), static_cast<i32>(subleaf));
+  #else
+          __cpuid_count(leaf, subleaf, cpuInfo[0], cpuInfo[1], cpuInfo[2], cpuInfo[3]);
+  #endif
+
+          return { static_cast<u32>(cpuInfo[0]), static_cast<u32>(cpuInfo[1]), static_cast<u32>(cpuInfo[2]), static_cast<u32>(cpuInfo[3]) };
+        };
+
+        // Leaf 0 returns the highest basic leaf in EAX and the vendor string in EBX, EDX, ECX (in that order).
+        const Array<u32, 4> vendorInfo = cpuid(0, 0);
+        const u32           maxLeaf    = vendorInfo[0];
+        Array<char, 13>     vendor     = {};
+
+        std::memcpy(&vendor[0], &vendorInfo[1], sizeof(u32));
+        std::memcpy(&vendor[4], &vendorInfo[3], sizeof(u32));
+        std::memcpy(&vendor[8], &vendorInfo[2], sizeof(u32));
+
+        details.vendor = String(vendor.data());
+
+        if (maxLeaf >= 1) {
+          const u32 signature  = cpuid(1, 0)[0];
+          const u32 baseFamily = (signature >> 8) & 0xF;
+          const u32 baseModel  = (signature >> 4) & 0xF;
+
+          // The extended fields only apply to family 0xF, and to family 6 for the model (as Linux computes them).
+          details.family   = baseFamily == 0xF ? baseFamily + ((signature >> 20) & 0xFF) : baseFamily;
+          details.model    = baseFamily == 0x6 || baseFamily == 0xF ? baseModel | (((signature >> 16) & 0xF) << 4) : baseModel;
+          details.stepping = signature & 0xF;
+        }
+
+        struct Feature {
+          u32   leaf;
+          usize reg; // 0-3 for EAX, EBX, ECX, EDX
+          u32   bit;
+          PCStr name;
+        };
+
+        // Named as in Linux's /proc/cpuinfo.
+        // clang-format off
+        constexpr Array<Feature, 27> features = {{
+          { 1, 3, 25,         "sse" },
+          { 1, 3, 26,        "sse2" },
+          { 1, 2,  0,         "pni" },
+          { 1, 2,  1,   "pclmulqdq" },
+          { 1, 2,  9,       "ssse3" },
+          { 1, 2, 12,         "fma" },
+          { 1, 2, 19,      "sse4_1" },
+          { 1, 2, 20,      "sse4_2" },
+          { 1, 2, 23,      "popcnt" },
+          { 1, 2, 25,         "aes" },
+          { 1, 2, 28,         "avx" },
+          { 1, 2, 29,        "f16c" },
+          { 1, 2, 30,      "rdrand" },
+          { 7, 1,  3,        "bmi1" },
+          { 7, 1,  5,        "avx2" },
+          { 7, 1,  8,        "bmi2" },
+          { 7, 1, 16,     "avx512f" },
+          { 7, 1, 17,    "avx512dq" },
+          { 7, 1, 18,      "rdseed" },
+          { 7, 1, 19,         "adx" },
+          { 7, 1, 21,  "avx512ifma" },
+          { 7, 1, 28,    "avx512cd" },
+          { 7, 1, 29,      "sha_ni" },
+          { 7, 1, 30,    "avx512bw" },
+          { 7, 1, 31,    "avx512vl" },
+          { 7, 2,  1,  "avx512vbmi" },
+          { 7, 2, 11, "avx512_vnni" },
+        }};
+        // clang-format on
+
+        const Array<u32, 4> leaf1 = maxLeaf >= 1 ? cpuid(1, 0) : Array<u32, 4> {};
+        const Array<u32, 4> leaf7 = maxLeaf >= 7 ? cpuid(7, 0) : Array<u32, 4> {};
+
+        for (const Feature& feature : features)
+          if (((feature.leaf == 1 ? leaf1 : leaf7).at(feature.reg) >> feature.bit) & 1)
+            details.flags.emplace_back(feature.name);
+      } else {
+        HKEY hKey = nullptr;
+
+        if (RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"HARDWARE\\DESCRIPTION\\System\\CentralProcessor\\0", 0, KEY_READ, &hKey) == ERROR_SUCCESS) {
+          if (Result<WString> vendorW = GetRegistryValue(hKey, L"VendorIdentifier"))
+            if (Result<String> vendor = ConvertWStringToUTF8(*vendorW); vendor && !vendor->empty())
+              details.vendor = *vendor;
+
+          RegCloseKey(hKey);
+        }
+
+        for (const auto& [feature, flag] : armProcessorFeatures)
+          if (IsProcessorFeaturePresent(feature))
+            details.flags.emplace_back(flag);
+      }
+
+      return details;
+    });
+  }
+
+  auto GetCPUCaches(CacheManager& cache) -> Result<Vec<CPUCacheInfo>> {
+    return cache.getOrSet<Vec<CPUCacheInfo>>("windows_cpu_caches", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<Vec<CPUCacheInfo>> {
+      using matchit::match, matchit::is, matchit::_;
+      using enum CPUCacheInfo::Type;
+
+      DWORD bufferSize = 0;
+
+      if (GetLogicalProcessorInformationEx(RelationCache, nullptr, &bufferSize) == FALSE && GetLastError() != ERROR_INSUFFICIENT_BUFFER)
+        ERR_FMT(ApiUnavailable, "GetLogicalProcessorInformationEx (size query) failed with error code {}", GetLastError());
+
+      Vec<BYTE> buffer(bufferSize);
+
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+      if (GetLogicalProcessorInformationEx(RelationCache, reinterpret_cast<PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>(buffer.data()), &bufferSize) == FALSE)
+        ERR_FMT(ApiUnavailable, "GetLogicalProcessorInformationEx (data retrieval) failed with error code {}", GetLastError());
+
+      // Each record describes one cache instance.
+      Vec<CPUCacheInfo> caches;
+
+      for (DWORD offset = 0; offset < bufferSize;) {
+        // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+        const auto* current = reinterpret_cast<PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>(&buffer[offset]);
+        offset += current->Size;
+
+        const CACHE_RELATIONSHIP& cacheRelation = current->Cache;
+
+        // Trace caches only ever existed on the Pentium 4.
+        if (cacheRelation.Type == CacheTrace)
+          continue;
+
+        const CPUCacheInfo::Type type = match(cacheRelation.Type)(
+          is | CacheData        = Data,
+          is | CacheInstruction = Instruction,
+          is | _                = Unified
+        );
+
+        const u32 sharedCpus = static_cast<u32>(std::popcount(static_cast<u64>(cacheRelation.GroupMask.Mask)));
+
+        if (auto existing = std::ranges::find_if(caches, [&](const CPUCacheInfo& info) { return info.level == cacheRelation.Level && info.type == type; }); existing != caches.end()) {
+          ++existing->instanceCount;
+          existing->sharedCpuCount = std::max(existing->sharedCpuCount, sharedCpus);
+        } else {
+          caches.push_back({
+            .type           = type,
+            .level          = cacheRelation.Level,
+            .sizeBytes      = cacheRelation.CacheSize,
+            .lineSizeBytes  = cacheRelation.LineSize > 0 ? Option<u32>(cacheRelation.LineSize) : None,
+            .sharedCpuCount = sharedCpus,
+            .instanceCount  = 1,
+          });
+        }
+      }
+
+      if (caches.empty())
+        ERR(NotFound, "GetLogicalProcessorInformationEx reported no caches");
+
+      std::ranges::sort(caches, [](const CPUCacheInfo& lhs, const CPUCacheInfo& rhs) {
+        return std::tie(lhs.level, lhs.type) < std::tie(rhs.level, rhs.type);
+      });
+
+      return caches;
+    });
+  }
+
+  auto GetNumaTopology(CacheManager& /*cache*/) -> Result<Vec<NumaNode>> {
+    DWORD bufferSize = 0;
+
+    if (GetLogicalProcessorInformationEx(RelationNumaNode, nullptr, &bufferSize) == FALSE && GetLastError() != ERROR_INSUFFICIENT_BUFFER)
+      ERR_FMT(ApiUnavailable, "GetLogicalProcessorInformationEx (size query) failed with error code {}", GetLastError());
+
+    Vec<BYTE> buffer(bufferSize);
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+    if (GetLogicalProcessorInformationEx(RelationNumaNode, reinterpret_cast<PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>(buffer.data()), &bufferSize) == FALSE)
+      ERR_FMT(ApiUnavailable, "GetLogicalProcessorInformationEx (data retrieval) failed with error code {}", GetLastError());
+
+    Vec<NumaNode> nodes;
+
+    for (DWORD offset = 0; offset < bufferSize;) {
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+      const auto* current = reinterpret_cast<PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>(&buffer[offset]);
+      offset += current->Size;
+
+      const NUMA_NODE_RELATIONSHIP& numaRelation = current->NumaNode;
+      NumaNode                      node { .id = numaRelation.NodeNumber, .totalMemoryBytes = None, .freeMemoryBytes = None, .cpus = {} };
+
+      // Processors are numbered across groups of 64, in the same order as GetCPUTimesPerCore.
+      const GROUP_AFFINITY& affinity = numaRelation.GroupMask;
+
+      for (u32 bit = 0; bit < sizeof(KAFFINITY) * 8; ++bit)
+        if ((affinity.Mask >> bit) & 1)
+          node.cpus.push_back((static_cast<u32>(affinity.Group) * sizeof(KAFFINITY) * 8) + bit);
+
+      if (ULONGLONG availableBytes = 0; GetNumaAvailableMemoryNodeEx(static_cast<USHORT>(numaRelation.NodeNumber), &availableBytes))
+        node.freeMemoryBytes = availableBytes;
+
+      nodes.push_back(std::move(node));
+    }
+
+    if (nodes.empty())
+      ERR(NotFound, "GetLogicalProcessorInformationEx reported no NUMA nodes");
+
+    std::ranges::sort(nodes, {}, &NumaNode::id);
+
+    return nodes;
+  }
+
+  auto GetCPUVulnerabilities(CacheManager& cache) -> Result<Vec<CPUVulnerability>> {
+    return cache.getOrSet<Vec<CPUVulnerability>>("windows_cpu_vulnerabilities", draconis::utils::cache::CachePolicy::inMemory(), []() -> Result<Vec<CPUVulnerability>> {
+      using enum CPUVulnerability::State;
+
+      // Both classes return a bitfield; the bit positions follow the SpeculationControl module.
+      ULONG kvaFlags  = 0;
+      ULONG specFlags = 0;
+
+      const bool hasKva  = NtQuerySystemInformation(KERNEL_VA_SHADOW_INFORMATION, &kvaFlags, sizeof(kvaFlags), nullptr) >= 0;
+      const bool hasSpec = NtQuerySystemInformation(SPECULATION_CONTROL_INFORMATION, &specFlags, sizeof(specFlags), nullptr) >= 0;
+
+      if (!hasKva && !hasSpec)
+        ERR(NotSupported, "Speculation control information is unavailable on this version of Windows");
+
+      const auto bit = [](const ULONG flags, const u32 index) -> bool { return ((flags >> index) & 1) != 0; };
+
+      Vec<CPUVulnerability> vulnerabilities;
+
+      const auto add = [&vulnerabilities](String name, const CPUVulnerability::State state, String details) {
+        vulnerabilities.push_back({ .name = std::move(name), .state = state, .details = std::move(details) });
+      };
+
+      if (hasKva) {
+        // KvaShadowRequired (bit 4) is only meaningful when KvaShadowRequiredAvailable (bit 5) is set.
+        const bool affected = !bit(kvaFlags, 5) || bit(kvaFlags, 4);
+
+        if (!affected)
+          add("meltdown", NotAffected, "Not affected");
+        else if (bit(kvaFlags, 0))
+          add("meltdown", Mitigated, bit(kvaFlags, 2) ? "Mitigation: KVA Shadow, PCID" : "Mitigation: KVA Shadow");
+        else
+          add("meltdown", Vulnerable, "Vulnerable");
+
+        // L1 Terminal Fault affects the same CPUs as Meltdown; bit 13 is L1TerminalFaultMitigationPresent.
+        if (!affected)
+          add("l1tf", NotAffected, "Not affected");
+        else if (bit(kvaFlags, 13))
+          add("l1tf", Mitigated, "Mitigation: PTE Inversion");
+        else
+          add("l1tf", Vulnerable, "Vulnerable");
+      }
+
+      if (hasSpec) {
+        // Bits 0-2 are BpbEnabled, BpbDisabledSystemPolicy and BpbDisabledNoHardwareSupport; 14 and 16 are retpoline and enhanced IBRS.
+        if (bit(specFlags, 16))
+          add("spectre_v2", Mitigated, "Mitigation: Enhanced IBRS");
+        else if (bit(specFlags, 0))
+          add("spectre_v2", Mitigated, bit(specFlags, 14) ? "Mitigation: Retpolines" : "Mitigation: IBRS");
+        else if (bit(specFlags, 2))
+          add("spectre_v2", Vulnerable, "Vulnerable: No microcode");
+        else if (bit(specFlags, 1))
+          add("spectre_v2", Vulnerable, "Vulnerable: Disabled by system policy");
+        else
+          add("spectre_v2", Vulnerable, "Vulnerable");
+
+        // Bit 8 says the SSBD bits are valid, 10 is SSBD enabled system-wide, and 12 is SSBD required.
+        if (!bit(specFlags, 8))
+          add("spec_store_bypass", Unknown, "Unknown");
+        else if (!bit(specFlags, 12))
+          add("spec_store_bypass", NotAffected, "Not affected");
+        else if (bit(specFlags, 10))
+          add("spec_store_bypass", Mitigated, "Mitigation: Speculative Store Bypass disabled");
+        else
+          add("spec_store_bypass", Vulnerable, "Vulnerable");
+      }
+
+      std::ranges::sort(vulnerabilities, {}, &CPUVulnerability::name);
+
+      return vulnerabilities;
+    });
+  }
+
+  auto GetCPUFrequency(CacheManager& /*cache*/) -> Result<CPUFrequency> {
+    // Not declared in any SDK header, but documented for CallNtPowerInformation.
+    struct ProcessorPowerInformation {
+      ULONG number;
+      ULONG maxMhz;
+      ULONG currentMhz;
+      ULONG mhzLimit;
+      ULONG maxIdleState;
+      ULONG currentIdleState;
+    };
+
+    const DWORD logicalProcessors = GetActiveProcessorCount(ALL_PROCESSOR_GROUPS);
+    if (logicalProcessors == 0)
+      ERR_FMT(ApiUnavailable, "GetActiveProcessorCount failed with error code {}", GetLastError());
+
+    Vec<ProcessorPowerInformation> info(logicalProcessors);
+
+    const NTSTATUS status = CallNtPowerInformation(
+      ProcessorInformation,
+      nullptr,
+      0,
+      info.data(),
+      static_cast<ULONG>(info.size() * sizeof(ProcessorPowerInformation))
+    );
+
+    if (status != 0)
+      ERR_FMT(ApiUnavailable, "CallNtPowerInformation(ProcessorInformation) failed with status {:#x}", static_cast<u32>(status));
+
+    const ProcessorPowerInformation& first = info.front();
+
+    // MaxMhz is the rated (base) frequency; Windows doesn't report a boost ceiling here.
+    return CPUFrequency(
+      first.currentMhz > 0 ? Option<u64>(first.currentMhz) : None,
+      first.maxMhz > 0 ? Option<u64>(first.maxMhz) : None,
+      None
+    );
+  }
+
+  auto GetCPUTemperature(CacheManager& /*cache*/) -> Result<f64> {
+    using Microsoft::WRL::ComPtr;
 
-    usize index = 0;
-    while (index < MAX_PATH && drives.at(index) != '\0') {
-      const char* drive = std::addressof(drives.at(index));
+    const HRESULT initResult = CoInitializeEx(nullptr, COINIT_MULTITHREADED);
 
-      const Result<DiskInfo> diskInfo = GetDiskInfoForDrive(drive, cache);
-      if (diskInfo)
-        disks.push_back(*diskInfo);
+    if (FAILED(initResult) && initResult != RPC_E_CHANGED_MODE)
+      ERR_FMT(ApiUnavailable, "CoInitializeEx failed with HRESULT {:#x}", static_cast<u32>(initResult));
+
+    // Only balance the CoInitializeEx call if it actually took effect on this thread.
+    const bool shouldUninitialize = SUCCEEDED(initResult);
+
+    const auto query = [&]() -> Result<f64> {
+      ComPtr<IWbemLocator> locator;
+
+      if (FAILED(CoCreateInstance(CLSID_WbemLocator, nullptr, CLSCTX_INPROC_SERVER, IID_PPV_ARGS(&locator))))
+        ERR(ApiUnavailable, "Failed to create WbemLocator");
+
+      ComPtr<IWbemServices> services;
+
+      // NOLINTNEXTLINE(*-pro-type-const-cast) - BSTR literals are fine for read-only use here
+      if (FAILED(locator->ConnectServer(const_cast<BSTR>(L"ROOT\\WMI"), nullptr, nullptr, nullptr, 0, nullptr, nullptr, &services)))
+        ERR(ApiUnavailable, "Failed to connect to the ROOT\\WMI namespace");
+
+      if (FAILED(CoSetProxyBlanket(services.Get(), RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE, nullptr, RPC_C_AUTHN_LEVEL_CALL, RPC_C_IMP_LEVEL_IMPERSONATE, nullptr, EOAC_NONE)))
+        ERR(ApiUnavailable, "CoSetProxyBlanket failed");
+
+      ComPtr<IEnumWbemClassObject> enumerator;
+
+      if (FAILED(services->ExecQuery(
+            const_cast<BSTR>(L"WQL"),
+            const_cast<BSTR>(L"SELECT CurrentTemperature FROM MSAcpi_ThermalZoneTemperature"),
+            WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
+            nullptr,
+            &enumerator
+          )))
+        ERR(PermissionRequired, "Querying MSAcpi_ThermalZoneTemperature failed (administrator rights are usually required)");
+
+      ComPtr<IWbemClassObject> object;
+      ULONG                    returned = 0;
+
+      if (FAILED(enumerator->Next(WBEM_INFINITE, 1, &object, &returned)) || returned == 0)
+        ERR(NotFound, "No ACPI thermal zones reported by WMI");
+
+      VARIANT value;
+      VariantInit(&value);
+
+      if (FAILED(object->Get(L"CurrentTemperature", 0, &value, nullptr, nullptr)) || (value.vt != VT_I4 && value.vt != VT_UI4)) {
+        VariantClear(&value);
+        ERR(ParseError, "CurrentTemperature is missing or not an integer");
+      }
+
+      // Reported in tenths of a Kelvin
+      const f64 celsius = (static_cast<f64>(value.uintVal) / 10.0) - 273.15;
+      VariantClear(&value);
+
+      return celsius;
+    };
+
+    Result<f64> result = query();
+
+    if (shouldUninitialize)
+      CoUninitialize();
+
+    return result;
+  }
+
+  auto GetFans(CacheManager& /*cache*/) -> Result<Vec<FanInfo>> {
+    using Microsoft::WRL::ComPtr;
+
+    const HRESULT initResult = CoInitializeEx(nullptr, COINIT_MULTITHREADED);
+
+    if (FAILED(initResult) && initResult != RPC_E_CHANGED_MODE)
+      ERR_FMT(ApiUnavailable, "CoInitializeEx failed with HRESULT {:#x}", static_cast<u32>(initResult));
+
+    // Only balance the CoInitializeEx call if it actually took effect on this thread.
+    const bool shouldUninitialize = SUCCEEDED(initResult);
+
+    const auto query = [&]() -> Result<Vec<FanInfo>> {
+      ComPtr<IWbemLocator> locator;
+
+      if (FAILED(CoCreateInstance(CLSID_WbemLocator, nullptr, CLSCTX_INPROC_SERVER, IID_PPV_ARGS(&locator))))
+        ERR(ApiUnavailable, "Failed to create WbemLocator");
+
+      ComPtr<IWbemServices> services;
+
+      // NOLINTNEXTLINE(*-pro-type-const-cast) - BSTR literals are fine for read-only use here
+      if (FAILED(locator->ConnectServer(const_cast<BSTR>(L"ROOT\\CIMV2"), nullptr, nullptr, nullptr, 0, nullptr, nullptr, &services)))
+        ERR(ApiUnavailable, "Failed to connect to the ROOT\\CIMV2 namespace");
+
+      if (FAILED(CoSetProxyBlanket(services.Get(), RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE, nullptr, RPC_C_AUTHN_LEVEL_CALL, RPC_C_IMP_LEVEL_IMPERSONATE, nullptr, EOAC_NONE)))
+        ERR(ApiUnavailable, "CoSetProxyBlanket failed");
+
+      ComPtr<IEnumWbemClassObject> enumerator;
+
+      if (FAILED(services->ExecQuery(
+            const_cast<BSTR>(L"WQL"),
+            const_cast<BSTR>(L"SELECT Name, DesiredSpeed FROM Win32_Fan"),
+            WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
+            nullptr,
+            &enumerator
+          )))
+        ERR(ApiUnavailable, "Querying Win32_Fan failed");
+
+      Vec<FanInfo>             fans;
+      ComPtr<IWbemClassObject> object;
+      ULONG                    returned = 0;
+
+      while (SUCCEEDED(enumerator->Next(WBEM_INFINITE, 1, &object, &returned)) && returned != 0) {
+        FanInfo fan { .label = std::format("Fan {}", fans.size() + 1), .rpm = None, .targetRpm = None };
+
+        VARIANT value;
+        VariantInit(&value);
+
+        if (SUCCEEDED(object->Get(L"Name", 0, &value, nullptr, nullptr)) && value.vt == VT_BSTR && value.bstrVal)
+          if (Result<String> name = ConvertWStringToUTF8(value.bstrVal); name && !name->empty())
+            fan.label = std::move(*name);
+
+        VariantClear(&value);
+
+        // WMI hands uint64 properties back as decimal strings.
+        if (SUCCEEDED(object->Get(L"DesiredSpeed", 0, &value, nullptr, nullptr)) && value.vt == VT_BSTR && value.bstrVal)
+          if (const u64 speed = std::wcstoull(value.bstrVal, nullptr, 10); speed > 0)
+            fan.targetRpm = static_cast<u32>(std::min<u64>(speed, std::numeric_limits<u32>::max()));
+
+        VariantClear(&value);
+
+        fans.push_back(std::move(fan));
+        object.Reset();
+      }
+
+      if (fans.empty())
+        ERR(NotFound, "No fans reported by WMI");
+
+      return fans;
+    };
+
+    Result<Vec<FanInfo>> result = query();
+
+    if (shouldUninitialize)
+      CoUninitialize();
+
+    return result;
+  }
+
+  auto GetSensors(CacheManager& /*cache*/) -> Result<Vec<SensorReading>> {
+    using Microsoft::WRL::ComPtr;
+    using enum SensorReading::Kind;
+
+    const HRESULT initResult = CoInitializeEx(nullptr, COINIT_MULTITHREADED);
+
+    if (FAILED(initResult) && initResult != RPC_E_CHANGED_MODE)
+      ERR_FMT(ApiUnavailable, "CoInitializeEx failed with HRESULT {:#x}", static_cast<u32>(initResult));
+
+    // Only balance the CoInitializeEx call if it actually took effect on this thread.
+    const bool shouldUninitialize = SUCCEEDED(initResult);
+
+    const auto query = [&]() -> Result<Vec<SensorReading>> {
+      ComPtr<IWbemLocator> locator;
+
+      if (FAILED(CoCreateInstance(CLSID_WbemLocator, nullptr, CLSCTX_INPROC_SERVER, IID_PPV_ARGS(&locator))))
+        ERR(ApiUnavailable, "Failed to create WbemLocator");
+
+      // Runs a WQL query in a namespace, returning no enumerator if the namespace doesn't exist.
+      const auto execQuery = [&](const wchar_t* wmiNamespace, const wchar_t* wql) -> ComPtr<IEnumWbemClassObject> {
+        ComPtr<IWbemServices>        services;
+        ComPtr<IEnumWbemClassObject> enumerator;
+
+        // NOLINTBEGIN(*-pro-type-const-cast) - BSTR literals are fine for read-only use here
+        if (FAILED(locator->ConnectServer(const_cast<BSTR>(wmiNamespace), nullptr, nullptr, nullptr, 0, nullptr, nullptr, &services)) ||
+            FAILED(CoSetProxyBlanket(services.Get(), RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE, nullptr, RPC_C_AUTHN_LEVEL_CALL, RPC_C_IMP_LEVEL_IMPERSONATE, nullptr, EOAC_NONE)) ||
+            FAILED(services->ExecQuery(const_cast<BSTR>(L"WQL"), const_cast<BSTR>(wql), WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY, nullptr, &enumerator)))
+          return nullptr;
+        // NOLINTEND(*-pro-type-const-cast)
+
+        return enumerator;
+      };
+
+      const auto getString = [](IWbemClassObject* object, const wchar_t* property) -> Option<String> {
+        VARIANT value;
+        VariantInit(&value);
+
+        Option<String> result;
+
+        if (SUCCEEDED(object->Get(property, 0, &value, nullptr, nullptr)) && value.vt == VT_BSTR && value.bstrVal)
+          if (Result<String> converted = ConvertWStringToUTF8(value.bstrVal))
+            result = std::move(*converted);
+
+        VariantClear(&value);
+
+        return result;
+      };
+
+      Vec<SensorReading>       sensors;
+      ComPtr<IWbemClassObject> object;
+      ULONG                    returned = 0;
+
+      // LibreHardwareMonitor (and its predecessor) publish every sensor they read while running.
+      for (const wchar_t* wmiNamespace : { L"ROOT\\LibreHardwareMonitor", L"ROOT\\OpenHardwareMonitor" }) {
+        const ComPtr<IEnumWbemClassObject> enumerator = execQuery(wmiNamespace, L"SELECT SensorType, Name, Value, Parent FROM Sensor");
+
+        if (!enumerator)
+          continue;
+
+        while (SUCCEEDED(enumerator->Next(WBEM_INFINITE, 1, &object, &returned)) && returned != 0) {
+          const Option<String> type = getString(object.Get(), L"SensorType");
+
+          Option<SensorReading::Kind> kind;
+
+          if (type == "Temperature")
+            kind = Temperature;
+          else if (type == "Voltage")
+            kind = Voltage;
+          else if (type == "Current")
+            kind = Current;
+          else if (type == "Power")
+            kind = Power;
+          else if (type == "Fan")
+            kind = Fan;
+
+          VARIANT value;
+          VariantInit(&value);
+
+          if (kind && SUCCEEDED(object->Get(L"Value", 0, &value, nullptr, nullptr)) && value.vt == VT_R4)
+            sensors.push_back({
+              .kind  = *kind,
+              .chip  = getString(object.Get(), L"Parent").value_or("Unknown"),
+              .label = getString(object.Get(), L"Name").value_or("Unknown"),
+              .value = static_cast<f64>(value.fltVal),
+            });
+
+          VariantClear(&value);
+          object.Reset();
+        }
+
+        if (!sensors.empty())
+          break;
+      }
+
+      if (const ComPtr<IEnumWbemClassObject> enumerator = execQuery(L"ROOT\\WMI", L"SELECT InstanceName, CurrentTemperature FROM MSAcpi_ThermalZoneTemperature"))
+        while (SUCCEEDED(enumerator->Next(WBEM_INFINITE, 1, &object, &returned)) && returned != 0) {
+          VARIANT value;
+          VariantInit(&value);
+
+          // Reported in tenths of a Kelvin
+          if (SUCCEEDED(object->Get(L"CurrentTemperature", 0, &value, nullptr, nullptr)) && (value.vt == VT_I4 || value.vt == VT_UI4))
+            sensors.push_back({
+              .kind  = Temperature,
+              .chip  = "ACPI",
+              .label = getString(object.Get(), L"InstanceName").value_or("Thermal Zone"),
+              .value = (static_cast<f64>(value.uintVal) / 10.0) - 273.15,
+            });
+
+          VariantClear(&value);
+          object.Reset();
+        }
+
+      if (sensors.empty())
+        ERR(NotFound, "No sensors reported by WMI (ACPI thermal zones usually require administrator rights)");
+
+      return sensors;
+    };
+
+    Result<Vec<SensorReading>> result = query();
+
+    if (shouldUninitialize)
+      CoUninitialize();
+
+    return result;
+  }
+
+  auto GetCPUPowerWatts(CacheManager& /*cache*/) -> Result<f64> {
+    // The energy meter interface (EMI) needs a driver handle, so user mode has no public route to RAPL.
+    ERR(NotSupported, "CPU package power is not exposed to user mode on Windows");
+  }
+
+  auto GetCPUTimesPerCore() -> Result<Vec<CPUTimes>> {
+    const DWORD logicalProcessors = GetActiveProcessorCount(ALL_PROCESSOR_GROUPS);
+    if (logicalProcessors == 0)
+      ERR_FMT(ApiUnavailable, "GetActiveProcessorCount failed with error code {}", GetLastError());
+
+    Vec<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION> info(logicalProcessors);
+    ULONG                                         returnLength = 0;
+
+    const NTSTATUS status = NtQuerySystemInformation(
+      SystemProcessorPerformanceInformation,
+      info.data(),
+      static_cast<ULONG>(info.size() * sizeof(SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION)),
+      &returnLength
+    );
+
+    if (status < 0)
+      ERR_FMT(ApiUnavailable, "NtQuerySystemInformation(SystemProcessorPerformanceInformation) failed with status {:#x}", static_cast<u32>(status));
+
+    info.resize(returnLength / sizeof(SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION));
+
+    Vec<CPUTimes> times;
+    times.reserve(info.size());
+
+    // KernelTime already includes IdleTime, so kernel + user is the total.
+    for (const SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION& cpu : info)
+      times.emplace_back(
+        static_cast<u64>(cpu.IdleTime.QuadPart),
+        static_cast<u64>(cpu.KernelTime.QuadPart) + static_cast<u64>(cpu.UserTime.QuadPart)
+      );
+
+    return times;
+  }
+
+  auto GetGPUModel(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("windows_gpu_model", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
+      Microsoft::WRL::ComPtr<IDXGIFactory> factory;
+
+  #pragma clang diagnostic push
+  #pragma clang diagnostic ignored "-Wlanguage-extension-token"
+      HRESULT result = CreateDXGIFactory(IID_PPV_ARGS(&factory));
+  #pragma clang diagnostic pop
+
+      if (FAILED(result))
+        ERR_FMT(ApiUnavailable, "Failed to create DXGI factory: {}", result);
+
+      Microsoft::WRL::ComPtr<IDXGIAdapter> adapter;
+      result = factory->EnumAdapters(0, &adapter);
+
+      if (result == DXGI_ERROR_NOT_FOUND)
+        ERR(NotFound, "No GPU adapter found");
+
+      if (FAILED(result))
+        ERR(ApiUnavailable, "Failed to enumerate adapters");
+
+      DXGI_ADAPTER_DESC desc {};
+      result = adapter->GetDesc(&desc);
+
+      if (FAILED(result))
+        ERR(ApiUnavailable, "Failed to get adapter description");
+
+      const i32 length = WideCharToMultiByte(CP_UTF8, 0, desc.Description, -1, nullptr, 0, nullptr, nullptr);
+
+      if (length == 0)
+        ERR(InternalError, "Failed to convert GPU name to UTF-8");
+
+      String gpuName(length, '\0');
+      WideCharToMultiByte(CP_UTF8, 0, desc.Description, -1, gpuName.data(), length, nullptr, nullptr);
+      gpuName.resize(length - 1);
+
+      return gpuName;
+    });
+  }
+
+  auto GetGPUs(CacheManager& cache) -> Result<Vec<GPUInfo>> {
+    using draconis::utils::cache::CachePolicy;
+    using matchit::match, matchit::is, matchit::_;
+
+    Microsoft::WRL::ComPtr<IDXGIFactory1> factory;
+
+  #pragma clang diagnostic push
+  #pragma clang diagnostic ignored "-Wlanguage-extension-token"
+    if (const HRESULT result = CreateDXGIFactory1(IID_PPV_ARGS(&factory)); FAILED(result))
+      ERR_FMT(ApiUnavailable, "Failed to create DXGI factory: {}", result);
+  #pragma clang diagnostic pop
+
+    // Adapters with less dedicated memory than this are carving it out of system RAM.
+    constexpr u64 integratedVramThreshold = 512ULL * 1024 * 1024;
+
+    // Probing loads every driver, and the versions can't change without a driver update, so the results are kept.
+    const Vec<os::vulkan::Device> vulkanDevices =
+      cache.getOrSet<Vec<os::vulkan::Device>>("windows_vulkan_devices", CachePolicy::inMemory(), os::vulkan::GetDevices).value_or(Vec<os::vulkan::Device> {});
+
+    Vec<GPUInfo> gpus;
+
+    Microsoft::WRL::ComPtr<IDXGIAdapter1> adapter;
+
+    for (UINT index = 0; factory->EnumAdapters1(index, &adapter) != DXGI_ERROR_NOT_FOUND; ++index) {
+      DXGI_ADAPTER_DESC1 desc {};
+
+      if (FAILED(adapter->GetDesc1(&desc)) || (desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE))
+        continue;
+
+      String vendor = match(desc.VendorId)(
+        is | 0x1002 = String("AMD"),
+        is | 0x10DE = String("NVIDIA"),
+        is | 0x8086 = String("Intel"),
+        is | 0x5143 = String("Qualcomm"),
+        is | _      = std::format("0x{:04X}", desc.VendorId)
+      );
+
+      String model = ConvertWStringToUTF8(desc.Description).value_or("Unknown");
+
+      // The user-mode driver version is packed into four 16-bit fields.
+      Option<String> driver;
+
+      if (LARGE_INTEGER umdVersion {}; SUCCEEDED(adapter->CheckInterfaceSupport(IID_IDXGIDevice, &umdVersion)))
+        driver = std::format(
+          "{}.{}.{}.{}",
+          HIWORD(umdVersion.HighPart),
+          LOWORD(umdVersion.HighPart),
+          HIWORD(umdVersion.LowPart),
+          LOWORD(umdVersion.LowPart)
+        );
+
+      // WDDM 2.7+ drivers report the temperature (in tenths of a degree) through the kernel thunk
+      // interface. Power is only reported as a percentage of TDP, so it's left unset.
+      Option<f64> temperature;
+
+      if (D3DKMT_OPENADAPTERFROMLUID openAdapter { .AdapterLuid = desc.AdapterLuid }; D3DKMTOpenAdapterFromLuid(&openAdapter) >= 0) {
+        D3DKMT_ADAPTER_PERFDATA perfData {};
+
+        D3DKMT_QUERYADAPTERINFO queryInfo {
+          .hAdapter              = openAdapter.hAdapter,
+          .Type                  = KMTQAITYPE_ADAPTERPERFDATA,
+          .pPrivateDriverData    = &perfData,
+          .PrivateDriverDataSize = sizeof(perfData),
+        };
+
+        if (D3DKMTQueryAdapterInfo(&queryInfo) >= 0 && perfData.Temperature > 0)
+          temperature = static_cast<f64>(perfData.Temperature) / 10.0;
+
+        D3DKMT_CLOSEADAPTER closeAdapter { .hAdapter = openAdapter.hAdapter };
+        D3DKMTCloseAdapter(&closeAdapter);
+      }
+
+      GPUInfo& gpu = gpus.emplace_back(
+        std::move(vendor),
+        std::move(model),
+        static_cast<u64>(desc.DedicatedVideoMemory),
+        std::move(driver),
+        desc.DedicatedVideoMemory <= integratedVramThreshold,
+        temperature
+      );
+
+      // Vulkan's driver info is the version vendors advertise (e.g. "552.44"), unlike the UMD file version.
+      if (const auto vulkanDevice = std::ranges::find_if(vulkanDevices, [&desc](const os::vulkan::Device& device) -> bool { return device.vendorId == desc.VendorId && device.deviceId == desc.DeviceId; });
+          vulkanDevice != vulkanDevices.end()) {
+        gpu.vulkanVersion = vulkanDevice->apiVersion;
+        gpu.driverVersion = vulkanDevice->driverVersion;
+      }
+
+      if (!gpu.driverVersion)
+        gpu.driverVersion = gpu.driver;
+
+      const String featureLevelKey = std::format("windows_d3d_feature_level_{:08X}{:08X}", static_cast<u32>(desc.AdapterLuid.HighPart), desc.AdapterLuid.LowPart);
+
+      if (Result<String> featureLevel = cache.getOrSet<String>(featureLevelKey, CachePolicy::inMemory(), [&adapter]() -> Result<String> { return gpu::GetMaxFeatureLevel(adapter.Get()); }))
+        gpu.directXVersion = *std::move(featureLevel);
+    }
+
+    if (gpus.empty())
+      ERR(NotFound, "No GPU adapter found");
+
+    return gpus;
+  }
+
+
+  auto GetUptime() -> Result<std::chrono::seconds> {
+    return std::chrono::seconds(GetTickCount64() / 1000);
+  }
+
+  auto GetBootTime() -> Result<std::chrono::system_clock::time_point> {
+    SYSTEM_TIMEOFDAY_INFORMATION timeOfDay {};
+
+    const NTSTATUS status = NtQuerySystemInformation(SystemTimeOfDayInformation, &timeOfDay, sizeof(timeOfDay), nullptr);
+
+    if (status < 0)
+      ERR_FMT(ApiUnavailable, "NtQuerySystemInformation(SystemTimeOfDayInformation) failed with status {:#x}", static_cast<u32>(status));
+
+    // winternl.h leaves this struct opaque, but BootTime is its first field: a
+    // FILETIME-style count of 100ns intervals since 1601.
+    LARGE_INTEGER bootTime {};
+    std::memcpy(&bootTime, &timeOfDay, sizeof(bootTime));
+
+    constexpr i64 epochDifference = 116'444'736'000'000'000;
+
+    return std::chrono::system_clock::from_time_t(static_cast<std::time_t>((bootTime.QuadPart - epochDifference) / 10'000'000));
+  }
+
+  auto GetLoadAverage() -> Result<LoadAverage> {
+    ERR(NotSupported, "Windows does not maintain load averages");
+  }
+
+  auto GetProcesses(CacheManager& /*cache*/) -> Result<Vec<ProcessInfo>> {
+    const cache::HandleWrapper<HANDLE> snapshot(CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0));
+
+    if (!snapshot)
+      ERR_FMT(ApiUnavailable, "CreateToolhelp32Snapshot failed with error code {}", GetLastError());
+
+    PROCESSENTRY32W entry {};
+    entry.dwSize = sizeof(PROCESSENTRY32W);
+
+    if (!Process32FirstW(snapshot.get(), &entry))
+      ERR_FMT(ApiUnavailable, "Process32FirstW failed with error code {}", GetLastError());
+
+    Vec<ProcessInfo> processes;
+
+    do {
+      processes.push_back(process::ReadProcessInfo(entry));
+    } while (Process32NextW(snapshot.get(), &entry));
+
+    return processes;
+  }
+
+  auto GetProcess(const u32 pid) -> Result<ProcessInfo> {
+    const cache::HandleWrapper<HANDLE> snapshot(CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0));
+
+    if (!snapshot)
+      ERR_FMT(ApiUnavailable, "CreateToolhelp32Snapshot failed with error code {}", GetLastError());
+
+    PROCESSENTRY32W entry {};
+    entry.dwSize = sizeof(PROCESSENTRY32W);
+
+    // The snapshot is the only place the parent PID and image name are available
+    // together, but only the matching process gets opened.
+    for (BOOL more = Process32FirstW(snapshot.get(), &entry); more; more = Process32NextW(snapshot.get(), &entry))
+      if (entry.th32ProcessID == pid)
+        return process::ReadProcessInfo(entry);
+
+    ERR_FMT(NotFound, "Process {} does not exist", pid);
+  }
+
+  auto FindProcesses(const StringView name) -> Result<Vec<ProcessInfo>> {
+    const cache::HandleWrapper<HANDLE> snapshot(CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0));
+
+    if (!snapshot)
+      ERR_FMT(ApiUnavailable, "CreateToolhelp32Snapshot failed with error code {}", GetLastError());
+
+    PROCESSENTRY32W entry {};
+    entry.dwSize = sizeof(PROCESSENTRY32W);
+
+    // Image names are case-insensitive, and callers usually omit the ".exe" suffix.
+    const auto matches = [&](const String& exeName) -> bool {
+      const StringView exeView = exeName;
+      const StringView stem    = exeView.ends_with(".exe") || exeView.ends_with(".EXE") ? exeView.substr(0, exeView.size() - 4) : exeView;
+
+      const auto equalsIgnoreCase = [](const StringView lhs, const StringView rhs) -> bool {
+        return std::ranges::equal(lhs, rhs, [](const char lhsChar, const char rhsChar) -> bool {
+          return std::tolower(static_cast<unsigned char>(lhsChar)) == std::tolower(static_cast<unsigned char>(rhsChar));
+        });
+      };
+
+      return equalsIgnoreCase(exeView, name) || equalsIgnoreCase(stem, name);
+    };
+
+    Vec<ProcessInfo> processes;
+
+    for (BOOL more = Process32FirstW(snapshot.get(), &entry); more; more = Process32NextW(snapshot.get(), &entry))
+      if (Result<String> exeName = helpers::ConvertWStringToUTF8(entry.szExeFile); exeName && matches(*exeName))
+        processes.push_back(process::ReadProcessInfo(entry));
+
+    return processes;
+  }
+
+  auto GetInitSystem(CacheManager& /*cache*/) -> Result<InitSystem> {
+    return InitSystem { .kind = InitSystem::Kind::ServiceControlManager, .name = "services.exe" };
+  }
+
+  auto GetServiceStatus(const StringView name) -> Result<ServiceInfo> {
+    const scm::ServiceHandle manager(OpenSCManagerW(nullptr, nullptr, SC_MANAGER_CONNECT), &CloseServiceHandle);
+
+    if (!manager)
+      ERR_FMT(ApiUnavailable, "OpenSCManagerW failed with error code {}", GetLastError());
+
+    const WString wideName = scm::ToWide(name);
+
+    const scm::ServiceHandle service(OpenServiceW(manager.get(), wideName.c_str(), SERVICE_QUERY_STATUS | SERVICE_QUERY_CONFIG), &CloseServiceHandle);
+
+    if (!service) {
+      const DWORD error = GetLastError();
+
+      if (error == ERROR_SERVICE_DOES_NOT_EXIST || error == ERROR_INVALID_NAME)
+        ERR_FMT(NotFound, "Service '{}' does not exist", name);
+
+      if (error == ERROR_ACCESS_DENIED)
+        ERR_FMT(PermissionDenied, "Access denied while opening service '{}'", name);
+
+      ERR_FMT(ApiUnavailable, "OpenServiceW failed with error code {}", error);
+    }
+
+    SERVICE_STATUS_PROCESS status {};
+    DWORD                  bytesNeeded = 0;
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - QueryServiceStatusEx takes the status struct as a byte buffer.
+    if (!QueryServiceStatusEx(service.get(), SC_STATUS_PROCESS_INFO, reinterpret_cast<LPBYTE>(&status), sizeof(status), &bytesNeeded))
+      ERR_FMT(ApiUnavailable, "QueryServiceStatusEx failed with error code {}", GetLastError());
+
+    ServiceInfo info;
+    info.name  = String(name);
+    info.state = scm::StateFromStatus(status);
+
+    scm::ReadConfig(service.get(), info);
+
+    return info;
+  }
+
+  auto ListServices() -> Result<Vec<ServiceInfo>> {
+    const scm::ServiceHandle manager(OpenSCManagerW(nullptr, nullptr, SC_MANAGER_CONNECT | SC_MANAGER_ENUMERATE_SERVICE), &CloseServiceHandle);
+
+    if (!manager)
+      ERR_FMT(ApiUnavailable, "OpenSCManagerW failed with error code {}", GetLastError());
+
+    DWORD     bytesNeeded  = 0;
+    DWORD     serviceCount = 0;
+    DWORD     resumeHandle = 0;
+    Vec<BYTE> buffer;
+
+    // The service list can grow between the sizing call and the real one, so retry until it fits.
+    while (true) {
+      const BOOL ok = EnumServicesStatusExW(
+        manager.get(),
+        SC_ENUM_PROCESS_INFO,
+        SERVICE_WIN32,
+        SERVICE_STATE_ALL,
+        buffer.empty() ? nullptr : buffer.data(),
+        static_cast<DWORD>(buffer.size()),
+        &bytesNeeded,
+        &serviceCount,
+        &resumeHandle,
+        nullptr
+      );
+
+      if (ok)
+        break;
+
+      if (GetLastError() != ERROR_MORE_DATA)
+        ERR_FMT(ApiUnavailable, "EnumServicesStatusExW failed with error code {}", GetLastError());
+
+      buffer.resize(buffer.size() + bytesNeeded);
+      resumeHandle = 0;
+    }
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - The SCM fills the buffer with an array of ENUM_SERVICE_STATUS_PROCESSW.
+    const Span<const ENUM_SERVICE_STATUS_PROCESSW> entries(reinterpret_cast<const ENUM_SERVICE_STATUS_PROCESSW*>(buffer.data()), serviceCount);
+
+    Vec<ServiceInfo> servicesList;
+    servicesList.reserve(entries.size());
+
+    for (const ENUM_SERVICE_STATUS_PROCESSW& entry : entries) {
+      Result<String> serviceName = ConvertWStringToUTF8(entry.lpServiceName);
+
+      if (!serviceName)
+        continue;
+
+      ServiceInfo info;
+      info.name  = std::move(*serviceName);
+      info.state = scm::StateFromStatus(entry.ServiceStatusProcess);
 
-      // Move to next drive string (skip null terminator)
-      index += static_cast<usize>(strlen(drive)) + 1;
+      // The start type lives in the service configuration, which needs its own handle.
+      if (const scm::ServiceHandle service(OpenServiceW(manager.get(), entry.lpServiceName, SERVICE_QUERY_CONFIG), &CloseServiceHandle); service)
+        scm::ReadConfig(service.get(), info);
+      else if (entry.lpDisplayName && *entry.lpDisplayName)
+        if (Result<String> displayName = ConvertWStringToUTF8(entry.lpDisplayName); displayName && *displayName != info.name)
+          info.description = std::move(*displayName);
+
+      servicesList.push_back(std::move(info));
     }
 
-    return disks;
+    return servicesList;
   }
 
-  auto GetSystemDisk(CacheManager& cache) -> Result<DiskInfo> {
-    // Get the system drive letter directly
-    Array<char, MAX_PATH> systemDir = {};
-    if (GetSystemDirectoryA(systemDir.data(), MAX_PATH) == 0)
-      ERR(IoError, "Failed to get system directory");
-
-    char   systemDrive = systemDir.front();
-    String driveStr    = String(1, systemDrive) + ":\\";
+  auto GetContainers(CacheManager& /*cache*/) -> Result<Vec<ContainerInfo>> {
+    // Docker Desktop serves its API on a named pipe rather than a socket. Containers run inside
+    // its VM, so there are no cgroups to read usage from here.
+    const cache::HandleWrapper<HANDLE> pipe(CreateFileW(LR"(\\.\pipe\docker_engine)", GENERIC_READ | GENERIC_WRITE, 0, nullptr, OPEN_EXISTING, 0, nullptr));
 
-    return GetDiskInfoForDrive(driveStr, cache);
-  }
+    if (!pipe) {
+      const DWORD error = GetLastError();
 
-  auto GetDiskByPath(const String& path, CacheManager& cache) -> Result<DiskInfo> {
-    if (path.empty())
-      ERR(InvalidArgument, "Path cannot be empty");
+      if (error == ERROR_FILE_NOT_FOUND)
+        ERR(NotFound, "No container runtime is running");
 
-    char driveLetter = path[0];
-    if (path.length() >= 2 && path[1] == ':') {
-      driveLetter = path[0];
-    } else {
-      Array<char, MAX_PATH> cwd = {};
-      if (GetCurrentDirectoryA(MAX_PATH, cwd.data()) == 0)
-        ERR(IoError, "Failed to get current directory");
+      if (error == ERROR_ACCESS_DENIED)
+        ERR(PermissionDenied, "Permission denied connecting to the Docker engine pipe");
 
-      driveLetter = cwd[0];
+      ERR_FMT(ApiUnavailable, "CreateFileW failed with error code {}", error);
     }
 
-    if (driveLetter >= 'a' && driveLetter <= 'z') {
-      const int temp = static_cast<int>(driveLetter) - 'a' + 'A';
-      driveLetter    = static_cast<char>(temp);
-    }
+    const String request = std::format("GET {} HTTP/1.0\r\nHost: localhost\r\nUser-Agent: draconis++\r\n\r\n", os::containers::LIST_PATH);
 
-    String driveRoot = String(1, driveLetter) + ":\\";
+    if (DWORD written = 0; !WriteFile(pipe.get(), request.data(), static_cast<DWORD>(request.size()), &written, nullptr) || written != request.size())
+      ERR_FMT(ApiUnavailable, "WriteFile failed with error code {}", GetLastError());
 
-    return GetDiskInfoForDrive(driveRoot, cache);
+    // The engine closes the pipe after an HTTP/1.0 response, which ends the read loop.
+    String            response;
+    Array<char, 4096> buffer {};
+    DWORD             bytesRead = 0;
+
+    while (response.size() < os::containers::MAX_LIST_RESPONSE && ReadFile(pipe.get(), buffer.data(), static_cast<DWORD>(buffer.size()), &bytesRead, nullptr) && bytesRead > 0)
+      response.append(buffer.data(), bytesRead);
+
+    const draconis::services::http::Response parsed = TRY(draconis::services::http::ParseResponse(response, "docker_engine"));
+
+    return os::containers::ParseContainerList(parsed, ContainerInfo::Runtime::Docker);
   }
 
-  auto GetCPUModel(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("windows_cpu_model", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
-      /*
-       * This function attempts to get the CPU model name on Windows in two ways:
-       * 1. Using __cpuid on x86/x86_64 platforms (much more direct and efficient).
-       * 2. Reading from the registry on all platforms (slower, but more reliable).
-       */
-      if constexpr (DRAC_ARCH_X86_64 || DRAC_ARCH_X86) {
-        /*
-         * The CPUID instruction is used to get the CPU model name on x86/x86_64 platforms.
-         * 1. First, we call CPUID with leaf 0x80000000 to ask the CPU if it supports the
-         *    extended functions needed to retrieve the brand string.
-         * 2. If it does, we then make three more calls to retrieve the 48-byte brand
-         *    string, which the CPU provides in three 16-byte chunks.
-         *
-         * (In this context, a "leaf" is basically just an action we ask the CPU to perform.)
-         */
+  auto GetSessions() -> Result<Vec<SessionInfo>> {
+    PWTS_SESSION_INFOW sessionInfos = nullptr;
+    DWORD              sessionCount = 0;
 
-        // Array to hold the raw 32-bit values from the EAX, EBX, ECX, and EDX registers.
-        Array<i32, 4> cpuInfo = {};
+    if (!WTSEnumerateSessionsW(WTS_CURRENT_SERVER_HANDLE, 0, 1, &sessionInfos, &sessionCount))
+      ERR_FMT(ApiUnavailable, "WTSEnumerateSessionsW failed with error code {}", GetLastError());
 
-        // Buffer to hold the raw 48-byte brand string + null terminator.
-        Array<char, 49> brandString = {};
+    const auto queryString = [](const DWORD sessionId, const WTS_INFO_CLASS infoClass) -> Option<String> {
+      LPWSTR buffer = nullptr;
+      DWORD  bytes  = 0;
 
-        // Step 1: Check for brand string support. The result is returned in EAX (cpuInfo[0]).
-  #if defined(_MSC_VER) || (defined(__clang__) && defined(_WIN32))
-        // Use __cpuidex to avoid conflict with Clang's __cpuid macro from cpuid.h
-        __cpuidex(cpuInfo.data(), static_cast<i32>(0x80000000), 0);
-  #else
-        __cpuid(0x80000000, cpuInfo[0], cpuInfo[1], cpuInfo[2], cpuInfo[3]);
-  #endif
+      if (!WTSQuerySessionInformationW(WTS_CURRENT_SERVER_HANDLE, sessionId, infoClass, &buffer, &bytes) || buffer == nullptr)
+        return None;
 
-        // We must have extended functions support (functions up to 0x80000004).
-        if (const u32 maxFunction = cpuInfo[0]; maxFunction >= 0x80000004) {
-          // Retrieve the brand string in three 16-byte parts.
-          for (u32 i = 0; i < 3; i++) {
-            // Call leaves 0x80000002, 0x80000003, and 0x80000004. Each call
-            // returns a 16-byte chunk of the brand string.
-  #if defined(_MSC_VER) || (defined(__clang__) && defined(_WIN32))
-            // Use __cpuidex to avoid conflict with Clang's __cpuid macro from cpuid.h
-            __cpuidex(cpuInfo.data(), static_cast<i32>(0x80000002 + i), 0);
-  #else
-            __cpuid(0x80000002 + i, cpuInfo[0], cpuInfo[1], cpuInfo[2], cpuInfo[3]);
-  #endif
+      Result<String> value = helpers::ConvertWStringToUTF8(WString(buffer));
+      WTSFreeMemory(buffer);
 
-            // Copy the chunk into the brand string buffer.
-            std::memcpy(&brandString.at(i * 16), cpuInfo.data(), sizeof(cpuInfo));
-          }
+      if (!value || value->empty())
+        return None;
 
-          String result(brandString.data());
+      return std::move(*value);
+    };
 
-          // Clean up any possible trailing whitespace.
-          while (!result.empty() && std::isspace(result.back()))
-            result.pop_back();
+    Vec<SessionInfo> sessions;
 
-          // We're done if we got a valid, non-empty string.
-          // Otherwise, fallback to querying the registry.
-          if (!result.empty())
-            return result;
-        }
-      } else {
-        /*
-         * If the CPUID instruction fails/is unsupported on the target architecture,
-         * we fallback to querying the registry. This is a lot more reliable than
-         * querying the CPU itself and supports all architectures, but it's also slower.
-         */
+    for (const WTS_SESSION_INFOW& session : Span<WTS_SESSION_INFOW>(sessionInfos, sessionCount)) {
+      // Services and listener sessions have no user attached.
+      Option<String> username = queryString(session.SessionId, WTSUserName);
 
-        HKEY hKey = nullptr;
+      if (!username)
+        continue;
 
-        // This key contains information about the processor.
-        if (FAILED(RegOpenKeyExW(HKEY_LOCAL_MACHINE, L"HARDWARE\\DESCRIPTION\\System\\CentralProcessor\\0", 0, KEY_READ, &hKey))) {
-          // Get the processor name value from the registry key.
-          Result<WString> processorNameW = GetRegistryValue(hKey, L"ProcessorNameString");
+      // 0 is the local console; anything else (ICA, RDP) is a remote client.
+      USHORT* protocol = nullptr;
+      DWORD   bytes    = 0;
+      bool    isRemote = false;
 
-          // Ensure the key is closed to prevent leaks.
-          RegCloseKey(hKey);
+      if (WTSQuerySessionInformationW(WTS_CURRENT_SERVER_HANDLE, session.SessionId, WTSClientProtocolType, reinterpret_cast<LPWSTR*>(&protocol), &bytes) && protocol != nullptr) {
+        isRemote = *protocol != 0;
+        WTSFreeMemory(protocol);
+      }
 
-          // The registry returns wide strings so we have to convert to UTF-8 before returning.
-          if (processorNameW)
-            return ConvertWStringToUTF8(*processorNameW);
-        }
+      // LogonTime is a FILETIME-style count of 100ns intervals since 1601.
+      Option<std::chrono::system_clock::time_point> loginTime;
+      WTSINFOW*                                     info = nullptr;
+
+      if (WTSQuerySessionInformationW(WTS_CURRENT_SERVER_HANDLE, session.SessionId, WTSSessionInfo, reinterpret_cast<LPWSTR*>(&info), &bytes) && info != nullptr) {
+        constexpr i64 epochDifference = 116'444'736'000'000'000;
+
+        if (info->LogonTime.QuadPart > epochDifference)
+          loginTime = std::chrono::system_clock::from_time_t(static_cast<std::time_t>((info->LogonTime.QuadPart - epochDifference) / 10'000'000));
+
+        WTSFreeMemory(info);
       }
 
-      // At this point, there's no other good method to get the CPU model on Windows.
-      // Using WMI is useless because it just calls the same registry key we're already using.
-      ERR(NotFound, "All methods to get CPU model failed on this platform");
-    });
+      sessions.push_back({
+        .type       = isRemote ? SessionInfo::Type::Remote : SessionInfo::Type::Graphical,
+        .username   = std::move(*username),
+        .terminal   = helpers::ConvertWStringToUTF8(session.pWinStationName).value_or(""),
+        .remoteHost = isRemote ? queryString(session.SessionId, WTSClientName) : None,
+        .loginTime  = loginTime,
+      });
+    }
+
+    WTSFreeMemory(sessionInfos);
+
+    return sessions;
   }
 
-  auto GetCPUCores(CacheManager& cache) -> Result<CPUCores> {
-    return cache.getOrSet<CPUCores>("windows_cpu_cores", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<CPUCores> {
-      const DWORD logicalProcessors = GetActiveProcessorCount(ALL_PROCESSOR_GROUPS);
-      if (logicalProcessors == 0)
-        ERR_FMT(ApiUnavailable, "GetActiveProcessorCount failed with error code {}", GetLastError());
+  auto GetCurrentUser() -> Result<UserInfo> {
+    using draconis::utils::env::GetEnv;
 
-      DWORD bufferSize = 0;
+    Array<WCHAR, UNLEN + 1> name {};
+    DWORD                   nameLength = name.size();
 
-      if (GetLogicalProcessorInformationEx(RelationProcessorCore, nullptr, &bufferSize) == FALSE && GetLastError() != ERROR_INSUFFICIENT_BUFFER)
-        ERR_FMT(ApiUnavailable, "GetLogicalProcessorInformationEx (size query) failed with error code {}", GetLastError());
+    if (!GetUserNameW(name.data(), &nameLength))
+      ERR_FMT(ApiUnavailable, "GetUserNameW failed with error code {}", GetLastError());
 
-      Array<BYTE, 1024> buffer {};
+    // nameLength includes the null terminator on success.
+    String username = TRY(helpers::ConvertWStringToUTF8(WString(name.data(), nameLength - 1)));
 
-      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
-      if (GetLogicalProcessorInformationEx(RelationProcessorCore, reinterpret_cast<PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>(buffer.data()), &bufferSize) == FALSE)
-        ERR_FMT(ApiUnavailable, "GetLogicalProcessorInformationEx (data retrieval) failed with error code {}", GetLastError());
+    HANDLE rawToken = nullptr;
 
-      DWORD      physicalCores = 0;
-      DWORD      offset        = 0;
-      Span<BYTE> bufferSpan(buffer);
+    if (!OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &rawToken))
+      ERR_FMT(ApiUnavailable, "OpenProcessToken failed with error code {}", GetLastError());
 
-      while (offset < bufferSize) {
-        physicalCores++;
+    const cache::HandleWrapper<HANDLE> token(rawToken);
 
-        // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
-        const auto* current = reinterpret_cast<PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>(&bufferSpan[offset]);
-        offset += current->Size;
-      }
+    DWORD length = 0;
+    GetTokenInformation(token.get(), TokenUser, nullptr, 0, &length);
 
-      return CPUCores(static_cast<u16>(physicalCores), static_cast<u16>(logicalProcessors));
-    });
+    Vec<BYTE> buffer(length);
+
+    if (length == 0 || !GetTokenInformation(token.get(), TokenUser, buffer.data(), length, &length))
+      ERR_FMT(ApiUnavailable, "GetTokenInformation failed with error code {}", GetLastError());
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - the buffer is laid out as a TOKEN_USER by the kernel
+    const auto* tokenUser = reinterpret_cast<const TOKEN_USER*>(buffer.data());
+
+    Option<String> sid;
+    LPWSTR         sidString = nullptr;
+
+    if (ConvertSidToStringSidW(tokenUser->User.Sid, &sidString)) {
+      if (Result<String> converted = helpers::ConvertWStringToUTF8(WString(sidString)))
+        sid = std::move(*converted);
+
+      LocalFree(sidString);
+    }
+
+    String homeDirectory;
+
+    if (const Result<WString> userProfile = GetEnv(L"USERPROFILE"))
+      homeDirectory = helpers::ConvertWStringToUTF8(*userProfile).value_or("");
+
+    return UserInfo {
+      .username      = std::move(username),
+      .uid           = None,
+      .gid           = None,
+      .sid           = std::move(sid),
+      .homeDirectory = std::move(homeDirectory),
+      .shell         = None,
+    };
   }
 
-  auto GetGPUModel(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("windows_gpu_model", draconis::utils::cache::CachePolicy::neverExpire(), []() -> Result<String> {
-      Microsoft::WRL::ComPtr<IDXGIFactory> factory;
+  auto GetLocale() -> Result<String> {
+    Array<WCHAR, LOCALE_NAME_MAX_LENGTH> name {};
 
-  #pragma clang diagnostic push
-  #pragma clang diagnostic ignored "-Wlanguage-extension-token"
-      HRESULT result = CreateDXGIFactory(IID_PPV_ARGS(&factory));
-  #pragma clang diagnostic pop
+    if (GetUserDefaultLocaleName(name.data(), static_cast<int>(name.size())) == 0)
+      ERR_FMT(ApiUnavailable, "GetUserDefaultLocaleName failed with error code {}", GetLastError());
 
-      if (FAILED(result))
-        ERR_FMT(ApiUnavailable, "Failed to create DXGI factory: {}", result);
+    return helpers::ConvertWStringToUTF8(WString(name.data()));
+  }
 
-      Microsoft::WRL::ComPtr<IDXGIAdapter> adapter;
-      result = factory->EnumAdapters(0, &adapter);
+  auto GetTimezone() -> Result<TimezoneInfo> {
+    DYNAMIC_TIME_ZONE_INFORMATION tzInfo {};
 
-      if (result == DXGI_ERROR_NOT_FOUND)
-        ERR(NotFound, "No GPU adapter found");
+    const DWORD zoneId = GetDynamicTimeZoneInformation(&tzInfo);
 
-      if (FAILED(result))
-        ERR(ApiUnavailable, "Failed to enumerate adapters");
+    if (zoneId == TIME_ZONE_ID_INVALID)
+      ERR_FMT(ApiUnavailable, "GetDynamicTimeZoneInformation failed with error code {}", GetLastError());
 
-      DXGI_ADAPTER_DESC desc {};
-      result = adapter->GetDesc(&desc);
+    // Bias is in minutes and has the opposite sign of the UTC offset.
+    const LONG bias = tzInfo.Bias + (zoneId == TIME_ZONE_ID_DAYLIGHT ? tzInfo.DaylightBias : tzInfo.StandardBias);
 
-      if (FAILED(result))
-        ERR(ApiUnavailable, "Failed to get adapter description");
+    String name;
 
-      const i32 length = WideCharToMultiByte(CP_UTF8, 0, desc.Description, -1, nullptr, 0, nullptr, nullptr);
+    // The standard library maps the Windows zone to its IANA name through ICU, which
+    // throws if the tzdb isn't available (e.g. on older Windows 10 builds).
+    try {
+      name = String(std::chrono::current_zone()->name());
+    } catch (const std::runtime_error&) {
+      name = TRY(helpers::ConvertWStringToUTF8(WString(tzInfo.TimeZoneKeyName)));
+    }
 
-      if (length == 0)
-        ERR(InternalError, "Failed to convert GPU name to UTF-8");
+    return TimezoneInfo {
+      .name             = std::move(name),
+      .utcOffsetSeconds = static_cast<i32>(-bias * 60),
+    };
+  }
 
-      String gpuName(length, '\0');
-      WideCharToMultiByte(CP_UTF8, 0, desc.Description, -1, gpuName.data(), length, nullptr, nullptr);
-      gpuName.resize(length - 1);
+  auto GetHostname() -> Result<String> {
+    return helpers::GetComputerNameUTF8(ComputerNameDnsHostname);
+  }
 
-      return gpuName;
-    });
+  auto GetFQDN() -> Result<String> {
+    // Machines outside a domain have no primary DNS suffix, so this is just the hostname there.
+    return helpers::GetComputerNameUTF8(ComputerNameDnsFullyQualified);
   }
 
-  auto GetUptime() -> Result<std::chrono::seconds> {
-    return std::chrono::seconds(GetTickCount64() / 1000);
+  auto GetKeyboardLayouts() -> Result<Vec<KeyboardLayout>> {
+    const i32 count = GetKeyboardLayoutList(0, nullptr);
+
+    if (count <= 0)
+      ERR(NotFound, "No keyboard layouts are installed");
+
+    Vec<HKL> handles(static_cast<usize>(count));
+    handles.resize(static_cast<usize>(std::max(GetKeyboardLayoutList(count, handles.data()), 0)));
+
+    // The active layout is per thread; the foreground window's thread is the one receiving the user's input.
+    const HKL active = GetKeyboardLayout(GetWindowThreadProcessId(GetForegroundWindow(), nullptr));
+
+    Vec<KeyboardLayout> layouts;
+    layouts.reserve(handles.size());
+
+    for (const HKL handle : handles)
+      layouts.push_back(keyboard::LayoutFromHkl(handle, handle == active));
+
+    return layouts;
   }
 
   auto GetOutputs(CacheManager& /*cache*/) -> Result<Vec<DisplayInfo>> {
@@ -1119,7 +4309,7 @@ namespace draconis::core::system {
         if (mode.infoType != DISPLAYCONFIG_MODE_INFO_TYPE_TARGET)
           continue;
 
-        outputs.emplace_back(DisplayInfo(
+        DisplayInfo& display = outputs.emplace_back(DisplayInfo(
           path.targetInfo.id,
           { .width = mode.targetMode.targetVideoSignalInfo.activeSize.cx, .height = mode.targetMode.targetVideoSignalInfo.activeSize.cy },
           mode.targetMode.targetVideoSignalInfo.totalSize.cx != 0 && mode.targetMode.targetVideoSignalInfo.totalSize.cy != 0
@@ -1127,6 +4317,8 @@ namespace draconis::core::system {
             : 0,
           (path.flags & DISPLAYCONFIG_PATH_ACTIVE) != 0
         ));
+
+        display::ApplyPathDetails(display, path, modes);
       }
     }
     // NOLINTEND(*-pro-type-union-access)
@@ -1165,7 +4357,7 @@ namespace draconis::core::system {
 
         const DISPLAYCONFIG_VIDEO_SIGNAL_INFO& videoSignalInfo = targetModeInfo.targetMode.targetVideoSignalInfo;
 
-        return DisplayInfo(
+        DisplayInfo display(
           path.targetInfo.id,
           { .width = videoSignalInfo.activeSize.cx, .height = videoSignalInfo.activeSize.cy },
           videoSignalInfo.totalSize.cx != 0 && videoSignalInfo.totalSize.cy != 0
@@ -1173,6 +4365,10 @@ namespace draconis::core::system {
             : 0,
           true
         );
+
+        display::ApplyPathDetails(display, path, modes);
+
+        return display;
       }
     }
     // NOLINTEND(*-pro-type-union-access)
@@ -1180,6 +4376,118 @@ namespace draconis::core::system {
     ERR(NotFound, "No primary display found with QueryDisplayConfig");
   }
 
+  auto GetBrightness(CacheManager& /*cache*/) -> Result<Vec<BacklightInfo>> {
+    using Microsoft::WRL::ComPtr;
+
+    Vec<BacklightInfo> backlights;
+
+    // Built-in panels report their backlight level through WMI.
+    const auto queryPanels = [&]() -> Result<Unit> {
+      ComPtr<IWbemLocator> locator;
+
+      if (FAILED(CoCreateInstance(CLSID_WbemLocator, nullptr, CLSCTX_INPROC_SERVER, IID_PPV_ARGS(&locator))))
+        ERR(ApiUnavailable, "Failed to create WbemLocator");
+
+      ComPtr<IWbemServices> services;
+
+      // NOLINTNEXTLINE(*-pro-type-const-cast) - BSTR literals are fine for read-only use here
+      if (FAILED(locator->ConnectServer(const_cast<BSTR>(L"ROOT\\WMI"), nullptr, nullptr, nullptr, 0, nullptr, nullptr, &services)))
+        ERR(ApiUnavailable, "Failed to connect to the ROOT\\WMI namespace");
+
+      if (FAILED(CoSetProxyBlanket(services.Get(), RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE, nullptr, RPC_C_AUTHN_LEVEL_CALL, RPC_C_IMP_LEVEL_IMPERSONATE, nullptr, EOAC_NONE)))
+        ERR(ApiUnavailable, "CoSetProxyBlanket failed");
+
+      ComPtr<IEnumWbemClassObject> enumerator;
+
+      // NOLINTBEGIN(*-pro-type-const-cast)
+      if (FAILED(services->ExecQuery(
+            const_cast<BSTR>(L"WQL"),
+            const_cast<BSTR>(L"SELECT InstanceName, CurrentBrightness FROM WmiMonitorBrightness WHERE Active = TRUE"),
+            WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
+            nullptr,
+            &enumerator
+          )))
+        ERR(ApiUnavailable, "Querying WmiMonitorBrightness failed");
+      // NOLINTEND(*-pro-type-const-cast)
+
+      ComPtr<IWbemClassObject> object;
+      ULONG                    returned = 0;
+
+      while (SUCCEEDED(enumerator->Next(WBEM_INFINITE, 1, &object, &returned)) && returned != 0) {
+        VARIANT name;
+        VARIANT level;
+        VariantInit(&name);
+        VariantInit(&level);
+
+        if (SUCCEEDED(object->Get(L"InstanceName", 0, &name, nullptr, nullptr)) && name.vt == VT_BSTR && name.bstrVal &&
+            SUCCEEDED(object->Get(L"CurrentBrightness", 0, &level, nullptr, nullptr)) && level.vt == VT_UI1)
+          if (Result<String> device = ConvertWStringToUTF8(name.bstrVal))
+            backlights.push_back({ .device = std::move(*device), .percent = std::min(100.0, static_cast<f64>(level.bVal)) });
+
+        VariantClear(&name);
+        VariantClear(&level);
+        object.Reset();
+      }
+
+      return {};
+    };
+
+    const HRESULT initResult = CoInitializeEx(nullptr, COINIT_MULTITHREADED);
+
+    if (SUCCEEDED(initResult) || initResult == RPC_E_CHANGED_MODE) {
+      // Desktops have no WmiMonitorBrightness instances at all, so a failure here isn't fatal.
+      if (Result<Unit> panels = queryPanels(); !panels)
+        debug_at(panels.error());
+
+      if (SUCCEEDED(initResult))
+        CoUninitialize();
+    }
+
+    // External monitors answer over DDC/CI; built-in panels fail this and were covered above.
+    Vec<HMONITOR> monitors;
+
+    EnumDisplayMonitors(
+      nullptr,
+      nullptr,
+      [](HMONITOR monitor, HDC /*hdc*/, LPRECT /*rect*/, const LPARAM data) -> BOOL {
+        reinterpret_cast<Vec<HMONITOR>*>(data)->push_back(monitor);
+        return TRUE;
+      },
+      reinterpret_cast<LPARAM>(&monitors)
+    );
+
+    for (HMONITOR monitor : monitors) {
+      DWORD physicalCount = 0;
+
+      if (!GetNumberOfPhysicalMonitorsFromHMONITOR(monitor, &physicalCount) || physicalCount == 0)
+        continue;
+
+      Vec<PHYSICAL_MONITOR> physicalMonitors(physicalCount);
+
+      if (!GetPhysicalMonitorsFromHMONITOR(monitor, physicalCount, physicalMonitors.data()))
+        continue;
+
+      for (const PHYSICAL_MONITOR& physical : physicalMonitors) {
+        DWORD minimum = 0;
+        DWORD current = 0;
+        DWORD maximum = 0;
+
+        if (GetMonitorBrightness(physical.hPhysicalMonitor, &minimum, &current, &maximum) && maximum > minimum)
+          backlights.push_back({
+            .device  = ConvertWStringToUTF8(physical.szPhysicalMonitorDescription).value_or("Monitor"),
+            .percent = std::clamp((static_cast<f64>(current) - static_cast<f64>(minimum)) * 100.0 / static_cast<f64>(maximum - minimum), 0.0, 100.0),
+          });
+      }
+
+      DestroyPhysicalMonitors(physicalCount, physicalMonitors.data());
+    }
+
+    if (backlights.empty())
+      ERR(NotFound, "No display exposes its brightness");
+
+    return backlights;
+  }
+
   auto GetNetworkInterfaces(CacheManager& /*cache*/) -> Result<Vec<NetworkInterface>> {
     Vec<NetworkInterface> interfaces;
     ULONG                 bufferSize = 15000; // A reasonable starting buffer size
@@ -1207,6 +4515,11 @@ namespace draconis::core::system {
 
       iface.isUp       = (pCurrAddresses->OperStatus == IfOperStatusUp);
       iface.isLoopback = (pCurrAddresses->IfType == IF_TYPE_SOFTWARE_LOOPBACK);
+      iface.mtu        = pCurrAddresses->Mtu;
+
+      // An unknown speed is reported as ULONG64_MAX; duplex mode isn't exposed by the IP Helper API.
+      if (pCurrAddresses->TransmitLinkSpeed != 0 && pCurrAddresses->TransmitLinkSpeed != std::numeric_limits<ULONG64>::max())
+        iface.linkSpeedMbps = pCurrAddresses->TransmitLinkSpeed / 1'000'000;
 
       // Format the MAC address
       if (pCurrAddresses->PhysicalAddressLength == 6)
@@ -1220,22 +4533,74 @@ namespace draconis::core::system {
           pCurrAddresses->PhysicalAddress[5]
         );
 
-      // Iterate through the IP addresses for this adapter
-      for (const IP_ADAPTER_UNICAST_ADDRESS* pUnicast = pCurrAddresses->FirstUnicastAddress; pUnicast != nullptr; pUnicast = pUnicast->Next)
-        if (pUnicast->Address.lpSockaddr->sa_family == AF_INET) {
-          // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
-          const auto* saIn = reinterpret_cast<sockaddr_in*>(pUnicast->Address.lpSockaddr);
+      // Iterate through the IP addresses for this adapter
+      for (const IP_ADAPTER_UNICAST_ADDRESS* pUnicast = pCurrAddresses->FirstUnicastAddress; pUnicast != nullptr; pUnicast = pUnicast->Next)
+        if (pUnicast->Address.lpSockaddr->sa_family == AF_INET) {
+          // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+          const auto* saIn = reinterpret_cast<sockaddr_in*>(pUnicast->Address.lpSockaddr);
+
+          Array<char, INET_ADDRSTRLEN> strBuffer {};
+
+          if (inet_ntop(AF_INET, &(saIn->sin_addr), strBuffer.data(), INET_ADDRSTRLEN))
+            iface.ipv4Address = strBuffer.data();
+        }
+
+      interfaces.emplace_back(iface);
+    }
+
+    return interfaces;
+  }
+
+  auto GetNetworkStats(CacheManager& /*cache*/) -> Result<Vec<InterfaceStats>> {
+    ULONG     bufferSize = 15000;
+    Vec<BYTE> buffer(bufferSize);
+
+    // Only the adapter names and indices are needed, so skip the address lists.
+    constexpr ULONG flags = GAA_FLAG_SKIP_UNICAST | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_DNS_SERVER;
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+    auto* pAddresses = reinterpret_cast<IP_ADAPTER_ADDRESSES*>(buffer.data());
+
+    DWORD result = GetAdaptersAddresses(AF_UNSPEC, flags, nullptr, pAddresses, &bufferSize);
+    if (result == ERROR_BUFFER_OVERFLOW) {
+      buffer.resize(bufferSize);
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+      pAddresses = reinterpret_cast<IP_ADAPTER_ADDRESSES*>(buffer.data());
+      result     = GetAdaptersAddresses(AF_UNSPEC, flags, nullptr, pAddresses, &bufferSize);
+    }
+
+    if (result != NO_ERROR)
+      ERR_FMT(NetworkError, "GetAdaptersAddresses failed with error: {}", result);
 
-          Array<char, INET_ADDRSTRLEN> strBuffer {};
+    Vec<InterfaceStats> stats;
 
-          if (inet_ntop(AF_INET, &(saIn->sin_addr), strBuffer.data(), INET_ADDRSTRLEN))
-            iface.ipv4Address = strBuffer.data();
-        }
+    for (IP_ADAPTER_ADDRESSES* pCurrAddresses = pAddresses; pCurrAddresses != nullptr; pCurrAddresses = pCurrAddresses->Next) {
+      MIB_IF_ROW2 row {};
+      // NOLINTNEXTLINE(*-union-access)
+      row.InterfaceIndex = pCurrAddresses->IfIndex;
 
-      interfaces.emplace_back(iface);
+      if (const DWORD status = GetIfEntry2(&row); status != NO_ERROR) {
+        debug_log("GetIfEntry2 failed for adapter {} with error: {}", pCurrAddresses->AdapterName, status);
+        continue;
+      }
+
+      stats.push_back({
+        .name      = pCurrAddresses->AdapterName,
+        .rxBytes   = row.InOctets,
+        .txBytes   = row.OutOctets,
+        .rxPackets = row.InUcastPkts + row.InNUcastPkts,
+        .txPackets = row.OutUcastPkts + row.OutNUcastPkts,
+        .rxErrors  = row.InErrors,
+        .txErrors  = row.OutErrors,
+        .rxDropped = row.InDiscards,
+        .txDropped = row.OutDiscards,
+      });
     }
 
-    return interfaces;
+    if (stats.empty())
+      ERR(NotFound, "No interface statistics available");
+
+    return stats;
   }
 
   auto GetPrimaryNetworkInterface(CacheManager& cache) -> Result<NetworkInterface> {
@@ -1276,6 +4641,11 @@ namespace draconis::core::system {
 
           iface.isUp       = (pCurrAddresses->OperStatus == IfOperStatusUp);
           iface.isLoopback = (pCurrAddresses->IfType == IF_TYPE_SOFTWARE_LOOPBACK);
+          iface.mtu        = pCurrAddresses->Mtu;
+
+          // An unknown speed is reported as ULONG64_MAX; duplex mode isn't exposed by the IP Helper API.
+          if (pCurrAddresses->TransmitLinkSpeed != 0 && pCurrAddresses->TransmitLinkSpeed != std::numeric_limits<ULONG64>::max())
+            iface.linkSpeedMbps = pCurrAddresses->TransmitLinkSpeed / 1'000'000;
 
           if (pCurrAddresses->PhysicalAddressLength == 6)
             iface.macAddress = std::format(
@@ -1307,6 +4677,125 @@ namespace draconis::core::system {
     });
   }
 
+  auto GetDefaultGateway(CacheManager& /*cache*/) -> Result<Gateway> {
+    MIB_IPFORWARDROW routeRow;
+    sockaddr_in      destAddr {};
+    destAddr.sin_family = AF_INET;
+    inet_pton(AF_INET, "8.8.8.8", &destAddr.sin_addr);
+
+    if (DWORD status = GetBestRoute(destAddr.sin_addr.s_addr, 0, &routeRow); status != NO_ERROR)
+      ERR_FMT(NotFound, "GetBestRoute failed with error: {} (no default route)", status);
+
+    ULONG     bufferSize = 15000;
+    Vec<BYTE> buffer(bufferSize);
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+    auto* pAddresses = reinterpret_cast<IP_ADAPTER_ADDRESSES*>(buffer.data());
+
+    DWORD result = GetAdaptersAddresses(AF_UNSPEC, GAA_FLAG_INCLUDE_GATEWAYS, nullptr, pAddresses, &bufferSize);
+    if (result == ERROR_BUFFER_OVERFLOW) {
+      buffer.resize(bufferSize);
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+      pAddresses = reinterpret_cast<IP_ADAPTER_ADDRESSES*>(buffer.data());
+      result     = GetAdaptersAddresses(AF_UNSPEC, GAA_FLAG_INCLUDE_GATEWAYS, nullptr, pAddresses, &bufferSize);
+    }
+
+    if (result != NO_ERROR)
+      ERR_FMT(NetworkError, "GetAdaptersAddresses failed with error: {}", result);
+
+    for (IP_ADAPTER_ADDRESSES* pCurrAddresses = pAddresses; pCurrAddresses != nullptr; pCurrAddresses = pCurrAddresses->Next) {
+      // NOLINTNEXTLINE(*-union-access)
+      if (pCurrAddresses->IfIndex != routeRow.dwForwardIfIndex)
+        continue;
+
+      Option<String> ipv6Gateway;
+
+      for (IP_ADAPTER_GATEWAY_ADDRESS_LH* pGateway = pCurrAddresses->FirstGatewayAddress; pGateway != nullptr; pGateway = pGateway->Next) {
+        const sockaddr* addr = pGateway->Address.lpSockaddr;
+
+        if (addr->sa_family == AF_INET) {
+          Array<char, INET_ADDRSTRLEN> strBuffer {};
+
+          // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+          if (inet_ntop(AF_INET, &reinterpret_cast<const sockaddr_in*>(addr)->sin_addr, strBuffer.data(), INET_ADDRSTRLEN))
+            return Gateway { .address = strBuffer.data(), .interfaceName = pCurrAddresses->AdapterName };
+        } else if (addr->sa_family == AF_INET6 && !ipv6Gateway) {
+          Array<char, INET6_ADDRSTRLEN> strBuffer {};
+
+          // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+          if (inet_ntop(AF_INET6, &reinterpret_cast<const sockaddr_in6*>(addr)->sin6_addr, strBuffer.data(), INET6_ADDRSTRLEN))
+            ipv6Gateway = String(strBuffer.data());
+        }
+      }
+
+      if (ipv6Gateway)
+        return Gateway { .address = *std::move(ipv6Gateway), .interfaceName = pCurrAddresses->AdapterName };
+
+      ERR(NotFound, "The interface of the best route has no gateway address");
+    }
+
+    ERR(NotFound, "Could not find the interface of the best route");
+  }
+
+  auto GetDnsServers(CacheManager& /*cache*/) -> Result<Vec<String>> {
+    ULONG     bufferSize = 15000;
+    Vec<BYTE> buffer(bufferSize);
+
+    // Only the DNS server list is needed, so skip everything else.
+    constexpr ULONG flags = GAA_FLAG_SKIP_UNICAST | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST;
+
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+    auto* pAddresses = reinterpret_cast<IP_ADAPTER_ADDRESSES*>(buffer.data());
+
+    DWORD result = GetAdaptersAddresses(AF_UNSPEC, flags, nullptr, pAddresses, &bufferSize);
+    if (result == ERROR_BUFFER_OVERFLOW) {
+      buffer.resize(bufferSize);
+      // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+      pAddresses = reinterpret_cast<IP_ADAPTER_ADDRESSES*>(buffer.data());
+      result     = GetAdaptersAddresses(AF_UNSPEC, flags, nullptr, pAddresses, &bufferSize);
+    }
+
+    if (result != NO_ERROR)
+      ERR_FMT(NetworkError, "GetAdaptersAddresses failed with error: {}", result);
+
+    Vec<String> servers;
+
+    for (IP_ADAPTER_ADDRESSES* pCurrAddresses = pAddresses; pCurrAddresses != nullptr; pCurrAddresses = pCurrAddresses->Next) {
+      if (pCurrAddresses->OperStatus != IfOperStatusUp || pCurrAddresses->IfType == IF_TYPE_SOFTWARE_LOOPBACK)
+        continue;
+
+      for (IP_ADAPTER_DNS_SERVER_ADDRESS* pDns = pCurrAddresses->FirstDnsServerAddress; pDns != nullptr; pDns = pDns->Next) {
+        const sockaddr* addr = pDns->Address.lpSockaddr;
+        String          address;
+
+        if (addr->sa_family == AF_INET) {
+          Array<char, INET_ADDRSTRLEN> strBuffer {};
+
+          // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+          if (inet_ntop(AF_INET, &reinterpret_cast<const sockaddr_in*>(addr)->sin_addr, strBuffer.data(), INET_ADDRSTRLEN))
+            address = strBuffer.data();
+        } else if (addr->sa_family == AF_INET6) {
+          Array<char, INET6_ADDRSTRLEN> strBuffer {};
+
+          // NOLINTNEXTLINE(*-pro-type-reinterpret-cast)
+          if (inet_ntop(AF_INET6, &reinterpret_cast<const sockaddr_in6*>(addr)->sin6_addr, strBuffer.data(), INET6_ADDRSTRLEN))
+            address = strBuffer.data();
+        }
+
+        // Adapters without IPv6 DNS configured report the deprecated site-local placeholders (fec0:0:0:ffff::1-3).
+        if (address.empty() || address.starts_with("fec0:0:0:ffff::") || std::ranges::find(servers, address) != servers.end())
+          continue;
+
+        servers.push_back(std::move(address));
+      }
+    }
+
+    if (servers.empty())
+      ERR(NotFound, "No DNS servers configured on any connected adapter");
+
+    return servers;
+  }
+
   auto GetBatteryInfo(CacheManager& /*cache*/) -> Result<Battery> {
     using matchit::match, matchit::is, matchit::_;
     using enum Battery::Status;
@@ -1343,6 +4832,439 @@ namespace draconis::core::system {
         : Some(std::chrono::seconds(powerStatus.BatteryFullLifeTime))
     );
   }
+
+  auto GetBatteries(CacheManager& /*cache*/) -> Result<Vec<Battery>> {
+    using enum Battery::Status;
+
+    const HDEVINFO deviceInfo = SetupDiGetClassDevsW(&BATTERY_DEVICE_CLASS, nullptr, nullptr, DIGCF_PRESENT | DIGCF_DEVICEINTERFACE);
+
+    if (deviceInfo == INVALID_HANDLE_VALUE)
+      ERR_FMT(ApiUnavailable, "SetupDiGetClassDevsW failed with error code {}", GetLastError());
+
+    const UniquePointer<void, decltype(&SetupDiDestroyDeviceInfoList)> deviceInfoDeleter(deviceInfo, &SetupDiDestroyDeviceInfoList);
+
+    Vec<Battery> batteries;
+
+    SP_DEVICE_INTERFACE_DATA interfaceData { .cbSize = sizeof(SP_DEVICE_INTERFACE_DATA) };
+
+    for (DWORD index = 0; SetupDiEnumDeviceInterfaces(deviceInfo, nullptr, &BATTERY_DEVICE_CLASS, index, &interfaceData); ++index) {
+      DWORD detailSize = 0;
+      SetupDiGetDeviceInterfaceDetailW(deviceInfo, &interfaceData, nullptr, 0, &detailSize, nullptr);
+
+      if (detailSize == 0)
+        continue;
+
+      Vec<u8> detailBuffer(detailSize);
+      auto*   detail = reinterpret_cast<PSP_DEVICE_INTERFACE_DETAIL_DATA_W>(detailBuffer.data());
+      detail->cbSize = sizeof(SP_DEVICE_INTERFACE_DETAIL_DATA_W);
+
+      if (!SetupDiGetDeviceInterfaceDetailW(deviceInfo, &interfaceData, detail, detailSize, nullptr, nullptr))
+        continue;
+
+      const cache::HandleWrapper<HANDLE> device(
+        CreateFileW(detail->DevicePath, GENERIC_READ | GENERIC_WRITE, FILE_SHARE_READ | FILE_SHARE_WRITE, nullptr, OPEN_EXISTING, FILE_ATTRIBUTE_NORMAL, nullptr)
+      );
+
+      if (!device)
+        continue;
+
+      // Every other query is keyed by a tag that changes whenever the battery is swapped.
+      BATTERY_QUERY_INFORMATION query {};
+      DWORD                     wait     = 0;
+      DWORD                     returned = 0;
+
+      if (!DeviceIoControl(device.get(), IOCTL_BATTERY_QUERY_TAG, &wait, sizeof(wait), &query.BatteryTag, sizeof(query.BatteryTag), &returned, nullptr) || query.BatteryTag == 0)
+        continue;
+
+      BATTERY_INFORMATION information {};
+      query.InformationLevel = BatteryInformation;
+
+      if (!DeviceIoControl(device.get(), IOCTL_BATTERY_QUERY_INFORMATION, &query, sizeof(query), &information, sizeof(information), &returned, nullptr))
+        continue;
+
+      BATTERY_WAIT_STATUS waitStatus { .BatteryTag = query.BatteryTag };
+      BATTERY_STATUS      status {};
+
+      if (!DeviceIoControl(device.get(), IOCTL_BATTERY_QUERY_STATUS, &waitStatus, sizeof(waitStatus), &status, sizeof(status), &returned, nullptr))
+        continue;
+
+      // Relative batteries report capacities as percentages already; FullChargedCapacity is then 100.
+      Option<u8> percentage;
+
+      if (information.FullChargedCapacity != 0 && status.Capacity != BATTERY_UNKNOWN_CAPACITY)
+        percentage = static_cast<u8>(std::min<u64>(100, static_cast<u64>(status.Capacity) * 100 / information.FullChargedCapacity));
+
+      Battery::Status state = Unknown;
+
+      if (status.PowerState & BATTERY_CHARGING)
+        state = Charging;
+      else if (status.PowerState & BATTERY_DISCHARGING)
+        state = Discharging;
+      else if (status.PowerState & BATTERY_POWER_ON_LINE)
+        state = percentage == 100 ? Full : Unknown;
+
+      // Only meaningful while discharging; the estimate covers this battery alone.
+      Option<std::chrono::seconds> timeRemaining;
+      ULONG                        estimatedTime = BATTERY_UNKNOWN_TIME;
+      query.InformationLevel                     = BatteryEstimatedTime;
+
+      if (state == Discharging && DeviceIoControl(device.get(), IOCTL_BATTERY_QUERY_INFORMATION, &query, sizeof(query), &estimatedTime, sizeof(estimatedTime), &returned, nullptr) && estimatedTime != BATTERY_UNKNOWN_TIME)
+        timeRemaining = std::chrono::seconds(estimatedTime);
+
+      Battery battery(state, percentage, timeRemaining);
+
+      Array<wchar_t, 128> name {};
+      query.InformationLevel = BatteryDeviceName;
+
+      if (DeviceIoControl(device.get(), IOCTL_BATTERY_QUERY_INFORMATION, &query, sizeof(query), name.data(), sizeof(name) - sizeof(wchar_t), &returned, nullptr))
+        battery.name = ConvertWStringToUTF8(name.data()).value_or("");
+
+      if (battery.name.empty())
+        battery.name = std::format("Battery {}", index);
+
+      batteries.push_back(std::move(battery));
+    }
+
+    if (batteries.empty())
+      ERR(NotFound, "No battery found");
+
+    return batteries;
+  }
+
+  auto GetPowerSource(CacheManager& /*cache*/) -> Result<PowerSource> {
+    SYSTEM_POWER_STATUS powerStatus;
+
+    if (!GetSystemPowerStatus(&powerStatus))
+      ERR_FMT(ApiUnavailable, "GetSystemPowerStatus failed with error code {}", GetLastError());
+
+    // 255 means the line status is unknown.
+    if (powerStatus.ACLineStatus == 255)
+      ERR(NotFound, "AC line status unknown");
+
+    // Windows doesn't expose the charger's wattage or USB-PD contract to user mode.
+    return PowerSource { .acConnected = powerStatus.ACLineStatus == 1, .chargerWatts = None, .usbPdWatts = None };
+  }
+
+  auto GetPowerProfile(CacheManager& /*cache*/) -> Result<PowerProfile> {
+    using enum PowerProfile::Mode;
+
+    GUID* scheme = nullptr;
+
+    if (const DWORD status = PowerGetActiveScheme(nullptr, &scheme); status != ERROR_SUCCESS)
+      ERR_FMT(ApiUnavailable, "PowerGetActiveScheme failed with error code {}", status);
+
+    const UniquePointer<GUID, decltype(&LocalFree)> schemeDeleter(scheme, &LocalFree);
+
+    // Custom plans are copies of a built-in one, but nothing records which, so they stay Unknown.
+    PowerProfile::Mode mode = Unknown;
+
+    if (*scheme == POWER_SAVER_SCHEME)
+      mode = PowerSaver;
+    else if (*scheme == BALANCED_SCHEME)
+      mode = Balanced;
+    else if (*scheme == HIGH_PERFORMANCE_SCHEME || *scheme == ULTIMATE_PERFORMANCE_SCHEME)
+      mode = Performance;
+
+    // The first call reports the buffer size in bytes, including the terminator.
+    Option<String> name;
+    DWORD          nameSize = 0;
+
+    if (PowerReadFriendlyName(nullptr, scheme, nullptr, nullptr, nullptr, &nameSize) == ERROR_SUCCESS && nameSize > sizeof(wchar_t)) {
+      WString friendlyName(nameSize / sizeof(wchar_t), L'\0');
+
+      if (PowerReadFriendlyName(nullptr, scheme, nullptr, nullptr, reinterpret_cast<PUCHAR>(friendlyName.data()), &nameSize) == ERROR_SUCCESS) {
+        friendlyName.resize(wcsnlen(friendlyName.c_str(), friendlyName.size()));
+
+        if (Result<String> converted = ConvertWStringToUTF8(friendlyName))
+          name = std::move(*converted);
+      }
+    }
+
+    return PowerProfile { .mode = mode, .name = std::move(name), .governor = None };
+  }
+
+  // Broadcasts such as WM_POWERBROADCAST and WM_DISPLAYCHANGE skip message-only windows, so notifications go to a
+  // hidden top-level window owned by a private thread, which queues the events for wait().
+  auto GetHardwareFingerprint() -> Result<String> {
+    String fingerprint;
+
+    const auto readValues = [&fingerprint](const PWCStr keyPath, const std::initializer_list<PWCStr> names) -> Unit {
+      HKEY key = nullptr;
+
+      if (RegOpenKeyExW(HKEY_LOCAL_MACHINE, keyPath, 0, KEY_READ, &key) != ERROR_SUCCESS)
+        return;
+
+      RegistryKey keyGuard(key);
+
+      for (const PWCStr name : names)
+        if (Result<String> value = GetRegistryValue(key, name).and_then([](const WString& wide) -> Result<String> { return ConvertWStringToUTF8(wide); }))
+          fingerprint += std::format("{};", *value);
+    };
+
+    readValues(L"HARDWARE\\DESCRIPTION\\System\\BIOS", { SYSTEM_MANUFACTURER, SYSTEM_PRODUCT_NAME, BASEBOARD_PRODUCT });
+    readValues(L"HARDWARE\\DESCRIPTION\\System\\CentralProcessor\\0", { L"ProcessorNameString" });
+
+    Vec<String>     gpus;
+    DISPLAY_DEVICEW device {};
+    device.cb = sizeof(device);
+
+    for (DWORD index = 0; EnumDisplayDevicesW(nullptr, index, &device, 0); ++index)
+      if (Result<String> name = ConvertWStringToUTF8(device.DeviceString); name && !name->empty())
+        gpus.push_back(std::move(*name));
+
+    // Each adapter is listed once per output, so duplicates are dropped.
+    std::ranges::sort(gpus);
+    gpus.erase(std::ranges::unique(gpus).begin(), gpus.end());
+
+    for (const String& gpu : gpus)
+      fingerprint += std::format("{};", gpu);
+
+    if (fingerprint.empty())
+      ERR(NotFound, "No hardware identifiers found in the registry or display devices");
+
+    return fingerprint;
+  }
+
+  struct SystemWatcher::Impl {
+    Mutex                   mutex;
+    std::condition_variable ready;
+    Vec<SystemEvent>        queue;
+    bool                    interrupted = false;
+
+    std::thread thread;
+    DWORD       threadId       = 0;
+    HANDLE      ipNotification = nullptr;
+
+    // Guarded by `mutex`, since IP Helper callbacks run on the thread pool.
+    Map<u64, Pair<String, bool>> linkUp;
+
+    Impl() = default;
+
+    Impl(const Impl&)                    = delete;
+    Impl(Impl&&)                         = delete;
+    auto operator=(const Impl&) -> Impl& = delete;
+    auto operator=(Impl&&) -> Impl&      = delete;
+
+    ~Impl() {
+      // Blocks until any callback in progress has returned.
+      if (ipNotification)
+        CancelMibChangeNotify2(ipNotification);
+
+      if (threadId != 0)
+        PostThreadMessageW(threadId, WM_QUIT, 0, 0);
+
+      if (thread.joinable())
+        thread.join();
+    }
+
+    auto push(SystemEvent event) -> Unit {
+      {
+        LockGuard lock(mutex);
+        queue.push_back(std::move(event));
+      }
+
+      ready.notify_one();
+    }
+
+    // Records the operational state of an interface, reporting it if it changed. Must be called with `mutex` held.
+    auto updateLinkLocked(const MIB_IF_ROW2& row, const bool report) -> Unit {
+      if (row.Type == IF_TYPE_SOFTWARE_LOOPBACK || !row.InterfaceAndOperStatusFlags.HardwareInterface)
+        return;
+
+      const bool up = row.OperStatus == IfOperStatusUp;
+
+      const auto [iter, inserted] = linkUp.try_emplace(row.InterfaceLuid.Value, String {}, up);
+
+      if (inserted)
+        if (Result<String> alias = ConvertWStringToUTF8(row.Alias))
+          iter->second.first = std::move(*alias);
+
+      if (inserted ? !up : iter->second.second == up)
+        return;
+
+      iter->second.second = up;
+
+      if (report)
+        queue.push_back({ .kind = up ? SystemEvent::Kind::NetworkUp : SystemEvent::Kind::NetworkDown, .source = iter->second.first });
+    }
+
+    static auto CALLBACK OnInterfaceChanged(PVOID context, PMIB_IPINTERFACE_ROW ipRow, const MIB_NOTIFICATION_TYPE type) -> VOID {
+      if (!ipRow)
+        return;
+
+      auto* impl = static_cast<Impl*>(context);
+
+      MIB_IF_ROW2 row {};
+      row.InterfaceLuid = ipRow->InterfaceLuid;
+
+      // A removed interface can no longer be looked up, so it's reported down under its last known name.
+      const bool found = type != MibDeleteInstance && GetIfEntry2(&row) == NO_ERROR;
+
+      {
+        LockGuard lock(impl->mutex);
+
+        if (found)
+          impl->updateLinkLocked(row, true);
+        else if (const auto iter = impl->linkUp.find(row.InterfaceLuid.Value); iter != impl->linkUp.end()) {
+          if (iter->second.second)
+            impl->queue.push_back({ .kind = SystemEvent::Kind::NetworkDown, .source = iter->second.first });
+
+          impl->linkUp.erase(iter);
+        }
+      }
+
+      impl->ready.notify_one();
+    }
+
+    static auto CALLBACK WindowProc(const HWND window, const UINT message, const WPARAM wParam, const LPARAM lParam) -> LRESULT {
+      auto* impl = reinterpret_cast<Impl*>(GetWindowLongPtrW(window, GWLP_USERDATA)); // NOLINT(performance-no-int-to-ptr)
+
+      if (!impl)
+        return DefWindowProcW(window, message, wParam, lParam);
+
+      switch (message) {
+        case WM_DEVICECHANGE: {
+          if (wParam != DBT_DEVICEARRIVAL && wParam != DBT_DEVICEREMOVECOMPLETE)
+            break;
+
+          const auto* header = reinterpret_cast<const DEV_BROADCAST_HDR*>(lParam); // NOLINT(performance-no-int-to-ptr)
+
+          if (!header || header->dbch_devicetype != DBT_DEVTYP_DEVICEINTERFACE)
+            break;
+
+          // The interface path, e.g. "\\?\USB#VID_046D&PID_C52B#...#{a5dcbf10-...}".
+          const auto* device = reinterpret_cast<const DEV_BROADCAST_DEVICEINTERFACE_W*>(header);
+
+          Option<String> source;
+
+          if (Result<String> path = ConvertWStringToUTF8(static_cast<PWCStr>(device->dbcc_name)))
+            source = std::move(*path);
+
+          impl->push({ .kind = wParam == DBT_DEVICEARRIVAL ? SystemEvent::Kind::DeviceAdded : SystemEvent::Kind::DeviceRemoved, .source = std::move(source) });
+          break;
+        }
+        case WM_POWERBROADCAST:
+          if (wParam == PBT_APMPOWERSTATUSCHANGE)
+            impl->push({ .kind = SystemEvent::Kind::BatteryChanged, .source = None });
+
+          return TRUE;
+        case WM_DISPLAYCHANGE:
+          impl->push({ .kind = SystemEvent::Kind::DisplayChanged, .source = None });
+          break;
+        default:
+          break;
+      }
+
+      return DefWindowProcW(window, message, wParam, lParam);
+    }
+
+    // Body of the notification thread: creates the window, reports the outcome through `started`, then pumps
+    // messages until the watcher is destroyed.
+    auto run(std::promise<Result<>> started) -> Unit {
+      constexpr PWCStr className = L"DraconisSystemWatcher";
+
+      const HINSTANCE instance = GetModuleHandleW(nullptr);
+
+      const WNDCLASSEXW windowClass {
+        .cbSize        = sizeof(WNDCLASSEXW),
+        .lpfnWndProc   = &WindowProc,
+        .hInstance     = instance,
+        .lpszClassName = className,
+      };
+
+      // The class outlives any one watcher, so a second watcher finds it already registered.
+      if (!RegisterClassExW(&windowClass) && GetLastError() != ERROR_CLASS_ALREADY_EXISTS) {
+        started.set_value(Err(DracError(ApiUnavailable, std::format("RegisterClassExW failed with error code {}", GetLastError()))));
+        return;
+      }
+
+      const HWND window = CreateWindowExW(0, className, L"", WS_OVERLAPPED, 0, 0, 0, 0, nullptr, nullptr, instance, nullptr);
+
+      if (!window) {
+        started.set_value(Err(DracError(ApiUnavailable, std::format("CreateWindowExW failed with error code {}", GetLastError()))));
+        return;
+      }
+
+      SetWindowLongPtrW(window, GWLP_USERDATA, reinterpret_cast<LONG_PTR>(this)); // NOLINT(cppcoreguidelines-pro-type-reinterpret-cast)
+
+      Vec<HDEVNOTIFY> deviceNotifications;
+
+      for (const GUID& interfaceClass : { USB_DEVICE_INTERFACE, DISK_INTERFACE }) {
+        DEV_BROADCAST_DEVICEINTERFACE_W filter {};
+        filter.dbcc_size       = sizeof(filter);
+        filter.dbcc_devicetype = DBT_DEVTYP_DEVICEINTERFACE;
+        filter.dbcc_classguid  = interfaceClass;
+
+        if (const HDEVNOTIFY notification = RegisterDeviceNotificationW(window, &filter, DEVICE_NOTIFY_WINDOW_HANDLE))
+          deviceNotifications.push_back(notification);
+      }
+
+      // The message queue exists once the window does, so WM_QUIT can be posted from here on.
+      threadId = GetCurrentThreadId();
+      started.set_value({});
+
+      MSG message {};
+
+      while (GetMessageW(&message, nullptr, 0, 0) > 0)
+        DispatchMessageW(&message);
+
+      for (const HDEVNOTIFY notification : deviceNotifications)
+        UnregisterDeviceNotification(notification);
+
+      DestroyWindow(window);
+    }
+  };
+
+  SystemWatcher::SystemWatcher(UniquePointer<Impl> impl) : m_impl(std::move(impl)) {}
+  SystemWatcher::SystemWatcher(SystemWatcher&&) noexcept                    = default;
+  auto SystemWatcher::operator=(SystemWatcher&&) noexcept -> SystemWatcher& = default;
+  SystemWatcher::~SystemWatcher()                                           = default;
+
+  auto SystemWatcher::Create() -> Result<SystemWatcher> {
+    auto impl = std::make_unique<Impl>();
+
+    std::promise<Result<>> started;
+    Future<Result<>>       windowCreated = started.get_future();
+
+    impl->thread = std::thread([watcher = impl.get(), started = std::move(started)]() mutable -> void { watcher->run(std::move(started)); });
+
+    if (Result<> result = windowCreated.get(); !result)
+      ERR_FROM(result.error());
+
+    // Seed the link states, so the first change to an interface that's already up isn't reported as it coming up.
+    if (MIB_IF_TABLE2* table = nullptr; GetIfTable2(&table) == NO_ERROR) {
+      const UniquePointer<MIB_IF_TABLE2, decltype(&FreeMibTable)> tableDeleter(table, &FreeMibTable);
+
+      LockGuard lock(impl->mutex);
+
+      for (const MIB_IF_ROW2& row : Span<const MIB_IF_ROW2>(static_cast<const MIB_IF_ROW2*>(table->Table), table->NumEntries))
+        impl->updateLinkLocked(row, false);
+    }
+
+    if (const DWORD status = NotifyIpInterfaceChange(AF_UNSPEC, &Impl::OnInterfaceChanged, impl.get(), FALSE, &impl->ipNotification); status != NO_ERROR)
+      ERR_FMT(ApiUnavailable, "NotifyIpInterfaceChange failed with error code {}", status);
+
+    return SystemWatcher(std::move(impl));
+  }
+
+  auto SystemWatcher::wait(const std::chrono::milliseconds timeout) -> Result<Vec<SystemEvent>> {
+    std::unique_lock lock(m_impl->mutex);
+
+    m_impl->ready.wait_for(lock, timeout, [this]() -> bool { return !m_impl->queue.empty() || m_impl->interrupted; });
+
+    m_impl->interrupted = false;
+
+    return std::exchange(m_impl->queue, {});
+  }
+
+  auto SystemWatcher::interrupt() -> Unit {
+    {
+      LockGuard lock(m_impl->mutex);
+      m_impl->interrupted = true;
+    }
+
+    m_impl->ready.notify_all();
+  }
 } // namespace draconis::core::system
 
   #if DRAC_ENABLE_PACKAGECOUNT
@@ -0,0 +1,389 @@
+/**
+ * @file Xkb.hpp
+ * @brief Shared decoding of XKB keyboard layout information for the Linux backend.
+ *
+ * @details Compositors, the X server and the system configuration all describe keyboard layouts in
+ * XKB terms, but in different shapes: comma-separated layout and variant lists (X11, Hyprland,
+ * KDE, `localectl`), or only the human-readable names from the XKB registry (Sway). Only the
+ * retrieval is platform-specific; this header maps:
+ * - Comma-separated layout and variant lists to `KeyboardLayout` entries
+ * - The NUL-separated `_XKB_RULES_NAMES` root window property to `KeyboardLayout` entries
+ * - The replies to Sway's `GET_INPUTS` and Hyprland's `j/devices` IPC requests
+ * - Layout and variant names to their descriptions in the XKB registry (`evdev.lst`), and back
+ * - The layouts set in `/etc/X11/xorg.conf.d/00-keyboard.conf`, `/etc/vconsole.conf`, and `/etc/default/keyboard`
+ */
+
+#pragma once
+
+#include <algorithm>
+#include <fstream>
+#include <glaze/core/meta.hpp> // glz::detail::Object, glz::object
+#include <glaze/json/read.hpp> // glz::read, glz::format_error
+
+#include <Drac++/Utils/DataTypes.hpp>
+#include <Drac++/Utils/Error.hpp>
+#include <Drac++/Utils/Types.hpp>
+
+#include "OS/Unix.hpp"
+
+namespace draconis::os::xkb {
+  namespace types = ::draconis::utils::types;
+
+  using enum ::draconis::utils::error::DracErrorCode;
+
+  using unix_shared::TrimConfigLine;
+
+  /**
+   * @brief A layout or layout variant listed in the XKB registry.
+   */
+  struct RegistryEntry {
+    types::String layout;      ///< Layout name, e.g. "de".
+    types::String variant;     ///< Variant name, or empty for the layout itself, e.g. "nodeadkeys".
+    types::String description; ///< Human-readable name, e.g. "German (no dead keys)".
+  };
+
+  /**
+   * @brief Reads the layouts and variants from the XKB registry.
+   * @return The entries, or an empty list if no registry is installed.
+   *
+   * @details The registry lists one entry per line under `! layout` and `! variant` headers:
+   * layouts as "us  English (US)", variants as "dvorak  us: English (Dvorak)".
+   */
+  [[nodiscard]] inline auto ReadRegistry() -> types::Vec<RegistryEntry> {
+    types::Vec<RegistryEntry> entries;
+
+    for (const types::PCStr path : { "/usr/share/X11/xkb/rules/evdev.lst", "/usr/share/X11/xkb/rules/base.lst" }) {
+      std::ifstream file(path);
+
+      if (!file)
+        continue;
+
+      types::String section;
+
+      for (types::String line; std::getline(file, line);) {
+        const types::StringView view = TrimConfigLine(line);
+
+        if (view.starts_with('!')) {
+          section = TrimConfigLine(view.substr(1));
+          continue;
+        }
+
+        const types::usize nameEnd = view.find_first_of(" \t");
+
+        if ((section != "layout" && section != "variant") || nameEnd == types::StringView::npos)
+          continue;
+
+        const types::StringView name = view.substr(0, nameEnd);
+        const types::StringView rest = TrimConfigLine(view.substr(nameEnd));
+
+        if (section == "layout") {
+          entries.push_back({ .layout = types::String(name), .variant = {}, .description = types::String(rest) });
+          continue;
+        }
+
+        // Variants are prefixed with the layout they belong to.
+        const types::usize colon = rest.find(": ");
+
+        if (colon == types::StringView::npos)
+          continue;
+
+        entries.push_back({
+          .layout      = types::String(rest.substr(0, colon)),
+          .variant     = types::String(name),
+          .description = types::String(rest.substr(colon + 2)),
+        });
+      }
+
+      if (!entries.empty())
+        break;
+    }
+
+    return entries;
+  }
+
+  /**
+   * @brief Fills in the description of layouts that don't have one yet from the XKB registry.
+   * @param layouts The layouts to describe.
+   * @param registry The registry, as returned by ReadRegistry.
+   */
+  inline auto Describe(types::Vec<types::KeyboardLayout>& layouts, const types::Vec<RegistryEntry>& registry) -> void {
+    for (types::KeyboardLayout& layout : layouts) {
+      if (layout.description)
+        continue;
+
+      const types::StringView variant = layout.variant ? types::StringView(*layout.variant) : types::StringView {};
+
+      const auto entry = std::ranges::find_if(registry, [&](const RegistryEntry& candidate) -> bool {
+        return candidate.layout == layout.name && candidate.variant == variant;
+      });
+
+      if (entry != registry.end())
+        layout.description = entry->description;
+    }
+  }
+
+  /**
+   * @brief Builds keyboard layouts from XKB's comma-separated layout and variant lists.
+   * @param layouts The layout list, e.g. "us,de".
+   * @param variants The variant list, matched to layouts by position, e.g. ",nodeadkeys". May be shorter or empty.
+   * @param activeIndex The index of the active layout, or None if it isn't known.
+   * @return One entry per non-empty layout name.
+   */
+  [[nodiscard]] inline auto SplitLayouts(types::StringView layouts, types::StringView variants, const types::Option<types::usize> activeIndex)
+    -> types::Vec<types::KeyboardLayout> {
+    types::Vec<types::KeyboardLayout> result;
+
+    // Takes the text up to the next comma and advances past it.
+    const auto next = [](types::StringView& list) -> types::StringView {
+      const types::usize      comma = list.find(',');
+      const types::StringView item  = TrimConfigLine(list.substr(0, comma));
+
+      list = comma == types::StringView::npos ? types::StringView {} : list.substr(comma + 1);
+
+      return item;
+    };
+
+    for (types::usize idx = 0; !layouts.empty(); ++idx) {
+      const types::StringView name    = next(layouts);
+      const types::StringView variant = next(variants);
+
+      if (name.empty())
+        continue;
+
+      result.push_back({
+        .name        = types::String(name),
+        .variant     = variant.empty() ? types::None : types::Option<types::String>(variant),
+        .description = types::None,
+        .active      = activeIndex == idx,
+      });
+    }
+
+    return result;
+  }
+
+  /**
+   * @brief Builds keyboard layouts from the value of the `_XKB_RULES_NAMES` root window property.
+   * @param rulesNames The property value: rules, model, layouts, variants, and options, each NUL-terminated.
+   * @param activeIndex The index of the active layout, or None if it isn't known.
+   * @return The layouts, or an empty list if the property doesn't name any.
+   */
+  [[nodiscard]] inline auto ParseRulesNames(const types::StringView rulesNames, const types::Option<types::usize> activeIndex)
+    -> types::Vec<types::KeyboardLayout> {
+    types::Array<types::StringView, 5> fields {};
+    types::StringView                  rest = rulesNames;
+
+    for (types::StringView& field : fields) {
+      const types::usize end = rest.find('\0');
+
+      field = rest.substr(0, end);
+      rest  = end == types::StringView::npos ? types::StringView {} : rest.substr(end + 1);
+    }
+
+    return SplitLayouts(fields[2], fields[3], activeIndex);
+  }
+
+  /**
+   * @brief An input device in the reply to Sway's `GET_INPUTS` request.
+   */
+  struct SwayInput {
+    types::String             type;                 ///< "keyboard", "pointer", "touchpad", etc.
+    types::Vec<types::String> xkbLayoutNames;       ///< Registry descriptions of the configured layouts.
+    types::Option<types::i64> xkbActiveLayoutIndex; ///< Index of the active layout.
+
+    // NOLINTBEGIN(readability-identifier-naming)
+    struct glaze {
+      using T = SwayInput;
+      static constexpr glz::detail::Object value =
+        glz::object("type", &T::type, "xkb_layout_names", &T::xkbLayoutNames, "xkb_active_layout_index", &T::xkbActiveLayoutIndex);
+    };
+    // NOLINTEND(readability-identifier-naming)
+  };
+
+  /**
+   * @brief Converts the reply to Sway's `GET_INPUTS` request into keyboard layouts.
+   * @param payload The JSON payload of the reply.
+   * @param registry The XKB registry, used to map the layout descriptions Sway reports back to layout names.
+   * @return The layouts of the first keyboard that has any, or an error.
+   *
+   * @details Sway only reports descriptions, so layouts the registry doesn't list use the description as their name.
+   */
+  [[nodiscard]] inline auto ParseSwayInputs(const types::StringView payload, const types::Vec<RegistryEntry>& registry)
+    -> types::Result<types::Vec<types::KeyboardLayout>> {
+    types::Vec<SwayInput> inputs;
+
+    if (const glz::error_ctx errorContext = glz::read<glz::opts { .error_on_unknown_keys = false }>(inputs, payload))
+      ERR_FMT(ParseError, "Failed to parse Sway input list: {}", glz::format_error(errorContext, payload));
+
+    const auto keyboard = std::ranges::find_if(inputs, [](const SwayInput& input) -> bool {
+      return input.type == "keyboard" && !input.xkbLayoutNames.empty();
+    });
+
+    if (keyboard == inputs.end())
+      ERR(NotFound, "Sway reports no keyboard with a layout");
+
+    types::Vec<types::KeyboardLayout> layouts;
+    layouts.reserve(keyboard->xkbLayoutNames.size());
+
+    for (types::usize idx = 0; idx < keyboard->xkbLayoutNames.size(); ++idx) {
+      const types::String& description = keyboard->xkbLayoutNames[idx];
+
+      const auto entry = std::ranges::find_if(registry, [&](const RegistryEntry& candidate) -> bool {
+        return candidate.description == description;
+      });
+
+      const bool known = entry != registry.end();
+
+      layouts.push_back({
+        .name        = known ? entry->layout : description,
+        .variant     = known && !entry->variant.empty() ? types::Option<types::String>(entry->variant) : types::None,
+        .description = description,
+        .active      = keyboard->xkbActiveLayoutIndex == static_cast<types::i64>(idx),
+      });
+    }
+
+    return layouts;
+  }
+
+  /**
+   * @brief A keyboard in the reply to Hyprland's `j/devices` request.
+   */
+  struct HyprlandKeyboard {
+    types::String layout;       ///< Comma-separated layout list.
+    types::String variant;      ///< Comma-separated variant list.
+    types::String activeKeymap; ///< Registry description of the active layout.
+    bool          main = false; ///< Whether this is the keyboard Hyprland treats as the primary one.
+
+    // NOLINTBEGIN(readability-identifier-naming)
+    struct glaze {
+      using T = HyprlandKeyboard;
+      static constexpr glz::detail::Object value =
+        glz::object("layout", &T::layout, "variant", &T::variant, "active_keymap", &T::activeKeymap, "main", &T::main);
+    };
+    // NOLINTEND(readability-identifier-naming)
+  };
+
+  /**
+   * @brief The reply to Hyprland's `j/devices` request; only keyboards are decoded.
+   */
+  struct HyprlandDevices {
+    types::Vec<HyprlandKeyboard> keyboards; ///< Every attached keyboard, including virtual ones.
+
+    // NOLINTBEGIN(readability-identifier-naming)
+    struct glaze {
+      using T = HyprlandDevices;
+      static constexpr glz::detail::Object value = glz::object("keyboards", &T::keyboards);
+    };
+    // NOLINTEND(readability-identifier-naming)
+  };
+
+  /**
+   * @brief Converts the reply to Hyprland's `j/devices` request into keyboard layouts.
+   * @param payload The JSON reply.
+   * @param registry The XKB registry, used to describe the layouts and find the active one.
+   * @return The layouts of the main keyboard (or the first one), or an error.
+   *
+   * @details Hyprland only reports the description of the active layout, so no layout is marked
+   * active if the registry doesn't describe it.
+   */
+  [[nodiscard]] inline auto ParseHyprlandDevices(const types::StringView payload, const types::Vec<RegistryEntry>& registry)
+    -> types::Result<types::Vec<types::KeyboardLayout>> {
+    HyprlandDevices devices;
+
+    if (const glz::error_ctx errorContext = glz::read<glz::opts { .error_on_unknown_keys = false }>(devices, payload))
+      ERR_FMT(ParseError, "Failed to parse Hyprland device list: {}", glz::format_error(errorContext, payload));
+
+    if (devices.keyboards.empty())
+      ERR(NotFound, "Hyprland reports no keyboards");
+
+    auto keyboard = std::ranges::find_if(devices.keyboards, &HyprlandKeyboard::main);
+
+    if (keyboard == devices.keyboards.end())
+      keyboard = devices.keyboards.begin();
+
+    types::Vec<types::KeyboardLayout> layouts = SplitLayouts(keyboard->layout, keyboard->variant, types::None);
+
+    Describe(layouts, registry);
+
+    for (types::KeyboardLayout& layout : layouts)
+      if (layout.description == keyboard->activeKeymap) {
+        layout.active = true;
+        break;
+      }
+
+    return layouts;
+  }
+
+  /**
+   * @brief Reads the system-wide keyboard layouts from configuration files.
+   * @return The configured layouts, none of them marked active, or an error.
+   *
+   * @details Checks, in order, `/etc/X11/xorg.conf.d/00-keyboard.conf` (written by `localectl`),
+   * `/etc/vconsole.conf`, and `/etc/default/keyboard` (Debian). If only a console keymap is set,
+   * `KEYMAP` from `/etc/vconsole.conf` is used as the layout name.
+   */
+  [[nodiscard]] inline auto GetLayoutsFromConfig() -> types::Result<types::Vec<types::KeyboardLayout>> {
+    // Returns the value of a KEY=value line, with optional quotes removed.
+    const auto readShellValue = [](const types::PCStr path, const types::StringView key) -> types::String {
+      std::ifstream file(path);
+
+      for (types::String line; std::getline(file, line);) {
+        types::StringView view = TrimConfigLine(line);
+
+        if (!view.starts_with(key) || view.size() <= key.size() || view[key.size()] != '=')
+          continue;
+
+        view.remove_prefix(key.size() + 1);
+
+        if (view.size() >= 2 && (view.front() == '"' || view.front() == '\'') && view.back() == view.front())
+          view = view.substr(1, view.size() - 2);
+
+        return types::String(view);
+      }
+
+      return {};
+    };
+
+    types::String layouts;
+    types::String variants;
+
+    // Lines look like: Option "XkbLayout" "us,de"
+    if (std::ifstream file("/etc/X11/xorg.conf.d/00-keyboard.conf"); file)
+      for (types::String line; std::getline(file, line);) {
+        const types::StringView view = TrimConfigLine(line);
+
+        if (!view.starts_with("Option"))
+          continue;
+
+        const types::usize valueEnd   = view.rfind('"');
+        const types::usize valueStart = valueEnd == 0 || valueEnd == types::StringView::npos ? types::StringView::npos : view.rfind('"', valueEnd - 1);
+
+        if (valueStart == types::StringView::npos)
+          continue;
+
+        const types::StringView value = view.substr(valueStart + 1, valueEnd - valueStart - 1);
+
+        if (view.contains("\"XkbLayout\""))
+          layouts = value;
+        else if (view.contains("\"XkbVariant\""))
+          variants = value;
+      }
+
+    for (const types::PCStr path : { "/etc/vconsole.conf", "/etc/default/keyboard" }) {
+      if (!layouts.empty())
+        break;
+
+      layouts  = readShellValue(path, "XKBLAYOUT");
+      variants = readShellValue(path, "XKBVARIANT");
+    }
+
+    if (layouts.empty())
+      layouts = readShellValue("/etc/vconsole.conf", "KEYMAP");
+
+    types::Vec<types::KeyboardLayout> result = SplitLayouts(layouts, variants, types::None);
+
+    if (result.empty())
+      ERR(NotFound, "No keyboard layout is configured");
+
+    return result;
+  }
+} // namespace draconis::os::xkb
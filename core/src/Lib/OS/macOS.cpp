@@ -1,22 +1,51 @@
 #ifdef __APPLE__
 
-  #include <CoreFoundation/CFPropertyList.h> // CFPropertyListCreateWithData, kCFPropertyListImmutable
-  #include <CoreFoundation/CFStream.h>       // CFReadStreamClose, CFReadStreamCreateWithFile, CFReadStreamOpen, CFReadStreamRead, CFReadStreamRef
-  #include <CoreGraphics/CGDirectDisplay.h>  // CGDisplayCopyDeviceDescription, CGDisplayCopyDisplayMode, CGDisplayIsMain, CGDisplayModeGetMaximumRefreshRate, CGDisplayModeGetRefreshRate, CGDisplayPixelsHigh, CGDisplayPixelsWide, CGDisplayRef, CGDisplayModeRef, CGDirectDisplayID
-  #include <IOKit/ps/IOPSKeys.h>             // kIOPSCurrentCapacityKey, kIOPSInternalBatteryType, kIOPSIsChargingKey, kIOPSTimeToEmptyKey, kIOPSTypeKey
-  #include <IOKit/ps/IOPowerSources.h>       // IOPSCopyPowerSourcesInfo, IOPSGetPowerSourceDescription
-  #include <ifaddrs.h>                       // freeifaddrs, getifaddrs, ifaddrs, sockaddr
-  #include <mach/mach_host.h>                // host_statistics64
-  #include <mach/mach_init.h>                // host_page_size, mach_host_self
-  #include <mach/vm_statistics.h>            // vm_statistics64_data_t
-  #include <map>                             // std::map
-  #include <net/if.h>                        // IFF_LOOPBACK, IFF_UP, IF_NAMESIZE, if_indextoname
-  #include <net/if_dl.h>                     // LLADDR, sockaddr_dl
-  #include <net/route.h>                     // RTA_DST, RTF_GATEWAY, rt_msghdr
-  #include <netdb.h>                         // NI_MAXHOST, NI_NUMERICHOST, getnameinfo
-  #include <netinet/in.h>                    // sockaddr_in
-  #include <sys/mount.h>                     // getmntinfo, statfs, MNT_LOCAL, MNT_NOWAIT
-  #include <sys/sysctl.h>                    // {CTL_KERN, KERN_PROC, KERN_PROC_ALL, kinfo_proc, sysctl, sysctlbyname}
+  #include <CoreAudio/AudioHardware.h>             // AudioObjectGetPropertyData, AudioObjectGetPropertyDataSize, kAudioHardwarePropertyDevices
+  #include <CoreFoundation/CFLocale.h>             // CFLocaleCopyCurrent, CFLocaleGetIdentifier
+  #include <CoreFoundation/CFPreferences.h>        // CFPreferencesCopyAppValue
+  #include <CoreFoundation/CFPropertyList.h>       // CFPropertyListCreateWithData, kCFPropertyListImmutable
+  #include <CoreFoundation/CFStream.h>             // CFReadStreamClose, CFReadStreamCreateWithFile, CFReadStreamOpen, CFReadStreamRead, CFReadStreamRef
+  #include <CoreGraphics/CGDirectDisplay.h>        // CGDisplayBounds, CGDisplayCopyColorSpace, CGDisplayCopyDeviceDescription, CGDisplayCopyDisplayMode, CGDisplayIsMain, CGDisplayModeGetMaximumRefreshRate, CGDisplayModelNumber, CGDisplayModeGetRefreshRate, CGDisplayPixelsHigh, CGDisplayPixelsWide, CGDisplayRotation, CGDisplayScreenSize, CGDisplaySerialNumber, CGDisplayVendorNumber, CGDisplayRef, CGDisplayModeRef, CGDirectDisplayID
+  #include <CoreGraphics/CGDisplayConfiguration.h> // CGDisplayRegisterReconfigurationCallback, CGDisplayRemoveReconfigurationCallback
+  #include <IOKit/IOBSD.h>                         // kIOBSDNameKey
+  #include <IOKit/IOKitKeys.h>                     // kIOPlatformSerialNumberKey, kIOPlatformUUIDKey, kIOServicePlane
+  #include <IOKit/IOKitLib.h>                      // IOConnectCallStructMethod, IORegistryEntryCreateCFProperty, IOIteratorNext, IORegistryEntryFromPath, IOServiceGetMatchingService, IOServiceGetMatchingServices, IOServiceMatching, IOServiceOpen
+  #include <IOKit/kext/KextManager.h>              // KextManagerCopyLoadedKextInfo
+  #include <IOKit/ps/IOPSKeys.h>                   // kIOPSCurrentCapacityKey, kIOPSInternalBatteryType, kIOPSIsChargingKey, kIOPSNameKey, kIOPSTimeToEmptyKey, kIOPSTypeKey
+  #include <IOKit/ps/IOPowerSources.h>             // IOPSCopyPowerSourcesInfo, IOPSCopyPowerSourcesList, IOPSGetPowerSourceDescription, IOPSNotificationCreateRunLoopSource
+  #include <IOKit/storage/IOBlockStorageDriver.h>  // kIOBlockStorageDriverClass, kIOBlockStorageDriverStatisticsKey, kIOBlockStorageDriverStatistics*Key
+  #include <IOKit/storage/IOMedia.h>               // kIOMediaClass, kIOMediaWholeKey
+  #include <ServiceManagement/ServiceManagement.h> // SMCopyAllJobDictionaries, SMJobCopyDictionary, kSMDomainSystemLaunchd, kSMDomainUserLaunchd
+  #include <SystemConfiguration/SCDynamicStore.h>  // SCDynamicStoreCopyMultiple, SCDynamicStoreCopyValue, SCDynamicStoreCreate, SCDynamicStoreCreateRunLoopSource, SCDynamicStoreSetNotificationKeys
+  #include <algorithm>                             // std::min, std::ranges::find_if, std::ranges::transform
+  #include <cctype>                                // std::tolower
+  #include <condition_variable>                    // std::condition_variable
+  #include <cstring>                               // std::memcpy, std::strncpy
+  #include <dlfcn.h>                               // dlopen, dlsym
+  #include <future>                                // std::promise
+  #include <ifaddrs.h>                             // freeifaddrs, getifaddrs, ifaddrs, sockaddr
+  #include <libproc.h>                             // proc_listallpids, proc_pidinfo, PROC_PIDTASKALLINFO, PROC_PIDT_SHORTBSDINFO
+  #include <mach/mach_host.h>                      // host_processor_info, host_statistics64
+  #include <mach/mach_init.h>                      // host_page_size, mach_host_self
+  #include <mach/mach_time.h>                      // mach_timebase_info
+  #include <mach/processor_info.h>                 // PROCESSOR_CPU_LOAD_INFO, processor_info_array_t
+  #include <mach/vm_map.h>                         // vm_deallocate
+  #include <mach/vm_statistics.h>                  // vm_statistics64_data_t
+  #include <map>                                   // std::map
+  #include <net/if.h>                              // IFF_LOOPBACK, IFF_UP, IF_NAMESIZE, if_indextoname, if_msghdr2, if_data64
+  #include <net/if_dl.h>                           // LLADDR, sockaddr_dl
+  #include <net/if_media.h>                        // IFM_ACTIVE, IFM_FDX, IFM_HDX
+  #include <net/route.h>                           // RTA_DST, RTF_GATEWAY, RTM_IFINFO2, rt_msghdr
+  #include <netdb.h>                               // NI_MAXHOST, NI_NUMERICHOST, getnameinfo
+  #include <netinet/in.h>                          // sockaddr_in
+  #include <ranges>                                // std::views::split
+  #include <sys/ioctl.h>                           // SIOCGIFMEDIA, ioctl
+  #include <sys/mount.h>                           // getmntinfo, statfs, MNT_LOCAL, MNT_NOWAIT
+  #include <sys/proc.h>                            // SIDL, SRUN, SSLEEP, SSTOP, SZOMB
+  #include <sys/socket.h>                          // socket
+  #include <sys/sysctl.h>                          // {CTL_KERN, KERN_PROC, KERN_PROC_ALL, kinfo_proc, sysctl, sysctlbyname}
+  #include <thread>                                // std::this_thread::sleep_for
+  #include <tuple>                                 // std::tuple
 
   #include <Drac++/Core/System.hpp>
   #include <Drac++/Services/Packages.hpp>
@@ -27,6 +56,9 @@
   #include <Drac++/Utils/Logging.hpp>
   #include <Drac++/Utils/Types.hpp>
 
+  #include "OS/Containers.hpp"
+  #include "OS/Cups.hpp"
+  #include "OS/Edid.hpp"
   #include "OS/Unix.hpp"
   #include "OS/macOS/Bridge.hpp"
 
@@ -36,6 +68,137 @@ using draconis::utils::cache::CacheManager, draconis::utils::cache::CachePolicy;
 using enum draconis::utils::error::DracErrorCode;
 
 namespace {
+  auto applyDisplayDetails(DisplayInfo& display, const CGDirectDisplayID displayID) -> Unit {
+    const CGRect bounds = CGDisplayBounds(displayID);
+
+    display.position  = { .x = static_cast<i32>(bounds.origin.x), .y = static_cast<i32>(bounds.origin.y) };
+    display.rotation  = static_cast<u16>(CGDisplayRotation(displayID));
+    display.connector = CGDisplayIsBuiltin(displayID) ? DisplayInfo::Connector::Internal : DisplayInfo::Connector::Unknown;
+
+    // CoreGraphics only tells wide gamut (Display P3 and wider) apart from sRGB.
+    if (const CGColorSpaceRef colorSpace = CGDisplayCopyColorSpace(displayID)) {
+      display.gamut = CGColorSpaceIsWideGamutRGB(colorSpace) ? DisplayInfo::Gamut::P3 : DisplayInfo::Gamut::SRGB;
+      CGColorSpaceRelease(colorSpace);
+    }
+
+    // External monitors only report headroom above SDR white once HDR is switched on, so support and enablement look the same.
+    if (const Option<f64> headroom = draconis::core::system::macOS::GetDisplayEdrHeadroom(displayID)) {
+      display.hdrSupported = *headroom > 1.0;
+      display.hdrEnabled   = display.hdrSupported;
+    }
+
+    CGDisplayModeRef mode = CGDisplayCopyDisplayMode(displayID);
+    if (mode == nullptr)
+      return;
+
+    // HiDPI modes report their size in points; the backing pixel size gives the scale and the physical density.
+    const usize pixelWidth = CGDisplayModeGetPixelWidth(mode);
+    const usize pointWidth = CGDisplayModeGetWidth(mode);
+
+    if (pixelWidth > 0 && pointWidth > 0)
+      display.scaleFactor = static_cast<f64>(pixelWidth) / static_cast<f64>(pointWidth);
+
+    // CGDisplayScreenSize follows the current rotation, while the mode's pixel size doesn't.
+    const CGSize physicalSize = CGDisplayScreenSize(displayID);
+    display.dpi               = draconis::os::unix_shared::DpiFromPhysicalSize(pixelWidth, static_cast<u32>(display.rotation % 180 == 0 ? physicalSize.width : physicalSize.height));
+
+    // The pixel encoding is deprecated without a CoreGraphics replacement; it describes each bit, with '-' marking padding.
+    #pragma clang diagnostic push
+    #pragma clang diagnostic ignored "-Wdeprecated-declarations"
+    if (const CFStringRef encoding = CGDisplayModeCopyPixelEncoding(mode)) {
+      u8 colorBits = 0;
+
+      for (CFIndex i = 0; i < CFStringGetLength(encoding); ++i)
+        if (CFStringGetCharacterAtIndex(encoding, i) != '-')
+          ++colorBits;
+
+      if (colorBits > 0)
+        display.colorDepth = colorBits;
+
+      CFRelease(encoding);
+    }
+    #pragma clang diagnostic pop
+
+    CGDisplayModeRelease(mode);
+  }
+
+  auto applyDisplayIdentity(DisplayInfo& display, const CGDirectDisplayID displayID) -> Unit {
+    const u32 vendorNumber = CGDisplayVendorNumber(displayID);
+    const u32 modelNumber  = CGDisplayModelNumber(displayID);
+    const u32 serialNumber = CGDisplaySerialNumber(displayID);
+
+    // CoreGraphics reports the packed PNP ID from the EDID (or 'unkn' without one), but not the name or serial descriptors.
+    if (vendorNumber > 0 && vendorNumber <= std::numeric_limits<u16>::max())
+      display.manufacturer = draconis::os::edid::ManufacturerName(static_cast<u16>(vendorNumber));
+
+    if (serialNumber != 0 && serialNumber != 0x01010101)
+      display.serial = std::format("{}", serialNumber);
+
+    const auto dictionaryString = [](const CFDictionaryRef dict, const CFStringRef key) -> Option<String> {
+      const CFTypeRef value = CFDictionaryGetValue(dict, key);
+
+      if (value == nullptr || CFGetTypeID(value) != CFStringGetTypeID())
+        return None;
+
+      Array<char, 256> buffer {};
+
+      if (!CFStringGetCString(static_cast<CFStringRef>(value), buffer.data(), buffer.size(), kCFStringEncodingUTF8) || buffer[0] == '\0')
+        return None;
+
+      return String(buffer.data());
+    };
+
+    const auto dictionaryNumber = [](const CFDictionaryRef dict, const CFStringRef key) -> Option<u32> {
+      const CFTypeRef value  = CFDictionaryGetValue(dict, key);
+      u32             number = 0;
+
+      if (value == nullptr || CFGetTypeID(value) != CFNumberGetTypeID() || !CFNumberGetValue(static_cast<CFNumberRef>(value), kCFNumberSInt32Type, &number))
+        return None;
+
+      return number;
+    };
+
+    // Apple Silicon framebuffers publish the decoded EDID as "DisplayAttributes"; match them up by vendor and product ID.
+    io_iterator_t iterator = 0;
+
+    if (IOServiceGetMatchingServices(kIOMainPortDefault, IOServiceMatching("IOMobileFramebuffer"), &iterator) != kIOReturnSuccess)
+      return;
+
+    while (const io_object_t service = IOIteratorNext(iterator)) {
+      const CFTypeRef attributes = IORegistryEntryCreateCFProperty(service, CFSTR("DisplayAttributes"), kCFAllocatorDefault, 0);
+
+      IOObjectRelease(service);
+
+      if (attributes == nullptr)
+        continue;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> attributesDeleter(attributes, &CFRelease);
+
+      if (CFGetTypeID(attributes) != CFDictionaryGetTypeID())
+        continue;
+
+      const CFTypeRef product = CFDictionaryGetValue(static_cast<CFDictionaryRef>(attributes), CFSTR("ProductAttributes"));
+
+      if (product == nullptr || CFGetTypeID(product) != CFDictionaryGetTypeID())
+        continue;
+
+      const auto productAttributes = static_cast<CFDictionaryRef>(product);
+
+      if (dictionaryNumber(productAttributes, CFSTR("LegacyManufacturerID")) != vendorNumber || dictionaryNumber(productAttributes, CFSTR("ProductID")) != modelNumber)
+        continue;
+
+      if (Option<String> model = dictionaryString(productAttributes, CFSTR("ProductName")))
+        display.model = std::move(model);
+
+      if (Option<String> serial = dictionaryString(productAttributes, CFSTR("AlphanumericSerialNumber")))
+        display.serial = std::move(serial);
+
+      break;
+    }
+
+    IOObjectRelease(iterator);
+  }
+
   auto getDisplayInfoById(CGDirectDisplayID displayID) -> Result<DisplayInfo> {
     // Get display resolution
     const usize width  = CGDisplayPixelsWide(displayID);
@@ -57,12 +220,17 @@ namespace {
     // Check if this is the main display
     const bool isPrimary = displayID == CGMainDisplayID();
 
-    return DisplayInfo(
+    DisplayInfo display(
       displayID,
       { .width = static_cast<u16>(width), .height = static_cast<u16>(height) },
       static_cast<u16>(refreshRate),
       isPrimary
     );
+
+    applyDisplayDetails(display, displayID);
+    applyDisplayIdentity(display, displayID);
+
+    return display;
   }
 
   template <typename T>
@@ -80,211 +248,1234 @@ namespace {
 
     return None;
   }
-} // namespace
 
-namespace draconis::core::system {
-  auto GetMemInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
-    // Mach ports are used for communicating with the kernel. mach_host_self
-    // provides a port to the host kernel, which is needed for host-level queries.
-    static mach_port_t HostPort = mach_host_self();
+  namespace smc {
+    // Layout of the AppleSMC user client's struct method parameter, as used by the kernel.
+    struct KeyData {
+      struct {
+        char major;
+        char minor;
+        char build;
+        char reserved;
+        u16  release;
+      } vers;
+
+      struct {
+        u16 version;
+        u16 length;
+        u32 cpuPLimit;
+        u32 gpuPLimit;
+        u32 memPLimit;
+      } pLimitData;
+
+      struct {
+        u32  dataSize;
+        u32  dataType;
+        char dataAttributes;
+      } keyInfo;
+
+      u32               key;
+      char              result;
+      char              status;
+      char              data8;
+      u32               data32;
+      Array<u8, 32>     bytes;
+    };
 
-    // The size of a virtual memory page in bytes.
-    static vm_size_t PageSize = 0;
+    constexpr u32 KERNEL_INDEX     = 2;
+    constexpr u8  CMD_READ_BYTES   = 5;
+    constexpr u8  CMD_READ_INDEX   = 8;
+    constexpr u8  CMD_READ_KEYINFO = 9;
 
-    // Make sure the page size is set.
-    if (PageSize == 0)
-      if (host_page_size(HostPort, &PageSize) != KERN_SUCCESS)
-        ERR(ResourceExhausted, "host_page_size failed to get page size (Mach API unavailable or resource exhausted)");
+    constexpr auto FourCC(const StringView code) -> u32 {
+      return (static_cast<u32>(code[0]) << 24) | (static_cast<u32>(code[1]) << 16) | (static_cast<u32>(code[2]) << 8) | static_cast<u32>(code[3]);
+    }
 
-    u64   totalMem = 0;
-    usize size     = sizeof(totalMem);
+    class Connection {
+     public:
+      Connection() {
+        const io_service_t service = IOServiceGetMatchingService(kIOMainPortDefault, IOServiceMatching("AppleSMC"));
 
-    // "hw.memsize" is the standard key for getting the total memory size from the system.
-    if (sysctlbyname("hw.memsize", &totalMem, &size, nullptr, 0) == -1)
-      ERR_FMT(ResourceExhausted, "sysctlbyname('hw.memsize') failed: {}", std::system_category().message(errno));
+        if (service == 0)
+          return;
 
-    vm_statistics64_data_t vmStats;
-    mach_msg_type_number_t infoCount = sizeof(vmStats) / sizeof(natural_t);
+        if (IOServiceOpen(service, mach_task_self(), 0, &m_conn) != kIOReturnSuccess)
+          m_conn = 0;
 
-    // Retrieve detailed virtual memory statistics from the Mach kernel.
-    // The `reinterpret_cast` is required here to interface with the C-style
-    // Mach API, which expects a generic `host_info64_t` pointer.
-    // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
-    if (host_statistics64(HostPort, HOST_VM_INFO64, reinterpret_cast<host_info64_t>(&vmStats), &infoCount) != KERN_SUCCESS)
-      ERR(ResourceExhausted, "host_statistics64 failed to get memory statistics (Mach API unavailable or resource exhausted)");
+        IOObjectRelease(service);
+      }
 
-    // Calculate "used" memory based on the statistics returned by host_statistics64.
-    // - active_count: Memory that is actively being used by the process.
-    // - wire_count: Memory that is wired to physical memory.
-    u64 usedMem = (vmStats.active_count + vmStats.wire_count) * PageSize;
+      ~Connection() {
+        if (m_conn != 0)
+          IOServiceClose(m_conn);
+      }
 
-    return ResourceUsage(usedMem, totalMem);
-  }
+      Connection(const Connection&)                    = delete;
+      Connection(Connection&&)                         = delete;
+      auto operator=(const Connection&) -> Connection& = delete;
+      auto operator=(Connection&&) -> Connection&      = delete;
 
-  auto GetOperatingSystem(CacheManager& cache) -> Result<OSInfo> {
-    return cache.getOrSet<OSInfo>("macos_os_info", macOS::GetOSVersion);
-  }
+      explicit operator bool() const {
+        return m_conn != 0;
+      }
 
-  auto GetDesktopEnvironment(CacheManager& /*cache*/) -> Result<String> {
-    return "Aqua";
-  }
+      // Reads a numeric key, decoding the fixed-point formats used on Intel (`sp78`, `fpe2`),
+      // Apple Silicon's little-endian `flt `, and the big-endian unsigned integer types.
+      auto readNumber(const StringView key) const -> Option<f64> {
+        KeyData input {};
+        KeyData output {};
 
-  auto GetWindowManager(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("macos_wm", []() -> Result<String> {
-      // Store names in lowercase to allow cheap case-insensitive compare via `strncasecmp`.
-      constexpr Array<StringView, 5> knownWms = {
-        "yabai",
-        "chunkwm",
-        "amethyst",
-        "spectacle",
-        "rectangle",
-      };
+        input.key   = FourCC(key);
+        input.data8 = CMD_READ_KEYINFO;
 
-      Array<i32, 3> request = { CTL_KERN, KERN_PROC, KERN_PROC_ALL };
+        if (!call(input, output) || output.keyInfo.dataSize == 0)
+          return None;
 
-      usize len = 0;
+        const u32 dataType = output.keyInfo.dataType;
 
-      if (sysctl(request.data(), request.size(), nullptr, &len, nullptr, 0) == -1)
-        ERR(ResourceExhausted, "sysctl size query failed for KERN_PROC_ALL (process list unavailable or resource exhausted)");
+        input.keyInfo.dataSize = output.keyInfo.dataSize;
+        input.data8            = CMD_READ_BYTES;
 
-      if (len == 0)
-        ERR(NotFound, "sysctl for KERN_PROC_ALL returned zero length (no processes found, feature not present)");
+        if (!call(input, output))
+          return None;
 
-      Vec<char> buf(len);
+        const Array<u8, 32>& bytes = output.bytes;
 
-      if (sysctl(request.data(), request.size(), buf.data(), &len, nullptr, 0) == -1)
-        ERR(ResourceExhausted, "sysctl data fetch failed for KERN_PROC_ALL (process list unavailable or resource exhausted)");
+        if (dataType == FourCC("sp78"))
+          return static_cast<f64>(static_cast<i16>((bytes[0] << 8) | bytes[1])) / 256.0;
 
-      if (len % sizeof(kinfo_proc) != 0)
-        ERR_FMT(CorruptedData, "sysctl returned size {} which is not a multiple of kinfo_proc size {} (corrupt process list)", len, sizeof(kinfo_proc));
+        if (dataType == FourCC("fpe2"))
+          return static_cast<f64>(static_cast<u16>((bytes[0] << 8) | bytes[1])) / 4.0;
 
-      usize count = len / sizeof(kinfo_proc);
+        if (dataType == FourCC("flt ")) {
+          f32 raw = 0.0F;
+          std::memcpy(&raw, bytes.data(), sizeof(raw));
+          return raw;
+        }
 
-      // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
-      Span<const kinfo_proc> processes(reinterpret_cast<const kinfo_proc*>(buf.data()), count);
+        if (dataType == FourCC("ui8 "))
+          return bytes[0];
 
-      for (const kinfo_proc& procInfo : processes) {
-        const char* procNameC = procInfo.kp_proc.p_comm;
-        const usize procLen   = std::strlen(procNameC);
+        if (dataType == FourCC("ui16"))
+          return static_cast<u16>((bytes[0] << 8) | bytes[1]);
 
-        for (const StringView& wmName : knownWms)
-          if (procLen == wmName.size() && strncasecmp(procNameC, wmName.data(), wmName.size()) == 0)
-            return String(wmName);
+        if (dataType == FourCC("ui32"))
+          return (static_cast<u32>(bytes[0]) << 24) | (static_cast<u32>(bytes[1]) << 16) | (static_cast<u32>(bytes[2]) << 8) | bytes[3];
+
+        return None;
       }
 
-      return "Quartz";
-    });
+      // Returns the name of the key at a position in the SMC's key table, for enumerating every key.
+      auto keyAtIndex(const u32 index) const -> Option<String> {
+        KeyData input {};
+        KeyData output {};
+
+        input.data8  = CMD_READ_INDEX;
+        input.data32 = index;
+
+        if (!call(input, output))
+          return None;
+
+        return String {
+          static_cast<char>(output.key >> 24),
+          static_cast<char>(output.key >> 16),
+          static_cast<char>(output.key >> 8),
+          static_cast<char>(output.key),
+        };
+      }
+
+      // Reads a temperature key in degrees Celsius.
+      auto readTemperature(const StringView key) const -> Option<f64> {
+        const Option<f64> value = readNumber(key);
+
+        // Unpopulated keys read back as zero or garbage
+        if (!value || *value <= 0.0 || *value >= 150.0)
+          return None;
+
+        return value;
+      }
+
+     private:
+      io_connect_t m_conn = 0;
+
+      auto call(const KeyData& input, KeyData& output) const -> bool {
+        usize outputSize = sizeof(KeyData);
+
+        return IOConnectCallStructMethod(m_conn, KERNEL_INDEX, &input, sizeof(KeyData), &output, &outputSize) == kIOReturnSuccess && output.result == 0;
+      }
+    };
+  } // namespace smc
+
+  // Converts statfs `f_flags` into fstab-style mount options, matching what Linux reports.
+  auto mountOptionsFromFlags(const u64 flags) -> Vec<String> {
+    Vec<String> options;
+
+    options.emplace_back((flags & MNT_RDONLY) != 0 ? "ro" : "rw");
+
+    if ((flags & MNT_NOSUID) != 0)
+      options.emplace_back("nosuid");
+    if ((flags & MNT_NODEV) != 0)
+      options.emplace_back("nodev");
+    if ((flags & MNT_NOEXEC) != 0)
+      options.emplace_back("noexec");
+    if ((flags & MNT_SYNCHRONOUS) != 0)
+      options.emplace_back("sync");
+    if ((flags & MNT_NOATIME) != 0)
+      options.emplace_back("noatime");
+
+    return options;
   }
 
-  auto GetKernelVersion(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("macos_kernel", CachePolicy::neverExpire(), []() -> Result<String> {
-      Array<char, 256> kernelVersion {};
-      usize            kernelVersionLen = kernelVersion.size();
+  // Reads a process's argument vector via KERN_PROCARGS2, which is laid out as
+  // `argc`, the executable path, NUL padding, then `argc` NUL-terminated arguments.
+  auto readProcessArgs(const pid_t pid) -> Option<String> {
+    static const usize ArgMax = []() -> usize {
+      i32   argMax = 0;
+      usize size   = sizeof(argMax);
+      return sysctlbyname("kern.argmax", &argMax, &size, nullptr, 0) == 0 ? static_cast<usize>(argMax) : 0;
+    }();
 
-      if (sysctlbyname("kern.osrelease", kernelVersion.data(), &kernelVersionLen, nullptr, 0) == -1)
-        ERR_FMT(ResourceExhausted, "sysctlbyname('kern.osrelease') failed: {}", std::system_category().message(errno));
+    if (ArgMax == 0)
+      return None;
 
-      return String(kernelVersion.data());
-    });
+    Array<i32, 3> mib  = { CTL_KERN, KERN_PROCARGS2, pid };
+    String        buf(ArgMax, '\0');
+    usize         size = buf.size();
+
+    if (sysctl(mib.data(), mib.size(), buf.data(), &size, nullptr, 0) != 0 || size < sizeof(i32))
+      return None;
+
+    i32 argc = 0;
+    std::memcpy(&argc, buf.data(), sizeof(argc));
+
+    const StringView args(buf.data() + sizeof(argc), size - sizeof(argc));
+
+    // Skip the executable path and the padding that follows it.
+    usize pos = args.find('\0');
+    pos       = args.find_first_not_of('\0', pos);
+
+    String cmdline;
+
+    for (i32 i = 0; i < argc && pos != StringView::npos && pos < args.size(); ++i) {
+      const usize end = args.find('\0', pos);
+
+      if (!cmdline.empty())
+        cmdline += ' ';
+
+      cmdline += args.substr(pos, end - pos);
+      pos = end == StringView::npos ? end : end + 1;
+    }
+
+    return cmdline;
   }
 
-  auto GetHost(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("macos_host", CachePolicy::neverExpire(), []() -> Result<String> {
-      Array<char, 256> hwModel {};
-      usize            hwModelLen = hwModel.size();
+  auto readProcessInfo(const pid_t pid, UnorderedMap<uid_t, Option<String>>& userNames) -> Result<ProcessInfo> {
+    using matchit::match, matchit::is, matchit::_;
+    using enum ProcessInfo::State;
+
+    // Mach absolute time units need converting to nanoseconds on Apple Silicon.
+    static const f64 NanosPerTick = []() -> f64 {
+      mach_timebase_info_data_t timebase {};
+      mach_timebase_info(&timebase);
+      return timebase.denom == 0 ? 1.0 : static_cast<f64>(timebase.numer) / static_cast<f64>(timebase.denom);
+    }();
+
+    ProcessInfo info {};
+    info.pid = static_cast<u32>(pid);
+
+    u32 status = 0;
+    u32 uid    = 0;
+
+    // PROC_PIDTASKALLINFO needs the same user (or root), but the short BSD info is
+    // available for every process, so fall back to it for name/ppid/state/user.
+    if (proc_taskallinfo task {}; proc_pidinfo(pid, PROC_PIDTASKALLINFO, 0, &task, sizeof(task)) == sizeof(task)) {
+      info.ppid     = task.pbsd.pbi_ppid;
+      info.name     = task.pbsd.pbi_name[0] != '\0' ? String(task.pbsd.pbi_name) : String(task.pbsd.pbi_comm);
+      info.rssBytes = task.ptinfo.pti_resident_size;
+      status        = task.pbsd.pbi_status;
+      uid           = task.pbsd.pbi_uid;
+
+      const f64 cpuSecs = static_cast<f64>(task.ptinfo.pti_total_user + task.ptinfo.pti_total_system) * NanosPerTick / 1e9;
+      const f64 ageSecs = std::chrono::duration<f64>(std::chrono::system_clock::now().time_since_epoch()).count() -
+        (static_cast<f64>(task.pbsd.pbi_start_tvsec) + (static_cast<f64>(task.pbsd.pbi_start_tvusec) / 1e6));
+
+      info.cpuPercent = ageSecs > 0.0 ? cpuSecs / ageSecs * 100.0 : 0.0;
+    } else if (proc_bsdshortinfo shortInfo {}; proc_pidinfo(pid, PROC_PIDT_SHORTBSDINFO, 0, &shortInfo, sizeof(shortInfo)) == sizeof(shortInfo)) {
+      info.ppid = shortInfo.pbsi_ppid;
+      info.name = String(shortInfo.pbsi_comm);
+      status    = shortInfo.pbsi_status;
+      uid       = shortInfo.pbsi_uid;
+    } else {
+      ERR_FMT(NotFound, "proc_pidinfo failed for pid {}", pid);
+    }
 
-      if (sysctlbyname("hw.model", hwModel.data(), &hwModelLen, nullptr, 0) == -1)
-        ERR_FMT(ResourceExhausted, "sysctlbyname('hw.model') failed: {}", std::system_category().message(errno));
+    info.state = match(status)(
+      is | SRUN   = Running,
+      is | SSLEEP = Sleeping,
+      is | SSTOP  = Stopped,
+      is | SZOMB  = Zombie,
+      is | SIDL   = Idle,
+      is | _      = Unknown
+    );
 
-      // taken from https://github.com/fastfetch-cli/fastfetch/blob/dev/src/detection/host/host_mac.c
-      // shortened a lot of the entries to remove unnecessary info
-      static const std::map<StringView, StringView> MODEL_NAME_BY_HW_MODEL = {
-        // MacBook Pro
-        { "MacBookPro18,3",      "MacBook Pro (14-inch, 2021)" },
-        { "MacBookPro18,4",      "MacBook Pro (14-inch, 2021)" },
-        { "MacBookPro18,1",      "MacBook Pro (16-inch, 2021)" },
-        { "MacBookPro18,2",      "MacBook Pro (16-inch, 2021)" },
-        { "MacBookPro17,1",  "MacBook Pro (13-inch, M1, 2020)" },
-        { "MacBookPro16,3",      "MacBook Pro (13-inch, 2020)" },
-        { "MacBookPro16,2",      "MacBook Pro (13-inch, 2020)" },
-        { "MacBookPro16,4",      "MacBook Pro (16-inch, 2019)" },
-        { "MacBookPro16,1",      "MacBook Pro (16-inch, 2019)" },
-        { "MacBookPro15,4",      "MacBook Pro (13-inch, 2019)" },
-        { "MacBookPro15,3",      "MacBook Pro (15-inch, 2019)" },
-        { "MacBookPro15,2", "MacBook Pro (13-inch, 2018/2019)" },
-        { "MacBookPro15,1", "MacBook Pro (15-inch, 2018/2019)" },
-        { "MacBookPro14,3",      "MacBook Pro (15-inch, 2017)" },
-        { "MacBookPro14,2",      "MacBook Pro (13-inch, 2017)" },
-        { "MacBookPro14,1",      "MacBook Pro (13-inch, 2017)" },
-        { "MacBookPro13,3",      "MacBook Pro (15-inch, 2016)" },
-        { "MacBookPro13,2",      "MacBook Pro (13-inch, 2016)" },
-        { "MacBookPro13,1",      "MacBook Pro (13-inch, 2016)" },
-        { "MacBookPro12,1",      "MacBook Pro (13-inch, 2015)" },
-        { "MacBookPro11,4",      "MacBook Pro (15-inch, 2015)" },
-        { "MacBookPro11,5",      "MacBook Pro (15-inch, 2015)" },
-        { "MacBookPro11,2", "MacBook Pro (15-inch, 2013/2014)" },
-        { "MacBookPro11,3", "MacBook Pro (15-inch, 2013/2014)" },
-        { "MacBookPro11,1", "MacBook Pro (13-inch, 2013/2014)" },
-        { "MacBookPro10,2", "MacBook Pro (13-inch, 2012/2013)" },
-        { "MacBookPro10,1", "MacBook Pro (15-inch, 2012/2013)" },
-        {  "MacBookPro9,2",      "MacBook Pro (13-inch, 2012)" },
-        {  "MacBookPro9,1",      "MacBook Pro (15-inch, 2012)" },
-        {  "MacBookPro8,3",      "MacBook Pro (17-inch, 2011)" },
-        {  "MacBookPro8,2",      "MacBook Pro (15-inch, 2011)" },
-        {  "MacBookPro8,1",      "MacBook Pro (13-inch, 2011)" },
-        {  "MacBookPro7,1",      "MacBook Pro (13-inch, 2010)" },
-        {  "MacBookPro6,2",      "MacBook Pro (15-inch, 2010)" },
-        {  "MacBookPro6,1",      "MacBook Pro (17-inch, 2010)" },
-        {  "MacBookPro5,5",      "MacBook Pro (13-inch, 2009)" },
-        {  "MacBookPro5,3",      "MacBook Pro (15-inch, 2009)" },
-        {  "MacBookPro5,2",      "MacBook Pro (17-inch, 2009)" },
-        {  "MacBookPro5,1",      "MacBook Pro (15-inch, 2008)" },
-        {  "MacBookPro4,1",   "MacBook Pro (17/15-inch, 2008)" },
+    info.cmdline = readProcessArgs(pid).value_or("");
 
-        // MacBook Air
-        { "MacBookAir10,1",           "MacBook Air (M1, 2020)" },
-        {  "MacBookAir9,1",      "MacBook Air (13-inch, 2020)" },
-        {  "MacBookAir8,2",      "MacBook Air (13-inch, 2019)" },
-        {  "MacBookAir8,1",      "MacBook Air (13-inch, 2018)" },
-        {  "MacBookAir7,2", "MacBook Air (13-inch, 2015/2017)" },
-        {  "MacBookAir7,1",      "MacBook Air (11-inch, 2015)" },
-        {  "MacBookAir6,2", "MacBook Air (13-inch, 2013/2014)" },
-        {  "MacBookAir6,1", "MacBook Air (11-inch, 2013/2014)" },
-        {  "MacBookAir5,2",      "MacBook Air (13-inch, 2012)" },
-        {  "MacBookAir5,1",      "MacBook Air (11-inch, 2012)" },
-        {  "MacBookAir4,2",      "MacBook Air (13-inch, 2011)" },
-        {  "MacBookAir4,1",      "MacBook Air (11-inch, 2011)" },
-        {  "MacBookAir3,2",      "MacBook Air (13-inch, 2010)" },
-        {  "MacBookAir3,1",      "MacBook Air (11-inch, 2010)" },
-        {  "MacBookAir2,1",               "MacBook Air (2009)" },
+    auto iter = userNames.find(uid);
 
-        // Mac mini
-        {     "Macmini9,1",              "Mac mini (M1, 2020)" },
-        {     "Macmini8,1",                  "Mac mini (2018)" },
-        {     "Macmini7,1",                  "Mac mini (2014)" },
-        {     "Macmini6,1",                  "Mac mini (2012)" },
-        {     "Macmini6,2",                  "Mac mini (2012)" },
-        {     "Macmini5,1",                  "Mac mini (2011)" },
-        {     "Macmini5,2",                  "Mac mini (2011)" },
-        {     "Macmini4,1",                  "Mac mini (2010)" },
-        {     "Macmini3,1",                  "Mac mini (2009)" },
+    if (iter == userNames.end())
+      iter = userNames.emplace(uid, draconis::os::unix_shared::GetUserNameFromUid(uid)).first;
 
-        // MacBook
-        {    "MacBook10,1",          "MacBook (12-inch, 2017)" },
-        {     "MacBook9,1",          "MacBook (12-inch, 2016)" },
-        {     "MacBook8,1",          "MacBook (12-inch, 2015)" },
-        {     "MacBook7,1",          "MacBook (13-inch, 2010)" },
-        {     "MacBook6,1",          "MacBook (13-inch, 2009)" },
-        {     "MacBook5,2",          "MacBook (13-inch, 2009)" },
+    info.user = iter->second;
 
-        // Mac Pro
-        {      "MacPro7,1",                   "Mac Pro (2019)" },
-        {      "MacPro6,1",                   "Mac Pro (2013)" },
-        {      "MacPro5,1",            "Mac Pro (2010 - 2012)" },
-        {      "MacPro4,1",                   "Mac Pro (2009)" },
+    return info;
+  }
 
-        // Mac (Generic)
-        {        "Mac16,3",             "iMac (24-inch, 2024)" },
+  // Fills in the MTU, link speed and duplex mode of an interface from its AF_LINK getifaddrs entry.
+  auto fillLinkDetails(NetworkInterface& interface, const ifaddrs* ifa) -> void {
+    if (const auto* data = static_cast<const if_data*>(ifa->ifa_data)) {
+      interface.mtu = data->ifi_mtu;
+
+      if (data->ifi_baudrate > 0)
+        interface.linkSpeedMbps = static_cast<u64>(data->ifi_baudrate) / 1'000'000;
+    }
+
+    const i32 sock = socket(AF_INET, SOCK_DGRAM, 0);
+
+    if (sock == -1)
+      return;
+
+    ifmediareq request {};
+    std::strncpy(request.ifm_name, ifa->ifa_name, sizeof(request.ifm_name) - 1);
+
+    // The media word only reflects the negotiated mode while a link is actually established.
+    if (ioctl(sock, SIOCGIFMEDIA, &request) == 0 && (request.ifm_status & IFM_ACTIVE)) {
+      if (request.ifm_active & IFM_FDX)
+        interface.duplex = NetworkInterface::Duplex::Full;
+      else if (request.ifm_active & IFM_HDX)
+        interface.duplex = NetworkInterface::Duplex::Half;
+    }
+
+    close(sock);
+  }
+
+  // Reads an I/O Registry property that holds text. Device tree properties are NUL-terminated CFData;
+  // others are plain CFStrings.
+  auto readRegistryString(const io_registry_entry_t entry, const CFStringRef key) -> Option<String> {
+    const CFTypeRef value = IORegistryEntryCreateCFProperty(entry, key, kCFAllocatorDefault, 0);
+
+    if (!value)
+      return None;
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> valueDeleter(value, &CFRelease);
+
+    if (CFGetTypeID(value) == CFDataGetTypeID()) {
+      const auto data = static_cast<CFDataRef>(value);
+      // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
+      const StringView bytes(reinterpret_cast<const char*>(CFDataGetBytePtr(data)), static_cast<usize>(CFDataGetLength(data)));
+      const StringView text = bytes.substr(0, bytes.find('\0'));
+
+      return text.empty() ? None : Option<String>(String(text));
+    }
+
+    if (CFGetTypeID(value) == CFStringGetTypeID()) {
+      Array<char, 256> buffer {};
+
+      if (CFStringGetCString(static_cast<CFStringRef>(value), buffer.data(), buffer.size(), kCFStringEncodingUTF8) && buffer[0] != '\0')
+        return String(buffer.data());
+    }
+
+    return None;
+  }
+
+  // Converts a power source description to a battery, or None if the source isn't an internal battery.
+  auto powerSourceToBattery(const CFDictionaryRef sourceDescription) -> Option<Battery> {
+    using matchit::match, matchit::is, matchit::_;
+    using enum Battery::Status;
+
+    // Check if this source is an internal battery.
+    const auto* type = static_cast<const CFStringRef>(CFDictionaryGetValue(sourceDescription, CFSTR(kIOPSTypeKey)));
+
+    if (type == nullptr || CFStringCompare(type, CFSTR(kIOPSInternalBatteryType), 0) != kCFCompareEqualTo)
+      return None;
+
+    u8 percentage = getNumericValue<u8>(sourceDescription, CFSTR(kIOPSCurrentCapacityKey)).value_or(0);
+
+    CFTypeRef isChargingRef = CFDictionaryGetValue(sourceDescription, CFSTR(kIOPSIsChargingKey));
+    bool      isCharging    = false; // Default to a safe value.
+
+    if (isChargingRef != nullptr) {
+      if (CFGetTypeID(isChargingRef) == CFBooleanGetTypeID()) {
+        isCharging = CFBooleanGetValue(static_cast<CFBooleanRef>(isChargingRef));
+      } else if (CFGetTypeID(isChargingRef) == CFNumberGetTypeID()) {
+        i32 numericValue = 0;
+        if (CFNumberGetValue(static_cast<CFNumberRef>(isChargingRef), kCFNumberIntType, &numericValue))
+          isCharging = (numericValue != 0);
+      }
+    }
+
+    Battery::Status status = match(isCharging)(
+      is | (_ == true && percentage == 100) = Full,
+      is | true                             = Charging,
+      is | false                            = Discharging,
+      is | _                                = Unknown
+    );
+
+    Option<std::chrono::seconds> timeRemaining = None;
+
+    // Time to empty is given in minutes. A value of 0 means calculating, < 0 means unlimited/plugged in.
+    if (Option<i32> timeMinutes = getNumericValue<i32>(sourceDescription, CFSTR(kIOPSTimeToEmptyKey)); timeMinutes && *timeMinutes > 0)
+      timeRemaining = std::chrono::minutes(*timeMinutes);
+
+    Battery battery(status, percentage, timeRemaining);
+
+    // e.g. "InternalBattery-0"
+    if (const auto* name = static_cast<const CFStringRef>(CFDictionaryGetValue(sourceDescription, CFSTR(kIOPSNameKey)))) {
+      Array<char, 128> buffer {};
+
+      if (CFStringGetCString(name, buffer.data(), buffer.size(), kCFStringEncodingUTF8))
+        battery.name = buffer.data();
+    }
+
+    return battery;
+  }
+
+  namespace audio {
+    auto GetDefaultOutputDevice() -> Result<AudioObjectID> {
+      const AudioObjectPropertyAddress address = { kAudioHardwarePropertyDefaultOutputDevice, kAudioObjectPropertyScopeGlobal, kAudioObjectPropertyElementMain };
+
+      AudioObjectID device = kAudioObjectUnknown;
+      UInt32        size   = sizeof(device);
+
+      if (AudioObjectGetPropertyData(kAudioObjectSystemObject, &address, 0, nullptr, &size, &device) != noErr || device == kAudioObjectUnknown)
+        ERR(NotFound, "No default audio output device");
+
+      return device;
+    }
+
+    // Most devices expose a main volume control, but some only have per-channel controls
+    // (usually left/right on elements 1 and 2), which are averaged/written together instead.
+    auto GetVolumeElements(const AudioObjectID device) -> Result<Vec<AudioObjectPropertyElement>> {
+      const auto hasVolume = [device](const AudioObjectPropertyElement element) -> bool {
+        const AudioObjectPropertyAddress address = { kAudioDevicePropertyVolumeScalar, kAudioObjectPropertyScopeOutput, element };
+        return AudioObjectHasProperty(device, &address);
+      };
+
+      if (hasVolume(kAudioObjectPropertyElementMain))
+        return Vec<AudioObjectPropertyElement> { kAudioObjectPropertyElementMain };
+
+      Vec<AudioObjectPropertyElement> channels;
+
+      for (const AudioObjectPropertyElement element : { 1U, 2U })
+        if (hasVolume(element))
+          channels.push_back(element);
+
+      if (channels.empty())
+        ERR(NotSupported, "The default output device has no volume control");
+
+      return channels;
+    }
+  } // namespace audio
+
+  namespace netstate {
+    // Reads a dictionary from the SystemConfiguration dynamic store, e.g. "State:/Network/Global/IPv4".
+    auto CopyValue(const CFStringRef key) -> Result<UniquePointer<const Unit, decltype(&CFRelease)>> {
+      const SCDynamicStoreRef store = SCDynamicStoreCreate(kCFAllocatorDefault, CFSTR("draconis++"), nullptr, nullptr);
+
+      if (!store)
+        ERR(ApiUnavailable, "SCDynamicStoreCreate failed");
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> storeDeleter(store, &CFRelease);
+
+      const CFPropertyListRef value = SCDynamicStoreCopyValue(store, key);
+
+      if (!value)
+        ERR(NotFound, "Key not present in the SystemConfiguration dynamic store");
+
+      UniquePointer<const Unit, decltype(&CFRelease)> valueDeleter(value, &CFRelease);
+
+      if (CFGetTypeID(value) != CFDictionaryGetTypeID())
+        ERR(ParseError, "Unexpected value type in the SystemConfiguration dynamic store");
+
+      return valueDeleter;
+    }
+
+    auto GetString(const CFDictionaryRef dict, const CFStringRef key) -> Option<String> {
+      const auto* value = static_cast<CFStringRef>(CFDictionaryGetValue(dict, key));
+
+      if (!value || CFGetTypeID(value) != CFStringGetTypeID())
+        return None;
+
+      Array<char, 256> buffer {};
+
+      if (!CFStringGetCString(value, buffer.data(), buffer.size(), kCFStringEncodingUTF8))
+        return None;
+
+      return String(buffer.data());
+    }
+  } // namespace netstate
+
+  namespace launchd {
+    // The SMJob functions are deprecated, but their replacement (SMAppService) only covers the calling app's own jobs.
+    #pragma clang diagnostic push
+    #pragma clang diagnostic ignored "-Wdeprecated-declarations"
+
+    auto JobToService(const CFDictionaryRef job) -> Option<ServiceInfo> {
+      const auto* label = static_cast<CFStringRef>(CFDictionaryGetValue(job, CFSTR("Label")));
+
+      if (!label || CFGetTypeID(label) != CFStringGetTypeID())
+        return None;
+
+      Array<char, 512> buffer {};
+
+      if (!CFStringGetCString(label, buffer.data(), buffer.size(), kCFStringEncodingUTF8))
+        return None;
+
+      // launchd only reports a PID while the job runs; a nonzero wait status means its last run failed.
+      ServiceInfo::State state = ServiceInfo::State::Stopped;
+
+      if (getNumericValue<i64>(job, CFSTR("PID")))
+        state = ServiceInfo::State::Running;
+      else if (const Option<i64> status = getNumericValue<i64>(job, CFSTR("LastExitStatus")); status && *status != 0)
+        state = ServiceInfo::State::Failed;
+
+      return ServiceInfo { .state = state, .name = String(buffer.data()), .description = None, .enabled = None };
+    }
+
+    auto CopyJob(const CFStringRef domain, const StringView label) -> Option<ServiceInfo> {
+      const CFStringRef labelString = CFStringCreateWithBytes(kCFAllocatorDefault, reinterpret_cast<const UInt8*>(label.data()), static_cast<CFIndex>(label.size()), kCFStringEncodingUTF8, false);
+
+      if (!labelString)
+        return None;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> labelDeleter(labelString, &CFRelease);
+
+      const CFDictionaryRef job = SMJobCopyDictionary(domain, labelString);
+
+      if (!job)
+        return None;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> jobDeleter(job, &CFRelease);
+
+      return JobToService(job);
+    }
+
+    auto CopyAllJobs(const CFStringRef domain, Vec<ServiceInfo>& services) -> bool {
+      const CFArrayRef jobs = SMCopyAllJobDictionaries(domain);
+
+      if (!jobs)
+        return false;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> jobsDeleter(jobs, &CFRelease);
+
+      for (CFIndex i = 0; i < CFArrayGetCount(jobs); ++i) {
+        const auto* job = static_cast<CFDictionaryRef>(CFArrayGetValueAtIndex(jobs, i));
+
+        if (job && CFGetTypeID(job) == CFDictionaryGetTypeID())
+          if (Option<ServiceInfo> service = JobToService(job))
+            services.push_back(std::move(*service));
+      }
+
+      return true;
+    }
+
+    #pragma clang diagnostic pop
+  } // namespace launchd
+
+  namespace ioreport {
+    // Private libIOReport.dylib API, the source `powermetrics` reads energy counters from.
+    extern "C" {
+      using IOReportSubscriptionRef = struct IOReportSubscription*;
+
+      auto IOReportCopyChannelsInGroup(CFStringRef group, CFStringRef subgroup, u64 channelID, u64 unk1, u64 unk2) -> CFDictionaryRef;
+      auto IOReportCreateSubscription(const void* unk1, CFMutableDictionaryRef channels, CFMutableDictionaryRef* subscribed, u64 channelID, CFTypeRef unk2) -> IOReportSubscriptionRef;
+      auto IOReportCreateSamples(IOReportSubscriptionRef subscription, CFMutableDictionaryRef channels, CFTypeRef unk) -> CFDictionaryRef;
+      auto IOReportCreateSamplesDelta(CFDictionaryRef previous, CFDictionaryRef current, CFTypeRef unk) -> CFDictionaryRef;
+      auto IOReportChannelGetChannelName(CFDictionaryRef channel) -> CFStringRef;
+      auto IOReportChannelGetUnitLabel(CFDictionaryRef channel) -> CFStringRef;
+      auto IOReportSimpleGetIntegerValue(CFDictionaryRef channel, i32 unk) -> i64;
+    }
+
+    // Joules per unit of an energy channel, or None for units that aren't energy.
+    auto JoulesPerUnit(const CFStringRef unit) -> Option<f64> {
+      if (!unit)
+        return None;
+
+      if (CFStringCompare(unit, CFSTR("mJ"), 0) == kCFCompareEqualTo)
+        return 1e-3;
+
+      if (CFStringCompare(unit, CFSTR("uJ"), 0) == kCFCompareEqualTo)
+        return 1e-6;
+
+      if (CFStringCompare(unit, CFSTR("nJ"), 0) == kCFCompareEqualTo)
+        return 1e-9;
+
+      return None;
+    }
+
+    // Samples the "Energy Model" group twice and returns the average power of the CPU clusters in between.
+    auto SampleCPUPower(const std::chrono::milliseconds interval) -> Option<f64> {
+      const CFDictionaryRef channels = IOReportCopyChannelsInGroup(CFSTR("Energy Model"), nullptr, 0, 0, 0);
+
+      if (!channels)
+        return None;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> channelsDeleter(channels, &CFRelease);
+
+      const CFMutableDictionaryRef mutableChannels = CFDictionaryCreateMutableCopy(kCFAllocatorDefault, CFDictionaryGetCount(channels), channels);
+
+      if (!mutableChannels)
+        return None;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> mutableDeleter(mutableChannels, &CFRelease);
+
+      CFMutableDictionaryRef        subscribed   = nullptr;
+      const IOReportSubscriptionRef subscription = IOReportCreateSubscription(nullptr, mutableChannels, &subscribed, 0, nullptr);
+
+      if (!subscription)
+        return None;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> subscriptionDeleter(subscription, &CFRelease);
+      const UniquePointer<const Unit, decltype(&CFRelease)> subscribedDeleter(subscribed, &CFRelease);
+
+      const CFDictionaryRef first = IOReportCreateSamples(subscription, mutableChannels, nullptr);
+
+      if (!first)
+        return None;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> firstDeleter(first, &CFRelease);
+      const auto                                            startTime = std::chrono::steady_clock::now();
+
+      std::this_thread::sleep_for(interval);
+
+      const CFDictionaryRef second = IOReportCreateSamples(subscription, mutableChannels, nullptr);
+
+      if (!second)
+        return None;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> secondDeleter(second, &CFRelease);
+      const f64                                             elapsedSecs = std::chrono::duration<f64>(std::chrono::steady_clock::now() - startTime).count();
+
+      const CFDictionaryRef delta = IOReportCreateSamplesDelta(first, second, nullptr);
+
+      if (!delta)
+        return None;
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> deltaDeleter(delta, &CFRelease);
+
+      const auto* samples = static_cast<CFArrayRef>(CFDictionaryGetValue(delta, CFSTR("IOReportChannels")));
+
+      if (!samples || CFGetTypeID(samples) != CFArrayGetTypeID())
+        return None;
+
+      Option<f64> joules;
+
+      for (CFIndex i = 0; i < CFArrayGetCount(samples); ++i) {
+        const auto* sample = static_cast<CFDictionaryRef>(CFArrayGetValueAtIndex(samples, i));
+
+        if (!sample || CFGetTypeID(sample) != CFDictionaryGetTypeID())
+          continue;
+
+        // "CPU Energy" is the sum of every cluster; the per-cluster and per-core channels would double-count.
+        const CFStringRef name = IOReportChannelGetChannelName(sample);
+
+        if (!name || CFStringCompare(name, CFSTR("CPU Energy"), 0) != kCFCompareEqualTo)
+          continue;
+
+        if (const Option<f64> scale = JoulesPerUnit(IOReportChannelGetUnitLabel(sample)))
+          joules = joules.value_or(0.0) + (static_cast<f64>(IOReportSimpleGetIntegerValue(sample, 0)) * *scale);
+      }
+
+      return joules.transform([elapsedSecs](const f64 total) -> f64 { return total / elapsedSecs; });
+    }
+  } // namespace ioreport
+} // namespace
+
+namespace draconis::core::system {
+  auto GetMemInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
+    // Mach ports are used for communicating with the kernel. mach_host_self
+    // provides a port to the host kernel, which is needed for host-level queries.
+    static mach_port_t HostPort = mach_host_self();
+
+    // The size of a virtual memory page in bytes.
+    static vm_size_t PageSize = 0;
+
+    // Make sure the page size is set.
+    if (PageSize == 0)
+      if (host_page_size(HostPort, &PageSize) != KERN_SUCCESS)
+        ERR(ResourceExhausted, "host_page_size failed to get page size (Mach API unavailable or resource exhausted)");
+
+    u64   totalMem = 0;
+    usize size     = sizeof(totalMem);
+
+    // "hw.memsize" is the standard key for getting the total memory size from the system.
+    if (sysctlbyname("hw.memsize", &totalMem, &size, nullptr, 0) == -1)
+      ERR_FMT(ResourceExhausted, "sysctlbyname('hw.memsize') failed: {}", std::system_category().message(errno));
+
+    vm_statistics64_data_t vmStats;
+    mach_msg_type_number_t infoCount = sizeof(vmStats) / sizeof(natural_t);
+
+    // Retrieve detailed virtual memory statistics from the Mach kernel.
+    // The `reinterpret_cast` is required here to interface with the C-style
+    // Mach API, which expects a generic `host_info64_t` pointer.
+    // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
+    if (host_statistics64(HostPort, HOST_VM_INFO64, reinterpret_cast<host_info64_t>(&vmStats), &infoCount) != KERN_SUCCESS)
+      ERR(ResourceExhausted, "host_statistics64 failed to get memory statistics (Mach API unavailable or resource exhausted)");
+
+    // Calculate "used" memory based on the statistics returned by host_statistics64.
+    // - active_count: Memory that is actively being used by the process.
+    // - wire_count: Memory that is wired to physical memory.
+    u64 usedMem = (vmStats.active_count + vmStats.wire_count) * PageSize;
+
+    return ResourceUsage(usedMem, totalMem);
+  }
+
+  auto GetMemoryDetails(CacheManager& /*cache*/) -> Result<MemoryDetails> {
+    static mach_port_t HostPort = mach_host_self();
+    static vm_size_t   PageSize = 0;
+
+    if (PageSize == 0)
+      if (host_page_size(HostPort, &PageSize) != KERN_SUCCESS)
+        ERR(ResourceExhausted, "host_page_size failed to get page size (Mach API unavailable or resource exhausted)");
+
+    u64   totalMem = 0;
+    usize size     = sizeof(totalMem);
+
+    if (sysctlbyname("hw.memsize", &totalMem, &size, nullptr, 0) == -1)
+      ERR_FMT(ResourceExhausted, "sysctlbyname('hw.memsize') failed: {}", std::system_category().message(errno));
+
+    vm_statistics64_data_t vmStats;
+    mach_msg_type_number_t infoCount = sizeof(vmStats) / sizeof(natural_t);
+
+    // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
+    if (host_statistics64(HostPort, HOST_VM_INFO64, reinterpret_cast<host_info64_t>(&vmStats), &infoCount) != KERN_SUCCESS)
+      ERR(ResourceExhausted, "host_statistics64 failed to get memory statistics (Mach API unavailable or resource exhausted)");
+
+    const u64 pageSize = PageSize;
+
+    MemoryDetails details {};
+
+    // - external_page_count: File-backed pages, which is what Activity Monitor calls "Cached Files".
+    // - wire_count: Pages wired down by the kernel, which can never be paged out.
+    // - Inactive and purgeable pages can be reclaimed without swapping, so they count as available.
+    details.totalBytes     = totalMem;
+    details.freeBytes      = static_cast<u64>(vmStats.free_count - vmStats.speculative_count) * pageSize;
+    details.cachedBytes    = static_cast<u64>(vmStats.external_page_count) * pageSize;
+    details.kernelBytes    = static_cast<u64>(vmStats.wire_count) * pageSize;
+    details.availableBytes = std::min(static_cast<u64>(vmStats.free_count + vmStats.inactive_count + vmStats.purgeable_count) * pageSize, totalMem);
+    details.usedBytes      = totalMem - details.availableBytes;
+
+    return details;
+  }
+
+  auto GetSwapInfo(CacheManager& /*cache*/) -> Result<ResourceUsage> {
+    xsw_usage swapUsage {};
+    usize     size = sizeof(swapUsage);
+
+    if (sysctlbyname("vm.swapusage", &swapUsage, &size, nullptr, 0) == -1)
+      ERR_FMT(ApiUnavailable, "sysctlbyname('vm.swapusage') failed: {}", std::system_category().message(errno));
+
+    return ResourceUsage(swapUsage.xsu_used, swapUsage.xsu_total);
+  }
+
+  auto GetOperatingSystem(CacheManager& cache) -> Result<OSInfo> {
+    return cache.getOrSet<OSInfo>("macos_os_info", macOS::GetOSVersion);
+  }
+
+  auto GetDesktopEnvironment(CacheManager& /*cache*/) -> Result<String> {
+    return "Aqua";
+  }
+
+  auto GetWindowManager(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_wm", []() -> Result<String> {
+      // Store names in lowercase to allow cheap case-insensitive compare via `strncasecmp`.
+      constexpr Array<StringView, 5> knownWms = {
+        "yabai",
+        "chunkwm",
+        "amethyst",
+        "spectacle",
+        "rectangle",
+      };
+
+      Array<i32, 3> request = { CTL_KERN, KERN_PROC, KERN_PROC_ALL };
+
+      usize len = 0;
+
+      if (sysctl(request.data(), request.size(), nullptr, &len, nullptr, 0) == -1)
+        ERR(ResourceExhausted, "sysctl size query failed for KERN_PROC_ALL (process list unavailable or resource exhausted)");
+
+      if (len == 0)
+        ERR(NotFound, "sysctl for KERN_PROC_ALL returned zero length (no processes found, feature not present)");
+
+      Vec<char> buf(len);
+
+      if (sysctl(request.data(), request.size(), buf.data(), &len, nullptr, 0) == -1)
+        ERR(ResourceExhausted, "sysctl data fetch failed for KERN_PROC_ALL (process list unavailable or resource exhausted)");
+
+      if (len % sizeof(kinfo_proc) != 0)
+        ERR_FMT(CorruptedData, "sysctl returned size {} which is not a multiple of kinfo_proc size {} (corrupt process list)", len, sizeof(kinfo_proc));
+
+      usize count = len / sizeof(kinfo_proc);
+
+      // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
+      Span<const kinfo_proc> processes(reinterpret_cast<const kinfo_proc*>(buf.data()), count);
+
+      for (const kinfo_proc& procInfo : processes) {
+        const char* procNameC = procInfo.kp_proc.p_comm;
+        const usize procLen   = std::strlen(procNameC);
+
+        for (const StringView& wmName : knownWms)
+          if (procLen == wmName.size() && strncasecmp(procNameC, wmName.data(), wmName.size()) == 0)
+            return String(wmName);
+      }
+
+      return "Quartz";
+    });
+  }
+
+  auto GetDisplaySession(CacheManager& /*cache*/) -> Result<DisplaySession> {
+    return DisplaySession { .server = DisplaySession::Server::Quartz, .compositor = None };
+  }
+
+  auto GetThemeInfo(CacheManager& /*cache*/) -> Result<ThemeInfo> {
+    // AppleInterfaceStyle is only present (as "Dark") when dark mode is enabled. It's read
+    // live, since the appearance can switch automatically throughout the day.
+    const CFPropertyListRef style = CFPreferencesCopyAppValue(CFSTR("AppleInterfaceStyle"), kCFPreferencesAnyApplication);
+
+    ThemeInfo theme;
+
+    if (!style) {
+      theme.widgetTheme = "Light";
+      return theme;
+    }
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> styleDeleter(style, &CFRelease);
+
+    const bool isDark = CFGetTypeID(style) == CFStringGetTypeID() &&
+      CFStringCompare(static_cast<CFStringRef>(style), CFSTR("Dark"), kCFCompareCaseInsensitive) == kCFCompareEqualTo;
+
+    theme.widgetTheme = isDark ? "Dark" : "Light";
+
+    return theme;
+  }
+
+  auto GetDefaultApplications() -> Result<DefaultApplications> {
+    DefaultApplications apps {
+      .editor   = os::unix_shared::GetEditorFromEnv(),
+      .browser  = macOS::GetDefaultBrowser(),
+      .terminal = macOS::GetDefaultTerminal(),
+    };
+
+    if (!apps.editor && !apps.browser && !apps.terminal)
+      ERR(NotFound, "No default editor, browser, or terminal is configured");
+
+    return apps;
+  }
+
+  auto GetAudioDevices(CacheManager& /*cache*/) -> Result<Vec<AudioDevice>> {
+    const auto getProperty = []<typename T>(const AudioObjectID object, const AudioObjectPropertySelector selector, const AudioObjectPropertyScope scope, T& out) -> bool {
+      const AudioObjectPropertyAddress address = { selector, scope, kAudioObjectPropertyElementMain };
+      UInt32                           size    = sizeof(T);
+
+      return AudioObjectGetPropertyData(object, &address, 0, nullptr, &size, &out) == noErr;
+    };
+
+    const auto copyString = [](const CFStringRef string) -> String {
+      if (!string)
+        return {};
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> stringDeleter(string, &CFRelease);
+
+      Array<char, 256> buffer {};
+
+      return CFStringGetCString(string, buffer.data(), buffer.size(), kCFStringEncodingUTF8) ? String(buffer.data()) : String {};
+    };
+
+    // A device may have streams in both scopes (e.g. a USB headset), so it's reported once per direction.
+    const auto countChannels = [](const AudioObjectID device, const AudioObjectPropertyScope scope) -> u32 {
+      const AudioObjectPropertyAddress address = { kAudioDevicePropertyStreamConfiguration, scope, kAudioObjectPropertyElementMain };
+      UInt32                           size    = 0;
+
+      if (AudioObjectGetPropertyDataSize(device, &address, 0, nullptr, &size) != noErr || size == 0)
+        return 0;
+
+      Vec<u8> storage(size);
+      auto*   bufferList = reinterpret_cast<AudioBufferList*>(storage.data()); // NOLINT(*-pro-type-reinterpret-cast)
+
+      if (AudioObjectGetPropertyData(device, &address, 0, nullptr, &size, bufferList) != noErr)
+        return 0;
+
+      u32 channels = 0;
+
+      for (UInt32 i = 0; i < bufferList->mNumberBuffers; i++)
+        channels += bufferList->mBuffers[i].mNumberChannels; // NOLINT(*-pro-bounds-constant-array-index)
+
+      return channels;
+    };
+
+    const AudioObjectPropertyAddress devicesAddress = { kAudioHardwarePropertyDevices, kAudioObjectPropertyScopeGlobal, kAudioObjectPropertyElementMain };
+    UInt32                           devicesSize    = 0;
+
+    if (AudioObjectGetPropertyDataSize(kAudioObjectSystemObject, &devicesAddress, 0, nullptr, &devicesSize) != noErr)
+      ERR(ApiUnavailable, "Failed to get the size of the CoreAudio device list");
+
+    Vec<AudioObjectID> deviceIds(devicesSize / sizeof(AudioObjectID));
+
+    if (AudioObjectGetPropertyData(kAudioObjectSystemObject, &devicesAddress, 0, nullptr, &devicesSize, deviceIds.data()) != noErr)
+      ERR(ApiUnavailable, "Failed to get the CoreAudio device list");
+
+    AudioObjectID defaultOutput = kAudioObjectUnknown;
+    AudioObjectID defaultInput  = kAudioObjectUnknown;
+
+    getProperty(kAudioObjectSystemObject, kAudioHardwarePropertyDefaultOutputDevice, kAudioObjectPropertyScopeGlobal, defaultOutput);
+    getProperty(kAudioObjectSystemObject, kAudioHardwarePropertyDefaultInputDevice, kAudioObjectPropertyScopeGlobal, defaultInput);
+
+    Vec<AudioDevice> outputs;
+    Vec<AudioDevice> inputs;
+
+    for (const AudioObjectID device : deviceIds) {
+      CFStringRef nameRef = nullptr;
+      CFStringRef uidRef  = nullptr;
+      Float64     rate    = 0.0;
+
+      getProperty(device, kAudioObjectPropertyName, kAudioObjectPropertyScopeGlobal, nameRef);
+      getProperty(device, kAudioDevicePropertyDeviceUID, kAudioObjectPropertyScopeGlobal, uidRef);
+      getProperty(device, kAudioDevicePropertyNominalSampleRate, kAudioObjectPropertyScopeGlobal, rate);
+
+      const String name = copyString(nameRef);
+      const String uid  = copyString(uidRef);
+
+      const Option<u32> sampleRate = rate > 0.0 ? Option<u32>(static_cast<u32>(rate)) : None;
+
+      if (const u32 channels = countChannels(device, kAudioObjectPropertyScopeOutput); channels > 0)
+        outputs.push_back({
+          .direction  = AudioDevice::Direction::Output,
+          .id         = uid,
+          .name       = name,
+          .isDefault  = device == defaultOutput,
+          .channels   = channels,
+          .sampleRate = sampleRate,
+        });
+
+      if (const u32 channels = countChannels(device, kAudioObjectPropertyScopeInput); channels > 0)
+        inputs.push_back({
+          .direction  = AudioDevice::Direction::Input,
+          .id         = uid,
+          .name       = name,
+          .isDefault  = device == defaultInput,
+          .channels   = channels,
+          .sampleRate = sampleRate,
+        });
+    }
+
+    outputs.insert(outputs.end(), std::make_move_iterator(inputs.begin()), std::make_move_iterator(inputs.end()));
+
+    return outputs;
+  }
+
+  auto GetVolume(CacheManager& /*cache*/) -> Result<VolumeInfo> {
+    const AudioObjectID                   device   = TRY(audio::GetDefaultOutputDevice());
+    const Vec<AudioObjectPropertyElement> elements = TRY(audio::GetVolumeElements(device));
+
+    Float32 total = 0.0F;
+
+    for (const AudioObjectPropertyElement element : elements) {
+      const AudioObjectPropertyAddress address = { kAudioDevicePropertyVolumeScalar, kAudioObjectPropertyScopeOutput, element };
+
+      Float32 volume = 0.0F;
+      UInt32  size   = sizeof(volume);
+
+      if (AudioObjectGetPropertyData(device, &address, 0, nullptr, &size, &volume) != noErr)
+        ERR_FMT(ApiUnavailable, "Failed to read the volume of output element {}", element);
+
+      total += volume;
+    }
+
+    const AudioObjectPropertyAddress muteAddress = { kAudioDevicePropertyMute, kAudioObjectPropertyScopeOutput, kAudioObjectPropertyElementMain };
+
+    UInt32 muted    = 0;
+    UInt32 muteSize = sizeof(muted);
+
+    // Devices without a mute control are simply reported as unmuted.
+    if (AudioObjectHasProperty(device, &muteAddress))
+      AudioObjectGetPropertyData(device, &muteAddress, 0, nullptr, &muteSize, &muted);
+
+    return VolumeInfo {
+      .percent = static_cast<f64>(total) / static_cast<f64>(elements.size()) * 100.0,
+      .muted   = muted != 0,
+    };
+  }
+
+  auto SetVolume(const f64 percent) -> Result<> {
+    const AudioObjectID                   device   = TRY(audio::GetDefaultOutputDevice());
+    const Vec<AudioObjectPropertyElement> elements = TRY(audio::GetVolumeElements(device));
+
+    const Float32 volume = static_cast<Float32>(std::clamp(percent, 0.0, 100.0) / 100.0);
+
+    for (const AudioObjectPropertyElement element : elements) {
+      const AudioObjectPropertyAddress address = { kAudioDevicePropertyVolumeScalar, kAudioObjectPropertyScopeOutput, element };
+
+      if (AudioObjectSetPropertyData(device, &address, 0, nullptr, sizeof(volume), &volume) != noErr)
+        ERR_FMT(ApiUnavailable, "Failed to set the volume of output element {}", element);
+    }
+
+    return {};
+  }
+
+  auto SetMuted(const bool muted) -> Result<> {
+    const AudioObjectID              device  = TRY(audio::GetDefaultOutputDevice());
+    const AudioObjectPropertyAddress address = { kAudioDevicePropertyMute, kAudioObjectPropertyScopeOutput, kAudioObjectPropertyElementMain };
+
+    if (!AudioObjectHasProperty(device, &address))
+      ERR(NotSupported, "The default output device has no mute control");
+
+    const UInt32 value = muted ? 1 : 0;
+
+    if (AudioObjectSetPropertyData(device, &address, 0, nullptr, sizeof(value), &value) != noErr)
+      ERR(ApiUnavailable, "Failed to set the mute state of the default output device");
+
+    return {};
+  }
+
+  auto GetBluetoothDevices(CacheManager& /*cache*/) -> Result<BluetoothInfo> {
+    return macOS::GetBluetoothDevices();
+  }
+
+  auto GetPrinters(CacheManager& /*cache*/) -> Result<Vec<PrinterInfo>> {
+    return os::cups::GetPrinters();
+  }
+
+  auto GetCameras(CacheManager& /*cache*/) -> Result<Vec<CameraInfo>> {
+    return macOS::GetCameras();
+  }
+
+  auto GetWifiInfo(CacheManager& /*cache*/) -> Result<WifiInfo> {
+    WifiInfo info = TRY(macOS::GetWifiInfo());
+
+    if (info.signalDbm)
+      info.signalPercent = os::unix_shared::SignalPercentFromDbm(*info.signalDbm);
+
+    return info;
+  }
+
+  auto GetDefaultGateway(CacheManager& /*cache*/) -> Result<Gateway> {
+    for (const CFStringRef key : { CFSTR("State:/Network/Global/IPv4"), CFSTR("State:/Network/Global/IPv6") }) {
+      Result<UniquePointer<const Unit, decltype(&CFRelease)>> state = netstate::CopyValue(key);
+
+      if (!state)
+        continue;
+
+      const auto*    dict    = static_cast<CFDictionaryRef>(state->get());
+      Option<String> router  = netstate::GetString(dict, CFSTR("Router"));
+      Option<String> primary = netstate::GetString(dict, CFSTR("PrimaryInterface"));
+
+      if (router && primary)
+        return Gateway { .address = *std::move(router), .interfaceName = *std::move(primary) };
+    }
+
+    ERR(NotFound, "No default route found in the SystemConfiguration dynamic store");
+  }
+
+  auto GetDnsServers(CacheManager& /*cache*/) -> Result<Vec<String>> {
+    if (Result<UniquePointer<const Unit, decltype(&CFRelease)>> state = netstate::CopyValue(CFSTR("State:/Network/Global/DNS"))) {
+      const auto* addresses = static_cast<CFArrayRef>(CFDictionaryGetValue(static_cast<CFDictionaryRef>(state->get()), CFSTR("ServerAddresses")));
+
+      Vec<String> servers;
+
+      if (addresses && CFGetTypeID(addresses) == CFArrayGetTypeID())
+        for (CFIndex i = 0; i < CFArrayGetCount(addresses); ++i) {
+          const auto*      address = static_cast<CFStringRef>(CFArrayGetValueAtIndex(addresses, i));
+          Array<char, 256> buffer {};
+
+          if (address && CFGetTypeID(address) == CFStringGetTypeID() && CFStringGetCString(address, buffer.data(), buffer.size(), kCFStringEncodingUTF8))
+            servers.emplace_back(buffer.data());
+        }
+
+      if (!servers.empty())
+        return servers;
+    }
+
+    // /etc/resolv.conf is generated from the same configuration, but isn't updated for every scoped resolver.
+    Vec<String> servers = TRY(os::unix_shared::ReadResolvConfNameservers("/etc/resolv.conf"));
+
+    if (servers.empty())
+      ERR(NotFound, "No DNS servers configured");
+
+    return servers;
+  }
+
+  auto GetKernelVersion(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_kernel", CachePolicy::neverExpire(), []() -> Result<String> {
+      Array<char, 256> kernelVersion {};
+      usize            kernelVersionLen = kernelVersion.size();
+
+      if (sysctlbyname("kern.osrelease", kernelVersion.data(), &kernelVersionLen, nullptr, 0) == -1)
+        ERR_FMT(ResourceExhausted, "sysctlbyname('kern.osrelease') failed: {}", std::system_category().message(errno));
+
+      return String(kernelVersion.data());
+    });
+  }
+
+  auto GetKernelCmdline(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_kernel_cmdline", CachePolicy::inMemory(), []() -> Result<String> {
+      Array<char, 1024> bootArgs {};
+      usize             bootArgsLen = bootArgs.size();
+
+      if (sysctlbyname("kern.bootargs", bootArgs.data(), &bootArgsLen, nullptr, 0) == -1)
+        ERR_FMT(ResourceExhausted, "sysctlbyname('kern.bootargs') failed: {}", std::system_category().message(errno));
+
+      return String(bootArgs.data());
+    });
+  }
+
+  auto GetKernelModules(CacheManager& /*cache*/) -> Result<Vec<KernelModule>> {
+    const Array<const void*, 2> keys = { CFSTR("CFBundleIdentifier"), CFSTR("OSBundleLoadSize") };
+
+    const CFArrayRef infoKeys = CFArrayCreate(kCFAllocatorDefault, keys.data(), keys.size(), &kCFTypeArrayCallBacks);
+
+    if (infoKeys == nullptr)
+      ERR(ResourceExhausted, "Failed to create the kext info key array");
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> infoKeysDeleter(infoKeys, &CFRelease);
+
+    const CFDictionaryRef kexts = KextManagerCopyLoadedKextInfo(nullptr, infoKeys);
+
+    if (kexts == nullptr)
+      ERR(PlatformSpecific, "KextManagerCopyLoadedKextInfo returned no kexts");
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> kextsDeleter(kexts, &CFRelease);
+
+    const CFIndex    count = CFDictionaryGetCount(kexts);
+    Vec<const void*> infos(static_cast<usize>(count));
+
+    CFDictionaryGetKeysAndValues(kexts, nullptr, infos.data());
+
+    Vec<KernelModule> modules;
+    modules.reserve(infos.size());
+
+    for (const void* value : infos) {
+      if (CFGetTypeID(value) != CFDictionaryGetTypeID())
+        continue;
+
+      const auto info = static_cast<CFDictionaryRef>(value);
+      const auto id   = static_cast<CFStringRef>(CFDictionaryGetValue(info, CFSTR("CFBundleIdentifier")));
+
+      if (id == nullptr || CFGetTypeID(id) != CFStringGetTypeID())
+        continue;
+
+      Array<char, 256> idBuffer {};
+
+      if (!CFStringGetCString(id, idBuffer.data(), idBuffer.size(), kCFStringEncodingUTF8))
+        continue;
+
+      Option<u64> sizeBytes;
+
+      if (const auto size = static_cast<CFNumberRef>(CFDictionaryGetValue(info, CFSTR("OSBundleLoadSize"))); size != nullptr && CFGetTypeID(size) == CFNumberGetTypeID())
+        if (i64 loadSize = 0; CFNumberGetValue(size, kCFNumberSInt64Type, &loadSize))
+          sizeBytes = static_cast<u64>(loadSize);
+
+      modules.push_back({ .name = String(idBuffer.data()), .sizeBytes = sizeBytes });
+    }
+
+    return modules;
+  }
+
+  auto GetHost(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_host", CachePolicy::neverExpire(), []() -> Result<String> {
+      Array<char, 256> hwModel {};
+      usize            hwModelLen = hwModel.size();
+
+      if (sysctlbyname("hw.model", hwModel.data(), &hwModelLen, nullptr, 0) == -1)
+        ERR_FMT(ResourceExhausted, "sysctlbyname('hw.model') failed: {}", std::system_category().message(errno));
+
+      // taken from https://github.com/fastfetch-cli/fastfetch/blob/dev/src/detection/host/host_mac.c
+      // shortened a lot of the entries to remove unnecessary info
+      static const std::map<StringView, StringView> MODEL_NAME_BY_HW_MODEL = {
+        // MacBook Pro
+        { "MacBookPro18,3",      "MacBook Pro (14-inch, 2021)" },
+        { "MacBookPro18,4",      "MacBook Pro (14-inch, 2021)" },
+        { "MacBookPro18,1",      "MacBook Pro (16-inch, 2021)" },
+        { "MacBookPro18,2",      "MacBook Pro (16-inch, 2021)" },
+        { "MacBookPro17,1",  "MacBook Pro (13-inch, M1, 2020)" },
+        { "MacBookPro16,3",      "MacBook Pro (13-inch, 2020)" },
+        { "MacBookPro16,2",      "MacBook Pro (13-inch, 2020)" },
+        { "MacBookPro16,4",      "MacBook Pro (16-inch, 2019)" },
+        { "MacBookPro16,1",      "MacBook Pro (16-inch, 2019)" },
+        { "MacBookPro15,4",      "MacBook Pro (13-inch, 2019)" },
+        { "MacBookPro15,3",      "MacBook Pro (15-inch, 2019)" },
+        { "MacBookPro15,2", "MacBook Pro (13-inch, 2018/2019)" },
+        { "MacBookPro15,1", "MacBook Pro (15-inch, 2018/2019)" },
+        { "MacBookPro14,3",      "MacBook Pro (15-inch, 2017)" },
+        { "MacBookPro14,2",      "MacBook Pro (13-inch, 2017)" },
+        { "MacBookPro14,1",      "MacBook Pro (13-inch, 2017)" },
+        { "MacBookPro13,3",      "MacBook Pro (15-inch, 2016)" },
+        { "MacBookPro13,2",      "MacBook Pro (13-inch, 2016)" },
+        { "MacBookPro13,1",      "MacBook Pro (13-inch, 2016)" },
+        { "MacBookPro12,1",      "MacBook Pro (13-inch, 2015)" },
+        { "MacBookPro11,4",      "MacBook Pro (15-inch, 2015)" },
+        { "MacBookPro11,5",      "MacBook Pro (15-inch, 2015)" },
+        { "MacBookPro11,2", "MacBook Pro (15-inch, 2013/2014)" },
+        { "MacBookPro11,3", "MacBook Pro (15-inch, 2013/2014)" },
+        { "MacBookPro11,1", "MacBook Pro (13-inch, 2013/2014)" },
+        { "MacBookPro10,2", "MacBook Pro (13-inch, 2012/2013)" },
+        { "MacBookPro10,1", "MacBook Pro (15-inch, 2012/2013)" },
+        {  "MacBookPro9,2",      "MacBook Pro (13-inch, 2012)" },
+        {  "MacBookPro9,1",      "MacBook Pro (15-inch, 2012)" },
+        {  "MacBookPro8,3",      "MacBook Pro (17-inch, 2011)" },
+        {  "MacBookPro8,2",      "MacBook Pro (15-inch, 2011)" },
+        {  "MacBookPro8,1",      "MacBook Pro (13-inch, 2011)" },
+        {  "MacBookPro7,1",      "MacBook Pro (13-inch, 2010)" },
+        {  "MacBookPro6,2",      "MacBook Pro (15-inch, 2010)" },
+        {  "MacBookPro6,1",      "MacBook Pro (17-inch, 2010)" },
+        {  "MacBookPro5,5",      "MacBook Pro (13-inch, 2009)" },
+        {  "MacBookPro5,3",      "MacBook Pro (15-inch, 2009)" },
+        {  "MacBookPro5,2",      "MacBook Pro (17-inch, 2009)" },
+        {  "MacBookPro5,1",      "MacBook Pro (15-inch, 2008)" },
+        {  "MacBookPro4,1",   "MacBook Pro (17/15-inch, 2008)" },
+
+        // MacBook Air
+        { "MacBookAir10,1",           "MacBook Air (M1, 2020)" },
+        {  "MacBookAir9,1",      "MacBook Air (13-inch, 2020)" },
+        {  "MacBookAir8,2",      "MacBook Air (13-inch, 2019)" },
+        {  "MacBookAir8,1",      "MacBook Air (13-inch, 2018)" },
+        {  "MacBookAir7,2", "MacBook Air (13-inch, 2015/2017)" },
+        {  "MacBookAir7,1",      "MacBook Air (11-inch, 2015)" },
+        {  "MacBookAir6,2", "MacBook Air (13-inch, 2013/2014)" },
+        {  "MacBookAir6,1", "MacBook Air (11-inch, 2013/2014)" },
+        {  "MacBookAir5,2",      "MacBook Air (13-inch, 2012)" },
+        {  "MacBookAir5,1",      "MacBook Air (11-inch, 2012)" },
+        {  "MacBookAir4,2",      "MacBook Air (13-inch, 2011)" },
+        {  "MacBookAir4,1",      "MacBook Air (11-inch, 2011)" },
+        {  "MacBookAir3,2",      "MacBook Air (13-inch, 2010)" },
+        {  "MacBookAir3,1",      "MacBook Air (11-inch, 2010)" },
+        {  "MacBookAir2,1",               "MacBook Air (2009)" },
+
+        // Mac mini
+        {     "Macmini9,1",              "Mac mini (M1, 2020)" },
+        {     "Macmini8,1",                  "Mac mini (2018)" },
+        {     "Macmini7,1",                  "Mac mini (2014)" },
+        {     "Macmini6,1",                  "Mac mini (2012)" },
+        {     "Macmini6,2",                  "Mac mini (2012)" },
+        {     "Macmini5,1",                  "Mac mini (2011)" },
+        {     "Macmini5,2",                  "Mac mini (2011)" },
+        {     "Macmini4,1",                  "Mac mini (2010)" },
+        {     "Macmini3,1",                  "Mac mini (2009)" },
+
+        // MacBook
+        {    "MacBook10,1",          "MacBook (12-inch, 2017)" },
+        {     "MacBook9,1",          "MacBook (12-inch, 2016)" },
+        {     "MacBook8,1",          "MacBook (12-inch, 2015)" },
+        {     "MacBook7,1",          "MacBook (13-inch, 2010)" },
+        {     "MacBook6,1",          "MacBook (13-inch, 2009)" },
+        {     "MacBook5,2",          "MacBook (13-inch, 2009)" },
+
+        // Mac Pro
+        {      "MacPro7,1",                   "Mac Pro (2019)" },
+        {      "MacPro6,1",                   "Mac Pro (2013)" },
+        {      "MacPro5,1",            "Mac Pro (2010 - 2012)" },
+        {      "MacPro4,1",                   "Mac Pro (2009)" },
+
+        // Mac (Generic)
+        {        "Mac16,3",             "iMac (24-inch, 2024)" },
         {        "Mac16,2",             "iMac (24-inch, 2024)" },
         {        "Mac16,1",      "MacBook Pro (14-inch, 2024)" },
         {        "Mac16,6",      "MacBook Pro (14-inch, 2024)" },
@@ -356,6 +1547,152 @@ namespace draconis::core::system {
     });
   }
 
+  auto GetFirmwareInfo(CacheManager& cache) -> Result<FirmwareInfo> {
+    return cache.getOrSet<FirmwareInfo>("macos_firmware_info", CachePolicy::neverExpire(), []() -> Result<FirmwareInfo> {
+      FirmwareInfo info {
+        .boardVendor  = String("Apple Inc."),
+        .boardModel   = None,
+        .boardVersion = None,
+        .vendor       = String("Apple Inc."),
+        .version      = None,
+        .releaseDate  = None,
+        .isUefi       = false,
+      };
+
+      const io_service_t platform = IOServiceGetMatchingService(kIOMainPortDefault, IOServiceMatching("IOPlatformExpertDevice"));
+
+      if (platform == IO_OBJECT_NULL)
+        ERR(NotFound, "IOPlatformExpertDevice not found");
+
+      // Intel Macs identify the logic board by board-id (e.g. "Mac-937A206F2EE63C01"); Apple Silicon by target-type (e.g. "J314s").
+      info.boardModel = readRegistryString(platform, CFSTR("board-id")).or_else([&]() -> Option<String> { return readRegistryString(platform, CFSTR("target-type")); });
+
+      IOObjectRelease(platform);
+
+      // Intel Macs boot EFI firmware, described by the rom node. Apple Silicon boots iBoot, whose version is under chosen.
+      if (const io_registry_entry_t rom = IORegistryEntryFromPath(kIOMainPortDefault, "IODeviceTree:/rom"); rom != IO_OBJECT_NULL) {
+        info.isUefi      = true;
+        info.vendor      = readRegistryString(rom, CFSTR("vendor")).value_or("Apple Inc.");
+        info.version     = readRegistryString(rom, CFSTR("version"));
+        info.releaseDate = readRegistryString(rom, CFSTR("release-date"));
+
+        IOObjectRelease(rom);
+      } else if (const io_registry_entry_t chosen = IORegistryEntryFromPath(kIOMainPortDefault, "IODeviceTree:/chosen"); chosen != IO_OBJECT_NULL) {
+        info.version = readRegistryString(chosen, CFSTR("system-firmware-version"));
+
+        IOObjectRelease(chosen);
+      }
+
+      return info;
+    });
+  }
+
+  auto GetMachineId(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_machine_id", CachePolicy::inMemory(), []() -> Result<String> {
+      const io_service_t platform = IOServiceGetMatchingService(kIOMainPortDefault, IOServiceMatching("IOPlatformExpertDevice"));
+
+      if (platform == IO_OBJECT_NULL)
+        ERR(NotFound, "IOPlatformExpertDevice not found");
+
+      Option<String> uuid = readRegistryString(platform, CFSTR(kIOPlatformUUIDKey));
+
+      IOObjectRelease(platform);
+
+      if (!uuid)
+        ERR(NotFound, "IOPlatformExpertDevice has no IOPlatformUUID");
+
+      return *uuid;
+    });
+  }
+
+  auto GetHardwareSerial(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_hardware_serial", CachePolicy::inMemory(), []() -> Result<String> {
+      const io_service_t platform = IOServiceGetMatchingService(kIOMainPortDefault, IOServiceMatching("IOPlatformExpertDevice"));
+
+      if (platform == IO_OBJECT_NULL)
+        ERR(NotFound, "IOPlatformExpertDevice not found");
+
+      Option<String> serial = readRegistryString(platform, CFSTR(kIOPlatformSerialNumberKey));
+
+      IOObjectRelease(platform);
+
+      if (!serial)
+        ERR(NotFound, "IOPlatformExpertDevice has no IOPlatformSerialNumber");
+
+      return *serial;
+    });
+  }
+
+  auto GetVirtualization(CacheManager& cache) -> Result<VirtEnv> {
+    return cache.getOrSet<VirtEnv>("macos_virtualization", CachePolicy::inMemory(), []() -> Result<VirtEnv> {
+      using Hypervisor = VirtEnv::Hypervisor;
+
+      VirtEnv env;
+
+      i32   vmmPresent = 0;
+      usize size       = sizeof(vmmPresent);
+
+      // Set by the kernel on both Intel and Apple Silicon when it was booted by a hypervisor.
+      if (sysctlbyname("kern.hv_vmm_present", &vmmPresent, &size, nullptr, 0) == -1 || vmmPresent == 0)
+        return env;
+
+      env.hypervisor = Hypervisor::Other;
+
+      Array<char, 256> hwModel {};
+      usize            hwModelLen = hwModel.size();
+
+      if (sysctlbyname("hw.model", hwModel.data(), &hwModelLen, nullptr, 0) == -1)
+        return env;
+
+      // Guests get a hypervisor-specific model identifier, e.g. "VirtualMac2,1", "Parallels-ARM" or "VMware7,1".
+      const StringView model(hwModel.data());
+
+      if (model.starts_with("VirtualMac"))
+        env.hypervisor = Hypervisor::Apple;
+      else if (model.contains("Parallels"))
+        env.hypervisor = Hypervisor::Parallels;
+      else if (model.starts_with("VMware"))
+        env.hypervisor = Hypervisor::VMware;
+
+      env.hypervisorVendor = String(model);
+
+      return env;
+    });
+  }
+
+  auto GetSecurityPlatformInfo(CacheManager& /*cache*/) -> Result<SecurityPlatformInfo> {
+    // Macs have no TPM or UEFI Secure Boot; boot security is enforced by the Secure Enclave / T2 instead.
+    ERR(NotSupported, "Secure Boot and TPM status are not available on macOS");
+  }
+
+  auto GetMacStatus(CacheManager& /*cache*/) -> Result<MacStatus> {
+    ERR(NotSupported, "SELinux and AppArmor are not available on macOS");
+  }
+
+  auto GetDomainInfo(CacheManager& /*cache*/) -> Result<DomainInfo> {
+    ERR(NotSupported, "Domain membership is only reported on Windows");
+  }
+
+  auto GetFirewallStatus(CacheManager& /*cache*/) -> Result<FirewallStatus> {
+    // 0 = off, 1 = on for specific services, 2 = block all incoming connections.
+    const CFPropertyListRef state = CFPreferencesCopyAppValue(CFSTR("globalstate"), CFSTR("/Library/Preferences/com.apple.alf"));
+
+    if (state == nullptr)
+      ERR(NotFound, "Application Firewall state not found in com.apple.alf");
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> stateDeleter(state, &CFRelease);
+
+    i32 globalState = 0;
+
+    if (CFGetTypeID(state) != CFNumberGetTypeID() || !CFNumberGetValue(static_cast<CFNumberRef>(state), kCFNumberSInt32Type, &globalState))
+      ERR(ParseError, "Application Firewall globalstate is not a number");
+
+    return FirewallStatus {
+      .backend  = FirewallStatus::Backend::Alf,
+      .profiles = { { .name = "default", .enabled = globalState != 0 } },
+    };
+  }
+
   auto GetCPUModel(CacheManager& cache) -> Result<String> {
     return cache.getOrSet<String>("macos_cpu_model", CachePolicy::neverExpire(), []() -> Result<String> {
       Array<char, 256> cpuModel {};
@@ -368,22 +1705,353 @@ namespace draconis::core::system {
     });
   }
 
-  auto GetCPUCores(CacheManager& cache) -> Result<CPUCores> {
-    return cache.getOrSet<CPUCores>("macos_cpu_cores", CachePolicy::neverExpire(), []() -> Result<CPUCores> {
-      u32   physicalCores = 0;
-      u32   logicalCores  = 0;
-      usize size          = sizeof(u32);
+  auto GetCPUCores(CacheManager& cache) -> Result<CPUCores> {
+    return cache.getOrSet<CPUCores>("macos_cpu_cores", CachePolicy::neverExpire(), []() -> Result<CPUCores> {
+      u32   physicalCores = 0;
+      u32   logicalCores  = 0;
+      usize size          = sizeof(u32);
+
+      if (sysctlbyname("hw.physicalcpu", &physicalCores, &size, nullptr, 0) == -1)
+        ERR_FMT(ResourceExhausted, "sysctlbyname('hw.physicalcpu') failed: {}", std::system_category().message(errno));
+
+      size = sizeof(u32);
+
+      if (sysctlbyname("hw.logicalcpu", &logicalCores, &size, nullptr, 0) == -1)
+        ERR_FMT(ResourceExhausted, "sysctlbyname('hw.logicalcpu') failed: {}", std::system_category().message(errno));
+
+      return CPUCores(physicalCores, logicalCores);
+    });
+  }
+
+  auto GetCPUDetails(CacheManager& cache) -> Result<CPUDetails> {
+    return cache.getOrSet<CPUDetails>("macos_cpu_details", CachePolicy::neverExpire(), []() -> Result<CPUDetails> {
+      const auto readString = [](PCStr name) -> Option<String> {
+        usize size = 0;
+
+        if (sysctlbyname(name, nullptr, &size, nullptr, 0) == -1 || size == 0)
+          return None;
+
+        String value(size, '\0');
+
+        if (sysctlbyname(name, value.data(), &size, nullptr, 0) == -1)
+          return None;
+
+        value.resize(std::strlen(value.c_str()));
+        return value;
+      };
+
+      const auto readU32 = [](PCStr name) -> Option<u32> {
+        u32   value = 0;
+        usize size  = sizeof(value);
+
+        if (sysctlbyname(name, &value, &size, nullptr, 0) == -1)
+          return None;
+
+        return value;
+      };
+
+      CPUDetails details;
+      details.architecture = TRY(os::unix_shared::GetMachineName());
+
+      if (details.architecture == "arm64") {
+        // Match the name Linux and Windows use for the same architecture.
+        details.architecture = "aarch64";
+        details.vendor       = "Apple";
+
+        // Each of these is 1 when the extension is available.
+        // clang-format off
+        constexpr Array<Pair<PCStr, PCStr>, 11> armFeatures = {{
+          {             "hw.optional.neon",   "asimd" },
+          {     "hw.optional.arm.FEAT_AES",     "aes" },
+          {  "hw.optional.arm.FEAT_SHA256",    "sha2" },
+          {      "hw.optional.armv8_crc32",   "crc32" },
+          {     "hw.optional.arm.FEAT_LSE", "atomics" },
+          {    "hw.optional.arm.FEAT_FP16",    "fphp" },
+          { "hw.optional.arm.FEAT_DotProd", "asimddp" },
+          {    "hw.optional.arm.FEAT_I8MM",    "i8mm" },
+          {    "hw.optional.arm.FEAT_BF16",    "bf16" },
+          {     "hw.optional.arm.FEAT_SME",     "sme" },
+          {    "hw.optional.arm.FEAT_SME2",    "sme2" },
+        }};
+        // clang-format on
+
+        for (const auto& [sysctlName, flag] : armFeatures)
+          if (readU32(sysctlName).value_or(0) == 1)
+            details.flags.emplace_back(flag);
+      } else {
+        details.vendor   = readString("machdep.cpu.vendor");
+        details.family   = readU32("machdep.cpu.family");
+        details.model    = readU32("machdep.cpu.model");
+        details.stepping = readU32("machdep.cpu.stepping");
+
+        // These list uppercase CPUID names ("SSE4.2", "AVX1.0"), which are normalized to Linux's spelling.
+        for (const PCStr name : { "machdep.cpu.features", "machdep.cpu.leaf7_features", "machdep.cpu.extfeatures" }) {
+          const String features = readString(name).value_or("");
+
+          for (const auto& part : features | std::views::split(' ')) {
+            String flag(part.begin(), part.end());
+
+            if (flag.empty())
+              continue;
+
+            std::ranges::transform(flag, flag.begin(), [](const unsigned char chr) -> char {
+              return chr == '.' ? '_' : static_cast<char>(std::tolower(chr));
+            });
+
+            details.flags.push_back(flag == "avx1_0" ? "avx" : std::move(flag));
+          }
+        }
+      }
+
+      return details;
+    });
+  }
+
+  auto GetCPUCaches(CacheManager& cache) -> Result<Vec<CPUCacheInfo>> {
+    return cache.getOrSet<Vec<CPUCacheInfo>>("macos_cpu_caches", CachePolicy::neverExpire(), []() -> Result<Vec<CPUCacheInfo>> {
+      using enum CPUCacheInfo::Type;
+
+      const auto readU64 = [](PCStr name) -> Option<u64> {
+        u64   value = 0;
+        usize size  = sizeof(value);
+
+        if (sysctlbyname(name, &value, &size, nullptr, 0) == -1 || value == 0)
+          return None;
+
+        return value;
+      };
+
+      // hw.cacheconfig holds the number of logical CPUs sharing each level, indexed by level (0 being memory).
+      Array<u64, 10> cacheConfig {};
+      usize          cacheConfigSize = sizeof(cacheConfig);
+
+      if (sysctlbyname("hw.cacheconfig", cacheConfig.data(), &cacheConfigSize, nullptr, 0) == -1)
+        cacheConfig.fill(0);
+
+      const u64         logicalCpus = readU64("hw.logicalcpu").value_or(1);
+      const Option<u64> lineSize    = readU64("hw.cachelinesize");
+
+      constexpr Array<std::tuple<u8, CPUCacheInfo::Type, PCStr>, 4> levels {{
+        { 1,        Data, "hw.l1dcachesize" },
+        { 1, Instruction, "hw.l1icachesize" },
+        { 2,     Unified,  "hw.l2cachesize" },
+        { 3,     Unified,  "hw.l3cachesize" },
+      }};
+
+      Vec<CPUCacheInfo> caches;
+
+      for (const auto& [level, type, sysctlName] : levels) {
+        const Option<u64> size = readU64(sysctlName);
+
+        if (!size)
+          continue;
+
+        const u64 sharedCpus = std::max<u64>(cacheConfig.at(level), 1);
+
+        caches.push_back({
+          .type           = type,
+          .level          = level,
+          .sizeBytes      = *size,
+          .lineSizeBytes  = lineSize.transform([](const u64 bytes) -> u32 { return static_cast<u32>(bytes); }),
+          .sharedCpuCount = static_cast<u32>(sharedCpus),
+          .instanceCount  = static_cast<u32>(std::max<u64>(logicalCpus / sharedCpus, 1)),
+        });
+      }
+
+      if (caches.empty())
+        ERR(NotFound, "No CPU cache sizes reported by sysctl");
+
+      return caches;
+    });
+  }
+
+  auto GetNumaTopology(CacheManager& /*cache*/) -> Result<Vec<NumaNode>> {
+    // Both Intel and Apple Silicon Macs have a single memory domain shared by every CPU.
+    u64   totalMem = 0;
+    usize size     = sizeof(totalMem);
+
+    if (sysctlbyname("hw.memsize", &totalMem, &size, nullptr, 0) == -1)
+      ERR_FMT(ResourceExhausted, "sysctlbyname('hw.memsize') failed: {}", std::system_category().message(errno));
+
+    u32 logicalCores = 0;
+    size             = sizeof(logicalCores);
+
+    if (sysctlbyname("hw.logicalcpu", &logicalCores, &size, nullptr, 0) == -1)
+      ERR_FMT(ResourceExhausted, "sysctlbyname('hw.logicalcpu') failed: {}", std::system_category().message(errno));
+
+    NumaNode node { .id = 0, .totalMemoryBytes = totalMem, .freeMemoryBytes = None, .cpus = {} };
+
+    for (u32 cpu = 0; cpu < logicalCores; ++cpu)
+      node.cpus.push_back(cpu);
+
+    return Vec<NumaNode> { std::move(node) };
+  }
+
+  auto GetCPUVulnerabilities(CacheManager& /*cache*/) -> Result<Vec<CPUVulnerability>> {
+    // XNU applies its mitigations unconditionally and has no interface reporting them.
+    ERR(NotSupported, "CPU vulnerability status is not exposed on macOS");
+  }
+
+  auto GetCPUFrequency(CacheManager& /*cache*/) -> Result<CPUFrequency> {
+    // These sysctls only exist on Intel Macs; Apple Silicon does not expose clock speeds.
+    const auto readMhz = [](PCStr name) -> Option<u64> {
+      u64   hertz = 0;
+      usize size  = sizeof(hertz);
+
+      if (sysctlbyname(name, &hertz, &size, nullptr, 0) == -1 || hertz == 0)
+        return None;
+
+      return hertz / 1'000'000;
+    };
+
+    CPUFrequency frequency(readMhz("hw.cpufrequency"), readMhz("hw.cpufrequency"), readMhz("hw.cpufrequency_max"));
+
+    if (!frequency.currentMhz && !frequency.maxMhz)
+      ERR(NotSupported, "CPU frequency is not exposed on this Mac");
+
+    return frequency;
+  }
+
+  auto GetCPUTemperature(CacheManager& /*cache*/) -> Result<f64> {
+    const smc::Connection conn;
+
+    if (!conn)
+      ERR(ApiUnavailable, "Failed to open the AppleSMC service");
+
+    // Intel proximity/die sensors first, then Apple Silicon performance-core sensors
+    // clang-format off
+    constexpr Array<StringView, 6> keys = {
+      "TC0P", "TC0D", "TC0E", "Tp09", "Tp0T", "Tp01",
+    };
+    // clang-format on
+
+    for (const StringView key : keys)
+      if (Option<f64> temp = conn.readTemperature(key))
+        return *temp;
+
+    ERR(NotFound, "None of the known CPU temperature SMC keys are available");
+  }
+
+  auto GetFans(CacheManager& /*cache*/) -> Result<Vec<FanInfo>> {
+    const smc::Connection conn;
+
+    if (!conn)
+      ERR(ApiUnavailable, "Failed to open the AppleSMC service");
+
+    const u32 fanCount = static_cast<u32>(conn.readNumber("FNum").value_or(0.0));
+
+    if (fanCount == 0)
+      ERR(NotFound, "The SMC reports no fans");
+
+    // Speeds are stored per fan index as F<n>Ac (actual) and F<n>Tg (target).
+    const auto readRpm = [&](const u32 index, const StringView suffix) -> Option<u32> {
+      return conn.readNumber(std::format("F{}{}", index, suffix)).transform([](const f64 rpm) -> u32 { return static_cast<u32>(std::max(rpm, 0.0)); });
+    };
+
+    Vec<FanInfo> fans;
+    fans.reserve(fanCount);
+
+    for (u32 i = 0; i < fanCount; ++i)
+      fans.push_back({
+        .label     = std::format("Fan {}", i + 1),
+        .rpm       = readRpm(i, "Ac"),
+        .targetRpm = readRpm(i, "Tg"),
+      });
+
+    return fans;
+  }
+
+  auto GetSensors(CacheManager& /*cache*/) -> Result<Vec<SensorReading>> {
+    using enum SensorReading::Kind;
+
+    const smc::Connection conn;
+
+    if (!conn)
+      ERR(ApiUnavailable, "Failed to open the AppleSMC service");
+
+    const u32 keyCount = static_cast<u32>(conn.readNumber("#KEY").value_or(0.0));
+
+    if (keyCount == 0)
+      ERR(ApiUnavailable, "The SMC reports no keys");
+
+    Vec<SensorReading> sensors;
+
+    for (u32 i = 0; i < keyCount; ++i) {
+      const Option<String> key = conn.keyAtIndex(i);
+
+      if (!key)
+        continue;
+
+      // The first letter of a key names the quantity. Most F keys are fan limits and modes,
+      // so only the F<n>Ac (actual speed) keys count as sensors.
+      Option<SensorReading::Kind> kind;
+
+      switch (key->front()) {
+        case 'T': kind = Temperature; break;
+        case 'V': kind = Voltage; break;
+        case 'I': kind = Current; break;
+        case 'P': kind = Power; break;
+        case 'F':
+          if (key->ends_with("Ac"))
+            kind = Fan;
+          break;
+        default: break;
+      }
+
+      if (!kind)
+        continue;
+
+      const Option<f64> value = *kind == Temperature ? conn.readTemperature(*key) : conn.readNumber(*key);
+
+      if (!value)
+        continue;
+
+      sensors.push_back({ .kind = *kind, .chip = "AppleSMC", .label = *key, .value = *value });
+    }
+
+    if (sensors.empty())
+      ERR(NotFound, "The SMC exposes no sensor keys");
+
+    return sensors;
+  }
+
+  auto GetCPUPowerWatts(CacheManager& /*cache*/) -> Result<f64> {
+    // Intel Macs keep a running package power figure in the SMC.
+    if (const smc::Connection conn; conn)
+      if (const Option<f64> watts = conn.readNumber("PCPC"); watts && *watts > 0.0)
+        return *watts;
+
+    // Apple Silicon only has energy counters, so sample them over a short interval.
+    if (const Option<f64> watts = ioreport::SampleCPUPower(std::chrono::milliseconds(100)))
+      return *watts;
+
+    ERR(NotFound, "Neither the SMC nor IOReport expose CPU energy on this Mac");
+  }
+
+  auto GetCPUTimesPerCore() -> Result<Vec<CPUTimes>> {
+    natural_t              cpuCount  = 0;
+    processor_info_array_t cpuInfo   = nullptr;
+    mach_msg_type_number_t infoCount = 0;
+
+    if (host_processor_info(mach_host_self(), PROCESSOR_CPU_LOAD_INFO, &cpuCount, &cpuInfo, &infoCount) != KERN_SUCCESS)
+      ERR(ApiUnavailable, "host_processor_info(PROCESSOR_CPU_LOAD_INFO) failed");
 
-      if (sysctlbyname("hw.physicalcpu", &physicalCores, &size, nullptr, 0) == -1)
-        ERR_FMT(ResourceExhausted, "sysctlbyname('hw.physicalcpu') failed: {}", std::system_category().message(errno));
+    Vec<CPUTimes> times;
+    times.reserve(cpuCount);
 
-      size = sizeof(u32);
+    for (natural_t i = 0; i < cpuCount; ++i) {
+      const u64 user   = cpuInfo[(CPU_STATE_MAX * i) + CPU_STATE_USER];
+      const u64 system = cpuInfo[(CPU_STATE_MAX * i) + CPU_STATE_SYSTEM];
+      const u64 nice   = cpuInfo[(CPU_STATE_MAX * i) + CPU_STATE_NICE];
+      const u64 idle   = cpuInfo[(CPU_STATE_MAX * i) + CPU_STATE_IDLE];
 
-      if (sysctlbyname("hw.logicalcpu", &logicalCores, &size, nullptr, 0) == -1)
-        ERR_FMT(ResourceExhausted, "sysctlbyname('hw.logicalcpu') failed: {}", std::system_category().message(errno));
+      times.emplace_back(idle, user + system + nice + idle);
+    }
 
-      return CPUCores(physicalCores, logicalCores);
-    });
+    // The info array is allocated in our address space by the kernel and must be released manually.
+    vm_deallocate(mach_task_self(), reinterpret_cast<vm_address_t>(cpuInfo), static_cast<vm_size_t>(infoCount) * sizeof(integer_t)); // NOLINT(*-pro-type-reinterpret-cast)
+
+    return times;
   }
 
   auto GetGPUModel(CacheManager& cache) -> Result<String> {
@@ -397,6 +2065,34 @@ namespace draconis::core::system {
     });
   }
 
+  auto GetGPUs(CacheManager& /*cache*/) -> Result<Vec<GPUInfo>> {
+    Vec<GPUInfo> gpus = TRY(macOS::GetGPUs());
+
+    // The SMC only exposes a single GPU temperature, which belongs to the discrete GPU
+    // if there is one. Power draw isn't exposed in a consistent format, so it's left unset.
+    if (const smc::Connection conn; conn) {
+      // Intel proximity/die sensors first, then Apple Silicon GPU cluster sensors
+      // clang-format off
+      constexpr Array<StringView, 4> keys = {
+        "TG0D", "TG0P", "Tg05", "Tg0D",
+      };
+      // clang-format on
+
+      auto target = std::ranges::find_if(gpus, [](const GPUInfo& gpu) -> bool { return !gpu.isIntegrated; });
+
+      if (target == gpus.end())
+        target = gpus.begin();
+
+      for (const StringView key : keys)
+        if (Option<f64> temp = conn.readTemperature(key)) {
+          target->temperatureCelsius = temp;
+          break;
+        }
+    }
+
+    return gpus;
+  }
+
   auto GetDiskUsage(CacheManager& /*cache*/) -> Result<ResourceUsage> {
     return os::unix_shared::GetRootDiskUsage();
   }
@@ -432,6 +2128,10 @@ namespace draconis::core::system {
         .driveType     = String((fs.f_flags & MNT_LOCAL) != 0 ? "Local" : "Network"),
         .totalBytes    = totalBytes,
         .usedBytes     = totalBytes - freeBytes,
+        .totalInodes   = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files)) : None,
+        .usedInodes    = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files) - static_cast<u64>(fs.f_ffree)) : None,
+        .mountOptions  = mountOptionsFromFlags(fs.f_flags),
+        .isReadOnly    = (fs.f_flags & MNT_RDONLY) != 0,
         .isSystemDrive = (std::strcmp(fs.f_mntonname, "/") == 0),
       });
     }
@@ -439,92 +2139,498 @@ namespace draconis::core::system {
     return disks;
   }
 
-  auto GetSystemDisk(CacheManager& /*cache*/) -> Result<DiskInfo> {
-    struct statfs fs;
+  auto GetSystemDisk(CacheManager& /*cache*/) -> Result<DiskInfo> {
+    struct statfs fs;
+
+    if (statfs("/", &fs) == -1)
+      ERR_FMT(IoError, "statfs('/') failed: {} (errno {})", std::strerror(errno), errno);
+
+    const u64 totalBytes = static_cast<u64>(fs.f_blocks) * static_cast<u64>(fs.f_bsize);
+    const u64 freeBytes  = static_cast<u64>(fs.f_bfree) * static_cast<u64>(fs.f_bsize);
+
+    return DiskInfo {
+      .name          = String(fs.f_mntfromname),
+      .mountPoint    = String("/"),
+      .filesystem    = String(fs.f_fstypename),
+      .driveType     = String((fs.f_flags & MNT_LOCAL) != 0 ? "Local" : "Network"),
+      .totalBytes    = totalBytes,
+      .usedBytes     = totalBytes - freeBytes,
+      .totalInodes   = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files)) : None,
+      .usedInodes    = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files) - static_cast<u64>(fs.f_ffree)) : None,
+      .mountOptions  = mountOptionsFromFlags(fs.f_flags),
+      .isReadOnly    = (fs.f_flags & MNT_RDONLY) != 0,
+      .isSystemDrive = true,
+    };
+  }
+
+  auto GetDiskByPath(const String& path, CacheManager& /*cache*/) -> Result<DiskInfo> {
+    if (path.empty())
+      ERR(InvalidArgument, "Path cannot be empty");
+
+    struct statfs fs;
+
+    if (statfs(path.c_str(), &fs) == -1)
+      ERR_FMT(IoError, "statfs('{}') failed: {} (errno {})", path, std::strerror(errno), errno);
+
+    const u64 totalBytes = static_cast<u64>(fs.f_blocks) * static_cast<u64>(fs.f_bsize);
+    const u64 freeBytes  = static_cast<u64>(fs.f_bfree) * static_cast<u64>(fs.f_bsize);
+
+    return DiskInfo {
+      .name          = String(fs.f_mntfromname),
+      .mountPoint    = String(fs.f_mntonname),
+      .filesystem    = String(fs.f_fstypename),
+      .driveType     = String((fs.f_flags & MNT_LOCAL) != 0 ? "Local" : "Network"),
+      .totalBytes    = totalBytes,
+      .usedBytes     = totalBytes - freeBytes,
+      .totalInodes   = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files)) : None,
+      .usedInodes    = fs.f_files != 0 ? Option<u64>(static_cast<u64>(fs.f_files) - static_cast<u64>(fs.f_ffree)) : None,
+      .mountOptions  = mountOptionsFromFlags(fs.f_flags),
+      .isReadOnly    = (fs.f_flags & MNT_RDONLY) != 0,
+      .isSystemDrive = (std::strcmp(fs.f_mntonname, "/") == 0),
+    };
+  }
+
+  auto GetDiskHealth(const StringView /*device*/) -> Result<DiskHealth> {
+    // SMART passthrough on macOS requires a third-party kernel extension for most drives.
+    ERR(NotSupported, "SMART health data is not available on macOS");
+  }
+
+  auto GetDiskTemperatures(CacheManager& /*cache*/) -> Result<Vec<DiskTemperature>> {
+    ERR(NotSupported, "Drive temperatures are not available on macOS");
+  }
+
+  auto GetDiskIOStats(CacheManager& /*cache*/) -> Result<Vec<DiskIOStats>> {
+    const auto statistic = [](const CFDictionaryRef statistics, const CFStringRef key) -> u64 {
+      const auto* value = static_cast<CFNumberRef>(CFDictionaryGetValue(statistics, key));
+      i64         count = 0;
+
+      if (value && CFGetTypeID(value) == CFNumberGetTypeID())
+        CFNumberGetValue(value, kCFNumberSInt64Type, &count);
+
+      return static_cast<u64>(std::max<i64>(count, 0));
+    };
+
+    io_iterator_t iterator = 0;
+
+    if (IOServiceGetMatchingServices(kIOMainPortDefault, IOServiceMatching(kIOBlockStorageDriverClass), &iterator) != kIOReturnSuccess)
+      ERR(ApiUnavailable, "IOServiceGetMatchingServices(IOBlockStorageDriver) failed");
+
+    Vec<DiskIOStats> stats;
+
+    while (const io_object_t driver = IOIteratorNext(iterator)) {
+      // The driver's child is the whole-disk IOMedia, whose BSD name (disk0) identifies the drive.
+      io_registry_entry_t media = IO_OBJECT_NULL;
+      Option<String>      name;
+
+      if (IORegistryEntryGetChildEntry(driver, kIOServicePlane, &media) == KERN_SUCCESS) {
+        name = readRegistryString(media, CFSTR(kIOBSDNameKey));
+        IOObjectRelease(media);
+      }
+
+      const CFTypeRef statistics = IORegistryEntryCreateCFProperty(driver, CFSTR(kIOBlockStorageDriverStatisticsKey), kCFAllocatorDefault, 0);
+
+      if (statistics) {
+        if (name && CFGetTypeID(statistics) == CFDictionaryGetTypeID()) {
+          const auto dict = static_cast<CFDictionaryRef>(statistics);
+
+          stats.push_back({
+            .device     = std::move(*name),
+            .readOps    = statistic(dict, CFSTR(kIOBlockStorageDriverStatisticsReadsKey)),
+            .writeOps   = statistic(dict, CFSTR(kIOBlockStorageDriverStatisticsWritesKey)),
+            .readBytes  = statistic(dict, CFSTR(kIOBlockStorageDriverStatisticsBytesReadKey)),
+            .writeBytes = statistic(dict, CFSTR(kIOBlockStorageDriverStatisticsBytesWrittenKey)),
+          });
+        }
+
+        CFRelease(statistics);
+      }
+
+      IOObjectRelease(driver);
+    }
+
+    IOObjectRelease(iterator);
+
+    if (stats.empty())
+      ERR(NotFound, "No IOBlockStorageDriver reported I/O statistics");
+
+    return stats;
+  }
+
+  auto GetNetworkMounts(CacheManager& /*cache*/) -> Result<Vec<NetworkMount>> {
+    struct statfs* mounts = nullptr;
+
+    // MNT_NOWAIT returns the cached statistics instead of asking each server, so a dead share can't block this.
+    const i32 count = getmntinfo(&mounts, MNT_NOWAIT);
+
+    if (count == 0 || mounts == nullptr)
+      ERR(IoError, "getmntinfo() failed: could not enumerate mounted filesystems");
+
+    Vec<NetworkMount> networkMounts;
+
+    for (i32 i = 0; i < count; ++i) {
+      const struct statfs& fs = mounts[i]; // NOLINT(cppcoreguidelines-pro-bounds-pointer-arithmetic)
+
+      if (Option<NetworkMount> mount = os::unix_shared::ParseNetworkMount(fs.f_mntfromname, fs.f_fstypename, fs.f_mntonname))
+        networkMounts.push_back(std::move(*mount));
+    }
+
+    return networkMounts;
+  }
+
+  auto GetShell(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_shell", CachePolicy::tempDirectory(), []() -> Result<String> {
+      if (const Result<String> shellPath = draconis::utils::env::GetEnv("SHELL")) {
+        // clang-format off
+        constexpr Array<Pair<StringView, StringView>, 8> shellMap {{
+            { "bash", "Bash"      },
+            { "zsh",  "Zsh"       },
+            { "ksh",  "KornShell" },
+            { "fish", "Fish"      },
+            { "tcsh", "TCsh"      },
+            { "csh",  "Csh"       },
+            { "sh",   "Sh"        },
+            { "nu",   "NuShell"   },
+        }};
+        // clang-format on
+
+        for (const auto& [exe, name] : shellMap)
+          if (shellPath->ends_with(exe))
+            return String(name);
+
+        return *shellPath;
+      }
+
+      ERR(ConfigurationError, "Could not find SHELL environment variable (SHELL not set in environment)");
+    });
+  }
+
+  auto GetTerminal(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_terminal", CachePolicy::inMemory(), []() -> Result<String> {
+      if (Option<String> terminal = os::unix_shared::GetTerminalFromEnv())
+        return *terminal;
+
+      return os::unix_shared::FindTerminalInProcessTree(getppid(), [](const pid_t pid) -> Option<Pair<String, pid_t>> {
+        proc_bsdshortinfo shortInfo {};
+
+        if (proc_pidinfo(pid, PROC_PIDT_SHORTBSDINFO, 0, &shortInfo, sizeof(shortInfo)) != sizeof(shortInfo))
+          return None;
+
+        return Pair<String, pid_t>(String(shortInfo.pbsi_comm), static_cast<pid_t>(shortInfo.pbsi_ppid));
+      });
+    });
+  }
+
+  auto GetTerminalFont(CacheManager& cache) -> Result<String> {
+    return cache.getOrSet<String>("macos_terminal_font", CachePolicy::inMemory(), [&cache]() -> Result<String> {
+      const String terminal = TRY(GetTerminal(cache));
+
+      if (terminal != "iTerm2")
+        return os::unix_shared::GetTerminalFontFromConfig(terminal);
+
+      // iTerm2 stores its profiles in "New Bookmarks"; the first one is the default profile,
+      // and its font is stored as "<PostScript name> <size>".
+      const CFPropertyListRef bookmarks = CFPreferencesCopyAppValue(CFSTR("New Bookmarks"), CFSTR("com.googlecode.iterm2"));
+
+      if (!bookmarks)
+        ERR(NotFound, "iTerm2 preferences do not contain any profiles");
+
+      const UniquePointer<const Unit, decltype(&CFRelease)> bookmarksDeleter(bookmarks, &CFRelease);
+
+      if (CFGetTypeID(bookmarks) != CFArrayGetTypeID() || CFArrayGetCount(static_cast<CFArrayRef>(bookmarks)) == 0)
+        ERR(ParseError, "iTerm2 profile list has an unexpected format");
+
+      const auto* profile = static_cast<CFDictionaryRef>(CFArrayGetValueAtIndex(static_cast<CFArrayRef>(bookmarks), 0));
+      const auto* font    = static_cast<CFStringRef>(CFDictionaryGetValue(profile, CFSTR("Normal Font")));
+
+      if (!font || CFGetTypeID(font) != CFStringGetTypeID())
+        ERR(NotFound, "iTerm2 default profile does not set a font");
+
+      Array<char, 256> buffer {};
+
+      if (!CFStringGetCString(font, buffer.data(), buffer.size(), kCFStringEncodingUTF8))
+        ERR(ParseError, "Failed to convert the iTerm2 font name to a C string");
+
+      return String(buffer.data());
+    });
+  }
+
+  auto GetUptime() -> Result<std::chrono::seconds> {
+    using namespace std::chrono;
+
+    Array<i32, 2> mib = { CTL_KERN, KERN_BOOTTIME };
+
+    struct timeval boottime;
+    usize          len = sizeof(boottime);
+
+    if (sysctl(mib.data(), mib.size(), &boottime, &len, nullptr, 0) == -1)
+      ERR(ResourceExhausted, "sysctl(CTL_KERN, KERN_BOOTTIME) failed: system boot time unavailable or resource exhausted");
+
+    const system_clock::time_point bootTimepoint = system_clock::from_time_t(boottime.tv_sec);
+
+    const system_clock::time_point now = system_clock::now();
+
+    return duration_cast<seconds>(now - bootTimepoint);
+  }
+
+  auto GetBootTime() -> Result<std::chrono::system_clock::time_point> {
+    Array<i32, 2> mib = { CTL_KERN, KERN_BOOTTIME };
+
+    struct timeval boottime;
+    usize          len = sizeof(boottime);
+
+    if (sysctl(mib.data(), mib.size(), &boottime, &len, nullptr, 0) == -1)
+      ERR(ResourceExhausted, "sysctl(CTL_KERN, KERN_BOOTTIME) failed: system boot time unavailable or resource exhausted");
+
+    return std::chrono::system_clock::from_time_t(boottime.tv_sec);
+  }
+
+  auto GetLoadAverage() -> Result<LoadAverage> {
+    return os::unix_shared::GetLoadAverage();
+  }
+
+  auto GetProcesses(CacheManager& /*cache*/) -> Result<Vec<ProcessInfo>> {
+    // Passing a null buffer returns the number of PIDs currently in use; pad it
+    // in case processes spawn between the two calls.
+    const i32 estimate = proc_listallpids(nullptr, 0);
+
+    if (estimate <= 0)
+      ERR(ApiUnavailable, "proc_listallpids failed to count processes");
+
+    Vec<pid_t> pids(static_cast<usize>(estimate) + 64);
+
+    const i32 count = proc_listallpids(pids.data(), static_cast<i32>(pids.size() * sizeof(pid_t)));
+
+    if (count <= 0)
+      ERR(ApiUnavailable, "proc_listallpids failed to list processes");
+
+    pids.resize(static_cast<usize>(count));
+
+    UnorderedMap<uid_t, Option<String>> userNames;
+    Vec<ProcessInfo>                    processes;
+    processes.reserve(pids.size());
+
+    // Processes can exit between listing and querying them, so skip failures.
+    for (const pid_t pid : pids)
+      if (Result<ProcessInfo> info = readProcessInfo(pid, userNames))
+        processes.push_back(std::move(*info));
+
+    return processes;
+  }
+
+  auto GetProcess(const u32 pid) -> Result<ProcessInfo> {
+    UnorderedMap<uid_t, Option<String>> userNames;
+
+    return readProcessInfo(static_cast<pid_t>(pid), userNames);
+  }
+
+  auto FindProcesses(const StringView name) -> Result<Vec<ProcessInfo>> {
+    const i32 estimate = proc_listallpids(nullptr, 0);
+
+    if (estimate <= 0)
+      ERR(ApiUnavailable, "proc_listallpids failed to count processes");
+
+    Vec<pid_t> pids(static_cast<usize>(estimate) + 64);
+
+    const i32 count = proc_listallpids(pids.data(), static_cast<i32>(pids.size() * sizeof(pid_t)));
+
+    if (count <= 0)
+      ERR(ApiUnavailable, "proc_listallpids failed to list processes");
+
+    pids.resize(static_cast<usize>(count));
+
+    UnorderedMap<uid_t, Option<String>> userNames;
+    Vec<ProcessInfo>                    processes;
+
+    for (const pid_t pid : pids) {
+      // proc_name is a single cheap syscall, so only matching processes get the full lookup.
+      Array<char, 2 * MAXCOMLEN + 1> nameBuf {};
+
+      if (proc_name(pid, nameBuf.data(), nameBuf.size()) <= 0 || StringView(nameBuf.data()) != name)
+        continue;
+
+      if (Result<ProcessInfo> info = readProcessInfo(pid, userNames))
+        processes.push_back(std::move(*info));
+    }
+
+    return processes;
+  }
+
+  auto GetSessions() -> Result<Vec<SessionInfo>> {
+    return os::unix_shared::GetSessions();
+  }
+
+  auto GetInitSystem(CacheManager& /*cache*/) -> Result<InitSystem> {
+    return InitSystem { .kind = InitSystem::Kind::Launchd, .name = "launchd" };
+  }
+
+  auto GetServiceStatus(const StringView name) -> Result<ServiceInfo> {
+    // Daemons live in the system domain, agents in the domain of the logged-in user.
+    for (const CFStringRef domain : { kSMDomainSystemLaunchd, kSMDomainUserLaunchd })
+      if (Option<ServiceInfo> service = launchd::CopyJob(domain, name))
+        return std::move(*service);
+
+    ERR_FMT(NotFound, "No launchd job labeled '{}'", name);
+  }
+
+  auto ListServices() -> Result<Vec<ServiceInfo>> {
+    Vec<ServiceInfo> services;
+
+    const bool gotSystemJobs = launchd::CopyAllJobs(kSMDomainSystemLaunchd, services);
+    const bool gotUserJobs   = launchd::CopyAllJobs(kSMDomainUserLaunchd, services);
+
+    if (!gotSystemJobs && !gotUserJobs)
+      ERR(ApiUnavailable, "SMCopyAllJobDictionaries returned no jobs");
+
+    return services;
+  }
+
+  auto GetContainers(CacheManager& /*cache*/) -> Result<Vec<ContainerInfo>> {
+    using draconis::utils::env::GetEnv;
+
+    // Containers run inside Docker Desktop's VM, so there are no cgroups to read usage from here.
+    Vec<String> sockets;
+
+    if (const Result<String> dockerHost = GetEnv("DOCKER_HOST"); dockerHost && dockerHost->starts_with("unix://"))
+      sockets.push_back(dockerHost->substr(std::char_traits<char>::length("unix://")));
+
+    // Newer Docker Desktop releases only create the per-user socket unless the admin setting is enabled.
+    if (const Result<String> home = GetEnv("HOME"))
+      sockets.push_back(std::format("{}/.docker/run/docker.sock", *home));
+
+    sockets.emplace_back("/var/run/docker.sock");
+
+    bool denied = false;
+
+    for (const String& socketPath : sockets) {
+      Result<Vec<ContainerInfo>> containers = os::containers::ListFromSocket(socketPath, ContainerInfo::Runtime::Docker);
+
+      if (containers)
+        return containers;
+
+      denied |= containers.error().code == PermissionDenied;
+    }
+
+    if (denied)
+      ERR(PermissionDenied, "Permission denied connecting to the Docker socket");
+
+    ERR(NotFound, "No container runtime is running");
+  }
+
+  auto GetCurrentUser() -> Result<UserInfo> {
+    return os::unix_shared::GetCurrentUser();
+  }
+
+  auto GetLocale() -> Result<String> {
+    if (Option<String> locale = os::unix_shared::GetLocaleFromEnv())
+      return std::move(*locale);
+
+    // GUI apps usually don't inherit LANG, so fall back to the user's region settings.
+    const CFLocaleRef locale = CFLocaleCopyCurrent();
+
+    if (!locale)
+      ERR(ApiUnavailable, "CFLocaleCopyCurrent returned null");
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> localeDeleter(locale, &CFRelease);
+
+    Array<char, 64> buffer {};
+
+    if (!CFStringGetCString(CFLocaleGetIdentifier(locale), buffer.data(), buffer.size(), kCFStringEncodingUTF8))
+      ERR(ParseError, "Failed to convert the locale identifier to a C string");
+
+    return String(buffer.data());
+  }
+
+  auto GetTimezone() -> Result<TimezoneInfo> {
+    return os::unix_shared::GetTimezone();
+  }
+
+  auto GetHostname() -> Result<String> {
+    return os::unix_shared::GetHostname();
+  }
+
+  auto GetFQDN() -> Result<String> {
+    return os::unix_shared::GetFQDN();
+  }
+
+  auto GetKeyboardLayouts() -> Result<Vec<KeyboardLayout>> {
+    // Reads a string field of an input source entry.
+    const auto sourceString = [](const CFDictionaryRef source, const CFStringRef key) -> Option<String> {
+      const CFTypeRef value = CFDictionaryGetValue(source, key);
+
+      if (value == nullptr || CFGetTypeID(value) != CFStringGetTypeID())
+        return None;
+
+      Array<char, 256> buffer {};
 
-    if (statfs("/", &fs) == -1)
-      ERR_FMT(IoError, "statfs('/') failed: {} (errno {})", std::strerror(errno), errno);
+      if (!CFStringGetCString(static_cast<CFStringRef>(value), buffer.data(), buffer.size(), kCFStringEncodingUTF8) || buffer[0] == '\0')
+        return None;
 
-    const u64 totalBytes = static_cast<u64>(fs.f_blocks) * static_cast<u64>(fs.f_bsize);
-    const u64 freeBytes  = static_cast<u64>(fs.f_bfree) * static_cast<u64>(fs.f_bsize);
+      return String(buffer.data());
+    };
 
-    return DiskInfo {
-      .name          = String(fs.f_mntfromname),
-      .mountPoint    = String("/"),
-      .filesystem    = String(fs.f_fstypename),
-      .driveType     = String((fs.f_flags & MNT_LOCAL) != 0 ? "Local" : "Network"),
-      .totalBytes    = totalBytes,
-      .usedBytes     = totalBytes - freeBytes,
-      .isSystemDrive = true,
+    // Keyboard layouts are stored by name (e.g. "U.S."), input methods by mode (e.g. "com.apple.inputmethod.Japanese").
+    const auto sourceName = [&sourceString](const CFDictionaryRef source) -> Option<String> {
+      if (Option<String> name = sourceString(source, CFSTR("KeyboardLayout Name")))
+        return name;
+
+      return sourceString(source, CFSTR("Input Mode"));
     };
-  }
 
-  auto GetDiskByPath(const String& path, CacheManager& /*cache*/) -> Result<DiskInfo> {
-    if (path.empty())
-      ERR(InvalidArgument, "Path cannot be empty");
+    // Iterates the dictionaries of a HIToolbox preference holding an array of input sources.
+    const auto forEachSource = [](const CFPropertyListRef sources, const auto& callback) -> void {
+      if (CFGetTypeID(sources) != CFArrayGetTypeID())
+        return;
 
-    struct statfs fs;
+      for (CFIndex idx = 0; idx < CFArrayGetCount(static_cast<CFArrayRef>(sources)); ++idx)
+        if (const CFTypeRef source = CFArrayGetValueAtIndex(static_cast<CFArrayRef>(sources), idx); CFGetTypeID(source) == CFDictionaryGetTypeID())
+          callback(static_cast<CFDictionaryRef>(source));
+    };
 
-    if (statfs(path.c_str(), &fs) == -1)
-      ERR_FMT(IoError, "statfs('{}') failed: {} (errno {})", path, std::strerror(errno), errno);
+    const CFPropertyListRef enabled = CFPreferencesCopyAppValue(CFSTR("AppleEnabledInputSources"), CFSTR("com.apple.HIToolbox"));
 
-    const u64 totalBytes = static_cast<u64>(fs.f_blocks) * static_cast<u64>(fs.f_bsize);
-    const u64 freeBytes  = static_cast<u64>(fs.f_bfree) * static_cast<u64>(fs.f_bsize);
+    if (!enabled)
+      ERR(NotFound, "No input sources are enabled");
 
-    return DiskInfo {
-      .name          = String(fs.f_mntfromname),
-      .mountPoint    = String(fs.f_mntonname),
-      .filesystem    = String(fs.f_fstypename),
-      .driveType     = String((fs.f_flags & MNT_LOCAL) != 0 ? "Local" : "Network"),
-      .totalBytes    = totalBytes,
-      .usedBytes     = totalBytes - freeBytes,
-      .isSystemDrive = (std::strcmp(fs.f_mntonname, "/") == 0),
-    };
-  }
+    const UniquePointer<const Unit, decltype(&CFRelease)> enabledDeleter(enabled, &CFRelease);
 
-  auto GetShell(CacheManager& cache) -> Result<String> {
-    return cache.getOrSet<String>("macos_shell", CachePolicy::tempDirectory(), []() -> Result<String> {
-      if (const Result<String> shellPath = draconis::utils::env::GetEnv("SHELL")) {
-        // clang-format off
-        constexpr Array<Pair<StringView, StringView>, 8> shellMap {{
-            { "bash", "Bash"      },
-            { "zsh",  "Zsh"       },
-            { "ksh",  "KornShell" },
-            { "fish", "Fish"      },
-            { "tcsh", "TCsh"      },
-            { "csh",  "Csh"       },
-            { "sh",   "Sh"        },
-            { "nu",   "NuShell"   },
-        }};
-        // clang-format on
+    // The selected sources are the current keyboard layout, plus the current input method if one is in use.
+    Vec<String> selected;
 
-        for (const auto& [exe, name] : shellMap)
-          if (shellPath->ends_with(exe))
-            return String(name);
+    if (const CFPropertyListRef selectedSources = CFPreferencesCopyAppValue(CFSTR("AppleSelectedInputSources"), CFSTR("com.apple.HIToolbox"))) {
+      const UniquePointer<const Unit, decltype(&CFRelease)> selectedDeleter(selectedSources, &CFRelease);
 
-        return *shellPath;
-      }
+      forEachSource(selectedSources, [&](const CFDictionaryRef source) -> void {
+        if (Option<String> name = sourceName(source))
+          selected.push_back(*std::move(name));
+      });
+    }
 
-      ERR(ConfigurationError, "Could not find SHELL environment variable (SHELL not set in environment)");
-    });
-  }
+    Vec<KeyboardLayout> layouts;
 
-  auto GetUptime() -> Result<std::chrono::seconds> {
-    using namespace std::chrono;
+    forEachSource(enabled, [&](const CFDictionaryRef source) -> void {
+      // Skip the emoji picker, palettes, and the bundle entries of input methods (their modes are listed separately).
+      if (const Option<String> kind = sourceString(source, CFSTR("InputSourceKind")); kind != "Keyboard Layout" && kind != "Input Mode")
+        return;
 
-    Array<i32, 2> mib = { CTL_KERN, KERN_BOOTTIME };
+      Option<String> name = sourceName(source);
 
-    struct timeval boottime;
-    usize          len = sizeof(boottime);
+      if (!name)
+        return;
 
-    if (sysctl(mib.data(), mib.size(), &boottime, &len, nullptr, 0) == -1)
-      ERR(ResourceExhausted, "sysctl(CTL_KERN, KERN_BOOTTIME) failed: system boot time unavailable or resource exhausted");
+      const bool active = std::ranges::find(selected, *name) != selected.end();
 
-    const system_clock::time_point bootTimepoint = system_clock::from_time_t(boottime.tv_sec);
+      layouts.push_back({
+        .name        = *std::move(name),
+        .variant     = None,
+        .description = None,
+        .active      = active,
+      });
+    });
 
-    const system_clock::time_point now = system_clock::now();
+    if (layouts.empty())
+      ERR(NotFound, "No keyboard input sources are enabled");
 
-    return duration_cast<seconds>(now - bootTimepoint);
+    return layouts;
   }
 
   auto GetPrimaryOutput(CacheManager& cache) -> Result<DisplayInfo> {
@@ -582,18 +2688,52 @@ namespace draconis::core::system {
           }
         }
 
-        displays.emplace_back(
+        DisplayInfo& display = displays.emplace_back(
           displayID,
           DisplayInfo::Resolution { .width = width, .height = height },
           refreshRate,
           isPrimary
         );
+
+        applyDisplayDetails(display, displayID);
       }
 
       return displays;
     });
   }
 
+  auto GetBrightness(CacheManager& /*cache*/) -> Result<Vec<BacklightInfo>> {
+    using GetBrightnessFn = i32 (*)(CGDirectDisplayID, float*);
+
+    // DisplayServices is a private framework, so it's resolved at runtime instead of being linked.
+    static RawPointer            DisplayServices      = dlopen("/System/Library/PrivateFrameworks/DisplayServices.framework/DisplayServices", RTLD_LAZY);
+    static const GetBrightnessFn GetDisplayBrightness = DisplayServices ? reinterpret_cast<GetBrightnessFn>(dlsym(DisplayServices, "DisplayServicesGetBrightness")) : nullptr;
+
+    if (!GetDisplayBrightness)
+      ERR(ApiUnavailable, "Failed to load DisplayServicesGetBrightness");
+
+    Array<CGDirectDisplayID, 16> displayIDs {};
+    u32                          displayCount = 0;
+
+    if (CGGetOnlineDisplayList(displayIDs.size(), displayIDs.data(), &displayCount) != kCGErrorSuccess)
+      ERR(ApiUnavailable, "CGGetOnlineDisplayList failed");
+
+    Vec<BacklightInfo> backlights;
+
+    // Third-party monitors fail here; DisplayServices only drives built-in and Apple displays.
+    for (const CGDirectDisplayID displayID : Span<const CGDirectDisplayID>(displayIDs.data(), displayCount))
+      if (float brightness = 0.0F; GetDisplayBrightness(displayID, &brightness) == 0)
+        backlights.push_back({
+          .device  = CGDisplayIsBuiltin(displayID) ? String("Built-in Display") : std::format("Display {}", displayID),
+          .percent = std::clamp(static_cast<f64>(brightness) * 100.0, 0.0, 100.0),
+        });
+
+    if (backlights.empty())
+      ERR(NotFound, "No display exposes its brightness");
+
+    return backlights;
+  }
+
   auto GetPrimaryNetworkInterface(CacheManager& cache) -> Result<NetworkInterface> {
     return cache.getOrSet<NetworkInterface>("macos_primary_network_interface", CachePolicy::tempDirectory(), []() -> Result<NetworkInterface> {
       // NOLINTBEGIN(cppcoreguidelines-pro-type-reinterpret-cast) – unavoidable when talking to C APIs.
@@ -670,6 +2810,8 @@ namespace draconis::core::system {
             break;
           }
           case AF_LINK: {
+            fillLinkDetails(primaryInterface, ifa);
+
             auto* sdl = reinterpret_cast<sockaddr_dl*>(ifa->ifa_addr);
             if (sdl && sdl->sdl_alen == 6) {
               const auto*          macPtr = reinterpret_cast<const u8*>(LLADDR(sdl));
@@ -699,6 +2841,62 @@ namespace draconis::core::system {
     });
   }
 
+  auto GetNetworkStats(CacheManager& /*cache*/) -> Result<Vec<InterfaceStats>> {
+    // NOLINTBEGIN(cppcoreguidelines-pro-type-reinterpret-cast) – unavoidable when talking to C APIs.
+    Array<i32, 6> mib = { CTL_NET, PF_ROUTE, 0, 0, NET_RT_IFLIST2, 0 };
+    usize         len = 0;
+
+    if (sysctl(mib.data(), mib.size(), nullptr, &len, nullptr, 0) == -1)
+      ERR_FMT(ApiUnavailable, "sysctl(NET_RT_IFLIST2) failed to get the interface list size: {}", std::system_category().message(errno));
+
+    Vec<char> buffer(len);
+
+    if (sysctl(mib.data(), mib.size(), buffer.data(), &len, nullptr, 0) == -1)
+      ERR_FMT(ApiUnavailable, "sysctl(NET_RT_IFLIST2) failed to get the interface list: {}", std::system_category().message(errno));
+
+    Vec<InterfaceStats> stats;
+
+    for (usize offset = 0; offset < len;) {
+      const auto* ifm = reinterpret_cast<const if_msghdr*>(std::next(buffer.data(), static_cast<ptrdiff_t>(offset)));
+
+      if (ifm->ifm_msglen == 0)
+        break;
+
+      offset += ifm->ifm_msglen;
+
+      // RTM_IFINFO2 messages carry the 64-bit counters; the address messages that follow each one are skipped.
+      if (ifm->ifm_type != RTM_IFINFO2)
+        continue;
+
+      const auto* ifm2 = reinterpret_cast<const if_msghdr2*>(ifm);
+
+      Array<char, IF_NAMESIZE> name {};
+
+      if (if_indextoname(ifm2->ifm_index, name.data()) == nullptr)
+        continue;
+
+      const if_data64& data = ifm2->ifm_data;
+
+      stats.push_back({
+        .name      = String(name.data()),
+        .rxBytes   = data.ifi_ibytes,
+        .txBytes   = data.ifi_obytes,
+        .rxPackets = data.ifi_ipackets,
+        .txPackets = data.ifi_opackets,
+        .rxErrors  = data.ifi_ierrors,
+        .txErrors  = data.ifi_oerrors,
+        .rxDropped = data.ifi_iqdrops,
+        .txDropped = static_cast<u64>(ifm2->ifm_snd_drops),
+      });
+    }
+
+    if (stats.empty())
+      ERR(NotFound, "sysctl(NET_RT_IFLIST2) returned no interfaces");
+
+    return stats;
+    // NOLINTEND(cppcoreguidelines-pro-type-reinterpret-cast)
+  }
+
   auto GetNetworkInterfaces(CacheManager& cache) -> Result<Vec<NetworkInterface>> {
     return cache.getOrSet<Vec<NetworkInterface>>("macos_network_interfaces", CachePolicy::tempDirectory(), []() -> Result<Vec<NetworkInterface>> {
       // NOLINTBEGIN(cppcoreguidelines-pro-type-reinterpret-cast) - This requires a lot of casts and there's no good way to avoid them.
@@ -731,6 +2929,8 @@ namespace draconis::core::system {
           if (getnameinfo(ifa->ifa_addr, sizeof(sockaddr_in), host.data(), host.size(), nullptr, 0, NI_NUMERICHOST) == 0)
             interface.ipv4Address = String(host.data());
         } else if (ifa->ifa_addr->sa_family == AF_LINK) {
+          fillLinkDetails(interface, ifa);
+
           auto* sdl = reinterpret_cast<sockaddr_dl*>(ifa->ifa_addr);
 
           if (sdl && sdl->sdl_alen == 6) {
@@ -766,9 +2966,6 @@ namespace draconis::core::system {
   }
 
   auto GetBatteryInfo(CacheManager& /*cache*/) -> Result<Battery> {
-    using matchit::match, matchit::is, matchit::_;
-    using enum Battery::Status;
-
     // This snapshot contains information about all power sources (e.g., AC Power, Battery).
     // It's a Core Foundation object that we must release.
     CFTypeRef powerSourcesInfo = IOPSCopyPowerSourcesInfo();
@@ -789,45 +2986,401 @@ namespace draconis::core::system {
       if (sourceDescription == nullptr)
         continue;
 
-      // Check if this source is an internal battery.
-      const auto* type = static_cast<const CFStringRef>(CFDictionaryGetValue(sourceDescription, CFSTR(kIOPSTypeKey)));
+      if (Option<Battery> battery = powerSourceToBattery(sourceDescription))
+        return std::move(*battery);
+    }
 
-      if (type == nullptr || CFStringCompare(type, CFSTR(kIOPSInternalBatteryType), 0) != kCFCompareEqualTo)
-        continue;
+    // If the loop finishes without finding an internal battery.
+    ERR(UnavailableFeature, "No internal battery found (no IOPSInternalBatteryType in power sources, feature not present)");
+  }
+
+  auto GetBatteries(CacheManager& /*cache*/) -> Result<Vec<Battery>> {
+    Vec<Battery> batteries;
+
+    if (const CFTypeRef powerSourcesInfo = IOPSCopyPowerSourcesInfo()) {
+      const UniquePointer<const Unit, decltype(&CFRelease)> powerSourcesInfoDeleter(powerSourcesInfo, &CFRelease);
+
+      if (const CFArrayRef powerSourcesList = IOPSCopyPowerSourcesList(powerSourcesInfo)) {
+        const UniquePointer<const Unit, decltype(&CFRelease)> powerSourcesListDeleter(powerSourcesList, &CFRelease);
+
+        for (CFIndex i = 0; i < CFArrayGetCount(powerSourcesList); ++i)
+          if (const CFDictionaryRef sourceDescription = IOPSGetPowerSourceDescription(powerSourcesInfo, CFArrayGetValueAtIndex(powerSourcesList, i)))
+            if (Option<Battery> battery = powerSourceToBattery(sourceDescription))
+              batteries.push_back(std::move(*battery));
+      }
+    }
+
+    // Bluetooth peripherals (Magic Mouse, Keyboard and Trackpad, and some third-party HID devices) publish their charge on their HID service.
+    io_iterator_t iterator = 0;
+
+    if (IOServiceGetMatchingServices(kIOMainPortDefault, IOServiceMatching("AppleDeviceManagementHIDEventService"), &iterator) == kIOReturnSuccess) {
+      while (const io_object_t service = IOIteratorNext(iterator)) {
+        const CFTypeRef percentValue = IORegistryEntryCreateCFProperty(service, CFSTR("BatteryPercent"), kCFAllocatorDefault, 0);
+        i32             percent      = -1;
+
+        if (percentValue) {
+          if (CFGetTypeID(percentValue) == CFNumberGetTypeID())
+            CFNumberGetValue(static_cast<CFNumberRef>(percentValue), kCFNumberSInt32Type, &percent);
+
+          CFRelease(percentValue);
+        }
+
+        if (percent >= 0 && percent <= 100) {
+          Battery battery(Battery::Status::Unknown, static_cast<u8>(percent), None);
+          battery.name         = readRegistryString(service, CFSTR("Product")).value_or("Bluetooth Device");
+          battery.isPeripheral = true;
+
+          batteries.push_back(std::move(battery));
+        }
 
-      u8 percentage = getNumericValue<u8>(sourceDescription, CFSTR(kIOPSCurrentCapacityKey)).value_or(0);
+        IOObjectRelease(service);
+      }
+
+      IOObjectRelease(iterator);
+    }
+
+    if (batteries.empty())
+      ERR(NotFound, "No battery found");
+
+    return batteries;
+  }
+
+  auto GetPowerSource(CacheManager& /*cache*/) -> Result<PowerSource> {
+    PowerSource source;
+
+    const CFTypeRef powerSourcesInfo = IOPSCopyPowerSourcesInfo();
+
+    if (powerSourcesInfo == nullptr)
+      ERR(UnavailableFeature, "IOPSCopyPowerSourcesInfo() returned nullptr (IOKit unavailable or no power sources/feature not present)");
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> powerSourcesInfoDeleter(powerSourcesInfo, &CFRelease);
+
+    // Desktops always report AC; the providing type is only "Battery Power" when running off a battery.
+    if (const CFStringRef providingType = IOPSGetProvidingPowerSourceType(powerSourcesInfo))
+      source.acConnected = CFEqual(providingType, CFSTR(kIOPMACPowerKey));
+
+    const CFDictionaryRef adapterDetails = IOPSCopyExternalPowerAdapterDetails();
+
+    if (adapterDetails == nullptr)
+      return source;
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> adapterDetailsDeleter(adapterDetails, &CFRelease);
+
+    if (const Option<i32> watts = getNumericValue<i32>(adapterDetails, CFSTR(kIOPSPowerAdapterWattsKey)); watts && *watts > 0)
+      source.chargerWatts = static_cast<f64>(*watts);
+
+    // USB-C adapters list their PD profiles under "UsbHvcMenu"; the negotiated one is given in mV and mA.
+    if (CFDictionaryContainsKey(adapterDetails, CFSTR("UsbHvcMenu"))) {
+      const Option<i32> millivolts = getNumericValue<i32>(adapterDetails, CFSTR("AdapterVoltage"));
+      const Option<i32> milliamps  = getNumericValue<i32>(adapterDetails, CFSTR("Current"));
+
+      if (millivolts && milliamps && *millivolts > 0 && *milliamps > 0)
+        source.usbPdWatts = static_cast<f64>(*millivolts) * static_cast<f64>(*milliamps) / 1e6;
+    }
+
+    return source;
+  }
+
+  auto GetPowerProfile(CacheManager& /*cache*/) -> Result<PowerProfile> {
+    // Low Power Mode is the only user-facing switch; otherwise macOS manages power automatically.
+    if (macOS::IsLowPowerModeEnabled())
+      return PowerProfile { .mode = PowerProfile::Mode::PowerSaver, .name = "Low Power Mode", .governor = None };
+
+    return PowerProfile { .mode = PowerProfile::Mode::Balanced, .name = "Automatic", .governor = None };
+  }
+
+  // Every notification is delivered on a private thread's run loop, which queues the events for wait().
+  auto GetHardwareFingerprint() -> Result<String> {
+    String fingerprint;
+
+    for (const PCStr name : { "hw.model", "machdep.cpu.brand_string" }) {
+      Array<char, 256> value {};
+      usize            valueLen = value.size();
+
+      if (sysctlbyname(name, value.data(), &valueLen, nullptr, 0) == 0)
+        fingerprint += std::format("{};", value.data());
+    }
+
+    if (fingerprint.empty())
+      ERR_FMT(ApiUnavailable, "sysctlbyname('hw.model') failed: {}", std::system_category().message(errno));
+
+    return fingerprint;
+  }
+
+  struct SystemWatcher::Impl {
+    Mutex                   mutex;
+    std::condition_variable ready;
+    Vec<SystemEvent>        queue;
+    bool                    interrupted = false;
+
+    std::thread        thread;
+    CFRunLoopRef       runLoop    = nullptr;
+    CFRunLoopSourceRef stopSource = nullptr;
+
+    // Only touched on the notification thread.
+    Map<String, bool> linkUp;
+
+    Impl() = default;
+
+    Impl(const Impl&)                    = delete;
+    Impl(Impl&&)                         = delete;
+    auto operator=(const Impl&) -> Impl& = delete;
+    auto operator=(Impl&&) -> Impl&      = delete;
+
+    ~Impl() {
+      // A signalled source is handled even if the run loop hasn't started yet, unlike CFRunLoopStop.
+      if (runLoop) {
+        CFRunLoopSourceSignal(stopSource);
+        CFRunLoopWakeUp(runLoop);
+      }
+
+      if (thread.joinable())
+        thread.join();
+
+      if (runLoop)
+        CFRelease(runLoop);
+
+      if (stopSource)
+        CFRelease(stopSource);
+    }
+
+    auto push(SystemEvent event) -> Unit {
+      {
+        LockGuard lock(mutex);
+        queue.push_back(std::move(event));
+      }
+
+      ready.notify_one();
+    }
 
-      CFTypeRef isChargingRef = CFDictionaryGetValue(sourceDescription, CFSTR(kIOPSIsChargingKey));
-      bool      isCharging    = false; // Default to a safe value.
+    static auto DrainDevices(Impl* impl, const io_iterator_t iterator, const Option<SystemEvent::Kind> kind) -> Unit {
+      while (const io_service_t service = IOIteratorNext(iterator)) {
+        if (kind) {
+          Option<String> name = readRegistryString(service, CFSTR(kIOBSDNameKey));
 
-      if (isChargingRef != nullptr) {
-        if (CFGetTypeID(isChargingRef) == CFBooleanGetTypeID()) {
-          isCharging = CFBooleanGetValue(static_cast<CFBooleanRef>(isChargingRef));
-        } else if (CFGetTypeID(isChargingRef) == CFNumberGetTypeID()) {
-          i32 numericValue = 0;
-          if (CFNumberGetValue(static_cast<CFNumberRef>(isChargingRef), kCFNumberIntType, &numericValue))
-            isCharging = (numericValue != 0);
+          if (io_name_t registryName; !name && IORegistryEntryGetName(service, registryName) == KERN_SUCCESS)
+            name = String(registryName);
+
+          impl->push({ .kind = *kind, .source = std::move(name) });
         }
+
+        IOObjectRelease(service);
       }
+    }
 
-      Battery::Status status = match(isCharging)(
-        is | (_ == true && percentage == 100) = Full,
-        is | true                             = Charging,
-        is | false                            = Discharging,
-        is | _                                = Unknown
-      );
+    static auto OnDevicesAdded(void* context, const io_iterator_t iterator) -> void {
+      DrainDevices(static_cast<Impl*>(context), iterator, SystemEvent::Kind::DeviceAdded);
+    }
 
-      Option<std::chrono::seconds> timeRemaining = None;
+    static auto OnDevicesRemoved(void* context, const io_iterator_t iterator) -> void {
+      DrainDevices(static_cast<Impl*>(context), iterator, SystemEvent::Kind::DeviceRemoved);
+    }
 
-      // Time to empty is given in minutes. A value of 0 means calculating, < 0 means unlimited/plugged in.
-      if (Option<i32> timeMinutes = getNumericValue<i32>(sourceDescription, CFSTR(kIOPSTimeToEmptyKey)); timeMinutes && *timeMinutes > 0)
-        timeRemaining = std::chrono::minutes(*timeMinutes);
+    static auto OnPowerChanged(void* context) -> void {
+      static_cast<Impl*>(context)->push({ .kind = SystemEvent::Kind::BatteryChanged, .source = None });
+    }
 
-      return Battery(status, percentage, timeRemaining);
+    static auto OnDisplayReconfigured(const CGDirectDisplayID display, const CGDisplayChangeSummaryFlags flags, void* context) -> void {
+      // Every reconfiguration is announced twice, once before and once after.
+      if ((flags & kCGDisplayBeginConfigurationFlag) == 0)
+        static_cast<Impl*>(context)->push({ .kind = SystemEvent::Kind::DisplayChanged, .source = std::to_string(display) });
     }
 
-    // If the loop finishes without finding an internal battery.
-    ERR(UnavailableFeature, "No internal battery found (no IOPSInternalBatteryType in power sources, feature not present)");
+    // Records the link state in a "State:/Network/Interface/<name>/Link" value, reporting it if it changed.
+    auto updateLink(const CFStringRef key, const CFPropertyListRef value, const bool report) -> Unit {
+      Array<char, 256> buffer {};
+
+      if (!CFStringGetCString(key, buffer.data(), buffer.size(), kCFStringEncodingUTF8))
+        return;
+
+      constexpr StringView prefix = "State:/Network/Interface/";
+
+      StringView name(buffer.data());
+
+      if (!name.starts_with(prefix))
+        return;
+
+      name.remove_prefix(prefix.size());
+      name = name.substr(0, name.find('/'));
+
+      // The key disappears along with the interface.
+      const bool up = value && CFGetTypeID(value) == CFDictionaryGetTypeID() &&
+        CFDictionaryGetValue(static_cast<CFDictionaryRef>(value), CFSTR("Active")) == kCFBooleanTrue;
+
+      const auto [iter, inserted] = linkUp.try_emplace(String(name), up);
+
+      if (inserted ? !up : iter->second == up)
+        return;
+
+      iter->second = up;
+
+      if (report)
+        push({ .kind = up ? SystemEvent::Kind::NetworkUp : SystemEvent::Kind::NetworkDown, .source = String(name) });
+    }
+
+    static auto OnLinksChanged(const SCDynamicStoreRef store, const CFArrayRef changedKeys, void* context) -> void {
+      for (CFIndex i = 0; i < CFArrayGetCount(changedKeys); ++i) {
+        const auto      key   = static_cast<CFStringRef>(CFArrayGetValueAtIndex(changedKeys, i));
+        const CFTypeRef value = SCDynamicStoreCopyValue(store, key);
+
+        static_cast<Impl*>(context)->updateLink(key, value, true);
+
+        if (value)
+          CFRelease(value);
+      }
+    }
+
+    static auto OnStop(void* /*context*/) -> void {
+      CFRunLoopStop(CFRunLoopGetCurrent());
+    }
+
+    // Body of the notification thread: subscribes on this thread's run loop, reports the outcome through `started`,
+    // then runs the loop until the watcher is destroyed.
+    auto run(std::promise<Result<>> started) -> Unit {
+      CFRunLoopSourceContext stopContext {};
+      stopContext.perform = &OnStop;
+
+      stopSource = CFRunLoopSourceCreate(kCFAllocatorDefault, 0, &stopContext);
+      CFRunLoopAddSource(CFRunLoopGetCurrent(), stopSource, kCFRunLoopDefaultMode);
+
+      IONotificationPortRef port        = IONotificationPortCreate(kIOMainPortDefault);
+      CFRunLoopSourceRef    powerSource = IOPSNotificationCreateRunLoopSource(&OnPowerChanged, this);
+      Vec<io_iterator_t>    iterators;
+      SCDynamicStoreRef     store       = nullptr;
+      CFRunLoopSourceRef    storeSource = nullptr;
+
+      const auto subscribe = [&]() -> Result<> {
+        if (!port)
+          ERR(ApiUnavailable, "IONotificationPortCreate failed");
+
+        CFRunLoopAddSource(CFRunLoopGetCurrent(), IONotificationPortGetRunLoopSource(port), kCFRunLoopDefaultMode);
+
+        for (const PCStr className : { "IOUSBHostDevice", kIOMediaClass })
+          for (const bool added : { true, false }) {
+            const CFMutableDictionaryRef matching = IOServiceMatching(className);
+
+            // Partitions come and go with their disk.
+            if (StringView(className) == kIOMediaClass)
+              CFDictionarySetValue(matching, CFSTR(kIOMediaWholeKey), kCFBooleanTrue);
+
+            io_iterator_t iterator = IO_OBJECT_NULL;
+
+            if (IOServiceAddMatchingNotification(port, added ? kIOFirstMatchNotification : kIOTerminatedNotification, matching, added ? &OnDevicesAdded : &OnDevicesRemoved, this, &iterator) != KERN_SUCCESS)
+              ERR_FMT(ApiUnavailable, "IOServiceAddMatchingNotification failed for {}", className);
+
+            // The devices already present come back through the iterator; draining it arms the notification.
+            DrainDevices(this, iterator, None);
+            iterators.push_back(iterator);
+          }
+
+        if (!powerSource)
+          ERR(ApiUnavailable, "IOPSNotificationCreateRunLoopSource failed");
+
+        CFRunLoopAddSource(CFRunLoopGetCurrent(), powerSource, kCFRunLoopDefaultMode);
+
+        if (CGDisplayRegisterReconfigurationCallback(&OnDisplayReconfigured, this) != kCGErrorSuccess)
+          ERR(ApiUnavailable, "CGDisplayRegisterReconfigurationCallback failed");
+
+        SCDynamicStoreContext storeContext { .version = 0, .info = this, .retain = nullptr, .release = nullptr, .copyDescription = nullptr };
+
+        store = SCDynamicStoreCreate(kCFAllocatorDefault, CFSTR("draconis++"), &OnLinksChanged, &storeContext);
+
+        if (!store)
+          ERR(ApiUnavailable, "SCDynamicStoreCreate failed");
+
+        const CFStringRef pattern  = CFSTR("State:/Network/Interface/[^/]+/Link");
+        const CFArrayRef  patterns = CFArrayCreate(kCFAllocatorDefault, reinterpret_cast<const void**>(&pattern), 1, &kCFTypeArrayCallBacks); // NOLINT(cppcoreguidelines-pro-type-reinterpret-cast)
+
+        const UniquePointer<const Unit, decltype(&CFRelease)> patternsDeleter(patterns, &CFRelease);
+
+        if (!SCDynamicStoreSetNotificationKeys(store, nullptr, patterns))
+          ERR(ApiUnavailable, "SCDynamicStoreSetNotificationKeys failed");
+
+        // Seed the link states, so the first change to an interface that's already up isn't reported as it coming up.
+        if (const CFDictionaryRef links = SCDynamicStoreCopyMultiple(store, nullptr, patterns)) {
+          const UniquePointer<const Unit, decltype(&CFRelease)> linksDeleter(links, &CFRelease);
+
+          const auto       count = static_cast<usize>(CFDictionaryGetCount(links));
+          Vec<const void*> keys(count);
+          Vec<const void*> values(count);
+
+          CFDictionaryGetKeysAndValues(links, keys.data(), values.data());
+
+          for (usize i = 0; i < count; ++i)
+            updateLink(static_cast<CFStringRef>(keys[i]), values[i], false);
+        }
+
+        storeSource = SCDynamicStoreCreateRunLoopSource(kCFAllocatorDefault, store, 0);
+
+        if (!storeSource)
+          ERR(ApiUnavailable, "SCDynamicStoreCreateRunLoopSource failed");
+
+        CFRunLoopAddSource(CFRunLoopGetCurrent(), storeSource, kCFRunLoopDefaultMode);
+
+        return {};
+      };
+
+      Result<> subscribed = subscribe();
+
+      // Retained so the destructor can still wake it if this thread has already exited.
+      if (subscribed)
+        runLoop = static_cast<CFRunLoopRef>(CFRetain(CFRunLoopGetCurrent()));
+
+      started.set_value(std::move(subscribed));
+
+      if (runLoop)
+        CFRunLoopRun();
+
+      CGDisplayRemoveReconfigurationCallback(&OnDisplayReconfigured, this);
+
+      for (const io_iterator_t iterator : iterators)
+        IOObjectRelease(iterator);
+
+      if (port)
+        IONotificationPortDestroy(port);
+
+      for (const CFTypeRef object : { static_cast<CFTypeRef>(storeSource), static_cast<CFTypeRef>(store), static_cast<CFTypeRef>(powerSource) })
+        if (object)
+          CFRelease(object);
+    }
+  };
+
+  SystemWatcher::SystemWatcher(UniquePointer<Impl> impl) : m_impl(std::move(impl)) {}
+  SystemWatcher::SystemWatcher(SystemWatcher&&) noexcept                    = default;
+  auto SystemWatcher::operator=(SystemWatcher&&) noexcept -> SystemWatcher& = default;
+  SystemWatcher::~SystemWatcher()                                           = default;
+
+  auto SystemWatcher::Create() -> Result<SystemWatcher> {
+    auto impl = std::make_unique<Impl>();
+
+    std::promise<Result<>> started;
+    Future<Result<>>       subscribed = started.get_future();
+
+    impl->thread = std::thread([watcher = impl.get(), started = std::move(started)]() mutable -> void { watcher->run(std::move(started)); });
+
+    if (Result<> result = subscribed.get(); !result)
+      ERR_FROM(result.error());
+
+    return SystemWatcher(std::move(impl));
+  }
+
+  auto SystemWatcher::wait(const std::chrono::milliseconds timeout) -> Result<Vec<SystemEvent>> {
+    std::unique_lock lock(m_impl->mutex);
+
+    m_impl->ready.wait_for(lock, timeout, [this]() -> bool { return !m_impl->queue.empty() || m_impl->interrupted; });
+
+    m_impl->interrupted = false;
+
+    return std::exchange(m_impl->queue, {});
+  }
+
+  auto SystemWatcher::interrupt() -> Unit {
+    {
+      LockGuard lock(m_impl->mutex);
+      m_impl->interrupted = true;
+    }
+
+    m_impl->ready.notify_all();
   }
 } // namespace draconis::core::system
 
@@ -27,6 +27,43 @@ namespace draconis::core::system::macOS {
    */
   auto GetGPUModel() -> types::Result<types::String>;
 
+  /**
+   * @brief Gets every Metal-capable GPU in the system.
+   * @return A Result containing a list of GPUInfo on success, or a DracError on failure.
+   *
+   * Apple Silicon GPUs and low-power GPUs are reported as integrated. VRAM is
+   * approximated with the device's recommended maximum working set size. The
+   * Metal version is the newest `MTLGPUFamilyMetal*` family the device supports.
+   */
+  auto GetGPUs() -> types::Result<types::Vec<types::GPUInfo>>;
+
+  /**
+   * @brief Gets the Bluetooth host controller and the paired devices.
+   * @return A Result containing the adapter and devices on success, or a DracError on failure.
+   *
+   * This function uses the IOBluetooth framework. IOBluetooth doesn't expose
+   * battery levels, so those are always None.
+   */
+  auto GetBluetoothDevices() -> types::Result<types::BluetoothInfo>;
+
+  /**
+   * @brief Gets the video capture devices and whether another app is using them.
+   * @return A Result containing the cameras on success, or a DracError on failure.
+   *
+   * This function uses the AVFoundation framework. Listing devices doesn't
+   * require camera permission, and never starts a capture session.
+   */
+  auto GetCameras() -> types::Result<types::Vec<types::CameraInfo>>;
+
+  /**
+   * @brief Gets the connection details of the default Wi-Fi interface.
+   * @return A Result containing the Wi-Fi details on success, or a DracError on failure.
+   *
+   * This function uses the CoreWLAN framework. Since macOS 14, the SSID and BSSID
+   * are only returned to processes with Location Services permission.
+   */
+  auto GetWifiInfo() -> types::Result<types::WifiInfo>;
+
   /**
    * @brief Gets the version of the macOS operating system.
    * @return A Result containing the version as a String on success, or a DracError on failure.
@@ -36,6 +73,36 @@ namespace draconis::core::system::macOS {
    * to the displayed major version.
    */
   auto GetOSVersion() -> types::Result<types::OSInfo>;
+
+  /**
+   * @brief Checks whether Low Power Mode is enabled.
+   * @return True if Low Power Mode is on, false if it is off or the OS predates it (macOS 12).
+   */
+  auto IsLowPowerModeEnabled() -> bool;
+
+  /**
+   * @brief Gets how far above SDR white a display can currently render.
+   * @param displayID The CoreGraphics ID of the display.
+   * @return The maximum potential EDR component value (1.0 when HDR is off or unsupported), or None if no screen matches the display.
+   *
+   * This function uses NSScreen, the only public API that reports a display's HDR headroom.
+   */
+  auto GetDisplayEdrHeadroom(types::u32 displayID) -> types::Option<types::f64>;
+
+  /**
+   * @brief Gets the name of the application that opens `https` URLs.
+   * @return The application's display name (e.g., "Safari"), or None if no handler is registered.
+   */
+  auto GetDefaultBrowser() -> types::Option<types::String>;
+
+  /**
+   * @brief Gets the name of the application that runs shell scripts.
+   * @return The application's display name (e.g., "Terminal", "iTerm"), or None if no handler is registered.
+   *
+   * LaunchServices has no replacement for the shell role handler, so this uses the deprecated
+   * `LSCopyDefaultRoleHandlerForContentType`.
+   */
+  auto GetDefaultTerminal() -> types::Option<types::String>;
 } // namespace draconis::core::system::macOS
 
 #endif
@@ -3,7 +3,8 @@
  * @brief macOS-specific implementations for retrieving system information.
  *
  * This file contains functions that interact with private and public macOS frameworks
- * (MediaRemote and Metal) to fetch details about the currently playing media and the system's GPU.
+ * (MediaRemote, Metal, IOBluetooth, AVFoundation, CoreWLAN and AppKit) to fetch details about the currently playing
+ * media, the system's GPU, Bluetooth devices, cameras, the Wi-Fi connection, the HDR headroom of displays and the default browser and terminal.
  * This implementation is conditionally compiled and should only be included on Apple platforms.
  */
 
@@ -11,7 +12,12 @@
 
   #include "Bridge.hpp"
 
-  #include <Metal/Metal.h> // For MTLDevice to identify the GPU.
+  #include <AVFoundation/AVFoundation.h> // For AVCaptureDeviceDiscoverySession and AVCaptureDevice.
+  #include <AppKit/AppKit.h>             // For NSScreen and NSWorkspace.
+  #include <CoreServices/CoreServices.h> // For LSCopyDefaultRoleHandlerForContentType.
+  #include <CoreWLAN/CoreWLAN.h>         // For CWWiFiClient and CWInterface.
+  #include <IOBluetooth/IOBluetooth.h>   // For IOBluetoothHostController and IOBluetoothDevice.
+  #include <Metal/Metal.h>               // For MTLDevice to identify the GPU.
 
   #include <Drac++/Utils/Error.hpp>
 
@@ -36,6 +42,188 @@ namespace draconis::core::system::macOS {
     }
   }
 
+  auto GetGPUs() -> Result<Vec<GPUInfo>> {
+    @autoreleasepool {
+      NSArray<id<MTLDevice>>* devices = MTLCopyAllDevices();
+
+      if (!devices || devices.count == 0)
+        return Err(DracError(NotFound, "No Metal-compatible GPUs found."));
+
+      Vec<GPUInfo> gpus;
+      gpus.reserve(devices.count);
+
+      for (id<MTLDevice> device in devices) {
+        const String name = device.name ? String([device.name UTF8String]) : String("Unknown");
+
+        // Apple Silicon GPUs are named "Apple M1" etc., and discrete GPUs are prefixed
+        // with their vendor ("AMD Radeon Pro 5500M"), so the first word is the vendor.
+        const usize spacePos = name.find(' ');
+        String      vendor   = spacePos == String::npos ? name : name.substr(0, spacePos);
+        String      model    = spacePos == String::npos ? name : name.substr(spacePos + 1);
+
+        GPUInfo& gpu = gpus.emplace_back(
+          std::move(vendor),
+          std::move(model),
+          static_cast<u64>(device.recommendedMaxWorkingSetSize),
+          None,
+          device.hasUnifiedMemory || device.isLowPower
+        );
+
+        // Every Metal device runs Metal 2 or later. MTLGPUFamilyMetal4 is spelled out for SDKs that predate it.
+        gpu.metalVersion = "2";
+
+        if (@available(macOS 26.0, *)) {
+          if ([device supportsFamily:static_cast<MTLGPUFamily>(5002)])
+            gpu.metalVersion = "4";
+        }
+
+        if (@available(macOS 13.0, *)) {
+          if (*gpu.metalVersion == "2" && [device supportsFamily:MTLGPUFamilyMetal3])
+            gpu.metalVersion = "3";
+        }
+
+        // OpenGL was frozen at 4.1 when it was deprecated in macOS 10.14; every GPU reports the same version.
+        gpu.openGLVersion = "4.1";
+      }
+
+      return gpus;
+    }
+  }
+
+  auto GetBluetoothDevices() -> Result<BluetoothInfo> {
+    @autoreleasepool {
+      IOBluetoothHostController* controller = [IOBluetoothHostController defaultController];
+
+      if (!controller || !controller.addressAsString)
+        return Err(DracError(NotFound, "No Bluetooth controller found."));
+
+      // IOBluetooth formats addresses as "aa-bb-cc-dd-ee-ff"; normalize to the colon-separated form used elsewhere.
+      const auto normalizeAddress = [](NSString* address) -> String {
+        if (!address)
+          return {};
+
+        return [[[address uppercaseString] stringByReplacingOccurrencesOfString:@"-" withString:@":"] UTF8String];
+      };
+
+      BluetoothInfo info;
+
+      info.adapters.push_back({
+        .name    = controller.nameAsString ? String([controller.nameAsString UTF8String]) : String("Bluetooth"),
+        .address = normalizeAddress(controller.addressAsString),
+        .powered = controller.powerState == kBluetoothHCIPowerStateON,
+      });
+
+      for (IOBluetoothDevice* device in [IOBluetoothDevice pairedDevices]) {
+        String address = normalizeAddress(device.addressString);
+
+        info.devices.push_back({
+          .name           = device.name ? String([device.name UTF8String]) : address,
+          .address        = std::move(address),
+          .paired         = static_cast<bool>(device.isPaired),
+          .connected      = static_cast<bool>(device.isConnected),
+          .batteryPercent = None,
+        });
+      }
+
+      return info;
+    }
+  }
+
+  auto GetCameras() -> Result<Vec<CameraInfo>> {
+    @autoreleasepool {
+      NSMutableArray<AVCaptureDeviceType>* deviceTypes = [NSMutableArray arrayWithObject:AVCaptureDeviceTypeBuiltInWideAngleCamera];
+
+      // USB webcams and Continuity Camera iPhones; before macOS 14 they were reported as "external unknown".
+      if (@available(macOS 14.0, *)) {
+        [deviceTypes addObject:AVCaptureDeviceTypeExternal];
+        [deviceTypes addObject:AVCaptureDeviceTypeContinuityCamera];
+      } else {
+        [deviceTypes addObject:AVCaptureDeviceTypeExternalUnknown];
+      }
+
+      AVCaptureDeviceDiscoverySession* session =
+        [AVCaptureDeviceDiscoverySession discoverySessionWithDeviceTypes:deviceTypes
+                                                               mediaType:AVMediaTypeVideo
+                                                                position:AVCaptureDevicePositionUnspecified];
+
+      if (!session)
+        return Err(DracError(ApiUnavailable, "Failed to create an AVCaptureDeviceDiscoverySession."));
+
+      Vec<CameraInfo> cameras;
+
+      for (AVCaptureDevice* device in session.devices) {
+        String uniqueId = device.uniqueID ? String([device.uniqueID UTF8String]) : String();
+
+        cameras.push_back({
+          .name  = device.localizedName ? String([device.localizedName UTF8String]) : uniqueId,
+          .id    = std::move(uniqueId),
+          .inUse = static_cast<bool>(device.isInUseByAnotherApplication),
+        });
+      }
+
+      return cameras;
+    }
+  }
+
+  auto GetWifiInfo() -> Result<WifiInfo> {
+    @autoreleasepool {
+      CWInterface* interface = [[CWWiFiClient sharedWiFiClient] interface];
+
+      if (!interface || !interface.interfaceName)
+        return Err(DracError(NotFound, "No Wi-Fi interface found."));
+
+      if (!interface.powerOn || interface.interfaceMode == kCWInterfaceModeNone)
+        return Err(DracError(NotFound, "The Wi-Fi interface is not connected."));
+
+      WifiInfo info {
+        .interfaceName = [interface.interfaceName UTF8String],
+        .ssid          = None,
+        .bssid         = None,
+        .signalDbm     = None,
+        .signalPercent = None,
+        .frequencyMhz  = None,
+        .band          = WifiInfo::Band::Unknown,
+        .linkRateMbps  = None,
+      };
+
+      if (NSString* ssid = interface.ssid)
+        info.ssid = [ssid UTF8String];
+
+      if (NSString* bssid = interface.bssid)
+        info.bssid = [[bssid uppercaseString] UTF8String];
+
+      // rssiValue is 0 when the interface isn't associated.
+      if (const NSInteger rssi = interface.rssiValue; rssi != 0)
+        info.signalDbm = static_cast<i32>(rssi);
+
+      if (CWChannel* channel = interface.wlanChannel) {
+        const NSInteger number = channel.channelNumber;
+
+        switch (channel.channelBand) {
+          case kCWChannelBand2GHz:
+            info.band         = WifiInfo::Band::GHz2_4;
+            info.frequencyMhz = static_cast<u32>(number == 14 ? 2484 : 2407 + (5 * number));
+            break;
+          case kCWChannelBand5GHz:
+            info.band         = WifiInfo::Band::GHz5;
+            info.frequencyMhz = static_cast<u32>(5000 + (5 * number));
+            break;
+          case kCWChannelBand6GHz:
+            info.band         = WifiInfo::Band::GHz6;
+            info.frequencyMhz = static_cast<u32>(5950 + (5 * number));
+            break;
+          default:
+            break;
+        }
+      }
+
+      if (const double rate = interface.transmitRate; rate > 0)
+        info.linkRateMbps = rate;
+
+      return info;
+    }
+  }
+
   auto GetOSVersion() -> Result<OSInfo> {
     @autoreleasepool {
       using matchit::match, matchit::is, matchit::_;
@@ -64,6 +252,65 @@ namespace draconis::core::system::macOS {
       );
     }
   }
+
+  auto IsLowPowerModeEnabled() -> bool {
+    @autoreleasepool {
+      if (@available(macOS 12.0, *))
+        return [[NSProcessInfo processInfo] isLowPowerModeEnabled];
+
+      return false;
+    }
+  }
+
+  auto GetDisplayEdrHeadroom(const u32 displayID) -> Option<f64> {
+    @autoreleasepool {
+      for (NSScreen* screen in [NSScreen screens]) {
+        NSNumber* screenNumber = screen.deviceDescription[@"NSScreenNumber"];
+
+        if (screenNumber == nil || screenNumber.unsignedIntValue != displayID)
+          continue;
+
+        if (@available(macOS 10.15, *))
+          return static_cast<f64>(screen.maximumPotentialExtendedDynamicRangeColorComponentValue);
+
+        return 1.0;
+      }
+
+      return None;
+    }
+  }
+
+  auto GetDefaultBrowser() -> Option<String> {
+    @autoreleasepool {
+      NSURL* appURL = [[NSWorkspace sharedWorkspace] URLForApplicationToOpenURL:[NSURL URLWithString:@"https://"]];
+
+      if (appURL == nil)
+        return None;
+
+      // The display name drops the ".app" extension and is localized, as in the Finder.
+      return [[[NSFileManager defaultManager] displayNameAtPath:appURL.path] UTF8String];
+    }
+  }
+
+  auto GetDefaultTerminal() -> Option<String> {
+    @autoreleasepool {
+  #pragma clang diagnostic push
+  #pragma clang diagnostic ignored "-Wdeprecated-declarations"
+      const CFStringRef bundleID = LSCopyDefaultRoleHandlerForContentType(CFSTR("public.unix-executable"), kLSRolesShell);
+  #pragma clang diagnostic pop
+
+      if (!bundleID)
+        return None;
+
+      NSURL* appURL = [[NSWorkspace sharedWorkspace] URLForApplicationWithBundleIdentifier:(__bridge NSString*)bundleID];
+      CFRelease(bundleID);
+
+      if (appURL == nil)
+        return None;
+
+      return [[[NSFileManager defaultManager] displayNameAtPath:appURL.path] UTF8String];
+    }
+  }
 } // namespace draconis::core::system::macOS
 
 #endif
@@ -0,0 +1,242 @@
+#if DRAC_ENABLE_CLOUDINFO
+
+  #ifdef _WIN32
+    #include <winsock2.h> // WSAStartup, WSACleanup
+    #include <windows.h>  // RegGetValueA, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ
+  #else
+    #include <sys/socket.h> // AF_INET
+  #endif
+
+  #include <chrono>  // std::chrono::{hours, milliseconds, seconds}
+  #include <format>  // std::format
+  #include <fstream> // std::ifstream
+  #include <utility> // std::move
+
+  #include "Drac++/Services/CloudInfo.hpp"
+
+  #include "Drac++/Utils/Error.hpp"
+  #include "Drac++/Utils/Logging.hpp"
+  #include "Drac++/Utils/Types.hpp"
+
+  #include "Services/Http.hpp"
+
+using namespace draconis::utils::types;
+using draconis::services::cloud::CloudInfo;
+using draconis::utils::cache::CacheManager;
+using enum draconis::utils::error::DracErrorCode;
+
+namespace {
+  using Provider = CloudInfo::Provider;
+
+  // Every supported provider serves its instance metadata on this link-local address.
+  constexpr PCStr METADATA_HOST = "169.254.169.254";
+
+  // A metadata service answers within milliseconds when there is one.
+  constexpr std::chrono::milliseconds METADATA_TIMEOUT = std::chrono::seconds(1);
+
+  // Chassis asset tags Azure and Oracle Cloud stamp on every instance.
+  constexpr StringView AZURE_ASSET_TAG  = "7783-7084-3265-9085-8269-3286-77";
+  constexpr StringView ORACLE_ASSET_TAG = "OracleCloud.com";
+
+  /**
+   * @brief The SMBIOS strings cloud hypervisors identify themselves with.
+   */
+  struct DmiStrings {
+    String manufacturer;
+    String productName;
+    String biosVendor;
+    String biosVersion;
+    String assetTag; ///< Not exposed on Windows.
+  };
+
+  auto ReadDmiStrings() -> DmiStrings {
+  #if defined(_WIN32)
+    const auto read = [](const PCStr name) -> String {
+      Array<char, 256> buffer {};
+      auto             size = static_cast<DWORD>(buffer.size());
+
+      if (RegGetValueA(HKEY_LOCAL_MACHINE, "HARDWARE\\DESCRIPTION\\System\\BIOS", name, RRF_RT_REG_SZ, nullptr, buffer.data(), &size) != ERROR_SUCCESS)
+        return {};
+
+      return buffer.data();
+    };
+
+    return {
+      .manufacturer = read("SystemManufacturer"),
+      .productName  = read("SystemProductName"),
+      .biosVendor   = read("BIOSVendor"),
+      .biosVersion  = read("BIOSVersion"),
+      .assetTag     = {},
+    };
+  #elif defined(__linux__)
+    const auto read = [](const PCStr name) -> String {
+      std::ifstream file(std::format("/sys/class/dmi/id/{}", name));
+      String        line;
+
+      if (!file.is_open() || !std::getline(file, line))
+        return {};
+
+      if (const usize end = line.find_last_not_of(" \t\r\n"); end != String::npos)
+        line.erase(end + 1);
+
+      return line;
+    };
+
+    return {
+      .manufacturer = read("sys_vendor"),
+      .productName  = read("product_name"),
+      .biosVendor   = read("bios_vendor"),
+      .biosVersion  = read("bios_version"),
+      .assetTag     = read("chassis_asset_tag"),
+    };
+  #else
+    // No cheap SMBIOS access elsewhere, so instances there (e.g. EC2 Mac) aren't recognized.
+    return {};
+  #endif
+  }
+
+  auto ProviderFromDmi(const DmiStrings& dmi) -> Option<Provider> {
+    // Nitro instances name themselves; older Xen-based instances only leave a trace in the BIOS version.
+    if (dmi.manufacturer == "Amazon EC2" || dmi.biosVendor == "Amazon EC2" || dmi.biosVersion.contains("amazon"))
+      return Provider::Aws;
+
+    if (dmi.manufacturer == "Google" || dmi.productName == "Google Compute Engine")
+      return Provider::Gcp;
+
+    if (dmi.assetTag == AZURE_ASSET_TAG)
+      return Provider::Azure;
+
+    if (dmi.manufacturer == "Hetzner")
+      return Provider::Hetzner;
+
+    if (dmi.manufacturer == "DigitalOcean")
+      return Provider::DigitalOcean;
+
+    if (dmi.assetTag == ORACLE_ASSET_TAG)
+      return Provider::Oracle;
+
+    return None;
+  }
+
+  /**
+   * @brief Requests a text value from the metadata service.
+   * @return The response body, or None if the service is unreachable or doesn't have the value.
+   */
+  auto FetchMetadata(const StringView method, const StringView path, const StringView headers) -> Option<String> {
+    Result<draconis::services::http::Response> response = draconis::services::http::Request(METADATA_HOST, method, path, headers, AF_INET, METADATA_TIMEOUT);
+
+    if (!response) {
+      debug_at(response.error());
+      return None;
+    }
+
+    if (response->status != 200 || response->body.empty()) {
+      debug_log("Metadata request {} {} returned status {}", method, path, response->status);
+      return None;
+    }
+
+    return std::move(response->body);
+  }
+
+  // Returns the part after the last '/', for values reported as resource paths (e.g. "projects/1/zones/us-central1-a").
+  auto LastSegment(const String& value) -> String {
+    const usize slash = value.rfind('/');
+    return slash == String::npos ? value : value.substr(slash + 1);
+  }
+
+  auto QueryMetadata(CloudInfo& info) -> Unit {
+    switch (info.provider) {
+      case Provider::Aws: {
+        // IMDSv2 session token first; instances that only allow IMDSv1 reject it, so fall back to plain requests.
+        String tokenHeader;
+
+        if (Option<String> token = FetchMetadata("PUT", "/latest/api/token", "X-aws-ec2-metadata-token-ttl-seconds: 60\r\n"))
+          tokenHeader = std::format("X-aws-ec2-metadata-token: {}\r\n", *token);
+
+        info.instanceType = FetchMetadata("GET", "/latest/meta-data/instance-type", tokenHeader);
+        info.region       = FetchMetadata("GET", "/latest/meta-data/placement/region", tokenHeader);
+        break;
+      }
+
+      case Provider::Gcp: {
+        constexpr StringView headers = "Metadata-Flavor: Google\r\n";
+
+        info.instanceType = FetchMetadata("GET", "/computeMetadata/v1/instance/machine-type", headers).transform(LastSegment);
+
+        // Only the zone (e.g. "us-central1-a") is reported; the region is the zone minus its suffix.
+        info.region = FetchMetadata("GET", "/computeMetadata/v1/instance/zone", headers)
+                        .transform(LastSegment)
+                        .transform([](const String& zone) -> String { return zone.substr(0, zone.rfind('-')); });
+        break;
+      }
+
+      case Provider::Azure: {
+        constexpr StringView headers = "Metadata: true\r\n";
+
+        info.instanceType = FetchMetadata("GET", "/metadata/instance/compute/vmSize?api-version=2021-02-01&format=text", headers);
+        info.region       = FetchMetadata("GET", "/metadata/instance/compute/location?api-version=2021-02-01&format=text", headers);
+        break;
+      }
+
+      // Hetzner and DigitalOcean don't expose the server type through their metadata services.
+      case Provider::Hetzner:
+        info.region = FetchMetadata("GET", "/hetzner/v1/metadata/region", {});
+        break;
+
+      case Provider::DigitalOcean:
+        info.region = FetchMetadata("GET", "/metadata/v1/region", {});
+        break;
+
+      case Provider::Oracle: {
+        constexpr StringView headers = "Authorization: Bearer Oracle\r\n";
+
+        info.instanceType = FetchMetadata("GET", "/opc/v2/instance/shape", headers);
+        info.region       = FetchMetadata("GET", "/opc/v2/instance/canonicalRegionName", headers);
+        break;
+      }
+    }
+  }
+} // namespace
+
+namespace draconis::services::cloud {
+  auto GetCloudInfo(CacheManager& cache) -> Result<CloudInfo> {
+    using draconis::utils::cache::CacheLocation, draconis::utils::cache::CachePolicy;
+
+    // An instance can only change type or region while it's stopped, so a day-old answer is still good.
+    const CachePolicy policy { .location = CacheLocation::TempDirectory, .ttl = std::chrono::hours(24) };
+
+    return cache.getOrSet<CloudInfo>("cloud_info", policy, []() -> Result<CloudInfo> {
+      const DmiStrings dmi = ReadDmiStrings();
+
+      Option<Provider> provider = ProviderFromDmi(dmi);
+
+      // Windows can't read the asset tag, which is what separates an Azure VM from a local Hyper-V one.
+      const bool maybeAzure = !provider && dmi.manufacturer == "Microsoft Corporation" && dmi.productName == "Virtual Machine";
+
+      if (!provider && !maybeAzure)
+        ERR(NotFound, "Not running on a recognized cloud provider");
+
+  #ifdef _WIN32
+      WSADATA wsaData;
+
+      if (WSAStartup(MAKEWORD(2, 2), &wsaData) != 0)
+        ERR(NetworkError, "WSAStartup failed");
+  #endif
+
+      CloudInfo info { .provider = provider.value_or(Provider::Azure), .instanceType = None, .region = None };
+
+      QueryMetadata(info);
+
+  #ifdef _WIN32
+      WSACleanup();
+  #endif
+
+      if (maybeAzure && !info.instanceType && !info.region)
+        ERR(NotFound, "Not running on a recognized cloud provider");
+
+      return info;
+    });
+  }
+} // namespace draconis::services::cloud
+
+#endif // DRAC_ENABLE_CLOUDINFO
@@ -0,0 +1,348 @@
+/**
+ * @file Http.hpp
+ * @brief Minimal plain-HTTP client shared by the network services (public IP, cloud metadata),
+ *        the container runtime queries, and the CUPS printer queries.
+ *
+ * @details The callers only ever talk to a few fixed endpoints that answer with an unchunked
+ * body, so this speaks just enough HTTP/1.0 over a raw socket to avoid depending on an HTTP
+ * library. Connecting and reading are both bounded by a timeout, so an unreachable endpoint
+ * costs at most that long. On Unix, requests can also be sent over a Unix domain socket, which
+ * is how the Docker and Podman daemons expose their APIs and how CUPS accepts IPP requests.
+ *
+ * On Windows, callers must have initialized Winsock (WSAStartup) first.
+ */
+
+#pragma once
+
+#ifdef _WIN32
+  #include <winsock2.h> // socket, connect, select, send, recv, closesocket, ioctlsocket
+  #include <ws2tcpip.h> // getaddrinfo, freeaddrinfo
+#else
+  #include <fcntl.h>      // fcntl, O_NONBLOCK
+  #include <netdb.h>      // getaddrinfo, freeaddrinfo, addrinfo
+  #include <sys/select.h> // select, fd_set
+  #include <sys/socket.h> // socket, connect, send, recv, setsockopt, getsockopt
+  #include <sys/un.h>     // sockaddr_un
+  #include <unistd.h>     // close
+#endif
+
+#include <cerrno>   // errno, EACCES
+#include <charconv> // std::from_chars
+#include <chrono>   // std::chrono::{milliseconds, seconds}
+#include <cstring>  // std::memcpy
+#include <format>   // std::format
+#include <utility>  // std::move
+
+#include <Drac++/Utils/Error.hpp>
+#include <Drac++/Utils/Types.hpp>
+
+namespace draconis::services::http {
+  namespace types = ::draconis::utils::types;
+  namespace error = ::draconis::utils::error;
+
+  using enum error::DracErrorCode;
+
+  inline constexpr std::chrono::milliseconds DEFAULT_TIMEOUT = std::chrono::seconds(3); ///< Connect and receive timeout.
+  inline constexpr types::usize              MAX_RESPONSE    = 16384;                  ///< Responses are cut off after this many bytes.
+
+  /**
+   * @struct Response
+   * @brief A response to a request made with Request().
+   */
+  struct Response {
+    types::u16    status; ///< HTTP status code, e.g. 200.
+    types::String body;   ///< Response body with surrounding whitespace trimmed.
+  };
+
+#ifdef _WIN32
+  using Socket = SOCKET;
+
+  inline constexpr Socket INVALID_SOCKET_HANDLE = INVALID_SOCKET;
+
+  inline auto CloseSocket(const Socket sock) -> types::Unit {
+    closesocket(sock);
+  }
+
+  inline auto SetNonBlocking(const Socket sock, const bool enabled) -> bool {
+    u_long mode = enabled ? 1 : 0;
+    return ioctlsocket(sock, FIONBIO, &mode) == 0;
+  }
+
+  inline auto SetReceiveTimeout(const Socket sock, const std::chrono::milliseconds timeout) -> types::Unit {
+    const DWORD millis = static_cast<DWORD>(timeout.count());
+    setsockopt(sock, SOL_SOCKET, SO_RCVTIMEO, reinterpret_cast<const char*>(&millis), sizeof(millis));
+  }
+#else
+  using Socket = int;
+
+  inline constexpr Socket INVALID_SOCKET_HANDLE = -1;
+
+  inline auto CloseSocket(const Socket sock) -> types::Unit {
+    close(sock);
+  }
+
+  inline auto SetNonBlocking(const Socket sock, const bool enabled) -> bool {
+    const types::i32 flags = fcntl(sock, F_GETFL, 0);
+
+    if (flags == -1)
+      return false;
+
+    return fcntl(sock, F_SETFL, enabled ? (flags | O_NONBLOCK) : (flags & ~O_NONBLOCK)) == 0;
+  }
+
+  inline auto SetReceiveTimeout(const Socket sock, const std::chrono::milliseconds timeout) -> types::Unit {
+    timeval value { .tv_sec = static_cast<time_t>(timeout.count() / 1000), .tv_usec = static_cast<suseconds_t>((timeout.count() % 1000) * 1000) };
+    setsockopt(sock, SOL_SOCKET, SO_RCVTIMEO, &value, sizeof(value));
+  }
+#endif
+
+  /**
+   * @brief Parses a raw HTTP/1.x response into its status code and trimmed body.
+   * @param response Everything read from the connection.
+   * @param source Name of the endpoint, used in error messages.
+   */
+  [[nodiscard]] inline auto ParseResponse(const types::StringView response, const types::StringView source) -> types::Result<Response> {
+    // Status line: "HTTP/1.x NNN Reason"
+    constexpr types::usize statusOffset = 9;
+
+    types::u16 status = 0;
+
+    if (!response.starts_with("HTTP/1.") || response.size() < statusOffset + 3 ||
+        std::from_chars(response.data() + statusOffset, response.data() + statusOffset + 3, status).ec != std::errc())
+      ERR_FMT(NetworkError, "Unexpected response from {}", source);
+
+    const types::usize bodyStart = response.find("\r\n\r\n");
+
+    if (bodyStart == types::StringView::npos)
+      ERR_FMT(ParseError, "Malformed response from {}", source);
+
+    types::String body(response.substr(bodyStart + 4));
+
+    if (const types::usize end = body.find_last_not_of(" \t\r\n"); end != types::String::npos)
+      body.erase(end + 1);
+    else
+      body.clear();
+
+    if (const types::usize start = body.find_first_not_of(" \t\r\n"); start != types::String::npos)
+      body.erase(0, start);
+
+    return Response { .status = status, .body = std::move(body) };
+  }
+
+  /**
+   * @brief Sends a fully formatted request over a connected socket and reads the response.
+   * @details Takes ownership of the socket and closes it before returning.
+   * @param sock A connected, blocking socket.
+   * @param request The request, including the terminating blank line.
+   * @param source Name of the endpoint, used in error messages.
+   * @param maxResponse Responses are cut off after this many bytes.
+   */
+  [[nodiscard]] inline auto Exchange(const Socket sock, const types::StringView request, const types::StringView source, const types::usize maxResponse) -> types::Result<Response> {
+    if (send(sock, request.data(), static_cast<types::i32>(request.size()), 0) != static_cast<types::i32>(request.size())) {
+      CloseSocket(sock);
+      ERR_FMT(NetworkError, "Failed to send request to {}", source);
+    }
+
+    types::String           response;
+    types::Array<char, 512> buffer {};
+
+    while (response.size() < maxResponse) {
+      const auto received = recv(sock, buffer.data(), static_cast<types::i32>(buffer.size()), 0);
+
+      if (received <= 0)
+        break;
+
+      response.append(buffer.data(), static_cast<types::usize>(received));
+    }
+
+    CloseSocket(sock);
+
+    return ParseResponse(response, source);
+  }
+
+  /**
+   * @brief Connects to port 80 of a host over the given address family, giving up after the timeout.
+   * @param host Host name or numeric address.
+   * @param family AF_INET, AF_INET6, or AF_UNSPEC for whichever resolves.
+   * @param timeout How long to wait for each candidate address to accept the connection.
+   * @return A connected, blocking socket with the receive timeout applied.
+   */
+  [[nodiscard]] inline auto ConnectWithTimeout(const types::PCStr host, const types::i32 family, const std::chrono::milliseconds timeout) -> types::Result<Socket> {
+    addrinfo hints {};
+    hints.ai_family   = family;
+    hints.ai_socktype = SOCK_STREAM;
+
+    addrinfo* addresses = nullptr;
+
+    if (getaddrinfo(host, "80", &hints, &addresses) != 0 || addresses == nullptr)
+      ERR_FMT(NetworkError, "Failed to resolve {}", host);
+
+    types::UniquePointer<addrinfo, decltype(&freeaddrinfo)> addressesGuard(addresses, &freeaddrinfo);
+
+    for (const addrinfo* addr = addresses; addr != nullptr; addr = addr->ai_next) {
+      const Socket sock = socket(addr->ai_family, addr->ai_socktype, addr->ai_protocol);
+
+      if (sock == INVALID_SOCKET_HANDLE)
+        continue;
+
+      // Connect in non-blocking mode so an unreachable address (e.g. no IPv6 route) fails fast.
+      if (!SetNonBlocking(sock, true)) {
+        CloseSocket(sock);
+        continue;
+      }
+
+      connect(sock, addr->ai_addr, static_cast<socklen_t>(addr->ai_addrlen));
+
+      fd_set writeSet;
+      FD_ZERO(&writeSet);
+      FD_SET(sock, &writeSet);
+
+      timeval selectTimeout {
+        .tv_sec  = static_cast<decltype(timeval::tv_sec)>(timeout.count() / 1000),
+        .tv_usec = static_cast<decltype(timeval::tv_usec)>((timeout.count() % 1000) * 1000),
+      };
+
+      types::i32 socketError = 0;
+      socklen_t  errorLength = sizeof(socketError);
+
+      const bool connected =
+        select(static_cast<types::i32>(sock) + 1, nullptr, &writeSet, nullptr, &selectTimeout) == 1 &&
+        getsockopt(sock, SOL_SOCKET, SO_ERROR, reinterpret_cast<char*>(&socketError), &errorLength) == 0 &&
+        socketError == 0;
+
+      if (connected && SetNonBlocking(sock, false)) {
+        SetReceiveTimeout(sock, timeout);
+        return sock;
+      }
+
+      CloseSocket(sock);
+    }
+
+    ERR_FMT(NetworkError, "Failed to connect to {}", host);
+  }
+
+  /**
+   * @brief Sends an HTTP/1.0 request and reads the whole response.
+   * @param host Host name or numeric address; also sent as the Host header.
+   * @param method Request method, e.g. "GET" or "PUT".
+   * @param path Request target, e.g. "/latest/meta-data/instance-type".
+   * @param headers Extra header lines, each terminated by "\r\n".
+   * @param family AF_INET, AF_INET6, or AF_UNSPEC for whichever resolves.
+   * @param timeout Connect and receive timeout.
+   * @return The status code and body. Non-2xx statuses are returned, not treated as errors.
+   */
+  [[nodiscard]] inline auto Request(
+    const types::PCStr              host,
+    const types::StringView         method,
+    const types::StringView         path,
+    const types::StringView         headers = {},
+    const types::i32                family  = AF_UNSPEC,
+    const std::chrono::milliseconds timeout = DEFAULT_TIMEOUT
+  ) -> types::Result<Response> {
+    const Socket sock = TRY(ConnectWithTimeout(host, family, timeout));
+
+    // HTTP/1.0 keeps the response unchunked and closes the connection when done.
+    const types::String request = std::format(
+      "{} {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: draconis++\r\n{}{}\r\n",
+      method,
+      path,
+      host,
+      method == "GET" ? "" : "Content-Length: 0\r\n",
+      headers
+    );
+
+    return Exchange(sock, request, host, MAX_RESPONSE);
+  }
+
+#ifndef _WIN32
+  /**
+   * @brief Connects to a Unix domain socket.
+   * @param socketPath Filesystem path of the socket, e.g. "/var/run/docker.sock".
+   * @param timeout Receive timeout applied to the connected socket.
+   * @return A connected, blocking socket.
+   */
+  [[nodiscard]] inline auto ConnectUnix(const types::StringView socketPath, const std::chrono::milliseconds timeout) -> types::Result<Socket> {
+    sockaddr_un address {};
+    address.sun_family = AF_UNIX;
+
+    if (socketPath.size() >= sizeof(address.sun_path))
+      ERR_FMT(InvalidArgument, "Socket path {} is too long", socketPath);
+
+    std::memcpy(static_cast<char*>(address.sun_path), socketPath.data(), socketPath.size());
+
+    const Socket sock = socket(AF_UNIX, SOCK_STREAM, 0);
+
+    if (sock == INVALID_SOCKET_HANDLE)
+      ERR_FMT(NetworkError, "Failed to create a socket for {}", socketPath);
+
+    // Connecting to a Unix socket never blocks for long, so only the read needs bounding.
+    // NOLINTNEXTLINE(*-pro-type-reinterpret-cast) - connect takes the generic sockaddr type.
+    if (connect(sock, reinterpret_cast<const sockaddr*>(&address), sizeof(address)) != 0) {
+      const types::i32 error = errno;
+      CloseSocket(sock);
+
+      if (error == EACCES)
+        ERR_FMT(PermissionDenied, "Permission denied connecting to {}", socketPath);
+
+      ERR_FMT(NotFound, "Nothing is listening on {}", socketPath);
+    }
+
+    SetReceiveTimeout(sock, timeout);
+
+    return sock;
+  }
+
+  /**
+   * @brief Sends an HTTP/1.0 GET request over a Unix domain socket and reads the whole response.
+   * @param socketPath Filesystem path of the socket, e.g. "/var/run/docker.sock".
+   * @param path Request target, e.g. "/containers/json".
+   * @param maxResponse Responses are cut off after this many bytes.
+   * @param timeout Receive timeout.
+   * @return The status code and body. Non-2xx statuses are returned, not treated as errors.
+   */
+  [[nodiscard]] inline auto RequestUnix(
+    const types::StringView         socketPath,
+    const types::StringView         path,
+    const types::usize              maxResponse = MAX_RESPONSE,
+    const std::chrono::milliseconds timeout     = DEFAULT_TIMEOUT
+  ) -> types::Result<Response> {
+    const Socket sock = TRY(ConnectUnix(socketPath, timeout));
+
+    const types::String request = std::format("GET {} HTTP/1.0\r\nHost: localhost\r\nUser-Agent: draconis++\r\n\r\n", path);
+
+    return Exchange(sock, request, socketPath, maxResponse);
+  }
+
+  /**
+   * @brief Sends an HTTP/1.0 POST request over a Unix domain socket and reads the whole response.
+   * @param socketPath Filesystem path of the socket, e.g. "/run/cups/cups.sock".
+   * @param path Request target, e.g. "/".
+   * @param contentType Media type of the body, e.g. "application/ipp".
+   * @param body Request body; may contain binary data.
+   * @param maxResponse Responses are cut off after this many bytes.
+   * @param timeout Receive timeout.
+   * @return The status code and body. Non-2xx statuses are returned, not treated as errors.
+   */
+  [[nodiscard]] inline auto PostUnix(
+    const types::StringView         socketPath,
+    const types::StringView         path,
+    const types::StringView         contentType,
+    const types::StringView         body,
+    const types::usize              maxResponse = MAX_RESPONSE,
+    const std::chrono::milliseconds timeout     = DEFAULT_TIMEOUT
+  ) -> types::Result<Response> {
+    const Socket sock = TRY(ConnectUnix(socketPath, timeout));
+
+    types::String request = std::format(
+      "POST {} HTTP/1.0\r\nHost: localhost\r\nUser-Agent: draconis++\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n",
+      path,
+      contentType,
+      body.size()
+    );
+
+    request.append(body);
+
+    return Exchange(sock, request, socketPath, maxResponse);
+  }
+#endif
+} // namespace draconis::services::http
@@ -12,8 +12,15 @@
     #include <pugixml.hpp> // pugi::{xml_document, xml_node, xml_parse_result}
   #endif
 
+  #ifdef __APPLE__
+    #include <CoreFoundation/CFNumber.h>      // CFNumberGetTypeID, CFNumberGetValue
+    #include <CoreFoundation/CFPreferences.h> // CFPreferencesCopyAppValue
+  #endif
+
+  #include <cstdio>       // std::{FILE, fread}, popen, pclose
   #include <filesystem>   // std::filesystem
   #include <matchit.hpp>  // matchit::{match, is, or_, _}
+  #include <sstream>      // std::istringstream
   #include <system_error> // std::{errc, error_code}
 
   #include "Drac++/Utils/Env.hpp"
@@ -115,6 +122,134 @@ namespace {
     });
   }
 
+  #if defined(__linux__) || defined(_WIN32)
+  /**
+   * @brief Runs a shell command and captures its standard output.
+   * @note The command is passed to the shell as-is, so it must never contain untrusted input.
+   */
+  auto CaptureCommandOutput(const char* command) -> Result<String> {
+    #ifdef _WIN32
+    FILE* pipe = _popen(command, "r");
+    #else
+    FILE* pipe = popen(command, "r");
+    #endif
+
+    if (!pipe)
+      ERR_FMT(ApiUnavailable, "Failed to run '{}'", command);
+
+    String            output;
+    Array<char, 4096> buffer {};
+
+    while (const usize bytesRead = std::fread(buffer.data(), 1, buffer.size(), pipe))
+      output.append(buffer.data(), bytesRead);
+
+    // The exit status isn't meaningful here: `dnf check-update` exits with 100 when updates are available.
+    #ifdef _WIN32
+    _pclose(pipe);
+    #else
+    pclose(pipe);
+    #endif
+
+    return output;
+  }
+
+  /**
+   * @brief Counts the lines of a command's output that satisfy a predicate.
+   */
+  template <typename Predicate>
+  auto CountOutputLines(const String& output, Predicate&& predicate) -> u64 {
+    std::istringstream stream(output);
+    String             line;
+    u64                count = 0;
+
+    while (std::getline(stream, line)) {
+      if (line.ends_with('\r'))
+        line.pop_back();
+
+      if (predicate(StringView(line)))
+        count++;
+    }
+
+    return count;
+  }
+  #endif // __linux__ || _WIN32
+
+  #ifdef __linux__
+  auto CountAptUpdates() -> Result<u64> {
+    // A simulated upgrade only reads the local package lists and doesn't need root.
+    const String output = TRY(CaptureCommandOutput("apt-get -s -o Debug::NoLocking=true upgrade 2>/dev/null"));
+
+    return CountOutputLines(output, [](const StringView line) -> bool { return line.starts_with("Inst "); });
+  }
+
+  auto CountDnfUpdates() -> Result<u64> {
+    const String output = TRY(CaptureCommandOutput("dnf -q --cacheonly check-update 2>/dev/null"));
+
+    bool inObsoletes = false;
+
+    // Each update is one "name.arch version repo" line; the obsoletes section repeats packages.
+    return CountOutputLines(output, [&](const StringView line) -> bool {
+      if (line.starts_with("Obsoleting"))
+        inObsoletes = true;
+
+      return !inObsoletes && !line.empty() && !line.starts_with(' ') && line.contains('.');
+    });
+  }
+
+  auto CountPacmanUpdates() -> Result<u64> {
+    const String output = TRY(CaptureCommandOutput("pacman -Qu 2>/dev/null"));
+
+    return CountOutputLines(output, [](const StringView line) -> bool { return !line.empty(); });
+  }
+  #elifdef __APPLE__
+  auto CountSoftwareUpdates() -> Result<u64> {
+    // softwareupdate records the result of its last background check here, which
+    // avoids the network round-trip that `softwareupdate -l` makes.
+    const CFPropertyListRef value = CFPreferencesCopyAppValue(CFSTR("LastRecommendedUpdatesAvailable"), CFSTR("/Library/Preferences/com.apple.SoftwareUpdate"));
+
+    if (!value)
+      ERR(NotFound, "softwareupdate has not recorded any update check");
+
+    const UniquePointer<const Unit, decltype(&CFRelease)> valueDeleter(value, &CFRelease);
+
+    i64 count = 0;
+
+    if (CFGetTypeID(value) != CFNumberGetTypeID() || !CFNumberGetValue(static_cast<CFNumberRef>(value), kCFNumberSInt64Type, &count) || count < 0)
+      ERR(ParseError, "LastRecommendedUpdatesAvailable is not a valid number");
+
+    return static_cast<u64>(count);
+  }
+  #elifdef _WIN32
+  auto CountWinGetUpdates() -> Result<u64> {
+    const String output = TRY(CaptureCommandOutput("winget upgrade --accept-source-agreements --disable-interactivity 2>NUL"));
+
+    bool inTable = false;
+    bool ended   = false;
+
+    // Updates are listed in a table below a "-----" separator, which ends at the first blank line.
+    const u64 count = CountOutputLines(output, [&](const StringView line) -> bool {
+      if (ended)
+        return false;
+
+      if (!inTable) {
+        inTable = line.starts_with("---");
+        return false;
+      }
+
+      if (line.empty() || line.ends_with("available.")) {
+        ended = true;
+        return false;
+      }
+
+      return true;
+    });
+
+    if (!inTable)
+      ERR(NotFound, "winget did not report an upgrade table");
+
+    return count;
+  }
+  #endif
 } // namespace
 
 namespace draconis::services::packages {
@@ -337,6 +472,45 @@ namespace draconis::services::packages {
     return totalCount;
   }
 
+  auto GetPendingUpdateCount(CacheManager& cache) -> Result<u64> {
+    using draconis::utils::cache::CacheLocation, draconis::utils::cache::CachePolicy;
+
+    const CachePolicy policy { .location = CacheLocation::Persistent, .ttl = std::chrono::hours(1) };
+
+    return cache.getOrSet<u64>("pkg_pending_updates", policy, []() -> Result<u64> {
+  #ifdef __linux__
+      u64  totalCount   = 0;
+      bool oneSucceeded = false;
+
+      const auto processResult = [&](const fs::path& binary, Result<u64> (*counter)()) -> void {
+        if (std::error_code errc; !fs::exists(binary, errc))
+          return;
+
+        if (const Result<u64> result = counter()) {
+          totalCount += *result;
+          oneSucceeded = true;
+        } else
+          debug_at(result.error());
+      };
+
+      processResult("/usr/bin/apt-get", CountAptUpdates);
+      processResult("/usr/bin/dnf", CountDnfUpdates);
+      processResult("/usr/bin/pacman", CountPacmanUpdates);
+
+      if (!oneSucceeded)
+        ERR(UnavailableFeature, "No supported package manager found for checking updates (apt, dnf, or pacman)");
+
+      return totalCount;
+  #elifdef __APPLE__
+      return CountSoftwareUpdates();
+  #elifdef _WIN32
+      return CountWinGetUpdates();
+  #else
+      ERR(NotSupported, "Checking for pending updates is not supported on this platform");
+  #endif
+    });
+  }
+
   auto GetIndividualCounts(CacheManager& cache, const Manager enabledPackageManagers) -> Result<Map<String, u64>> {
     Map<String, u64> individualCounts;
     bool             oneSucceeded = false;
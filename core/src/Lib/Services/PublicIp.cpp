@@ -0,0 +1,88 @@
+#if DRAC_ENABLE_PUBLICIP
+
+  #ifdef _WIN32
+    #include <winsock2.h> // WSAStartup, WSACleanup
+    #include <ws2tcpip.h> // inet_pton
+  #else
+    #include <arpa/inet.h>  // inet_pton
+    #include <sys/socket.h> // AF_INET, AF_INET6
+  #endif
+
+  #include <chrono> // std::chrono::minutes
+
+  #include "Drac++/Services/PublicIp.hpp"
+
+  #include "Drac++/Utils/Error.hpp"
+  #include "Drac++/Utils/Logging.hpp"
+  #include "Drac++/Utils/Types.hpp"
+
+  #include "Services/Http.hpp"
+
+using namespace draconis::utils::types;
+using draconis::utils::cache::CacheManager;
+using enum draconis::utils::error::DracErrorCode;
+
+namespace {
+  /**
+   * @brief Fetches the address a plain-text "what is my IP" service reports for this machine.
+   */
+  auto FetchAddress(const PCStr host, const i32 family) -> Result<String> {
+    const draconis::services::http::Response response = TRY(draconis::services::http::Request(host, "GET", "/", "Accept: text/plain\r\n", family));
+
+    if (response.status != 200)
+      ERR_FMT(NetworkError, "Unexpected response from {}", host);
+
+    const String& address = response.body;
+
+    // Make sure the body really is an address of the requested family before handing it out.
+    Array<u8, 16> parsed {};
+
+    if (address.empty() || inet_pton(family, address.c_str(), parsed.data()) != 1)
+      ERR_FMT(ParseError, "{} did not return a valid address", host);
+
+    return address;
+  }
+} // namespace
+
+namespace draconis::services::network {
+  auto GetPublicIp(CacheManager& cache) -> Result<PublicIp> {
+    using draconis::utils::cache::CacheLocation, draconis::utils::cache::CachePolicy;
+
+    const CachePolicy policy { .location = CacheLocation::TempDirectory, .ttl = std::chrono::minutes(30) };
+
+    return cache.getOrSet<PublicIp>("public_ip", policy, []() -> Result<PublicIp> {
+  #ifdef _WIN32
+      WSADATA wsaData;
+
+      if (WSAStartup(MAKEWORD(2, 2), &wsaData) != 0)
+        ERR(NetworkError, "WSAStartup failed");
+  #endif
+
+      PublicIp publicIp;
+
+      Result<String> v4 = FetchAddress("api.ipify.org", AF_INET);
+      Result<String> v6 = FetchAddress("api6.ipify.org", AF_INET6);
+
+  #ifdef _WIN32
+      WSACleanup();
+  #endif
+
+      if (v4)
+        publicIp.v4 = *std::move(v4);
+      else
+        debug_at(v4.error());
+
+      if (v6)
+        publicIp.v6 = *std::move(v6);
+      else
+        debug_at(v6.error());
+
+      if (!publicIp.v4 && !publicIp.v6)
+        ERR(NetworkError, "Failed to determine the public IP address");
+
+      return publicIp;
+    });
+  }
+} // namespace draconis::services::network
+
+#endif // DRAC_ENABLE_PUBLICIP
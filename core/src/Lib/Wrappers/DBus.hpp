@@ -0,0 +1,384 @@
+#pragma once
+
+#if (defined(__linux__) || defined(__FreeBSD__) || defined(__DragonFly__) || defined(__NetBSD__)) && DRAC_USE_DBUS
+
+  #include <dbus/dbus.h> // D-Bus low-level client library
+  #include <format>      // std::format
+  #include <utility>     // std::exchange
+  #include <variant>     // std::monostate, std::get_if
+
+  #include <Drac++/Utils/Error.hpp>
+  #include <Drac++/Utils/Types.hpp>
+
+namespace dbus {
+  namespace types = draconis::utils::types;
+  namespace error = draconis::utils::error;
+
+  /**
+   * @brief A decoded D-Bus variant value
+   *
+   * Only the basic types needed for reading properties are decoded; every
+   * integer type is widened to i64 and object paths are stored as strings.
+   * Containers and other unsupported types decode to std::monostate.
+   */
+  using Value = types::Variant<std::monostate, bool, types::i64, types::f64, types::String>;
+
+  using Properties     = types::Map<types::String, Value>;      ///< Property name -> value
+  using Interfaces     = types::Map<types::String, Properties>; ///< Interface name -> properties
+  using ManagedObjects = types::Map<types::String, Interfaces>; ///< Object path -> interfaces
+
+  using Message = types::UniquePointer<DBusMessage, decltype(&dbus_message_unref)>; ///< Owned message, unreferenced on destruction
+
+  /**
+   * @brief Get a typed property from a property map
+   *
+   * @tparam T The expected type of the property
+   * @param properties The property map
+   * @param name The name of the property
+   * @return The property value, or None if it is missing or has a different type
+   */
+  template <typename T>
+  [[nodiscard]] inline auto GetProperty(const Properties& properties, const types::StringView name) -> types::Option<T> {
+    const auto iter = properties.find(name);
+
+    if (iter == properties.end())
+      return types::None;
+
+    if (const T* value = std::get_if<T>(&iter->second))
+      return *value;
+
+    return types::None;
+  }
+
+  /**
+   * @brief Decode the value inside a D-Bus variant
+   *
+   * @param variant An iterator positioned on a variant
+   * @return The decoded value
+   */
+  inline auto ReadVariant(DBusMessageIter* variant) -> Value {
+    DBusMessageIter inner;
+    dbus_message_iter_recurse(variant, &inner);
+
+    switch (dbus_message_iter_get_arg_type(&inner)) {
+      case DBUS_TYPE_BOOLEAN: {
+        dbus_bool_t value = FALSE;
+        dbus_message_iter_get_basic(&inner, &value);
+        return value != FALSE;
+      }
+      case DBUS_TYPE_BYTE: {
+        types::u8 value = 0;
+        dbus_message_iter_get_basic(&inner, &value);
+        return static_cast<types::i64>(value);
+      }
+      case DBUS_TYPE_INT16:
+      case DBUS_TYPE_UINT16:
+      case DBUS_TYPE_INT32:
+      case DBUS_TYPE_UINT32:
+      case DBUS_TYPE_INT64:
+      case DBUS_TYPE_UINT64: {
+        // Every integer type fits in the 8-byte basic value union.
+        DBusBasicValue value = {};
+        dbus_message_iter_get_basic(&inner, &value);
+
+        switch (dbus_message_iter_get_arg_type(&inner)) {
+          case DBUS_TYPE_INT16:  return static_cast<types::i64>(value.i16);
+          case DBUS_TYPE_UINT16: return static_cast<types::i64>(value.u16);
+          case DBUS_TYPE_INT32:  return static_cast<types::i64>(value.i32);
+          case DBUS_TYPE_UINT32: return static_cast<types::i64>(value.u32);
+          case DBUS_TYPE_INT64:  return value.i64;
+          default:               return static_cast<types::i64>(value.u64);
+        }
+      }
+      case DBUS_TYPE_DOUBLE: {
+        double value = 0.0;
+        dbus_message_iter_get_basic(&inner, &value);
+        return value;
+      }
+      case DBUS_TYPE_STRING:
+      case DBUS_TYPE_OBJECT_PATH: {
+        types::PCStr value = nullptr;
+        dbus_message_iter_get_basic(&inner, &value);
+        return value ? types::String(value) : types::String();
+      }
+      default:
+        return std::monostate {};
+    }
+  }
+
+  /**
+   * @brief Decode an a{sv} property dictionary
+   *
+   * @param array An iterator positioned on the dictionary
+   * @return The decoded properties
+   */
+  inline auto ReadProperties(DBusMessageIter* array) -> Properties {
+    Properties properties;
+
+    DBusMessageIter entries;
+    dbus_message_iter_recurse(array, &entries);
+
+    while (dbus_message_iter_get_arg_type(&entries) == DBUS_TYPE_DICT_ENTRY) {
+      DBusMessageIter entry;
+      dbus_message_iter_recurse(&entries, &entry);
+
+      types::PCStr name = nullptr;
+      dbus_message_iter_get_basic(&entry, &name);
+
+      if (name && dbus_message_iter_next(&entry))
+        properties.emplace(name, ReadVariant(&entry));
+
+      dbus_message_iter_next(&entries);
+    }
+
+    return properties;
+  }
+
+  /**
+   * @brief Create a method call message
+   *
+   * @param service The bus name of the service, e.g. "org.freedesktop.systemd1"
+   * @param path The object path to call the method on
+   * @param interface The interface declaring the method
+   * @param method The method name
+   * @return The message, to which arguments can be appended before sending it with Connection::call
+   */
+  [[nodiscard]] inline auto NewMethodCall(const types::PCStr service, const types::PCStr path, const types::PCStr interface, const types::PCStr method) -> types::Result<Message> {
+    DBusMessage* call = dbus_message_new_method_call(service, path, interface, method);
+
+    if (!call)
+      return types::Err(error::DracError(error::DracErrorCode::OutOfMemory, "Failed to allocate D-Bus method call"));
+
+    return Message(call, &dbus_message_unref);
+  }
+
+  /**
+   * @brief Append a string array (as) argument
+   *
+   * @param args An iterator initialized with dbus_message_iter_init_append
+   * @param strings The strings to append
+   * @return Whether the argument was appended (false means out of memory)
+   */
+  inline auto AppendStringArray(DBusMessageIter* args, const types::Span<const types::PCStr> strings) -> bool {
+    DBusMessageIter array;
+
+    if (!dbus_message_iter_open_container(args, DBUS_TYPE_ARRAY, DBUS_TYPE_STRING_AS_STRING, &array))
+      return false;
+
+    for (types::PCStr string : strings)
+      if (!dbus_message_iter_append_basic(&array, DBUS_TYPE_STRING, &string)) {
+        dbus_message_iter_abandon_container(args, &array);
+        return false;
+      }
+
+    return dbus_message_iter_close_container(args, &array) != FALSE;
+  }
+
+  /**
+   * RAII wrapper for a shared D-Bus bus connection
+   * Drops the library's reference on destruction
+   */
+  class Connection {
+    DBusConnection* m_connection = nullptr; ///< The shared bus connection
+
+    explicit Connection(DBusConnection* connection)
+      : m_connection(connection) {}
+
+    /**
+     * @brief Connect to one of the well-known buses
+     * @param type The bus to connect to
+     * @param busName The bus name used in error messages (e.g. "system")
+     * @return The connection, or an error if the bus is unavailable
+     */
+    static auto connect(const DBusBusType type, const types::StringView busName) -> types::Result<Connection> {
+      DBusError err;
+      dbus_error_init(&err);
+
+      DBusConnection* connection = dbus_bus_get(type, &err);
+
+      if (dbus_error_is_set(&err)) {
+        types::String message = err.message ? err.message : "unknown error";
+        dbus_error_free(&err);
+
+        if (connection)
+          dbus_connection_unref(connection);
+
+        return types::Err(error::DracError(error::DracErrorCode::ApiUnavailable, std::format("Failed to connect to the D-Bus {} bus: {}", busName, message)));
+      }
+
+      if (!connection)
+        return types::Err(error::DracError(error::DracErrorCode::ApiUnavailable, std::format("Failed to connect to the D-Bus {} bus", busName)));
+
+      // The connection is shared with the rest of the process; a bus disconnect must not kill the host application.
+      dbus_connection_set_exit_on_disconnect(connection, FALSE);
+
+      return Connection(connection);
+    }
+
+   public:
+    /**
+     * @brief Connect to the system bus
+     * @return The connection, or an error if the bus is unavailable
+     */
+    static auto system() -> types::Result<Connection> {
+      return connect(DBUS_BUS_SYSTEM, "system");
+    }
+
+    /**
+     * @brief Connect to the current user's session bus
+     * @return The connection, or an error if the bus is unavailable
+     */
+    static auto session() -> types::Result<Connection> {
+      return connect(DBUS_BUS_SESSION, "session");
+    }
+
+    ~Connection() {
+      if (m_connection)
+        dbus_connection_unref(m_connection);
+    }
+
+    // Non-copyable
+    Connection(const Connection&)                    = delete;
+    auto operator=(const Connection&) -> Connection& = delete;
+
+    // Movable
+    Connection(Connection&& other) noexcept
+      : m_connection(std::exchange(other.m_connection, nullptr)) {}
+
+    /**
+     * @brief Move assignment operator
+     * @param other The other connection
+     * @return The moved connection
+     */
+    auto operator=(Connection&& other) noexcept -> Connection& {
+      if (this != &other) {
+        if (m_connection)
+          dbus_connection_unref(m_connection);
+
+        m_connection = std::exchange(other.m_connection, nullptr);
+      }
+      return *this;
+    }
+
+    /**
+     * @brief Get the underlying connection
+     * @return The underlying connection
+     */
+    [[nodiscard]] auto get() const -> DBusConnection* {
+      return m_connection;
+    }
+
+    /**
+     * @brief Send a method call and wait for its reply
+     *
+     * @param request The method call, with its arguments already appended
+     * @param timeoutMs How long to wait for the reply
+     * @return The reply, or an error if the call failed or the service returned an error
+     */
+    [[nodiscard]] auto call(DBusMessage* request, const types::i32 timeoutMs = 1000) const -> types::Result<Message> {
+      DBusError err;
+      dbus_error_init(&err);
+
+      DBusMessage* reply = dbus_connection_send_with_reply_and_block(m_connection, request, timeoutMs, &err);
+
+      const types::PCStr member      = dbus_message_get_member(request);
+      const types::PCStr destination = dbus_message_get_destination(request);
+
+      if (dbus_error_is_set(&err)) {
+        types::String message = err.message ? err.message : "unknown error";
+        dbus_error_free(&err);
+
+        if (reply)
+          dbus_message_unref(reply);
+
+        return types::Err(error::DracError(error::DracErrorCode::ApiUnavailable, std::format("{} on {} failed: {}", member ? member : "?", destination ? destination : "?", message)));
+      }
+
+      if (!reply)
+        return types::Err(error::DracError(error::DracErrorCode::ApiUnavailable, std::format("{} on {} returned no reply", member ? member : "?", destination ? destination : "?")));
+
+      return Message(reply, &dbus_message_unref);
+    }
+
+    /**
+     * @brief Call org.freedesktop.DBus.Properties.Get for a single property
+     *
+     * @param service The bus name of the service, e.g. "org.freedesktop.UPower"
+     * @param path The object path exposing the property
+     * @param interface The interface declaring the property
+     * @param name The property name
+     * @param timeoutMs How long to wait for the reply
+     * @return The decoded property value
+     */
+    [[nodiscard]] auto getProperty(const types::PCStr service, const types::PCStr path, const types::PCStr interface, const types::PCStr name, const types::i32 timeoutMs = 1000) const -> types::Result<Value> {
+      const Message request = TRY(NewMethodCall(service, path, "org.freedesktop.DBus.Properties", "Get"));
+
+      if (!dbus_message_append_args(request.get(), DBUS_TYPE_STRING, &interface, DBUS_TYPE_STRING, &name, DBUS_TYPE_INVALID))
+        return types::Err(error::DracError(error::DracErrorCode::OutOfMemory, "Failed to append D-Bus method arguments"));
+
+      const Message reply = TRY(call(request.get(), timeoutMs));
+
+      DBusMessageIter root;
+      if (!dbus_message_iter_init(reply.get(), &root) || dbus_message_iter_get_arg_type(&root) != DBUS_TYPE_VARIANT)
+        return types::Err(error::DracError(error::DracErrorCode::ParseError, std::format("Get({}) on {} returned no variant", name, service)));
+
+      return ReadVariant(&root);
+    }
+
+    /**
+     * @brief Call org.freedesktop.DBus.ObjectManager.GetManagedObjects on a service
+     *
+     * @param service The bus name of the service, e.g. "org.bluez"
+     * @param path The object path exposing the object manager
+     * @param timeoutMs How long to wait for the reply
+     * @return Every object the service manages, with its interfaces and properties
+     */
+    [[nodiscard]] auto getManagedObjects(const types::PCStr service, const types::PCStr path = "/", const types::i32 timeoutMs = 1000) const -> types::Result<ManagedObjects> {
+      const Message request = TRY(NewMethodCall(service, path, "org.freedesktop.DBus.ObjectManager", "GetManagedObjects"));
+      const Message reply   = TRY(call(request.get(), timeoutMs));
+
+      ManagedObjects objects;
+
+      DBusMessageIter root;
+      if (dbus_message_iter_init(reply.get(), &root) && dbus_message_iter_get_arg_type(&root) == DBUS_TYPE_ARRAY) {
+        DBusMessageIter objectEntries;
+        dbus_message_iter_recurse(&root, &objectEntries);
+
+        // a{oa{sa{sv}}}
+        while (dbus_message_iter_get_arg_type(&objectEntries) == DBUS_TYPE_DICT_ENTRY) {
+          DBusMessageIter objectEntry;
+          dbus_message_iter_recurse(&objectEntries, &objectEntry);
+
+          types::PCStr objectPath = nullptr;
+          dbus_message_iter_get_basic(&objectEntry, &objectPath);
+
+          if (objectPath && dbus_message_iter_next(&objectEntry)) {
+            Interfaces& interfaces = objects[objectPath];
+
+            DBusMessageIter interfaceEntries;
+            dbus_message_iter_recurse(&objectEntry, &interfaceEntries);
+
+            while (dbus_message_iter_get_arg_type(&interfaceEntries) == DBUS_TYPE_DICT_ENTRY) {
+              DBusMessageIter interfaceEntry;
+              dbus_message_iter_recurse(&interfaceEntries, &interfaceEntry);
+
+              types::PCStr interfaceName = nullptr;
+              dbus_message_iter_get_basic(&interfaceEntry, &interfaceName);
+
+              if (interfaceName && dbus_message_iter_next(&interfaceEntry))
+                interfaces.emplace(interfaceName, ReadProperties(&interfaceEntry));
+
+              dbus_message_iter_next(&interfaceEntries);
+            }
+          }
+
+          dbus_message_iter_next(&objectEntries);
+        }
+      }
+
+      return objects;
+    }
+  };
+} // namespace dbus
+
+#endif // (defined(__linux__) || defined(__FreeBSD__) || defined(__DragonFly__) || defined(__NetBSD__)) && DRAC_USE_DBUS
@@ -9,6 +9,9 @@
   #include <Drac++/Utils/Logging.hpp>
   #include <Drac++/Utils/Types.hpp>
 
+  #include "OS/Edid.hpp"
+  #include "OS/Unix.hpp"
+
 namespace wl {
   namespace types = draconis::utils::types;
 
@@ -301,17 +304,22 @@ namespace wl {
       };
 
       AddRegistryListener(registry, &REGISTRY_LISTENER, this);
+      // The first roundtrip announces the globals; the second delivers the events of the outputs bound during it.
+      Roundtrip(m_display);
       Roundtrip(m_display);
       DestroyRegistry(registry);
 
       types::Vec<types::DisplayInfo> displays;
       for (const auto& output : m_callbackData.outputs) {
-        displays.emplace_back(
+        types::DisplayInfo& display = displays.emplace_back(
           output.id,
           types::DisplayInfo::Resolution { .width = output.width, .height = output.height },
           output.refreshRate / 1000.0,
           displays.empty()
         );
+
+        applyOutputDetails(display, output.details);
+        DestroyOutput(output.output);
       }
 
       return displays;
@@ -345,15 +353,31 @@ namespace wl {
    private:
     Display* m_display; ///< The Wayland display object
 
+    /**
+     * @brief Data from the geometry, scale and name events of an output
+     */
+    struct OutputDetails {
+      types::i32    x             = 0;
+      types::i32    y             = 0;
+      types::i32    physicalWidth = 0;
+      types::i32    transform     = WL_OUTPUT_TRANSFORM_NORMAL;
+      types::i32    scale         = 1;
+      types::String name;  ///< Connector name (wl_output v4), e.g. "DP-1"
+      types::String make;  ///< Manufacturer as reported by the compositor
+      types::String model; ///< Model as reported by the compositor
+    };
+
     /**
      * @brief Data for display callbacks
      */
     struct CallbackData {
       struct Inner {
-        types::usize id;
-        types::usize width;
-        types::usize height;
-        types::f64   refreshRate;
+        Output*       output;
+        types::usize  id;
+        types::usize  width;
+        types::usize  height;
+        types::f64    refreshRate;
+        OutputDetails details;
       };
 
       types::Vec<Inner> outputs;
@@ -367,11 +391,74 @@ namespace wl {
     struct PrimaryDisplayData {
       Output*            output = nullptr;
       types::DisplayInfo display;
+      OutputDetails      details;
       bool               done = false;
     };
 
     PrimaryDisplayData m_primaryDisplayData; ///< Data for the primary output
 
+    /**
+     * @brief Fill in the fields of a DisplayInfo that come from the geometry, scale and name events
+     *
+     * @param display The display to update (its resolution must already be set)
+     * @param details The collected output details
+     */
+    static auto applyOutputDetails(types::DisplayInfo& display, const OutputDetails& details) -> types::Unit {
+      display.position    = { .x = details.x, .y = details.y };
+      display.scaleFactor = details.scale > 0 ? static_cast<types::f64>(details.scale) : 1.0;
+      // Transforms rotate counter-clockwise (values 4-7 are the flipped variants of 0-3); DisplayInfo reports clockwise degrees.
+      display.rotation  = static_cast<types::u16>((360 - ((details.transform % 4) * 90)) % 360);
+      display.dpi       = draconis::os::unix_shared::DpiFromPhysicalSize(display.resolution.width, details.physicalWidth > 0 ? static_cast<types::u32>(details.physicalWidth) : 0);
+      display.connector = draconis::os::unix_shared::ConnectorFromOutputName(details.name);
+
+      // Compositors without EDID access send placeholders such as "Unknown".
+      const auto reported = [](const types::String& value) -> types::Option<types::String> {
+        if (value.empty() || value == "Unknown" || value == "unknown")
+          return types::None;
+
+        return value;
+      };
+
+      display.manufacturer = reported(details.make);
+      display.model        = reported(details.model);
+
+  #ifdef __linux__
+      // The connector name matches the DRM connector, whose EDID also carries the serial number and color capabilities.
+      if (details.name.empty())
+        return;
+
+      const types::Option<types::Vec<types::u8>> edid = draconis::os::unix_shared::ReadDrmEdid(details.name);
+
+      if (!edid)
+        return;
+
+      if (const types::Option<draconis::os::edid::Identity> identity = draconis::os::edid::ParseIdentity(*edid)) {
+        display.manufacturer = identity->manufacturer ? identity->manufacturer : display.manufacturer;
+        display.model        = identity->model ? identity->model : display.model;
+        display.serial       = identity->serial;
+      }
+
+      if (const types::Option<draconis::os::edid::ColorCapabilities> color = draconis::os::edid::ParseColorCapabilities(*edid)) {
+        display.gamut        = color->gamut;
+        display.hdrSupported = color->hdrSupported;
+      }
+  #endif
+    }
+
+    /**
+     * @brief Find the record for a bound output
+     *
+     * @param output The Wayland output object
+     * @return The matching record, or nullptr if the output isn't tracked
+     */
+    auto findOutput(const Output* output) -> CallbackData::Inner* {
+      for (CallbackData::Inner& inner : m_callbackData.outputs)
+        if (inner.output == output)
+          return &inner;
+
+      return nullptr;
+    }
+
     /**
      * @brief Wayland output mode callback
      *
@@ -380,15 +467,14 @@ namespace wl {
      * @param height The height of the output
      * @param refresh The refresh rate of the output
      */
-    auto outputMode(types::u32 flags, types::i32 width, types::i32 height, types::i32 refresh) -> types::Unit {
+    auto outputMode(const Output* output, types::u32 flags, types::i32 width, types::i32 height, types::i32 refresh) -> types::Unit {
       if (!(flags & WL_OUTPUT_MODE_CURRENT))
         return;
 
-      if (!m_callbackData.outputs.empty()) {
-        CallbackData::Inner& currentOutput = m_callbackData.outputs.back();
-        currentOutput.width                = width > 0 ? width : 0;
-        currentOutput.height               = height > 0 ? height : 0;
-        currentOutput.refreshRate          = refresh > 0 ? refresh : 0;
+      if (CallbackData::Inner* currentOutput = findOutput(output)) {
+        currentOutput->width       = width > 0 ? width : 0;
+        currentOutput->height      = height > 0 ? height : 0;
+        currentOutput->refreshRate = refresh > 0 ? refresh : 0;
       }
     }
 
@@ -402,8 +488,55 @@ namespace wl {
      * @param height The height of the output
      * @param refresh The refresh rate of the output
      */
-    static auto outputMode(types::RawPointer data, wl_output* /*output*/, types::u32 flags, types::i32 width, types::i32 height, types::i32 refresh) -> types::Unit {
-      static_cast<DisplayManager*>(data)->outputMode(flags, width, height, refresh);
+    static auto outputMode(types::RawPointer data, wl_output* output, types::u32 flags, types::i32 width, types::i32 height, types::i32 refresh) -> types::Unit {
+      static_cast<DisplayManager*>(data)->outputMode(output, flags, width, height, refresh);
+    }
+
+    /**
+     * @brief Static Wayland output geometry callback
+     *
+     * @param data The user data
+     * @param output The Wayland output object
+     * @param x The horizontal position in the global compositor space
+     * @param y The vertical position in the global compositor space
+     * @param physicalWidth The physical width in millimeters
+     * @param make The manufacturer
+     * @param model The model
+     * @param transform The output transform
+     */
+    static auto outputGeometry(types::RawPointer data, wl_output* output, types::i32 x, types::i32 y, types::i32 physicalWidth, types::i32 /*physicalHeight*/, types::i32 /*subpixel*/, types::PCStr make, types::PCStr model, types::i32 transform) -> types::Unit {
+      if (CallbackData::Inner* currentOutput = static_cast<DisplayManager*>(data)->findOutput(output)) {
+        currentOutput->details.x             = x;
+        currentOutput->details.y             = y;
+        currentOutput->details.physicalWidth = physicalWidth;
+        currentOutput->details.transform     = transform;
+        currentOutput->details.make          = make ? make : "";
+        currentOutput->details.model         = model ? model : "";
+      }
+    }
+
+    /**
+     * @brief Static Wayland output scale callback
+     *
+     * @param data The user data
+     * @param output The Wayland output object
+     * @param factor The integer buffer scale
+     */
+    static auto outputScale(types::RawPointer data, wl_output* output, types::i32 factor) -> types::Unit {
+      if (CallbackData::Inner* currentOutput = static_cast<DisplayManager*>(data)->findOutput(output))
+        currentOutput->details.scale = factor;
+    }
+
+    /**
+     * @brief Static Wayland output name callback (wl_output v4)
+     *
+     * @param data The user data
+     * @param output The Wayland output object
+     * @param name The output name (e.g. "DP-1")
+     */
+    static auto outputName(types::RawPointer data, wl_output* output, types::PCStr name) -> types::Unit {
+      if (CallbackData::Inner* currentOutput = static_cast<DisplayManager*>(data)->findOutput(output); currentOutput && name)
+        currentOutput->details.name = name;
     }
 
     /**
@@ -422,22 +555,22 @@ namespace wl {
         registry,
         objectId,
         &wl_output_interface,
-        std::min(version, 2U)
+        std::min(version, 4U)
       ));
 
       if (!output)
         return;
 
       const static OutputListener OUTPUT_LISTENER = {
-        .geometry    = nullptr,
+        .geometry    = outputGeometry,
         .mode        = outputMode,
-        .done        = nullptr,
-        .scale       = nullptr,
-        .name        = nullptr,
-        .description = nullptr
+        .done        = [](types::RawPointer, wl_output*) {},
+        .scale       = outputScale,
+        .name        = outputName,
+        .description = [](types::RawPointer, wl_output*, types::PCStr) {}
       };
 
-      m_callbackData.outputs.push_back({ .id = objectId, .width = 0, .height = 0, .refreshRate = 0.0 });
+      m_callbackData.outputs.push_back({ .output = output, .id = objectId, .width = 0, .height = 0, .refreshRate = 0.0 });
       AddOutputListener(output, &OUTPUT_LISTENER, this);
     }
 
@@ -488,8 +621,12 @@ namespace wl {
      * @brief Wayland primary display done callback
      */
     auto primaryDone() -> types::Unit {
-      if (m_primaryDisplayData.display.resolution.width > 0)
-        m_primaryDisplayData.done = true;
+      if (m_primaryDisplayData.display.resolution.width == 0)
+        return;
+
+      applyOutputDetails(m_primaryDisplayData.display, m_primaryDisplayData.details);
+
+      m_primaryDisplayData.done = true;
     }
 
     /**
@@ -502,8 +639,51 @@ namespace wl {
       static_cast<DisplayManager*>(data)->primaryDone();
     }
 
-    static auto primaryScale(types::RawPointer /*data*/, wl_output* /*wl_output*/, types::i32 /*scale*/) -> types::Unit {}
-    static auto primaryGeometry(void* /*data*/, struct wl_output* /*wl_output*/, int32_t /*x*/, int32_t /*y*/, int32_t /*physical_width*/, int32_t /*physical_height*/, int32_t /*subpixel*/, const char* /*make*/, const char* /*model*/, int32_t /*transform*/) -> types::Unit {}
+    /**
+     * @brief Static Wayland primary display scale callback
+     *
+     * @param data The user data
+     * @param wl_output The Wayland output object
+     * @param scale The integer buffer scale
+     */
+    static auto primaryScale(types::RawPointer data, wl_output* /*wl_output*/, types::i32 scale) -> types::Unit {
+      static_cast<DisplayManager*>(data)->m_primaryDisplayData.details.scale = scale;
+    }
+
+    /**
+     * @brief Static Wayland primary display geometry callback
+     *
+     * @param data The user data
+     * @param wl_output The Wayland output object
+     * @param x The horizontal position in the global compositor space
+     * @param y The vertical position in the global compositor space
+     * @param physical_width The physical width in millimeters
+     * @param make The manufacturer
+     * @param model The model
+     * @param transform The output transform
+     */
+    static auto primaryGeometry(void* data, struct wl_output* /*wl_output*/, int32_t x, int32_t y, int32_t physical_width, int32_t /*physical_height*/, int32_t /*subpixel*/, const char* make, const char* model, int32_t transform) -> types::Unit {
+      PrimaryDisplayData& primary = static_cast<DisplayManager*>(data)->m_primaryDisplayData;
+
+      primary.details.x             = x;
+      primary.details.y             = y;
+      primary.details.physicalWidth = physical_width;
+      primary.details.transform     = transform;
+      primary.details.make          = make ? make : "";
+      primary.details.model         = model ? model : "";
+    }
+
+    /**
+     * @brief Static Wayland primary display name callback (wl_output v4)
+     *
+     * @param data The user data
+     * @param wl_output The Wayland output object
+     * @param name The output name (e.g. "DP-1")
+     */
+    static auto primaryName(types::RawPointer data, wl_output* /*wl_output*/, types::PCStr name) -> types::Unit {
+      if (name)
+        static_cast<DisplayManager*>(data)->m_primaryDisplayData.details.name = name;
+    }
 
     /**
      * @brief Wayland primary display registry handler
@@ -524,7 +704,7 @@ namespace wl {
         registry,
         name,
         &wl_output_interface,
-        std::min(version, 2U)
+        std::min(version, 4U)
       ));
 
       if (!m_primaryDisplayData.output)
@@ -535,8 +715,8 @@ namespace wl {
         .mode        = primaryMode,
         .done        = primaryDone,
         .scale       = primaryScale,
-        .name        = nullptr,
-        .description = nullptr
+        .name        = primaryName,
+        .description = [](types::RawPointer, wl_output*, types::PCStr) {}
       };
 
       AddOutputListener(m_primaryDisplayData.output, &LISTENER, this);
@@ -16,11 +16,13 @@ namespace xcb {
   using Window     = xcb_window_t;
   using Atom       = xcb_atom_t;
 
-  using GenericError  = xcb_generic_error_t;
-  using IntAtomCookie = xcb_intern_atom_cookie_t;
-  using IntAtomReply  = xcb_intern_atom_reply_t;
-  using GetPropCookie = xcb_get_property_cookie_t;
-  using GetPropReply  = xcb_get_property_reply_t;
+  using GenericError   = xcb_generic_error_t;
+  using IntAtomCookie  = xcb_intern_atom_cookie_t;
+  using IntAtomReply   = xcb_intern_atom_reply_t;
+  using GetPropCookie  = xcb_get_property_cookie_t;
+  using GetPropReply   = xcb_get_property_reply_t;
+  using QueryPtrCookie = xcb_query_pointer_cookie_t;
+  using QueryPtrReply  = xcb_query_pointer_reply_t;
 
   using RandrOutput                          = xcb_randr_output_t;
   using RandrCrtc                            = xcb_randr_crtc_t;
@@ -36,12 +38,21 @@ namespace xcb {
   using RandrGetOutputInfoReply              = xcb_randr_get_output_info_reply_t;
   using RandrGetCrtcInfoCookie               = xcb_randr_get_crtc_info_cookie_t;
   using RandrGetCrtcInfoReply                = xcb_randr_get_crtc_info_reply_t;
+  using RandrGetOutputPropertyCookie         = xcb_randr_get_output_property_cookie_t;
+  using RandrGetOutputPropertyReply          = xcb_randr_get_output_property_reply_t;
   using Timestamp                            = xcb_timestamp_t;
 
   constexpr Atom       ATOM_WINDOW  = XCB_ATOM_WINDOW;  ///< Window atom
+  constexpr Atom       ATOM_ANY     = XCB_ATOM_ANY;     ///< Matches any property type
+  constexpr Atom       ATOM_STRING  = XCB_ATOM_STRING;  ///< Latin-1 string atom
   constexpr Timestamp  CURRENT_TIME = XCB_CURRENT_TIME; ///< Current time for XCB requests
   constexpr types::u32 NONE         = XCB_NONE;         ///< None value for XCB requests
 
+  constexpr types::u16 RANDR_ROTATE_0   = XCB_RANDR_ROTATION_ROTATE_0;   ///< Output is not rotated
+  constexpr types::u16 RANDR_ROTATE_90  = XCB_RANDR_ROTATION_ROTATE_90;  ///< Output is rotated 90 degrees counter-clockwise
+  constexpr types::u16 RANDR_ROTATE_180 = XCB_RANDR_ROTATION_ROTATE_180; ///< Output is rotated 180 degrees
+  constexpr types::u16 RANDR_ROTATE_270 = XCB_RANDR_ROTATION_ROTATE_270; ///< Output is rotated 270 degrees counter-clockwise
+
   /**
    * @brief Enum representing different types of connection errors
    *
@@ -188,6 +199,29 @@ namespace xcb {
     return xcb_get_property_value(reply);
   }
 
+  /**
+   * @brief Query the pointer position and the current modifier/group state
+   *
+   * @param conn The connection object
+   * @param window The window to report the position relative to
+   * @return The cookie for the pointer query
+   */
+  inline auto QueryPointer(Connection* conn, const Window window) -> QueryPtrCookie {
+    return xcb_query_pointer(conn, window);
+  }
+
+  /**
+   * @brief Get the reply for a pointer query
+   *
+   * @param conn The connection object
+   * @param cookie The cookie for the pointer query
+   * @param err The pointer to the generic error
+   * @return The reply for the pointer query
+   */
+  inline auto QueryPointerReply(Connection* conn, const QueryPtrCookie cookie, GenericError** err) -> QueryPtrReply* {
+    return xcb_query_pointer_reply(conn, cookie, err);
+  }
+
   /**
    * @brief Query an XCB extension
    *
@@ -321,6 +355,26 @@ namespace xcb {
     return xcb_randr_get_output_info_reply(conn, cookie, err);
   }
 
+  /**
+   * @brief Get the name of an output (e.g. "HDMI-1") from the output info reply
+   *
+   * @param reply The reply for the output info query
+   * @return The output name (not null-terminated)
+   */
+  inline auto GetOutputInfoName(const RandrGetOutputInfoReply* reply) -> types::u8* {
+    return xcb_randr_get_output_info_name(reply);
+  }
+
+  /**
+   * @brief Get the length of the output name from the output info reply
+   *
+   * @param reply The reply for the output info query
+   * @return The length of the output name
+   */
+  inline auto GetOutputInfoNameLength(const RandrGetOutputInfoReply* reply) -> types::i32 {
+    return xcb_randr_get_output_info_name_length(reply);
+  }
+
   /**
    * @brief Get the CRTC info
    *
@@ -345,6 +399,60 @@ namespace xcb {
     return xcb_randr_get_crtc_info_reply(conn, cookie, err);
   }
 
+  /**
+   * @brief Get an output property
+   *
+   * @param conn The connection object
+   * @param output The output
+   * @param property The property atom
+   * @param type The expected property type (ATOM_ANY for any)
+   * @param long_offset The offset into the property, in 32-bit units
+   * @param long_length The maximum length to read, in 32-bit units
+   * @return The cookie for the output property query
+   */
+  inline auto GetOutputProperty(
+    Connection*       conn,
+    const RandrOutput output,
+    const Atom        property,
+    const Atom        type,
+    const types::u32  long_offset,
+    const types::u32  long_length
+  ) -> RandrGetOutputPropertyCookie {
+    return xcb_randr_get_output_property(conn, output, property, type, long_offset, long_length, 0, 0);
+  }
+
+  /**
+   * @brief Get the reply for an output property
+   *
+   * @param conn The connection object
+   * @param cookie The cookie for the output property query
+   * @param err The pointer to the generic error
+   * @return The reply for the output property query
+   */
+  inline auto GetOutputPropertyReply(Connection* conn, const RandrGetOutputPropertyCookie cookie, GenericError** err) -> RandrGetOutputPropertyReply* {
+    return xcb_randr_get_output_property_reply(conn, cookie, err);
+  }
+
+  /**
+   * @brief Get the data of an output property
+   *
+   * @param reply The reply for the output property query
+   * @return The property data
+   */
+  inline auto GetOutputPropertyData(const RandrGetOutputPropertyReply* reply) -> types::u8* {
+    return xcb_randr_get_output_property_data(reply);
+  }
+
+  /**
+   * @brief Get the length of the data of an output property
+   *
+   * @param reply The reply for the output property query
+   * @return The length of the property data, in bytes for 8-bit properties
+   */
+  inline auto GetOutputPropertyDataLength(const RandrGetOutputPropertyReply* reply) -> types::i32 {
+    return xcb_randr_get_output_property_data_length(reply);
+  }
+
   /**
    * RAII wrapper for X11 Display connections
    * Automatically handles resource acquisition and cleanup
@@ -0,0 +1,14 @@
+/**
+ * @file main.cpp
+ * @brief Helper process that runs a sandboxed plugin for its host
+ */
+
+#include <Drac++/Core/PluginManager.hpp>
+
+#include <Drac++/Utils/Types.hpp>
+
+using draconis::utils::types::i32;
+
+auto main(const i32 argc, char* argv[]) -> i32 {
+  return draconis::core::plugin::RunSandboxHost(argc, argv);
+}
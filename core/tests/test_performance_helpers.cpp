@@ -50,6 +50,14 @@ auto main() -> int {
     expect(PluginFieldToString(value) == "items: 42, value");
   };
 
+  "Timestamp and null plugin fields format"_test = [] -> void {
+    const PluginFieldValue timestamp { PluginTimestamp { std::chrono::milliseconds { 1'700'000'000'000 } } };
+    expect(PluginFieldToString(timestamp) == "2023-11-14T22:13:20Z");
+
+    const PluginFieldValue null { nullptr };
+    expect(PluginFieldToString(null).empty());
+  };
+
   "Cache accepts move-only fetchers"_test = [] -> void {
     CacheManager cache;
     auto         fetcher = [value = std::make_unique<i32>(7)]() -> Result<i32> {
@@ -166,6 +174,16 @@ auto main() -> int {
     expect(freshFetchCount == 1_i);
   };
 
+  "Cache domains match whole keys"_test = [] -> void {
+    expect(GetCacheDomain("macos_outputs") == CacheDomain::Desktop);
+    expect(GetCacheDomain("linux_cpu_details") == CacheDomain::Cpu);
+    expect(GetCacheDomain("pkg_count_nix") == CacheDomain::Packages);
+
+    expect(!GetCacheDomain("pkg_count_").has_value());
+    expect(!GetCacheDomain("shell").has_value());
+    expect(!GetCacheDomain(UniqueCacheKey("linux_cpu_details")).has_value());
+  };
+
   "Temporary disk entries promote into typed memory"_test = [] -> void {
     const String key = UniqueCacheKey("disk_promotion");
     CacheManager firstCache;
@@ -189,6 +207,54 @@ auto main() -> int {
     secondCache.invalidate(key);
   };
 
+  "Disk entries written under the current fingerprint are reused"_test = [] -> void {
+    const String key = UniqueCacheKey("fingerprint_match");
+    CacheManager firstCache;
+    firstCache.setFingerprint("fingerprint-a");
+    firstCache.invalidate(key);
+
+    expect(firstCache.getOrSet<String>(key, CachePolicy::tempDirectory(), []() -> Result<String> {
+                       return String { "from_disk" };
+                     })
+             .has_value());
+
+    CacheManager secondCache;
+    secondCache.setFingerprint("fingerprint-a");
+    i32        fallbackFetchCount = 0;
+    const auto second             = secondCache.getOrSet<String>(key, CachePolicy::tempDirectory(), [&]() -> Result<String> {
+      ++fallbackFetchCount;
+      return String { "fallback" };
+    });
+    expect(*second == "from_disk");
+    expect(fallbackFetchCount == 0_i);
+
+    secondCache.invalidate(key);
+  };
+
+  "Disk entries written under another fingerprint are refetched"_test = [] -> void {
+    const String key = UniqueCacheKey("fingerprint_mismatch");
+    CacheManager firstCache;
+    firstCache.setFingerprint("fingerprint-a");
+    firstCache.invalidate(key);
+
+    expect(firstCache.getOrSet<String>(key, CachePolicy::tempDirectory(), []() -> Result<String> {
+                       return String { "old_hardware" };
+                     })
+             .has_value());
+
+    CacheManager secondCache;
+    secondCache.setFingerprint("fingerprint-b");
+    i32        fetchCount = 0;
+    const auto second     = secondCache.getOrSet<String>(key, CachePolicy::tempDirectory(), [&]() -> Result<String> {
+      ++fetchCount;
+      return String { "new_hardware" };
+    });
+    expect(*second == "new_hardware");
+    expect(fetchCount == 1_i);
+
+    secondCache.invalidate(key);
+  };
+
   "Corrupt disk entries are replaced atomically"_test = [] -> void {
     const String key       = UniqueCacheKey("corrupt_entry");
     const auto   cacheDir  = CacheManager::getTempCacheDir();
@@ -246,6 +312,28 @@ auto main() -> int {
     std::filesystem::remove(sentinelPath, error);
   };
 
+  "Domain invalidation only removes the domain's keys"_test = [] -> void {
+    const String key = UniqueCacheKey("pkg_count_domain");
+    CacheManager cache;
+    expect(cache.getOrSet<u64>(key, CachePolicy::tempDirectory(), []() -> Result<u64> { return 1; }).has_value());
+
+    const auto unrelatedPath = CacheManager::getTempCacheDir() / UniqueCacheKey("linux_cpu_details");
+    {
+      std::ofstream unrelated(unrelatedPath, std::ios::trunc);
+      unrelated << "keep";
+    }
+
+    cache.invalidateDomain(CacheDomain::Cpu);
+    expect(std::filesystem::exists(unrelatedPath));
+    expect(std::filesystem::exists(CacheManager::getTempCacheDir() / key));
+
+    cache.invalidateDomain(CacheDomain::Packages);
+    expect(!std::filesystem::exists(CacheManager::getTempCacheDir() / key));
+
+    std::error_code error;
+    std::filesystem::remove(unrelatedPath, error);
+  };
+
   "Expired memory entries refetch"_test = [] -> void {
     CacheManager      cache;
     i32               fetchCount = 0;